@@ -72,22 +72,42 @@ fn derive_visit(input: proc_macro::TokenStream, visit_type: &VisitType) -> proc_
     let generics = add_trait_bounds(input.generics, visit_type);
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-    let (pre_visit, post_visit) = attributes.visit(quote!(self));
     let children = visit_children(&input.data, visit_type);
 
-    let expanded = quote! {
-        // The generated impl.
-        impl #impl_generics sqlparser::ast::#visit_trait for #name #ty_generics #where_clause {
-            fn visit<V: sqlparser::ast::#visitor_trait>(
-                &#modifier self,
-                visitor: &mut V
-            ) -> ::std::ops::ControlFlow<V::Break> {
-                #pre_visit
-                #children
-                #post_visit
-                ::std::ops::ControlFlow::Continue(())
+    let expanded = match attributes.with {
+        // Nodes with hooks are visited through the corresponding visitor
+        // method, which by default invokes the hooks around `visit_children`
+        Some(with) => quote! {
+            // The generated impl.
+            impl #impl_generics sqlparser::ast::#visit_trait for #name #ty_generics #where_clause {
+                fn visit<V: sqlparser::ast::#visitor_trait>(
+                    &#modifier self,
+                    visitor: &mut V
+                ) -> ::std::ops::ControlFlow<V::Break> {
+                    visitor.#with(self)
+                }
+
+                fn visit_children<V: sqlparser::ast::#visitor_trait>(
+                    &#modifier self,
+                    visitor: &mut V
+                ) -> ::std::ops::ControlFlow<V::Break> {
+                    #children
+                    ::std::ops::ControlFlow::Continue(())
+                }
             }
-        }
+        },
+        None => quote! {
+            // The generated impl.
+            impl #impl_generics sqlparser::ast::#visit_trait for #name #ty_generics #where_clause {
+                fn visit<V: sqlparser::ast::#visitor_trait>(
+                    &#modifier self,
+                    visitor: &mut V
+                ) -> ::std::ops::ControlFlow<V::Break> {
+                    #children
+                    ::std::ops::ControlFlow::Continue(())
+                }
+            }
+        },
     };
 
     proc_macro::TokenStream::from(expanded)
@@ -15,9 +15,12 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use criterion::{criterion_group, criterion_main, Criterion};
-use sqlparser::dialect::GenericDialect;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use sqlparser::ast::arena::ParseArena;
+use sqlparser::ast::{Query, Statement};
+use sqlparser::dialect::{Dialect, GenericDialect, MySqlDialect};
 use sqlparser::parser::Parser;
+use sqlparser::tokenizer::Tokenizer;
 
 fn basic_queries(c: &mut Criterion) {
     let mut group = c.benchmark_group("sqlparser-rs parsing benchmark");
@@ -44,5 +47,122 @@ fn basic_queries(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, basic_queries);
+/// Cloning a parsed query only bumps a reference count, independent of the
+/// size of the query.
+fn clone_queries(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sqlparser-rs clone benchmark");
+    let dialect = GenericDialect {};
+
+    let sql = "
+        WITH derived AS (
+            SELECT MAX(a) AS max_a,
+                   COUNT(b) AS b_num,
+                   user_id
+            FROM t
+            GROUP BY user_id
+        )
+        SELECT * FROM t
+        LEFT JOIN derived USING (user_id)
+        WHERE max_a > (SELECT AVG(a) FROM t)
+    ";
+    let statement = Parser::parse_sql(&dialect, sql).unwrap().remove(0);
+    group.bench_function("sqlparser::clone_statement", |b| {
+        b.iter(|| statement.clone());
+    });
+
+    let Statement::Query(query) = &statement else {
+        unreachable!()
+    };
+    let query: &Query = query;
+    group.bench_function("sqlparser::deep_clone_query", |b| {
+        b.iter(|| query.clone());
+    });
+}
+
+/// Tokenizing and parsing a multi-megabyte script, where the time is
+/// dominated by the per-token costs of the tokenizer and parser.
+fn large_script(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sqlparser-rs large script benchmark");
+    group.sample_size(10);
+    let dialect = GenericDialect {};
+
+    let statements = "
+        SELECT a.id, b.name, COUNT(*) AS cnt, SUM(c.amount) AS total
+        FROM accounts AS a
+        JOIN users b ON a.user_id = b.id
+        LEFT JOIN charges c ON c.account_id = a.id
+        WHERE a.created_at > '2020-01-01' AND b.status IN ('active', 'pending')
+        GROUP BY a.id, b.name
+        HAVING COUNT(*) > 1
+        ORDER BY cnt DESC
+        LIMIT 100;
+        INSERT INTO audit_log (id, user_id, action, payload)
+        VALUES (1, 42, 'login', NULL), (2, 43, 'logout', 'x');
+        UPDATE users SET status = 'inactive', updated_at = CURRENT_TIMESTAMP WHERE last_login < '2019-01-01';
+    ";
+    let script = statements.repeat(4 * 1024 * 1024 / statements.len());
+
+    group.bench_function("sqlparser::tokenize_4mb", |b| {
+        b.iter(|| Tokenizer::new(&dialect, &script).tokenize().unwrap());
+    });
+    group.bench_function("sqlparser::parse_4mb", |b| {
+        b.iter(|| Parser::parse_sql(&dialect, &script).unwrap());
+    });
+}
+
+/// Parsing chains of binary operators of growing length into boxed
+/// expressions versus into a reused [`ParseArena`]. Both should grow
+/// linearly with the length of the chain, also for a dialect that overrides
+/// [`Dialect::parse_infix`](sqlparser::dialect::Dialect::parse_infix).
+fn operator_chain(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sqlparser-rs operator chain benchmark");
+    let generic = GenericDialect {};
+    let mysql = MySqlDialect {};
+    let dialects: [(&str, &dyn Dialect); 2] = [("generic", &generic), ("mysql", &mysql)];
+
+    for n in [1000, 2000, 4000, 8000] {
+        let sql = (0..n)
+            .map(|i| format!("c{i} = {i}"))
+            .collect::<Vec<_>>()
+            .join(" AND ");
+        for (name, dialect) in dialects {
+            group.bench_with_input(
+                BenchmarkId::new(format!("sqlparser::parse_expr/{name}"), n),
+                &sql,
+                |b, sql| {
+                    b.iter(|| {
+                        Parser::new(dialect)
+                            .try_with_sql(sql)
+                            .unwrap()
+                            .parse_expr()
+                            .unwrap()
+                    });
+                },
+            );
+            let mut arena = ParseArena::new();
+            group.bench_with_input(
+                BenchmarkId::new(format!("sqlparser::parse_expr_in/{name}"), n),
+                &sql,
+                |b, sql| {
+                    b.iter(|| {
+                        arena.clear();
+                        Parser::new(dialect)
+                            .try_with_sql(sql)
+                            .unwrap()
+                            .parse_expr_in(&mut arena)
+                            .unwrap()
+                    });
+                },
+            );
+        }
+    }
+}
+
+criterion_group!(
+    benches,
+    basic_queries,
+    clone_queries,
+    large_script,
+    operator_chain
+);
 criterion_main!(benches);
@@ -0,0 +1,475 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Arena backed expressions, where children are [`ExprId`]s instead of
+//! `Box`es.
+//!
+//! A [`ParseArena`] stores the nodes of any number of expressions in a
+//! single `Vec`, so that long operator chains such as `a AND b AND c ...`
+//! don't allocate a `Box` per operand, and walking them doesn't chase
+//! pointers across the heap. [`Parser::parse_expr_in`] parses an expression
+//! directly into an arena, and [`ParseArena::to_expr`] converts it back to
+//! the boxed [`Expr`] used by the rest of the AST.
+//!
+//! Only the most common expression kinds have an arena representation,
+//! everything else is stored as an [`ArenaExpr::Other`] holding the boxed
+//! [`Expr`].
+//!
+//! # Example
+//! ```
+//! # use sqlparser::ast::arena::{ArenaExpr, ParseArena};
+//! # use sqlparser::ast::BinaryOperator;
+//! # use sqlparser::dialect::GenericDialect;
+//! # use sqlparser::parser::Parser;
+//! let mut arena = ParseArena::new();
+//! let id = Parser::new(&GenericDialect {})
+//!     .try_with_sql("a = 1 AND b IS NULL")
+//!     .unwrap()
+//!     .parse_expr_in(&mut arena)
+//!     .unwrap();
+//! assert!(matches!(
+//!     arena[id],
+//!     ArenaExpr::BinaryOp { op: BinaryOperator::And, .. }
+//! ));
+//! assert_eq!(arena.to_expr(id).to_string(), "a = 1 AND b IS NULL");
+//! ```
+//!
+//! [`Parser::parse_expr_in`]: crate::parser::Parser::parse_expr_in
+
+#[cfg(not(feature = "std"))]
+use alloc::borrow::Cow;
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, vec::Vec};
+use core::mem;
+use core::ops::Index;
+#[cfg(feature = "std")]
+use std::borrow::Cow;
+
+use crate::ast::{BinaryOperator, Expr, Ident, UnaryOperator, Value};
+
+/// The index of an expression in a [`ParseArena`].
+///
+/// An `ExprId` is only meaningful for the arena that created it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ExprId(u32);
+
+impl ExprId {
+    /// The position of the expression in its arena.
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// An expression stored in a [`ParseArena`], see [`Expr`] for the meaning of
+/// the variants.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ArenaExpr {
+    /// Identifier e.g. table name or column name
+    Identifier(Ident),
+    /// Multi-part identifier, e.g. `table_alias.column`
+    CompoundIdentifier(Vec<Ident>),
+    /// A literal value, such as string, number, date or NULL
+    Value(Value),
+    /// Binary operation e.g. `1 + 1` or `foo > bar`
+    BinaryOp {
+        left: ExprId,
+        op: BinaryOperator,
+        right: ExprId,
+    },
+    /// Unary operation e.g. `NOT foo`
+    UnaryOp { op: UnaryOperator, expr: ExprId },
+    /// Nested expression e.g. `(foo > bar)` or `(1)`
+    Nested(ExprId),
+    /// `IS NULL` operator
+    IsNull(ExprId),
+    /// `IS NOT NULL` operator
+    IsNotNull(ExprId),
+    /// `<expr> [ NOT ] BETWEEN <low> AND <high>`
+    Between {
+        expr: ExprId,
+        negated: bool,
+        low: ExprId,
+        high: ExprId,
+    },
+    /// `[ NOT ] IN (val1, val2, ...)`
+    InList {
+        expr: ExprId,
+        list: Vec<ExprId>,
+        negated: bool,
+    },
+    /// ROW / TUPLE a single value, such as `SELECT (1, 2)`
+    Tuple(Vec<ExprId>),
+    /// Any other expression, in its boxed form
+    Other(Box<Expr>),
+}
+
+/// Storage for the nodes of [`ArenaExpr`] trees.
+///
+/// Nodes are never removed individually, use [`ParseArena::clear`] to reuse
+/// the allocation for the next batch of expressions.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ParseArena {
+    exprs: Vec<ArenaExpr>,
+}
+
+impl ParseArena {
+    /// Creates an empty arena.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty arena with room for `capacity` nodes.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            exprs: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// The number of nodes in the arena.
+    pub fn len(&self) -> usize {
+        self.exprs.len()
+    }
+
+    /// Whether the arena has no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.exprs.is_empty()
+    }
+
+    /// Removes all nodes, invalidating every [`ExprId`] handed out so far.
+    pub fn clear(&mut self) {
+        self.exprs.clear();
+    }
+
+    /// Adds a node whose children are already in the arena.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the arena holds more than `u32::MAX` nodes.
+    pub fn push(&mut self, expr: ArenaExpr) -> ExprId {
+        let id = u32::try_from(self.exprs.len()).expect("too many nodes in ParseArena");
+        self.exprs.push(expr);
+        ExprId(id)
+    }
+
+    /// Moves a boxed expression into the arena, returning the id of its root.
+    pub fn alloc(&mut self, expr: Expr) -> ExprId {
+        let node = match expr {
+            Expr::Identifier(ident) => ArenaExpr::Identifier(ident),
+            Expr::CompoundIdentifier(idents) => ArenaExpr::CompoundIdentifier(idents),
+            Expr::Value(value) => ArenaExpr::Value(value),
+            Expr::BinaryOp { left, op, right } => ArenaExpr::BinaryOp {
+                left: self.alloc(*left),
+                op,
+                right: self.alloc(*right),
+            },
+            Expr::UnaryOp { op, expr } => ArenaExpr::UnaryOp {
+                op,
+                expr: self.alloc(*expr),
+            },
+            Expr::Nested(expr) => ArenaExpr::Nested(self.alloc(*expr)),
+            Expr::IsNull(expr) => ArenaExpr::IsNull(self.alloc(*expr)),
+            Expr::IsNotNull(expr) => ArenaExpr::IsNotNull(self.alloc(*expr)),
+            Expr::Between {
+                expr,
+                negated,
+                low,
+                high,
+            } => ArenaExpr::Between {
+                expr: self.alloc(*expr),
+                negated,
+                low: self.alloc(*low),
+                high: self.alloc(*high),
+            },
+            Expr::InList {
+                expr,
+                list,
+                negated,
+            } => ArenaExpr::InList {
+                expr: self.alloc(*expr),
+                list: list.into_iter().map(|expr| self.alloc(expr)).collect(),
+                negated,
+            },
+            Expr::Tuple(exprs) => {
+                ArenaExpr::Tuple(exprs.into_iter().map(|expr| self.alloc(expr)).collect())
+            }
+            other => ArenaExpr::Other(Box::new(other)),
+        };
+        self.push(node)
+    }
+
+    /// Converts the expression rooted at `id` to a boxed [`Expr`].
+    pub fn to_expr(&self, id: ExprId) -> Expr {
+        let boxed = |id: ExprId| Box::new(self.to_expr(id));
+        match &self[id] {
+            ArenaExpr::Identifier(ident) => Expr::Identifier(ident.clone()),
+            ArenaExpr::CompoundIdentifier(idents) => Expr::CompoundIdentifier(idents.clone()),
+            ArenaExpr::Value(value) => Expr::Value(value.clone()),
+            ArenaExpr::BinaryOp { left, op, right } => Expr::BinaryOp {
+                left: boxed(*left),
+                op: op.clone(),
+                right: boxed(*right),
+            },
+            ArenaExpr::UnaryOp { op, expr } => Expr::UnaryOp {
+                op: *op,
+                expr: boxed(*expr),
+            },
+            ArenaExpr::Nested(expr) => Expr::Nested(boxed(*expr)),
+            ArenaExpr::IsNull(expr) => Expr::IsNull(boxed(*expr)),
+            ArenaExpr::IsNotNull(expr) => Expr::IsNotNull(boxed(*expr)),
+            ArenaExpr::Between {
+                expr,
+                negated,
+                low,
+                high,
+            } => Expr::Between {
+                expr: boxed(*expr),
+                negated: *negated,
+                low: boxed(*low),
+                high: boxed(*high),
+            },
+            ArenaExpr::InList {
+                expr,
+                list,
+                negated,
+            } => Expr::InList {
+                expr: boxed(*expr),
+                list: list.iter().map(|id| self.to_expr(*id)).collect(),
+                negated: *negated,
+            },
+            ArenaExpr::Tuple(exprs) => {
+                Expr::Tuple(exprs.iter().map(|id| self.to_expr(*id)).collect())
+            }
+            ArenaExpr::Other(expr) => Expr::clone(expr),
+        }
+    }
+
+    /// Moves the expression rooted at `id` out of the arena, like
+    /// [`ParseArena::to_expr`] but without cloning.
+    ///
+    /// The nodes of the expression are left behind as empty tuples.
+    pub(crate) fn take(&mut self, id: ExprId) -> Expr {
+        let node = mem::replace(&mut self.exprs[id.index()], ArenaExpr::Tuple(Vec::new()));
+        let mut take = |id: ExprId| self.take(id);
+        match node {
+            ArenaExpr::Identifier(ident) => Expr::Identifier(ident),
+            ArenaExpr::CompoundIdentifier(idents) => Expr::CompoundIdentifier(idents),
+            ArenaExpr::Value(value) => Expr::Value(value),
+            ArenaExpr::BinaryOp { left, op, right } => Expr::BinaryOp {
+                left: Box::new(take(left)),
+                op,
+                right: Box::new(take(right)),
+            },
+            ArenaExpr::UnaryOp { op, expr } => Expr::UnaryOp {
+                op,
+                expr: Box::new(take(expr)),
+            },
+            ArenaExpr::Nested(expr) => Expr::Nested(Box::new(take(expr))),
+            ArenaExpr::IsNull(expr) => Expr::IsNull(Box::new(take(expr))),
+            ArenaExpr::IsNotNull(expr) => Expr::IsNotNull(Box::new(take(expr))),
+            ArenaExpr::Between {
+                expr,
+                negated,
+                low,
+                high,
+            } => Expr::Between {
+                expr: Box::new(take(expr)),
+                negated,
+                low: Box::new(take(low)),
+                high: Box::new(take(high)),
+            },
+            ArenaExpr::InList {
+                expr,
+                list,
+                negated,
+            } => Expr::InList {
+                expr: Box::new(take(expr)),
+                list: list.into_iter().map(&mut take).collect(),
+                negated,
+            },
+            ArenaExpr::Tuple(exprs) => Expr::Tuple(exprs.into_iter().map(&mut take).collect()),
+            ArenaExpr::Other(expr) => *expr,
+        }
+    }
+
+    /// The expression at `id` if it has no children in the arena, borrowed
+    /// if it is stored boxed.
+    pub(crate) fn leaf_expr(&self, id: ExprId) -> Option<Cow<'_, Expr>> {
+        match &self[id] {
+            ArenaExpr::Identifier(_) | ArenaExpr::CompoundIdentifier(_) | ArenaExpr::Value(_) => {
+                Some(Cow::Owned(self.to_expr(id)))
+            }
+            ArenaExpr::Other(expr) => Some(Cow::Borrowed(expr)),
+            _ => None,
+        }
+    }
+
+    /// Removes all nodes from `len` onwards.
+    pub(crate) fn truncate(&mut self, len: usize) {
+        self.exprs.truncate(len);
+    }
+}
+
+impl Index<ExprId> for ParseArena {
+    type Output = ArenaExpr;
+
+    fn index(&self, id: ExprId) -> &ArenaExpr {
+        &self.exprs[id.index()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ArenaExpr, ParseArena};
+    use crate::ast::{BinaryOperator, Expr};
+    use crate::dialect::{Associativity, Dialect, GenericDialect, MySqlDialect, Precedence};
+    use crate::parser::{Parser, ParserError};
+    use crate::tokenizer::Token;
+
+    fn parse_in(dialect: &dyn crate::dialect::Dialect, sql: &str) -> (ParseArena, Expr) {
+        let mut arena = ParseArena::new();
+        let id = Parser::new(dialect)
+            .try_with_sql(sql)
+            .unwrap()
+            .parse_expr_in(&mut arena)
+            .unwrap();
+        let expr = arena.to_expr(id);
+        (arena, expr)
+    }
+
+    #[test]
+    fn test_parse_expr_in_matches_boxed_parser() {
+        for sql in [
+            "a + b * c - d / e",
+            "NOT a OR b AND c",
+            "(a + 1) * -b",
+            "a BETWEEN 1 AND 2 OR b NOT IN (1, 2 + 3)",
+            "x > ALL(SELECT y FROM t)",
+            "f(a, b) || 'x' IS NOT NULL",
+            "CAST(a AS INT) = (1, 2)",
+        ] {
+            let (_, expr) = parse_in(&GenericDialect {}, sql);
+            let boxed = Parser::new(&GenericDialect {})
+                .try_with_sql(sql)
+                .unwrap()
+                .parse_expr()
+                .unwrap();
+            assert_eq!(expr, boxed, "{sql}");
+        }
+    }
+
+    #[test]
+    fn test_parse_expr_in_dialect_infix() {
+        let (_, expr) = parse_in(&MySqlDialect {}, "a DIV 2 + 1");
+        assert_eq!(expr.to_string(), "a DIV 2 + 1");
+        let (_, expr) = parse_in(&MySqlDialect {}, "a + doc -> '$.x' = 1");
+        assert_eq!(expr.to_string(), "a + doc -> '$.x' = 1");
+
+        let mut arena = ParseArena::new();
+        assert!(Parser::new(&MySqlDialect {})
+            .try_with_sql("doc -> 1 +")
+            .unwrap()
+            .parse_expr_in(&mut arena)
+            .is_err());
+    }
+
+    /// Redefines the built-in `^` as a right associative operator.
+    #[derive(Debug)]
+    struct PowerDialect;
+
+    impl Dialect for PowerDialect {
+        fn is_identifier_start(&self, ch: char) -> bool {
+            ch.is_ascii_alphabetic()
+        }
+
+        fn is_identifier_part(&self, ch: char) -> bool {
+            ch.is_ascii_alphanumeric()
+        }
+
+        fn get_next_precedence(&self, parser: &Parser) -> Option<Result<u8, ParserError>> {
+            match parser.peek_token().token {
+                Token::Caret => Some(Ok(self.prec_value(Precedence::MulDivModOp) + 1)),
+                _ => None,
+            }
+        }
+
+        fn parse_infix(
+            &self,
+            parser: &mut Parser,
+            expr: &Expr,
+            precedence: u8,
+        ) -> Option<Result<Expr, ParserError>> {
+            if !parser.consume_token(&Token::Caret) {
+                return None;
+            }
+            Some(
+                parser
+                    .parse_infix_operand(precedence, Associativity::Right)
+                    .map(|right| Expr::BinaryOp {
+                        left: Box::new(expr.clone()),
+                        op: BinaryOperator::PGExp,
+                        right: Box::new(right),
+                    }),
+            )
+        }
+    }
+
+    #[test]
+    fn test_parse_expr_in_custom_infix_matches_boxed_parser() {
+        for sql in ["2 ^ 3 ^ 2", "a + 2 ^ 3 ^ 2 * b", "(a ^ b) ^ c AND d"] {
+            let (_, expr) = parse_in(&PowerDialect, sql);
+            let boxed = Parser::new(&PowerDialect)
+                .try_with_sql(sql)
+                .unwrap()
+                .parse_expr()
+                .unwrap();
+            assert_eq!(expr, boxed, "{sql}");
+        }
+        let (_, expr) = parse_in(&PowerDialect, "2 ^ 3 ^ 2");
+        assert_eq!(expr.to_string(), "2 ^ 3 ^ 2");
+        let Expr::BinaryOp { right, .. } = expr else {
+            panic!("expected a binary operator, got {expr:?}");
+        };
+        assert!(matches!(*right, Expr::BinaryOp { .. }));
+    }
+
+    #[test]
+    fn test_boxed_infix_moves_left_operand() {
+        let (arena, expr) = parse_in(&GenericDialect {}, "a + b IS NULL");
+        assert_eq!(expr.to_string(), "a + b IS NULL");
+        // `a`, `b`, `a + b` and `IS NULL`, without leftovers of the first `a + b`
+        assert_eq!(arena.len(), 4);
+        assert!(matches!(arena[super::ExprId(3)], ArenaExpr::IsNull(_)));
+    }
+
+    #[test]
+    fn test_operator_chain_is_flat() {
+        let (arena, _) = parse_in(&GenericDialect {}, "a AND b AND c");
+        assert_eq!(arena.len(), 5);
+        let last = super::ExprId(4);
+        let ArenaExpr::BinaryOp { left, op, right } = &arena[last] else {
+            panic!("expected a binary operator, got {:?}", arena[last]);
+        };
+        assert_eq!(*op, BinaryOperator::And);
+        assert!(matches!(arena[*left], ArenaExpr::BinaryOp { .. }));
+        assert!(matches!(arena[*right], ArenaExpr::Identifier(_)));
+
+        // also when the dialect overrides infix parsing for other operators
+        let (arena, _) = parse_in(&MySqlDialect {}, "a AND b AND c");
+        assert_eq!(arena.len(), 5);
+        assert!(matches!(arena[last], ArenaExpr::BinaryOp { .. }));
+    }
+}
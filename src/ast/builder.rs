@@ -0,0 +1,545 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Fluent builders for constructing common AST nodes programmatically.
+//!
+//! Every builder starts from an empty node, so only the clauses that are
+//! actually needed have to be set:
+//!
+//! ```
+//! # use sqlparser::ast::*;
+//! let select = Select::builder()
+//!     .projection(vec![SelectItem::Wildcard(WildcardAdditionalOptions::default())])
+//!     .from(vec![TableWithJoins {
+//!         relation: TableFactor::Table {
+//!             name: ObjectName(vec![Ident::new("t")]),
+//!             alias: None,
+//!             args: None,
+//!             with_hints: vec![],
+//!             version: None,
+//!             partitions: vec![],
+//!             with_ordinality: false,
+//!         },
+//!         joins: vec![],
+//!     }])
+//!     .selection(Some(Expr::Identifier(Ident::new("a"))))
+//!     .build();
+//! let query = Query::select(select)
+//!     .limit(Some(Expr::Value(Value::Number("10".parse().unwrap(), false))))
+//!     .build();
+//! assert_eq!(query.to_string(), "SELECT * FROM t WHERE a LIMIT 10");
+//! ```
+//!
+//! DDL statements have builders as well, which is handy when generating
+//! schema migrations:
+//!
+//! ```
+//! # use sqlparser::ast::*;
+//! # use sqlparser::ast::builder::AlterTableBuilder;
+//! let name = ObjectName(vec![Ident::new("t")]);
+//! let index = CreateIndex::builder(name.clone(), vec![])
+//!     .name(Some(ObjectName(vec![Ident::new("t_a")])))
+//!     .if_not_exists(true)
+//!     .build();
+//! assert_eq!(index.to_string(), "CREATE INDEX IF NOT EXISTS t_a ON t()");
+//! let alter = AlterTableBuilder::new(name)
+//!     .operation(AlterTableOperation::DropColumn {
+//!         column_name: Ident::new("a"),
+//!         if_exists: true,
+//!         cascade: false,
+//!     })
+//!     .build();
+//! assert_eq!(alter.to_string(), "ALTER TABLE t DROP COLUMN IF EXISTS a");
+//! ```
+//!
+//! See also [`CreateTableBuilder`](crate::ast::helpers::stmt_create_table::CreateTableBuilder).
+
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, string::String, vec, vec::Vec};
+
+use crate::ast::{
+    AlterTableOperation, Assignment, ConnectBy, CreateIndex, CreateTableOptions, Delete,
+    DialectSpecificOption, Distinct, Expr, Fetch, ForClause, FormatClause, FromTable, GroupByExpr,
+    HiveSetLocation, Ident, Insert, LateralView, LockClause, NamedWindowDefinition, ObjectName,
+    Offset, OnInsert, OrderBy, OrderByExpr, Query, QueryHint, Select, SelectInto, SelectItem,
+    SetExpr, Setting, Statement, TableWithJoins, Top, ValueTableMode, ViewColumnDef, ViewEngine,
+    With,
+};
+
+/// Generates a setter for each listed field of the node being built, or of
+/// the builder itself when no node is given.
+macro_rules! setters {
+    ($node:ident { $($field:ident: $ty:ty),* $(,)? }) => {
+        $(
+            pub fn $field(mut self, $field: $ty) -> Self {
+                self.$node.$field = $field;
+                self
+            }
+        )*
+    };
+    ({ $($field:ident: $ty:ty),* $(,)? }) => {
+        $(
+            pub fn $field(mut self, $field: $ty) -> Self {
+                self.$field = $field;
+                self
+            }
+        )*
+    };
+}
+
+/// Builder for a [`Select`], see [`Select::builder`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SelectBuilder {
+    select: Select,
+}
+
+impl Select {
+    /// Returns a builder for a `SELECT` without any clauses.
+    pub fn builder() -> SelectBuilder {
+        SelectBuilder {
+            select: Select {
+                distinct: None,
+                top: None,
+                projection: vec![],
+                into: None,
+                from: vec![],
+                lateral_views: vec![],
+                prewhere: None,
+                selection: None,
+                group_by: GroupByExpr::Expressions(vec![], vec![]),
+                cluster_by: vec![],
+                distribute_by: vec![],
+                sort_by: vec![],
+                having: None,
+                named_window: vec![],
+                qualify: None,
+                window_before_qualify: false,
+                value_table_mode: None,
+                connect_by: None,
+            },
+        }
+    }
+}
+
+impl SelectBuilder {
+    setters!(select {
+        distinct: Option<Distinct>,
+        top: Option<Top>,
+        projection: Vec<SelectItem>,
+        into: Option<SelectInto>,
+        from: Vec<TableWithJoins>,
+        lateral_views: Vec<LateralView>,
+        prewhere: Option<Expr>,
+        selection: Option<Expr>,
+        group_by: GroupByExpr,
+        cluster_by: Vec<Expr>,
+        distribute_by: Vec<Expr>,
+        sort_by: Vec<Expr>,
+        having: Option<Expr>,
+        named_window: Vec<NamedWindowDefinition>,
+        qualify: Option<Expr>,
+        window_before_qualify: bool,
+        value_table_mode: Option<ValueTableMode>,
+        connect_by: Option<ConnectBy>,
+    });
+
+    pub fn build(self) -> Select {
+        self.select
+    }
+}
+
+/// Builder for a [`Query`], see [`Query::builder`] and [`Query::select`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct QueryBuilder {
+    query: Query,
+}
+
+impl Query {
+    /// Returns a builder for a query with the given body and no other clauses.
+    pub fn builder(body: SetExpr) -> QueryBuilder {
+        QueryBuilder {
+            query: Query {
+                with: None,
+                body: Box::new(body),
+                order_by: None,
+                limit: None,
+                limit_by: vec![],
+                offset: None,
+                fetch: None,
+                locks: vec![],
+                for_clause: None,
+                settings: None,
+                format_clause: None,
+                option_clause: None,
+            },
+        }
+    }
+
+    /// Returns a builder for a query whose body is the given `SELECT`.
+    pub fn select(select: Select) -> QueryBuilder {
+        Query::builder(SetExpr::Select(Box::new(select)))
+    }
+}
+
+impl QueryBuilder {
+    setters!(query {
+        with: Option<With>,
+        order_by: Option<OrderBy>,
+        limit: Option<Expr>,
+        limit_by: Vec<Expr>,
+        offset: Option<Offset>,
+        fetch: Option<Fetch>,
+        locks: Vec<LockClause>,
+        for_clause: Option<ForClause>,
+        settings: Option<Vec<Setting>>,
+        format_clause: Option<FormatClause>,
+        option_clause: Option<Vec<QueryHint>>,
+    });
+
+    pub fn build(self) -> Query {
+        self.query
+    }
+}
+
+/// Builder for an `INSERT` statement, see [`Insert::builder`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InsertBuilder {
+    insert: Insert,
+}
+
+impl Insert {
+    /// Returns a builder for an `INSERT INTO <table_name>` without a source.
+    pub fn builder(table_name: ObjectName) -> InsertBuilder {
+        InsertBuilder {
+            insert: Insert {
+                or: None,
+                ignore: false,
+                into: true,
+                table_name,
+                table_alias: None,
+                columns: vec![],
+                overwrite: false,
+                source: None,
+                assignments: vec![],
+                partitioned: None,
+                after_columns: vec![],
+                table: false,
+                on: vec![],
+                returning: None,
+                replace_into: false,
+                priority: None,
+                insert_alias: None,
+            },
+        }
+    }
+}
+
+impl InsertBuilder {
+    setters!(insert {
+        ignore: bool,
+        into: bool,
+        table_alias: Option<Ident>,
+        columns: Vec<Ident>,
+        overwrite: bool,
+        source: Option<Box<Query>>,
+        assignments: Vec<Assignment>,
+        partitioned: Option<Vec<Expr>>,
+        after_columns: Vec<Ident>,
+        table: bool,
+        on: Vec<OnInsert>,
+        returning: Option<Vec<SelectItem>>,
+        replace_into: bool,
+    });
+
+    pub fn build(self) -> Statement {
+        Statement::Insert(self.insert)
+    }
+}
+
+/// Builder for a `DELETE` statement, see [`Delete::builder`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DeleteBuilder {
+    delete: Delete,
+}
+
+impl Delete {
+    /// Returns a builder for a `DELETE FROM <from>` without any other clauses.
+    pub fn builder(from: Vec<TableWithJoins>) -> DeleteBuilder {
+        DeleteBuilder {
+            delete: Delete {
+                tables: vec![],
+                from: FromTable::WithFromKeyword(from),
+                using: None,
+                selection: None,
+                returning: None,
+                order_by: vec![],
+                limit: None,
+            },
+        }
+    }
+}
+
+impl DeleteBuilder {
+    setters!(delete {
+        tables: Vec<ObjectName>,
+        using: Option<Vec<TableWithJoins>>,
+        selection: Option<Expr>,
+        returning: Option<Vec<SelectItem>>,
+        order_by: Vec<OrderByExpr>,
+        limit: Option<Expr>,
+    });
+
+    pub fn build(self) -> Statement {
+        Statement::Delete(self.delete)
+    }
+}
+
+/// Builder for an `UPDATE` statement.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UpdateBuilder {
+    table: TableWithJoins,
+    assignments: Vec<Assignment>,
+    from: Vec<TableWithJoins>,
+    selection: Option<Expr>,
+    returning: Option<Vec<SelectItem>>,
+}
+
+impl UpdateBuilder {
+    /// Returns a builder for an `UPDATE <table> SET <assignments>` without
+    /// any other clauses.
+    pub fn new(table: TableWithJoins, assignments: Vec<Assignment>) -> Self {
+        UpdateBuilder {
+            table,
+            assignments,
+            from: vec![],
+            selection: None,
+            returning: None,
+        }
+    }
+
+    pub fn from(mut self, from: Vec<TableWithJoins>) -> Self {
+        self.from = from;
+        self
+    }
+
+    pub fn selection(mut self, selection: Option<Expr>) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    pub fn returning(mut self, returning: Option<Vec<SelectItem>>) -> Self {
+        self.returning = returning;
+        self
+    }
+
+    pub fn build(self) -> Statement {
+        Statement::Update {
+            table: self.table,
+            assignments: self.assignments,
+            from: self.from,
+            selection: self.selection,
+            returning: self.returning,
+        }
+    }
+}
+
+/// Builder for a `CREATE INDEX` statement, see [`CreateIndex::builder`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CreateIndexBuilder {
+    create_index: CreateIndex,
+}
+
+impl CreateIndex {
+    /// Returns a builder for an unnamed `CREATE INDEX ON <table_name> (<columns>)`.
+    pub fn builder(table_name: ObjectName, columns: Vec<OrderByExpr>) -> CreateIndexBuilder {
+        CreateIndexBuilder {
+            create_index: CreateIndex {
+                name: None,
+                table_name,
+                using: None,
+                columns,
+                unique: false,
+                concurrently: false,
+                if_not_exists: false,
+                include: vec![],
+                nulls_distinct: None,
+                with: vec![],
+                predicate: None,
+            },
+        }
+    }
+}
+
+impl CreateIndexBuilder {
+    setters!(create_index {
+        name: Option<ObjectName>,
+        using: Option<Ident>,
+        unique: bool,
+        concurrently: bool,
+        if_not_exists: bool,
+        include: Vec<Ident>,
+        nulls_distinct: Option<bool>,
+        with: Vec<Expr>,
+        predicate: Option<Expr>,
+    });
+
+    pub fn build(self) -> Statement {
+        Statement::CreateIndex(self.create_index)
+    }
+}
+
+/// Builder for a `CREATE VIEW` statement.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CreateViewBuilder {
+    name: ObjectName,
+    query: Box<Query>,
+    or_replace: bool,
+    materialized: bool,
+    columns: Vec<ViewColumnDef>,
+    options: CreateTableOptions,
+    cluster_by: Vec<Ident>,
+    comment: Option<String>,
+    with_no_schema_binding: bool,
+    if_not_exists: bool,
+    temporary: bool,
+    to: Option<ObjectName>,
+    engine: Option<ViewEngine>,
+    populate: bool,
+    using: Option<Ident>,
+    with_data: Option<bool>,
+    unknown_options: Vec<DialectSpecificOption>,
+}
+
+impl CreateViewBuilder {
+    /// Returns a builder for a `CREATE VIEW <name> AS <query>` without any
+    /// other clauses.
+    pub fn new(name: ObjectName, query: Query) -> Self {
+        CreateViewBuilder {
+            name,
+            query: Box::new(query),
+            or_replace: false,
+            materialized: false,
+            columns: vec![],
+            options: CreateTableOptions::None,
+            cluster_by: vec![],
+            comment: None,
+            with_no_schema_binding: false,
+            if_not_exists: false,
+            temporary: false,
+            to: None,
+            engine: None,
+            populate: false,
+            using: None,
+            with_data: None,
+            unknown_options: vec![],
+        }
+    }
+
+    setters!({
+        or_replace: bool,
+        materialized: bool,
+        columns: Vec<ViewColumnDef>,
+        options: CreateTableOptions,
+        cluster_by: Vec<Ident>,
+        comment: Option<String>,
+        with_no_schema_binding: bool,
+        if_not_exists: bool,
+        temporary: bool,
+        to: Option<ObjectName>,
+        engine: Option<ViewEngine>,
+        populate: bool,
+        using: Option<Ident>,
+        with_data: Option<bool>,
+        unknown_options: Vec<DialectSpecificOption>,
+    });
+
+    pub fn build(self) -> Statement {
+        Statement::CreateView {
+            or_replace: self.or_replace,
+            materialized: self.materialized,
+            name: self.name,
+            columns: self.columns,
+            query: self.query,
+            options: self.options,
+            cluster_by: self.cluster_by,
+            comment: self.comment,
+            with_no_schema_binding: self.with_no_schema_binding,
+            if_not_exists: self.if_not_exists,
+            temporary: self.temporary,
+            to: self.to,
+            engine: self.engine,
+            populate: self.populate,
+            using: self.using,
+            with_data: self.with_data,
+            unknown_options: self.unknown_options,
+        }
+    }
+}
+
+/// Builder for an `ALTER TABLE` statement.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AlterTableBuilder {
+    name: ObjectName,
+    if_exists: bool,
+    only: bool,
+    operations: Vec<AlterTableOperation>,
+    location: Option<HiveSetLocation>,
+    on_cluster: Option<Ident>,
+    unknown_options: Vec<DialectSpecificOption>,
+}
+
+impl AlterTableBuilder {
+    /// Returns a builder for an `ALTER TABLE <name>` without any operations.
+    pub fn new(name: ObjectName) -> Self {
+        AlterTableBuilder {
+            name,
+            if_exists: false,
+            only: false,
+            operations: vec![],
+            location: None,
+            on_cluster: None,
+            unknown_options: vec![],
+        }
+    }
+
+    setters!({
+        if_exists: bool,
+        only: bool,
+        operations: Vec<AlterTableOperation>,
+        location: Option<HiveSetLocation>,
+        on_cluster: Option<Ident>,
+        unknown_options: Vec<DialectSpecificOption>,
+    });
+
+    /// Appends an operation to the ones already set.
+    pub fn operation(mut self, operation: AlterTableOperation) -> Self {
+        self.operations.push(operation);
+        self
+    }
+
+    pub fn build(self) -> Statement {
+        Statement::AlterTable {
+            name: self.name,
+            if_exists: self.if_exists,
+            only: self.only,
+            operations: self.operations,
+            location: self.location,
+            on_cluster: self.on_cluster,
+            unknown_options: self.unknown_options,
+        }
+    }
+}
@@ -0,0 +1,145 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Opt-in rewrites of the AST into a canonical shape, so that analyses
+//! only have to handle one of several equivalent ways to write a query.
+//!
+//! ```
+//! # use sqlparser::ast::canonicalize::comma_joins_to_cross_joins;
+//! # use sqlparser::dialect::GenericDialect;
+//! # use sqlparser::parser::Parser;
+//! let mut query = Parser::new(&GenericDialect {})
+//!     .try_with_sql("SELECT * FROM a, b WHERE a.id = b.id")
+//!     .unwrap()
+//!     .parse_query()
+//!     .unwrap();
+//! comma_joins_to_cross_joins(&mut query);
+//! assert_eq!(
+//!     query.to_string(),
+//!     "SELECT * FROM a CROSS JOIN b WHERE a.id = b.id"
+//! );
+//! ```
+
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, vec, vec::Vec};
+use core::mem;
+use core::ops::ControlFlow;
+
+use crate::ast::{
+    Join, JoinOperator, Query, SetExpr, TableFactor, TableWithJoins, VisitMut, VisitorMut,
+};
+
+/// Rewrites the implicit joins of a comma separated `FROM` list into
+/// explicit `CROSS JOIN`s, in `query` and in all of its subqueries.
+///
+/// `FROM a, b JOIN c ON b.x = c.x` becomes
+/// `FROM a CROSS JOIN (b JOIN c ON b.x = c.x)`: the joins of every item of
+/// the list are kept together, as they bind tighter than the comma.
+pub fn comma_joins_to_cross_joins(query: &mut Query) {
+    let _ = query.visit(&mut CommaJoins);
+}
+
+struct CommaJoins;
+
+impl VisitorMut for CommaJoins {
+    type Break = ();
+
+    fn post_visit_query(&mut self, query: &mut Query) -> ControlFlow<Self::Break> {
+        // Nested queries are visited on their own
+        cross_join_set_expr(&mut query.body);
+        ControlFlow::Continue(())
+    }
+}
+
+fn cross_join_set_expr(set_expr: &mut SetExpr) {
+    match set_expr {
+        SetExpr::Select(select) => cross_join_from(&mut select.from),
+        SetExpr::SetOperation { left, right, .. } => {
+            cross_join_set_expr(left);
+            cross_join_set_expr(right);
+        }
+        _ => {}
+    }
+}
+
+fn cross_join_from(from: &mut Vec<TableWithJoins>) {
+    if from.len() < 2 {
+        return;
+    }
+    let mut tables = mem::take(from).into_iter();
+    let mut first = tables.next().unwrap();
+    for table in tables {
+        let relation = if table.joins.is_empty() {
+            table.relation
+        } else {
+            TableFactor::NestedJoin {
+                table_with_joins: Box::new(table),
+                alias: None,
+            }
+        };
+        first.joins.push(Join {
+            relation,
+            global: false,
+            join_operator: JoinOperator::CrossJoin,
+        });
+    }
+    *from = vec![first];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dialect::GenericDialect;
+    use crate::parser::Parser;
+
+    fn canonicalize(sql: &str) -> String {
+        let mut query = Parser::new(&GenericDialect {})
+            .try_with_sql(sql)
+            .unwrap()
+            .parse_query()
+            .unwrap();
+        comma_joins_to_cross_joins(&mut query);
+        query.to_string()
+    }
+
+    #[test]
+    fn comma_joins() {
+        assert_eq!(
+            canonicalize("SELECT * FROM a, b, c"),
+            "SELECT * FROM a CROSS JOIN b CROSS JOIN c"
+        );
+        assert_eq!(
+            canonicalize("SELECT * FROM a JOIN b ON a.x = b.x, c"),
+            "SELECT * FROM a JOIN b ON a.x = b.x CROSS JOIN c"
+        );
+        assert_eq!(
+            canonicalize("SELECT * FROM a, b LEFT JOIN c ON b.x = c.x"),
+            "SELECT * FROM a CROSS JOIN (b LEFT JOIN c ON b.x = c.x)"
+        );
+        assert_eq!(canonicalize("SELECT * FROM a"), "SELECT * FROM a");
+    }
+
+    #[test]
+    fn comma_joins_in_subqueries() {
+        assert_eq!(
+            canonicalize(
+                "WITH w AS (SELECT * FROM a, b) SELECT * FROM w WHERE x IN (SELECT y FROM c, d) UNION SELECT * FROM (SELECT * FROM e, f) AS g"
+            ),
+            "WITH w AS (SELECT * FROM a CROSS JOIN b) SELECT * FROM w WHERE x IN (SELECT y FROM c CROSS JOIN d) UNION SELECT * FROM (SELECT * FROM e CROSS JOIN f) AS g"
+        );
+    }
+}
@@ -0,0 +1,432 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Validation of parsed statements against a level of the SQL standard.
+//!
+//! [`check_conformance`] walks a statement and reports every construct that
+//! is either vendor specific, or was only introduced in a later revision of
+//! the standard than the requested [`SqlStandard`]. Standard constructs are
+//! identified by their SQL/Foundation feature ID (e.g. `T611` for
+//! elementary OLAP operations).
+//!
+//! The check is best-effort and purely syntactic: it covers the constructs
+//! that most commonly hurt portability rather than the complete feature
+//! taxonomy of the standard.
+//!
+//! # Example
+//! ```
+//! # use sqlparser::ast::conformance::{check_conformance, SqlStandard};
+//! # use sqlparser::dialect::GenericDialect;
+//! # use sqlparser::parser::Parser;
+//! let sql = "SELECT TOP 5 a FROM t WHERE b ILIKE 'x%'";
+//! let statements = Parser::parse_sql(&GenericDialect {}, sql).unwrap();
+//! let violations = check_conformance(&statements[0], SqlStandard::Sql2016);
+//! let constructs: Vec<_> = violations.iter().map(|v| v.construct).collect();
+//! assert_eq!(constructs, vec!["TOP", "ILIKE"]);
+//! ```
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::ControlFlow;
+
+use crate::ast::{
+    CastKind, Expr, JoinOperator, Query, Select, SetExpr, Statement, TableFactor, TableWithJoins,
+    Visit, Visitor,
+};
+
+/// A revision of the SQL standard.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SqlStandard {
+    /// SQL-92
+    Sql1992,
+    /// SQL:1999
+    Sql1999,
+    /// SQL:2003
+    Sql2003,
+    /// SQL:2008
+    Sql2008,
+    /// SQL:2011
+    Sql2011,
+    /// SQL:2016
+    Sql2016,
+}
+
+impl fmt::Display for SqlStandard {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            SqlStandard::Sql1992 => "SQL-92",
+            SqlStandard::Sql1999 => "SQL:1999",
+            SqlStandard::Sql2003 => "SQL:2003",
+            SqlStandard::Sql2008 => "SQL:2008",
+            SqlStandard::Sql2011 => "SQL:2011",
+            SqlStandard::Sql2016 => "SQL:2016",
+        })
+    }
+}
+
+/// Why a construct does not conform to the requested standard.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ConformanceIssue {
+    /// The construct is not part of any revision of the standard.
+    NonStandard,
+    /// The construct is standard feature `feature`, introduced in `since`.
+    IntroducedIn {
+        /// The SQL/Foundation feature ID, e.g. `T611`.
+        feature: &'static str,
+        /// The first revision of the standard that includes the feature.
+        since: SqlStandard,
+    },
+}
+
+/// A construct that does not conform to the requested standard.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ConformanceViolation {
+    /// Short SQL rendering of the offending construct, e.g. `ILIKE`.
+    pub construct: &'static str,
+    /// Why the construct does not conform.
+    pub issue: ConformanceIssue,
+}
+
+impl fmt::Display for ConformanceViolation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.issue {
+            ConformanceIssue::NonStandard => write!(f, "{} is not standard SQL", self.construct),
+            ConformanceIssue::IntroducedIn { feature, since } => {
+                write!(f, "{} requires {since} (feature {feature})", self.construct)
+            }
+        }
+    }
+}
+
+/// Returns the constructs used by `statement` (including nested queries and
+/// expressions) that do not conform to `standard`, in order of appearance.
+pub fn check_conformance(
+    statement: &Statement,
+    standard: SqlStandard,
+) -> Vec<ConformanceViolation> {
+    let mut checker = ConformanceChecker {
+        standard,
+        violations: Vec::new(),
+    };
+    let _ = statement.visit(&mut checker);
+    checker.violations
+}
+
+struct ConformanceChecker {
+    standard: SqlStandard,
+    violations: Vec<ConformanceViolation>,
+}
+
+impl ConformanceChecker {
+    fn non_standard(&mut self, construct: &'static str) {
+        self.violations.push(ConformanceViolation {
+            construct,
+            issue: ConformanceIssue::NonStandard,
+        });
+    }
+
+    fn requires(&mut self, construct: &'static str, feature: &'static str, since: SqlStandard) {
+        if since > self.standard {
+            self.violations.push(ConformanceViolation {
+                construct,
+                issue: ConformanceIssue::IntroducedIn { feature, since },
+            });
+        }
+    }
+
+    fn check_set_expr(&mut self, set_expr: &SetExpr) {
+        match set_expr {
+            SetExpr::Select(select) => self.check_select(select),
+            SetExpr::SetOperation { left, right, .. } => {
+                self.check_set_expr(left);
+                self.check_set_expr(right);
+            }
+            SetExpr::Table(_) => self.requires("TABLE", "F661", SqlStandard::Sql2003),
+            _ => {}
+        }
+    }
+
+    fn check_select(&mut self, select: &Select) {
+        if select.top.is_some() {
+            self.non_standard("TOP");
+        }
+        if matches!(select.distinct, Some(crate::ast::Distinct::On(_))) {
+            self.non_standard("DISTINCT ON");
+        }
+        if !select.lateral_views.is_empty() {
+            self.non_standard("LATERAL VIEW");
+        }
+        if select.prewhere.is_some() {
+            self.non_standard("PREWHERE");
+        }
+        if select.qualify.is_some() {
+            self.non_standard("QUALIFY");
+        }
+        if select.connect_by.is_some() {
+            self.non_standard("CONNECT BY");
+        }
+        if !select.cluster_by.is_empty()
+            || !select.distribute_by.is_empty()
+            || !select.sort_by.is_empty()
+        {
+            self.non_standard("CLUSTER/DISTRIBUTE/SORT BY");
+        }
+        if !select.named_window.is_empty() {
+            self.requires("WINDOW", "T611", SqlStandard::Sql2003);
+        }
+        for table_with_joins in &select.from {
+            self.check_joins(table_with_joins);
+        }
+    }
+
+    /// Checks the joins of `table_with_joins`, including the ones inside
+    /// parenthesized joins such as `a JOIN (b SEMI JOIN c)`.
+    fn check_joins(&mut self, table_with_joins: &TableWithJoins) {
+        self.check_nested_joins(&table_with_joins.relation);
+        for join in &table_with_joins.joins {
+            match &join.join_operator {
+                JoinOperator::LeftSemi(_)
+                | JoinOperator::RightSemi(_)
+                | JoinOperator::LeftAnti(_)
+                | JoinOperator::RightAnti(_)
+                | JoinOperator::Semi(_)
+                | JoinOperator::Anti(_) => self.non_standard("SEMI/ANTI JOIN"),
+                JoinOperator::CrossApply | JoinOperator::OuterApply => self.non_standard("APPLY"),
+                JoinOperator::StraightJoin(_) => self.non_standard("STRAIGHT_JOIN"),
+                JoinOperator::ArrayJoin | JoinOperator::LeftArrayJoin => {
+                    self.non_standard("ARRAY JOIN")
+                }
+                JoinOperator::AsOf { .. } => self.non_standard("ASOF JOIN"),
+                _ => {}
+            }
+            self.check_nested_joins(&join.relation);
+        }
+    }
+
+    fn check_nested_joins(&mut self, relation: &TableFactor) {
+        if let TableFactor::NestedJoin {
+            table_with_joins, ..
+        } = relation
+        {
+            self.check_joins(table_with_joins);
+        }
+    }
+}
+
+impl Visitor for ConformanceChecker {
+    type Break = ();
+
+    fn pre_visit_statement(&mut self, statement: &Statement) -> ControlFlow<Self::Break> {
+        match statement {
+            Statement::Merge { .. } => self.requires("MERGE", "F312", SqlStandard::Sql2003),
+            Statement::Pragma { .. } => self.non_standard("PRAGMA"),
+            Statement::Copy { .. } => self.non_standard("COPY"),
+            Statement::Do { .. } => self.non_standard("DO"),
+            Statement::Handler { .. } => self.non_standard("HANDLER"),
+            Statement::GrantProxy { .. } => self.non_standard("GRANT PROXY"),
+            Statement::RevokeProxy { .. } => self.non_standard("REVOKE PROXY"),
+            Statement::SetPassword { .. } => self.non_standard("SET PASSWORD"),
+            Statement::Use(_) => self.non_standard("USE"),
+            Statement::If { .. } => self.non_standard("IF"),
+            Statement::While { .. } => self.non_standard("WHILE"),
+            Statement::Block(_) => self.non_standard("BEGIN ... END"),
+            Statement::Return(_) => self.non_standard("RETURN"),
+            Statement::ShowTables { .. }
+            | Statement::ShowColumns { .. }
+            | Statement::ShowCreate { .. }
+            | Statement::ShowCreateUser { .. }
+            | Statement::ShowObjects { .. } => self.non_standard("SHOW"),
+            Statement::Insert(insert) if insert.replace_into => self.non_standard("REPLACE INTO"),
+            Statement::Insert(insert) if insert.ignore => self.non_standard("INSERT IGNORE"),
+            _ => {}
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn pre_visit_query(&mut self, query: &Query) -> ControlFlow<Self::Break> {
+        if let Some(with) = &query.with {
+            if with.recursive {
+                self.requires("WITH RECURSIVE", "T131", SqlStandard::Sql1999);
+            } else {
+                self.requires("WITH", "T121", SqlStandard::Sql1999);
+            }
+        }
+        if query.limit.is_some() {
+            self.non_standard("LIMIT");
+        }
+        if query.offset.is_some() {
+            self.requires("OFFSET", "F861", SqlStandard::Sql2008);
+        }
+        if query.fetch.is_some() {
+            self.requires("FETCH FIRST", "F856", SqlStandard::Sql2008);
+        }
+        if query.for_clause.is_some() {
+            self.non_standard("FOR XML/JSON/BROWSE");
+        }
+        if query.settings.is_some() || query.format_clause.is_some() {
+            self.non_standard("SETTINGS/FORMAT");
+        }
+        self.check_set_expr(&query.body);
+        ControlFlow::Continue(())
+    }
+
+    fn pre_visit_table_factor(&mut self, table_factor: &TableFactor) -> ControlFlow<Self::Break> {
+        match table_factor {
+            TableFactor::Table {
+                with_hints,
+                version,
+                ..
+            } => {
+                if !with_hints.is_empty() {
+                    self.non_standard("table hints");
+                }
+                if version.is_some() {
+                    self.requires("FOR SYSTEM_TIME", "T180", SqlStandard::Sql2011);
+                }
+            }
+            TableFactor::Derived { lateral: true, .. } => {
+                self.requires("LATERAL", "T491", SqlStandard::Sql1999)
+            }
+            TableFactor::UNNEST { .. } => self.requires("UNNEST", "S301", SqlStandard::Sql1999),
+            TableFactor::JsonTable { .. } => {
+                self.requires("JSON_TABLE", "T821", SqlStandard::Sql2016)
+            }
+            TableFactor::MatchRecognize { .. } => {
+                self.requires("MATCH_RECOGNIZE", "R010", SqlStandard::Sql2016)
+            }
+            TableFactor::Pivot { .. } => self.non_standard("PIVOT"),
+            TableFactor::Unpivot { .. } => self.non_standard("UNPIVOT"),
+            TableFactor::RowsFrom { lateral, .. } => {
+                if *lateral {
+                    self.requires("LATERAL", "T491", SqlStandard::Sql1999);
+                }
+                self.non_standard("ROWS FROM");
+            }
+            _ => {}
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn pre_visit_expr(&mut self, expr: &Expr) -> ControlFlow<Self::Break> {
+        match expr {
+            Expr::ILike { .. } => self.non_standard("ILIKE"),
+            Expr::RLike { .. } => self.non_standard("RLIKE/REGEXP"),
+            Expr::SimilarTo { .. } => self.requires("SIMILAR TO", "T141", SqlStandard::Sql1999),
+            Expr::IsDistinctFrom(..) | Expr::IsNotDistinctFrom(..) => {
+                self.requires("IS DISTINCT FROM", "T151", SqlStandard::Sql1999)
+            }
+            Expr::JsonAccess { .. } => self.non_standard("JSON access operator"),
+            Expr::MapAccess { .. } => self.non_standard("map access"),
+            Expr::MatchAgainst { .. } => self.non_standard("MATCH ... AGAINST"),
+            Expr::Lambda(_) => self.non_standard("lambda function"),
+            Expr::Dictionary(_) | Expr::Map(_) => self.non_standard("dictionary/map literal"),
+            Expr::OuterJoin(_) => self.non_standard("(+) outer join"),
+            Expr::Prior(_) => self.non_standard("PRIOR"),
+            Expr::Cast { kind, .. } => match kind {
+                CastKind::Cast => {}
+                CastKind::DoubleColon => self.non_standard("::"),
+                CastKind::TryCast => self.non_standard("TRY_CAST"),
+                CastKind::SafeCast => self.non_standard("SAFE_CAST"),
+            },
+            Expr::GroupingSets(_) | Expr::Cube(_) | Expr::Rollup(_) => {
+                self.requires("GROUPING SETS/CUBE/ROLLUP", "T431", SqlStandard::Sql1999)
+            }
+            Expr::Array(_) => self.requires("ARRAY", "S091", SqlStandard::Sql1999),
+            Expr::ArraySubquery(_) => self.requires("ARRAY subquery", "S095", SqlStandard::Sql2003),
+            Expr::Function(function) => {
+                if function.over.is_some() {
+                    self.requires("OVER", "T611", SqlStandard::Sql2003);
+                }
+                if function.filter.is_some() {
+                    self.requires("FILTER", "T612", SqlStandard::Sql2003);
+                }
+                if !function.within_group.is_empty() {
+                    self.requires("WITHIN GROUP", "T612", SqlStandard::Sql2003);
+                }
+            }
+            _ => {}
+        }
+        ControlFlow::Continue(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dialect::GenericDialect;
+    use crate::parser::Parser;
+
+    fn check(sql: &str, standard: SqlStandard) -> Vec<ConformanceViolation> {
+        let statement = Parser::parse_sql(&GenericDialect {}, sql)
+            .unwrap()
+            .remove(0);
+        check_conformance(&statement, standard)
+    }
+
+    #[test]
+    fn test_standard_query_conforms() {
+        let sql = "SELECT a, COUNT(*) FROM t JOIN u ON t.id = u.id WHERE b IN (SELECT c FROM v) GROUP BY a";
+        assert_eq!(check(sql, SqlStandard::Sql1992), vec![]);
+    }
+
+    #[test]
+    fn test_feature_level() {
+        let sql = "WITH w AS (SELECT 1) SELECT ROW_NUMBER() OVER (ORDER BY a) FROM w";
+        assert_eq!(check(sql, SqlStandard::Sql2003), vec![]);
+        assert_eq!(
+            check(sql, SqlStandard::Sql1999),
+            vec![ConformanceViolation {
+                construct: "OVER",
+                issue: ConformanceIssue::IntroducedIn {
+                    feature: "T611",
+                    since: SqlStandard::Sql2003
+                },
+            }]
+        );
+        assert_eq!(
+            check(sql, SqlStandard::Sql1992)
+                .iter()
+                .map(|v| v.to_string())
+                .collect::<Vec<_>>(),
+            vec![
+                "WITH requires SQL:1999 (feature T121)",
+                "OVER requires SQL:2003 (feature T611)",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_non_standard_constructs() {
+        let sql = "SELECT a::INT FROM t WHERE EXISTS (SELECT 1 FROM u LIMIT 1)";
+        let constructs: Vec<_> = check(sql, SqlStandard::Sql2016)
+            .into_iter()
+            .map(|v| v.construct)
+            .collect();
+        assert_eq!(constructs, vec!["::", "LIMIT"]);
+    }
+
+    #[test]
+    fn test_nested_joins() {
+        let sql = "SELECT * FROM a JOIN (b SEMI JOIN (c CROSS APPLY d) ON true) ON true";
+        let constructs: Vec<_> = check(sql, SqlStandard::Sql2016)
+            .into_iter()
+            .map(|v| v.construct)
+            .collect();
+        assert_eq!(constructs, vec!["SEMI/ANTI JOIN", "APPLY"]);
+    }
+}
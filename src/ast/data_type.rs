@@ -19,6 +19,8 @@
 use alloc::{boxed::Box, format, string::String, vec::Vec};
 use core::fmt;
 
+#[cfg(feature = "json_schema")]
+use schemars::JsonSchema;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -32,7 +34,12 @@ use super::{value::escape_single_quote_string, ColumnDef};
 /// SQL data types
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(
+    feature = "visitor",
+    derive(Visit, VisitMut),
+    visit(with = "visit_data_type")
+)]
 pub enum DataType {
     /// Fixed-length character type e.g. CHARACTER(10)
     Character(Option<CharacterLength>),
@@ -46,6 +53,10 @@ pub enum DataType {
     Varchar(Option<CharacterLength>),
     /// Variable-length character type e.g. NVARCHAR(10)
     Nvarchar(Option<CharacterLength>),
+    /// Variable-length character type e.g. VARCHAR2(10), [Oracle]
+    ///
+    /// [Oracle]: https://docs.oracle.com/en/database/oracle/oracle-database/23/sqlrf/Data-Types.html#GUID-DF7E10FC-A461-4325-A295-3FD4D150809E
+    Varchar2(Option<CharacterLength>),
     /// Uuid type
     Uuid,
     /// Large character object with optional length e.g. CHARACTER LARGE OBJECT, CHARACTER LARGE OBJECT(1000), [standard]
@@ -96,6 +107,10 @@ pub enum DataType {
     ///
     /// [BigDecimal]: https://cloud.google.com/bigquery/docs/reference/standard-sql/data-types#decimal_types
     BigDecimal(ExactNumberInfo),
+    /// Number type with optional precision and scale e.g. NUMBER(10,2), [Oracle]
+    ///
+    /// [Oracle]: https://docs.oracle.com/en/database/oracle/oracle-database/23/sqlrf/Data-Types.html#GUID-75209AF6-476D-4C44-A5DC-5FA70D701B78
+    Number(ExactNumberInfo),
     /// Dec type with optional precision and scale e.g. DEC(10,2), [standard][1]
     ///
     /// [1]: https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#exact-numeric-type
@@ -342,6 +357,7 @@ impl fmt::Display for DataType {
             DataType::CharVarying(size) => format_character_string_type(f, "CHAR VARYING", size),
             DataType::Varchar(size) => format_character_string_type(f, "VARCHAR", size),
             DataType::Nvarchar(size) => format_character_string_type(f, "NVARCHAR", size),
+            DataType::Varchar2(size) => format_character_string_type(f, "VARCHAR2", size),
             DataType::Uuid => write!(f, "UUID"),
             DataType::CharacterLargeObject(size) => {
                 format_type_with_optional_length(f, "CHARACTER LARGE OBJECT", size, false)
@@ -367,6 +383,7 @@ impl fmt::Display for DataType {
             }
             DataType::BigNumeric(info) => write!(f, "BIGNUMERIC{info}"),
             DataType::BigDecimal(info) => write!(f, "BIGDECIMAL{info}"),
+            DataType::Number(info) => write!(f, "NUMBER{info}"),
             DataType::Float(size) => format_type_with_optional_length(f, "FLOAT", size, false),
             DataType::TinyInt(zerofill) => {
                 format_type_with_optional_length(f, "TINYINT", zerofill, false)
@@ -633,6 +650,7 @@ fn format_clickhouse_datetime_precision_and_timezone(
 /// Type of brackets used for `STRUCT` literals.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum StructBracketKind {
     /// Example: `STRUCT(a INT, b STRING)`
@@ -647,6 +665,7 @@ pub enum StructBracketKind {
 /// guarantee compatibility with the input query we must maintain its exact information.
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum TimezoneInfo {
     /// No information about time zone. E.g., TIMESTAMP
@@ -695,6 +714,7 @@ impl fmt::Display for TimezoneInfo {
 /// [standard]: https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#exact-numeric-type
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum ExactNumberInfo {
     /// No additional information e.g. `DECIMAL`
@@ -726,6 +746,7 @@ impl fmt::Display for ExactNumberInfo {
 /// [1]: https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#character-length
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum CharacterLength {
     IntegerLength {
@@ -760,12 +781,21 @@ impl fmt::Display for CharacterLength {
 /// [1]: https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#char-length-units
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum CharLengthUnits {
     /// CHARACTERS unit
     Characters,
     /// OCTETS unit
     Octets,
+    /// CHAR unit, e.g. `VARCHAR2(10 CHAR)` in [Oracle]
+    ///
+    /// [Oracle]: https://docs.oracle.com/en/database/oracle/oracle-database/23/sqlrf/Data-Types.html#GUID-DF7E10FC-A461-4325-A295-3FD4D150809E
+    Char,
+    /// BYTE unit, e.g. `VARCHAR2(10 BYTE)` in [Oracle]
+    ///
+    /// [Oracle]: https://docs.oracle.com/en/database/oracle/oracle-database/23/sqlrf/Data-Types.html#GUID-DF7E10FC-A461-4325-A295-3FD4D150809E
+    Byte,
 }
 
 impl fmt::Display for CharLengthUnits {
@@ -777,6 +807,12 @@ impl fmt::Display for CharLengthUnits {
             Self::Octets => {
                 write!(f, "OCTETS")
             }
+            Self::Char => {
+                write!(f, "CHAR")
+            }
+            Self::Byte => {
+                write!(f, "BYTE")
+            }
         }
     }
 }
@@ -787,6 +823,7 @@ impl fmt::Display for CharLengthUnits {
 /// For example: Bigquery/Hive use `ARRAY<INT>` whereas snowflake uses ARRAY.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum ArrayElemTypeDef {
     /// `ARRAY`
@@ -22,6 +22,8 @@
 use alloc::vec::Vec;
 use core::fmt;
 
+#[cfg(feature = "json_schema")]
+use schemars::JsonSchema;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -36,6 +38,7 @@ use crate::ast::{display_separated, ObjectName};
 /// <https://www.postgresql.org/docs/current/sql-createrole.html>
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum RoleOption {
     BypassRLS(bool),
@@ -101,6 +104,7 @@ impl fmt::Display for RoleOption {
 /// * SET `configuration_parameter` FROM CURRENT
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum SetConfigValue {
     Default,
@@ -113,6 +117,7 @@ pub enum SetConfigValue {
 /// * RESET ALL
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum ResetConfig {
     ALL,
@@ -122,6 +127,7 @@ pub enum ResetConfig {
 /// An `ALTER ROLE` (`Statement::AlterRole`) operation
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum AlterRoleOperation {
     /// Generic
@@ -202,6 +208,7 @@ impl fmt::Display for AlterRoleOperation {
 /// A `USE` (`Statement::Use`) operation
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum Use {
     Catalog(ObjectName),   // e.g. `USE CATALOG foo.bar`
@@ -225,3 +232,27 @@ impl fmt::Display for Use {
         }
     }
 }
+
+/// A MySQL account name, `'user_name'@'host_name'`.
+///
+/// The host part is optional and defaults to `'%'` in MySQL.
+///
+/// <https://dev.mysql.com/doc/refman/8.0/en/account-names.html>
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct AccountName {
+    pub user: Ident,
+    pub host: Option<Ident>,
+}
+
+impl fmt::Display for AccountName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.user)?;
+        if let Some(host) = &self.host {
+            write!(f, "@{host}")?;
+        }
+        Ok(())
+    }
+}
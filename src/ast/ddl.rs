@@ -22,16 +22,20 @@
 use alloc::{boxed::Box, string::String, vec::Vec};
 use core::fmt::{self, Write};
 
+#[cfg(feature = "json_schema")]
+use schemars::JsonSchema;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "visitor")]
 use sqlparser_derive::{Visit, VisitMut};
 
+use crate::ast::script::ScriptStatement;
 use crate::ast::value::escape_single_quote_string;
 use crate::ast::{
-    display_comma_separated, display_separated, DataType, Expr, Ident, MySQLColumnPosition,
-    ObjectName, OrderByExpr, ProjectionSelect, SequenceOptions, SqlOption, Value,
+    display_comma_separated, display_separated, AccountName, ArgMode, DataType, DateTimeField,
+    Expr, Ident, MySQLColumnPosition, ObjectName, OrderByExpr, ProjectionSelect, SequenceOptions,
+    SqlOption, Statement, Value,
 };
 use crate::keywords::Keyword;
 use crate::tokenizer::Token;
@@ -39,10 +43,17 @@ use crate::tokenizer::Token;
 /// An `ALTER TABLE` (`Statement::AlterTable`) operation
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum AlterTableOperation {
-    /// `ADD <table_constraint>`
-    AddConstraint(TableConstraint),
+    /// `ADD <table_constraint> [NOT VALID]`
+    AddConstraint {
+        constraint: TableConstraint,
+        /// `NOT VALID`: skip checking existing rows against the constraint.
+        ///
+        /// Note: this is a PostgreSQL-specific option.
+        not_valid: bool,
+    },
     /// `ADD [COLUMN] [IF NOT EXISTS] <column_def>`
     AddColumn {
         /// `[COLUMN]`.
@@ -108,6 +119,10 @@ pub enum AlterTableOperation {
         name: Ident,
         cascade: bool,
     },
+    /// `VALIDATE CONSTRAINT <name>`
+    ///
+    /// Note: this is a PostgreSQL-specific operation.
+    ValidateConstraint { name: Ident },
     /// `DROP [ COLUMN ] [ IF EXISTS ] <column_name> [ CASCADE ]`
     DropColumn {
         column_name: Ident,
@@ -196,19 +211,25 @@ pub enum AlterTableOperation {
     },
     /// `RENAME TO <table_name>`
     RenameTable { table_name: ObjectName },
-    // CHANGE [ COLUMN ] <old_name> <new_name> <data_type> [ <options> ]
+    /// `CHANGE [ COLUMN ] <old_name> <new_name> <data_type> [ COLLATE <collation> ] [ <options> ]`
     ChangeColumn {
+        /// Whether the `COLUMN` keyword was specified
+        column_keyword: bool,
         old_name: Ident,
         new_name: Ident,
         data_type: DataType,
+        collation: Option<ObjectName>,
         options: Vec<ColumnOption>,
         /// MySQL `ALTER TABLE` only  [FIRST | AFTER column_name]
         column_position: Option<MySQLColumnPosition>,
     },
-    // CHANGE [ COLUMN ] <col_name> <data_type> [ <options> ]
+    /// `MODIFY [ COLUMN ] <col_name> <data_type> [ COLLATE <collation> ] [ <options> ]`
     ModifyColumn {
+        /// Whether the `COLUMN` keyword was specified
+        column_keyword: bool,
         col_name: Ident,
         data_type: DataType,
+        collation: Option<ObjectName>,
         options: Vec<ColumnOption>,
         /// MySQL `ALTER TABLE` only  [FIRST | AFTER column_name]
         column_position: Option<MySQLColumnPosition>,
@@ -233,6 +254,97 @@ pub enum AlterTableOperation {
     ///
     /// Note: this is PostgreSQL-specific <https://www.postgresql.org/docs/current/sql-altertable.html>
     OwnerTo { new_owner: Owner },
+    /// `RENAME { INDEX | KEY } <old_name> TO <new_name>`
+    ///
+    /// Note: this is MySQL-specific <https://dev.mysql.com/doc/refman/8.0/en/alter-table.html>
+    RenameIndex {
+        /// Whether `INDEX` or `KEY` was used
+        index_type_display: KeyOrIndexDisplay,
+        old_name: Ident,
+        new_name: Ident,
+    },
+    /// `ALGORITHM [=] { DEFAULT | INSTANT | INPLACE | COPY }`
+    ///
+    /// Note: this is MySQL-specific <https://dev.mysql.com/doc/refman/8.0/en/alter-table.html>
+    Algorithm {
+        equals: bool,
+        algorithm: AlterTableAlgorithm,
+    },
+    /// `LOCK [=] { DEFAULT | NONE | SHARED | EXCLUSIVE }`
+    ///
+    /// Note: this is MySQL-specific <https://dev.mysql.com/doc/refman/8.0/en/alter-table.html>
+    Lock { equals: bool, lock: AlterTableLock },
+    /// `ORDER BY <column> [, ...]`
+    ///
+    /// Note: this is MySQL-specific <https://dev.mysql.com/doc/refman/8.0/en/alter-table.html>
+    OrderBy { exprs: Vec<OrderByExpr> },
+    /// `ADD PARTITION (<partition_definition> [, ...])`
+    ///
+    /// Note: this is MySQL-specific <https://dev.mysql.com/doc/refman/8.0/en/alter-table-partition-operations.html>
+    AddPartitionDefinitions {
+        partitions: Vec<PartitionDefinition>,
+    },
+    /// `DROP PARTITION <partition_name> [, ...]`
+    ///
+    /// Note: this is MySQL-specific <https://dev.mysql.com/doc/refman/8.0/en/alter-table-partition-operations.html>
+    DropPartitionNames { partitions: Vec<Ident> },
+    /// `REORGANIZE PARTITION <partition_name> [, ...] INTO (<partition_definition> [, ...])`
+    ///
+    /// Note: this is MySQL-specific <https://dev.mysql.com/doc/refman/8.0/en/alter-table-partition-operations.html>
+    ReorganizePartitions {
+        partitions: Vec<Ident>,
+        into: Vec<PartitionDefinition>,
+    },
+    /// `COALESCE PARTITION <number>`
+    ///
+    /// Note: this is MySQL-specific <https://dev.mysql.com/doc/refman/8.0/en/alter-table-partition-operations.html>
+    CoalescePartition { number: Value },
+}
+
+/// The `ALGORITHM` of a MySQL `ALTER TABLE` (`AlterTableOperation::Algorithm`)
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum AlterTableAlgorithm {
+    Default,
+    Instant,
+    Inplace,
+    Copy,
+}
+
+impl fmt::Display for AlterTableAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            AlterTableAlgorithm::Default => "DEFAULT",
+            AlterTableAlgorithm::Instant => "INSTANT",
+            AlterTableAlgorithm::Inplace => "INPLACE",
+            AlterTableAlgorithm::Copy => "COPY",
+        })
+    }
+}
+
+/// The `LOCK` of a MySQL `ALTER TABLE` (`AlterTableOperation::Lock`)
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum AlterTableLock {
+    Default,
+    None,
+    Shared,
+    Exclusive,
+}
+
+impl fmt::Display for AlterTableLock {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            AlterTableLock::Default => "DEFAULT",
+            AlterTableLock::None => "NONE",
+            AlterTableLock::Shared => "SHARED",
+            AlterTableLock::Exclusive => "EXCLUSIVE",
+        })
+    }
 }
 
 /// An `ALTER Policy` (`Statement::AlterPolicy`) operation
@@ -240,6 +352,7 @@ pub enum AlterTableOperation {
 /// [PostgreSQL Documentation](https://www.postgresql.org/docs/current/sql-altertable.html)
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum AlterPolicyOperation {
     Rename {
@@ -280,6 +393,7 @@ impl fmt::Display for AlterPolicyOperation {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum Owner {
     Ident(Ident),
@@ -301,11 +415,155 @@ impl fmt::Display for Owner {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum AlterIndexOperation {
     RenameIndex { index_name: ObjectName },
 }
 
+/// An `ALTER EXTENSION` (`Statement::AlterExtension`) operation
+///
+/// [PostgreSQL](https://www.postgresql.org/docs/current/sql-alterextension.html)
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum AlterExtensionOperation {
+    /// `UPDATE [ TO new_version ]`
+    Update { version: Option<Ident> },
+    /// `SET SCHEMA new_schema`
+    SetSchema { schema: Ident },
+}
+
+/// The definition of a collation created by `Statement::CreateCollation`
+///
+/// [PostgreSQL](https://www.postgresql.org/docs/current/sql-createcollation.html)
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum CollationDefinition {
+    /// `( option = value [, ...] )`, e.g. `(provider = icu, locale = 'de-u-ks-level2')`
+    Options(Vec<SqlOption>),
+    /// `FROM existing_collation`
+    From(ObjectName),
+}
+
+/// An `ALTER COLLATION` (`Statement::AlterCollation`) operation
+///
+/// [PostgreSQL](https://www.postgresql.org/docs/current/sql-altercollation.html)
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum AlterCollationOperation {
+    /// `REFRESH VERSION`
+    RefreshVersion,
+    /// `RENAME TO new_name`
+    RenameTo { new_name: Ident },
+    /// `OWNER TO { new_owner | CURRENT_ROLE | CURRENT_USER | SESSION_USER }`
+    OwnerTo(Owner),
+    /// `SET SCHEMA new_schema`
+    SetSchema { schema: Ident },
+}
+
+/// An `ALTER STATISTICS` (`Statement::AlterStatistics`) operation
+///
+/// [PostgreSQL](https://www.postgresql.org/docs/current/sql-alterstatistics.html)
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum AlterStatisticsOperation {
+    /// `OWNER TO { new_owner | CURRENT_ROLE | CURRENT_USER | SESSION_USER }`
+    OwnerTo(Owner),
+    /// `RENAME TO new_name`
+    RenameTo { new_name: Ident },
+    /// `SET SCHEMA new_schema`
+    SetSchema { schema: Ident },
+    /// `SET STATISTICS { new_target | DEFAULT }`, where `None` is `DEFAULT`
+    SetStatistics { target: Option<Expr> },
+}
+
+/// An option of a DDL statement that is not supported by the parser, kept
+/// as the word it starts with and the raw tokens of its value. These are only
+/// parsed if [`ParserOptions::permissive_ddl`] is set.
+///
+/// [`ParserOptions::permissive_ddl`]: crate::parser::ParserOptions::permissive_ddl
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct DialectSpecificOption {
+    pub keyword: Ident,
+    pub value: Vec<Token>,
+}
+
+impl fmt::Display for DialectSpecificOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.keyword)?;
+        let mut previous: Option<&Token> = None;
+        for token in &self.value {
+            let space = !matches!(token, Token::Comma | Token::RParen)
+                && !matches!(previous, Some(Token::LParen));
+            write!(f, "{}{token}", if space { " " } else { "" })?;
+            previous = Some(token);
+        }
+        Ok(())
+    }
+}
+
+/// An `ALTER TASK` (`Statement::AlterTask`) operation
+///
+/// [Snowflake](https://docs.snowflake.com/en/sql-reference/sql/alter-task)
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum AlterTaskOperation {
+    /// `RESUME`
+    Resume,
+    /// `SUSPEND`
+    Suspend,
+    /// `ADD AFTER <task> [, ...]`
+    AddAfter(Vec<ObjectName>),
+    /// `REMOVE AFTER <task> [, ...]`
+    RemoveAfter(Vec<ObjectName>),
+    /// `SET <param> = <value> [ ... ]`
+    Set(Vec<SqlOption>),
+    /// `UNSET <param> [, ...]`
+    Unset(Vec<Ident>),
+    /// `MODIFY AS <statement>`
+    ModifyAs(Box<Statement>),
+    /// `MODIFY WHEN <condition>`
+    ModifyWhen(Expr),
+}
+
+/// The event a rule (`Statement::CreateRule`) is fired on
+///
+/// [PostgreSQL](https://www.postgresql.org/docs/current/sql-createrule.html)
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum RuleEvent {
+    Select,
+    Insert,
+    Update,
+    Delete,
+}
+
+/// Whether the commands of a rule (`Statement::CreateRule`) run in addition
+/// to the original command (`ALSO`) or replace it (`INSTEAD`)
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum RuleKind {
+    Also,
+    Instead,
+}
+
 impl fmt::Display for AlterTableOperation {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -318,7 +576,16 @@ impl fmt::Display for AlterTableOperation {
                 display_separated(new_partitions, " "),
                 ine = if *if_not_exists { " IF NOT EXISTS" } else { "" }
             ),
-            AlterTableOperation::AddConstraint(c) => write!(f, "ADD {c}"),
+            AlterTableOperation::AddConstraint {
+                constraint,
+                not_valid,
+            } => {
+                write!(f, "ADD {constraint}")?;
+                if *not_valid {
+                    write!(f, " NOT VALID")?;
+                }
+                Ok(())
+            }
             AlterTableOperation::AddColumn {
                 column_keyword,
                 if_not_exists,
@@ -409,6 +676,9 @@ impl fmt::Display for AlterTableOperation {
                 display_comma_separated(partitions),
                 ie = if *if_exists { " IF EXISTS" } else { "" }
             ),
+            AlterTableOperation::ValidateConstraint { name } => {
+                write!(f, "VALIDATE CONSTRAINT {name}")
+            }
             AlterTableOperation::DropConstraint {
                 if_exists,
                 name,
@@ -478,13 +748,22 @@ impl fmt::Display for AlterTableOperation {
                 write!(f, "RENAME TO {table_name}")
             }
             AlterTableOperation::ChangeColumn {
+                column_keyword,
                 old_name,
                 new_name,
                 data_type,
+                collation,
                 options,
                 column_position,
             } => {
-                write!(f, "CHANGE COLUMN {old_name} {new_name} {data_type}")?;
+                write!(f, "CHANGE ")?;
+                if *column_keyword {
+                    write!(f, "COLUMN ")?;
+                }
+                write!(f, "{old_name} {new_name} {data_type}")?;
+                if let Some(collation) = collation {
+                    write!(f, " COLLATE {collation}")?;
+                }
                 if !options.is_empty() {
                     write!(f, " {}", display_separated(options, " "))?;
                 }
@@ -495,12 +774,21 @@ impl fmt::Display for AlterTableOperation {
                 Ok(())
             }
             AlterTableOperation::ModifyColumn {
+                column_keyword,
                 col_name,
                 data_type,
+                collation,
                 options,
                 column_position,
             } => {
-                write!(f, "MODIFY COLUMN {col_name} {data_type}")?;
+                write!(f, "MODIFY ")?;
+                if *column_keyword {
+                    write!(f, "COLUMN ")?;
+                }
+                write!(f, "{col_name} {data_type}")?;
+                if let Some(collation) = collation {
+                    write!(f, " COLLATE {collation}")?;
+                }
                 if !options.is_empty() {
                     write!(f, " {}", display_separated(options, " "))?;
                 }
@@ -519,6 +807,37 @@ impl fmt::Display for AlterTableOperation {
             AlterTableOperation::OwnerTo { new_owner } => {
                 write!(f, "OWNER TO {new_owner}")
             }
+            AlterTableOperation::RenameIndex {
+                index_type_display,
+                old_name,
+                new_name,
+            } => write!(f, "RENAME {index_type_display} {old_name} TO {new_name}"),
+            AlterTableOperation::Algorithm { equals, algorithm } => {
+                let equals = if *equals { "=" } else { " " };
+                write!(f, "ALGORITHM{equals}{algorithm}")
+            }
+            AlterTableOperation::Lock { equals, lock } => {
+                let equals = if *equals { "=" } else { " " };
+                write!(f, "LOCK{equals}{lock}")
+            }
+            AlterTableOperation::OrderBy { exprs } => {
+                write!(f, "ORDER BY {}", display_comma_separated(exprs))
+            }
+            AlterTableOperation::AddPartitionDefinitions { partitions } => {
+                write!(f, "ADD PARTITION ({})", display_comma_separated(partitions))
+            }
+            AlterTableOperation::DropPartitionNames { partitions } => {
+                write!(f, "DROP PARTITION {}", display_comma_separated(partitions))
+            }
+            AlterTableOperation::ReorganizePartitions { partitions, into } => write!(
+                f,
+                "REORGANIZE PARTITION {} INTO ({})",
+                display_comma_separated(partitions),
+                display_comma_separated(into)
+            ),
+            AlterTableOperation::CoalescePartition { number } => {
+                write!(f, "COALESCE PARTITION {number}")
+            }
             AlterTableOperation::SetTblProperties { table_properties } => {
                 write!(
                     f,
@@ -550,6 +869,100 @@ impl fmt::Display for AlterTableOperation {
     }
 }
 
+impl fmt::Display for AlterExtensionOperation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AlterExtensionOperation::Update { version } => {
+                write!(f, "UPDATE")?;
+                if let Some(version) = version {
+                    write!(f, " TO {version}")?;
+                }
+                Ok(())
+            }
+            AlterExtensionOperation::SetSchema { schema } => write!(f, "SET SCHEMA {schema}"),
+        }
+    }
+}
+
+impl fmt::Display for CollationDefinition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CollationDefinition::Options(options) => {
+                write!(f, "({})", display_comma_separated(options))
+            }
+            CollationDefinition::From(name) => write!(f, "FROM {name}"),
+        }
+    }
+}
+
+impl fmt::Display for AlterCollationOperation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AlterCollationOperation::RefreshVersion => write!(f, "REFRESH VERSION"),
+            AlterCollationOperation::RenameTo { new_name } => write!(f, "RENAME TO {new_name}"),
+            AlterCollationOperation::OwnerTo(owner) => write!(f, "OWNER TO {owner}"),
+            AlterCollationOperation::SetSchema { schema } => write!(f, "SET SCHEMA {schema}"),
+        }
+    }
+}
+
+impl fmt::Display for AlterStatisticsOperation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AlterStatisticsOperation::OwnerTo(owner) => write!(f, "OWNER TO {owner}"),
+            AlterStatisticsOperation::RenameTo { new_name } => write!(f, "RENAME TO {new_name}"),
+            AlterStatisticsOperation::SetSchema { schema } => write!(f, "SET SCHEMA {schema}"),
+            AlterStatisticsOperation::SetStatistics { target } => match target {
+                Some(target) => write!(f, "SET STATISTICS {target}"),
+                None => write!(f, "SET STATISTICS DEFAULT"),
+            },
+        }
+    }
+}
+
+impl fmt::Display for AlterTaskOperation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AlterTaskOperation::Resume => write!(f, "RESUME"),
+            AlterTaskOperation::Suspend => write!(f, "SUSPEND"),
+            AlterTaskOperation::AddAfter(tasks) => {
+                write!(f, "ADD AFTER {}", display_comma_separated(tasks))
+            }
+            AlterTaskOperation::RemoveAfter(tasks) => {
+                write!(f, "REMOVE AFTER {}", display_comma_separated(tasks))
+            }
+            AlterTaskOperation::Set(options) => {
+                write!(f, "SET {}", display_separated(options, " "))
+            }
+            AlterTaskOperation::Unset(params) => {
+                write!(f, "UNSET {}", display_comma_separated(params))
+            }
+            AlterTaskOperation::ModifyAs(statement) => write!(f, "MODIFY AS {statement}"),
+            AlterTaskOperation::ModifyWhen(condition) => write!(f, "MODIFY WHEN {condition}"),
+        }
+    }
+}
+
+impl fmt::Display for RuleEvent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            RuleEvent::Select => "SELECT",
+            RuleEvent::Insert => "INSERT",
+            RuleEvent::Update => "UPDATE",
+            RuleEvent::Delete => "DELETE",
+        })
+    }
+}
+
+impl fmt::Display for RuleKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            RuleKind::Also => "ALSO",
+            RuleKind::Instead => "INSTEAD",
+        })
+    }
+}
+
 impl fmt::Display for AlterIndexOperation {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -563,6 +976,7 @@ impl fmt::Display for AlterIndexOperation {
 /// An `ALTER COLUMN` (`Statement::AlterTable`) operation
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum AlterColumnOperation {
     /// `SET NOT NULL`
@@ -636,6 +1050,7 @@ impl fmt::Display for AlterColumnOperation {
 /// `ALTER TABLE ADD <constraint>` statement.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum TableConstraint {
     /// MySQL [definition][1] for `UNIQUE` constraints statements:\
@@ -738,6 +1153,10 @@ pub enum TableConstraint {
         index_type: Option<IndexType>,
         /// Referred column identifier list.
         columns: Vec<Ident>,
+        /// Optional [index options][1] after the columns.
+        ///
+        /// [1]: IndexOption
+        index_options: Vec<IndexOption>,
     },
     /// MySQLs [fulltext][1] definition. Since the [`SPATIAL`][2] definition is exactly the same,
     /// and MySQL displays both the same way, it is part of this definition as well.
@@ -745,7 +1164,7 @@ pub enum TableConstraint {
     /// Supported syntax:
     ///
     /// ```markdown
-    /// {FULLTEXT | SPATIAL} [INDEX | KEY] [index_name] (key_part,...)
+    /// {FULLTEXT | SPATIAL} [INDEX | KEY] [index_name] (key_part,...) [index_option]...
     ///
     /// key_part: col_name
     /// ```
@@ -761,6 +1180,10 @@ pub enum TableConstraint {
         opt_index_name: Option<Ident>,
         /// Referred column identifier list.
         columns: Vec<Ident>,
+        /// Optional [index options][1] after the columns.
+        ///
+        /// [1]: IndexOption
+        index_options: Vec<IndexOption>,
     },
 }
 
@@ -852,6 +1275,7 @@ impl fmt::Display for TableConstraint {
                 name,
                 index_type,
                 columns,
+                index_options,
             } => {
                 write!(f, "{}", if *display_as_key { "KEY" } else { "INDEX" })?;
                 if let Some(name) = name {
@@ -861,6 +1285,9 @@ impl fmt::Display for TableConstraint {
                     write!(f, " USING {index_type}")?;
                 }
                 write!(f, " ({})", display_comma_separated(columns))?;
+                if !index_options.is_empty() {
+                    write!(f, " {}", display_separated(index_options, " "))?;
+                }
 
                 Ok(())
             }
@@ -869,6 +1296,7 @@ impl fmt::Display for TableConstraint {
                 index_type_display,
                 opt_index_name,
                 columns,
+                index_options,
             } => {
                 if *fulltext {
                     write!(f, "FULLTEXT")?;
@@ -884,6 +1312,10 @@ impl fmt::Display for TableConstraint {
 
                 write!(f, " ({})", display_comma_separated(columns))?;
 
+                if !index_options.is_empty() {
+                    write!(f, " {}", display_separated(index_options, " "))?;
+                }
+
                 Ok(())
             }
         }
@@ -899,6 +1331,7 @@ impl fmt::Display for TableConstraint {
 /// [1]: https://dev.mysql.com/doc/refman/8.0/en/create-table.html
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum KeyOrIndexDisplay {
     /// Nothing to display
@@ -947,6 +1380,7 @@ impl fmt::Display for KeyOrIndexDisplay {
 /// [3]: https://www.postgresql.org/docs/14/sql-createindex.html
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum IndexType {
     BTree,
@@ -971,10 +1405,19 @@ impl fmt::Display for IndexType {
 /// [2]: https://dev.mysql.com/doc/refman/8.3/en/create-index.html
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum IndexOption {
     Using(IndexType),
     Comment(String),
+    /// `KEY_BLOCK_SIZE [=] value`
+    KeyBlockSize(Value),
+    /// `WITH PARSER parser_name`, only valid for `FULLTEXT` indexes
+    WithParser(Ident),
+    /// `VISIBLE`
+    Visible,
+    /// `INVISIBLE`
+    Invisible,
 }
 
 impl fmt::Display for IndexOption {
@@ -982,20 +1425,29 @@ impl fmt::Display for IndexOption {
         match self {
             Self::Using(index_type) => write!(f, "USING {index_type}"),
             Self::Comment(s) => write!(f, "COMMENT '{s}'"),
+            Self::KeyBlockSize(value) => write!(f, "KEY_BLOCK_SIZE = {value}"),
+            Self::WithParser(parser) => write!(f, "WITH PARSER {parser}"),
+            Self::Visible => write!(f, "VISIBLE"),
+            Self::Invisible => write!(f, "INVISIBLE"),
         }
     }
 }
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct ProcedureParam {
+    pub mode: Option<ArgMode>,
     pub name: Ident,
     pub data_type: DataType,
 }
 
 impl fmt::Display for ProcedureParam {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(mode) = &self.mode {
+            write!(f, "{mode} ")?;
+        }
         write!(f, "{} {}", self.name, self.data_type)
     }
 }
@@ -1003,6 +1455,7 @@ impl fmt::Display for ProcedureParam {
 /// SQL column definition
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct ColumnDef {
     pub name: Ident,
@@ -1046,6 +1499,7 @@ impl fmt::Display for ColumnDef {
 /// ```
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct ViewColumnDef {
     pub name: Ident,
@@ -1084,6 +1538,7 @@ impl fmt::Display for ViewColumnDef {
 /// "column options," and we allow any column option to be named.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct ColumnOptionDef {
     pub name: Option<Ident>,
@@ -1098,6 +1553,7 @@ impl fmt::Display for ColumnOptionDef {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct IdentityProperty {
     pub seed: Expr,
@@ -1114,6 +1570,7 @@ impl fmt::Display for IdentityProperty {
 /// TABLE` statement.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum ColumnOption {
     /// `NULL`
@@ -1190,6 +1647,58 @@ pub enum ColumnOption {
     /// SQLite specific: ON CONFLICT option on column definition
     /// <https://www.sqlite.org/lang_conflict.html>
     OnConflict(Keyword),
+    /// MySQL specific: `VISIBLE`
+    /// <https://dev.mysql.com/doc/refman/8.0/en/invisible-columns.html>
+    Visible,
+    /// MySQL specific: `INVISIBLE`
+    Invisible,
+    /// MySQL specific: `COLUMN_FORMAT { FIXED | DYNAMIC | DEFAULT }`
+    /// <https://dev.mysql.com/doc/refman/8.0/en/create-table.html>
+    ColumnFormat(ColumnFormat),
+    /// MySQL specific: `STORAGE { DISK | MEMORY }`
+    Storage(ColumnStorage),
+    /// MySQL specific: `SRID <value>` of a spatial column
+    Srid(Expr),
+}
+
+/// The `COLUMN_FORMAT` of a MySQL column (`ColumnOption::ColumnFormat`)
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum ColumnFormat {
+    Fixed,
+    Dynamic,
+    Default,
+}
+
+impl fmt::Display for ColumnFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            ColumnFormat::Fixed => "FIXED",
+            ColumnFormat::Dynamic => "DYNAMIC",
+            ColumnFormat::Default => "DEFAULT",
+        })
+    }
+}
+
+/// The `STORAGE` of a MySQL NDB column (`ColumnOption::Storage`)
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum ColumnStorage {
+    Disk,
+    Memory,
+}
+
+impl fmt::Display for ColumnStorage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            ColumnStorage::Disk => "DISK",
+            ColumnStorage::Memory => "MEMORY",
+        })
+    }
 }
 
 impl fmt::Display for ColumnOption {
@@ -1302,6 +1811,11 @@ impl fmt::Display for ColumnOption {
                 write!(f, "ON CONFLICT {:?}", keyword)?;
                 Ok(())
             }
+            Visible => write!(f, "VISIBLE"),
+            Invisible => write!(f, "INVISIBLE"),
+            ColumnFormat(format) => write!(f, "COLUMN_FORMAT {format}"),
+            Storage(storage) => write!(f, "STORAGE {storage}"),
+            Srid(srid) => write!(f, "SRID {srid}"),
         }
     }
 }
@@ -1310,6 +1824,7 @@ impl fmt::Display for ColumnOption {
 /// 'ExpStored' is used for a column generated from an expression and stored.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum GeneratedAs {
     Always,
@@ -1321,6 +1836,7 @@ pub enum GeneratedAs {
 /// No modifier is typically the same as Virtual.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum GeneratedExpressionMode {
     Virtual,
@@ -1376,6 +1892,7 @@ fn display_option_spaced<T: fmt::Display>(option: &Option<T>) -> impl fmt::Displ
 /// Used in UNIQUE and foreign key constraints. The individual settings may occur in any order.
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Default, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct ConstraintCharacteristics {
     /// `[ DEFERRABLE | NOT DEFERRABLE ]`
@@ -1388,6 +1905,7 @@ pub struct ConstraintCharacteristics {
 
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum DeferrableInitial {
     /// `INITIALLY IMMEDIATE`
@@ -1455,6 +1973,7 @@ impl fmt::Display for ConstraintCharacteristics {
 /// Used in foreign key constraints in `ON UPDATE` and `ON DELETE` options.
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum ReferentialAction {
     Restrict,
@@ -1479,6 +1998,7 @@ impl fmt::Display for ReferentialAction {
 /// SQL user defined type definition
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum UserDefinedTypeRepresentation {
     Composite {
@@ -1499,6 +2019,7 @@ impl fmt::Display for UserDefinedTypeRepresentation {
 /// SQL user defined type attribute definition
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct UserDefinedTypeCompositeAttributeDef {
     pub name: Ident,
@@ -1521,6 +2042,7 @@ impl fmt::Display for UserDefinedTypeCompositeAttributeDef {
 /// [ClickHouse](https://clickhouse.com/docs/en/sql-reference/statements/optimize)
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum Partition {
     Identifier(Ident),
@@ -1544,10 +2066,63 @@ impl fmt::Display for Partition {
     }
 }
 
+/// A MySQL partition definition, as used in `ALTER TABLE ... ADD PARTITION`:
+///
+/// ```sql
+/// PARTITION <name> [ VALUES { LESS THAN { (<expr> [, ...]) | MAXVALUE } | IN (<expr> [, ...]) } ]
+/// ```
+///
+/// [MySQL](https://dev.mysql.com/doc/refman/8.0/en/alter-table-partition-operations.html)
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct PartitionDefinition {
+    pub name: Ident,
+    pub values: Option<PartitionValues>,
+}
+
+impl fmt::Display for PartitionDefinition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PARTITION {}", self.name)?;
+        if let Some(values) = &self.values {
+            write!(f, " VALUES {values}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The `VALUES` bound of a [PartitionDefinition]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum PartitionValues {
+    /// `LESS THAN (<expr> [, ...])`, used by `RANGE` partitioning
+    LessThan(Vec<Expr>),
+    /// `LESS THAN MAXVALUE`
+    LessThanMaxValue,
+    /// `IN (<expr> [, ...])`, used by `LIST` partitioning
+    In(Vec<Expr>),
+}
+
+impl fmt::Display for PartitionValues {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PartitionValues::LessThan(exprs) => {
+                write!(f, "LESS THAN ({})", display_comma_separated(exprs))
+            }
+            PartitionValues::LessThanMaxValue => write!(f, "LESS THAN MAXVALUE"),
+            PartitionValues::In(exprs) => write!(f, "IN ({})", display_comma_separated(exprs)),
+        }
+    }
+}
+
 /// DEDUPLICATE statement used in OPTIMIZE TABLE et al. such as in ClickHouse SQL
 /// [ClickHouse](https://clickhouse.com/docs/en/sql-reference/statements/optimize)
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum Deduplicate {
     All,
@@ -1569,6 +2144,7 @@ impl fmt::Display for Deduplicate {
 /// [Hive](https://cwiki.apache.org/confluence/display/Hive/LanguageManual+DDL#LanguageManualDDL-CreateTable)
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct ClusteredBy {
     pub columns: Vec<Ident>,
@@ -1589,3 +2165,293 @@ impl fmt::Display for ClusteredBy {
         write!(f, " INTO {} BUCKETS", self.num_buckets)
     }
 }
+
+/// PostgreSQL's declarative partitioning clause of `CREATE TABLE`:
+/// `PARTITION BY { RANGE | LIST | HASH } ( { column_name | ( expression ) } [, ...] )`
+///
+/// [PostgreSQL](https://www.postgresql.org/docs/current/sql-createtable.html)
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct PartitionSpec {
+    pub strategy: PartitionStrategy,
+    pub columns: Vec<Expr>,
+}
+
+impl fmt::Display for PartitionSpec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "PARTITION BY {} ({})",
+            self.strategy,
+            display_comma_separated(&self.columns)
+        )
+    }
+}
+
+/// The partitioning strategy of a [`PartitionSpec`]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum PartitionStrategy {
+    Range,
+    List,
+    Hash,
+}
+
+impl fmt::Display for PartitionStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            PartitionStrategy::Range => "RANGE",
+            PartitionStrategy::List => "LIST",
+            PartitionStrategy::Hash => "HASH",
+        })
+    }
+}
+
+/// The bound of a partition created with `CREATE TABLE ... PARTITION OF parent`
+///
+/// [PostgreSQL](https://www.postgresql.org/docs/current/sql-createtable.html)
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum ForValues {
+    /// `FOR VALUES IN ( expr [, ...] )`
+    In(Vec<Expr>),
+    /// `FOR VALUES FROM ( bound [, ...] ) TO ( bound [, ...] )`
+    From {
+        from: Vec<PartitionBoundValue>,
+        to: Vec<PartitionBoundValue>,
+    },
+    /// `FOR VALUES WITH ( MODULUS n, REMAINDER r )`
+    With { modulus: u64, remainder: u64 },
+    /// `DEFAULT`
+    Default,
+}
+
+impl fmt::Display for ForValues {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ForValues::In(values) => {
+                write!(f, "FOR VALUES IN ({})", display_comma_separated(values))
+            }
+            ForValues::From { from, to } => write!(
+                f,
+                "FOR VALUES FROM ({}) TO ({})",
+                display_comma_separated(from),
+                display_comma_separated(to)
+            ),
+            ForValues::With { modulus, remainder } => write!(
+                f,
+                "FOR VALUES WITH (MODULUS {modulus}, REMAINDER {remainder})"
+            ),
+            ForValues::Default => write!(f, "DEFAULT"),
+        }
+    }
+}
+
+/// A bound of a range partition, see [`ForValues::From`]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum PartitionBoundValue {
+    Expr(Expr),
+    MinValue,
+    MaxValue,
+}
+
+impl fmt::Display for PartitionBoundValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PartitionBoundValue::Expr(expr) => write!(f, "{expr}"),
+            PartitionBoundValue::MinValue => write!(f, "MINVALUE"),
+            PartitionBoundValue::MaxValue => write!(f, "MAXVALUE"),
+        }
+    }
+}
+
+/// `CREATE EVENT` statement (MySQL)
+///
+/// ```sql
+/// CREATE [DEFINER = user] EVENT [IF NOT EXISTS] event_name
+///     ON SCHEDULE schedule
+///     [ON COMPLETION [NOT] PRESERVE]
+///     [ENABLE | DISABLE | DISABLE ON SLAVE]
+///     [COMMENT 'string']
+///     DO event_body
+/// ```
+///
+/// <https://dev.mysql.com/doc/refman/8.0/en/create-event.html>
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct CreateEvent {
+    pub definer: Option<AccountName>,
+    pub if_not_exists: bool,
+    pub name: ObjectName,
+    pub schedule: EventSchedule,
+    /// `ON COMPLETION [NOT] PRESERVE`, `Some(true)` for `PRESERVE`
+    pub on_completion_preserve: Option<bool>,
+    pub status: Option<EventStatus>,
+    pub comment: Option<String>,
+    /// A single statement or a `BEGIN ... END` compound statement
+    pub body: Box<ScriptStatement>,
+}
+
+impl fmt::Display for CreateEvent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "CREATE ")?;
+        if let Some(definer) = &self.definer {
+            write!(f, "DEFINER = {definer} ")?;
+        }
+        write!(
+            f,
+            "EVENT {if_not_exists}{name} ON SCHEDULE {schedule}",
+            if_not_exists = if self.if_not_exists {
+                "IF NOT EXISTS "
+            } else {
+                ""
+            },
+            name = self.name,
+            schedule = self.schedule,
+        )?;
+        fmt_event_completion(f, self.on_completion_preserve)?;
+        if let Some(status) = &self.status {
+            write!(f, " {status}")?;
+        }
+        if let Some(comment) = &self.comment {
+            write!(f, " COMMENT '{}'", escape_single_quote_string(comment))?;
+        }
+        write!(f, " DO {}", self.body)
+    }
+}
+
+/// `ALTER EVENT` statement (MySQL)
+///
+/// ```sql
+/// ALTER [DEFINER = user] EVENT event_name
+///     [ON SCHEDULE schedule]
+///     [ON COMPLETION [NOT] PRESERVE]
+///     [RENAME TO new_event_name]
+///     [ENABLE | DISABLE | DISABLE ON SLAVE]
+///     [COMMENT 'string']
+///     [DO event_body]
+/// ```
+///
+/// <https://dev.mysql.com/doc/refman/8.0/en/alter-event.html>
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct AlterEvent {
+    pub definer: Option<AccountName>,
+    pub name: ObjectName,
+    pub schedule: Option<EventSchedule>,
+    /// `ON COMPLETION [NOT] PRESERVE`, `Some(true)` for `PRESERVE`
+    pub on_completion_preserve: Option<bool>,
+    pub rename_to: Option<ObjectName>,
+    pub status: Option<EventStatus>,
+    pub comment: Option<String>,
+    pub body: Option<Box<ScriptStatement>>,
+}
+
+impl fmt::Display for AlterEvent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ALTER ")?;
+        if let Some(definer) = &self.definer {
+            write!(f, "DEFINER = {definer} ")?;
+        }
+        write!(f, "EVENT {}", self.name)?;
+        if let Some(schedule) = &self.schedule {
+            write!(f, " ON SCHEDULE {schedule}")?;
+        }
+        fmt_event_completion(f, self.on_completion_preserve)?;
+        if let Some(rename_to) = &self.rename_to {
+            write!(f, " RENAME TO {rename_to}")?;
+        }
+        if let Some(status) = &self.status {
+            write!(f, " {status}")?;
+        }
+        if let Some(comment) = &self.comment {
+            write!(f, " COMMENT '{}'", escape_single_quote_string(comment))?;
+        }
+        if let Some(body) = &self.body {
+            write!(f, " DO {body}")?;
+        }
+        Ok(())
+    }
+}
+
+fn fmt_event_completion(f: &mut fmt::Formatter, preserve: Option<bool>) -> fmt::Result {
+    match preserve {
+        Some(true) => write!(f, " ON COMPLETION PRESERVE"),
+        Some(false) => write!(f, " ON COMPLETION NOT PRESERVE"),
+        None => Ok(()),
+    }
+}
+
+/// The `ON SCHEDULE` clause of a [CreateEvent] or [AlterEvent]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum EventSchedule {
+    /// `AT timestamp [+ INTERVAL interval] ...`
+    At(Expr),
+    /// `EVERY interval [STARTS timestamp] [ENDS timestamp]`
+    Every {
+        quantity: Expr,
+        unit: DateTimeField,
+        starts: Option<Expr>,
+        ends: Option<Expr>,
+    },
+}
+
+impl fmt::Display for EventSchedule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EventSchedule::At(timestamp) => write!(f, "AT {timestamp}"),
+            EventSchedule::Every {
+                quantity,
+                unit,
+                starts,
+                ends,
+            } => {
+                write!(f, "EVERY {quantity} {unit}")?;
+                if let Some(starts) = starts {
+                    write!(f, " STARTS {starts}")?;
+                }
+                if let Some(ends) = ends {
+                    write!(f, " ENDS {ends}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Whether an event is enabled, see [CreateEvent]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum EventStatus {
+    Enable,
+    Disable,
+    DisableOnSlave,
+}
+
+impl fmt::Display for EventStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EventStatus::Enable => write!(f, "ENABLE"),
+            EventStatus::Disable => write!(f, "DISABLE"),
+            EventStatus::DisableOnSlave => write!(f, "DISABLE ON SLAVE"),
+        }
+    }
+}
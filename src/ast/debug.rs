@@ -0,0 +1,745 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Human readable rendering of AST nodes, intended for debugging.
+//!
+//! The derived `Debug` output of a large statement is hard to read, even
+//! when pretty printed. [`tree`] renders any AST node as an indented tree
+//! of node names and their non-empty fields instead. The tree is built from
+//! the `Serialize` implementation of the node, so this module requires the
+//! `serde` feature.
+//!
+//! # Example
+//! ```
+//! # use sqlparser::ast::debug::tree;
+//! # use sqlparser::dialect::GenericDialect;
+//! # use sqlparser::parser::Parser;
+//! let statements = Parser::parse_sql(&GenericDialect {}, "SELECT a FROM t WHERE b = c").unwrap();
+//! let expected = "\
+//! Query
+//!   body: Select
+//!     projection:
+//!       - UnnamedExpr(Identifier(a))
+//!     from:
+//!       - TableWithJoins
+//!           relation: Table
+//!             name: t
+//!     selection: BinaryOp
+//!       left: Identifier(b)
+//!       op: Eq
+//!       right: Identifier(c)
+//!     group_by: Expressions([], [])
+//! ";
+//! assert_eq!(tree(&statements[0]), expected);
+//! ```
+//!
+//! [`dump`] renders the same tree with exactly one node per line, which is
+//! better suited for golden file tests, see its documentation for the format.
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::fmt::{self, Write};
+
+use serde::ser::{self, Serialize};
+
+use crate::ast::Ident;
+
+/// Renders `node` as an indented tree, one node or field per line.
+///
+/// Fields that carry no information (`None`, `false` and empty lists) are
+/// omitted, `Some` wrappers are elided, and identifiers and object names are
+/// printed the way they appear in SQL.
+pub fn tree<T: Serialize>(node: &T) -> String {
+    let node = Node::from_serialize(node).simplify();
+    let mut out = String::new();
+    // writing to a `String` cannot fail
+    let _ = node.write_block(&mut out, 0);
+    out
+}
+
+/// Renders `node` in a stable, line oriented format intended for golden file
+/// (snapshot) tests, where a change to the AST shows up as a small diff.
+///
+/// # Format
+///
+/// * Every node is written on its own line, indented by two spaces per
+///   level of nesting: `<label>: <node>`, where the root node has no label.
+/// * The label is the name of a struct field, the position of a tuple field
+///   (`0`, `1`, ...), and the field label followed by `[<index>]` for the
+///   elements of a list, e.g. `projection[0]`. Lists themselves don't get a
+///   line, so that every line is a node.
+/// * Structs and enum variants are written as their name, with their fields
+///   on the following lines. Literals (strings, numbers, booleans and unit
+///   variants) are written the way `Debug` writes them.
+/// * Identifiers and object names are written the way they appear in SQL.
+/// * `Some` wrappers are elided, and fields that are `None`, `false` or
+///   empty lists are omitted, so that adding an optional field to a node
+///   doesn't change the output for existing SQL.
+///
+/// The output only reflects the structure of the AST, so it changes when
+/// the AST does. Changes to the format itself are breaking changes.
+///
+/// # Example
+/// ```
+/// # use sqlparser::ast::debug::dump;
+/// # use sqlparser::dialect::GenericDialect;
+/// # use sqlparser::parser::Parser;
+/// let statements = Parser::parse_sql(&GenericDialect {}, "SELECT a, 'x' FROM s.t").unwrap();
+/// let expected = "\
+/// Query
+///   body: Select
+///     projection[0]: UnnamedExpr
+///       0: Identifier
+///         0: a
+///     projection[1]: UnnamedExpr
+///       0: Value
+///         0: SingleQuotedString
+///           0: \"x\"
+///     from[0]: TableWithJoins
+///       relation: Table
+///         name: s.t
+///     group_by: Expressions
+/// ";
+/// assert_eq!(dump(&statements[0]), expected);
+/// ```
+pub fn dump<T: Serialize>(node: &T) -> String {
+    let node = Node::from_serialize(node).simplify();
+    let mut out = String::new();
+    // writing to a `String` cannot fail
+    let _ = node.write_lines(&mut out, "", 0);
+    out
+}
+
+/// A node of the tree, as produced by [`NodeSerializer`].
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+    /// A unit variant, number, boolean or `None`, as written by `Debug`.
+    Atom(String),
+    /// A string literal.
+    Str(String),
+    /// A character literal.
+    Char(char),
+    /// A struct or struct-like enum variant: `Name { field: value, .. }`
+    Struct(&'static str, Vec<(&'static str, Node)>),
+    /// A tuple struct, tuple-like enum variant or tuple: `Name(value, ..)`
+    Tuple(&'static str, Vec<Node>),
+    /// A list of values: `[value, ..]`
+    List(Vec<Node>),
+}
+
+impl Node {
+    fn from_serialize<T: Serialize + ?Sized>(value: &T) -> Node {
+        value
+            .serialize(NodeSerializer)
+            .unwrap_or_else(|Error(message)| Node::Atom(message))
+    }
+
+    /// Removes uninformative fields and wrappers from the tree.
+    fn simplify(self) -> Node {
+        match self {
+            Node::Tuple(name, values) => {
+                let values: Vec<_> = values.into_iter().map(Node::simplify).collect();
+                match (name, values.as_slice()) {
+                    ("ObjectName", [Node::List(parts)]) => {
+                        let parts: Vec<_> = parts.iter().map(Node::inline).collect();
+                        Node::Atom(parts.join("."))
+                    }
+                    // `Query(Query { .. })` and similar wrappers of a single struct
+                    (_, [Node::Struct(inner, _)]) if *inner == name => {
+                        values.into_iter().next().unwrap()
+                    }
+                    _ => Node::Tuple(name, values),
+                }
+            }
+            Node::Struct("Ident", fields) => {
+                let mut value = None;
+                let mut quote_style = None;
+                for (name, field) in &fields {
+                    match (*name, field) {
+                        ("value", Node::Str(s)) => value = Some(s.as_str()),
+                        ("quote_style", Node::Char(c)) => quote_style = Some(*c),
+                        _ => {}
+                    }
+                }
+                match value {
+                    Some(value) => Node::Atom(display_ident(value, quote_style)),
+                    None => Node::Struct("Ident", fields),
+                }
+            }
+            Node::Struct(name, fields) => Node::Struct(
+                name,
+                fields
+                    .into_iter()
+                    .map(|(name, value)| (name, value.simplify()))
+                    .filter(|(_, value)| !value.is_empty())
+                    .collect(),
+            ),
+            Node::List(values) => Node::List(values.into_iter().map(Node::simplify).collect()),
+            node => node,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        match self {
+            Node::Atom(atom) => atom == "None" || atom == "false",
+            Node::List(values) => values.is_empty(),
+            _ => false,
+        }
+    }
+
+    /// Whether this node is rendered on a single line.
+    fn is_inline(&self) -> bool {
+        match self {
+            Node::Atom(_) | Node::Str(_) | Node::Char(_) => true,
+            Node::Tuple(_, values) => values.iter().all(Node::is_inline),
+            Node::Struct(_, fields) => fields.is_empty(),
+            Node::List(values) => values.is_empty(),
+        }
+    }
+
+    fn inline(&self) -> String {
+        match self {
+            Node::Atom(atom) => atom.clone(),
+            Node::Str(s) => format!("{s:?}"),
+            Node::Char(c) => format!("{c:?}"),
+            Node::Tuple(name, values) => {
+                let values: Vec<_> = values.iter().map(Node::inline).collect();
+                format!("{name}({})", values.join(", "))
+            }
+            Node::Struct(name, _) => name.to_string(),
+            Node::List(_) => "[]".to_string(),
+        }
+    }
+
+    /// Writes this node on a line labeled `label`, followed by its children
+    /// indented by two more spaces, see [`dump`].
+    fn write_lines(&self, out: &mut String, label: &str, indent: usize) -> fmt::Result {
+        if let Node::List(values) = self {
+            for (i, value) in values.iter().enumerate() {
+                value.write_lines(out, &format!("{label}[{i}]"), indent)?;
+            }
+            return Ok(());
+        }
+        write!(out, "{:indent$}", "")?;
+        if !label.is_empty() {
+            write!(out, "{label}: ")?;
+        }
+        match self {
+            Node::Atom(_) | Node::Str(_) | Node::Char(_) => writeln!(out, "{}", self.inline()),
+            Node::Struct(name, fields) => {
+                writeln!(out, "{name}")?;
+                for (field, value) in fields {
+                    value.write_lines(out, field, indent + 2)?;
+                }
+                Ok(())
+            }
+            Node::Tuple(name, values) => {
+                writeln!(out, "{}", if name.is_empty() { "()" } else { name })?;
+                for (i, value) in values.iter().enumerate() {
+                    if !value.is_empty() {
+                        value.write_lines(out, &i.to_string(), indent + 2)?;
+                    }
+                }
+                Ok(())
+            }
+            Node::List(_) => unreachable!("lists are written as their elements"),
+        }
+    }
+
+    /// Writes the remainder of the current line for this node, followed by
+    /// any children on subsequent lines indented by `indent`.
+    fn write_block(&self, out: &mut String, indent: usize) -> fmt::Result {
+        if self.is_inline() {
+            return writeln!(out, "{}", self.inline());
+        }
+        match self {
+            Node::Struct(name, fields) => {
+                writeln!(out, "{name}")?;
+                for (field, value) in fields {
+                    write!(out, "{:indent$}{field}:", "", indent = indent + 2)?;
+                    if value.is_inline() {
+                        writeln!(out, " {}", value.inline())?;
+                    } else if let Node::List(_) = value {
+                        writeln!(out)?;
+                        value.write_block(out, indent + 4)?;
+                    } else {
+                        write!(out, " ")?;
+                        value.write_block(out, indent + 2)?;
+                    }
+                }
+                Ok(())
+            }
+            Node::Tuple(name, values) => {
+                writeln!(out, "{name}")?;
+                for value in values {
+                    if let Node::List(_) = value {
+                        value.write_block(out, indent + 2)?;
+                    } else {
+                        write!(out, "{:indent$}", "", indent = indent + 2)?;
+                        value.write_block(out, indent + 2)?;
+                    }
+                }
+                Ok(())
+            }
+            Node::List(values) => {
+                for value in values {
+                    write!(out, "{:indent$}- ", "", indent = indent)?;
+                    value.write_block(out, indent + 2)?;
+                }
+                Ok(())
+            }
+            Node::Atom(_) | Node::Str(_) | Node::Char(_) => {
+                unreachable!("literals are always inline")
+            }
+        }
+    }
+}
+
+/// Writes an identifier the way it appears in SQL, like the `Display` of
+/// [`Ident`] but accepting any quote character.
+fn display_ident(value: &str, quote_style: Option<char>) -> String {
+    match quote_style {
+        Some('"' | '\'' | '`' | '[') | None => {
+            let mut ident = Ident::new(value);
+            ident.quote_style = quote_style;
+            ident.to_string()
+        }
+        Some(quote) => format!("{quote}{value}{quote}"),
+    }
+}
+
+/// The error of [`NodeSerializer`], only produced by hand written
+/// `Serialize` implementations.
+#[derive(Debug)]
+struct Error(String);
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl ser::StdError for Error {}
+
+impl ser::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error(msg.to_string())
+    }
+}
+
+/// Converts a `Serialize` value into a [`Node`], keeping the names of its
+/// types, variants and fields.
+struct NodeSerializer;
+
+/// The fields of a compound value, see [`NodeSerializer`].
+struct Compound {
+    name: &'static str,
+    kind: CompoundKind,
+    values: Vec<Node>,
+    fields: Vec<(&'static str, Node)>,
+}
+
+enum CompoundKind {
+    List,
+    Tuple,
+    Struct,
+}
+
+impl Compound {
+    fn new(name: &'static str, kind: CompoundKind) -> Self {
+        Compound {
+            name,
+            kind,
+            values: Vec::new(),
+            fields: Vec::new(),
+        }
+    }
+
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.values.push(value.serialize(NodeSerializer)?);
+        Ok(())
+    }
+
+    fn push_field<T: Serialize + ?Sized>(
+        &mut self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.fields.push((name, value.serialize(NodeSerializer)?));
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Node, Error> {
+        Ok(match self.kind {
+            CompoundKind::List => Node::List(self.values),
+            CompoundKind::Tuple => Node::Tuple(self.name, self.values),
+            CompoundKind::Struct => Node::Struct(self.name, self.fields),
+        })
+    }
+}
+
+impl ser::Serializer for NodeSerializer {
+    type Ok = Node;
+    type Error = Error;
+    type SerializeSeq = Compound;
+    type SerializeTuple = Compound;
+    type SerializeTupleStruct = Compound;
+    type SerializeTupleVariant = Compound;
+    type SerializeMap = Compound;
+    type SerializeStruct = Compound;
+    type SerializeStructVariant = Compound;
+
+    fn serialize_bool(self, v: bool) -> Result<Node, Error> {
+        Ok(Node::Atom(v.to_string()))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Node, Error> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Node, Error> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Node, Error> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Node, Error> {
+        Ok(Node::Atom(v.to_string()))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Node, Error> {
+        self.serialize_u64(v.into())
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Node, Error> {
+        self.serialize_u64(v.into())
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Node, Error> {
+        self.serialize_u64(v.into())
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Node, Error> {
+        Ok(Node::Atom(v.to_string()))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Node, Error> {
+        Ok(Node::Atom(format!("{v:?}")))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Node, Error> {
+        Ok(Node::Atom(format!("{v:?}")))
+    }
+
+    fn serialize_char(self, v: char) -> Result<Node, Error> {
+        Ok(Node::Char(v))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Node, Error> {
+        Ok(Node::Str(v.to_string()))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Node, Error> {
+        Ok(Node::Atom(format!("{v:?}")))
+    }
+
+    fn serialize_none(self) -> Result<Node, Error> {
+        Ok(Node::Atom("None".to_string()))
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Node, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Node, Error> {
+        Ok(Node::Atom("()".to_string()))
+    }
+
+    fn serialize_unit_struct(self, name: &'static str) -> Result<Node, Error> {
+        Ok(Node::Atom(name.to_string()))
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Node, Error> {
+        Ok(Node::Atom(variant.to_string()))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<Node, Error> {
+        Ok(Node::Tuple(name, vec![value.serialize(self)?]))
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Node, Error> {
+        Ok(Node::Tuple(variant, vec![value.serialize(self)?]))
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Compound, Error> {
+        Ok(Compound::new("", CompoundKind::List))
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Compound, Error> {
+        Ok(Compound::new("", CompoundKind::Tuple))
+    }
+
+    fn serialize_tuple_struct(self, name: &'static str, _len: usize) -> Result<Compound, Error> {
+        Ok(Compound::new(name, CompoundKind::Tuple))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Compound, Error> {
+        Ok(Compound::new(variant, CompoundKind::Tuple))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Compound, Error> {
+        Ok(Compound::new("", CompoundKind::List))
+    }
+
+    fn serialize_struct(self, name: &'static str, _len: usize) -> Result<Compound, Error> {
+        Ok(Compound::new(name, CompoundKind::Struct))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Compound, Error> {
+        Ok(Compound::new(variant, CompoundKind::Struct))
+    }
+}
+
+impl ser::SerializeSeq for Compound {
+    type Ok = Node;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Node, Error> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTuple for Compound {
+    type Ok = Node;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Node, Error> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleStruct for Compound {
+    type Ok = Node;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Node, Error> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleVariant for Compound {
+    type Ok = Node;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Node, Error> {
+        self.finish()
+    }
+}
+
+/// Map entries are written as a list of `(key, value)` tuples.
+impl ser::SerializeMap for Compound {
+    type Ok = Node;
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Error> {
+        let key = key.serialize(NodeSerializer)?;
+        self.values.push(Node::Tuple("", vec![key]));
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let value = value.serialize(NodeSerializer)?;
+        match self.values.last_mut() {
+            Some(Node::Tuple(_, entry)) => {
+                entry.push(value);
+                Ok(())
+            }
+            _ => Err(Error("map value without a key".to_string())),
+        }
+    }
+
+    fn end(self) -> Result<Node, Error> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStruct for Compound {
+    type Ok = Node;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.push_field(key, value)
+    }
+
+    fn end(self) -> Result<Node, Error> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStructVariant for Compound {
+    type Ok = Node;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.push_field(key, value)
+    }
+
+    fn end(self) -> Result<Node, Error> {
+        self.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{dump, tree};
+    use crate::ast::{Expr, Ident};
+    use crate::dialect::GenericDialect;
+    use crate::parser::Parser;
+
+    #[test]
+    fn test_tree_quoted_identifiers() {
+        let expr = Expr::CompoundIdentifier(vec![
+            Ident::with_quote('"', "my \"schema\""),
+            Ident::new("col"),
+        ]);
+        assert_eq!(
+            tree(&expr),
+            "CompoundIdentifier\n  - \"my \"\"schema\"\"\"\n  - col\n"
+        );
+    }
+
+    #[test]
+    fn test_tree_other_quote_styles() {
+        let ident = Ident {
+            value: "a b".into(),
+            quote_style: Some('$'),
+        };
+        assert_eq!(tree(&Expr::Identifier(ident)), "Identifier($a b$)\n");
+    }
+
+    #[test]
+    fn test_tree_nested_expressions() {
+        let sql = "SELECT (a + b) * c";
+        let statement = Parser::parse_sql(&GenericDialect {}, sql)
+            .unwrap()
+            .remove(0);
+        let expected = "\
+Query
+  body: Select
+    projection:
+      - UnnamedExpr
+          BinaryOp
+            left: Nested
+              BinaryOp
+                left: Identifier(a)
+                op: Plus
+                right: Identifier(b)
+            op: Multiply
+            right: Identifier(c)
+    group_by: Expressions([], [])
+";
+        assert_eq!(tree(&statement), expected);
+    }
+
+    #[test]
+    fn test_dump_one_node_per_line() {
+        let sql = "INSERT INTO t (a, b) VALUES (TRUE, NULL), (-c, 'x')";
+        let statement = Parser::parse_sql(&GenericDialect {}, sql)
+            .unwrap()
+            .remove(0);
+        let expected = "\
+Insert
+  into: true
+  table_name: t
+  columns[0]: a
+  columns[1]: b
+  source: Query
+    body: Values
+      rows[0][0]: Value
+        0: Boolean
+          0: true
+      rows[0][1]: Value
+        0: Null
+      rows[1][0]: UnaryOp
+        op: Minus
+        expr: Identifier
+          0: c
+      rows[1][1]: Value
+        0: SingleQuotedString
+          0: \"x\"
+";
+        assert_eq!(dump(&statement), expected);
+    }
+}
@@ -19,6 +19,8 @@
 use alloc::{boxed::Box, string::String, vec::Vec};
 
 use core::fmt::{self, Display};
+#[cfg(feature = "json_schema")]
+use schemars::JsonSchema;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 #[cfg(feature = "visitor")]
@@ -27,16 +29,18 @@ use sqlparser_derive::{Visit, VisitMut};
 pub use super::ddl::{ColumnDef, TableConstraint};
 
 use super::{
-    display_comma_separated, display_separated, ClusteredBy, CommentDef, Expr, FileFormat,
-    FromTable, HiveDistributionStyle, HiveFormat, HiveIOFormat, HiveRowFormat, Ident,
-    InsertAliases, MysqlInsertPriority, ObjectName, OnCommit, OnInsert, OneOrManyWithParens,
-    OrderByExpr, Query, RowAccessPolicy, SelectItem, SqlOption, SqliteOnConflict, TableEngine,
-    TableWithJoins, Tag, WrappedCollection,
+    display_comma_separated, display_separated, Assignment, ClusteredBy, CommentDef,
+    DialectSpecificOption, Expr, FileFormat, ForValues, FromTable, HiveDistributionStyle,
+    HiveFormat, HiveIOFormat, HiveRowFormat, Ident, InsertAliases, MysqlInsertPriority, ObjectName,
+    OnCommit, OnInsert, OneOrManyWithParens, OrderByExpr, PartitionSpec, Query, RowAccessPolicy,
+    SelectItem, SqlOption, SqliteOnConflict, Statement, TableEngine, TableWithJoins, Tag,
+    WrappedCollection,
 };
 
 /// CREATE INDEX statement.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct CreateIndex {
     /// index name
@@ -103,6 +107,7 @@ impl Display for CreateIndex {
 /// CREATE TABLE statement.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct CreateTable {
     pub or_replace: bool,
@@ -118,25 +123,44 @@ pub struct CreateTable {
     /// Optional schema
     pub columns: Vec<ColumnDef>,
     pub constraints: Vec<TableConstraint>,
-    pub hive_distribution: HiveDistributionStyle,
+    pub query: Option<Box<Query>>,
+    pub like: Option<ObjectName>,
+    pub clone: Option<ObjectName>,
+    pub comment: Option<CommentDef>,
+    pub on_commit: Option<OnCommit>,
+    /// Where and how the table data is stored
+    pub storage: CreateTableStorage,
+    /// How the table data is partitioned, clustered and sorted
+    pub partitioning: CreateTablePartitioning,
+    /// Clauses only supported by a single dialect
+    pub dialect_options: CreateTableDialectOptions,
+}
+
+/// The storage related clauses of a [`CreateTable`]
+#[derive(Debug, Default, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct CreateTableStorage {
     pub hive_formats: Option<HiveFormat>,
     pub table_properties: Vec<SqlOption>,
     pub with_options: Vec<SqlOption>,
     pub file_format: Option<FileFormat>,
     pub location: Option<String>,
-    pub query: Option<Box<Query>>,
-    pub without_rowid: bool,
-    pub like: Option<ObjectName>,
-    pub clone: Option<ObjectName>,
     pub engine: Option<TableEngine>,
-    pub comment: Option<CommentDef>,
     pub auto_increment_offset: Option<u32>,
     pub default_charset: Option<String>,
     pub collation: Option<String>,
-    pub on_commit: Option<OnCommit>,
-    /// ClickHouse "ON CLUSTER" clause:
-    /// <https://clickhouse.com/docs/en/sql-reference/distributed-ddl/>
-    pub on_cluster: Option<Ident>,
+}
+
+/// The partitioning and clustering clauses of a [`CreateTable`]
+#[derive(Debug, Default, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct CreateTablePartitioning {
+    /// Hive `PARTITIONED BY` or `SKEWED BY` clause
+    pub hive_distribution: HiveDistributionStyle,
     /// ClickHouse "PRIMARY KEY " clause.
     /// <https://clickhouse.com/docs/en/sql-reference/statements/create/table/>
     pub primary_key: Option<Box<Expr>>,
@@ -144,6 +168,12 @@ pub struct CreateTable {
     /// than empty (represented as ()), the latter meaning "no sorting".
     /// <https://clickhouse.com/docs/en/sql-reference/statements/create/table/>
     pub order_by: Option<OneOrManyWithParens<Expr>>,
+    /// PostgreSQL: The parent table of a partition created with `PARTITION OF`
+    pub partition_of: Option<ObjectName>,
+    /// PostgreSQL: The bound of a partition created with `PARTITION OF`
+    pub for_values: Option<ForValues>,
+    /// PostgreSQL: The `PARTITION BY { RANGE | LIST | HASH } (...)` clause of a partitioned table
+    pub partition_spec: Option<PartitionSpec>,
     /// BigQuery: A partition expression for the table.
     /// <https://cloud.google.com/bigquery/docs/reference/standard-sql/data-definition-language#partition_expression>
     pub partition_by: Option<Box<Expr>>,
@@ -153,6 +183,19 @@ pub struct CreateTable {
     /// Hive: Table clustering column list.
     /// <https://cwiki.apache.org/confluence/display/Hive/LanguageManual+DDL#LanguageManualDDL-CreateTable>
     pub clustered_by: Option<ClusteredBy>,
+}
+
+/// The dialect specific clauses of a [`CreateTable`]
+#[derive(Debug, Default, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct CreateTableDialectOptions {
+    /// SQLite "WITHOUT ROWID" clause
+    pub without_rowid: bool,
+    /// ClickHouse "ON CLUSTER" clause:
+    /// <https://clickhouse.com/docs/en/sql-reference/distributed-ddl/>
+    pub on_cluster: Option<Ident>,
     /// BigQuery: Table options list.
     /// <https://cloud.google.com/bigquery/docs/reference/standard-sql/data-definition-language#table_option_list>
     pub options: Option<Vec<SqlOption>>,
@@ -187,6 +230,15 @@ pub struct CreateTable {
     /// Snowflake "WITH TAG" clause
     /// <https://docs.snowflake.com/en/sql-reference/sql/create-table>
     pub with_tags: Option<Vec<Tag>>,
+    /// Trailing options that are not supported by the parser, see
+    /// [`ParserOptions::permissive_ddl`](crate::parser::ParserOptions::permissive_ddl)
+    pub unknown_options: Vec<DialectSpecificOption>,
+}
+
+impl From<CreateTable> for Statement {
+    fn from(create_table: CreateTable) -> Self {
+        Statement::CreateTable(create_table)
+    }
 }
 
 impl Display for CreateTable {
@@ -218,20 +270,34 @@ impl Display for CreateTable {
             volatile = if self.volatile { "VOLATILE " } else { "" },
             name = self.name,
         )?;
-        if let Some(on_cluster) = &self.on_cluster {
+        if let Some(on_cluster) = &self.dialect_options.on_cluster {
             write!(f, " ON CLUSTER {}", on_cluster)?;
         }
+        if let Some(partition_of) = &self.partitioning.partition_of {
+            write!(f, " PARTITION OF {partition_of}")?;
+        }
         if !self.columns.is_empty() || !self.constraints.is_empty() {
             write!(f, " ({}", display_comma_separated(&self.columns))?;
             if !self.columns.is_empty() && !self.constraints.is_empty() {
                 write!(f, ", ")?;
             }
             write!(f, "{})", display_comma_separated(&self.constraints))?;
-        } else if self.query.is_none() && self.like.is_none() && self.clone.is_none() {
+        } else if self.query.is_none()
+            && self.like.is_none()
+            && self.clone.is_none()
+            && self.partitioning.partition_of.is_none()
+        {
             // PostgreSQL allows `CREATE TABLE t ();`, but requires empty parens
             write!(f, " ()")?;
         }
 
+        if let Some(for_values) = &self.partitioning.for_values {
+            write!(f, " {for_values}")?;
+        }
+        if let Some(partition_spec) = &self.partitioning.partition_spec {
+            write!(f, " {partition_spec}")?;
+        }
+
         // Hive table comment should be after column definitions, please refer to:
         // [Hive](https://cwiki.apache.org/confluence/display/Hive/LanguageManual+DDL#LanguageManualDDL-CreateTable)
         if let Some(CommentDef::AfterColumnDefsWithoutEq(comment)) = &self.comment {
@@ -239,7 +305,7 @@ impl Display for CreateTable {
         }
 
         // Only for SQLite
-        if self.without_rowid {
+        if self.dialect_options.without_rowid {
             write!(f, " WITHOUT ROWID")?;
         }
 
@@ -252,7 +318,7 @@ impl Display for CreateTable {
             write!(f, " CLONE {c}")?;
         }
 
-        match &self.hive_distribution {
+        match &self.partitioning.hive_distribution {
             HiveDistributionStyle::PARTITIONED { columns } => {
                 write!(f, " PARTITIONED BY ({})", display_comma_separated(columns))?;
             }
@@ -274,7 +340,7 @@ impl Display for CreateTable {
             _ => (),
         }
 
-        if let Some(clustered_by) = &self.clustered_by {
+        if let Some(clustered_by) = &self.partitioning.clustered_by {
             write!(f, " {clustered_by}")?;
         }
 
@@ -283,7 +349,7 @@ impl Display for CreateTable {
             serde_properties,
             storage,
             location,
-        }) = &self.hive_formats
+        }) = &self.storage.hive_formats
         {
             match row_format {
                 Some(HiveRowFormat::SERDE { class }) => write!(f, " ROW FORMAT SERDE '{class}'")?,
@@ -322,22 +388,26 @@ impl Display for CreateTable {
             }
         }
         if self.external {
-            if let Some(file_format) = self.file_format {
+            if let Some(file_format) = self.storage.file_format {
                 write!(f, " STORED AS {file_format}")?;
             }
-            write!(f, " LOCATION '{}'", self.location.as_ref().unwrap())?;
+            write!(f, " LOCATION '{}'", self.storage.location.as_ref().unwrap())?;
         }
-        if !self.table_properties.is_empty() {
+        if !self.storage.table_properties.is_empty() {
             write!(
                 f,
                 " TBLPROPERTIES ({})",
-                display_comma_separated(&self.table_properties)
+                display_comma_separated(&self.storage.table_properties)
             )?;
         }
-        if !self.with_options.is_empty() {
-            write!(f, " WITH ({})", display_comma_separated(&self.with_options))?;
+        if !self.storage.with_options.is_empty() {
+            write!(
+                f,
+                " WITH ({})",
+                display_comma_separated(&self.storage.with_options)
+            )?;
         }
-        if let Some(engine) = &self.engine {
+        if let Some(engine) = &self.storage.engine {
             write!(f, " ENGINE={engine}")?;
         }
         if let Some(comment_def) = &self.comment {
@@ -353,23 +423,23 @@ impl Display for CreateTable {
             }
         }
 
-        if let Some(auto_increment_offset) = self.auto_increment_offset {
+        if let Some(auto_increment_offset) = self.storage.auto_increment_offset {
             write!(f, " AUTO_INCREMENT {auto_increment_offset}")?;
         }
-        if let Some(primary_key) = &self.primary_key {
+        if let Some(primary_key) = &self.partitioning.primary_key {
             write!(f, " PRIMARY KEY {}", primary_key)?;
         }
-        if let Some(order_by) = &self.order_by {
+        if let Some(order_by) = &self.partitioning.order_by {
             write!(f, " ORDER BY {}", order_by)?;
         }
-        if let Some(partition_by) = self.partition_by.as_ref() {
+        if let Some(partition_by) = self.partitioning.partition_by.as_ref() {
             write!(f, " PARTITION BY {partition_by}")?;
         }
-        if let Some(cluster_by) = self.cluster_by.as_ref() {
+        if let Some(cluster_by) = self.partitioning.cluster_by.as_ref() {
             write!(f, " CLUSTER BY {cluster_by}")?;
         }
 
-        if let Some(options) = self.options.as_ref() {
+        if let Some(options) = self.dialect_options.options.as_ref() {
             write!(
                 f,
                 " OPTIONS({})",
@@ -377,11 +447,11 @@ impl Display for CreateTable {
             )?;
         }
 
-        if self.copy_grants {
+        if self.dialect_options.copy_grants {
             write!(f, " COPY GRANTS")?;
         }
 
-        if let Some(is_enabled) = self.enable_schema_evolution {
+        if let Some(is_enabled) = self.dialect_options.enable_schema_evolution {
             write!(
                 f,
                 " ENABLE_SCHEMA_EVOLUTION={}",
@@ -389,7 +459,7 @@ impl Display for CreateTable {
             )?;
         }
 
-        if let Some(is_enabled) = self.change_tracking {
+        if let Some(is_enabled) = self.dialect_options.change_tracking {
             write!(
                 f,
                 " CHANGE_TRACKING={}",
@@ -397,40 +467,43 @@ impl Display for CreateTable {
             )?;
         }
 
-        if let Some(data_retention_time_in_days) = self.data_retention_time_in_days {
+        if let Some(data_retention_time_in_days) = self.dialect_options.data_retention_time_in_days
+        {
             write!(
                 f,
                 " DATA_RETENTION_TIME_IN_DAYS={data_retention_time_in_days}",
             )?;
         }
 
-        if let Some(max_data_extension_time_in_days) = self.max_data_extension_time_in_days {
+        if let Some(max_data_extension_time_in_days) =
+            self.dialect_options.max_data_extension_time_in_days
+        {
             write!(
                 f,
                 " MAX_DATA_EXTENSION_TIME_IN_DAYS={max_data_extension_time_in_days}",
             )?;
         }
 
-        if let Some(default_ddl_collation) = &self.default_ddl_collation {
+        if let Some(default_ddl_collation) = &self.dialect_options.default_ddl_collation {
             write!(f, " DEFAULT_DDL_COLLATION='{default_ddl_collation}'",)?;
         }
 
-        if let Some(with_aggregation_policy) = &self.with_aggregation_policy {
+        if let Some(with_aggregation_policy) = &self.dialect_options.with_aggregation_policy {
             write!(f, " WITH AGGREGATION POLICY {with_aggregation_policy}",)?;
         }
 
-        if let Some(row_access_policy) = &self.with_row_access_policy {
+        if let Some(row_access_policy) = &self.dialect_options.with_row_access_policy {
             write!(f, " {row_access_policy}",)?;
         }
 
-        if let Some(tag) = &self.with_tags {
+        if let Some(tag) = &self.dialect_options.with_tags {
             write!(f, " WITH TAG ({})", display_comma_separated(tag.as_slice()))?;
         }
 
-        if let Some(default_charset) = &self.default_charset {
+        if let Some(default_charset) = &self.storage.default_charset {
             write!(f, " DEFAULT CHARSET={default_charset}")?;
         }
-        if let Some(collation) = &self.collation {
+        if let Some(collation) = &self.storage.collation {
             write!(f, " COLLATE={collation}")?;
         }
 
@@ -443,12 +516,15 @@ impl Display for CreateTable {
             };
             write!(f, " {on_commit}")?;
         }
-        if self.strict {
+        if self.dialect_options.strict {
             write!(f, " STRICT")?;
         }
         if let Some(query) = &self.query {
             write!(f, " AS {query}")?;
         }
+        for option in &self.dialect_options.unknown_options {
+            write!(f, " {option}")?;
+        }
         Ok(())
     }
 }
@@ -456,6 +532,7 @@ impl Display for CreateTable {
 /// INSERT statement.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct Insert {
     /// Only for Sqlite
@@ -475,13 +552,18 @@ pub struct Insert {
     pub overwrite: bool,
     /// A SQL query that specifies what to insert
     pub source: Option<Box<Query>>,
+    /// MySQL `INSERT INTO t SET a = 1, b = 2`, which is used instead of a
+    /// column list and `source`
+    pub assignments: Vec<Assignment>,
     /// partitioned insert (Hive)
     pub partitioned: Option<Vec<Expr>>,
     /// Columns defined after PARTITION
     pub after_columns: Vec<Ident>,
     /// whether the insert has the table keyword (Hive)
     pub table: bool,
-    pub on: Option<OnInsert>,
+    /// The `ON CONFLICT` / `ON DUPLICATE KEY UPDATE` clauses, SQLite
+    /// allows more than one `ON CONFLICT` clause per statement.
+    pub on: Vec<OnInsert>,
     /// RETURNING
     pub returning: Option<Vec<SelectItem>>,
     /// Only for mysql
@@ -495,6 +577,7 @@ pub struct Insert {
 /// DELETE statement.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct Delete {
     /// Multi tables delete are supported in mysql
@@ -512,3 +595,120 @@ pub struct Delete {
     /// LIMIT (MySQL)
     pub limit: Option<Expr>,
 }
+
+/// Multi-table INSERT statement (Oracle, Snowflake).
+///
+/// ```sql
+/// INSERT [OVERWRITE] { ALL | FIRST }
+///   [ WHEN <condition> THEN ] INTO <table> [ ( <columns> ) ] [ VALUES ( <values> ) ] ...
+///   [ ELSE INTO ... ]
+/// <subquery>
+/// ```
+///
+/// See <https://docs.oracle.com/en/database/oracle/oracle-database/23/sqlrf/INSERT.html>
+/// and <https://docs.snowflake.com/en/sql-reference/sql/insert-multi-table>
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct MultiTableInsert {
+    /// OVERWRITE (Snowflake)
+    pub overwrite: bool,
+    /// `ALL` or `FIRST`
+    pub kind: MultiTableInsertKind,
+    /// Unconditional `INTO` clauses, e.g. `INSERT ALL INTO t1 INTO t2 SELECT ...`
+    pub into_clauses: Vec<MultiTableInsertIntoClause>,
+    /// Conditional `WHEN <condition> THEN INTO ...` clauses
+    pub when_clauses: Vec<MultiTableInsertWhenClause>,
+    /// `ELSE INTO ...` clauses, applied when no `WHEN` condition matched
+    pub else_clause: Option<Vec<MultiTableInsertIntoClause>>,
+    /// The query providing the rows to insert
+    pub source: Box<Query>,
+}
+
+impl Display for MultiTableInsert {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "INSERT {overwrite}{kind}",
+            overwrite = if self.overwrite { "OVERWRITE " } else { "" },
+            kind = self.kind,
+        )?;
+        if !self.into_clauses.is_empty() {
+            write!(f, " {}", display_separated(&self.into_clauses, " "))?;
+        }
+        if !self.when_clauses.is_empty() {
+            write!(f, " {}", display_separated(&self.when_clauses, " "))?;
+        }
+        if let Some(else_clause) = &self.else_clause {
+            write!(f, " ELSE {}", display_separated(else_clause, " "))?;
+        }
+        write!(f, " {}", self.source)
+    }
+}
+
+/// Whether a [MultiTableInsert] evaluates all `WHEN` clauses or stops at the
+/// first one whose condition is true.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum MultiTableInsertKind {
+    All,
+    First,
+}
+
+impl Display for MultiTableInsertKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MultiTableInsertKind::All => write!(f, "ALL"),
+            MultiTableInsertKind::First => write!(f, "FIRST"),
+        }
+    }
+}
+
+/// `INTO <table> [ ( <columns> ) ] [ VALUES ( <values> ) ]` clause of a [MultiTableInsert]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct MultiTableInsertIntoClause {
+    #[cfg_attr(feature = "visitor", visit(with = "visit_relation"))]
+    pub table_name: ObjectName,
+    pub columns: Vec<Ident>,
+    pub values: Option<Vec<Expr>>,
+}
+
+impl Display for MultiTableInsertIntoClause {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "INTO {}", self.table_name)?;
+        if !self.columns.is_empty() {
+            write!(f, " ({})", display_comma_separated(&self.columns))?;
+        }
+        if let Some(values) = &self.values {
+            write!(f, " VALUES ({})", display_comma_separated(values))?;
+        }
+        Ok(())
+    }
+}
+
+/// `WHEN <condition> THEN INTO ...` clause of a [MultiTableInsert]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct MultiTableInsertWhenClause {
+    pub condition: Expr,
+    pub into_clauses: Vec<MultiTableInsertIntoClause>,
+}
+
+impl Display for MultiTableInsertWhenClause {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "WHEN {} THEN {}",
+            self.condition,
+            display_separated(&self.into_clauses, " ")
+        )
+    }
+}
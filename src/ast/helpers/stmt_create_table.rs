@@ -18,17 +18,22 @@
 #[cfg(not(feature = "std"))]
 use alloc::{boxed::Box, format, string::String, vec, vec::Vec};
 
+#[cfg(feature = "json_schema")]
+use schemars::JsonSchema;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "visitor")]
 use sqlparser_derive::{Visit, VisitMut};
 
-use super::super::dml::CreateTable;
+use super::super::dml::{
+    CreateTable, CreateTableDialectOptions, CreateTablePartitioning, CreateTableStorage,
+};
 use crate::ast::{
-    ClusteredBy, ColumnDef, CommentDef, Expr, FileFormat, HiveDistributionStyle, HiveFormat, Ident,
-    ObjectName, OnCommit, OneOrManyWithParens, Query, RowAccessPolicy, SqlOption, Statement,
-    TableConstraint, TableEngine, Tag, WrappedCollection,
+    ClusteredBy, ColumnDef, CommentDef, DialectSpecificOption, Expr, FileFormat, ForValues,
+    HiveDistributionStyle, HiveFormat, Ident, ObjectName, OnCommit, OneOrManyWithParens,
+    PartitionSpec, Query, RowAccessPolicy, SqlOption, Statement, TableConstraint, TableEngine, Tag,
+    WrappedCollection,
 };
 use crate::parser::ParserError;
 
@@ -62,6 +67,7 @@ use crate::parser::ParserError;
 /// [1]: crate::ast::Statement::CreateTable
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct CreateTableBuilder {
     pub or_replace: bool,
@@ -93,6 +99,9 @@ pub struct CreateTableBuilder {
     pub on_cluster: Option<Ident>,
     pub primary_key: Option<Box<Expr>>,
     pub order_by: Option<OneOrManyWithParens<Expr>>,
+    pub partition_of: Option<ObjectName>,
+    pub for_values: Option<ForValues>,
+    pub partition_spec: Option<PartitionSpec>,
     pub partition_by: Option<Box<Expr>>,
     pub cluster_by: Option<WrappedCollection<Vec<Ident>>>,
     pub clustered_by: Option<ClusteredBy>,
@@ -107,6 +116,7 @@ pub struct CreateTableBuilder {
     pub with_aggregation_policy: Option<ObjectName>,
     pub with_row_access_policy: Option<RowAccessPolicy>,
     pub with_tags: Option<Vec<Tag>>,
+    pub unknown_options: Vec<DialectSpecificOption>,
 }
 
 impl CreateTableBuilder {
@@ -141,6 +151,9 @@ impl CreateTableBuilder {
             on_cluster: None,
             primary_key: None,
             order_by: None,
+            partition_of: None,
+            for_values: None,
+            partition_spec: None,
             partition_by: None,
             cluster_by: None,
             clustered_by: None,
@@ -155,6 +168,7 @@ impl CreateTableBuilder {
             with_aggregation_policy: None,
             with_row_access_policy: None,
             with_tags: None,
+            unknown_options: vec![],
         }
     }
     pub fn or_replace(mut self, or_replace: bool) -> Self {
@@ -295,6 +309,21 @@ impl CreateTableBuilder {
         self
     }
 
+    pub fn partition_of(mut self, partition_of: Option<ObjectName>) -> Self {
+        self.partition_of = partition_of;
+        self
+    }
+
+    pub fn for_values(mut self, for_values: Option<ForValues>) -> Self {
+        self.for_values = for_values;
+        self
+    }
+
+    pub fn partition_spec(mut self, partition_spec: Option<PartitionSpec>) -> Self {
+        self.partition_spec = partition_spec;
+        self
+    }
+
     pub fn partition_by(mut self, partition_by: Option<Box<Expr>>) -> Self {
         self.partition_by = partition_by;
         self
@@ -371,52 +400,187 @@ impl CreateTableBuilder {
         self
     }
 
+    pub fn unknown_options(mut self, unknown_options: Vec<DialectSpecificOption>) -> Self {
+        self.unknown_options = unknown_options;
+        self
+    }
+
     pub fn build(self) -> Statement {
-        Statement::CreateTable(CreateTable {
-            or_replace: self.or_replace,
-            temporary: self.temporary,
-            external: self.external,
-            global: self.global,
-            if_not_exists: self.if_not_exists,
-            transient: self.transient,
-            volatile: self.volatile,
-            name: self.name,
-            columns: self.columns,
-            constraints: self.constraints,
-            hive_distribution: self.hive_distribution,
-            hive_formats: self.hive_formats,
-            table_properties: self.table_properties,
-            with_options: self.with_options,
-            file_format: self.file_format,
-            location: self.location,
-            query: self.query,
-            without_rowid: self.without_rowid,
-            like: self.like,
-            clone: self.clone,
-            engine: self.engine,
-            comment: self.comment,
-            auto_increment_offset: self.auto_increment_offset,
-            default_charset: self.default_charset,
-            collation: self.collation,
-            on_commit: self.on_commit,
-            on_cluster: self.on_cluster,
-            primary_key: self.primary_key,
-            order_by: self.order_by,
-            partition_by: self.partition_by,
-            cluster_by: self.cluster_by,
-            clustered_by: self.clustered_by,
-            options: self.options,
-            strict: self.strict,
-            copy_grants: self.copy_grants,
-            enable_schema_evolution: self.enable_schema_evolution,
-            change_tracking: self.change_tracking,
-            data_retention_time_in_days: self.data_retention_time_in_days,
-            max_data_extension_time_in_days: self.max_data_extension_time_in_days,
-            default_ddl_collation: self.default_ddl_collation,
-            with_aggregation_policy: self.with_aggregation_policy,
-            with_row_access_policy: self.with_row_access_policy,
-            with_tags: self.with_tags,
-        })
+        Statement::CreateTable(self.into())
+    }
+}
+
+impl From<CreateTableBuilder> for CreateTable {
+    fn from(builder: CreateTableBuilder) -> Self {
+        CreateTable {
+            or_replace: builder.or_replace,
+            temporary: builder.temporary,
+            external: builder.external,
+            global: builder.global,
+            if_not_exists: builder.if_not_exists,
+            transient: builder.transient,
+            volatile: builder.volatile,
+            name: builder.name,
+            columns: builder.columns,
+            constraints: builder.constraints,
+            query: builder.query,
+            like: builder.like,
+            clone: builder.clone,
+            comment: builder.comment,
+            on_commit: builder.on_commit,
+            storage: CreateTableStorage {
+                hive_formats: builder.hive_formats,
+                table_properties: builder.table_properties,
+                with_options: builder.with_options,
+                file_format: builder.file_format,
+                location: builder.location,
+                engine: builder.engine,
+                auto_increment_offset: builder.auto_increment_offset,
+                default_charset: builder.default_charset,
+                collation: builder.collation,
+            },
+            partitioning: CreateTablePartitioning {
+                hive_distribution: builder.hive_distribution,
+                primary_key: builder.primary_key,
+                order_by: builder.order_by,
+                partition_of: builder.partition_of,
+                for_values: builder.for_values,
+                partition_spec: builder.partition_spec,
+                partition_by: builder.partition_by,
+                cluster_by: builder.cluster_by,
+                clustered_by: builder.clustered_by,
+            },
+            dialect_options: CreateTableDialectOptions {
+                without_rowid: builder.without_rowid,
+                on_cluster: builder.on_cluster,
+                options: builder.options,
+                strict: builder.strict,
+                copy_grants: builder.copy_grants,
+                enable_schema_evolution: builder.enable_schema_evolution,
+                change_tracking: builder.change_tracking,
+                data_retention_time_in_days: builder.data_retention_time_in_days,
+                max_data_extension_time_in_days: builder.max_data_extension_time_in_days,
+                default_ddl_collation: builder.default_ddl_collation,
+                with_aggregation_policy: builder.with_aggregation_policy,
+                with_row_access_policy: builder.with_row_access_policy,
+                with_tags: builder.with_tags,
+                unknown_options: builder.unknown_options,
+            },
+        }
+    }
+}
+
+/// Flattens a [`CreateTable`] back into a builder, which is handy when
+/// migrating code that used to work with the flat list of fields.
+impl From<CreateTable> for CreateTableBuilder {
+    fn from(create_table: CreateTable) -> Self {
+        let CreateTable {
+            or_replace,
+            temporary,
+            external,
+            global,
+            if_not_exists,
+            transient,
+            volatile,
+            name,
+            columns,
+            constraints,
+            query,
+            like,
+            clone,
+            comment,
+            on_commit,
+            storage:
+                CreateTableStorage {
+                    hive_formats,
+                    table_properties,
+                    with_options,
+                    file_format,
+                    location,
+                    engine,
+                    auto_increment_offset,
+                    default_charset,
+                    collation,
+                },
+            partitioning:
+                CreateTablePartitioning {
+                    hive_distribution,
+                    primary_key,
+                    order_by,
+                    partition_of,
+                    for_values,
+                    partition_spec,
+                    partition_by,
+                    cluster_by,
+                    clustered_by,
+                },
+            dialect_options:
+                CreateTableDialectOptions {
+                    without_rowid,
+                    on_cluster,
+                    options,
+                    strict,
+                    copy_grants,
+                    enable_schema_evolution,
+                    change_tracking,
+                    data_retention_time_in_days,
+                    max_data_extension_time_in_days,
+                    default_ddl_collation,
+                    with_aggregation_policy,
+                    with_row_access_policy,
+                    with_tags,
+                    unknown_options,
+                },
+        } = create_table;
+        Self {
+            or_replace,
+            temporary,
+            external,
+            global,
+            if_not_exists,
+            transient,
+            volatile,
+            name,
+            columns,
+            constraints,
+            query,
+            like,
+            clone,
+            comment,
+            on_commit,
+            hive_formats,
+            table_properties,
+            with_options,
+            file_format,
+            location,
+            engine,
+            auto_increment_offset,
+            default_charset,
+            collation,
+            hive_distribution,
+            primary_key,
+            order_by,
+            partition_of,
+            for_values,
+            partition_spec,
+            partition_by,
+            cluster_by,
+            clustered_by,
+            without_rowid,
+            on_cluster,
+            options,
+            strict,
+            copy_grants,
+            enable_schema_evolution,
+            change_tracking,
+            data_retention_time_in_days,
+            max_data_extension_time_in_days,
+            default_ddl_collation,
+            with_aggregation_policy,
+            with_row_access_policy,
+            with_tags,
+            unknown_options,
+        }
     }
 }
 
@@ -427,95 +591,7 @@ impl TryFrom<Statement> for CreateTableBuilder {
     // ownership.
     fn try_from(stmt: Statement) -> Result<Self, Self::Error> {
         match stmt {
-            Statement::CreateTable(CreateTable {
-                or_replace,
-                temporary,
-                external,
-                global,
-                if_not_exists,
-                transient,
-                volatile,
-                name,
-                columns,
-                constraints,
-                hive_distribution,
-                hive_formats,
-                table_properties,
-                with_options,
-                file_format,
-                location,
-                query,
-                without_rowid,
-                like,
-                clone,
-                engine,
-                comment,
-                auto_increment_offset,
-                default_charset,
-                collation,
-                on_commit,
-                on_cluster,
-                primary_key,
-                order_by,
-                partition_by,
-                cluster_by,
-                clustered_by,
-                options,
-                strict,
-                copy_grants,
-                enable_schema_evolution,
-                change_tracking,
-                data_retention_time_in_days,
-                max_data_extension_time_in_days,
-                default_ddl_collation,
-                with_aggregation_policy,
-                with_row_access_policy,
-                with_tags,
-            }) => Ok(Self {
-                or_replace,
-                temporary,
-                external,
-                global,
-                if_not_exists,
-                transient,
-                name,
-                columns,
-                constraints,
-                hive_distribution,
-                hive_formats,
-                table_properties,
-                with_options,
-                file_format,
-                location,
-                query,
-                without_rowid,
-                like,
-                clone,
-                engine,
-                comment,
-                auto_increment_offset,
-                default_charset,
-                collation,
-                on_commit,
-                on_cluster,
-                primary_key,
-                order_by,
-                partition_by,
-                cluster_by,
-                clustered_by,
-                options,
-                strict,
-                copy_grants,
-                enable_schema_evolution,
-                change_tracking,
-                data_retention_time_in_days,
-                max_data_extension_time_in_days,
-                default_ddl_collation,
-                with_aggregation_policy,
-                with_row_access_policy,
-                with_tags,
-                volatile,
-            }),
+            Statement::CreateTable(create_table) => Ok(create_table.into()),
             _ => Err(ParserError::ParserError(format!(
                 "Expected create table statement, but received: {stmt}"
             ))),
@@ -534,7 +610,7 @@ pub(crate) struct CreateTableConfiguration {
 #[cfg(test)]
 mod tests {
     use crate::ast::helpers::stmt_create_table::CreateTableBuilder;
-    use crate::ast::{Ident, ObjectName, Statement};
+    use crate::ast::{CreateTable, Ident, ObjectName, Statement, TableEngine};
     use crate::parser::ParserError;
 
     #[test]
@@ -546,6 +622,21 @@ mod tests {
         assert_eq!(builder, CreateTableBuilder::try_from(stmt).unwrap());
     }
 
+    #[test]
+    pub fn test_from_create_table() {
+        let builder = CreateTableBuilder::new(ObjectName(vec![Ident::new("table_name")]))
+            .engine(Some(TableEngine {
+                name: "MergeTree".to_string(),
+                parameters: None,
+            }))
+            .strict(true);
+
+        let create_table = CreateTable::from(builder.clone());
+        assert!(create_table.storage.engine.is_some());
+        assert!(create_table.dialect_options.strict);
+        assert_eq!(builder, CreateTableBuilder::from(create_table));
+    }
+
     #[test]
     pub fn test_from_invalid_statement() {
         let stmt = Statement::Commit { chain: false };
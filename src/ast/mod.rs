@@ -16,6 +16,8 @@
 // under the License.
 
 //! SQL Abstract Syntax Tree (AST) types
+#[cfg(all(not(feature = "std"), target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
 #[cfg(not(feature = "std"))]
 use alloc::{
     boxed::Box,
@@ -23,10 +25,14 @@ use alloc::{
     string::{String, ToString},
     vec::Vec,
 };
+#[cfg(all(feature = "std", target_has_atomic = "ptr"))]
+use std::sync::Arc;
 
 use core::fmt::{self, Display};
-use core::ops::Deref;
+use core::ops::{Deref, Range};
 
+#[cfg(feature = "json_schema")]
+use schemars::JsonSchema;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -37,59 +43,121 @@ pub use self::data_type::{
     ArrayElemTypeDef, CharLengthUnits, CharacterLength, DataType, ExactNumberInfo,
     StructBracketKind, TimezoneInfo,
 };
-pub use self::dcl::{AlterRoleOperation, ResetConfig, RoleOption, SetConfigValue, Use};
+pub use self::dcl::{
+    AccountName, AlterRoleOperation, ResetConfig, RoleOption, SetConfigValue, Use,
+};
 pub use self::ddl::{
-    AlterColumnOperation, AlterIndexOperation, AlterPolicyOperation, AlterTableOperation,
-    ClusteredBy, ColumnDef, ColumnOption, ColumnOptionDef, ConstraintCharacteristics, Deduplicate,
-    DeferrableInitial, GeneratedAs, GeneratedExpressionMode, IdentityProperty, IndexOption,
-    IndexType, KeyOrIndexDisplay, Owner, Partition, ProcedureParam, ReferentialAction,
-    TableConstraint, UserDefinedTypeCompositeAttributeDef, UserDefinedTypeRepresentation,
-    ViewColumnDef,
+    AlterCollationOperation, AlterColumnOperation, AlterEvent, AlterExtensionOperation,
+    AlterIndexOperation, AlterPolicyOperation, AlterStatisticsOperation, AlterTableAlgorithm,
+    AlterTableLock, AlterTableOperation, AlterTaskOperation, ClusteredBy, CollationDefinition,
+    ColumnDef, ColumnFormat, ColumnOption, ColumnOptionDef, ColumnStorage,
+    ConstraintCharacteristics, CreateEvent, Deduplicate, DeferrableInitial, DialectSpecificOption,
+    EventSchedule, EventStatus, ForValues, GeneratedAs, GeneratedExpressionMode, IdentityProperty,
+    IndexOption, IndexType, KeyOrIndexDisplay, Owner, Partition, PartitionBoundValue,
+    PartitionDefinition, PartitionSpec, PartitionStrategy, PartitionValues, ProcedureParam,
+    ReferentialAction, RuleEvent, RuleKind, TableConstraint, UserDefinedTypeCompositeAttributeDef,
+    UserDefinedTypeRepresentation, ViewColumnDef,
+};
+pub use self::dml::{
+    CreateIndex, CreateTable, CreateTableDialectOptions, CreateTablePartitioning,
+    CreateTableStorage, Delete, Insert, MultiTableInsert, MultiTableInsertIntoClause,
+    MultiTableInsertKind, MultiTableInsertWhenClause,
 };
-pub use self::dml::{CreateIndex, CreateTable, Delete, Insert};
 pub use self::operator::{BinaryOperator, UnaryOperator};
 pub use self::query::{
     AfterMatchSkip, ConnectBy, Cte, CteAsMaterialized, Distinct, EmptyMatchesMode,
     ExceptSelectItem, ExcludeSelectItem, ExprWithAlias, Fetch, ForClause, ForJson, ForXml,
-    FormatClause, GroupByExpr, GroupByWithModifier, IdentWithAlias, IlikeSelectItem, Interpolate,
-    InterpolateExpr, Join, JoinConstraint, JoinOperator, JsonTableColumn,
-    JsonTableColumnErrorHandling, LateralView, LockClause, LockType, MatchRecognizePattern,
-    MatchRecognizeSymbol, Measure, NamedWindowDefinition, NamedWindowExpr, NonBlock, Offset,
-    OffsetRows, OrderBy, OrderByExpr, PivotValueSource, ProjectionSelect, Query, RenameSelectItem,
-    RepetitionQuantifier, ReplaceSelectElement, ReplaceSelectItem, RowsPerMatch, Select,
-    SelectInto, SelectItem, SetExpr, SetOperator, SetQuantifier, Setting, SymbolDefinition, Table,
-    TableAlias, TableFactor, TableFunctionArgs, TableVersion, TableWithJoins, Top, TopQuantity,
-    ValueTableMode, Values, WildcardAdditionalOptions, With, WithFill,
+    ForXmlElements, ForXmlSchema, FormatClause, GroupByExpr, GroupByWithModifier, IdentWithAlias,
+    IlikeSelectItem, Interpolate, InterpolateExpr, Join, JoinConstraint, JoinOperator,
+    JsonTableColumn, JsonTableColumnErrorHandling, LateralView, LockClause, LockType,
+    MatchRecognizePattern, MatchRecognizeSymbol, Measure, NamedWindowDefinition, NamedWindowExpr,
+    NonBlock, Offset, OffsetRows, OptimizeForVariable, OrderBy, OrderByExpr, OrderByKind,
+    OrderByOptions, PivotValueSource, ProjectionSelect, Query, QueryHint, RenameSelectItem,
+    RepetitionQuantifier, ReplaceSelectElement, ReplaceSelectItem, RowsFromFunction, RowsPerMatch,
+    Select, SelectInto, SelectItem, SetExpr, SetOperator, SetQuantifier, Setting, SubsetDefinition,
+    SymbolDefinition, Table, TableAlias, TableAliasColumnDef, TableFactor, TableFunctionArgs,
+    TableHint, TableVersion, TableWithJoins, Top, TopQuantity, ValueTableMode, Values,
+    WildcardAdditionalOptions, With, WithFill,
 };
 
 pub use self::trigger::{
     TriggerEvent, TriggerExecBody, TriggerExecBodyType, TriggerObject, TriggerPeriod,
-    TriggerReferencing, TriggerReferencingType,
+    TriggerReferencing, TriggerReferencingType, TriggerRow,
 };
 
 pub use self::value::{
     escape_double_quote_string, escape_quoted_string, DateTimeField, DollarQuotedString,
-    TrimWhereField, Value,
+    QuoteDelimitedString, TrimWhereField, Value,
 };
 
+#[cfg(feature = "visitor")]
+pub use self::semantic_eq::{semantic_eq, SemanticEqOptions};
 use crate::ast::helpers::stmt_data_loading::{
     DataLoadingOptions, StageLoadSelectItem, StageParamsObject,
 };
+use crate::tokenizer::{Span, Token, Whitespace};
 #[cfg(feature = "visitor")]
 pub use visitor::*;
 
+#[cfg(feature = "arena")]
+pub mod arena;
+pub mod builder;
+#[cfg(feature = "visitor")]
+pub mod canonicalize;
+#[cfg(feature = "visitor")]
+pub mod conformance;
 mod data_type;
 mod dcl;
 mod ddl;
+#[cfg(feature = "serde")]
+pub mod debug;
 mod dml;
 pub mod helpers;
 mod operator;
+#[cfg(feature = "visitor")]
+pub mod parenthesize;
+#[cfg(feature = "visitor")]
+pub mod placeholders;
 mod query;
+pub mod script;
+#[cfg(feature = "visitor")]
+mod semantic_eq;
 mod trigger;
 mod value;
 
 #[cfg(feature = "visitor")]
 mod visitor;
+#[cfg(feature = "visitor")]
+pub mod writer;
+
+/// Owning pointer to a [`Query`] nested in the AST, used by
+/// [`Statement::Query`], [`Expr::Subquery`], [`Expr::ArraySubquery`],
+/// [`Expr::InSubquery`], [`Expr::Exists`] and [`TableFactor::Derived`].
+///
+/// It is an [`Arc`] on targets with atomic pointers, so that cloning a
+/// statement or expression shares these queries instead of copying them, and
+/// a [`Box`] on targets without them. Both are created with `QueryRef::new`
+/// and deref to [`Query`]. A mutable visitor clones a shared query before
+/// visiting it.
+///
+/// The other queries of the AST, e.g. the source of an `INSERT` or the query
+/// of a `CREATE VIEW`, are still a `Box<Query>` and copied when cloned.
+#[cfg(target_has_atomic = "ptr")]
+pub type QueryRef = Arc<Query>;
+
+/// Owning pointer to a [`Query`] nested in the AST, used by
+/// [`Statement::Query`], [`Expr::Subquery`], [`Expr::ArraySubquery`],
+/// [`Expr::InSubquery`], [`Expr::Exists`] and [`TableFactor::Derived`].
+///
+/// It is an `Arc` on targets with atomic pointers, so that cloning a
+/// statement or expression shares these queries instead of copying them, and
+/// a [`Box`] on targets without them. Both are created with `QueryRef::new`
+/// and deref to [`Query`].
+///
+/// The other queries of the AST, e.g. the source of an `INSERT` or the query
+/// of a `CREATE VIEW`, are still a `Box<Query>` and copied when cloned.
+#[cfg(not(target_has_atomic = "ptr"))]
+pub type QueryRef = Box<Query>;
 
 pub struct DisplaySeparated<'a, T>
 where
@@ -128,13 +196,26 @@ where
     DisplaySeparated { slice, sep: ", " }
 }
 
+/// The string type used to store [`Ident::value`].
+///
+/// This is a [`compact_str::CompactString`], which stores short identifiers
+/// inline without a heap allocation. It derefs to `str`, and converts from and
+/// into [`String`].
+pub type IdentValue = compact_str::CompactString;
+
 /// An identifier, decomposed into its value or character data and the quote style.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(
+    feature = "visitor",
+    derive(Visit, VisitMut),
+    visit(with = "visit_ident")
+)]
 pub struct Ident {
     /// The value of the identifier without quotes.
-    pub value: String,
+    #[cfg_attr(feature = "json_schema", schemars(with = "String"))]
+    pub value: IdentValue,
     /// The starting quote if any. Valid quote characters are the single quote,
     /// double quote, backtick, and opening square bracket.
     pub quote_style: Option<char>,
@@ -144,7 +225,7 @@ impl Ident {
     /// Create a new identifier with the given value and no quotes.
     pub fn new<S>(value: S) -> Self
     where
-        S: Into<String>,
+        S: Into<IdentValue>,
     {
         Ident {
             value: value.into(),
@@ -156,7 +237,7 @@ impl Ident {
     /// panics if the given quote is not a valid quote character.
     pub fn with_quote<S>(quote: char, value: S) -> Self
     where
-        S: Into<String>,
+        S: Into<IdentValue>,
     {
         assert!(quote == '\'' || quote == '"' || quote == '`' || quote == '[');
         Ident {
@@ -169,7 +250,7 @@ impl Ident {
 impl From<&str> for Ident {
     fn from(value: &str) -> Self {
         Ident {
-            value: value.to_string(),
+            value: value.into(),
             quote_style: None,
         }
     }
@@ -192,6 +273,7 @@ impl fmt::Display for Ident {
 /// A name of a table, view, custom type, etc., possibly multi-part, i.e. db.schema.obj
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct ObjectName(pub Vec<Ident>);
 
@@ -205,6 +287,7 @@ impl fmt::Display for ObjectName {
 /// `ARRAY[..]`, or `[..]`
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct Array {
     /// The list of expressions between brackets
@@ -235,6 +318,7 @@ impl fmt::Display for Array {
 /// so the user will have to reject intervals like `HOUR TO YEAR`.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct Interval {
     pub value: Box<Expr>,
@@ -294,6 +378,7 @@ impl fmt::Display for Interval {
 /// [bigquery]: https://cloud.google.com/bigquery/docs/reference/standard-sql/data-types#struct_type
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct StructField {
     pub field_name: Option<Ident>,
@@ -315,6 +400,7 @@ impl fmt::Display for StructField {
 /// [duckdb]: https://duckdb.org/docs/sql/data_types/union.html
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct UnionField {
     pub field_name: Ident,
@@ -332,6 +418,7 @@ impl fmt::Display for UnionField {
 /// [duckdb]: https://duckdb.org/docs/sql/data_types/struct#creating-structs
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct DictionaryField {
     pub key: Ident,
@@ -347,6 +434,7 @@ impl fmt::Display for DictionaryField {
 /// Represents a Map expression.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct Map {
     pub entries: Vec<MapEntry>,
@@ -363,6 +451,7 @@ impl Display for Map {
 /// [duckdb]: https://duckdb.org/docs/sql/data_types/map.html#creating-maps
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct MapEntry {
     pub key: Box<Expr>,
@@ -379,6 +468,7 @@ impl fmt::Display for MapEntry {
 /// BigQuery: <https://cloud.google.com/bigquery/docs/reference/standard-sql/format-elements#formatting_syntax>
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum CastFormat {
     Value(Value),
@@ -388,6 +478,7 @@ pub enum CastFormat {
 /// Represents the syntax/style used in a map access.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum MapAccessSyntax {
     /// Access using bracket notation. `mymap[mykey]`
@@ -404,6 +495,7 @@ pub enum MapAccessSyntax {
 /// ```
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct MapAccessKey {
     pub key: Expr,
@@ -422,6 +514,7 @@ impl fmt::Display for MapAccessKey {
 /// An element of a JSON path.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum JsonPathElem {
     /// Accesses an object field using dot notation, e.g. `obj:foo.bar.baz`.
@@ -441,6 +534,7 @@ pub enum JsonPathElem {
 /// See <https://docs.databricks.com/en/sql/language-manual/sql-ref-json-path-expression.html>.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct JsonPath {
     pub path: Vec<JsonPathElem>,
@@ -475,6 +569,7 @@ impl fmt::Display for JsonPath {
 /// The syntax used for in a cast expression.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum CastKind {
     /// The standard SQL cast syntax, e.g. `CAST(<expr> as <datatype>)`
@@ -500,6 +595,7 @@ pub enum CastKind {
 /// See <https://docs.snowflake.com/en/sql-reference/functions/extract>
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum ExtractSyntax {
     /// `EXTRACT( <date_or_time_part> FROM <date_or_time_expr> )`
@@ -518,6 +614,7 @@ pub enum ExtractSyntax {
 /// `CEIL/FLOOR(<expr>)`.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum CeilFloorKind {
     /// `CEIL( <expr> TO <DateTimeField>)`
@@ -533,6 +630,7 @@ pub enum CeilFloorKind {
 /// inappropriate type, like `WHERE 1` or `SELECT 1=1`, as necessary.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(
     feature = "visitor",
     derive(Visit, VisitMut),
@@ -588,7 +686,7 @@ pub enum Expr {
     /// `[ NOT ] IN (SELECT ...)`
     InSubquery {
         expr: Box<Expr>,
-        subquery: Box<Query>,
+        subquery: QueryRef,
         negated: bool,
     },
     /// `[ NOT ] IN UNNEST(array_expression)`
@@ -668,6 +766,9 @@ pub enum Expr {
     },
     /// CONVERT a value to a different data type or character encoding. e.g. `CONVERT(foo USING utf8mb4)`
     Convert {
+        /// Whether this is `TRY_CONVERT`, which returns NULL instead of
+        /// failing (MSSQL)
+        is_try: bool,
         /// The expression to convert
         expr: Box<Expr>,
         /// The target data type
@@ -681,6 +782,20 @@ pub enum Expr {
         /// [MSSQL]: https://learn.microsoft.com/en-us/sql/t-sql/functions/cast-and-convert-transact-sql?view=sql-server-ver16#style
         styles: Vec<Expr>,
     },
+    /// `PARSE` a string to a date/time or number type, e.g.
+    /// `PARSE('2024-01-02' AS DATE USING 'en-US')`
+    ///
+    /// [MSSQL]: https://learn.microsoft.com/en-us/sql/t-sql/functions/parse-transact-sql
+    Parse {
+        /// Whether this is `TRY_PARSE`, which returns NULL instead of failing
+        is_try: bool,
+        /// The string to parse
+        expr: Box<Expr>,
+        /// The target data type
+        data_type: DataType,
+        /// The culture used to interpret the string, e.g. `'en-US'`
+        culture: Option<Box<Expr>>,
+    },
     /// `CAST` an expression to a different data type e.g. `CAST(foo AS VARCHAR(123))`
     Cast {
         kind: CastKind,
@@ -817,12 +932,15 @@ pub enum Expr {
     /// An exists expression `[ NOT ] EXISTS(SELECT ...)`, used in expressions like
     /// `WHERE [ NOT ] EXISTS (SELECT ...)`.
     Exists {
-        subquery: Box<Query>,
+        subquery: QueryRef,
         negated: bool,
     },
     /// A parenthesized subquery `(SELECT ...)`, used in expression like
     /// `SELECT (subquery) AS x` or `WHERE (subquery) = x`
-    Subquery(Box<Query>),
+    ///
+    /// The query is reference counted where the target supports it, so that
+    /// cloning the expression is cheap.
+    Subquery(QueryRef),
     /// The `GROUPING SETS` expr.
     GroupingSets(Vec<Vec<Expr>>),
     /// The `CUBE` expr.
@@ -831,6 +949,12 @@ pub enum Expr {
     Rollup(Vec<Vec<Expr>>),
     /// ROW / TUPLE a single value, such as `SELECT (1, 2)`
     Tuple(Vec<Expr>),
+    /// A row constructor with the explicit `ROW` keyword, e.g. `ROW(1, 'a')`
+    /// or `ROW()`
+    Row(Vec<Expr>),
+    /// The `DEFAULT` placeholder for the default value of a column, e.g. in
+    /// `INSERT INTO t (a, b) VALUES (DEFAULT, 1)` or `UPDATE t SET a = DEFAULT`
+    Default,
     /// `BigQuery` specific `Struct` literal expression [1]
     /// Syntax:
     /// ```sql
@@ -877,6 +1001,9 @@ pub enum Expr {
     },
     /// An array expression e.g. `ARRAY[1, 2]`
     Array(Array),
+    /// An array built from the rows of a subquery, e.g.
+    /// `ARRAY(SELECT id FROM t)`
+    ArraySubquery(QueryRef),
     /// An interval expression e.g. `INTERVAL '1' YEAR`
     Interval(Interval),
     /// `MySQL` specific text search function [(1)].
@@ -918,6 +1045,16 @@ pub enum Expr {
     OuterJoin(Box<Expr>),
     /// A reference to the prior level in a CONNECT BY clause.
     Prior(Box<Expr>),
+    /// A column of the row that fired a trigger, `NEW.column` or
+    /// `OLD.column`, in the condition or body of a row-level trigger.
+    ///
+    /// Only parsed for dialects that
+    /// [support it](crate::dialect::Dialect::supports_trigger_row_references),
+    /// elsewhere such references are compound identifiers.
+    TriggerRowColumn {
+        row: TriggerRow,
+        column: Ident,
+    },
     /// A lambda function.
     ///
     /// Syntax:
@@ -932,6 +1069,7 @@ pub enum Expr {
 /// The contents inside the `[` and `]` in a subscript expression.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum Subscript {
     /// Accesses the element of the array at the given index.
@@ -994,6 +1132,7 @@ impl fmt::Display for Subscript {
 /// A lambda function.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct LambdaFunction {
     /// The parameters to the lambda function.
@@ -1032,6 +1171,7 @@ impl fmt::Display for LambdaFunction {
 /// ```
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OneOrManyWithParens<T> {
     /// A single `T`, unparenthesized.
@@ -1370,13 +1510,14 @@ impl fmt::Display for Expr {
                 }
             }
             Expr::Convert {
+                is_try,
                 expr,
                 target_before_value,
                 data_type,
                 charset,
                 styles,
             } => {
-                write!(f, "CONVERT(")?;
+                write!(f, "{}CONVERT(", if *is_try { "TRY_" } else { "" })?;
                 if let Some(data_type) = data_type {
                     if let Some(charset) = charset {
                         write!(f, "{expr}, {data_type} CHARACTER SET {charset}")
@@ -1395,6 +1536,19 @@ impl fmt::Display for Expr {
                 }
                 write!(f, ")")
             }
+            Expr::Parse {
+                is_try,
+                expr,
+                data_type,
+                culture,
+            } => {
+                let prefix = if *is_try { "TRY_" } else { "" };
+                write!(f, "{prefix}PARSE({expr} AS {data_type}")?;
+                if let Some(culture) = culture {
+                    write!(f, " USING {culture}")?;
+                }
+                write!(f, ")")
+            }
             Expr::Cast {
                 kind,
                 expr,
@@ -1588,6 +1742,10 @@ impl fmt::Display for Expr {
             Expr::Tuple(exprs) => {
                 write!(f, "({})", display_comma_separated(exprs))
             }
+            Expr::Row(exprs) => {
+                write!(f, "ROW({})", display_comma_separated(exprs))
+            }
+            Expr::Default => write!(f, "DEFAULT"),
             Expr::Struct { values, fields } => {
                 if !fields.is_empty() {
                     write!(
@@ -1618,6 +1776,9 @@ impl fmt::Display for Expr {
             Expr::Array(set) => {
                 write!(f, "{set}")
             }
+            Expr::ArraySubquery(query) => {
+                write!(f, "ARRAY({query})")
+            }
             Expr::JsonAccess { value, path } => {
                 write!(f, "{value}{path}")
             }
@@ -1652,6 +1813,7 @@ impl fmt::Display for Expr {
                 write!(f, "{expr} (+)")
             }
             Expr::Prior(expr) => write!(f, "PRIOR {expr}"),
+            Expr::TriggerRowColumn { row, column } => write!(f, "{row}.{column}"),
             Expr::Lambda(lambda) => write!(f, "{lambda}"),
         }
     }
@@ -1659,6 +1821,7 @@ impl fmt::Display for Expr {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum WindowType {
     WindowSpec(WindowSpec),
@@ -1677,6 +1840,7 @@ impl Display for WindowType {
 /// A window specification (i.e. `OVER ([window_name] PARTITION BY .. ORDER BY .. etc.)`)
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct WindowSpec {
     /// Optional window name.
@@ -1727,6 +1891,9 @@ impl fmt::Display for WindowSpec {
             } else {
                 write!(f, "{} {}", window_frame.units, window_frame.start_bound)?;
             }
+            if let Some(exclude) = &window_frame.exclude {
+                write!(f, " EXCLUDE {exclude}")?;
+            }
         }
         Ok(())
     }
@@ -1739,6 +1906,7 @@ impl fmt::Display for WindowSpec {
 /// reject invalid bounds like `ROWS UNBOUNDED FOLLOWING` before execution.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct WindowFrame {
     pub units: WindowFrameUnits,
@@ -1747,7 +1915,8 @@ pub struct WindowFrame {
     /// indicates the shorthand form (e.g. `ROWS 1 PRECEDING`), which must
     /// behave the same as `end_bound = WindowFrameBound::CurrentRow`.
     pub end_bound: Option<WindowFrameBound>,
-    // TBD: EXCLUDE
+    /// `EXCLUDE { CURRENT ROW | GROUP | TIES | NO OTHERS }`
+    pub exclude: Option<WindowFrameExclusion>,
 }
 
 impl Default for WindowFrame {
@@ -1759,12 +1928,14 @@ impl Default for WindowFrame {
             units: WindowFrameUnits::Range,
             start_bound: WindowFrameBound::Preceding(None),
             end_bound: None,
+            exclude: None,
         }
     }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum WindowFrameUnits {
     Rows,
@@ -1782,11 +1953,41 @@ impl fmt::Display for WindowFrameUnits {
     }
 }
 
+/// The rows excluded from a window frame, e.g. `EXCLUDE CURRENT ROW`.
+///
+/// See [PostgreSQL](https://www.postgresql.org/docs/current/sql-expressions.html#SYNTAX-WINDOW-FUNCTIONS)
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum WindowFrameExclusion {
+    /// `EXCLUDE CURRENT ROW`
+    CurrentRow,
+    /// `EXCLUDE GROUP`, the current row and its peers
+    Group,
+    /// `EXCLUDE TIES`, the peers of the current row but not the row itself
+    Ties,
+    /// `EXCLUDE NO OTHERS`, the default
+    NoOthers,
+}
+
+impl fmt::Display for WindowFrameExclusion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            WindowFrameExclusion::CurrentRow => "CURRENT ROW",
+            WindowFrameExclusion::Group => "GROUP",
+            WindowFrameExclusion::Ties => "TIES",
+            WindowFrameExclusion::NoOthers => "NO OTHERS",
+        })
+    }
+}
+
 /// Specifies Ignore / Respect NULL within window functions.
 /// For example
 /// `FIRST_VALUE(column2) IGNORE NULLS OVER (PARTITION BY column1)`
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum NullTreatment {
     IgnoreNulls,
@@ -1805,6 +2006,7 @@ impl fmt::Display for NullTreatment {
 /// Specifies [WindowFrame]'s `start_bound` and `end_bound`
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum WindowFrameBound {
     /// `CURRENT ROW`
@@ -1829,6 +2031,7 @@ impl fmt::Display for WindowFrameBound {
 
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum AddDropSync {
     ADD,
@@ -1848,11 +2051,14 @@ impl fmt::Display for AddDropSync {
 
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum ShowCreateObject {
+    Database,
     Event,
     Function,
     Procedure,
+    Schema,
     Table,
     Trigger,
     View,
@@ -1861,9 +2067,11 @@ pub enum ShowCreateObject {
 impl fmt::Display for ShowCreateObject {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
+            ShowCreateObject::Database => f.write_str("DATABASE"),
             ShowCreateObject::Event => f.write_str("EVENT"),
             ShowCreateObject::Function => f.write_str("FUNCTION"),
             ShowCreateObject::Procedure => f.write_str("PROCEDURE"),
+            ShowCreateObject::Schema => f.write_str("SCHEMA"),
             ShowCreateObject::Table => f.write_str("TABLE"),
             ShowCreateObject::Trigger => f.write_str("TRIGGER"),
             ShowCreateObject::View => f.write_str("VIEW"),
@@ -1871,8 +2079,94 @@ impl fmt::Display for ShowCreateObject {
     }
 }
 
+/// The kind of objects listed by [`Statement::ShowObjects`]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum ShowObjectType {
+    Databases,
+    Schemas,
+    Objects,
+    Tables,
+    ExternalTables,
+    Views,
+    MaterializedViews,
+    Columns,
+    UserFunctions,
+    Procedures,
+    Sequences,
+    Stages,
+    Tasks,
+    Warehouses,
+    Users,
+    Roles,
+}
+
+impl fmt::Display for ShowObjectType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            ShowObjectType::Databases => "DATABASES",
+            ShowObjectType::Schemas => "SCHEMAS",
+            ShowObjectType::Objects => "OBJECTS",
+            ShowObjectType::Tables => "TABLES",
+            ShowObjectType::ExternalTables => "EXTERNAL TABLES",
+            ShowObjectType::Views => "VIEWS",
+            ShowObjectType::MaterializedViews => "MATERIALIZED VIEWS",
+            ShowObjectType::Columns => "COLUMNS",
+            ShowObjectType::UserFunctions => "USER FUNCTIONS",
+            ShowObjectType::Procedures => "PROCEDURES",
+            ShowObjectType::Sequences => "SEQUENCES",
+            ShowObjectType::Stages => "STAGES",
+            ShowObjectType::Tasks => "TASKS",
+            ShowObjectType::Warehouses => "WAREHOUSES",
+            ShowObjectType::Users => "USERS",
+            ShowObjectType::Roles => "ROLES",
+        })
+    }
+}
+
+/// The `IN` clause of [`Statement::ShowObjects`]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum ShowObjectsScope {
+    /// `IN ACCOUNT`
+    Account,
+    /// `IN DATABASE [ <name> ]`, the current database if no name is given
+    Database(Option<ObjectName>),
+    /// `IN SCHEMA [ <name> ]`, the current schema if no name is given
+    Schema(Option<ObjectName>),
+    /// `IN TABLE [ <name> ]`
+    Table(Option<ObjectName>),
+    /// `IN VIEW [ <name> ]`
+    View(Option<ObjectName>),
+    /// `IN <name>`, a database or schema name
+    Name(ObjectName),
+}
+
+impl fmt::Display for ShowObjectsScope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (keyword, name) = match self {
+            ShowObjectsScope::Account => return f.write_str("ACCOUNT"),
+            ShowObjectsScope::Database(name) => ("DATABASE", name),
+            ShowObjectsScope::Schema(name) => ("SCHEMA", name),
+            ShowObjectsScope::Table(name) => ("TABLE", name),
+            ShowObjectsScope::View(name) => ("VIEW", name),
+            ShowObjectsScope::Name(name) => return write!(f, "{name}"),
+        };
+        f.write_str(keyword)?;
+        if let Some(name) = name {
+            write!(f, " {name}")?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum CommentObject {
     Column,
@@ -1892,6 +2186,7 @@ impl fmt::Display for CommentObject {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum Password {
     Password(Expr),
@@ -1907,6 +2202,7 @@ pub enum Password {
 /// ```
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum DeclareAssignment {
     /// Plain expression specified.
@@ -1965,6 +2261,7 @@ impl fmt::Display for DeclareAssignment {
 /// Represents the type of a `DECLARE` statement.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum DeclareType {
     /// Cursor variable type. e.g. [Snowflake] [Postgres]
@@ -2022,6 +2319,7 @@ impl fmt::Display for DeclareType {
 /// [BigQuery]: https://cloud.google.com/bigquery/docs/reference/standard-sql/procedural-language#declare
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct Declare {
     /// The name(s) being declared.
@@ -2117,6 +2415,7 @@ impl fmt::Display for Declare {
 /// Sql options of a `CREATE TABLE` statement.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum CreateTableOptions {
     None,
@@ -2163,6 +2462,7 @@ impl fmt::Display for CreateTableOptions {
 /// ```
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum FromTable {
     /// An explicit `FROM` keyword was specified.
@@ -2179,6 +2479,7 @@ pub enum FromTable {
 /// [PostgreSQL](https://www.postgresql.org/docs/current/sql-createpolicy.html)
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum CreatePolicyType {
     Permissive,
@@ -2192,6 +2493,7 @@ pub enum CreatePolicyType {
 /// [PostgreSQL](https://www.postgresql.org/docs/current/sql-createpolicy.html)
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum CreatePolicyCommand {
     All,
@@ -2205,6 +2507,7 @@ pub enum CreatePolicyCommand {
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(
     feature = "visitor",
     derive(Visit, VisitMut),
@@ -2262,12 +2565,34 @@ pub enum Statement {
     /// ```sql
     /// SELECT
     /// ```
-    Query(Box<Query>),
+    ///
+    /// The query is reference counted where the target supports it, so that
+    /// cloning the statement is cheap, e.g. when handing out parsed queries
+    /// from a cache.
+    Query(QueryRef),
     /// ```sql
     /// INSERT
     /// ```
     Insert(Insert),
     /// ```sql
+    /// INSERT { ALL | FIRST } [ WHEN ... THEN ] INTO ... <subquery>
+    /// ```
+    ///
+    /// Multi-table insert (Oracle, Snowflake)
+    MultiTableInsert(MultiTableInsert),
+    /// ```sql
+    /// CREATE EVENT
+    /// ```
+    ///
+    /// See [CreateEvent] (MySQL)
+    CreateEvent(CreateEvent),
+    /// ```sql
+    /// ALTER EVENT
+    /// ```
+    ///
+    /// See [AlterEvent] (MySQL)
+    AlterEvent(AlterEvent),
+    /// ```sql
     /// INSTALL
     /// ```
     Install {
@@ -2346,8 +2671,8 @@ pub enum Statement {
         table: TableWithJoins,
         /// Column assignments
         assignments: Vec<Assignment>,
-        /// Table which provide value to be set
-        from: Option<TableWithJoins>,
+        /// Tables which provide values to be set, e.g. `FROM t1, t2 JOIN t3 ON ...`
+        from: Vec<TableWithJoins>,
         /// WHERE
         selection: Option<Expr>,
         /// RETURNING
@@ -2381,6 +2706,18 @@ pub enum Statement {
         /// if not None, has Clickhouse `TO` clause, specify the table into which to insert results
         /// <https://clickhouse.com/docs/en/sql-reference/statements/create/view#materialized-view>
         to: Option<ObjectName>,
+        /// Clickhouse `ENGINE = ...` clause, the storage of a materialized view without `TO` table
+        engine: Option<ViewEngine>,
+        /// if true, has Clickhouse `POPULATE` clause, i.e. the view is filled with the existing data
+        populate: bool,
+        /// Postgres `USING method` clause, the table access method of a materialized view
+        /// <https://www.postgresql.org/docs/current/sql-creatematerializedview.html>
+        using: Option<Ident>,
+        /// Postgres `WITH [ NO ] DATA` clause, `Some(false)` for `WITH NO DATA`
+        with_data: Option<bool>,
+        /// Trailing options that are not supported by the parser, see
+        /// [`ParserOptions::permissive_ddl`](crate::parser::ParserOptions::permissive_ddl)
+        unknown_options: Vec<DialectSpecificOption>,
     },
     /// ```sql
     /// CREATE TABLE
@@ -2469,8 +2806,18 @@ pub enum Statement {
         /// For example: `ALTER TABLE table_name ON CLUSTER cluster_name ADD COLUMN c UInt32`
         /// [ClickHouse](https://clickhouse.com/docs/en/sql-reference/statements/alter/update)
         on_cluster: Option<Ident>,
+        /// Trailing options that are not supported by the parser, see
+        /// [`ParserOptions::permissive_ddl`](crate::parser::ParserOptions::permissive_ddl)
+        unknown_options: Vec<DialectSpecificOption>,
     },
     /// ```sql
+    /// RENAME TABLE <old_name> TO <new_name> [, <old_name> TO <new_name> ...]
+    /// ```
+    ///
+    /// Note: this is a MySQL-specific statement, see
+    /// <https://dev.mysql.com/doc/refman/8.0/en/rename-table.html>
+    RenameTable(Vec<RenameTable>),
+    /// ```sql
     /// ALTER INDEX
     /// ```
     AlterIndex {
@@ -2627,6 +2974,124 @@ pub enum Statement {
         version: Option<Ident>,
     },
     /// ```sql
+    /// ALTER EXTENSION name { UPDATE [ TO new_version ] | SET SCHEMA new_schema }
+    /// ```
+    ///
+    /// Note: this is a PostgreSQL-specific statement.
+    AlterExtension {
+        name: Ident,
+        operation: AlterExtensionOperation,
+    },
+    /// ```sql
+    /// CREATE COLLATION [ IF NOT EXISTS ] name { ( option = value [, ...] ) | FROM existing_collation }
+    /// ```
+    ///
+    /// Note: this is a PostgreSQL-specific statement.
+    /// <https://www.postgresql.org/docs/current/sql-createcollation.html>
+    CreateCollation {
+        if_not_exists: bool,
+        name: ObjectName,
+        definition: CollationDefinition,
+    },
+    /// ```sql
+    /// ALTER COLLATION name { REFRESH VERSION | RENAME TO new_name | OWNER TO new_owner | SET SCHEMA new_schema }
+    /// ```
+    ///
+    /// Note: this is a PostgreSQL-specific statement.
+    /// <https://www.postgresql.org/docs/current/sql-altercollation.html>
+    AlterCollation {
+        name: ObjectName,
+        operation: AlterCollationOperation,
+    },
+    /// ```sql
+    /// CREATE STATISTICS [ [ IF NOT EXISTS ] name ] [ ( kind [, ...] ) ]
+    ///     ON { column_name | ( expression ) } [, ...] FROM table_name
+    /// ```
+    ///
+    /// Note: this is a PostgreSQL-specific statement.
+    /// <https://www.postgresql.org/docs/current/sql-createstatistics.html>
+    CreateStatistics {
+        if_not_exists: bool,
+        /// The name of the statistics object, generated by the server if omitted
+        name: Option<ObjectName>,
+        /// The statistics kinds, e.g. `ndistinct`, `dependencies` or `mcv`
+        kinds: Vec<Ident>,
+        /// The columns and parenthesized expressions the statistics cover
+        on: Vec<Expr>,
+        #[cfg_attr(feature = "visitor", visit(with = "visit_relation"))]
+        table_name: ObjectName,
+    },
+    /// ```sql
+    /// ALTER STATISTICS name { OWNER TO new_owner | RENAME TO new_name | SET SCHEMA new_schema | SET STATISTICS { new_target | DEFAULT } }
+    /// ```
+    ///
+    /// Note: this is a PostgreSQL-specific statement.
+    /// <https://www.postgresql.org/docs/current/sql-alterstatistics.html>
+    AlterStatistics {
+        name: ObjectName,
+        operation: AlterStatisticsOperation,
+    },
+    /// A rare PostgreSQL DDL statement that has no dedicated representation,
+    /// e.g. `CREATE ACCESS METHOD`, `CREATE TRANSFORM` or `DROP SERVER`.
+    ///
+    /// Only the statements listed in the parser's curated list are parsed
+    /// this way, everything after the object name is kept as raw tokens:
+    /// ```sql
+    /// CREATE ACCESS METHOD heptree TYPE INDEX HANDLER heptree_handler
+    /// ```
+    GenericDdl {
+        /// The keywords that introduce the statement, including any
+        /// `IF [NOT] EXISTS`, e.g. `CREATE ACCESS METHOD`
+        verb: Vec<Ident>,
+        /// The name of the object, if it directly follows the `verb`
+        name: Option<ObjectName>,
+        /// The remaining tokens of the statement, including whitespace
+        options: Vec<Token>,
+    },
+    /// ```sql
+    /// CREATE [ OR REPLACE ] [ TRUSTED ] [ PROCEDURAL ] LANGUAGE name
+    ///     [ HANDLER call_handler [ INLINE inline_handler ] [ VALIDATOR valfunction ] ]
+    /// ```
+    ///
+    /// Note: this is a PostgreSQL-specific statement.
+    /// <https://www.postgresql.org/docs/current/sql-createlanguage.html>
+    CreateLanguage {
+        or_replace: bool,
+        trusted: bool,
+        procedural: bool,
+        name: Ident,
+        handler: Option<ObjectName>,
+        inline_handler: Option<ObjectName>,
+        validator: Option<ObjectName>,
+    },
+    /// ```sql
+    /// CREATE [ OR REPLACE ] RULE name AS ON event TO table_name [ WHERE condition ]
+    ///     DO [ ALSO | INSTEAD ] { NOTHING | command | ( command ; command ... ) }
+    /// ```
+    ///
+    /// Note: this is a PostgreSQL-specific statement.
+    /// <https://www.postgresql.org/docs/current/sql-createrule.html>
+    CreateRule {
+        or_replace: bool,
+        name: Ident,
+        event: RuleEvent,
+        table_name: ObjectName,
+        condition: Option<Expr>,
+        kind: Option<RuleKind>,
+        /// The commands to run, empty for `NOTHING`
+        commands: Vec<Statement>,
+    },
+    /// ```sql
+    /// NOTIFY channel [ , payload ]
+    /// ```
+    ///
+    /// Note: this is a PostgreSQL-specific statement.
+    /// <https://www.postgresql.org/docs/current/sql-notify.html>
+    Notify {
+        channel: Ident,
+        payload: Option<String>,
+    },
+    /// ```sql
     /// FETCH
     /// ```
     /// Retrieve rows from a query using a cursor
@@ -2662,6 +3127,44 @@ pub enum Statement {
     /// but may also compatible with other SQL.
     Discard { object_type: DiscardObject },
     /// ```sql
+    /// DO [ LANGUAGE lang_name ] code
+    /// ```
+    ///
+    /// Executes an anonymous code block.
+    ///
+    /// Note: this is a PostgreSQL-specific statement.
+    /// <https://www.postgresql.org/docs/current/sql-do.html>
+    ///
+    /// MySQL's `DO expr [, expr] ...`, which evaluates expressions without
+    /// returning a result, is represented with [`DoBody::Expressions`].
+    /// <https://dev.mysql.com/doc/refman/8.0/en/do.html>
+    Do {
+        language: Option<Ident>,
+        body: DoBody,
+    },
+    /// ```sql
+    /// HANDLER tbl_name { OPEN [ [AS] alias ] | READ ... | CLOSE }
+    /// ```
+    ///
+    /// Note: this is a MySQL-specific statement.
+    /// <https://dev.mysql.com/doc/refman/8.0/en/handler.html>
+    Handler {
+        table_name: ObjectName,
+        operation: HandlerOperation,
+    },
+    /// ```sql
+    /// REFRESH MATERIALIZED VIEW [ CONCURRENTLY ] name [ WITH [ NO ] DATA ]
+    /// ```
+    ///
+    /// Note: this is a PostgreSQL-specific statement.
+    /// <https://www.postgresql.org/docs/current/sql-refreshmaterializedview.html>
+    RefreshMaterializedView {
+        concurrently: bool,
+        name: ObjectName,
+        /// `Some(false)` for `WITH NO DATA`
+        with_data: Option<bool>,
+    },
+    /// ```sql
     /// SET [ SESSION | LOCAL ] ROLE role_name
     /// ```
     ///
@@ -2744,15 +3247,25 @@ pub enum Statement {
         session: bool,
     },
     /// ```sql
-    /// SHOW CREATE TABLE
+    /// SHOW CREATE {TABLE | VIEW | FUNCTION | ...} name
+    /// SHOW CREATE {DATABASE | SCHEMA} [IF NOT EXISTS] name
     /// ```
     ///
     /// Note: this is a MySQL-specific statement.
     ShowCreate {
         obj_type: ShowCreateObject,
+        /// `IF NOT EXISTS`, only allowed for databases
+        if_not_exists: bool,
         obj_name: ObjectName,
     },
     /// ```sql
+    /// SHOW CREATE USER user
+    /// ```
+    ///
+    /// Note: this is a MySQL-specific statement.
+    /// See <https://dev.mysql.com/doc/refman/8.0/en/show-create-user.html>
+    ShowCreateUser { user: AccountName },
+    /// ```sql
     /// SHOW COLUMNS
     /// ```
     ///
@@ -2781,6 +3294,22 @@ pub enum Statement {
     /// Note: this is a MySQL-specific statement.
     ShowCollation { filter: Option<ShowStatementFilter> },
     /// ```sql
+    /// SHOW [ TERSE ] <objects> [ LIKE '<pattern>' ] [ IN <scope> ]
+    ///     [ STARTS WITH '<name>' ] [ LIMIT <rows> [ FROM '<name>' ] ]
+    /// ```
+    ///
+    /// Note: this is a Snowflake-specific statement.
+    /// <https://docs.snowflake.com/en/sql-reference/sql/show-objects>
+    ShowObjects {
+        terse: bool,
+        object_type: ShowObjectType,
+        like: Option<String>,
+        scope: Option<ShowObjectsScope>,
+        starts_with: Option<String>,
+        limit: Option<Expr>,
+        limit_from: Option<String>,
+    },
+    /// ```sql
     /// `USE ...`
     /// ```
     Use(Use),
@@ -2832,12 +3361,17 @@ pub enum Statement {
         savepoint: Option<Ident>,
     },
     /// ```sql
-    /// CREATE SCHEMA
+    /// CREATE SCHEMA [ <schema element> ... ]
     /// ```
     CreateSchema {
         /// `<schema name> | AUTHORIZATION <schema authorization identifier>  | <schema name>  AUTHORIZATION <schema authorization identifier>`
         schema_name: SchemaName,
         if_not_exists: bool,
+        /// The `CREATE` and `GRANT` statements creating objects within the
+        /// new schema, e.g. `CREATE SCHEMA s CREATE TABLE t (a INT)`.
+        ///
+        /// See [PostgreSQL](https://www.postgresql.org/docs/current/sql-createschema.html)
+        elements: Vec<Statement>,
     },
     /// ```sql
     /// CREATE DATABASE
@@ -2885,6 +3419,34 @@ pub enum Statement {
         ///
         /// [Postgres](https://www.postgresql.org/docs/current/sql-createfunction.html)
         parallel: Option<FunctionParallel>,
+        /// Whether the function is a window function, i.e. `WINDOW`
+        ///
+        /// [Postgres](https://www.postgresql.org/docs/current/sql-createfunction.html)
+        window: bool,
+        /// `[ EXTERNAL ] SECURITY { INVOKER | DEFINER }`
+        ///
+        /// [Postgres](https://www.postgresql.org/docs/current/sql-createfunction.html)
+        security: Option<FunctionSecurity>,
+        /// `LEAKPROOF` (`Some(true)`) or `NOT LEAKPROOF` (`Some(false)`)
+        ///
+        /// [Postgres](https://www.postgresql.org/docs/current/sql-createfunction.html)
+        leakproof: Option<bool>,
+        /// COST execution_cost
+        ///
+        /// [Postgres](https://www.postgresql.org/docs/current/sql-createfunction.html)
+        cost: Option<Expr>,
+        /// ROWS result_rows
+        ///
+        /// [Postgres](https://www.postgresql.org/docs/current/sql-createfunction.html)
+        rows: Option<Expr>,
+        /// SUPPORT support_function
+        ///
+        /// [Postgres](https://www.postgresql.org/docs/current/sql-createfunction.html)
+        support: Option<ObjectName>,
+        /// SET configuration_parameter { TO value | = value | FROM CURRENT }
+        ///
+        /// [Postgres](https://www.postgresql.org/docs/current/sql-createfunction.html)
+        set_params: Vec<FunctionDefinitionSetParam>,
         /// USING ... (Hive only)
         using: Option<CreateFunctionUsing>,
         /// Language used in a UDF definition.
@@ -2913,6 +3475,38 @@ pub enum Statement {
         /// ```
         /// [BigQuery](https://cloud.google.com/bigquery/docs/reference/standard-sql/data-definition-language#create_a_remote_function)
         remote_connection: Option<ObjectName>,
+        /// Runtime version of a UDF written in Python, Java or Scala.
+        ///
+        /// Example:
+        /// ```sql
+        /// RUNTIME_VERSION = '3.10'
+        /// ```
+        /// [Snowflake](https://docs.snowflake.com/en/sql-reference/sql/create-function)
+        runtime_version: Option<String>,
+        /// Packages required by the handler code.
+        ///
+        /// Example:
+        /// ```sql
+        /// PACKAGES = ('numpy', 'pandas')
+        /// ```
+        /// [Snowflake](https://docs.snowflake.com/en/sql-reference/sql/create-function)
+        packages: Option<Vec<String>>,
+        /// Staged files imported by the handler code.
+        ///
+        /// Example:
+        /// ```sql
+        /// IMPORTS = ('@stage/module.py')
+        /// ```
+        /// [Snowflake](https://docs.snowflake.com/en/sql-reference/sql/create-function)
+        imports: Option<Vec<String>>,
+        /// Name of the handler function or class.
+        ///
+        /// Example:
+        /// ```sql
+        /// HANDLER = 'main'
+        /// ```
+        /// [Snowflake](https://docs.snowflake.com/en/sql-reference/sql/create-function)
+        handler: Option<String>,
     },
     /// CREATE TRIGGER
     ///
@@ -3007,11 +3601,27 @@ pub enum Statement {
     /// ```sql
     /// CREATE PROCEDURE
     /// ```
+    ///
+    /// Supported variants:
+    /// 1. [MsSql](https://learn.microsoft.com/en-us/sql/t-sql/statements/create-procedure-transact-sql)
+    /// 2. [Snowflake](https://docs.snowflake.com/en/sql-reference/sql/create-procedure)
     CreateProcedure {
         or_alter: bool,
+        or_replace: bool,
         name: ObjectName,
         params: Option<Vec<ProcedureParam>>,
-        body: Vec<Statement>,
+        return_type: Option<DataType>,
+        /// Language of the procedure handler, e.g. `LANGUAGE PYTHON`
+        language: Option<Ident>,
+        /// See [Statement::CreateFunction::runtime_version]
+        runtime_version: Option<String>,
+        /// See [Statement::CreateFunction::packages]
+        packages: Option<Vec<String>>,
+        /// See [Statement::CreateFunction::imports]
+        imports: Option<Vec<String>>,
+        /// See [Statement::CreateFunction::handler]
+        handler: Option<String>,
+        body: CreateProcedureBody,
     },
     /// ```sql
     /// CREATE MACRO
@@ -3027,6 +3637,22 @@ pub enum Statement {
         definition: MacroDefinition,
     },
     /// ```sql
+    /// CREATE [ OR REPLACE ] MODEL [ IF NOT EXISTS ] <name>
+    ///     [ TRANSFORM ( <select_list> ) ] [ OPTIONS ( <options> ) ] [ AS <query> ]
+    /// ```
+    ///
+    /// See [BigQuery](https://cloud.google.com/bigquery/docs/reference/standard-sql/bigqueryml-syntax-create)
+    CreateModel {
+        or_replace: bool,
+        if_not_exists: bool,
+        name: ObjectName,
+        /// The preprocessing of the training data, i.e. `TRANSFORM ( ... )`
+        transform: Option<Vec<SelectItem>>,
+        options: Vec<SqlOption>,
+        /// The training data
+        query: Option<Box<Query>>,
+    },
+    /// ```sql
     /// CREATE STAGE
     /// ```
     /// See <https://docs.snowflake.com/en/sql-reference/sql/create-stage>
@@ -3042,6 +3668,37 @@ pub enum Statement {
         comment: Option<String>,
     },
     /// ```sql
+    /// CREATE [ OR REPLACE ] TASK [ IF NOT EXISTS ] <name>
+    ///     [ WAREHOUSE = <warehouse> ] [ SCHEDULE = '<schedule>' ] [ <param> = <value> ... ]
+    ///     [ AFTER <task> [, ...] ] [ WHEN <condition> ]
+    ///     AS <statement>
+    /// ```
+    /// See <https://docs.snowflake.com/en/sql-reference/sql/create-task>
+    CreateTask {
+        or_replace: bool,
+        if_not_exists: bool,
+        name: ObjectName,
+        warehouse: Option<Ident>,
+        /// e.g. `'5 MINUTE'` or `'USING CRON 0 9 * * * UTC'`
+        schedule: Option<String>,
+        /// Any other parameters, e.g. `COMMENT` or session parameters
+        options: Vec<SqlOption>,
+        /// The predecessor tasks of this task
+        after: Vec<ObjectName>,
+        condition: Option<Expr>,
+        /// The statement the task executes
+        statement: Box<Statement>,
+    },
+    /// ```sql
+    /// ALTER TASK [ IF EXISTS ] <name> <operation>
+    /// ```
+    /// See <https://docs.snowflake.com/en/sql-reference/sql/alter-task>
+    AlterTask {
+        if_exists: bool,
+        name: ObjectName,
+        operation: AlterTaskOperation,
+    },
+    /// ```sql
     /// ASSERT <condition> [AS <message>]
     /// ```
     Assert {
@@ -3049,11 +3706,44 @@ pub enum Statement {
         message: Option<Expr>,
     },
     /// ```sql
+    /// IF <condition> <statement> [ELSE <statement>]
+    /// ```
+    ///
+    /// [MsSql](https://learn.microsoft.com/en-us/sql/t-sql/language-elements/if-else-transact-sql)
+    If {
+        condition: Expr,
+        then_statement: Box<Statement>,
+        else_statement: Option<Box<Statement>>,
+    },
+    /// ```sql
+    /// WHILE <condition> <statement>
+    /// ```
+    ///
+    /// [MsSql](https://learn.microsoft.com/en-us/sql/t-sql/language-elements/while-transact-sql)
+    While {
+        condition: Expr,
+        body: Box<Statement>,
+    },
+    /// ```sql
+    /// BEGIN <statements> END
+    /// ```
+    ///
+    /// [MsSql](https://learn.microsoft.com/en-us/sql/t-sql/language-elements/begin-end-transact-sql)
+    Block(Vec<Statement>),
+    /// ```sql
+    /// RETURN [<expr>]
+    /// ```
+    ///
+    /// [MsSql](https://learn.microsoft.com/en-us/sql/t-sql/language-elements/return-transact-sql)
+    Return(Option<Expr>),
+    /// ```sql
     /// GRANT privileges ON objects TO grantees
     /// ```
     Grant {
         privileges: Privileges,
         objects: GrantObjects,
+        /// The kind of the grantees, e.g. `ROLE` in Snowflake's `TO ROLE r`
+        grantees_type: Option<GranteesType>,
         grantees: Vec<Ident>,
         with_grant_option: bool,
         granted_by: Option<Ident>,
@@ -3064,15 +3754,70 @@ pub enum Statement {
     Revoke {
         privileges: Privileges,
         objects: GrantObjects,
+        /// The kind of the grantees, e.g. `ROLE` in Snowflake's `FROM ROLE r`
+        grantees_type: Option<GranteesType>,
         grantees: Vec<Ident>,
         granted_by: Option<Ident>,
         cascade: bool,
     },
     /// ```sql
-    /// DEALLOCATE [ PREPARE ] { name | ALL }
+    /// GRANT ROLE role_name TO { ROLE | USER } grantee
     /// ```
     ///
-    /// Note: this is a PostgreSQL-specific statement.
+    /// [Snowflake](https://docs.snowflake.com/en/sql-reference/sql/grant-role)
+    GrantRole {
+        roles: Vec<Ident>,
+        grantees_type: Option<GranteesType>,
+        grantees: Vec<Ident>,
+    },
+    /// ```sql
+    /// REVOKE ROLE role_name FROM { ROLE | USER } grantee
+    /// ```
+    ///
+    /// [Snowflake](https://docs.snowflake.com/en/sql-reference/sql/revoke-role)
+    RevokeRole {
+        roles: Vec<Ident>,
+        grantees_type: Option<GranteesType>,
+        grantees: Vec<Ident>,
+    },
+    /// ```sql
+    /// GRANT PROXY ON proxied_user TO user [, user] ... [WITH GRANT OPTION]
+    /// ```
+    ///
+    /// [MySQL](https://dev.mysql.com/doc/refman/8.0/en/grant.html#grant-proxy-privileges)
+    GrantProxy {
+        proxied_user: AccountName,
+        grantees: Vec<AccountName>,
+        with_grant_option: bool,
+    },
+    /// ```sql
+    /// REVOKE PROXY ON proxied_user FROM user [, user] ...
+    /// ```
+    ///
+    /// [MySQL](https://dev.mysql.com/doc/refman/8.0/en/revoke.html)
+    RevokeProxy {
+        proxied_user: AccountName,
+        grantees: Vec<AccountName>,
+    },
+    /// ```sql
+    /// SET PASSWORD [FOR user] = 'auth_string' [REPLACE 'current_auth_string'] [RETAIN CURRENT PASSWORD]
+    /// SET PASSWORD [FOR user] TO RANDOM [REPLACE 'current_auth_string'] [RETAIN CURRENT PASSWORD]
+    /// ```
+    ///
+    /// [MySQL](https://dev.mysql.com/doc/refman/8.0/en/set-password.html)
+    SetPassword {
+        /// The account to change, or `None` for the current user
+        user: Option<AccountName>,
+        password: SetPasswordValue,
+        /// The current password, as required by `REPLACE`
+        replace: Option<Value>,
+        retain_current_password: bool,
+    },
+    /// ```sql
+    /// DEALLOCATE [ PREPARE ] { name | ALL }
+    /// ```
+    ///
+    /// Note: this is a PostgreSQL-specific statement.
     Deallocate { name: Ident, prepare: bool },
     /// ```sql
     /// EXECUTE name [ ( parameter [, ...] ) ] [USING <expr>]
@@ -3261,6 +4006,17 @@ pub enum Statement {
         include_final: bool,
         deduplicate: Option<Deduplicate>,
     },
+    /// A statement that could not be parsed, kept as its raw tokens.
+    ///
+    /// This is only produced if [`ParserOptions::unparsed_statements`] is set.
+    ///
+    /// [`ParserOptions::unparsed_statements`]: crate::parser::ParserOptions::unparsed_statements
+    Unparsed {
+        /// The tokens of the statement, without whitespace and comments.
+        tokens: Vec<Token>,
+        /// The text of the statement, rebuilt from all of its tokens.
+        sql_fragment: String,
+    },
 }
 
 impl fmt::Display for Statement {
@@ -3540,6 +4296,7 @@ impl fmt::Display for Statement {
                     columns,
                     after_columns,
                     source,
+                    assignments,
                     table,
                     on,
                     returning,
@@ -3589,9 +4346,9 @@ impl fmt::Display for Statement {
 
                 if let Some(source) = source {
                     write!(f, "{source}")?;
-                }
-
-                if source.is_none() && columns.is_empty() {
+                } else if !assignments.is_empty() {
+                    write!(f, "SET {}", display_comma_separated(assignments))?;
+                } else if columns.is_empty() {
                     write!(f, "DEFAULT VALUES")?;
                 }
 
@@ -3605,7 +4362,7 @@ impl fmt::Display for Statement {
                     }
                 }
 
-                if let Some(on) = on {
+                for on in on {
                     write!(f, "{on}")?;
                 }
 
@@ -3654,16 +4411,15 @@ impl fmt::Display for Statement {
                     write!(f, " {}", display_separated(legacy_options, " "))?;
                 }
                 if !values.is_empty() {
+                    let format = CopyDataFormat::new(options, legacy_options);
                     writeln!(f, ";")?;
-                    let mut delim = "";
+                    let mut delim = None;
                     for v in values {
-                        write!(f, "{delim}")?;
-                        delim = "\t";
-                        if let Some(v) = v {
-                            write!(f, "{v}")?;
-                        } else {
-                            write!(f, "\\N")?;
+                        if let Some(delim) = delim {
+                            write!(f, "{delim}")?;
                         }
+                        delim = Some(format.delimiter);
+                        write!(f, "{}", v.as_deref().unwrap_or(&format.null))?;
                     }
                     write!(f, "\n\\.")?;
                 }
@@ -3680,8 +4436,8 @@ impl fmt::Display for Statement {
                 if !assignments.is_empty() {
                     write!(f, " SET {}", display_comma_separated(assignments))?;
                 }
-                if let Some(from) = from {
-                    write!(f, " FROM {from}")?;
+                if !from.is_empty() {
+                    write!(f, " FROM {}", display_comma_separated(from))?;
                 }
                 if let Some(selection) = selection {
                     write!(f, " WHERE {selection}")?;
@@ -3766,10 +4522,21 @@ impl fmt::Display for Statement {
                 behavior,
                 called_on_null,
                 parallel,
+                window,
+                security,
+                leakproof,
+                cost,
+                rows,
+                support,
+                set_params,
                 using,
                 determinism_specifier,
                 options,
                 remote_connection,
+                runtime_version,
+                packages,
+                imports,
+                handler,
             } => {
                 write!(
                     f,
@@ -3790,21 +4557,48 @@ impl fmt::Display for Statement {
                 if let Some(language) = language {
                     write!(f, " LANGUAGE {language}")?;
                 }
+                if *window {
+                    write!(f, " WINDOW")?;
+                }
                 if let Some(behavior) = behavior {
                     write!(f, " {behavior}")?;
                 }
                 if let Some(called_on_null) = called_on_null {
                     write!(f, " {called_on_null}")?;
                 }
+                if let Some(security) = security {
+                    write!(f, " {security}")?;
+                }
+                match leakproof {
+                    Some(true) => write!(f, " LEAKPROOF")?,
+                    Some(false) => write!(f, " NOT LEAKPROOF")?,
+                    None => {}
+                }
                 if let Some(parallel) = parallel {
                     write!(f, " {parallel}")?;
                 }
+                if let Some(cost) = cost {
+                    write!(f, " COST {cost}")?;
+                }
+                if let Some(rows) = rows {
+                    write!(f, " ROWS {rows}")?;
+                }
+                if let Some(support) = support {
+                    write!(f, " SUPPORT {support}")?;
+                }
+                for set_param in set_params {
+                    write!(f, " {set_param}")?;
+                }
+                display_handler_properties(f, runtime_version, packages, imports, handler)?;
                 if let Some(remote_connection) = remote_connection {
                     write!(f, " REMOTE WITH CONNECTION {remote_connection}")?;
                 }
                 if let Some(CreateFunctionBody::AsBeforeOptions(function_body)) = function_body {
                     write!(f, " AS {function_body}")?;
                 }
+                if let Some(CreateFunctionBody::AsScript(script)) = function_body {
+                    write!(f, " AS $$ {script} $$")?;
+                }
                 if let Some(CreateFunctionBody::Return(function_body)) = function_body {
                     write!(f, " RETURN {function_body}")?;
                 }
@@ -3891,26 +4685,38 @@ impl fmt::Display for Statement {
             Statement::CreateProcedure {
                 name,
                 or_alter,
+                or_replace,
                 params,
+                return_type,
+                language,
+                runtime_version,
+                packages,
+                imports,
+                handler,
                 body,
             } => {
                 write!(
                     f,
-                    "CREATE {or_alter}PROCEDURE {name}",
+                    "CREATE {or_alter}{or_replace}PROCEDURE {name}",
                     or_alter = if *or_alter { "OR ALTER " } else { "" },
+                    or_replace = if *or_replace { "OR REPLACE " } else { "" },
                     name = name
                 )?;
 
                 if let Some(p) = params {
-                    if !p.is_empty() {
+                    // MySQL requires the parentheses even without parameters
+                    if !p.is_empty() || matches!(body, CreateProcedureBody::Routine(_)) {
                         write!(f, " ({})", display_comma_separated(p))?;
                     }
                 }
-                write!(
-                    f,
-                    " AS BEGIN {body} END",
-                    body = display_separated(body, "; ")
-                )
+                if let Some(return_type) = return_type {
+                    write!(f, " RETURNS {return_type}")?;
+                }
+                if let Some(language) = language {
+                    write!(f, " LANGUAGE {language}")?;
+                }
+                display_handler_properties(f, runtime_version, packages, imports, handler)?;
+                write!(f, " {body}")
             }
             Statement::CreateMacro {
                 or_replace,
@@ -3934,6 +4740,31 @@ impl fmt::Display for Statement {
                 }
                 Ok(())
             }
+            Statement::CreateModel {
+                or_replace,
+                if_not_exists,
+                name,
+                transform,
+                options,
+                query,
+            } => {
+                write!(
+                    f,
+                    "CREATE {or_replace}MODEL {if_not_exists}{name}",
+                    or_replace = if *or_replace { "OR REPLACE " } else { "" },
+                    if_not_exists = if *if_not_exists { "IF NOT EXISTS " } else { "" },
+                )?;
+                if let Some(transform) = transform {
+                    write!(f, " TRANSFORM({})", display_comma_separated(transform))?;
+                }
+                if !options.is_empty() {
+                    write!(f, " OPTIONS({})", display_comma_separated(options))?;
+                }
+                if let Some(query) = query {
+                    write!(f, " AS {query}")?;
+                }
+                Ok(())
+            }
             Statement::CreateView {
                 name,
                 or_replace,
@@ -3947,6 +4778,11 @@ impl fmt::Display for Statement {
                 if_not_exists,
                 temporary,
                 to,
+                engine,
+                populate,
+                using,
+                with_data,
+                unknown_options,
             } => {
                 write!(
                     f,
@@ -3964,6 +4800,9 @@ impl fmt::Display for Statement {
                 if !columns.is_empty() {
                     write!(f, " ({})", display_comma_separated(columns))?;
                 }
+                if let Some(using) = using {
+                    write!(f, " USING {using}")?;
+                }
                 if matches!(options, CreateTableOptions::With(_)) {
                     write!(f, " {options}")?;
                 }
@@ -3980,10 +4819,24 @@ impl fmt::Display for Statement {
                 if matches!(options, CreateTableOptions::Options(_)) {
                     write!(f, " {options}")?;
                 }
+                if let Some(engine) = engine {
+                    write!(f, " {engine}")?;
+                }
+                if *populate {
+                    write!(f, " POPULATE")?;
+                }
                 write!(f, " AS {query}")?;
+                match with_data {
+                    Some(true) => write!(f, " WITH DATA")?,
+                    Some(false) => write!(f, " WITH NO DATA")?,
+                    None => {}
+                }
                 if *with_no_schema_binding {
                     write!(f, " WITH NO SCHEMA BINDING")?;
                 }
+                for option in unknown_options {
+                    write!(f, " {option}")?;
+                }
                 Ok(())
             }
             Statement::CreateTable(create_table) => create_table.fmt(f),
@@ -4006,6 +4859,9 @@ impl fmt::Display for Statement {
                 Ok(())
             }
             Statement::CreateIndex(create_index) => create_index.fmt(f),
+            Statement::MultiTableInsert(insert) => insert.fmt(f),
+            Statement::CreateEvent(create_event) => create_event.fmt(f),
+            Statement::AlterEvent(alter_event) => alter_event.fmt(f),
             Statement::CreateExtension {
                 name,
                 if_not_exists,
@@ -4034,6 +4890,120 @@ impl fmt::Display for Statement {
 
                 Ok(())
             }
+            Statement::AlterExtension { name, operation } => {
+                write!(f, "ALTER EXTENSION {name} {operation}")
+            }
+            Statement::CreateCollation {
+                if_not_exists,
+                name,
+                definition,
+            } => {
+                write!(
+                    f,
+                    "CREATE COLLATION {if_not_exists}{name} {definition}",
+                    if_not_exists = if *if_not_exists { "IF NOT EXISTS " } else { "" },
+                )
+            }
+            Statement::AlterCollation { name, operation } => {
+                write!(f, "ALTER COLLATION {name} {operation}")
+            }
+            Statement::CreateStatistics {
+                if_not_exists,
+                name,
+                kinds,
+                on,
+                table_name,
+            } => {
+                write!(f, "CREATE STATISTICS")?;
+                if *if_not_exists {
+                    write!(f, " IF NOT EXISTS")?;
+                }
+                if let Some(name) = name {
+                    write!(f, " {name}")?;
+                }
+                if !kinds.is_empty() {
+                    write!(f, " ({})", display_comma_separated(kinds))?;
+                }
+                write!(f, " ON {} FROM {table_name}", display_comma_separated(on))
+            }
+            Statement::AlterStatistics { name, operation } => {
+                write!(f, "ALTER STATISTICS {name} {operation}")
+            }
+            Statement::GenericDdl {
+                verb,
+                name,
+                options,
+            } => {
+                write!(f, "{}", display_separated(verb, " "))?;
+                if let Some(name) = name {
+                    write!(f, " {name}")?;
+                }
+                for token in options {
+                    write!(f, "{token}")?;
+                }
+                Ok(())
+            }
+            Statement::CreateLanguage {
+                or_replace,
+                trusted,
+                procedural,
+                name,
+                handler,
+                inline_handler,
+                validator,
+            } => {
+                write!(
+                    f,
+                    "CREATE {or_replace}{trusted}{procedural}LANGUAGE {name}",
+                    or_replace = if *or_replace { "OR REPLACE " } else { "" },
+                    trusted = if *trusted { "TRUSTED " } else { "" },
+                    procedural = if *procedural { "PROCEDURAL " } else { "" },
+                )?;
+                if let Some(handler) = handler {
+                    write!(f, " HANDLER {handler}")?;
+                }
+                if let Some(inline_handler) = inline_handler {
+                    write!(f, " INLINE {inline_handler}")?;
+                }
+                if let Some(validator) = validator {
+                    write!(f, " VALIDATOR {validator}")?;
+                }
+                Ok(())
+            }
+            Statement::CreateRule {
+                or_replace,
+                name,
+                event,
+                table_name,
+                condition,
+                kind,
+                commands,
+            } => {
+                write!(
+                    f,
+                    "CREATE {or_replace}RULE {name} AS ON {event} TO {table_name}",
+                    or_replace = if *or_replace { "OR REPLACE " } else { "" },
+                )?;
+                if let Some(condition) = condition {
+                    write!(f, " WHERE {condition}")?;
+                }
+                write!(f, " DO")?;
+                if let Some(kind) = kind {
+                    write!(f, " {kind}")?;
+                }
+                match commands.as_slice() {
+                    [] => write!(f, " NOTHING"),
+                    [command] => write!(f, " {command}"),
+                    commands => write!(f, " ({})", display_separated(commands, "; ")),
+                }
+            }
+            Statement::Notify { channel, payload } => {
+                write!(f, "NOTIFY {channel}")?;
+                if let Some(payload) = payload {
+                    write!(f, ", '{}'", value::escape_single_quote_string(payload))?;
+                }
+                Ok(())
+            }
             Statement::CreateRole {
                 names,
                 if_not_exists,
@@ -4210,6 +5180,7 @@ impl fmt::Display for Statement {
                 operations,
                 location,
                 on_cluster,
+                unknown_options,
             } => {
                 write!(f, "ALTER TABLE ")?;
                 if *if_exists {
@@ -4230,8 +5201,14 @@ impl fmt::Display for Statement {
                 if let Some(loc) = location {
                     write!(f, " {loc}")?
                 }
+                for option in unknown_options {
+                    write!(f, " {option}")?;
+                }
                 Ok(())
             }
+            Statement::RenameTable(renames) => {
+                write!(f, "RENAME TABLE {}", display_comma_separated(renames))
+            }
             Statement::AlterIndex { name, operation } => {
                 write!(f, "ALTER INDEX {name} {operation}")
             }
@@ -4351,6 +5328,33 @@ impl fmt::Display for Statement {
                 write!(f, "DISCARD {object_type}")?;
                 Ok(())
             }
+            Statement::Do { language, body } => {
+                write!(f, "DO")?;
+                if let Some(language) = language {
+                    write!(f, " LANGUAGE {language}")?;
+                }
+                write!(f, " {body}")
+            }
+            Statement::Handler {
+                table_name,
+                operation,
+            } => write!(f, "HANDLER {table_name} {operation}"),
+            Statement::RefreshMaterializedView {
+                concurrently,
+                name,
+                with_data,
+            } => {
+                write!(
+                    f,
+                    "REFRESH MATERIALIZED VIEW {concurrently}{name}",
+                    concurrently = if *concurrently { "CONCURRENTLY " } else { "" },
+                )?;
+                match with_data {
+                    Some(true) => write!(f, " WITH DATA"),
+                    Some(false) => write!(f, " WITH NO DATA"),
+                    None => Ok(()),
+                }
+            }
             Self::SetRole {
                 context_modifier,
                 role_name,
@@ -4448,10 +5452,18 @@ impl fmt::Display for Statement {
                 }
                 Ok(())
             }
-            Statement::ShowCreate { obj_type, obj_name } => {
-                write!(f, "SHOW CREATE {obj_type} {obj_name}",)?;
-                Ok(())
+            Statement::ShowCreate {
+                obj_type,
+                if_not_exists,
+                obj_name,
+            } => {
+                write!(
+                    f,
+                    "SHOW CREATE {obj_type} {if_not_exists}{obj_name}",
+                    if_not_exists = if *if_not_exists { "IF NOT EXISTS " } else { "" },
+                )
             }
+            Statement::ShowCreateUser { user } => write!(f, "SHOW CREATE USER {user}"),
             Statement::ShowColumns {
                 extended,
                 full,
@@ -4505,6 +5517,45 @@ impl fmt::Display for Statement {
                 }
                 Ok(())
             }
+            Statement::ShowObjects {
+                terse,
+                object_type,
+                like,
+                scope,
+                starts_with,
+                limit,
+                limit_from,
+            } => {
+                write!(
+                    f,
+                    "SHOW {terse}{object_type}",
+                    terse = if *terse { "TERSE " } else { "" },
+                )?;
+                if let Some(like) = like {
+                    write!(f, " LIKE '{}'", value::escape_single_quote_string(like))?;
+                }
+                if let Some(scope) = scope {
+                    write!(f, " IN {scope}")?;
+                }
+                if let Some(starts_with) = starts_with {
+                    write!(
+                        f,
+                        " STARTS WITH '{}'",
+                        value::escape_single_quote_string(starts_with)
+                    )?;
+                }
+                if let Some(limit) = limit {
+                    write!(f, " LIMIT {limit}")?;
+                }
+                if let Some(limit_from) = limit_from {
+                    write!(
+                        f,
+                        " FROM '{}'",
+                        value::escape_single_quote_string(limit_from)
+                    )?;
+                }
+                Ok(())
+            }
             Statement::StartTransaction {
                 modes,
                 begin: syntax_begin,
@@ -4561,12 +5612,19 @@ impl fmt::Display for Statement {
             Statement::CreateSchema {
                 schema_name,
                 if_not_exists,
-            } => write!(
-                f,
-                "CREATE SCHEMA {if_not_exists}{name}",
-                if_not_exists = if *if_not_exists { "IF NOT EXISTS " } else { "" },
-                name = schema_name
-            ),
+                elements,
+            } => {
+                write!(
+                    f,
+                    "CREATE SCHEMA {if_not_exists}{name}",
+                    if_not_exists = if *if_not_exists { "IF NOT EXISTS " } else { "" },
+                    name = schema_name
+                )?;
+                for element in elements {
+                    write!(f, " {element}")?;
+                }
+                Ok(())
+            }
             Statement::Assert { condition, message } => {
                 write!(f, "ASSERT {condition}")?;
                 if let Some(m) = message {
@@ -4574,16 +5632,44 @@ impl fmt::Display for Statement {
                 }
                 Ok(())
             }
+            Statement::If {
+                condition,
+                then_statement,
+                else_statement,
+            } => {
+                write!(f, "IF {condition} {then_statement}")?;
+                if let Some(else_statement) = else_statement {
+                    write!(f, " ELSE {else_statement}")?;
+                }
+                Ok(())
+            }
+            Statement::While { condition, body } => write!(f, "WHILE {condition} {body}"),
+            Statement::Block(statements) => {
+                write!(f, "BEGIN ")?;
+                if !statements.is_empty() {
+                    write!(f, "{} ", display_separated(statements, "; "))?;
+                }
+                write!(f, "END")
+            }
+            Statement::Return(expr) => {
+                write!(f, "RETURN")?;
+                if let Some(expr) = expr {
+                    write!(f, " {expr}")?;
+                }
+                Ok(())
+            }
             Statement::Grant {
                 privileges,
                 objects,
+                grantees_type,
                 grantees,
                 with_grant_option,
                 granted_by,
             } => {
                 write!(f, "GRANT {privileges} ")?;
                 write!(f, "ON {objects} ")?;
-                write!(f, "TO {}", display_comma_separated(grantees))?;
+                write!(f, "TO ")?;
+                display_grantees(f, grantees_type, grantees)?;
                 if *with_grant_option {
                     write!(f, " WITH GRANT OPTION")?;
                 }
@@ -4595,19 +5681,79 @@ impl fmt::Display for Statement {
             Statement::Revoke {
                 privileges,
                 objects,
+                grantees_type,
                 grantees,
                 granted_by,
                 cascade,
             } => {
                 write!(f, "REVOKE {privileges} ")?;
                 write!(f, "ON {objects} ")?;
-                write!(f, "FROM {}", display_comma_separated(grantees))?;
+                write!(f, "FROM ")?;
+                display_grantees(f, grantees_type, grantees)?;
                 if let Some(grantor) = granted_by {
                     write!(f, " GRANTED BY {grantor}")?;
                 }
                 write!(f, " {}", if *cascade { "CASCADE" } else { "RESTRICT" })?;
                 Ok(())
             }
+            Statement::GrantRole {
+                roles,
+                grantees_type,
+                grantees,
+            } => {
+                write!(f, "GRANT ROLE {} TO ", display_comma_separated(roles))?;
+                display_grantees(f, grantees_type, grantees)
+            }
+            Statement::RevokeRole {
+                roles,
+                grantees_type,
+                grantees,
+            } => {
+                write!(f, "REVOKE ROLE {} FROM ", display_comma_separated(roles))?;
+                display_grantees(f, grantees_type, grantees)
+            }
+            Statement::GrantProxy {
+                proxied_user,
+                grantees,
+                with_grant_option,
+            } => {
+                write!(
+                    f,
+                    "GRANT PROXY ON {proxied_user} TO {}",
+                    display_comma_separated(grantees)
+                )?;
+                if *with_grant_option {
+                    write!(f, " WITH GRANT OPTION")?;
+                }
+                Ok(())
+            }
+            Statement::RevokeProxy {
+                proxied_user,
+                grantees,
+            } => write!(
+                f,
+                "REVOKE PROXY ON {proxied_user} FROM {}",
+                display_comma_separated(grantees)
+            ),
+            Statement::SetPassword {
+                user,
+                password,
+                replace,
+                retain_current_password,
+            } => {
+                write!(f, "SET PASSWORD")?;
+                if let Some(user) = user {
+                    write!(f, " FOR {user}")?;
+                }
+                write!(f, " {password}")?;
+                if let Some(replace) = replace {
+                    write!(f, " REPLACE {replace}")?;
+                }
+                if *retain_current_password {
+                    write!(f, " RETAIN CURRENT PASSWORD")?;
+                }
+                Ok(())
+            }
             Statement::Deallocate { name, prepare } => write!(
                 f,
                 "DEALLOCATE {prepare}{name}",
@@ -4776,6 +5922,55 @@ impl fmt::Display for Statement {
                 }
                 Ok(())
             }
+            Statement::CreateTask {
+                or_replace,
+                if_not_exists,
+                name,
+                warehouse,
+                schedule,
+                options,
+                after,
+                condition,
+                statement,
+            } => {
+                write!(
+                    f,
+                    "CREATE {or_replace}TASK {if_not_exists}{name}",
+                    or_replace = if *or_replace { "OR REPLACE " } else { "" },
+                    if_not_exists = if *if_not_exists { "IF NOT EXISTS " } else { "" },
+                )?;
+                if let Some(warehouse) = warehouse {
+                    write!(f, " WAREHOUSE = {warehouse}")?;
+                }
+                if let Some(schedule) = schedule {
+                    write!(
+                        f,
+                        " SCHEDULE = '{}'",
+                        value::escape_single_quote_string(schedule)
+                    )?;
+                }
+                if !options.is_empty() {
+                    write!(f, " {}", display_separated(options, " "))?;
+                }
+                if !after.is_empty() {
+                    write!(f, " AFTER {}", display_comma_separated(after))?;
+                }
+                if let Some(condition) = condition {
+                    write!(f, " WHEN {condition}")?;
+                }
+                write!(f, " AS {statement}")
+            }
+            Statement::AlterTask {
+                if_exists,
+                name,
+                operation,
+            } => {
+                write!(
+                    f,
+                    "ALTER TASK {if_exists}{name} {operation}",
+                    if_exists = if *if_exists { "IF EXISTS " } else { "" },
+                )
+            }
             Statement::CopyIntoSnowflake {
                 into,
                 from_stage,
@@ -4889,10 +6084,86 @@ impl fmt::Display for Statement {
                 }
                 Ok(())
             }
+            Statement::Unparsed { sql_fragment, .. } => write!(f, "{sql_fragment}"),
         }
     }
 }
 
+/// A [`Statement`] together with the comments surrounding it in the source.
+///
+/// See [`Parser::parse_commented_statements`](crate::parser::Parser::parse_commented_statements).
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct CommentedStatement {
+    /// Comments preceding the statement.
+    pub leading_comments: Vec<Whitespace>,
+    /// The statement itself.
+    pub statement: Statement,
+    /// Comments following the statement on the line where it ends.
+    pub trailing_comments: Vec<Whitespace>,
+    /// Comments inside the statement, attached to the tokens nearest to
+    /// them, in source order.
+    ///
+    /// These are not part of the `Display` output, as the statement is
+    /// rendered without its original layout. A rewriter that preserves the
+    /// layout can splice them back in using [`TokenComments::span`].
+    pub inner_comments: Vec<TokenComments>,
+}
+
+impl fmt::Display for CommentedStatement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for comment in &self.leading_comments {
+            write!(f, "{comment}")?;
+            if let Whitespace::MultiLineComment(_) = comment {
+                writeln!(f)?;
+            }
+        }
+        write!(f, "{}", self.statement)?;
+        for comment in &self.trailing_comments {
+            write!(f, " {comment}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Comments attached to a token of a [`CommentedStatement`], i.e. to the
+/// AST nodes starting or ending with that token.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct TokenComments {
+    /// The location of the token in the source.
+    pub span: Span,
+    /// Comments preceding the token.
+    pub leading: Vec<Whitespace>,
+    /// Comments following the token on the line where it ends.
+    pub trailing: Vec<Whitespace>,
+}
+
+/// A [`Statement`] together with the location of its text in the source.
+///
+/// See [`Parser::parse_sql_with_ranges`](crate::parser::Parser::parse_sql_with_ranges).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct ParsedStatement {
+    /// The parsed statement.
+    pub statement: Statement,
+    /// The byte range of the statement in the source, from the start of
+    /// its first token to the end of its last one. This excludes the
+    /// terminating `;` as well as any surrounding whitespace and comments.
+    /// The range of a `COPY ... FROM STDIN` extends over the `;` to the end of
+    /// the inline data that follows it.
+    pub source_range: Range<usize>,
+    /// Whether the statement is terminated by a `;`, as opposed to the end
+    /// of the input. This is always the case for a `COPY ... FROM STDIN`.
+    pub ends_with_semicolon: bool,
+}
+
 /// Can use to describe options in create sequence or table column type identity
 /// ```sql
 /// [ INCREMENT [ BY ] increment ]
@@ -4901,6 +6172,7 @@ impl fmt::Display for Statement {
 /// ```
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum SequenceOptions {
     IncrementBy(Expr, bool),
@@ -4958,6 +6230,7 @@ impl fmt::Display for SequenceOptions {
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 pub struct TruncateTableTarget {
     /// name of the table being truncated
     #[cfg_attr(feature = "visitor", visit(with = "visit_relation"))]
@@ -4974,6 +6247,7 @@ impl fmt::Display for TruncateTableTarget {
 /// [ RESTART IDENTITY | CONTINUE IDENTITY ]
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum TruncateIdentityOption {
     Restart,
@@ -4984,6 +6258,7 @@ pub enum TruncateIdentityOption {
 /// [ CASCADE | RESTRICT ]
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum TruncateCascadeOption {
     Cascade,
@@ -4994,6 +6269,7 @@ pub enum TruncateCascadeOption {
 /// [ MINVALUE minvalue | NO MINVALUE ] [ MAXVALUE maxvalue | NO MAXVALUE ]
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum MinMaxValue {
     // clause is not specified
@@ -5006,6 +6282,7 @@ pub enum MinMaxValue {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 #[non_exhaustive]
 pub enum OnInsert {
@@ -5017,6 +6294,7 @@ pub enum OnInsert {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct InsertAliases {
     pub row_alias: ObjectName,
@@ -5025,6 +6303,7 @@ pub struct InsertAliases {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OnConflict {
     pub conflict_target: Option<ConflictTarget>,
@@ -5032,13 +6311,24 @@ pub struct OnConflict {
 }
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum ConflictTarget {
     Columns(Vec<Ident>),
     OnConstraint(ObjectName),
+    /// Unique index inference from index expressions and/or a partial index
+    /// predicate, e.g. `(lower(email)) WHERE active`. A plain list of columns
+    /// without predicate is [ConflictTarget::Columns] instead.
+    ///
+    /// See <https://www.postgresql.org/docs/current/sql-insert.html#SQL-ON-CONFLICT>
+    IndexInference {
+        exprs: Vec<Expr>,
+        predicate: Option<Expr>,
+    },
 }
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OnConflictAction {
     DoNothing,
@@ -5047,6 +6337,7 @@ pub enum OnConflictAction {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct DoUpdate {
     /// Column assignments
@@ -5081,6 +6372,13 @@ impl fmt::Display for ConflictTarget {
         match self {
             ConflictTarget::Columns(cols) => write!(f, "({})", display_comma_separated(cols)),
             ConflictTarget::OnConstraint(name) => write!(f, " ON CONSTRAINT {name}"),
+            ConflictTarget::IndexInference { exprs, predicate } => {
+                write!(f, "({})", display_comma_separated(exprs))?;
+                if let Some(predicate) = predicate {
+                    write!(f, " WHERE {predicate}")?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -5109,6 +6407,7 @@ impl fmt::Display for OnConflictAction {
 /// Privileges granted in a GRANT statement or revoked in a REVOKE statement.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum Privileges {
     /// All privileges applicable to the object type
@@ -5146,6 +6445,7 @@ impl fmt::Display for Privileges {
 /// Specific direction for FETCH statement
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum FetchDirection {
     Count { limit: Value },
@@ -5210,6 +6510,7 @@ impl fmt::Display for FetchDirection {
 /// A privilege on a database object (table, sequence, etc.).
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum Action {
     Connect,
@@ -5260,18 +6561,33 @@ impl fmt::Display for Action {
 /// Objects on which privileges are granted in a GRANT statement.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum GrantObjects {
     /// Grant privileges on `ALL SEQUENCES IN SCHEMA <schema_name> [, ...]`
     AllSequencesInSchema { schemas: Vec<ObjectName> },
     /// Grant privileges on `ALL TABLES IN SCHEMA <schema_name> [, ...]`
     AllTablesInSchema { schemas: Vec<ObjectName> },
+    /// Grant privileges on specific databases
+    Databases(Vec<ObjectName>),
+    /// Grant privileges on specific functions, optionally with their argument types
+    Functions(Vec<FunctionDesc>),
+    /// Grant privileges on specific procedures, optionally with their argument types
+    Procedures(Vec<FunctionDesc>),
     /// Grant privileges on specific schemas
     Schemas(Vec<ObjectName>),
     /// Grant privileges on specific sequences
     Sequences(Vec<ObjectName>),
+    /// Grant privileges on specific stages
+    ///
+    /// [Snowflake](https://docs.snowflake.com/en/sql-reference/sql/grant-privilege)
+    Stages(Vec<ObjectName>),
     /// Grant privileges on specific tables
     Tables(Vec<ObjectName>),
+    /// Grant privileges on specific warehouses
+    ///
+    /// [Snowflake](https://docs.snowflake.com/en/sql-reference/sql/grant-privilege)
+    Warehouses(Vec<ObjectName>),
 }
 
 impl fmt::Display for GrantObjects {
@@ -5286,6 +6602,21 @@ impl fmt::Display for GrantObjects {
             GrantObjects::Tables(tables) => {
                 write!(f, "{}", display_comma_separated(tables))
             }
+            GrantObjects::Databases(databases) => {
+                write!(f, "DATABASE {}", display_comma_separated(databases))
+            }
+            GrantObjects::Functions(functions) => {
+                write!(f, "FUNCTION {}", display_comma_separated(functions))
+            }
+            GrantObjects::Procedures(procedures) => {
+                write!(f, "PROCEDURE {}", display_comma_separated(procedures))
+            }
+            GrantObjects::Stages(stages) => {
+                write!(f, "STAGE {}", display_comma_separated(stages))
+            }
+            GrantObjects::Warehouses(warehouses) => {
+                write!(f, "WAREHOUSE {}", display_comma_separated(warehouses))
+            }
             GrantObjects::AllSequencesInSchema { schemas } => {
                 write!(
                     f,
@@ -5304,9 +6635,60 @@ impl fmt::Display for GrantObjects {
     }
 }
 
+/// The kind of the grantees of a GRANT or REVOKE statement
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum GranteesType {
+    Role,
+    User,
+    Share,
+}
+
+impl fmt::Display for GranteesType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            GranteesType::Role => "ROLE",
+            GranteesType::User => "USER",
+            GranteesType::Share => "SHARE",
+        })
+    }
+}
+
+fn display_grantees(
+    f: &mut fmt::Formatter,
+    grantees_type: &Option<GranteesType>,
+    grantees: &[Ident],
+) -> fmt::Result {
+    if let Some(grantees_type) = grantees_type {
+        write!(f, "{grantees_type} ")?;
+    }
+    write!(f, "{}", display_comma_separated(grantees))
+}
+
+/// A `<old_name> TO <new_name>` pair of a [Statement::RenameTable]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct RenameTable {
+    #[cfg_attr(feature = "visitor", visit(with = "visit_relation"))]
+    pub old_name: ObjectName,
+    #[cfg_attr(feature = "visitor", visit(with = "visit_relation"))]
+    pub new_name: ObjectName,
+}
+
+impl fmt::Display for RenameTable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} TO {}", self.old_name, self.new_name)
+    }
+}
+
 /// SQL assignment `foo = expr` as used in SQLUpdate
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct Assignment {
     pub target: AssignmentTarget,
@@ -5320,16 +6702,24 @@ impl fmt::Display for Assignment {
 }
 
 /// Left-hand side of an assignment in an UPDATE statement,
-/// e.g. `foo` in `foo = 5` (ColumnName assignment) or
-/// `(a, b)` in `(a, b) = (1, 2)` (Tuple assignment).
+/// e.g. `foo` in `foo = 5` (ColumnName assignment),
+/// `(a, b)` in `(a, b) = (1, 2)` (Tuple assignment) or
+/// `arr[2]` in `arr[2] = 5` (Element assignment).
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum AssignmentTarget {
     /// A single column
     ColumnName(ObjectName),
     /// A tuple of columns
     Tuple(Vec<ObjectName>),
+    /// An element, slice or field of a column, i.e. a chain of
+    /// [Expr::Subscript] and [Expr::CompositeAccess] on a column, e.g.
+    /// `arr[2]`, `arr[:3]`, `jsonb_col['a']` or `col[1].field`
+    ///
+    /// See [PostgreSQL](https://www.postgresql.org/docs/current/sql-update.html)
+    Element(Expr),
 }
 
 impl fmt::Display for AssignmentTarget {
@@ -5337,12 +6727,14 @@ impl fmt::Display for AssignmentTarget {
         match self {
             AssignmentTarget::ColumnName(column) => write!(f, "{}", column),
             AssignmentTarget::Tuple(columns) => write!(f, "({})", display_comma_separated(columns)),
+            AssignmentTarget::Element(element) => write!(f, "{element}"),
         }
     }
 }
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum FunctionArgExpr {
     Expr(Expr),
@@ -5374,6 +6766,7 @@ impl fmt::Display for FunctionArgExpr {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 /// Operator used to separate function arguments
 pub enum FunctionArgOperator {
@@ -5397,6 +6790,7 @@ impl fmt::Display for FunctionArgOperator {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum FunctionArg {
     Named {
@@ -5422,6 +6816,7 @@ impl fmt::Display for FunctionArg {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum CloseCursor {
     All,
@@ -5440,9 +6835,16 @@ impl fmt::Display for CloseCursor {
 /// A function call
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct Function {
     pub name: ObjectName,
+    /// Whether the call has the BigQuery `SAFE.` prefix, which makes it
+    /// return `NULL` instead of raising an error. The prefix is not part of
+    /// [`Function::name`].
+    ///
+    /// [BigQuery](https://cloud.google.com/bigquery/docs/reference/standard-sql/functions-reference#safe_prefix)
+    pub safe: bool,
     /// The parameters to the function, including any options specified within the
     /// delimiting parentheses.
     ///
@@ -5481,6 +6883,9 @@ pub struct Function {
 
 impl fmt::Display for Function {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.safe {
+            write!(f, "SAFE.")?;
+        }
         write!(f, "{}{}{}", self.name, self.parameters, self.args)?;
 
         if !self.within_group.is_empty() {
@@ -5510,6 +6915,7 @@ impl fmt::Display for Function {
 /// The arguments passed to a function call.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum FunctionArguments {
     /// Used for special functions like `CURRENT_TIMESTAMP` that are invoked
@@ -5536,6 +6942,7 @@ impl fmt::Display for FunctionArguments {
 /// This represents everything inside the parentheses when calling a function.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct FunctionArgumentList {
     /// `[ ALL | DISTINCT ]`
@@ -5561,6 +6968,7 @@ impl fmt::Display for FunctionArgumentList {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum FunctionArgumentClause {
     /// Indicates how `NULL`s should be handled in the calculation, e.g. in `FIRST_VALUE` on [BigQuery].
@@ -5616,6 +7024,7 @@ impl fmt::Display for FunctionArgumentClause {
 
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum DuplicateTreatment {
     /// Perform the calculation only unique values.
@@ -5635,6 +7044,7 @@ impl fmt::Display for DuplicateTreatment {
 
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum AnalyzeFormat {
     TEXT,
@@ -5655,6 +7065,7 @@ impl fmt::Display for AnalyzeFormat {
 /// External table's available file format
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum FileFormat {
     TEXTFILE,
@@ -5684,6 +7095,7 @@ impl fmt::Display for FileFormat {
 /// The `ON OVERFLOW` clause of a LISTAGG invocation
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum ListAggOnOverflow {
     /// `ON OVERFLOW ERROR`
@@ -5720,6 +7132,7 @@ impl fmt::Display for ListAggOnOverflow {
 /// The `HAVING` clause in a call to `ANY_VALUE` on BigQuery.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct HavingBound(pub HavingBoundKind, pub Expr);
 
@@ -5731,6 +7144,7 @@ impl fmt::Display for HavingBound {
 
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum HavingBoundKind {
     Min,
@@ -5748,6 +7162,7 @@ impl fmt::Display for HavingBoundKind {
 
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum ObjectType {
     Table,
@@ -5759,6 +7174,10 @@ pub enum ObjectType {
     Sequence,
     Stage,
     Type,
+    Event,
+    Collation,
+    Task,
+    Statistics,
 }
 
 impl fmt::Display for ObjectType {
@@ -5773,12 +7192,17 @@ impl fmt::Display for ObjectType {
             ObjectType::Sequence => "SEQUENCE",
             ObjectType::Stage => "STAGE",
             ObjectType::Type => "TYPE",
+            ObjectType::Event => "EVENT",
+            ObjectType::Collation => "COLLATION",
+            ObjectType::Task => "TASK",
+            ObjectType::Statistics => "STATISTICS",
         })
     }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum KillType {
     Connection,
@@ -5798,8 +7222,9 @@ impl fmt::Display for KillType {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Default, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum HiveDistributionStyle {
     PARTITIONED {
@@ -5810,11 +7235,13 @@ pub enum HiveDistributionStyle {
         on: Vec<ColumnDef>,
         stored_as_directories: bool,
     },
+    #[default]
     NONE,
 }
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum HiveRowFormat {
     SERDE { class: String },
@@ -5823,6 +7250,7 @@ pub enum HiveRowFormat {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct HiveRowDelimiter {
     pub delimiter: HiveDelimiter,
@@ -5838,6 +7266,7 @@ impl fmt::Display for HiveRowDelimiter {
 
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum HiveDelimiter {
     FieldsTerminatedBy,
@@ -5864,6 +7293,7 @@ impl fmt::Display for HiveDelimiter {
 
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum HiveDescribeFormat {
     Extended,
@@ -5882,6 +7312,7 @@ impl fmt::Display for HiveDescribeFormat {
 
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum DescribeAlias {
     Describe,
@@ -5902,6 +7333,7 @@ impl fmt::Display for DescribeAlias {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 #[allow(clippy::large_enum_variant)]
 pub enum HiveIOFormat {
@@ -5916,6 +7348,7 @@ pub enum HiveIOFormat {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct HiveFormat {
     pub row_format: Option<HiveRowFormat>,
@@ -5926,6 +7359,7 @@ pub struct HiveFormat {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct ClusteredIndex {
     pub name: Ident,
@@ -5945,6 +7379,7 @@ impl fmt::Display for ClusteredIndex {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum TableOptionsClustered {
     ColumnstoreIndex,
@@ -5975,6 +7410,7 @@ impl fmt::Display for TableOptionsClustered {
 /// Specifies which partition the boundary values on table partitioning belongs to.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum PartitionRangeDirection {
     Left,
@@ -5983,6 +7419,7 @@ pub enum PartitionRangeDirection {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum SqlOption {
     /// Clustered represents the clustered version of table storage for MSSQL.
@@ -6045,6 +7482,7 @@ impl fmt::Display for SqlOption {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct SecretOption {
     pub key: Ident,
@@ -6059,6 +7497,7 @@ impl fmt::Display for SecretOption {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum AttachDuckDBDatabaseOption {
     ReadOnly(Option<bool>),
@@ -6078,6 +7517,7 @@ impl fmt::Display for AttachDuckDBDatabaseOption {
 
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum TransactionMode {
     AccessMode(TransactionAccessMode),
@@ -6096,6 +7536,7 @@ impl fmt::Display for TransactionMode {
 
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum TransactionAccessMode {
     ReadOnly,
@@ -6114,6 +7555,7 @@ impl fmt::Display for TransactionAccessMode {
 
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum TransactionIsolationLevel {
     ReadUncommitted,
@@ -6139,6 +7581,7 @@ impl fmt::Display for TransactionIsolationLevel {
 /// <https://sqlite.org/lang_transaction.html>
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum TransactionModifier {
     Deferred,
@@ -6159,6 +7602,7 @@ impl fmt::Display for TransactionModifier {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum ShowStatementFilter {
     Like(String),
@@ -6183,6 +7627,7 @@ impl fmt::Display for ShowStatementFilter {
 /// for more details.
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum SqliteOnConflict {
     Rollback,
@@ -6212,6 +7657,7 @@ impl fmt::Display for SqliteOnConflict {
 /// for more details.
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum MysqlInsertPriority {
     LowPriority,
@@ -6232,6 +7678,7 @@ impl fmt::Display for crate::ast::MysqlInsertPriority {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum CopySource {
     Table {
@@ -6246,6 +7693,7 @@ pub enum CopySource {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum CopyTarget {
     Stdin,
@@ -6278,6 +7726,7 @@ impl fmt::Display for CopyTarget {
 
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OnCommit {
     DeleteRows,
@@ -6290,6 +7739,7 @@ pub enum OnCommit {
 /// <https://www.postgresql.org/docs/14/sql-copy.html>
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum CopyOption {
     /// FORMAT format_name
@@ -6300,20 +7750,34 @@ pub enum CopyOption {
     Delimiter(char),
     /// NULL 'null_string'
     Null(String),
+    /// DEFAULT 'default_string'
+    Default(String),
     /// HEADER \[ boolean \]
     Header(bool),
+    /// HEADER MATCH
+    HeaderMatch,
     /// QUOTE 'quote_character'
     Quote(char),
     /// ESCAPE 'escape_character'
     Escape(char),
-    /// FORCE_QUOTE { ( column_name [, ...] ) | * }
+    /// FORCE_QUOTE ( column_name [, ...] )
     ForceQuote(Vec<Ident>),
+    /// FORCE_QUOTE *
+    ForceQuoteAll,
     /// FORCE_NOT_NULL ( column_name [, ...] )
     ForceNotNull(Vec<Ident>),
+    /// FORCE_NOT_NULL *
+    ForceNotNullAll,
     /// FORCE_NULL ( column_name [, ...] )
     ForceNull(Vec<Ident>),
+    /// FORCE_NULL *
+    ForceNullAll,
+    /// ON_ERROR error_action
+    OnError(Ident),
     /// ENCODING 'encoding_name'
     Encoding(String),
+    /// LOG_VERBOSITY verbosity
+    LogVerbosity(Ident),
 }
 
 impl fmt::Display for CopyOption {
@@ -6325,16 +7789,25 @@ impl fmt::Display for CopyOption {
             Freeze(false) => write!(f, "FREEZE FALSE"),
             Delimiter(char) => write!(f, "DELIMITER '{char}'"),
             Null(string) => write!(f, "NULL '{}'", value::escape_single_quote_string(string)),
+            Default(string) => {
+                write!(f, "DEFAULT '{}'", value::escape_single_quote_string(string))
+            }
             Header(true) => write!(f, "HEADER"),
             Header(false) => write!(f, "HEADER FALSE"),
+            HeaderMatch => write!(f, "HEADER MATCH"),
             Quote(char) => write!(f, "QUOTE '{char}'"),
             Escape(char) => write!(f, "ESCAPE '{char}'"),
             ForceQuote(columns) => write!(f, "FORCE_QUOTE ({})", display_comma_separated(columns)),
+            ForceQuoteAll => write!(f, "FORCE_QUOTE *"),
             ForceNotNull(columns) => {
                 write!(f, "FORCE_NOT_NULL ({})", display_comma_separated(columns))
             }
+            ForceNotNullAll => write!(f, "FORCE_NOT_NULL *"),
             ForceNull(columns) => write!(f, "FORCE_NULL ({})", display_comma_separated(columns)),
+            ForceNullAll => write!(f, "FORCE_NULL *"),
+            OnError(action) => write!(f, "ON_ERROR {action}"),
             Encoding(name) => write!(f, "ENCODING '{}'", value::escape_single_quote_string(name)),
+            LogVerbosity(verbosity) => write!(f, "LOG_VERBOSITY {verbosity}"),
         }
     }
 }
@@ -6344,6 +7817,7 @@ impl fmt::Display for CopyOption {
 /// <https://www.postgresql.org/docs/8.4/sql-copy.html>
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum CopyLegacyOption {
     /// BINARY
@@ -6373,6 +7847,7 @@ impl fmt::Display for CopyLegacyOption {
 /// <https://www.postgresql.org/docs/8.4/sql-copy.html>
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum CopyLegacyCsvOption {
     /// HEADER
@@ -6402,6 +7877,77 @@ impl fmt::Display for CopyLegacyCsvOption {
     }
 }
 
+/// The format of the inline data of a `COPY ... FROM STDIN` statement, as
+/// given by its options.
+///
+/// <https://www.postgresql.org/docs/current/sql-copy.html#id-1.9.3.55.9>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CopyDataFormat {
+    /// Whether the data is in `CSV` rather than `TEXT` format
+    pub csv: bool,
+    /// Whether the data is in `BINARY` format
+    pub binary: bool,
+    /// The character separating the columns of a row
+    pub delimiter: char,
+    /// The string representing a null value
+    pub null: String,
+    /// The quoting character of `CSV` values
+    pub quote: char,
+    /// The character escaping a quoting character in a quoted `CSV` value
+    pub escape: char,
+}
+
+impl CopyDataFormat {
+    /// Returns the format given by the options of a `COPY` statement
+    pub fn new(options: &[CopyOption], legacy_options: &[CopyLegacyOption]) -> Self {
+        let mut csv = false;
+        let mut binary = false;
+        let mut delimiter = None;
+        let mut null = None;
+        let mut quote = None;
+        let mut escape = None;
+        for option in options {
+            match option {
+                CopyOption::Format(name) => {
+                    csv = name.value.eq_ignore_ascii_case("csv");
+                    binary = name.value.eq_ignore_ascii_case("binary");
+                }
+                CopyOption::Delimiter(c) => delimiter = Some(*c),
+                CopyOption::Null(s) => null = Some(s.clone()),
+                CopyOption::Quote(c) => quote = Some(*c),
+                CopyOption::Escape(c) => escape = Some(*c),
+                _ => {}
+            }
+        }
+        for option in legacy_options {
+            match option {
+                CopyLegacyOption::Binary => binary = true,
+                CopyLegacyOption::Delimiter(c) => delimiter = Some(*c),
+                CopyLegacyOption::Null(s) => null = Some(s.clone()),
+                CopyLegacyOption::Csv(csv_options) => {
+                    csv = true;
+                    for option in csv_options {
+                        match option {
+                            CopyLegacyCsvOption::Quote(c) => quote = Some(*c),
+                            CopyLegacyCsvOption::Escape(c) => escape = Some(*c),
+                            _ => {}
+                        }
+                    }
+                }
+            }
+        }
+        let quote = quote.unwrap_or('"');
+        Self {
+            csv,
+            binary,
+            delimiter: delimiter.unwrap_or(if csv { ',' } else { '\t' }),
+            null: null.unwrap_or_else(|| if csv { "" } else { "\\N" }.to_string()),
+            quote,
+            escape: escape.unwrap_or(quote),
+        }
+    }
+}
+
 /// Variant of `WHEN` clause used within a `MERGE` Statement.
 ///
 /// Example:
@@ -6412,6 +7958,7 @@ impl fmt::Display for CopyLegacyCsvOption {
 /// [BigQuery](https://cloud.google.com/bigquery/docs/reference/standard-sql/dml-syntax#merge_statement)
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum MergeClauseKind {
     /// `WHEN MATCHED`
@@ -6445,6 +7992,7 @@ impl Display for MergeClauseKind {
 /// [BigQuery](https://cloud.google.com/bigquery/docs/reference/standard-sql/dml-syntax#merge_statement)
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum MergeInsertKind {
     /// The insert expression is defined from an explicit `VALUES` clause
@@ -6489,6 +8037,7 @@ impl Display for MergeInsertKind {
 /// [BigQuery](https://cloud.google.com/bigquery/docs/reference/standard-sql/dml-syntax#merge_statement)
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct MergeInsertExpr {
     /// Columns (if any) specified by the insert.
@@ -6523,6 +8072,7 @@ impl Display for MergeInsertExpr {
 /// [BigQuery](https://cloud.google.com/bigquery/docs/reference/standard-sql/dml-syntax#merge_statement)
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum MergeAction {
     /// An `INSERT` clause
@@ -6538,7 +8088,14 @@ pub enum MergeAction {
     /// ```sql
     /// UPDATE SET quantity = T.quantity + S.quantity
     /// ```
-    Update { assignments: Vec<Assignment> },
+    Update {
+        assignments: Vec<Assignment>,
+        /// Oracle `DELETE WHERE <condition>`, deleting the updated rows
+        /// that satisfy the condition
+        ///
+        /// [Oracle](https://docs.oracle.com/en/database/oracle/oracle-database/23/sqlrf/MERGE.html)
+        delete_where: Option<Expr>,
+    },
     /// A plain `DELETE` clause
     Delete,
 }
@@ -6549,8 +8106,15 @@ impl Display for MergeAction {
             MergeAction::Insert(insert) => {
                 write!(f, "INSERT {insert}")
             }
-            MergeAction::Update { assignments } => {
-                write!(f, "UPDATE SET {}", display_comma_separated(assignments))
+            MergeAction::Update {
+                assignments,
+                delete_where,
+            } => {
+                write!(f, "UPDATE SET {}", display_comma_separated(assignments))?;
+                if let Some(delete_where) = delete_where {
+                    write!(f, " DELETE WHERE {delete_where}")?;
+                }
+                Ok(())
             }
             MergeAction::Delete => {
                 write!(f, "DELETE")
@@ -6569,6 +8133,7 @@ impl Display for MergeAction {
 /// [BigQuery](https://cloud.google.com/bigquery/docs/reference/standard-sql/dml-syntax#merge_statement)
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct MergeClause {
     pub clause_kind: MergeClauseKind,
@@ -6594,6 +8159,7 @@ impl Display for MergeClause {
 
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum DiscardObject {
     ALL,
@@ -6615,6 +8181,7 @@ impl fmt::Display for DiscardObject {
 
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum FlushType {
     BinaryLogs,
@@ -6654,6 +8221,7 @@ impl fmt::Display for FlushType {
 
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum FlushLocation {
     NoWriteToBinlog,
@@ -6672,6 +8240,7 @@ impl fmt::Display for FlushLocation {
 /// Optional context modifier for statements that can be or `LOCAL`, or `SESSION`.
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum ContextModifier {
     /// No context defined. Each dialect defines the default in this scenario.
@@ -6701,6 +8270,7 @@ impl fmt::Display for ContextModifier {
 /// Function describe in DROP FUNCTION.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 pub enum DropFunctionOption {
     Restrict,
     Cascade,
@@ -6718,6 +8288,7 @@ impl fmt::Display for DropFunctionOption {
 /// Generic function description for DROP FUNCTION and CREATE TRIGGER.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct FunctionDesc {
     pub name: ObjectName,
@@ -6737,6 +8308,7 @@ impl fmt::Display for FunctionDesc {
 /// Function argument in CREATE OR DROP FUNCTION.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OperateFunctionArg {
     pub mode: Option<ArgMode>,
@@ -6786,6 +8358,7 @@ impl fmt::Display for OperateFunctionArg {
 /// The mode of an argument in CREATE FUNCTION.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum ArgMode {
     In,
@@ -6806,6 +8379,7 @@ impl fmt::Display for ArgMode {
 /// These attributes inform the query optimizer about the behavior of the function.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum FunctionBehavior {
     Immutable,
@@ -6826,6 +8400,7 @@ impl fmt::Display for FunctionBehavior {
 /// These attributes describe the behavior of the function when called with a null argument.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum FunctionCalledOnNull {
     CalledOnNullInput,
@@ -6846,6 +8421,7 @@ impl fmt::Display for FunctionCalledOnNull {
 /// If it is safe for PostgreSQL to call the function from multiple threads at once
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum FunctionParallel {
     Unsafe,
@@ -6863,11 +8439,75 @@ impl fmt::Display for FunctionParallel {
     }
 }
 
+/// Security context in which a function is executed
+///
+/// [Postgres]: https://www.postgresql.org/docs/current/sql-createfunction.html
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum FunctionSecurity {
+    Definer,
+    Invoker,
+}
+
+impl fmt::Display for FunctionSecurity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FunctionSecurity::Definer => write!(f, "SECURITY DEFINER"),
+            FunctionSecurity::Invoker => write!(f, "SECURITY INVOKER"),
+        }
+    }
+}
+
+/// A `SET` clause of a function definition, which sets a configuration
+/// parameter for the duration of each call.
+///
+/// [Postgres]: https://www.postgresql.org/docs/current/sql-createfunction.html
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct FunctionDefinitionSetParam {
+    pub name: ObjectName,
+    pub value: FunctionSetValue,
+}
+
+impl fmt::Display for FunctionDefinitionSetParam {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SET {} {}", self.name, self.value)
+    }
+}
+
+/// The value of a [FunctionDefinitionSetParam]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum FunctionSetValue {
+    /// `TO value [, ...]`, also written as `= value [, ...]`
+    Values(Vec<Expr>),
+    /// `FROM CURRENT`
+    FromCurrent,
+}
+
+impl fmt::Display for FunctionSetValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FunctionSetValue::Values(values) => {
+                write!(f, "TO {}", display_comma_separated(values))
+            }
+            FunctionSetValue::FromCurrent => write!(f, "FROM CURRENT"),
+        }
+    }
+}
+
 /// [BigQuery] Determinism specifier used in a UDF definition.
 ///
 /// [BigQuery]: https://cloud.google.com/bigquery/docs/reference/standard-sql/data-definition-language#syntax_11
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum FunctionDeterminismSpecifier {
     Deterministic,
@@ -6894,6 +8534,7 @@ impl fmt::Display for FunctionDeterminismSpecifier {
 /// [Postgres]: https://www.postgresql.org/docs/15/sql-createfunction.html
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum CreateFunctionBody {
     /// A function body expression using the 'AS' keyword and shows up
@@ -6931,10 +8572,278 @@ pub enum CreateFunctionBody {
     ///
     /// [Postgres]: https://www.postgresql.org/docs/current/sql-createfunction.html
     Return(Expr),
+    /// A PL/pgSQL function body using the 'AS' keyword, parsed into a
+    /// script when [ParserOptions::parse_script_bodies] is enabled.
+    ///
+    /// Example:
+    /// ```sql
+    /// CREATE FUNCTION myfunc(a INTEGER) RETURNS INTEGER
+    /// LANGUAGE plpgsql
+    /// AS $$ BEGIN RETURN a + 1; END $$;
+    /// ```
+    ///
+    /// [ParserOptions::parse_script_bodies]: crate::parser::ParserOptions::parse_script_bodies
+    AsScript(script::ScriptBlock),
+}
+
+/// The body of a `CREATE PROCEDURE` statement.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum CreateProcedureBody {
+    /// Statements enclosed in `BEGIN ... END`.
+    ///
+    /// Example:
+    /// ```sql
+    /// CREATE PROCEDURE p AS BEGIN SELECT 1 END
+    /// ```
+    Statements(Vec<Statement>),
+    /// A procedure definition given as a string, typically handler code
+    /// written in a language other than SQL.
+    ///
+    /// Example:
+    /// ```sql
+    /// CREATE PROCEDURE p() RETURNS INT LANGUAGE PYTHON ... AS $$ ... $$
+    /// ```
+    ///
+    /// [Snowflake]: https://docs.snowflake.com/en/sql-reference/sql/create-procedure
+    Definition(Expr),
+    /// A Snowflake Scripting procedure definition, parsed into a script when
+    /// [ParserOptions::parse_script_bodies] is enabled.
+    ///
+    /// Example:
+    /// ```sql
+    /// CREATE PROCEDURE p() RETURNS INT LANGUAGE SQL AS $$ BEGIN RETURN 1; END $$
+    /// ```
+    ///
+    /// [ParserOptions::parse_script_bodies]: crate::parser::ParserOptions::parse_script_bodies
+    Script(script::ScriptBlock),
+    /// A MySQL routine body, a single statement that is not preceded by
+    /// `AS` and is typically a `BEGIN ... END` compound statement.
+    ///
+    /// Example:
+    /// ```sql
+    /// CREATE PROCEDURE p(IN a INT) BEGIN DECLARE b INT DEFAULT a; SELECT b; END
+    /// ```
+    ///
+    /// [MySQL](https://dev.mysql.com/doc/refman/8.0/en/create-procedure.html)
+    Routine(Box<script::ScriptStatement>),
+}
+
+impl fmt::Display for CreateProcedureBody {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CreateProcedureBody::Statements(statements) => {
+                write!(f, "AS BEGIN {} END", display_separated(statements, "; "))
+            }
+            CreateProcedureBody::Definition(definition) => write!(f, "AS {definition}"),
+            CreateProcedureBody::Script(script) => write!(f, "AS $$ {script} $$"),
+            CreateProcedureBody::Routine(statement) => write!(f, "{statement}"),
+        }
+    }
+}
+
+/// The code of a `DO` statement.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum DoBody {
+    /// The code as a string literal, typically dollar-quoted.
+    ///
+    /// Example:
+    /// ```sql
+    /// DO $$ BEGIN RAISE NOTICE 'hi'; END $$
+    /// ```
+    Definition(Expr),
+    /// PL/pgSQL code, parsed into a script when
+    /// [ParserOptions::parse_script_bodies] is enabled.
+    ///
+    /// [ParserOptions::parse_script_bodies]: crate::parser::ParserOptions::parse_script_bodies
+    Script(script::ScriptBlock),
+    /// The expressions evaluated by a MySQL `DO` statement.
+    ///
+    /// Example:
+    /// ```sql
+    /// DO SLEEP(1), RELEASE_LOCK('lock')
+    /// ```
+    Expressions(Vec<Expr>),
+}
+
+impl fmt::Display for DoBody {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DoBody::Definition(definition) => write!(f, "{definition}"),
+            DoBody::Script(script) => write!(f, "$$ {script} $$"),
+            DoBody::Expressions(exprs) => write!(f, "{}", display_comma_separated(exprs)),
+        }
+    }
+}
+
+/// The new password of a MySQL [`Statement::SetPassword`].
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum SetPasswordValue {
+    /// `= 'auth_string'`, or the deprecated `= PASSWORD('auth_string')`
+    Expr(Expr),
+    /// `TO RANDOM`
+    Random,
+}
+
+impl fmt::Display for SetPasswordValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SetPasswordValue::Expr(expr) => write!(f, "= {expr}"),
+            SetPasswordValue::Random => write!(f, "TO RANDOM"),
+        }
+    }
+}
+
+/// The operation of a MySQL [`Statement::Handler`].
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum HandlerOperation {
+    /// `OPEN [ [AS] alias ]`
+    Open { alias: Option<Ident> },
+    /// ```sql
+    /// READ [ index_name ] position [ WHERE where_condition ] [ LIMIT [offset,] row_count ]
+    /// ```
+    Read {
+        index_name: Option<Ident>,
+        position: HandlerReadPosition,
+        selection: Option<Expr>,
+        offset: Option<Expr>,
+        limit: Option<Expr>,
+    },
+    /// `CLOSE`
+    Close,
+}
+
+impl fmt::Display for HandlerOperation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HandlerOperation::Open { alias } => {
+                write!(f, "OPEN")?;
+                if let Some(alias) = alias {
+                    write!(f, " AS {alias}")?;
+                }
+                Ok(())
+            }
+            HandlerOperation::Read {
+                index_name,
+                position,
+                selection,
+                offset,
+                limit,
+            } => {
+                write!(f, "READ")?;
+                if let Some(index_name) = index_name {
+                    write!(f, " {index_name}")?;
+                }
+                write!(f, " {position}")?;
+                if let Some(selection) = selection {
+                    write!(f, " WHERE {selection}")?;
+                }
+                if let Some(limit) = limit {
+                    write!(f, " LIMIT ")?;
+                    if let Some(offset) = offset {
+                        write!(f, "{offset}, ")?;
+                    }
+                    write!(f, "{limit}")?;
+                }
+                Ok(())
+            }
+            HandlerOperation::Close => write!(f, "CLOSE"),
+        }
+    }
+}
+
+/// The rows read by `HANDLER ... READ`.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum HandlerReadPosition {
+    First,
+    Next,
+    Prev,
+    Last,
+    /// The rows whose index key compares to `values` with `op`, one of
+    /// `=`, `<=`, `>=`, `<` or `>`, e.g. `READ idx >= (1, 'a')`
+    Compare {
+        op: BinaryOperator,
+        values: Vec<Expr>,
+    },
+}
+
+impl fmt::Display for HandlerReadPosition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HandlerReadPosition::First => write!(f, "FIRST"),
+            HandlerReadPosition::Next => write!(f, "NEXT"),
+            HandlerReadPosition::Prev => write!(f, "PREV"),
+            HandlerReadPosition::Last => write!(f, "LAST"),
+            HandlerReadPosition::Compare { op, values } => {
+                write!(f, "{op} ({})", display_comma_separated(values))
+            }
+        }
+    }
+}
+
+/// Writes the properties of a function or procedure whose handler is
+/// written in a language other than SQL.
+///
+/// [Snowflake]: https://docs.snowflake.com/en/sql-reference/sql/create-function
+fn display_handler_properties(
+    f: &mut fmt::Formatter,
+    runtime_version: &Option<String>,
+    packages: &Option<Vec<String>>,
+    imports: &Option<Vec<String>>,
+    handler: &Option<String>,
+) -> fmt::Result {
+    fn display_string_list(f: &mut fmt::Formatter, list: &[String]) -> fmt::Result {
+        let mut delim = "";
+        for item in list {
+            write!(f, "{delim}'{}'", value::escape_single_quote_string(item))?;
+            delim = ", ";
+        }
+        Ok(())
+    }
+    if let Some(runtime_version) = runtime_version {
+        write!(
+            f,
+            " RUNTIME_VERSION = '{}'",
+            value::escape_single_quote_string(runtime_version)
+        )?;
+    }
+    if let Some(packages) = packages {
+        write!(f, " PACKAGES = (")?;
+        display_string_list(f, packages)?;
+        write!(f, ")")?;
+    }
+    if let Some(imports) = imports {
+        write!(f, " IMPORTS = (")?;
+        display_string_list(f, imports)?;
+        write!(f, ")")?;
+    }
+    if let Some(handler) = handler {
+        write!(
+            f,
+            " HANDLER = '{}'",
+            value::escape_single_quote_string(handler)
+        )?;
+    }
+    Ok(())
 }
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum CreateFunctionUsing {
     Jar(String),
@@ -6959,6 +8868,7 @@ impl fmt::Display for CreateFunctionUsing {
 /// for more details
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct MacroArg {
     pub name: Ident,
@@ -6987,6 +8897,7 @@ impl fmt::Display for MacroArg {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum MacroDefinition {
     Expr(Expr),
@@ -7008,6 +8919,7 @@ impl fmt::Display for MacroDefinition {
 /// [1]: https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#schema-definition
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum SchemaName {
     /// Only schema name specified: `<schema name>`.
@@ -7039,6 +8951,7 @@ impl fmt::Display for SchemaName {
 /// [1]: https://dev.mysql.com/doc/refman/8.0/en/fulltext-search.html#function_match
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum SearchModifier {
     /// `IN NATURAL LANGUAGE MODE`.
@@ -7074,6 +8987,7 @@ impl fmt::Display for SearchModifier {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct LockTable {
     pub table: Ident,
@@ -7100,6 +9014,7 @@ impl fmt::Display for LockTable {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum LockTableType {
     Read { local: bool },
@@ -7129,6 +9044,7 @@ impl fmt::Display for LockTableType {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct HiveSetLocation {
     pub has_set: bool,
@@ -7148,6 +9064,7 @@ impl fmt::Display for HiveSetLocation {
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum MySQLColumnPosition {
     First,
@@ -7158,10 +9075,7 @@ impl Display for MySQLColumnPosition {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             MySQLColumnPosition::First => Ok(write!(f, "FIRST")?),
-            MySQLColumnPosition::After(ident) => {
-                let column_name = &ident.value;
-                Ok(write!(f, "AFTER {column_name}")?)
-            }
+            MySQLColumnPosition::After(ident) => Ok(write!(f, "AFTER {ident}")?),
         }
     }
 }
@@ -7171,6 +9085,7 @@ impl Display for MySQLColumnPosition {
 /// [clickhouse]: https://clickhouse.com/docs/en/engines/table-engines
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct TableEngine {
     pub name: String,
@@ -7189,12 +9104,51 @@ impl Display for TableEngine {
     }
 }
 
+/// The storage of a ClickHouse materialized view that has no `TO` table:
+///
+/// ```sql
+/// ENGINE = engine [ PRIMARY KEY expr ] [ ORDER BY expr ] [ PARTITION BY expr ] [ SETTINGS name = value, ... ]
+/// ```
+///
+/// <https://clickhouse.com/docs/en/sql-reference/statements/create/view#materialized-view>
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct ViewEngine {
+    pub engine: TableEngine,
+    pub primary_key: Option<Box<Expr>>,
+    pub order_by: Option<OneOrManyWithParens<Expr>>,
+    pub partition_by: Option<Box<Expr>>,
+    pub settings: Option<Vec<Setting>>,
+}
+
+impl Display for ViewEngine {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ENGINE={}", self.engine)?;
+        if let Some(primary_key) = &self.primary_key {
+            write!(f, " PRIMARY KEY {primary_key}")?;
+        }
+        if let Some(order_by) = &self.order_by {
+            write!(f, " ORDER BY {order_by}")?;
+        }
+        if let Some(partition_by) = &self.partition_by {
+            write!(f, " PARTITION BY {partition_by}")?;
+        }
+        if let Some(settings) = &self.settings {
+            write!(f, " SETTINGS {}", display_comma_separated(settings))?;
+        }
+        Ok(())
+    }
+}
+
 /// Snowflake `WITH ROW ACCESS POLICY policy_name ON (identifier, ...)`
 ///
 /// <https://docs.snowflake.com/en/sql-reference/sql/create-table>
 /// <https://docs.snowflake.com/en/user-guide/security-row-intro>
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct RowAccessPolicy {
     pub policy: ObjectName,
@@ -7223,6 +9177,7 @@ impl Display for RowAccessPolicy {
 /// <https://docs.snowflake.com/en/sql-reference/sql/create-table>
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct Tag {
     pub key: Ident,
@@ -7244,6 +9199,7 @@ impl Display for Tag {
 /// Helper to indicate if a comment includes the `=` in the display form
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum CommentDef {
     /// Includes `=` when printing the comment, as `COMMENT = 'comment'`
@@ -7282,6 +9238,7 @@ impl Display for CommentDef {
 /// ```
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum WrappedCollection<T> {
     /// Print the collection without wrapping symbols, as `item, item, item`
@@ -7331,6 +9288,7 @@ where
 /// ```
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct UtilityOption {
     pub name: Ident,
@@ -0,0 +1,532 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Precedence-aware insertion of parentheses into expressions.
+//!
+//! The parser only produces [`Expr::Nested`] where the source had
+//! parentheses, so an expression tree that was built or rewritten
+//! programmatically may not survive a round-trip through its SQL text:
+//! `a OR b` as the left operand of `AND` is displayed as `a OR b AND c`,
+//! which parses as `a OR (b AND c)`.
+//!
+//! [`add_parentheses`] wraps every operand that binds less tightly than
+//! its operator requires in an [`Expr::Nested`], following the precedence
+//! rules of the parser for the default dialect precedences (see
+//! [`Dialect::prec_value`]). The resulting SQL parses back to the same tree,
+//! apart from the added [`Expr::Nested`] expressions.
+//!
+//! ```
+//! # use sqlparser::ast::{BinaryOperator, Expr, Ident};
+//! let a_or_b = Expr::BinaryOp {
+//!     left: Box::new(Expr::Identifier(Ident::new("a"))),
+//!     op: BinaryOperator::Or,
+//!     right: Box::new(Expr::Identifier(Ident::new("b"))),
+//! };
+//! let expr = Expr::BinaryOp {
+//!     left: Box::new(a_or_b),
+//!     op: BinaryOperator::And,
+//!     right: Box::new(Expr::Identifier(Ident::new("c"))),
+//! };
+//! assert_eq!(expr.to_string(), "a OR b AND c");
+//! assert_eq!(expr.to_sql_parenthesized(), "(a OR b) AND c");
+//! ```
+
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, string::String, string::ToString};
+use core::mem;
+use core::ops::ControlFlow;
+
+use crate::ast::{BinaryOperator, CastKind, Expr, UnaryOperator, VisitMut, VisitorMut};
+use crate::dialect::{Dialect, GenericDialect, Precedence};
+
+/// The precedence of expressions that aren't operator applications, e.g.
+/// identifiers, literals and function calls.
+const ATOM: u8 = u8::MAX;
+
+impl Expr {
+    /// Returns the SQL text of this expression with the parentheses needed
+    /// to parse it back to the same tree, see [`add_parentheses`].
+    pub fn to_sql_parenthesized(&self) -> String {
+        let mut expr = self.clone();
+        add_parentheses(&mut expr);
+        expr.to_string()
+    }
+}
+
+/// Wraps the operands of all the expressions in `node` that would otherwise
+/// be parsed as part of a different expression in [`Expr::Nested`].
+pub fn add_parentheses<V: VisitMut>(node: &mut V) {
+    let _ = node.visit(&mut Parenthesize);
+}
+
+struct Parenthesize;
+
+impl VisitorMut for Parenthesize {
+    type Break = ();
+
+    fn post_visit_expr(&mut self, expr: &mut Expr) -> ControlFlow<Self::Break> {
+        // Operands are visited, and parenthesized, before their operator
+        match expr {
+            Expr::BinaryOp { left, op, right } => {
+                let precedence = binary_precedence(op);
+                nest_left(left, precedence);
+                nest_right(right, precedence);
+            }
+            Expr::AnyOp {
+                left, compare_op, ..
+            }
+            | Expr::AllOp {
+                left, compare_op, ..
+            } => nest_left(left, binary_precedence(compare_op)),
+            Expr::UnaryOp {
+                op: UnaryOperator::PGPostfixFactorial,
+                expr,
+            } => nest_left(expr, prec(Precedence::DoubleColon)),
+            Expr::UnaryOp { op, expr } => {
+                nest_right(expr, unary_precedence(op));
+                // `- -a` would be displayed as the comment `--a`
+                if matches!(op, UnaryOperator::Minus | UnaryOperator::Plus)
+                    && matches!(
+                        **expr,
+                        Expr::UnaryOp {
+                            op: UnaryOperator::Minus | UnaryOperator::Plus,
+                            ..
+                        }
+                    )
+                {
+                    nest(expr);
+                }
+            }
+            Expr::IsFalse(expr)
+            | Expr::IsNotFalse(expr)
+            | Expr::IsTrue(expr)
+            | Expr::IsNotTrue(expr)
+            | Expr::IsNull(expr)
+            | Expr::IsNotNull(expr)
+            | Expr::IsUnknown(expr)
+            | Expr::IsNotUnknown(expr)
+            | Expr::IsDistinctFrom(expr, _)
+            | Expr::IsNotDistinctFrom(expr, _) => nest_left(expr, prec(Precedence::Is)),
+            Expr::InList { expr, .. }
+            | Expr::InSubquery { expr, .. }
+            | Expr::InUnnest { expr, .. } => nest_left(expr, prec(Precedence::Between)),
+            Expr::Between {
+                expr, low, high, ..
+            } => {
+                let precedence = prec(Precedence::Between);
+                nest_left(expr, precedence);
+                // `low` must also end before the `AND` that follows it
+                if precedence_of(low) <= precedence || trailing_precedence(low) <= precedence {
+                    nest(low);
+                }
+                nest_right(high, precedence);
+            }
+            Expr::Like { expr, pattern, .. }
+            | Expr::ILike { expr, pattern, .. }
+            | Expr::SimilarTo { expr, pattern, .. }
+            | Expr::RLike { expr, pattern, .. } => {
+                let precedence = prec(Precedence::Like);
+                nest_left(expr, precedence);
+                nest_right(pattern, precedence);
+            }
+            Expr::AtTimeZone {
+                timestamp,
+                time_zone,
+            } => {
+                let precedence = prec(Precedence::AtTz);
+                nest_left(timestamp, precedence);
+                nest_right(time_zone, precedence);
+            }
+            Expr::Cast {
+                kind: CastKind::DoubleColon,
+                expr,
+                ..
+            }
+            | Expr::Subscript { expr, .. }
+            | Expr::MapAccess { column: expr, .. }
+            | Expr::JsonAccess { value: expr, .. } => {
+                nest_left(expr, prec(Precedence::DoubleColon))
+            }
+            // `(a).b` rather than the compound identifier `a.b`
+            Expr::CompositeAccess { expr, .. } if !matches!(**expr, Expr::Nested(_)) => nest(expr),
+            // `COLLATE` applies to the operand right before it
+            Expr::Collate { expr, .. } if precedence_of(expr) < ATOM => nest(expr),
+            Expr::Prior(expr) => nest_right(expr, prec(Precedence::PlusMinus)),
+            _ => {}
+        }
+        ControlFlow::Continue(())
+    }
+}
+
+fn nest(expr: &mut Expr) {
+    let inner = mem::replace(expr, Expr::Wildcard);
+    *expr = Expr::Nested(Box::new(inner));
+}
+
+/// Parenthesizes the operand to the left of an operator of `precedence`,
+/// which must neither bind less tightly than the operator nor end with an
+/// operand that would take the operator in.
+fn nest_left(expr: &mut Expr, precedence: u8) {
+    if precedence_of(expr) < precedence || trailing_precedence(expr) < precedence {
+        nest(expr);
+    }
+}
+
+/// Parenthesizes the operand to the right of an operator of `precedence`,
+/// which is parsed up to the next operator that doesn't bind more tightly.
+fn nest_right(expr: &mut Expr, precedence: u8) {
+    if precedence_of(expr) <= precedence {
+        nest(expr);
+    }
+}
+
+fn prec(precedence: Precedence) -> u8 {
+    GenericDialect {}.prec_value(precedence)
+}
+
+/// Returns how tightly the operator at the top of `expr` binds.
+fn precedence_of(expr: &Expr) -> u8 {
+    match expr {
+        Expr::BinaryOp { op, .. } => binary_precedence(op),
+        Expr::AnyOp { compare_op, .. } | Expr::AllOp { compare_op, .. } => {
+            binary_precedence(compare_op)
+        }
+        Expr::UnaryOp {
+            op: UnaryOperator::PGPostfixFactorial,
+            ..
+        } => prec(Precedence::DoubleColon),
+        Expr::UnaryOp { op, .. } => unary_precedence(op),
+        Expr::IsFalse(_)
+        | Expr::IsNotFalse(_)
+        | Expr::IsTrue(_)
+        | Expr::IsNotTrue(_)
+        | Expr::IsNull(_)
+        | Expr::IsNotNull(_)
+        | Expr::IsUnknown(_)
+        | Expr::IsNotUnknown(_)
+        | Expr::IsDistinctFrom(..)
+        | Expr::IsNotDistinctFrom(..) => prec(Precedence::Is),
+        Expr::InList { .. }
+        | Expr::InSubquery { .. }
+        | Expr::InUnnest { .. }
+        | Expr::Between { .. } => prec(Precedence::Between),
+        Expr::Like { .. } | Expr::ILike { .. } | Expr::SimilarTo { .. } | Expr::RLike { .. } => {
+            prec(Precedence::Like)
+        }
+        Expr::AtTimeZone { .. } => prec(Precedence::AtTz),
+        Expr::Cast {
+            kind: CastKind::DoubleColon,
+            ..
+        }
+        | Expr::Subscript { .. }
+        | Expr::MapAccess { .. }
+        | Expr::JsonAccess { .. } => prec(Precedence::DoubleColon),
+        Expr::Prior(_) => prec(Precedence::PlusMinus),
+        _ => ATOM,
+    }
+}
+
+/// Returns the lowest precedence of the operators along the right edge of
+/// `expr`: the operand at the end of `expr` would extend over a following
+/// operator that binds more tightly than that.
+fn trailing_precedence(expr: &Expr) -> u8 {
+    match expr {
+        Expr::BinaryOp { op, right, .. } => binary_precedence(op).min(trailing_precedence(right)),
+        Expr::UnaryOp {
+            op: UnaryOperator::PGPostfixFactorial,
+            ..
+        } => ATOM,
+        Expr::UnaryOp { op, expr } => unary_precedence(op).min(trailing_precedence(expr)),
+        // the right operand is parsed as a whole expression
+        Expr::IsDistinctFrom(..) | Expr::IsNotDistinctFrom(..) => 0,
+        Expr::Between { high, .. } => prec(Precedence::Between).min(trailing_precedence(high)),
+        Expr::Like {
+            pattern,
+            escape_char: None,
+            ..
+        }
+        | Expr::ILike {
+            pattern,
+            escape_char: None,
+            ..
+        }
+        | Expr::SimilarTo {
+            pattern,
+            escape_char: None,
+            ..
+        }
+        | Expr::RLike { pattern, .. } => prec(Precedence::Like).min(trailing_precedence(pattern)),
+        Expr::AtTimeZone { time_zone, .. } => {
+            prec(Precedence::AtTz).min(trailing_precedence(time_zone))
+        }
+        Expr::Prior(expr) => prec(Precedence::PlusMinus).min(trailing_precedence(expr)),
+        _ => ATOM,
+    }
+}
+
+/// Returns the precedence the operand of a prefix operator is parsed with.
+fn unary_precedence(op: &UnaryOperator) -> u8 {
+    match op {
+        UnaryOperator::Plus | UnaryOperator::Minus => prec(Precedence::MulDivModOp),
+        UnaryOperator::Not => prec(Precedence::UnaryNot),
+        UnaryOperator::PGPostfixFactorial => prec(Precedence::DoubleColon),
+        UnaryOperator::PGBitwiseNot
+        | UnaryOperator::PGSquareRoot
+        | UnaryOperator::PGCubeRoot
+        | UnaryOperator::PGPrefixFactorial
+        | UnaryOperator::PGAbs => prec(Precedence::PlusMinus),
+    }
+}
+
+fn binary_precedence(op: &BinaryOperator) -> u8 {
+    prec(match op {
+        BinaryOperator::Or => Precedence::Or,
+        BinaryOperator::And => Precedence::And,
+        BinaryOperator::Xor => Precedence::Xor,
+        BinaryOperator::Eq
+        | BinaryOperator::NotEq
+        | BinaryOperator::Lt
+        | BinaryOperator::LtEq
+        | BinaryOperator::Gt
+        | BinaryOperator::GtEq
+        | BinaryOperator::Spaceship
+        | BinaryOperator::PGRegexMatch
+        | BinaryOperator::PGRegexIMatch
+        | BinaryOperator::PGRegexNotMatch
+        | BinaryOperator::PGRegexNotIMatch
+        | BinaryOperator::PGLikeMatch
+        | BinaryOperator::PGILikeMatch
+        | BinaryOperator::PGNotLikeMatch
+        | BinaryOperator::PGNotILikeMatch => Precedence::Eq,
+        BinaryOperator::BitwiseOr => Precedence::Pipe,
+        BinaryOperator::BitwiseXor
+        | BinaryOperator::PGExp
+        | BinaryOperator::PGBitwiseXor
+        | BinaryOperator::PGBitwiseShiftLeft
+        | BinaryOperator::PGBitwiseShiftRight => Precedence::Caret,
+        BinaryOperator::BitwiseAnd => Precedence::Ampersand,
+        BinaryOperator::Plus | BinaryOperator::Minus => Precedence::PlusMinus,
+        BinaryOperator::Multiply
+        | BinaryOperator::Divide
+        | BinaryOperator::Modulo
+        | BinaryOperator::StringConcat
+        | BinaryOperator::DuckIntegerDivide
+        | BinaryOperator::MyIntegerDivide => Precedence::MulDivModOp,
+        BinaryOperator::PGOverlap
+        | BinaryOperator::PGStartsWith
+        | BinaryOperator::MyJsonExtract
+        | BinaryOperator::MyJsonUnquoteExtract => Precedence::DoubleColon,
+        BinaryOperator::Arrow
+        | BinaryOperator::LongArrow
+        | BinaryOperator::HashArrow
+        | BinaryOperator::HashLongArrow
+        | BinaryOperator::AtAt
+        | BinaryOperator::AtArrow
+        | BinaryOperator::ArrowAt
+        | BinaryOperator::HashMinus
+        | BinaryOperator::AtQuestion
+        | BinaryOperator::Question
+        | BinaryOperator::QuestionAnd
+        | BinaryOperator::QuestionPipe
+        | BinaryOperator::Custom(_) => Precedence::PgOther,
+        BinaryOperator::PGCustomBinaryOperator(_) => Precedence::Between,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::{Ident, Value};
+    use crate::parser::Parser;
+
+    fn ident(name: &str) -> Expr {
+        Expr::Identifier(Ident::new(name))
+    }
+
+    fn binary(left: Expr, op: BinaryOperator, right: Expr) -> Expr {
+        Expr::BinaryOp {
+            left: Box::new(left),
+            op,
+            right: Box::new(right),
+        }
+    }
+
+    fn unary(op: UnaryOperator, expr: Expr) -> Expr {
+        Expr::UnaryOp {
+            op,
+            expr: Box::new(expr),
+        }
+    }
+
+    /// Removes all the `Expr::Nested` from `expr`.
+    fn strip_nested(mut expr: Expr) -> Expr {
+        struct Strip;
+        impl VisitorMut for Strip {
+            type Break = ();
+            fn post_visit_expr(&mut self, expr: &mut Expr) -> ControlFlow<()> {
+                while let Expr::Nested(inner) = expr {
+                    *expr = mem::replace(inner.as_mut(), Expr::Wildcard);
+                }
+                ControlFlow::Continue(())
+            }
+        }
+        let _ = expr.visit(&mut Strip);
+        expr
+    }
+
+    /// Checks that `expr` is displayed as `expected` and parses back to itself.
+    fn assert_parenthesized(expr: Expr, expected: &str) {
+        let sql = expr.to_sql_parenthesized();
+        assert_eq!(sql, expected);
+        let parsed = Parser::new(&GenericDialect {})
+            .try_with_sql(&sql)
+            .unwrap()
+            .parse_expr()
+            .unwrap();
+        assert_eq!(strip_nested(parsed), strip_nested(expr), "{sql}");
+    }
+
+    #[test]
+    fn binary_operators() {
+        let (a, b, c) = (ident("a"), ident("b"), ident("c"));
+        assert_parenthesized(
+            binary(
+                binary(a.clone(), BinaryOperator::Or, b.clone()),
+                BinaryOperator::And,
+                c.clone(),
+            ),
+            "(a OR b) AND c",
+        );
+        assert_parenthesized(
+            binary(
+                a.clone(),
+                BinaryOperator::Minus,
+                binary(b.clone(), BinaryOperator::Minus, c.clone()),
+            ),
+            "a - (b - c)",
+        );
+        // no parentheses where the precedence already matches the tree
+        assert_parenthesized(
+            binary(
+                binary(a.clone(), BinaryOperator::Minus, b.clone()),
+                BinaryOperator::Minus,
+                binary(b.clone(), BinaryOperator::Multiply, c.clone()),
+            ),
+            "a - b - b * c",
+        );
+        assert_parenthesized(
+            binary(
+                binary(a.clone(), BinaryOperator::Plus, b.clone()),
+                BinaryOperator::Multiply,
+                c.clone(),
+            ),
+            "(a + b) * c",
+        );
+    }
+
+    #[test]
+    fn unary_operators() {
+        let (a, b) = (ident("a"), ident("b"));
+        assert_parenthesized(
+            binary(
+                unary(UnaryOperator::Not, a.clone()),
+                BinaryOperator::Eq,
+                b.clone(),
+            ),
+            "(NOT a) = b",
+        );
+        assert_parenthesized(
+            unary(
+                UnaryOperator::Not,
+                binary(a.clone(), BinaryOperator::Eq, b.clone()),
+            ),
+            "NOT a = b",
+        );
+        assert_parenthesized(
+            unary(
+                UnaryOperator::Minus,
+                binary(a.clone(), BinaryOperator::Plus, b.clone()),
+            ),
+            "-(a + b)",
+        );
+        assert_parenthesized(
+            unary(UnaryOperator::Minus, unary(UnaryOperator::Minus, a.clone())),
+            "-(-a)",
+        );
+    }
+
+    #[test]
+    fn postfix_and_keyword_operators() {
+        let (a, b, c) = (ident("a"), ident("b"), ident("c"));
+        let a_and_b = binary(a.clone(), BinaryOperator::And, b.clone());
+        assert_parenthesized(Expr::IsNull(Box::new(a_and_b.clone())), "(a AND b) IS NULL");
+        assert_parenthesized(
+            binary(
+                Expr::IsDistinctFrom(Box::new(a.clone()), Box::new(b.clone())),
+                BinaryOperator::And,
+                c.clone(),
+            ),
+            "(a IS DISTINCT FROM b) AND c",
+        );
+        assert_parenthesized(
+            Expr::Between {
+                expr: Box::new(a_and_b.clone()),
+                negated: false,
+                low: Box::new(Expr::Value(Value::Boolean(false))),
+                high: Box::new(binary(b.clone(), BinaryOperator::Or, c.clone())),
+            },
+            "(a AND b) BETWEEN false AND (b OR c)",
+        );
+        assert_parenthesized(
+            Expr::Cast {
+                kind: CastKind::DoubleColon,
+                expr: Box::new(binary(a.clone(), BinaryOperator::Plus, b.clone())),
+                data_type: crate::ast::DataType::Int(None),
+                format: None,
+            },
+            "(a + b)::INT",
+        );
+        assert_parenthesized(
+            Expr::Like {
+                negated: true,
+                any: false,
+                expr: Box::new(binary(a.clone(), BinaryOperator::StringConcat, b.clone())),
+                pattern: Box::new(binary(b, BinaryOperator::Or, c)),
+                escape_char: None,
+            },
+            "a || b NOT LIKE (b OR c)",
+        );
+    }
+
+    #[test]
+    fn parsed_expressions_are_unchanged() {
+        for sql in [
+            "a + b * c - d",
+            "NOT a = b AND c OR d IS NULL",
+            "(a OR b) AND c",
+            "a BETWEEN 1 AND 2 AND b",
+            "x::INT + 1",
+        ] {
+            let expr = Parser::new(&GenericDialect {})
+                .try_with_sql(sql)
+                .unwrap()
+                .parse_expr()
+                .unwrap();
+            assert_eq!(expr.to_sql_parenthesized(), sql);
+        }
+    }
+}
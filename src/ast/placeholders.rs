@@ -0,0 +1,357 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Best-effort type hints for the placeholders of a prepared statement.
+//!
+//! [`infer_placeholder_types`] looks at the syntactic context of every
+//! placeholder (`$1`, `?`, `:name`, ...) and reports what its value is used
+//! for, e.g. that `$1` in `a = $1` is compared with the column `a`. Resolving
+//! the column to an actual type is left to the caller, who knows the schema.
+//!
+//! # Example
+//! ```
+//! # use sqlparser::ast::placeholders::{infer_placeholder_types, PlaceholderTypeHint};
+//! # use sqlparser::ast::Ident;
+//! # use sqlparser::dialect::PostgreSqlDialect;
+//! # use sqlparser::parser::Parser;
+//! let sql = "SELECT * FROM t WHERE a = $1 LIMIT $2";
+//! let statements = Parser::parse_sql(&PostgreSqlDialect {}, sql).unwrap();
+//! let placeholders = infer_placeholder_types(&statements[0]);
+//! assert_eq!(placeholders[0].placeholder, "$1");
+//! assert_eq!(
+//!     placeholders[0].hint,
+//!     Some(PlaceholderTypeHint::Column(vec![Ident::new("a")]))
+//! );
+//! assert_eq!(placeholders[1].hint, Some(PlaceholderTypeHint::RowCount));
+//! ```
+
+#[cfg(not(feature = "std"))]
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use core::ops::ControlFlow;
+#[cfg(feature = "std")]
+use std::collections::BTreeMap;
+
+use crate::ast::{
+    AssignmentTarget, BinaryOperator, DataType, Expr, Ident, Query, SetExpr, Statement,
+    UnaryOperator, Value, Visit, Visitor,
+};
+
+/// What the value of a placeholder is used for.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PlaceholderTypeHint {
+    /// Compared with, assigned to or inserted into a column, e.g. `a = $1`,
+    /// `a IN ($1, $2)`, `a BETWEEN $1 AND $2`, `SET a = $1` or
+    /// `INSERT INTO t (a) VALUES ($1)`. The column has the same type.
+    Column(Vec<Ident>),
+    /// Cast to a type, e.g. `$1::INT` or `CAST($1 AS INT)`
+    DataType(DataType),
+    /// A row count or offset, e.g. `LIMIT $1` or `OFFSET $1`
+    RowCount,
+    /// A condition, e.g. `WHERE $1` or `a AND $1`
+    Boolean,
+    /// A `LIKE` pattern, e.g. `a LIKE $1`
+    Text,
+}
+
+/// A placeholder of a statement and what its value is used for.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PlaceholderType {
+    /// The placeholder as written, e.g. `$1` or `?`
+    pub placeholder: String,
+    /// What the value is used for, if that could be determined
+    pub hint: Option<PlaceholderTypeHint>,
+}
+
+/// Returns the placeholders of `statement` in order of their first
+/// appearance, each with a type hint derived from its context.
+///
+/// Every anonymous `?` placeholder is reported separately, while all the
+/// occurrences of another placeholder, e.g. `$1`, are reported once with the
+/// hint of the first occurrence that has one.
+pub fn infer_placeholder_types(statement: &Statement) -> Vec<PlaceholderType> {
+    let mut inference = Inference::default();
+    let _ = statement.visit(&mut inference);
+    inference.placeholders
+}
+
+#[derive(Default)]
+struct Inference {
+    /// The hints of the placeholders that have not been visited yet, by the
+    /// address of their expression
+    pending: BTreeMap<usize, PlaceholderTypeHint>,
+    placeholders: Vec<PlaceholderType>,
+}
+
+impl Inference {
+    fn hint(&mut self, expr: &Expr, hint: PlaceholderTypeHint) {
+        if matches!(expr, Expr::Value(Value::Placeholder(_))) {
+            self.pending
+                .entry(expr as *const Expr as usize)
+                .or_insert(hint);
+        }
+    }
+
+    fn hint_column(&mut self, expr: &Expr, other: &Expr) {
+        if let Some(column) = column_of(other) {
+            self.hint(expr, PlaceholderTypeHint::Column(column));
+        }
+    }
+
+    fn check_set_expr(&mut self, set_expr: &SetExpr) {
+        match set_expr {
+            SetExpr::Select(select) => {
+                if let Some(selection) = &select.selection {
+                    self.hint(selection, PlaceholderTypeHint::Boolean);
+                }
+                if let Some(having) = &select.having {
+                    self.hint(having, PlaceholderTypeHint::Boolean);
+                }
+            }
+            SetExpr::Query(query) => self.check_set_expr(&query.body),
+            SetExpr::SetOperation { left, right, .. } => {
+                self.check_set_expr(left);
+                self.check_set_expr(right);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// The column named by `expr`, if it is a plain column reference
+fn column_of(expr: &Expr) -> Option<Vec<Ident>> {
+    match expr {
+        Expr::Identifier(ident) => Some(vec![ident.clone()]),
+        Expr::CompoundIdentifier(idents) => Some(idents.clone()),
+        Expr::Nested(expr) => column_of(expr),
+        _ => None,
+    }
+}
+
+impl Visitor for Inference {
+    type Break = ();
+
+    fn pre_visit_statement(&mut self, statement: &Statement) -> ControlFlow<Self::Break> {
+        match statement {
+            Statement::Insert(insert) if !insert.columns.is_empty() => {
+                if let Some(SetExpr::Values(values)) = insert.source.as_ref().map(|q| &*q.body) {
+                    for row in &values.rows {
+                        for (value, column) in row.iter().zip(&insert.columns) {
+                            self.hint(value, PlaceholderTypeHint::Column(vec![column.clone()]));
+                        }
+                    }
+                }
+            }
+            Statement::Update {
+                assignments,
+                selection,
+                ..
+            } => {
+                for assignment in assignments {
+                    if let AssignmentTarget::ColumnName(column) = &assignment.target {
+                        self.hint(
+                            &assignment.value,
+                            PlaceholderTypeHint::Column(column.0.clone()),
+                        );
+                    }
+                }
+                if let Some(selection) = selection {
+                    self.hint(selection, PlaceholderTypeHint::Boolean);
+                }
+            }
+            Statement::Delete(delete) => {
+                if let Some(selection) = &delete.selection {
+                    self.hint(selection, PlaceholderTypeHint::Boolean);
+                }
+            }
+            _ => {}
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn pre_visit_query(&mut self, query: &Query) -> ControlFlow<Self::Break> {
+        if let Some(limit) = &query.limit {
+            self.hint(limit, PlaceholderTypeHint::RowCount);
+        }
+        if let Some(offset) = &query.offset {
+            self.hint(&offset.value, PlaceholderTypeHint::RowCount);
+        }
+        if let Some(quantity) = query.fetch.as_ref().and_then(|f| f.quantity.as_ref()) {
+            self.hint(quantity, PlaceholderTypeHint::RowCount);
+        }
+        self.check_set_expr(&query.body);
+        ControlFlow::Continue(())
+    }
+
+    fn pre_visit_expr(&mut self, expr: &Expr) -> ControlFlow<Self::Break> {
+        match expr {
+            Expr::Value(Value::Placeholder(placeholder)) => {
+                let hint = self.pending.remove(&(expr as *const Expr as usize));
+                let existing = if placeholder == "?" {
+                    None
+                } else {
+                    self.placeholders
+                        .iter_mut()
+                        .find(|p| &p.placeholder == placeholder)
+                };
+                match existing {
+                    Some(existing) => {
+                        if existing.hint.is_none() {
+                            existing.hint = hint;
+                        }
+                    }
+                    None => self.placeholders.push(PlaceholderType {
+                        placeholder: placeholder.clone(),
+                        hint,
+                    }),
+                }
+            }
+            Expr::BinaryOp { left, op, right } => match op {
+                BinaryOperator::And | BinaryOperator::Or | BinaryOperator::Xor => {
+                    self.hint(left, PlaceholderTypeHint::Boolean);
+                    self.hint(right, PlaceholderTypeHint::Boolean);
+                }
+                BinaryOperator::Eq
+                | BinaryOperator::NotEq
+                | BinaryOperator::Lt
+                | BinaryOperator::LtEq
+                | BinaryOperator::Gt
+                | BinaryOperator::GtEq
+                | BinaryOperator::Spaceship => {
+                    self.hint_column(left, right);
+                    self.hint_column(right, left);
+                }
+                _ => {}
+            },
+            Expr::UnaryOp {
+                op: UnaryOperator::Not,
+                expr,
+            } => self.hint(expr, PlaceholderTypeHint::Boolean),
+            Expr::IsDistinctFrom(left, right) | Expr::IsNotDistinctFrom(left, right) => {
+                self.hint_column(left, right);
+                self.hint_column(right, left);
+            }
+            Expr::InList { expr, list, .. } => {
+                for item in list {
+                    self.hint_column(item, expr);
+                }
+            }
+            Expr::Between {
+                expr, low, high, ..
+            } => {
+                self.hint_column(low, expr);
+                self.hint_column(high, expr);
+            }
+            Expr::Like { pattern, .. }
+            | Expr::ILike { pattern, .. }
+            | Expr::SimilarTo { pattern, .. } => self.hint(pattern, PlaceholderTypeHint::Text),
+            Expr::Cast {
+                expr, data_type, ..
+            } => self.hint(expr, PlaceholderTypeHint::DataType(data_type.clone())),
+            _ => {}
+        }
+        ControlFlow::Continue(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dialect::{GenericDialect, PostgreSqlDialect};
+    use crate::parser::Parser;
+
+    fn infer(sql: &str) -> Vec<(String, Option<PlaceholderTypeHint>)> {
+        let statements = Parser::parse_sql(&PostgreSqlDialect {}, sql).unwrap();
+        infer_placeholder_types(&statements[0])
+            .into_iter()
+            .map(|p| (p.placeholder, p.hint))
+            .collect()
+    }
+
+    fn column(name: &str) -> Option<PlaceholderTypeHint> {
+        Some(PlaceholderTypeHint::Column(vec![Ident::new(name)]))
+    }
+
+    #[test]
+    fn test_select() {
+        assert_eq!(
+            infer(
+                "SELECT * FROM t WHERE $2 < t.a AND b IN ($1, $3) AND c LIKE $4 LIMIT $5 OFFSET $6"
+            ),
+            vec![
+                (
+                    "$2".into(),
+                    Some(PlaceholderTypeHint::Column(vec![
+                        Ident::new("t"),
+                        Ident::new("a")
+                    ]))
+                ),
+                ("$1".into(), column("b")),
+                ("$3".into(), column("b")),
+                ("$4".into(), Some(PlaceholderTypeHint::Text)),
+                ("$5".into(), Some(PlaceholderTypeHint::RowCount)),
+                ("$6".into(), Some(PlaceholderTypeHint::RowCount)),
+            ]
+        );
+        assert_eq!(
+            infer("SELECT $1::INT, $2 FROM t WHERE a BETWEEN $2 AND $3 OR $4"),
+            vec![
+                (
+                    "$1".into(),
+                    Some(PlaceholderTypeHint::DataType(DataType::Int(None)))
+                ),
+                ("$2".into(), column("a")),
+                ("$3".into(), column("a")),
+                ("$4".into(), Some(PlaceholderTypeHint::Boolean)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_insert_update_delete() {
+        assert_eq!(
+            infer("INSERT INTO t (a, b) VALUES ($1, $2 + 1)"),
+            vec![("$1".into(), column("a")), ("$2".into(), None)]
+        );
+        assert_eq!(
+            infer("UPDATE t SET a = $1 WHERE b = $2"),
+            vec![("$1".into(), column("a")), ("$2".into(), column("b"))]
+        );
+        assert_eq!(
+            infer("DELETE FROM t WHERE $1"),
+            vec![("$1".into(), Some(PlaceholderTypeHint::Boolean))]
+        );
+    }
+
+    #[test]
+    fn test_anonymous_placeholders() {
+        let statements =
+            Parser::parse_sql(&GenericDialect {}, "SELECT * FROM t WHERE a = ? AND b = ?").unwrap();
+        assert_eq!(
+            infer_placeholder_types(&statements[0]),
+            vec![
+                PlaceholderType {
+                    placeholder: "?".into(),
+                    hint: column("a"),
+                },
+                PlaceholderType {
+                    placeholder: "?".into(),
+                    hint: column("b"),
+                },
+            ]
+        );
+    }
+}
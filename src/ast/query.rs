@@ -18,6 +18,8 @@
 #[cfg(not(feature = "std"))]
 use alloc::{boxed::Box, vec::Vec};
 
+#[cfg(feature = "json_schema")]
+use schemars::JsonSchema;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -30,6 +32,7 @@ use crate::ast::*;
 /// including `WITH`, `UNION` / other set operations, and `ORDER BY`.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 #[cfg_attr(feature = "visitor", visit(with = "visit_query"))]
 pub struct Query {
@@ -64,6 +67,10 @@ pub struct Query {
     /// [ClickHouse](https://clickhouse.com/docs/en/sql-reference/statements/select/format)
     /// (ClickHouse-specific)
     pub format_clause: Option<FormatClause>,
+    /// `OPTION (MAXDOP 1, RECOMPILE)`
+    ///
+    /// [MSSQL](https://learn.microsoft.com/en-us/sql/t-sql/queries/option-clause-transact-sql)
+    pub option_clause: Option<Vec<QueryHint>>,
 }
 
 impl fmt::Display for Query {
@@ -96,6 +103,9 @@ impl fmt::Display for Query {
         if let Some(ref for_clause) = self.for_clause {
             write!(f, " {}", for_clause)?;
         }
+        if let Some(ref option_clause) = self.option_clause {
+            write!(f, " OPTION ({})", display_comma_separated(option_clause))?;
+        }
         if let Some(ref format) = self.format_clause {
             write!(f, " {}", format)?;
         }
@@ -110,6 +120,7 @@ impl fmt::Display for Query {
 /// [ClickHouse](https://clickhouse.com/docs/en/sql-reference/statements/alter/projection#add-projection)
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct ProjectionSelect {
     pub projection: Vec<SelectItem>,
@@ -135,6 +146,7 @@ impl fmt::Display for ProjectionSelect {
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum SetExpr {
     /// Restricted SELECT .. FROM .. HAVING (no ORDER BY or set operations)
@@ -152,6 +164,7 @@ pub enum SetExpr {
     Values(Values),
     Insert(Statement),
     Update(Statement),
+    Delete(Statement),
     Table(Box<Table>),
 }
 
@@ -174,6 +187,7 @@ impl fmt::Display for SetExpr {
             SetExpr::Values(v) => write!(f, "{v}"),
             SetExpr::Insert(v) => write!(f, "{v}"),
             SetExpr::Update(v) => write!(f, "{v}"),
+            SetExpr::Delete(v) => write!(f, "{v}"),
             SetExpr::Table(t) => write!(f, "{t}"),
             SetExpr::SetOperation {
                 left,
@@ -199,11 +213,14 @@ impl fmt::Display for SetExpr {
 
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum SetOperator {
     Union,
     Except,
     Intersect,
+    /// `MINUS`, a synonym of `EXCEPT` used by Oracle and Snowflake
+    Minus,
 }
 
 impl fmt::Display for SetOperator {
@@ -212,6 +229,7 @@ impl fmt::Display for SetOperator {
             SetOperator::Union => "UNION",
             SetOperator::Except => "EXCEPT",
             SetOperator::Intersect => "INTERSECT",
+            SetOperator::Minus => "MINUS",
         })
     }
 }
@@ -221,6 +239,7 @@ impl fmt::Display for SetOperator {
 // For example, BigQuery does not support `DISTINCT` for `EXCEPT` and `INTERSECT`
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum SetQuantifier {
     All,
@@ -246,6 +265,7 @@ impl fmt::Display for SetQuantifier {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 /// A [`TABLE` command]( https://www.postgresql.org/docs/current/sql-select.html#SQL-TABLE)
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct Table {
@@ -274,6 +294,7 @@ impl fmt::Display for Table {
 /// to a set operation like `UNION`.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct Select {
     pub distinct: Option<Distinct>,
@@ -406,12 +427,13 @@ impl fmt::Display for Select {
 /// A hive LATERAL VIEW with potential column aliases
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct LateralView {
     /// LATERAL VIEW
     pub lateral_view: Expr,
-    /// LATERAL VIEW table name
-    pub lateral_view_name: ObjectName,
+    /// LATERAL VIEW table name, optional in Spark SQL and Databricks
+    pub lateral_view_name: Option<ObjectName>,
     /// LATERAL VIEW optional column aliases
     pub lateral_col_alias: Vec<Ident>,
     /// LATERAL VIEW OUTER
@@ -422,11 +444,13 @@ impl fmt::Display for LateralView {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
-            " LATERAL VIEW{outer} {} {}",
+            " LATERAL VIEW{outer} {}",
             self.lateral_view,
-            self.lateral_view_name,
             outer = if self.outer { " OUTER" } else { "" }
         )?;
+        if let Some(name) = &self.lateral_view_name {
+            write!(f, " {name}")?;
+        }
         if !self.lateral_col_alias.is_empty() {
             write!(
                 f,
@@ -445,6 +469,7 @@ impl fmt::Display for LateralView {
 /// ```
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum NamedWindowExpr {
     /// A direct reference to another named window definition.
@@ -482,6 +507,7 @@ impl fmt::Display for NamedWindowExpr {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct NamedWindowDefinition(pub Ident, pub NamedWindowExpr);
 
@@ -493,6 +519,7 @@ impl fmt::Display for NamedWindowDefinition {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct With {
     pub recursive: bool,
@@ -512,6 +539,7 @@ impl fmt::Display for With {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum CteAsMaterialized {
     /// The `WITH` statement specifies `AS MATERIALIZED` behavior
@@ -540,6 +568,7 @@ impl fmt::Display for CteAsMaterialized {
 /// number of columns in the query matches the number of columns in the query.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct Cte {
     pub alias: TableAlias,
@@ -564,7 +593,9 @@ impl fmt::Display for Cte {
 /// One item of the comma-separated list following `SELECT`
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+#[cfg_attr(feature = "visitor", visit(with = "visit_select_item"))]
 pub enum SelectItem {
     /// Any expression, not followed by `[ AS ] alias`
     UnnamedExpr(Expr),
@@ -584,6 +615,7 @@ pub enum SelectItem {
 /// ```
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct IdentWithAlias {
     pub ident: Ident,
@@ -599,6 +631,7 @@ impl fmt::Display for IdentWithAlias {
 /// Additional options for wildcards, e.g. Snowflake `EXCLUDE`/`RENAME` and Bigquery `EXCEPT`.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct WildcardAdditionalOptions {
     /// `[ILIKE...]`.
@@ -647,6 +680,7 @@ impl fmt::Display for WildcardAdditionalOptions {
 /// ```
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct IlikeSelectItem {
     pub pattern: String,
@@ -671,6 +705,7 @@ impl fmt::Display for IlikeSelectItem {
 /// ```
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum ExcludeSelectItem {
     /// Single column name without parenthesis.
@@ -712,6 +747,7 @@ impl fmt::Display for ExcludeSelectItem {
 /// ```
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum RenameSelectItem {
     /// Single column name with alias without parenthesis.
@@ -752,6 +788,7 @@ impl fmt::Display for RenameSelectItem {
 /// ```
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct ExceptSelectItem {
     /// First guaranteed column.
@@ -786,6 +823,7 @@ impl fmt::Display for ExceptSelectItem {
 /// ```
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct ReplaceSelectItem {
     pub items: Vec<Box<ReplaceSelectElement>>,
@@ -805,6 +843,7 @@ impl fmt::Display for ReplaceSelectItem {
 /// ```
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct ReplaceSelectElement {
     pub expr: Expr,
@@ -843,6 +882,7 @@ impl fmt::Display for SelectItem {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct TableWithJoins {
     pub relation: TableFactor,
@@ -864,6 +904,7 @@ impl fmt::Display for TableWithJoins {
 /// See <https://docs.snowflake.com/en/sql-reference/constructs/connect-by>.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct ConnectBy {
     /// START WITH
@@ -885,6 +926,7 @@ impl fmt::Display for ConnectBy {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct Setting {
     pub key: Ident,
@@ -905,6 +947,7 @@ impl fmt::Display for Setting {
 /// ```
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct ExprWithAlias {
     pub expr: Expr,
@@ -925,6 +968,7 @@ impl fmt::Display for ExprWithAlias {
 /// Arguments to a table-valued function
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct TableFunctionArgs {
     pub args: Vec<FunctionArg>,
@@ -938,6 +982,7 @@ pub struct TableFunctionArgs {
 /// A table name or a parenthesized subquery with an optional alias
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 #[cfg_attr(feature = "visitor", visit(with = "visit_table_factor"))]
 pub enum TableFactor {
@@ -954,7 +999,7 @@ pub enum TableFactor {
         /// whereas it's `None` in the case of a regular table name.
         args: Option<TableFunctionArgs>,
         /// MSSQL-specific `WITH (...)` hints such as NOLOCK.
-        with_hints: Vec<Expr>,
+        with_hints: Vec<TableHint>,
         /// Optional version qualifier to facilitate table time-travel, as
         /// supported by BigQuery and MSSQL.
         version: Option<TableVersion>,
@@ -967,7 +1012,7 @@ pub enum TableFactor {
     },
     Derived {
         lateral: bool,
-        subquery: Box<Query>,
+        subquery: QueryRef,
         alias: Option<TableAlias>,
     },
     /// `TABLE(<expr>)[ AS <alias> ]`
@@ -975,11 +1020,13 @@ pub enum TableFactor {
         expr: Expr,
         alias: Option<TableAlias>,
     },
-    /// `e.g. LATERAL FLATTEN(<args>)[ AS <alias> ]`
+    /// `e.g. LATERAL FLATTEN(<args>)[ WITH ORDINALITY ][ AS <alias> ]`
     Function {
         lateral: bool,
         name: ObjectName,
         args: Vec<FunctionArg>,
+        /// `WITH ORDINALITY`, supported by Postgres
+        with_ordinality: bool,
         alias: Option<TableAlias>,
     },
     /// ```sql
@@ -1081,15 +1128,48 @@ pub enum TableFactor {
         after_match_skip: Option<AfterMatchSkip>,
         /// `PATTERN ( <pattern> )`
         pattern: MatchRecognizePattern,
+        /// `SUBSET <name> = ( <symbol> [, ... ] ) [, ... ]`
+        subsets: Vec<SubsetDefinition>,
         /// `DEFINE <symbol> AS <expr> [, ... ]`
+        ///
+        /// Empty if the `DEFINE` subclause is omitted, in which case every
+        /// pattern symbol implicitly matches any row.
         symbols: Vec<SymbolDefinition>,
         alias: Option<TableAlias>,
     },
+    /// The arrays unnested by a ClickHouse `[LEFT] ARRAY JOIN`, only used as
+    /// the relation of a [`JoinOperator::ArrayJoin`] or
+    /// [`JoinOperator::LeftArrayJoin`].
+    ///
+    /// Syntax:
+    /// ```sql
+    /// ARRAY JOIN expr [ AS alias ] [, ... ]
+    /// ```
+    ///
+    /// See <https://clickhouse.com/docs/en/sql-reference/statements/select/array-join>.
+    ArrayJoinItems(Vec<ExprWithAlias>),
+    /// PostgreSQL `ROWS FROM`, which joins the results of several
+    /// set-returning functions side by side.
+    ///
+    /// Syntax:
+    /// ```sql
+    /// [LATERAL] ROWS FROM (function_call [AS (column_definition [, ...])] [, ...])
+    ///     [WITH ORDINALITY] [[AS] alias [(column_alias [, ...])]]
+    /// ```
+    ///
+    /// See <https://www.postgresql.org/docs/current/queries-table-expressions.html#QUERIES-TABLEFUNCTIONS>.
+    RowsFrom {
+        lateral: bool,
+        functions: Vec<RowsFromFunction>,
+        with_ordinality: bool,
+        alias: Option<TableAlias>,
+    },
 }
 
 /// The source of values in a `PIVOT` operation.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum PivotValueSource {
     /// Pivot on a static list of values.
@@ -1127,6 +1207,7 @@ impl fmt::Display for PivotValueSource {
 /// See <https://docs.snowflake.com/en/sql-reference/constructs/match_recognize#measures-specifying-additional-output-columns>.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct Measure {
     pub expr: Expr,
@@ -1144,6 +1225,7 @@ impl fmt::Display for Measure {
 /// See <https://docs.snowflake.com/en/sql-reference/constructs/match_recognize#row-s-per-match-specifying-the-rows-to-return>.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum RowsPerMatch {
     /// `ONE ROW PER MATCH`
@@ -1172,6 +1254,7 @@ impl fmt::Display for RowsPerMatch {
 /// See <https://docs.snowflake.com/en/sql-reference/constructs/match_recognize#after-match-skip-specifying-where-to-continue-after-a-match>.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum AfterMatchSkip {
     /// `PAST LAST ROW`
@@ -1189,7 +1272,7 @@ impl fmt::Display for AfterMatchSkip {
         write!(f, "AFTER MATCH SKIP ")?;
         match self {
             AfterMatchSkip::PastLastRow => write!(f, "PAST LAST ROW"),
-            AfterMatchSkip::ToNextRow => write!(f, " TO NEXT ROW"),
+            AfterMatchSkip::ToNextRow => write!(f, "TO NEXT ROW"),
             AfterMatchSkip::ToFirst(symbol) => write!(f, "TO FIRST {symbol}"),
             AfterMatchSkip::ToLast(symbol) => write!(f, "TO LAST {symbol}"),
         }
@@ -1198,6 +1281,7 @@ impl fmt::Display for AfterMatchSkip {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum EmptyMatchesMode {
     /// `SHOW EMPTY MATCHES`
@@ -1223,6 +1307,7 @@ impl fmt::Display for EmptyMatchesMode {
 /// See <https://docs.snowflake.com/en/sql-reference/constructs/match_recognize#define-defining-symbols>.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct SymbolDefinition {
     pub symbol: Ident,
@@ -1235,9 +1320,34 @@ impl fmt::Display for SymbolDefinition {
     }
 }
 
+/// A union of pattern symbols defined in the `SUBSET` subclause of a
+/// `MATCH_RECOGNIZE` operation, e.g. `U = (A, B)`.
+///
+/// See <https://trino.io/docs/current/sql/match-recognize.html#union-variables>.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct SubsetDefinition {
+    pub name: Ident,
+    pub symbols: Vec<Ident>,
+}
+
+impl fmt::Display for SubsetDefinition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} = ({})",
+            self.name,
+            display_comma_separated(&self.symbols)
+        )
+    }
+}
+
 /// A symbol in a `MATCH_RECOGNIZE` pattern.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum MatchRecognizeSymbol {
     /// A named symbol, e.g. `S1`.
@@ -1263,12 +1373,13 @@ impl fmt::Display for MatchRecognizeSymbol {
 /// See <https://docs.snowflake.com/en/sql-reference/constructs/match_recognize#pattern-specifying-the-pattern-to-match>.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum MatchRecognizePattern {
     /// A named symbol such as `S1` or a virtual symbol such as `^`.
     Symbol(MatchRecognizeSymbol),
-    /// {- symbol -}
-    Exclude(MatchRecognizeSymbol),
+    /// {- pattern -}
+    Exclude(Box<MatchRecognizePattern>),
     /// PERMUTE(symbol_1, ..., symbol_n)
     Permute(Vec<MatchRecognizeSymbol>),
     /// pattern_1 pattern_2 ... pattern_n
@@ -1286,7 +1397,7 @@ impl fmt::Display for MatchRecognizePattern {
         use MatchRecognizePattern::*;
         match self {
             Symbol(symbol) => write!(f, "{}", symbol),
-            Exclude(symbol) => write!(f, "{{- {symbol} -}}"),
+            Exclude(pattern) => write!(f, "{{- {pattern} -}}"),
             Permute(symbols) => write!(f, "PERMUTE({})", display_comma_separated(symbols)),
             Concat(patterns) => write!(f, "{}", display_separated(patterns, " ")),
             Group(pattern) => write!(f, "( {pattern} )"),
@@ -1300,6 +1411,7 @@ impl fmt::Display for MatchRecognizePattern {
 /// `MATCH_RECOGNIZE` operation.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum RepetitionQuantifier {
     /// `*`
@@ -1347,7 +1459,7 @@ impl fmt::Display for TableFactor {
             } => {
                 write!(f, "{name}")?;
                 if !partitions.is_empty() {
-                    write!(f, "PARTITION ({})", display_comma_separated(partitions))?;
+                    write!(f, " PARTITION ({})", display_comma_separated(partitions))?;
                 }
                 if let Some(args) = args {
                     write!(f, "(")?;
@@ -1392,6 +1504,7 @@ impl fmt::Display for TableFactor {
                 lateral,
                 name,
                 args,
+                with_ordinality,
                 alias,
             } => {
                 if *lateral {
@@ -1399,6 +1512,9 @@ impl fmt::Display for TableFactor {
                 }
                 write!(f, "{name}")?;
                 write!(f, "({})", display_comma_separated(args))?;
+                if *with_ordinality {
+                    write!(f, " WITH ORDINALITY")?;
+                }
                 if let Some(alias) = alias {
                     write!(f, " AS {alias}")?;
                 }
@@ -1512,6 +1628,7 @@ impl fmt::Display for TableFactor {
                 rows_per_match,
                 after_match_skip,
                 pattern,
+                subsets,
                 symbols,
                 alias,
             } => {
@@ -1531,23 +1648,51 @@ impl fmt::Display for TableFactor {
                 if let Some(after_match_skip) = after_match_skip {
                     write!(f, "{after_match_skip} ")?;
                 }
-                write!(f, "PATTERN ({pattern}) ")?;
-                write!(f, "DEFINE {})", display_comma_separated(symbols))?;
+                write!(f, "PATTERN ({pattern})")?;
+                if !subsets.is_empty() {
+                    write!(f, " SUBSET {}", display_comma_separated(subsets))?;
+                }
+                if !symbols.is_empty() {
+                    write!(f, " DEFINE {}", display_comma_separated(symbols))?;
+                }
+                write!(f, ")")?;
                 if alias.is_some() {
                     write!(f, " AS {}", alias.as_ref().unwrap())?;
                 }
                 Ok(())
             }
+            TableFactor::ArrayJoinItems(items) => {
+                write!(f, "{}", display_comma_separated(items))
+            }
+            TableFactor::RowsFrom {
+                lateral,
+                functions,
+                with_ordinality,
+                alias,
+            } => {
+                if *lateral {
+                    write!(f, "LATERAL ")?;
+                }
+                write!(f, "ROWS FROM ({})", display_comma_separated(functions))?;
+                if *with_ordinality {
+                    write!(f, " WITH ORDINALITY")?;
+                }
+                if let Some(alias) = alias {
+                    write!(f, " AS {alias}")?;
+                }
+                Ok(())
+            }
         }
     }
 }
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct TableAlias {
     pub name: Ident,
-    pub columns: Vec<Ident>,
+    pub columns: Vec<TableAliasColumnDef>,
 }
 
 impl fmt::Display for TableAlias {
@@ -1560,8 +1705,74 @@ impl fmt::Display for TableAlias {
     }
 }
 
+/// A column of a [`TableAlias`], with an optional data type as in the
+/// column definition list of a PostgreSQL record-returning function, e.g.
+/// `SELECT * FROM f() AS t (a INT, b TEXT)`
+///
+/// See <https://www.postgresql.org/docs/current/sql-select.html#SQL-FROM>.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct TableAliasColumnDef {
+    pub name: Ident,
+    pub data_type: Option<DataType>,
+}
+
+impl TableAliasColumnDef {
+    /// Create a column without a data type
+    pub fn from_name<S: Into<IdentValue>>(name: S) -> Self {
+        TableAliasColumnDef {
+            name: Ident::new(name),
+            data_type: None,
+        }
+    }
+}
+
+impl From<Ident> for TableAliasColumnDef {
+    fn from(name: Ident) -> Self {
+        TableAliasColumnDef {
+            name,
+            data_type: None,
+        }
+    }
+}
+
+impl fmt::Display for TableAliasColumnDef {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        if let Some(data_type) = &self.data_type {
+            write!(f, " {data_type}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A function call of a [`TableFactor::RowsFrom`], with the column
+/// definition list required by functions returning `record`, e.g.
+/// `f() AS (a INT, b TEXT)`
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct RowsFromFunction {
+    pub expr: Expr,
+    pub columns: Vec<TableAliasColumnDef>,
+}
+
+impl fmt::Display for RowsFromFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.expr)?;
+        if !self.columns.is_empty() {
+            write!(f, " AS ({})", display_comma_separated(&self.columns))?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum TableVersion {
     ForSystemTimeAsOf(Expr),
@@ -1578,7 +1789,9 @@ impl Display for TableVersion {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+#[cfg_attr(feature = "visitor", visit(with = "visit_join"))]
 pub struct Join {
     pub relation: TableFactor,
     /// ClickHouse supports the optional `GLOBAL` keyword before the join operator.
@@ -1601,8 +1814,12 @@ impl fmt::Display for Join {
                 fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
                     match self.0 {
                         JoinConstraint::On(expr) => write!(f, " ON {expr}"),
-                        JoinConstraint::Using(attrs) => {
-                            write!(f, " USING({})", display_comma_separated(attrs))
+                        JoinConstraint::Using { columns, alias } => {
+                            write!(f, " USING({})", display_comma_separated(columns))?;
+                            if let Some(alias) = alias {
+                                write!(f, " AS {alias}")?;
+                            }
+                            Ok(())
                         }
                         _ => Ok(()),
                     }
@@ -1672,23 +1889,44 @@ impl fmt::Display for Join {
                 self.relation,
                 suffix(constraint)
             ),
+            JoinOperator::Semi(constraint) => write!(
+                f,
+                " {}SEMI JOIN {}{}",
+                prefix(constraint),
+                self.relation,
+                suffix(constraint)
+            ),
+            JoinOperator::Anti(constraint) => write!(
+                f,
+                " {}ANTI JOIN {}{}",
+                prefix(constraint),
+                self.relation,
+                suffix(constraint)
+            ),
             JoinOperator::CrossApply => write!(f, " CROSS APPLY {}", self.relation),
+            JoinOperator::StraightJoin(constraint) => {
+                write!(f, " STRAIGHT_JOIN {}{}", self.relation, suffix(constraint))
+            }
+            JoinOperator::ArrayJoin => write!(f, " ARRAY JOIN {}", self.relation),
+            JoinOperator::LeftArrayJoin => write!(f, " LEFT ARRAY JOIN {}", self.relation),
             JoinOperator::OuterApply => write!(f, " OUTER APPLY {}", self.relation),
             JoinOperator::AsOf {
                 match_condition,
                 constraint,
-            } => write!(
-                f,
-                " ASOF JOIN {} MATCH_CONDITION ({match_condition}){}",
-                self.relation,
-                suffix(constraint)
-            ),
+            } => {
+                write!(f, " ASOF JOIN {}", self.relation)?;
+                if let Some(match_condition) = match_condition {
+                    write!(f, " MATCH_CONDITION ({match_condition})")?;
+                }
+                write!(f, "{}", suffix(constraint))
+            }
         }
     }
 }
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum JoinOperator {
     Inner(JoinConstraint),
@@ -1704,35 +1942,65 @@ pub enum JoinOperator {
     LeftAnti(JoinConstraint),
     /// RIGHT ANTI (non-standard)
     RightAnti(JoinConstraint),
+    /// SEMI (non-standard)
+    ///
+    /// See <https://duckdb.org/docs/sql/query_syntax/from.html#semi-and-anti-joins>.
+    Semi(JoinConstraint),
+    /// ANTI (non-standard)
+    ///
+    /// See <https://duckdb.org/docs/sql/query_syntax/from.html#semi-and-anti-joins>.
+    Anti(JoinConstraint),
     /// CROSS APPLY (non-standard)
     CrossApply,
     /// OUTER APPLY (non-standard)
     OuterApply,
+    /// MySQL `STRAIGHT_JOIN`, an inner join that reads the left table first
+    ///
+    /// See <https://dev.mysql.com/doc/refman/8.4/en/join.html>.
+    StraightJoin(JoinConstraint),
+    /// ClickHouse `ARRAY JOIN`, the relation is a [`TableFactor::ArrayJoinItems`]
+    ArrayJoin,
+    /// ClickHouse `LEFT ARRAY JOIN`, the relation is a [`TableFactor::ArrayJoinItems`]
+    LeftArrayJoin,
     /// `ASOF` joins are used for joining tables containing time-series data
     /// whose timestamp columns do not match exactly.
     ///
-    /// See <https://docs.snowflake.com/en/sql-reference/constructs/asof-join>.
+    /// Snowflake gives the inequality in a `MATCH_CONDITION` clause, while
+    /// DuckDB and ClickHouse include it in the `ON` constraint.
+    ///
+    /// See <https://docs.snowflake.com/en/sql-reference/constructs/asof-join>,
+    /// <https://duckdb.org/docs/guides/sql_features/asof_join.html> and
+    /// <https://clickhouse.com/docs/en/sql-reference/statements/select/join#asof-join-usage>.
     AsOf {
-        match_condition: Expr,
+        match_condition: Option<Expr>,
         constraint: JoinConstraint,
     },
 }
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum JoinConstraint {
     On(Expr),
-    Using(Vec<Ident>),
+    /// `USING (a, b)`, optionally followed by an alias for the join columns
+    /// as in `USING (a, b) AS j`
+    ///
+    /// [PostgreSQL](https://www.postgresql.org/docs/16/queries-table-expressions.html#QUERIES-JOIN)
+    Using {
+        columns: Vec<Ident>,
+        alias: Option<Ident>,
+    },
     Natural,
     None,
 }
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OrderBy {
-    pub exprs: Vec<OrderByExpr>,
+    pub kind: OrderByKind,
     /// Optional: `INTERPOLATE`
     /// Supported by [ClickHouse syntax]
     ///
@@ -1743,8 +2011,13 @@ pub struct OrderBy {
 impl fmt::Display for OrderBy {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "ORDER BY")?;
-        if !self.exprs.is_empty() {
-            write!(f, " {}", display_comma_separated(&self.exprs))?;
+        match &self.kind {
+            OrderByKind::All(options) => write!(f, " ALL{options}")?,
+            OrderByKind::Expressions(exprs) => {
+                if !exprs.is_empty() {
+                    write!(f, " {}", display_comma_separated(exprs))?;
+                }
+            }
         }
         if let Some(ref interpolate) = self.interpolate {
             match &interpolate.exprs {
@@ -1756,9 +2029,54 @@ impl fmt::Display for OrderBy {
     }
 }
 
+/// What an `ORDER BY` clause sorts by
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum OrderByKind {
+    /// `ALL` syntax of [DuckDB] and [ClickHouse], which sorts by all the
+    /// columns of the select list from left to right.
+    ///
+    /// [DuckDB]: <https://duckdb.org/docs/sql/query_syntax/orderby.html#order-by-all>
+    /// [ClickHouse]: <https://clickhouse.com/docs/en/sql-reference/statements/select/order-by>
+    All(OrderByOptions),
+    /// Expressions
+    Expressions(Vec<OrderByExpr>),
+}
+
+/// The `ASC`/`DESC` and `NULLS FIRST`/`NULLS LAST` options of [`OrderByKind::All`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct OrderByOptions {
+    /// Optional `ASC` or `DESC`
+    pub asc: Option<bool>,
+    /// Optional `NULLS FIRST` or `NULLS LAST`
+    pub nulls_first: Option<bool>,
+}
+
+impl fmt::Display for OrderByOptions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.asc {
+            Some(true) => write!(f, " ASC")?,
+            Some(false) => write!(f, " DESC")?,
+            None => (),
+        }
+        match self.nulls_first {
+            Some(true) => write!(f, " NULLS FIRST")?,
+            Some(false) => write!(f, " NULLS LAST")?,
+            None => (),
+        }
+        Ok(())
+    }
+}
+
 /// An `ORDER BY` expression
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OrderByExpr {
     pub expr: Expr,
@@ -1797,6 +2115,7 @@ impl fmt::Display for OrderByExpr {
 /// [ClickHouse syntax]: <https://clickhouse.com/docs/en/sql-reference/statements/select/order-by#order-by-expr-with-fill-modifier>
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct WithFill {
     pub from: Option<Expr>,
@@ -1826,6 +2145,7 @@ impl fmt::Display for WithFill {
 /// [ClickHouse syntax]: <https://clickhouse.com/docs/en/sql-reference/statements/select/order-by#order-by-expr-with-fill-modifier>
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct InterpolateExpr {
     pub column: Ident,
@@ -1834,6 +2154,7 @@ pub struct InterpolateExpr {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct Interpolate {
     pub exprs: Option<Vec<InterpolateExpr>>,
@@ -1851,6 +2172,7 @@ impl fmt::Display for InterpolateExpr {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct Offset {
     pub value: Expr,
@@ -1866,6 +2188,7 @@ impl fmt::Display for Offset {
 /// Stores the keyword after `OFFSET <number>`
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OffsetRows {
     /// Omitting ROW/ROWS is non-standard MySQL quirk.
@@ -1886,6 +2209,7 @@ impl fmt::Display for OffsetRows {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct Fetch {
     pub with_ties: bool,
@@ -1907,18 +2231,20 @@ impl fmt::Display for Fetch {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct LockClause {
     pub lock_type: LockType,
-    pub of: Option<ObjectName>,
+    /// The tables listed after `OF`, if any
+    pub of: Vec<ObjectName>,
     pub nonblock: Option<NonBlock>,
 }
 
 impl fmt::Display for LockClause {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "FOR {}", &self.lock_type)?;
-        if let Some(ref of) = self.of {
-            write!(f, " OF {of}")?;
+        if !self.of.is_empty() {
+            write!(f, " OF {}", display_comma_separated(&self.of))?;
         }
         if let Some(ref nb) = self.nonblock {
             write!(f, " {nb}")?;
@@ -1929,6 +2255,7 @@ impl fmt::Display for LockClause {
 
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum LockType {
     Share,
@@ -1947,6 +2274,7 @@ impl fmt::Display for LockType {
 
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum NonBlock {
     Nowait,
@@ -1965,6 +2293,7 @@ impl fmt::Display for NonBlock {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum Distinct {
     /// DISTINCT
@@ -1988,6 +2317,7 @@ impl fmt::Display for Distinct {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct Top {
     /// SQL semantic equivalent of LIMIT but with same structure as FETCH.
@@ -2000,6 +2330,7 @@ pub struct Top {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum TopQuantity {
     // A parenthesized expression. MSSQL only.
@@ -2027,6 +2358,7 @@ impl fmt::Display for Top {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct Values {
     /// Was there an explicit ROWs keyword (MySQL)?
@@ -2051,6 +2383,7 @@ impl fmt::Display for Values {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct SelectInto {
     pub temporary: bool,
@@ -2075,6 +2408,7 @@ impl fmt::Display for SelectInto {
 /// [ClickHouse]: <https://clickhouse.com/docs/en/sql-reference/statements/select/group-by#rollup-modifier>
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum GroupByWithModifier {
     Rollup,
@@ -2094,6 +2428,7 @@ impl fmt::Display for GroupByWithModifier {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum GroupByExpr {
     /// ALL syntax of [Snowflake], [DuckDB] and [ClickHouse].
@@ -2138,6 +2473,7 @@ impl fmt::Display for GroupByExpr {
 /// [ClickHouse]: <https://clickhouse.com/docs/en/sql-reference/statements/select/format>
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum FormatClause {
     Identifier(Ident),
@@ -2157,24 +2493,38 @@ impl fmt::Display for FormatClause {
 /// (formats the output of a query as XML or JSON)
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum ForClause {
     Browse,
     Json {
         for_json: ForJson,
-        root: Option<String>,
+        /// `ROOT [('name')]`, `Some(None)` if no name is given
+        root: Option<Option<String>>,
         include_null_values: bool,
         without_array_wrapper: bool,
     },
     Xml {
         for_xml: ForXml,
-        elements: bool,
+        /// `XMLDATA` or `XMLSCHEMA [('uri')]`
+        schema: Option<ForXmlSchema>,
+        /// `ELEMENTS [XSINIL | ABSENT]`
+        elements: Option<ForXmlElements>,
         binary_base64: bool,
-        root: Option<String>,
+        /// `ROOT [('name')]`, `Some(None)` if no name is given
+        root: Option<Option<String>>,
         r#type: bool,
     },
 }
 
+/// Writes an optional parenthesized name such as `('root')`
+fn display_for_clause_name(f: &mut fmt::Formatter, name: &Option<String>) -> fmt::Result {
+    if let Some(name) = name {
+        write!(f, "('{}')", value::escape_single_quote_string(name))?;
+    }
+    Ok(())
+}
+
 impl fmt::Display for ForClause {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -2188,7 +2538,8 @@ impl fmt::Display for ForClause {
                 write!(f, "FOR JSON ")?;
                 write!(f, "{}", for_json)?;
                 if let Some(root) = root {
-                    write!(f, ", ROOT('{}')", root)?;
+                    write!(f, ", ROOT")?;
+                    display_for_clause_name(f, root)?;
                 }
                 if *include_null_values {
                     write!(f, ", INCLUDE_NULL_VALUES")?;
@@ -2200,6 +2551,7 @@ impl fmt::Display for ForClause {
             }
             ForClause::Xml {
                 for_xml,
+                schema,
                 elements,
                 binary_base64,
                 root,
@@ -2214,10 +2566,14 @@ impl fmt::Display for ForClause {
                     write!(f, ", TYPE")?;
                 }
                 if let Some(root) = root {
-                    write!(f, ", ROOT('{}')", root)?;
+                    write!(f, ", ROOT")?;
+                    display_for_clause_name(f, root)?;
                 }
-                if *elements {
-                    write!(f, ", ELEMENTS")?;
+                if let Some(schema) = schema {
+                    write!(f, ", {schema}")?;
+                }
+                if let Some(elements) = elements {
+                    write!(f, ", {elements}")?;
                 }
                 Ok(())
             }
@@ -2227,6 +2583,7 @@ impl fmt::Display for ForClause {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum ForXml {
     Raw(Option<String>),
@@ -2238,29 +2595,72 @@ pub enum ForXml {
 impl fmt::Display for ForXml {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            ForXml::Raw(root) => {
+            ForXml::Raw(element_name) => {
                 write!(f, "RAW")?;
-                if let Some(root) = root {
-                    write!(f, "('{}')", root)?;
-                }
-                Ok(())
+                display_for_clause_name(f, element_name)
             }
             ForXml::Auto => write!(f, "AUTO"),
             ForXml::Explicit => write!(f, "EXPLICIT"),
-            ForXml::Path(root) => {
+            ForXml::Path(element_name) => {
                 write!(f, "PATH")?;
-                if let Some(root) = root {
-                    write!(f, "('{}')", root)?;
-                }
-                Ok(())
+                display_for_clause_name(f, element_name)
             }
         }
     }
 }
 
+/// The inline schema directive of a `FOR XML` clause
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum ForXmlSchema {
+    /// `XMLDATA`
+    XmlData,
+    /// `XMLSCHEMA [('TargetNameSpaceURI')]`
+    XmlSchema(Option<String>),
+}
+
+impl fmt::Display for ForXmlSchema {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ForXmlSchema::XmlData => write!(f, "XMLDATA"),
+            ForXmlSchema::XmlSchema(uri) => {
+                write!(f, "XMLSCHEMA")?;
+                display_for_clause_name(f, uri)
+            }
+        }
+    }
+}
+
+/// The `ELEMENTS` directive of a `FOR XML` clause
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum ForXmlElements {
+    /// `ELEMENTS`
+    Default,
+    /// `ELEMENTS XSINIL`, which emits elements for `NULL` values
+    XsiNil,
+    /// `ELEMENTS ABSENT`, which omits elements for `NULL` values
+    Absent,
+}
+
+impl fmt::Display for ForXmlElements {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ForXmlElements::Default => write!(f, "ELEMENTS"),
+            ForXmlElements::XsiNil => write!(f, "ELEMENTS XSINIL"),
+            ForXmlElements::Absent => write!(f, "ELEMENTS ABSENT"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 pub enum ForJson {
     Auto,
     Path,
@@ -2275,6 +2675,125 @@ impl fmt::Display for ForJson {
     }
 }
 
+/// A table hint in `FROM t WITH (NOLOCK, INDEX(ix))`
+///
+/// [MSSQL](https://learn.microsoft.com/en-us/sql/t-sql/queries/hints-transact-sql-table)
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum TableHint {
+    /// A hint without arguments, e.g. `NOLOCK`, `READPAST` or `FORCESCAN`
+    Keyword(Ident),
+    /// `INDEX (ix_a, ix_b)`, or the older `INDEX = ix_a` when `equals` is set
+    Index { indexes: Vec<Expr>, equals: bool },
+    /// `FORCESEEK (ix (col_a, col_b))`
+    ForceSeek { index: Expr, columns: Vec<Ident> },
+    /// Any other hint, e.g. `SPATIAL_WINDOW_MAX_CELLS = 512`
+    Expr(Expr),
+}
+
+impl fmt::Display for TableHint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TableHint::Keyword(keyword) => write!(f, "{keyword}"),
+            TableHint::Index {
+                indexes,
+                equals: true,
+            } => write!(f, "INDEX = {}", display_comma_separated(indexes)),
+            TableHint::Index {
+                indexes,
+                equals: false,
+            } => write!(f, "INDEX({})", display_comma_separated(indexes)),
+            TableHint::ForceSeek { index, columns } => {
+                write!(
+                    f,
+                    "FORCESEEK({index}({}))",
+                    display_comma_separated(columns)
+                )
+            }
+            TableHint::Expr(expr) => write!(f, "{expr}"),
+        }
+    }
+}
+
+/// A query hint in `OPTION (...)`
+///
+/// [MSSQL](https://learn.microsoft.com/en-us/sql/t-sql/queries/hints-transact-sql-query)
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum QueryHint {
+    /// A hint made of keywords with an optional value, e.g. `RECOMPILE`,
+    /// `HASH JOIN`, `MAXDOP 1` or `USE PLAN N'...'`
+    Keywords {
+        keywords: Vec<Ident>,
+        value: Option<Expr>,
+    },
+    /// `MAX_GRANT_PERCENT = 5` or `LABEL = 'name'`
+    Assignment { name: Ident, value: Expr },
+    /// `OPTIMIZE FOR (@a = 1, @b UNKNOWN)`, or `OPTIMIZE FOR UNKNOWN` when
+    /// there are no variables
+    OptimizeFor(Vec<OptimizeForVariable>),
+    /// `USE HINT ('DISABLE_OPTIMIZED_NESTED_LOOP')`
+    UseHint(Vec<Value>),
+    /// `TABLE HINT (t, NOLOCK)`
+    TableHint {
+        table: ObjectName,
+        hints: Vec<TableHint>,
+    },
+}
+
+impl fmt::Display for QueryHint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            QueryHint::Keywords { keywords, value } => {
+                write!(f, "{}", display_separated(keywords, " "))?;
+                if let Some(value) = value {
+                    write!(f, " {value}")?;
+                }
+                Ok(())
+            }
+            QueryHint::Assignment { name, value } => write!(f, "{name} = {value}"),
+            QueryHint::OptimizeFor(variables) if variables.is_empty() => {
+                write!(f, "OPTIMIZE FOR UNKNOWN")
+            }
+            QueryHint::OptimizeFor(variables) => {
+                write!(f, "OPTIMIZE FOR ({})", display_comma_separated(variables))
+            }
+            QueryHint::UseHint(hints) => write!(f, "USE HINT ({})", display_comma_separated(hints)),
+            QueryHint::TableHint { table, hints } => {
+                write!(f, "TABLE HINT ({table}")?;
+                if !hints.is_empty() {
+                    write!(f, ", {}", display_comma_separated(hints))?;
+                }
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+/// A variable in `OPTIMIZE FOR (@a = 1, @b UNKNOWN)`
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct OptimizeForVariable {
+    pub name: Ident,
+    /// The value to optimize for, `None` for `UNKNOWN`
+    pub value: Option<Expr>,
+}
+
+impl fmt::Display for OptimizeForVariable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.value {
+            Some(value) => write!(f, "{} = {value}", self.name),
+            None => write!(f, "{} UNKNOWN", self.name),
+        }
+    }
+}
+
 /// A single column definition in MySQL's `JSON_TABLE` table valued function.
 /// ```sql
 /// SELECT *
@@ -2288,6 +2807,7 @@ impl fmt::Display for ForJson {
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 pub struct JsonTableColumn {
     /// The name of the column to be extracted.
     pub name: Ident,
@@ -2328,6 +2848,7 @@ impl fmt::Display for JsonTableColumn {
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 pub enum JsonTableColumnErrorHandling {
     Null,
     Default(Value),
@@ -2352,6 +2873,7 @@ impl fmt::Display for JsonTableColumnErrorHandling {
 /// <https://cloud.google.com/bigquery/docs/reference/standard-sql/query-syntax#value_tables>
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum ValueTableMode {
     AsStruct,
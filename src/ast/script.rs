@@ -0,0 +1,425 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! AST types for procedural scripts, i.e. the bodies of [PL/pgSQL] functions,
+//! [Snowflake Scripting] procedures and [MySQL stored programs].
+//!
+//! String bodies are only parsed when [`ParserOptions::parse_script_bodies`]
+//! is enabled, otherwise they are kept as opaque strings. MySQL routine
+//! bodies are not quoted and are always parsed.
+//!
+//! [PL/pgSQL]: https://www.postgresql.org/docs/current/plpgsql-structure.html
+//! [Snowflake Scripting]: https://docs.snowflake.com/en/developer-guide/snowflake-scripting/index
+//! [MySQL stored programs]: https://dev.mysql.com/doc/refman/8.0/en/sql-compound-statements.html
+//! [`ParserOptions::parse_script_bodies`]: crate::parser::ParserOptions::parse_script_bodies
+
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::fmt;
+
+#[cfg(feature = "json_schema")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "visitor")]
+use sqlparser_derive::{Visit, VisitMut};
+
+use crate::ast::{
+    display_comma_separated, display_separated, DataType, Expr, Ident, ObjectName, Query, Statement,
+};
+
+/// A block of a script:
+///
+/// ```sql
+/// [ DECLARE declarations ]
+/// BEGIN
+///     statements
+/// [ EXCEPTION
+///     WHEN condition [ OR condition ... ] THEN statements ... ]
+/// END
+/// ```
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct ScriptBlock {
+    pub declarations: Vec<ScriptDeclaration>,
+    pub statements: Vec<ScriptStatement>,
+    pub exception_handlers: Vec<ScriptExceptionHandler>,
+}
+
+/// Writes each statement followed by a `;`
+fn display_statements(f: &mut fmt::Formatter, statements: &[ScriptStatement]) -> fmt::Result {
+    for statement in statements {
+        write!(f, " {statement};")?;
+    }
+    Ok(())
+}
+
+impl fmt::Display for ScriptBlock {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if !self.declarations.is_empty() {
+            write!(f, "DECLARE")?;
+            for declaration in &self.declarations {
+                write!(f, " {declaration};")?;
+            }
+            write!(f, " ")?;
+        }
+        write!(f, "BEGIN")?;
+        display_statements(f, &self.statements)?;
+        if !self.exception_handlers.is_empty() {
+            write!(f, " EXCEPTION")?;
+            for handler in &self.exception_handlers {
+                write!(f, " {handler}")?;
+            }
+        }
+        write!(f, " END")
+    }
+}
+
+/// A variable declaration in the `DECLARE` section of a [ScriptBlock]:
+///
+/// ```sql
+/// name [ CONSTANT ] [ type ] [ NOT NULL ] [ { DEFAULT | := } expression ]
+/// ```
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct ScriptDeclaration {
+    pub name: Ident,
+    pub constant: bool,
+    pub data_type: Option<DataType>,
+    pub not_null: bool,
+    pub default: Option<Expr>,
+}
+
+impl fmt::Display for ScriptDeclaration {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        if self.constant {
+            write!(f, " CONSTANT")?;
+        }
+        if let Some(data_type) = &self.data_type {
+            write!(f, " {data_type}")?;
+        }
+        if self.not_null {
+            write!(f, " NOT NULL")?;
+        }
+        if let Some(default) = &self.default {
+            write!(f, " DEFAULT {default}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A `WHEN condition [ OR condition ... ] THEN statements` handler in the
+/// `EXCEPTION` section of a [ScriptBlock]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct ScriptExceptionHandler {
+    pub conditions: Vec<Ident>,
+    pub statements: Vec<ScriptStatement>,
+}
+
+impl fmt::Display for ScriptExceptionHandler {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "WHEN {} THEN",
+            display_separated(&self.conditions, " OR ")
+        )?;
+        display_statements(f, &self.statements)
+    }
+}
+
+/// A `condition THEN statements` branch of a [ScriptStatement::If]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct ScriptConditionalBlock {
+    pub condition: Expr,
+    pub statements: Vec<ScriptStatement>,
+}
+
+impl fmt::Display for ScriptConditionalBlock {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} THEN", self.condition)?;
+        display_statements(f, &self.statements)
+    }
+}
+
+/// The action taken by a `DECLARE ... HANDLER` once it has run its statement
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum ScriptHandlerAction {
+    Continue,
+    Exit,
+    Undo,
+}
+
+impl fmt::Display for ScriptHandlerAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ScriptHandlerAction::Continue => write!(f, "CONTINUE"),
+            ScriptHandlerAction::Exit => write!(f, "EXIT"),
+            ScriptHandlerAction::Undo => write!(f, "UNDO"),
+        }
+    }
+}
+
+/// A condition that activates a `DECLARE ... HANDLER`
+///
+/// [MySQL](https://dev.mysql.com/doc/refman/8.0/en/declare-handler.html)
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum ScriptHandlerCondition {
+    /// A MySQL error code, e.g. `1051`
+    ErrorCode(u64),
+    /// `SQLSTATE [ VALUE ] 'sqlstate'`
+    SqlState(String),
+    /// The name of a condition declared with `DECLARE ... CONDITION`
+    Name(Ident),
+    /// `SQLWARNING`
+    SqlWarning,
+    /// `NOT FOUND`
+    NotFound,
+    /// `SQLEXCEPTION`
+    SqlException,
+}
+
+impl fmt::Display for ScriptHandlerCondition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ScriptHandlerCondition::ErrorCode(code) => write!(f, "{code}"),
+            ScriptHandlerCondition::SqlState(state) => write!(f, "SQLSTATE '{state}'"),
+            ScriptHandlerCondition::Name(name) => write!(f, "{name}"),
+            ScriptHandlerCondition::SqlWarning => write!(f, "SQLWARNING"),
+            ScriptHandlerCondition::NotFound => write!(f, "NOT FOUND"),
+            ScriptHandlerCondition::SqlException => write!(f, "SQLEXCEPTION"),
+        }
+    }
+}
+
+/// A statement of a script
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum ScriptStatement {
+    /// A nested block
+    Block(ScriptBlock),
+    /// ```sql
+    /// IF condition THEN statements
+    /// [ { ELSEIF | ELSIF } condition THEN statements ... ]
+    /// [ ELSE statements ]
+    /// END IF
+    /// ```
+    If {
+        /// The `IF` branch followed by any `ELSEIF` branches
+        branches: Vec<ScriptConditionalBlock>,
+        else_statements: Option<Vec<ScriptStatement>>,
+    },
+    /// `LOOP statements END LOOP`
+    Loop(Vec<ScriptStatement>),
+    /// `WHILE condition DO statements END WHILE`
+    While {
+        condition: Expr,
+        statements: Vec<ScriptStatement>,
+    },
+    /// `REPEAT statements UNTIL condition END REPEAT`
+    Repeat {
+        statements: Vec<ScriptStatement>,
+        until: Expr,
+    },
+    /// A labeled block or loop, `label: statement [ label ]`
+    Labeled {
+        label: Ident,
+        statement: Box<ScriptStatement>,
+        /// The label repeated after the end of the block or loop
+        end_label: Option<Ident>,
+    },
+    /// `LEAVE label`, exits the labeled block or loop
+    Leave(Ident),
+    /// `ITERATE label`, starts the next iteration of the labeled loop
+    Iterate(Ident),
+    /// `EXIT [ WHEN condition ]`
+    Exit(Option<Expr>),
+    /// `RETURN [ expression ]`
+    Return(Option<Expr>),
+    /// `RETURN QUERY query`
+    ReturnQuery(Box<Query>),
+    /// `RAISE [ level ] [ expression [, ...] ]`
+    Raise {
+        /// One of `DEBUG`, `LOG`, `INFO`, `NOTICE`, `WARNING` or `EXCEPTION`
+        level: Option<Ident>,
+        arguments: Vec<Expr>,
+    },
+    /// `[ LET ] name := expression`
+    Assignment {
+        /// Whether the variable is declared by `LET` (Snowflake)
+        is_let: bool,
+        name: ObjectName,
+        value: Expr,
+    },
+    /// `DECLARE name [, ...] type [ DEFAULT expression ]` (MySQL)
+    DeclareVariables {
+        names: Vec<Ident>,
+        data_type: DataType,
+        default: Option<Expr>,
+    },
+    /// `DECLARE name CURSOR FOR query` (MySQL)
+    DeclareCursor { name: Ident, query: Box<Query> },
+    /// `DECLARE action HANDLER FOR condition [, ...] statement` (MySQL)
+    DeclareHandler {
+        action: ScriptHandlerAction,
+        conditions: Vec<ScriptHandlerCondition>,
+        statement: Box<ScriptStatement>,
+    },
+    /// `OPEN cursor` (MySQL)
+    Open(Ident),
+    /// `FETCH cursor INTO variable [, ...]` (MySQL)
+    Fetch { cursor: Ident, into: Vec<Ident> },
+    /// Any other SQL statement
+    Sql(Statement),
+}
+
+impl fmt::Display for ScriptStatement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ScriptStatement::Block(block) => write!(f, "{block}"),
+            ScriptStatement::If {
+                branches,
+                else_statements,
+            } => {
+                let mut keyword = "IF";
+                for branch in branches {
+                    write!(f, "{keyword} {branch} ")?;
+                    keyword = "ELSEIF";
+                }
+                if let Some(else_statements) = else_statements {
+                    write!(f, "ELSE")?;
+                    display_statements(f, else_statements)?;
+                    write!(f, " ")?;
+                }
+                write!(f, "END IF")
+            }
+            ScriptStatement::Loop(statements) => {
+                write!(f, "LOOP")?;
+                display_statements(f, statements)?;
+                write!(f, " END LOOP")
+            }
+            ScriptStatement::While {
+                condition,
+                statements,
+            } => {
+                write!(f, "WHILE {condition} DO")?;
+                display_statements(f, statements)?;
+                write!(f, " END WHILE")
+            }
+            ScriptStatement::Repeat { statements, until } => {
+                write!(f, "REPEAT")?;
+                display_statements(f, statements)?;
+                write!(f, " UNTIL {until} END REPEAT")
+            }
+            ScriptStatement::Labeled {
+                label,
+                statement,
+                end_label,
+            } => {
+                write!(f, "{label}: {statement}")?;
+                if let Some(end_label) = end_label {
+                    write!(f, " {end_label}")?;
+                }
+                Ok(())
+            }
+            ScriptStatement::Leave(label) => write!(f, "LEAVE {label}"),
+            ScriptStatement::Iterate(label) => write!(f, "ITERATE {label}"),
+            ScriptStatement::Exit(condition) => {
+                write!(f, "EXIT")?;
+                if let Some(condition) = condition {
+                    write!(f, " WHEN {condition}")?;
+                }
+                Ok(())
+            }
+            ScriptStatement::Return(expr) => {
+                write!(f, "RETURN")?;
+                if let Some(expr) = expr {
+                    write!(f, " {expr}")?;
+                }
+                Ok(())
+            }
+            ScriptStatement::ReturnQuery(query) => write!(f, "RETURN QUERY {query}"),
+            ScriptStatement::Raise { level, arguments } => {
+                write!(f, "RAISE")?;
+                if let Some(level) = level {
+                    write!(f, " {level}")?;
+                }
+                if !arguments.is_empty() {
+                    write!(f, " {}", display_separated(arguments, ", "))?;
+                }
+                Ok(())
+            }
+            ScriptStatement::Assignment {
+                is_let,
+                name,
+                value,
+            } => {
+                if *is_let {
+                    write!(f, "LET ")?;
+                }
+                write!(f, "{name} := {value}")
+            }
+            ScriptStatement::DeclareVariables {
+                names,
+                data_type,
+                default,
+            } => {
+                write!(f, "DECLARE {} {data_type}", display_comma_separated(names))?;
+                if let Some(default) = default {
+                    write!(f, " DEFAULT {default}")?;
+                }
+                Ok(())
+            }
+            ScriptStatement::DeclareCursor { name, query } => {
+                write!(f, "DECLARE {name} CURSOR FOR {query}")
+            }
+            ScriptStatement::DeclareHandler {
+                action,
+                conditions,
+                statement,
+            } => write!(
+                f,
+                "DECLARE {action} HANDLER FOR {} {statement}",
+                display_comma_separated(conditions)
+            ),
+            ScriptStatement::Open(cursor) => write!(f, "OPEN {cursor}"),
+            ScriptStatement::Fetch { cursor, into } => {
+                write!(f, "FETCH {cursor} INTO {}", display_comma_separated(into))
+            }
+            ScriptStatement::Sql(statement) => write!(f, "{statement}"),
+        }
+    }
+}
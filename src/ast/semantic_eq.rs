@@ -0,0 +1,337 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Comparison of statements that ignores differences in how they are
+//! written, see [`semantic_eq`].
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use core::mem;
+use core::ops::ControlFlow;
+
+use crate::ast::{
+    AlterTableOperation, DataType, FromTable, Ident, IdentValue, Privileges, SelectItem, Statement,
+    VisitMut, VisitorMut, WildcardAdditionalOptions,
+};
+
+/// The differences ignored by [`semantic_eq`]. The default options ignore
+/// everything but the case of identifiers, since whether identifiers are
+/// case sensitive depends on the database.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SemanticEqOptions {
+    /// Ignore how identifiers are quoted, e.g. `"a"` equals `a`.
+    pub ignore_identifier_quoting: bool,
+    /// Compare identifiers case insensitively, e.g. `A` equals `a`.
+    pub ignore_identifier_case: bool,
+    /// Ignore keywords that may be left out without changing the meaning of
+    /// a statement, e.g. the `AS` of `SELECT * REPLACE (a AS b)`, the `INTO`
+    /// of `INSERT INTO` or the `COLUMN` of `ALTER TABLE t ADD COLUMN`.
+    pub ignore_optional_keywords: bool,
+    /// Treat different names of the same data type as equal, e.g. `INT`
+    /// and `INTEGER`, or `BOOL` and `BOOLEAN`.
+    pub ignore_data_type_aliases: bool,
+}
+
+impl Default for SemanticEqOptions {
+    fn default() -> Self {
+        Self {
+            ignore_identifier_quoting: true,
+            ignore_identifier_case: false,
+            ignore_optional_keywords: true,
+            ignore_data_type_aliases: true,
+        }
+    }
+}
+
+/// Returns whether `left` and `right` are the same statement, ignoring the
+/// differences enabled in `options`. With all options disabled this is the
+/// same as `left == right`.
+///
+/// ```
+/// # use sqlparser::ast::{semantic_eq, SemanticEqOptions};
+/// # use sqlparser::dialect::GenericDialect;
+/// # use sqlparser::parser::Parser;
+/// let dialect = GenericDialect {};
+/// let left = Parser::parse_sql(&dialect, r#"SELECT CAST("a" AS INT) FROM t"#).unwrap();
+/// let right = Parser::parse_sql(&dialect, "SELECT CAST(a AS INTEGER) FROM t").unwrap();
+/// assert_ne!(left, right);
+/// assert!(semantic_eq(&left[0], &right[0], &SemanticEqOptions::default()));
+/// ```
+pub fn semantic_eq(left: &Statement, right: &Statement, options: &SemanticEqOptions) -> bool {
+    let mut normalize = Normalize { options };
+    let mut left = left.clone();
+    let mut right = right.clone();
+    let _ = left.visit(&mut normalize);
+    let _ = right.visit(&mut normalize);
+    left == right
+}
+
+/// Rewrites every difference ignored by its options into a single form
+struct Normalize<'a> {
+    options: &'a SemanticEqOptions,
+}
+
+impl VisitorMut for Normalize<'_> {
+    type Break = ();
+
+    fn pre_visit_ident(&mut self, ident: &mut Ident) -> ControlFlow<Self::Break> {
+        if self.options.ignore_identifier_quoting {
+            ident.quote_style = None;
+        }
+        if self.options.ignore_identifier_case {
+            ident.value = IdentValue::from(&*ident.value.to_lowercase());
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn pre_visit_data_type(&mut self, data_type: &mut DataType) -> ControlFlow<Self::Break> {
+        if self.options.ignore_data_type_aliases {
+            normalize_data_type(data_type);
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn pre_visit_select_item(&mut self, select_item: &mut SelectItem) -> ControlFlow<Self::Break> {
+        if self.options.ignore_optional_keywords {
+            if let SelectItem::Wildcard(options) | SelectItem::QualifiedWildcard(_, options) =
+                select_item
+            {
+                normalize_wildcard_options(options);
+            }
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn pre_visit_statement(&mut self, statement: &mut Statement) -> ControlFlow<Self::Break> {
+        if self.options.ignore_optional_keywords {
+            normalize_statement_keywords(statement);
+        }
+        ControlFlow::Continue(())
+    }
+}
+
+/// Replaces `data_type` by the name used for it by the SQL standard, or by
+/// the most common name if the standard has none.
+///
+/// Names that mean different types in different databases, like `INT8`
+/// (8 bytes in PostgreSQL but 8 bits in ClickHouse), are kept as is.
+fn normalize_data_type(data_type: &mut DataType) {
+    let normalized = match data_type {
+        DataType::Char(length) => DataType::Character(*length),
+        DataType::CharVarying(length) | DataType::CharacterVarying(length) => {
+            DataType::Varchar(*length)
+        }
+        DataType::CharLargeObject(length) | DataType::Clob(length) => {
+            DataType::CharacterLargeObject(*length)
+        }
+        DataType::Dec(info) => DataType::Decimal(*info),
+        DataType::Int2(width) => DataType::SmallInt(*width),
+        DataType::Int(width) | DataType::Int4(width) => DataType::Integer(*width),
+        DataType::UnsignedInt2(width) => DataType::UnsignedSmallInt(*width),
+        DataType::UnsignedInt(width) | DataType::UnsignedInt4(width) => {
+            DataType::UnsignedInteger(*width)
+        }
+        DataType::Float4 => DataType::Real,
+        DataType::Float8 | DataType::Double => DataType::DoublePrecision,
+        DataType::Bool => DataType::Boolean,
+        _ => return,
+    };
+    *data_type = normalized;
+}
+
+fn normalize_wildcard_options(options: &mut WildcardAdditionalOptions) {
+    if let Some(replace) = &mut options.opt_replace {
+        for element in &mut replace.items {
+            element.as_keyword = true;
+        }
+    }
+}
+
+/// Marks every optional keyword of `statement` as written
+fn normalize_statement_keywords(statement: &mut Statement) {
+    match statement {
+        Statement::Insert(insert) => insert.into = true,
+        Statement::Delete(delete) => {
+            if let FromTable::WithoutKeyword(from) = &mut delete.from {
+                delete.from = FromTable::WithFromKeyword(mem::take(from));
+            }
+        }
+        Statement::Truncate { table, .. } => *table = true,
+        Statement::Cache { has_as, query, .. } => *has_as = query.is_some(),
+        Statement::ExplainTable {
+            has_table_keyword, ..
+        } => *has_table_keyword = true,
+        Statement::AlterTable { operations, .. } => {
+            for operation in operations {
+                match operation {
+                    AlterTableOperation::AddColumn { column_keyword, .. }
+                    | AlterTableOperation::ChangeColumn { column_keyword, .. }
+                    | AlterTableOperation::ModifyColumn { column_keyword, .. } => {
+                        *column_keyword = true
+                    }
+                    _ => {}
+                }
+            }
+        }
+        Statement::Grant { privileges, .. } | Statement::Revoke { privileges, .. } => {
+            if let Privileges::All {
+                with_privileges_keyword,
+            } = privileges
+            {
+                *with_privileges_keyword = true;
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dialect::{Dialect, GenericDialect, MySqlDialect, PostgreSqlDialect};
+    use crate::parser::Parser;
+
+    fn equal(dialect: &dyn Dialect, left: &str, right: &str, options: &SemanticEqOptions) -> bool {
+        let left = Parser::parse_sql(dialect, left).unwrap();
+        let right = Parser::parse_sql(dialect, right).unwrap();
+        semantic_eq(&left[0], &right[0], options)
+    }
+
+    #[test]
+    fn identifiers() {
+        let options = SemanticEqOptions::default();
+        let strict = SemanticEqOptions {
+            ignore_identifier_quoting: false,
+            ..Default::default()
+        };
+        let dialect = GenericDialect {};
+        assert!(equal(
+            &dialect,
+            r#"SELECT "a" FROM "s".t"#,
+            "SELECT a FROM s.t",
+            &options
+        ));
+        assert!(!equal(
+            &dialect,
+            r#"SELECT "a" FROM t"#,
+            "SELECT a FROM t",
+            &strict
+        ));
+        assert!(!equal(
+            &dialect,
+            "SELECT A FROM t",
+            "SELECT a FROM t",
+            &options
+        ));
+
+        let case_insensitive = SemanticEqOptions {
+            ignore_identifier_case: true,
+            ..Default::default()
+        };
+        assert!(equal(
+            &dialect,
+            r#"SELECT "A" FROM T"#,
+            "SELECT a FROM t",
+            &case_insensitive
+        ));
+        assert!(!equal(
+            &dialect,
+            "SELECT a FROM t",
+            "SELECT b FROM t",
+            &case_insensitive
+        ));
+    }
+
+    #[test]
+    fn data_types() {
+        let options = SemanticEqOptions::default();
+        let strict = SemanticEqOptions {
+            ignore_data_type_aliases: false,
+            ..Default::default()
+        };
+        let dialect = PostgreSqlDialect {};
+        assert!(equal(
+            &dialect,
+            "CREATE TABLE t (a INT, b BOOL, c INT4[], d FLOAT8, e CHAR VARYING(10))",
+            "CREATE TABLE t (a INTEGER, b BOOLEAN, c INTEGER[], d DOUBLE PRECISION, e VARCHAR(10))",
+            &options
+        ));
+        assert!(!equal(
+            &dialect,
+            "SELECT a::INT",
+            "SELECT CAST(a AS INTEGER)",
+            &options
+        ));
+        assert!(equal(
+            &dialect,
+            "SELECT CAST(a AS INT)",
+            "SELECT CAST(a AS INTEGER)",
+            &options
+        ));
+        assert!(!equal(
+            &dialect,
+            "SELECT CAST(a AS INT)",
+            "SELECT CAST(a AS INTEGER)",
+            &strict
+        ));
+        assert!(!equal(
+            &dialect,
+            "SELECT CAST(a AS INT)",
+            "SELECT CAST(a AS BIGINT)",
+            &options
+        ));
+        assert!(!equal(
+            &dialect,
+            "SELECT CAST(a AS INT8)",
+            "SELECT CAST(a AS BIGINT)",
+            &options
+        ));
+    }
+
+    #[test]
+    fn optional_keywords() {
+        let options = SemanticEqOptions::default();
+        let strict = SemanticEqOptions {
+            ignore_optional_keywords: false,
+            ..Default::default()
+        };
+        let dialect = MySqlDialect {};
+        for (left, right) in [
+            ("INSERT t VALUES (1)", "INSERT INTO t VALUES (1)"),
+            (
+                "ALTER TABLE t ADD a INT, MODIFY b TEXT",
+                "ALTER TABLE t ADD COLUMN a INT, MODIFY COLUMN b TEXT",
+            ),
+            ("GRANT ALL ON t TO u", "GRANT ALL PRIVILEGES ON t TO u"),
+        ] {
+            assert!(equal(&dialect, left, right, &options), "{left}");
+            assert!(!equal(&dialect, left, right, &strict), "{left}");
+        }
+        assert!(equal(
+            &GenericDialect {},
+            "SELECT * REPLACE (a b) FROM t",
+            "SELECT * REPLACE (a AS b) FROM t",
+            &options
+        ));
+        assert!(!equal(
+            &dialect,
+            "INSERT INTO t VALUES (1)",
+            "INSERT INTO u VALUES (1)",
+            &options
+        ));
+    }
+}
@@ -21,6 +21,7 @@ use super::*;
 /// This specifies whether the trigger function should be fired once for every row affected by the trigger event, or just once per SQL statement.
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum TriggerObject {
     Row,
@@ -39,6 +40,7 @@ impl fmt::Display for TriggerObject {
 /// This clause indicates whether the following relation name is for the before-image transition relation or the after-image transition relation
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum TriggerReferencingType {
     OldTable,
@@ -54,9 +56,31 @@ impl fmt::Display for TriggerReferencingType {
     }
 }
 
+/// The row that fired a row-level trigger, see [Expr::TriggerRowColumn]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum TriggerRow {
+    /// The row after an `INSERT` or `UPDATE`
+    New,
+    /// The row before an `UPDATE` or `DELETE`
+    Old,
+}
+
+impl fmt::Display for TriggerRow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TriggerRow::New => write!(f, "NEW"),
+            TriggerRow::Old => write!(f, "OLD"),
+        }
+    }
+}
+
 /// This keyword immediately precedes the declaration of one or two relation names that provide access to the transition relations of the triggering statement
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct TriggerReferencing {
     pub refer_type: TriggerReferencingType,
@@ -79,6 +103,7 @@ impl fmt::Display for TriggerReferencing {
 /// Used to describe trigger events
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum TriggerEvent {
     Insert,
@@ -108,6 +133,7 @@ impl fmt::Display for TriggerEvent {
 /// Trigger period
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum TriggerPeriod {
     After,
@@ -128,6 +154,7 @@ impl fmt::Display for TriggerPeriod {
 /// Types of trigger body execution body.
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum TriggerExecBodyType {
     Function,
@@ -145,6 +172,7 @@ impl fmt::Display for TriggerExecBodyType {
 /// This keyword immediately precedes the declaration of one or two relation names that provide access to the transition relations of the triggering statement
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct TriggerExecBody {
     pub exec_type: TriggerExecBodyType,
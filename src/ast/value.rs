@@ -23,6 +23,8 @@ use core::fmt;
 #[cfg(feature = "bigdecimal")]
 use bigdecimal::BigDecimal;
 
+#[cfg(feature = "json_schema")]
+use schemars::JsonSchema;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -33,6 +35,7 @@ use sqlparser_derive::{Visit, VisitMut};
 /// Primitive SQL values such as number and string
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum Value {
     /// Numeric literal
@@ -83,6 +86,24 @@ pub enum Value {
     /// Triple double quoted literal with raw string prefix. Example `R"""abc"""`
     /// [BigQuery](https://cloud.google.com/bigquery/docs/reference/standard-sql/lexical#quoted_literals)
     TripleDoubleQuotedRawStringLiteral(String),
+    /// Single quoted literal with raw byte string prefix. Example `RB'abc'`
+    /// [BigQuery](https://cloud.google.com/bigquery/docs/reference/standard-sql/lexical#quoted_literals)
+    SingleQuotedRawByteStringLiteral(String),
+    /// Double quoted literal with raw byte string prefix. Example `RB"abc"`
+    /// [BigQuery](https://cloud.google.com/bigquery/docs/reference/standard-sql/lexical#quoted_literals)
+    DoubleQuotedRawByteStringLiteral(String),
+    /// Triple single quoted literal with raw byte string prefix. Example `RB'''abc'''`
+    /// [BigQuery](https://cloud.google.com/bigquery/docs/reference/standard-sql/lexical#quoted_literals)
+    TripleSingleQuotedRawByteStringLiteral(String),
+    /// Triple double quoted literal with raw byte string prefix. Example `RB"""abc"""`
+    /// [BigQuery](https://cloud.google.com/bigquery/docs/reference/standard-sql/lexical#quoted_literals)
+    TripleDoubleQuotedRawByteStringLiteral(String),
+    /// Quote-delimited string literal, e.g. `Q'[it's]'`
+    /// [Oracle](https://docs.oracle.com/en/database/oracle/oracle-database/23/sqlrf/Literals.html#GUID-1824CBAA-6E16-4921-B2A6-112FB02248DA)
+    QuoteDelimitedStringLiteral(QuoteDelimitedString),
+    /// National quote-delimited string literal, e.g. `NQ'[it's]'`
+    /// [Oracle](https://docs.oracle.com/en/database/oracle/oracle-database/23/sqlrf/Literals.html#GUID-1824CBAA-6E16-4921-B2A6-112FB02248DA)
+    NationalQuoteDelimitedStringLiteral(QuoteDelimitedString),
     /// N'string value'
     NationalStringLiteral(String),
     /// X'hex value'
@@ -113,6 +134,8 @@ impl fmt::Display for Value {
             Value::EscapedStringLiteral(v) => write!(f, "E'{}'", escape_escaped_string(v)),
             Value::UnicodeStringLiteral(v) => write!(f, "U&'{}'", escape_unicode_string(v)),
             Value::NationalStringLiteral(v) => write!(f, "N'{v}'"),
+            Value::QuoteDelimitedStringLiteral(v) => write!(f, "Q'{v}'"),
+            Value::NationalQuoteDelimitedStringLiteral(v) => write!(f, "NQ'{v}'"),
             Value::HexStringLiteral(v) => write!(f, "X'{v}'"),
             Value::Boolean(v) => write!(f, "{v}"),
             Value::SingleQuotedByteStringLiteral(v) => write!(f, "B'{v}'"),
@@ -123,6 +146,10 @@ impl fmt::Display for Value {
             Value::DoubleQuotedRawStringLiteral(v) => write!(f, "R\"{v}\""),
             Value::TripleSingleQuotedRawStringLiteral(v) => write!(f, "R'''{v}'''"),
             Value::TripleDoubleQuotedRawStringLiteral(v) => write!(f, r#"R"""{v}""""#),
+            Value::SingleQuotedRawByteStringLiteral(v) => write!(f, "RB'{v}'"),
+            Value::DoubleQuotedRawByteStringLiteral(v) => write!(f, "RB\"{v}\""),
+            Value::TripleSingleQuotedRawByteStringLiteral(v) => write!(f, "RB'''{v}'''"),
+            Value::TripleDoubleQuotedRawByteStringLiteral(v) => write!(f, r#"RB"""{v}""""#),
             Value::Null => write!(f, "NULL"),
             Value::Placeholder(v) => write!(f, "{v}"),
         }
@@ -131,6 +158,7 @@ impl fmt::Display for Value {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct DollarQuotedString {
     pub value: String,
@@ -150,8 +178,29 @@ impl fmt::Display for DollarQuotedString {
     }
 }
 
+/// The contents of a quote-delimited string literal such as `Q'[it's]'`,
+/// which is displayed without its `Q` prefix and the enclosing quotes.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct QuoteDelimitedString {
+    /// The delimiter after the opening quote, e.g. `[`
+    pub start_quote: char,
+    pub value: String,
+    /// The delimiter before the closing quote, e.g. `]`
+    pub end_quote: char,
+}
+
+impl fmt::Display for QuoteDelimitedString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}{}", self.start_quote, self.value, self.end_quote)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Ord, PartialOrd, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum DateTimeField {
     Year,
@@ -394,6 +443,7 @@ pub fn escape_unicode_string(s: &str) -> EscapeUnicodeStringLiteral<'_> {
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum TrimWhereField {
     Both,
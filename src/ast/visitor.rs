@@ -17,8 +17,14 @@
 
 //! Recursive visitors for ast Nodes. See [`Visitor`] for more details.
 
-use crate::ast::{Expr, ObjectName, Query, Statement, TableFactor};
+use crate::ast::{
+    DataType, Expr, Ident, Join, ObjectName, Query, SelectItem, Statement, TableFactor,
+};
+#[cfg(all(not(feature = "std"), target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
 use core::ops::ControlFlow;
+#[cfg(all(feature = "std", target_has_atomic = "ptr"))]
+use std::sync::Arc;
 
 /// A type that can be visited by a [`Visitor`]. See [`Visitor`] for
 /// recursively visiting parsed SQL statements.
@@ -33,6 +39,12 @@ use core::ops::ControlFlow;
 /// ```
 pub trait Visit {
     fn visit<V: Visitor>(&self, visitor: &mut V) -> ControlFlow<V::Break>;
+
+    /// Visits the children of this node without invoking the [`Visitor`]
+    /// hooks of the node itself, see e.g. [walk_query].
+    fn visit_children<V: Visitor>(&self, visitor: &mut V) -> ControlFlow<V::Break> {
+        self.visit(visitor)
+    }
 }
 
 /// A type that can be visited by a [`VisitorMut`]. See [`VisitorMut`] for
@@ -48,6 +60,12 @@ pub trait Visit {
 /// ```
 pub trait VisitMut {
     fn visit<V: VisitorMut>(&mut self, visitor: &mut V) -> ControlFlow<V::Break>;
+
+    /// Visits the children of this node without invoking the [`VisitorMut`]
+    /// hooks of the node itself, see e.g. [walk_query_mut].
+    fn visit_children<V: VisitorMut>(&mut self, visitor: &mut V) -> ControlFlow<V::Break> {
+        self.visit(visitor)
+    }
 }
 
 impl<T: Visit> Visit for Option<T> {
@@ -72,6 +90,21 @@ impl<T: Visit> Visit for Box<T> {
     fn visit<V: Visitor>(&self, visitor: &mut V) -> ControlFlow<V::Break> {
         T::visit(self, visitor)
     }
+
+    fn visit_children<V: Visitor>(&self, visitor: &mut V) -> ControlFlow<V::Break> {
+        T::visit_children(self, visitor)
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<T: Visit> Visit for Arc<T> {
+    fn visit<V: Visitor>(&self, visitor: &mut V) -> ControlFlow<V::Break> {
+        T::visit(self, visitor)
+    }
+
+    fn visit_children<V: Visitor>(&self, visitor: &mut V) -> ControlFlow<V::Break> {
+        T::visit_children(self, visitor)
+    }
 }
 
 impl<T: VisitMut> VisitMut for Option<T> {
@@ -96,6 +129,32 @@ impl<T: VisitMut> VisitMut for Box<T> {
     fn visit<V: VisitorMut>(&mut self, visitor: &mut V) -> ControlFlow<V::Break> {
         T::visit(self, visitor)
     }
+
+    fn visit_children<V: VisitorMut>(&mut self, visitor: &mut V) -> ControlFlow<V::Break> {
+        T::visit_children(self, visitor)
+    }
+}
+
+/// Shared nodes are cloned before being visited (copy-on-write), so mutating
+/// visitors never affect other owners of the same node. A node that isn't
+/// shared is visited in place.
+///
+/// The clone is made even if the visitor ends up not changing the node, so a
+/// pass that rewrites a clone of a statement, like [`to_sql`] or
+/// [`semantic_eq`], deep-copies the [`QueryRef`](crate::ast::QueryRef)s the
+/// clone shares with the original.
+///
+/// [`to_sql`]: crate::ast::writer::to_sql
+/// [`semantic_eq`]: crate::ast::semantic_eq
+#[cfg(target_has_atomic = "ptr")]
+impl<T: VisitMut + Clone> VisitMut for Arc<T> {
+    fn visit<V: VisitorMut>(&mut self, visitor: &mut V) -> ControlFlow<V::Break> {
+        T::visit(Arc::make_mut(self), visitor)
+    }
+
+    fn visit_children<V: VisitorMut>(&mut self, visitor: &mut V) -> ControlFlow<V::Break> {
+        T::visit_children(Arc::make_mut(self), visitor)
+    }
 }
 
 macro_rules! visit_noop {
@@ -113,16 +172,21 @@ macro_rules! visit_noop {
     };
 }
 
-visit_noop!(u8, u16, u32, u64, i8, i16, i32, i64, char, bool, String);
+visit_noop!(u8, u16, u32, u64, usize, i8, i16, i32, i64, char, bool, String);
+visit_noop!(core::ops::Range<usize>);
 
 #[cfg(feature = "bigdecimal")]
 visit_noop!(bigdecimal::BigDecimal);
 
+visit_noop!(compact_str::CompactString);
+
 /// A visitor that can be used to walk an AST tree.
 ///
 /// `pre_visit_` methods are invoked before visiting all children of the
 /// node and `post_visit_` methods are invoked after visiting all
-/// children of the node.
+/// children of the node. `visit_` methods drive both, and can be
+/// overridden to control the traversal of the children, see
+/// [`Visitor::visit_query`].
 ///
 /// # See also
 ///
@@ -214,6 +278,26 @@ pub trait Visitor {
         ControlFlow::Continue(())
     }
 
+    /// Invoked for any select items that appear in the AST before visiting children
+    fn pre_visit_select_item(&mut self, _select_item: &SelectItem) -> ControlFlow<Self::Break> {
+        ControlFlow::Continue(())
+    }
+
+    /// Invoked for any select items that appear in the AST after visiting children
+    fn post_visit_select_item(&mut self, _select_item: &SelectItem) -> ControlFlow<Self::Break> {
+        ControlFlow::Continue(())
+    }
+
+    /// Invoked for any joins that appear in the AST before visiting children
+    fn pre_visit_join(&mut self, _join: &Join) -> ControlFlow<Self::Break> {
+        ControlFlow::Continue(())
+    }
+
+    /// Invoked for any joins that appear in the AST after visiting children
+    fn post_visit_join(&mut self, _join: &Join) -> ControlFlow<Self::Break> {
+        ControlFlow::Continue(())
+    }
+
     /// Invoked for any expressions that appear in the AST before visiting children
     fn pre_visit_expr(&mut self, _expr: &Expr) -> ControlFlow<Self::Break> {
         ControlFlow::Continue(())
@@ -233,13 +317,119 @@ pub trait Visitor {
     fn post_visit_statement(&mut self, _statement: &Statement) -> ControlFlow<Self::Break> {
         ControlFlow::Continue(())
     }
+
+    /// Invoked for any identifiers that appear in the AST before visiting children
+    fn pre_visit_ident(&mut self, _ident: &Ident) -> ControlFlow<Self::Break> {
+        ControlFlow::Continue(())
+    }
+
+    /// Invoked for any identifiers that appear in the AST after visiting children
+    fn post_visit_ident(&mut self, _ident: &Ident) -> ControlFlow<Self::Break> {
+        ControlFlow::Continue(())
+    }
+
+    /// Invoked for any data types that appear in the AST before visiting children
+    fn pre_visit_data_type(&mut self, _data_type: &DataType) -> ControlFlow<Self::Break> {
+        ControlFlow::Continue(())
+    }
+
+    /// Invoked for any data types that appear in the AST after visiting children
+    fn post_visit_data_type(&mut self, _data_type: &DataType) -> ControlFlow<Self::Break> {
+        ControlFlow::Continue(())
+    }
+
+    /// Invoked for any queries that appear in the AST.
+    ///
+    /// The default implementation invokes [`Visitor::pre_visit_query`], visits
+    /// the children with [walk_query] and invokes
+    /// [`Visitor::post_visit_query`]. Override it to skip the children, to
+    /// return early or to replace the node before its children are visited.
+    fn visit_query(&mut self, query: &Query) -> ControlFlow<Self::Break>
+    where
+        Self: Sized,
+    {
+        self.pre_visit_query(query)?;
+        walk_query(self, query)?;
+        self.post_visit_query(query)
+    }
+
+    /// Invoked for any table factors that appear in the AST, see [`Visitor::visit_query`]
+    fn visit_table_factor(&mut self, table_factor: &TableFactor) -> ControlFlow<Self::Break>
+    where
+        Self: Sized,
+    {
+        self.pre_visit_table_factor(table_factor)?;
+        walk_table_factor(self, table_factor)?;
+        self.post_visit_table_factor(table_factor)
+    }
+
+    /// Invoked for any select items that appear in the AST, see [`Visitor::visit_query`]
+    fn visit_select_item(&mut self, select_item: &SelectItem) -> ControlFlow<Self::Break>
+    where
+        Self: Sized,
+    {
+        self.pre_visit_select_item(select_item)?;
+        walk_select_item(self, select_item)?;
+        self.post_visit_select_item(select_item)
+    }
+
+    /// Invoked for any joins that appear in the AST, see [`Visitor::visit_query`]
+    fn visit_join(&mut self, join: &Join) -> ControlFlow<Self::Break>
+    where
+        Self: Sized,
+    {
+        self.pre_visit_join(join)?;
+        walk_join(self, join)?;
+        self.post_visit_join(join)
+    }
+
+    /// Invoked for any expressions that appear in the AST, see [`Visitor::visit_query`]
+    fn visit_expr(&mut self, expr: &Expr) -> ControlFlow<Self::Break>
+    where
+        Self: Sized,
+    {
+        self.pre_visit_expr(expr)?;
+        walk_expr(self, expr)?;
+        self.post_visit_expr(expr)
+    }
+
+    /// Invoked for any statements that appear in the AST, see [`Visitor::visit_query`]
+    fn visit_statement(&mut self, statement: &Statement) -> ControlFlow<Self::Break>
+    where
+        Self: Sized,
+    {
+        self.pre_visit_statement(statement)?;
+        walk_statement(self, statement)?;
+        self.post_visit_statement(statement)
+    }
+
+    /// Invoked for any identifiers that appear in the AST, see [`Visitor::visit_query`]
+    fn visit_ident(&mut self, ident: &Ident) -> ControlFlow<Self::Break>
+    where
+        Self: Sized,
+    {
+        self.pre_visit_ident(ident)?;
+        self.post_visit_ident(ident)
+    }
+
+    /// Invoked for any data types that appear in the AST, see [`Visitor::visit_query`]
+    fn visit_data_type(&mut self, data_type: &DataType) -> ControlFlow<Self::Break>
+    where
+        Self: Sized,
+    {
+        self.pre_visit_data_type(data_type)?;
+        walk_data_type(self, data_type)?;
+        self.post_visit_data_type(data_type)
+    }
 }
 
 /// A visitor that can be used to mutate an AST tree.
 ///
 /// `pre_visit_` methods are invoked before visiting all children of the
 /// node and `post_visit_` methods are invoked after visiting all
-/// children of the node.
+/// children of the node. `visit_` methods drive both, and can be
+/// overridden to control the traversal of the children, see
+/// [`VisitorMut::visit_query`].
 ///
 /// # See also
 ///
@@ -264,7 +454,7 @@ pub trait Visitor {
 ///
 ///   fn post_visit_expr(&mut self, expr: &mut Expr) -> ControlFlow<Self::Break> {
 ///     if let Expr::Identifier(Ident{ value, ..}) = expr {
-///         *value = value.replace("to_replace", "replaced")
+///         *value = value.replace("to_replace", "replaced").into()
 ///     }
 ///     ControlFlow::Continue(())
 ///   }
@@ -318,6 +508,29 @@ pub trait VisitorMut {
         ControlFlow::Continue(())
     }
 
+    /// Invoked for any select items that appear in the AST before visiting children
+    fn pre_visit_select_item(&mut self, _select_item: &mut SelectItem) -> ControlFlow<Self::Break> {
+        ControlFlow::Continue(())
+    }
+
+    /// Invoked for any select items that appear in the AST after visiting children
+    fn post_visit_select_item(
+        &mut self,
+        _select_item: &mut SelectItem,
+    ) -> ControlFlow<Self::Break> {
+        ControlFlow::Continue(())
+    }
+
+    /// Invoked for any joins that appear in the AST before visiting children
+    fn pre_visit_join(&mut self, _join: &mut Join) -> ControlFlow<Self::Break> {
+        ControlFlow::Continue(())
+    }
+
+    /// Invoked for any joins that appear in the AST after visiting children
+    fn post_visit_join(&mut self, _join: &mut Join) -> ControlFlow<Self::Break> {
+        ControlFlow::Continue(())
+    }
+
     /// Invoked for any expressions that appear in the AST before visiting children
     fn pre_visit_expr(&mut self, _expr: &mut Expr) -> ControlFlow<Self::Break> {
         ControlFlow::Continue(())
@@ -337,6 +550,212 @@ pub trait VisitorMut {
     fn post_visit_statement(&mut self, _statement: &mut Statement) -> ControlFlow<Self::Break> {
         ControlFlow::Continue(())
     }
+
+    /// Invoked for any identifiers that appear in the AST before visiting children
+    fn pre_visit_ident(&mut self, _ident: &mut Ident) -> ControlFlow<Self::Break> {
+        ControlFlow::Continue(())
+    }
+
+    /// Invoked for any identifiers that appear in the AST after visiting children
+    fn post_visit_ident(&mut self, _ident: &mut Ident) -> ControlFlow<Self::Break> {
+        ControlFlow::Continue(())
+    }
+
+    /// Invoked for any data types that appear in the AST before visiting children
+    fn pre_visit_data_type(&mut self, _data_type: &mut DataType) -> ControlFlow<Self::Break> {
+        ControlFlow::Continue(())
+    }
+
+    /// Invoked for any data types that appear in the AST after visiting children
+    fn post_visit_data_type(&mut self, _data_type: &mut DataType) -> ControlFlow<Self::Break> {
+        ControlFlow::Continue(())
+    }
+
+    /// Invoked for any queries that appear in the AST.
+    ///
+    /// The default implementation invokes [`VisitorMut::pre_visit_query`], visits
+    /// the children with [walk_query_mut] and invokes
+    /// [`VisitorMut::post_visit_query`]. Override it to skip the children, to
+    /// return early or to replace the node before its children are visited.
+    fn visit_query(&mut self, query: &mut Query) -> ControlFlow<Self::Break>
+    where
+        Self: Sized,
+    {
+        self.pre_visit_query(query)?;
+        walk_query_mut(self, query)?;
+        self.post_visit_query(query)
+    }
+
+    /// Invoked for any table factors that appear in the AST, see [`VisitorMut::visit_query`]
+    fn visit_table_factor(&mut self, table_factor: &mut TableFactor) -> ControlFlow<Self::Break>
+    where
+        Self: Sized,
+    {
+        self.pre_visit_table_factor(table_factor)?;
+        walk_table_factor_mut(self, table_factor)?;
+        self.post_visit_table_factor(table_factor)
+    }
+
+    /// Invoked for any select items that appear in the AST, see [`VisitorMut::visit_query`]
+    fn visit_select_item(&mut self, select_item: &mut SelectItem) -> ControlFlow<Self::Break>
+    where
+        Self: Sized,
+    {
+        self.pre_visit_select_item(select_item)?;
+        walk_select_item_mut(self, select_item)?;
+        self.post_visit_select_item(select_item)
+    }
+
+    /// Invoked for any joins that appear in the AST, see [`VisitorMut::visit_query`]
+    fn visit_join(&mut self, join: &mut Join) -> ControlFlow<Self::Break>
+    where
+        Self: Sized,
+    {
+        self.pre_visit_join(join)?;
+        walk_join_mut(self, join)?;
+        self.post_visit_join(join)
+    }
+
+    /// Invoked for any expressions that appear in the AST, see [`VisitorMut::visit_query`]
+    fn visit_expr(&mut self, expr: &mut Expr) -> ControlFlow<Self::Break>
+    where
+        Self: Sized,
+    {
+        self.pre_visit_expr(expr)?;
+        walk_expr_mut(self, expr)?;
+        self.post_visit_expr(expr)
+    }
+
+    /// Invoked for any statements that appear in the AST, see [`VisitorMut::visit_query`]
+    fn visit_statement(&mut self, statement: &mut Statement) -> ControlFlow<Self::Break>
+    where
+        Self: Sized,
+    {
+        self.pre_visit_statement(statement)?;
+        walk_statement_mut(self, statement)?;
+        self.post_visit_statement(statement)
+    }
+
+    /// Invoked for any identifiers that appear in the AST, see [`VisitorMut::visit_query`]
+    fn visit_ident(&mut self, ident: &mut Ident) -> ControlFlow<Self::Break>
+    where
+        Self: Sized,
+    {
+        self.pre_visit_ident(ident)?;
+        self.post_visit_ident(ident)
+    }
+
+    /// Invoked for any data types that appear in the AST, see [`VisitorMut::visit_query`]
+    fn visit_data_type(&mut self, data_type: &mut DataType) -> ControlFlow<Self::Break>
+    where
+        Self: Sized,
+    {
+        self.pre_visit_data_type(data_type)?;
+        walk_data_type_mut(self, data_type)?;
+        self.post_visit_data_type(data_type)
+    }
+}
+
+/// Visits the children of a [`Query`] with `visitor`, without invoking the
+/// `query` hooks of `visitor` for `query` itself.
+pub fn walk_query<V: Visitor>(visitor: &mut V, query: &Query) -> ControlFlow<V::Break> {
+    query.visit_children(visitor)
+}
+
+/// Visits the children of a [`Query`] with `visitor`, without invoking the
+/// `query` hooks of `visitor` for `query` itself.
+pub fn walk_query_mut<V: VisitorMut>(visitor: &mut V, query: &mut Query) -> ControlFlow<V::Break> {
+    query.visit_children(visitor)
+}
+
+/// Visits the children of a [`TableFactor`] with `visitor`, without invoking the
+/// `table_factor` hooks of `visitor` for `table_factor` itself.
+pub fn walk_table_factor<V: Visitor>(
+    visitor: &mut V,
+    table_factor: &TableFactor,
+) -> ControlFlow<V::Break> {
+    table_factor.visit_children(visitor)
+}
+
+/// Visits the children of a [`TableFactor`] with `visitor`, without invoking the
+/// `table_factor` hooks of `visitor` for `table_factor` itself.
+pub fn walk_table_factor_mut<V: VisitorMut>(
+    visitor: &mut V,
+    table_factor: &mut TableFactor,
+) -> ControlFlow<V::Break> {
+    table_factor.visit_children(visitor)
+}
+
+/// Visits the children of a [`SelectItem`] with `visitor`, without invoking the
+/// `select_item` hooks of `visitor` for `select_item` itself.
+pub fn walk_select_item<V: Visitor>(
+    visitor: &mut V,
+    select_item: &SelectItem,
+) -> ControlFlow<V::Break> {
+    select_item.visit_children(visitor)
+}
+
+/// Visits the children of a [`SelectItem`] with `visitor`, without invoking the
+/// `select_item` hooks of `visitor` for `select_item` itself.
+pub fn walk_select_item_mut<V: VisitorMut>(
+    visitor: &mut V,
+    select_item: &mut SelectItem,
+) -> ControlFlow<V::Break> {
+    select_item.visit_children(visitor)
+}
+
+/// Visits the children of a [`Join`] with `visitor`, without invoking the
+/// `join` hooks of `visitor` for `join` itself.
+pub fn walk_join<V: Visitor>(visitor: &mut V, join: &Join) -> ControlFlow<V::Break> {
+    join.visit_children(visitor)
+}
+
+/// Visits the children of a [`Join`] with `visitor`, without invoking the
+/// `join` hooks of `visitor` for `join` itself.
+pub fn walk_join_mut<V: VisitorMut>(visitor: &mut V, join: &mut Join) -> ControlFlow<V::Break> {
+    join.visit_children(visitor)
+}
+
+/// Visits the children of an [`Expr`] with `visitor`, without invoking the
+/// `expr` hooks of `visitor` for `expr` itself.
+pub fn walk_expr<V: Visitor>(visitor: &mut V, expr: &Expr) -> ControlFlow<V::Break> {
+    expr.visit_children(visitor)
+}
+
+/// Visits the children of an [`Expr`] with `visitor`, without invoking the
+/// `expr` hooks of `visitor` for `expr` itself.
+pub fn walk_expr_mut<V: VisitorMut>(visitor: &mut V, expr: &mut Expr) -> ControlFlow<V::Break> {
+    expr.visit_children(visitor)
+}
+
+/// Visits the children of a [`DataType`] with `visitor`, without invoking the
+/// `data_type` hooks of `visitor` for `data_type` itself.
+pub fn walk_data_type<V: Visitor>(visitor: &mut V, data_type: &DataType) -> ControlFlow<V::Break> {
+    data_type.visit_children(visitor)
+}
+
+/// Visits the children of a [`DataType`] with `visitor`, without invoking the
+/// `data_type` hooks of `visitor` for `data_type` itself.
+pub fn walk_data_type_mut<V: VisitorMut>(
+    visitor: &mut V,
+    data_type: &mut DataType,
+) -> ControlFlow<V::Break> {
+    data_type.visit_children(visitor)
+}
+
+/// Visits the children of a [`Statement`] with `visitor`, without invoking the
+/// `statement` hooks of `visitor` for `statement` itself.
+pub fn walk_statement<V: Visitor>(visitor: &mut V, statement: &Statement) -> ControlFlow<V::Break> {
+    statement.visit_children(visitor)
+}
+
+/// Visits the children of a [`Statement`] with `visitor`, without invoking the
+/// `statement` hooks of `visitor` for `statement` itself.
+pub fn walk_statement_mut<V: VisitorMut>(
+    visitor: &mut V,
+    statement: &mut Statement,
+) -> ControlFlow<V::Break> {
+    statement.visit_children(visitor)
 }
 
 struct RelationVisitor<F>(F);
@@ -411,7 +830,7 @@ where
 ///
 /// // visit statements, renaming table foo to bar
 /// visit_relations_mut(&mut statements, |table| {
-///   table.0[0].value = table.0[0].value.replace("foo", "bar");
+///   table.0[0].value = table.0[0].value.replace("foo", "bar").into();
 ///   ControlFlow::<()>::Continue(())
 /// });
 ///
@@ -495,6 +914,7 @@ where
 /// ```
 /// # use sqlparser::parser::Parser;
 /// # use sqlparser::dialect::GenericDialect;
+/// # use std::sync::Arc;
 /// # use sqlparser::ast::{Expr, visit_expressions_mut, visit_statements_mut};
 /// # use core::ops::ControlFlow;
 /// let sql = "SELECT (SELECT y FROM z LIMIT 9) FROM t LIMIT 3";
@@ -503,7 +923,7 @@ where
 /// // Remove all select limits in sub-queries
 /// visit_expressions_mut(&mut statements, |expr| {
 ///   if let Expr::Subquery(q) = expr {
-///      q.limit = None
+///      Arc::make_mut(q).limit = None
 ///   }
 ///   ControlFlow::<()>::Continue(())
 /// });
@@ -539,6 +959,7 @@ where
 ///           filter: None,
 ///           over: None,
 ///           parameters: FunctionArguments::None,
+///           safe: false,
 ///           within_group: vec![],
 ///      });
 ///   }
@@ -618,6 +1039,7 @@ where
 /// ```
 /// # use sqlparser::parser::Parser;
 /// # use sqlparser::dialect::GenericDialect;
+/// # use std::sync::Arc;
 /// # use sqlparser::ast::{Statement, visit_statements_mut};
 /// # use core::ops::ControlFlow;
 /// let sql = "SELECT x FROM foo LIMIT 9+$limit; SELECT * FROM t LIMIT f()";
@@ -626,7 +1048,7 @@ where
 /// // Remove all select limits in outer statements (not in sub-queries)
 /// visit_statements_mut(&mut statements, |stmt| {
 ///   if let Statement::Query(q) = stmt {
-///      q.limit = None
+///      Arc::make_mut(q).limit = None
 ///   }
 ///   ControlFlow::<()>::Continue(())
 /// });
@@ -646,6 +1068,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ast::{Ident, QueryRef, TableAlias};
     use crate::dialect::GenericDialect;
     use crate::parser::Parser;
     use crate::tokenizer::Tokenizer;
@@ -728,7 +1151,7 @@ mod tests {
             .unwrap();
 
         let mut visitor = TestVisitor::default();
-        s.visit(&mut visitor);
+        let _ = s.visit(&mut visitor);
         visitor.visited
     }
 
@@ -884,4 +1307,160 @@ mod tests {
             assert_eq!(actual, expected)
         }
     }
+
+    #[test]
+    fn test_select_item_and_join_hooks() {
+        #[derive(Default)]
+        struct V {
+            visited: Vec<String>,
+        }
+
+        impl Visitor for V {
+            type Break = ();
+
+            fn pre_visit_select_item(&mut self, item: &SelectItem) -> ControlFlow<Self::Break> {
+                self.visited.push(format!("PRE: SELECT ITEM: {item}"));
+                ControlFlow::Continue(())
+            }
+
+            fn post_visit_select_item(&mut self, item: &SelectItem) -> ControlFlow<Self::Break> {
+                self.visited.push(format!("POST: SELECT ITEM: {item}"));
+                ControlFlow::Continue(())
+            }
+
+            fn pre_visit_join(&mut self, join: &Join) -> ControlFlow<Self::Break> {
+                self.visited.push(format!("PRE: JOIN:{join}"));
+                ControlFlow::Continue(())
+            }
+
+            fn post_visit_join(&mut self, join: &Join) -> ControlFlow<Self::Break> {
+                self.visited.push(format!("POST: JOIN:{join}"));
+                ControlFlow::Continue(())
+            }
+
+            fn pre_visit_expr(&mut self, expr: &Expr) -> ControlFlow<Self::Break> {
+                self.visited.push(format!("PRE: EXPR: {expr}"));
+                ControlFlow::Continue(())
+            }
+        }
+
+        let sql = "SELECT a AS b FROM t1 JOIN t2 ON c";
+        let statements = Parser::parse_sql(&GenericDialect {}, sql).unwrap();
+        let mut visitor = V::default();
+        let _ = statements.visit(&mut visitor);
+        assert_eq!(
+            visitor.visited,
+            [
+                "PRE: SELECT ITEM: a AS b",
+                "PRE: EXPR: a",
+                "POST: SELECT ITEM: a AS b",
+                "PRE: JOIN: JOIN t2 ON c",
+                "PRE: EXPR: c",
+                "POST: JOIN: JOIN t2 ON c",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_visit_mut_skip_children() {
+        // Replaces `secret` with a subquery and counts the remaining
+        // relations, without descending into the replacement
+        #[derive(Default)]
+        struct Rewriter {
+            relations: usize,
+        }
+
+        impl VisitorMut for Rewriter {
+            type Break = ();
+
+            fn visit_table_factor(
+                &mut self,
+                table_factor: &mut TableFactor,
+            ) -> ControlFlow<Self::Break> {
+                if let TableFactor::Table { name, alias, .. } = table_factor {
+                    if name.to_string() == "secret" {
+                        let alias = alias.clone().unwrap_or(TableAlias {
+                            name: Ident::new("secret"),
+                            columns: vec![],
+                        });
+                        let sql = "SELECT * FROM secret WHERE visible";
+                        let subquery = Parser::new(&GenericDialect {})
+                            .try_with_sql(sql)
+                            .unwrap()
+                            .parse_query()
+                            .unwrap();
+                        *table_factor = TableFactor::Derived {
+                            lateral: false,
+                            subquery: QueryRef::new(subquery),
+                            alias: Some(alias),
+                        };
+                        return ControlFlow::Continue(());
+                    }
+                }
+                walk_table_factor_mut(self, table_factor)
+            }
+
+            fn pre_visit_relation(
+                &mut self,
+                _relation: &mut ObjectName,
+            ) -> ControlFlow<Self::Break> {
+                self.relations += 1;
+                ControlFlow::Continue(())
+            }
+        }
+
+        let sql = "SELECT * FROM t JOIN secret AS s ON t.id = s.id";
+        let mut statements = Parser::parse_sql(&GenericDialect {}, sql).unwrap();
+        let mut rewriter = Rewriter::default();
+        let _ = VisitMut::visit(&mut statements, &mut rewriter);
+        assert_eq!(
+            statements[0].to_string(),
+            "SELECT * FROM t JOIN (SELECT * FROM secret WHERE visible) AS s ON t.id = s.id"
+        );
+        assert_eq!(rewriter.relations, 1);
+    }
+
+    #[test]
+    fn test_visit_mut_break() {
+        // Stops at the first query, before any of its children are visited
+        struct Stop;
+
+        impl VisitorMut for Stop {
+            type Break = String;
+
+            fn visit_query(&mut self, query: &mut Query) -> ControlFlow<Self::Break> {
+                ControlFlow::Break(query.to_string())
+            }
+
+            fn pre_visit_expr(&mut self, _expr: &mut Expr) -> ControlFlow<Self::Break> {
+                panic!("expressions should not be visited");
+            }
+        }
+
+        let sql = "SELECT a FROM t WHERE b IN (SELECT c FROM u)";
+        let mut statements = Parser::parse_sql(&GenericDialect {}, sql).unwrap();
+        assert_eq!(
+            VisitMut::visit(&mut statements, &mut Stop),
+            ControlFlow::Break(sql.to_string())
+        );
+    }
+
+    #[test]
+    fn test_visit_mut_shared_query() {
+        let sql = "SELECT a FROM t WHERE b IN (SELECT c FROM u)";
+        let statements = Parser::parse_sql(&GenericDialect {}, sql).unwrap();
+        let original = statements[0].clone();
+        let mut copy = original.clone();
+
+        let _ = visit_relations_mut(&mut copy, |name| {
+            name.0[0].value.push_str("_renamed");
+            ControlFlow::<()>::Continue(())
+        });
+
+        assert_eq!(original.to_string(), sql);
+        assert_eq!(
+            copy.to_string(),
+            "SELECT a FROM t_renamed WHERE b IN (SELECT c FROM u_renamed)"
+        );
+    }
 }
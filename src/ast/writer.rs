@@ -0,0 +1,393 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Rendering of AST nodes as SQL in a configurable style.
+//!
+//! The `Display` implementation of the AST writes keywords in upper case
+//! and keeps identifiers quoted the way they were written. [`to_sql`]
+//! instead renders a node according to [`SqlWriterOptions`], e.g. to match
+//! a house style or to avoid collisions with reserved words. Syntax that
+//! can be written in several places, like the `IGNORE NULLS` of a window
+//! function, is also moved to a place the dialect supports:
+//!
+//! ```
+//! # use sqlparser::ast::writer::{IdentifierQuote, KeywordCase, SqlWriterOptions};
+//! # use sqlparser::dialect::MySqlDialect;
+//! # use sqlparser::parser::Parser;
+//! let dialect = MySqlDialect {};
+//! let statements = Parser::parse_sql(&dialect, "SELECT COUNT(*), `order` FROM t").unwrap();
+//! let options = SqlWriterOptions {
+//!     identifier_quote: Some(IdentifierQuote::Backtick),
+//!     keyword_case: KeywordCase::Lower,
+//! };
+//! assert_eq!(
+//!     statements[0].to_sql(&dialect, &options).unwrap(),
+//!     "select COUNT(*), `order` from `t`"
+//! );
+//! ```
+
+#[cfg(not(feature = "std"))]
+use alloc::{collections::BTreeSet, format, string::String, string::ToString, vec::Vec};
+use core::fmt;
+use core::ops::ControlFlow;
+#[cfg(feature = "std")]
+use std::collections::BTreeSet;
+
+use crate::ast::{
+    Expr, Function, FunctionArgumentClause, FunctionArguments, Ident, ObjectName, Statement, Visit,
+    VisitMut, Visitor, VisitorMut,
+};
+use crate::dialect::Dialect;
+use crate::keywords::Keyword;
+use crate::parser::{ParserError, SourceOffsets};
+use crate::tokenizer::{Token, Tokenizer};
+
+/// The case of keywords in the output of [`to_sql`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeywordCase {
+    /// `SELECT a FROM t`, as written by `Display`
+    #[default]
+    Upper,
+    /// `select a from t`. Identifiers are written as is, even if they are
+    /// keywords, like the function name `COUNT` or a MySQL table `Status`.
+    Lower,
+}
+
+/// The quotes [`to_sql`] can put around identifiers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IdentifierQuote {
+    /// `"a"`, as in the SQL standard
+    Double,
+    /// `` `a` ``, as in MySQL and BigQuery
+    Backtick,
+    /// `[a]`, as in MSSQL
+    Bracket,
+}
+
+impl IdentifierQuote {
+    /// The opening quote character, as stored in [`Ident::quote_style`]
+    fn char(self) -> char {
+        match self {
+            IdentifierQuote::Double => '"',
+            IdentifierQuote::Backtick => '`',
+            IdentifierQuote::Bracket => '[',
+        }
+    }
+}
+
+/// Options for [`to_sql`]. The default options render the same SQL as
+/// `Display`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct SqlWriterOptions {
+    /// Quote every identifier this way, regardless of how it was written.
+    /// Function names are left as is, since quoting them changes how they
+    /// are resolved.
+    pub identifier_quote: Option<IdentifierQuote>,
+    /// The case to write keywords in.
+    pub keyword_case: KeywordCase,
+}
+
+/// Renders `node` as SQL according to `options`. The `dialect` is used to
+/// find the keywords in the rendered SQL, and to place clauses such as
+/// `IGNORE NULLS` where the dialect accepts them.
+pub fn to_sql<T>(
+    node: &T,
+    dialect: &dyn Dialect,
+    options: &SqlWriterOptions,
+) -> Result<String, ParserError>
+where
+    T: Visit + VisitMut + Clone + fmt::Display,
+{
+    let mut node = node.clone();
+    let _ = VisitMut::visit(&mut node, &mut PlaceNullTreatment { dialect });
+    if let Some(quote) = options.identifier_quote {
+        let _ = VisitMut::visit(
+            &mut node,
+            &mut QuoteIdentifiers {
+                quote: quote.char(),
+                function_names: Vec::new(),
+            },
+        );
+    }
+    let sql = node.to_string();
+    match options.keyword_case {
+        KeywordCase::Upper => Ok(sql),
+        KeywordCase::Lower => {
+            let mut identifiers = UnquotedIdentifiers::default();
+            let _ = Visit::visit(&node, &mut identifiers);
+            lowercase_keywords(&sql, dialect, &identifiers.0)
+        }
+    }
+}
+
+impl Statement {
+    /// Renders the statement as SQL according to `options`, see [`to_sql`]
+    pub fn to_sql(
+        &self,
+        dialect: &dyn Dialect,
+        options: &SqlWriterOptions,
+    ) -> Result<String, ParserError> {
+        to_sql(self, dialect, options)
+    }
+}
+
+/// Moves the null treatment of window functions, written either within
+/// or after the parameter list, to a position supported by the dialect
+struct PlaceNullTreatment<'a> {
+    dialect: &'a dyn Dialect,
+}
+
+impl PlaceNullTreatment<'_> {
+    fn place(&self, function: &mut Function) {
+        let FunctionArguments::List(list) = &mut function.args else {
+            return;
+        };
+        if let Some(null_treatment) = function.null_treatment {
+            if !self
+                .dialect
+                .supports_window_function_null_treatment_after_args()
+                && self.dialect.supports_window_function_null_treatment_arg()
+                && !list.args.is_empty()
+            {
+                list.clauses.insert(
+                    0,
+                    FunctionArgumentClause::IgnoreOrRespectNulls(null_treatment),
+                );
+                function.null_treatment = None;
+            }
+        } else if !self.dialect.supports_window_function_null_treatment_arg()
+            && self
+                .dialect
+                .supports_window_function_null_treatment_after_args()
+        {
+            let position = list.clauses.iter().position(|clause| {
+                matches!(clause, FunctionArgumentClause::IgnoreOrRespectNulls(_))
+            });
+            if let Some(position) = position {
+                let FunctionArgumentClause::IgnoreOrRespectNulls(null_treatment) =
+                    list.clauses.remove(position)
+                else {
+                    unreachable!()
+                };
+                function.null_treatment = Some(null_treatment);
+            }
+        }
+    }
+}
+
+impl VisitorMut for PlaceNullTreatment<'_> {
+    type Break = ();
+
+    fn post_visit_expr(&mut self, expr: &mut Expr) -> ControlFlow<Self::Break> {
+        if let Expr::Function(function) = expr {
+            self.place(function);
+        }
+        ControlFlow::Continue(())
+    }
+}
+
+/// Quotes every identifier except for function names
+struct QuoteIdentifiers {
+    quote: char,
+    /// The original names of the functions being visited
+    function_names: Vec<ObjectName>,
+}
+
+impl VisitorMut for QuoteIdentifiers {
+    type Break = ();
+
+    fn pre_visit_expr(&mut self, expr: &mut Expr) -> ControlFlow<Self::Break> {
+        if let Expr::Function(function) = expr {
+            self.function_names.push(function.name.clone());
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn post_visit_expr(&mut self, expr: &mut Expr) -> ControlFlow<Self::Break> {
+        if let Expr::Function(function) = expr {
+            if let Some(name) = self.function_names.pop() {
+                function.name = name;
+            }
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn post_visit_ident(&mut self, ident: &mut Ident) -> ControlFlow<Self::Break> {
+        ident.quote_style = Some(self.quote);
+        ControlFlow::Continue(())
+    }
+}
+
+/// Collects the values of the unquoted identifiers of a node
+#[derive(Default)]
+struct UnquotedIdentifiers(BTreeSet<String>);
+
+impl Visitor for UnquotedIdentifiers {
+    type Break = ();
+
+    fn post_visit_ident(&mut self, ident: &Ident) -> ControlFlow<Self::Break> {
+        if ident.quote_style.is_none() {
+            self.0.insert(ident.value.to_string());
+        }
+        ControlFlow::Continue(())
+    }
+}
+
+/// Lowercases the unquoted keywords of `sql`, keeping everything else as is.
+/// Words written like one of the `identifiers` are taken to be identifiers.
+fn lowercase_keywords(
+    sql: &str,
+    dialect: &dyn Dialect,
+    identifiers: &BTreeSet<String>,
+) -> Result<String, ParserError> {
+    let tokens = Tokenizer::new(dialect, sql)
+        .with_unescape(false)
+        .tokenize_with_location()?;
+    let offsets = SourceOffsets::new(sql);
+    let mut result = String::with_capacity(sql.len());
+    let mut copied = 0;
+    for token in tokens {
+        let Token::Word(word) = &token.token else {
+            continue;
+        };
+        if word.quote_style.is_some()
+            || word.keyword == Keyword::NoKeyword
+            || identifiers.contains(word.value.as_str())
+        {
+            continue;
+        }
+        let start = offsets.offset(token.location);
+        result.push_str(&sql[copied..start]);
+        result.push_str(&word.value.to_lowercase());
+        copied = start + word.value.len();
+    }
+    result.push_str(&sql[copied..]);
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dialect::{
+        BigQueryDialect, GenericDialect, MsSqlDialect, MySqlDialect, PostgreSqlDialect,
+    };
+    use crate::parser::Parser;
+
+    fn write(sql: &str, options: &SqlWriterOptions) -> String {
+        let dialect = PostgreSqlDialect {};
+        let statement = Parser::parse_sql(&dialect, sql).unwrap().remove(0);
+        statement.to_sql(&dialect, options).unwrap()
+    }
+
+    #[test]
+    fn default_options_match_display() {
+        let sql = r#"SELECT "a", b FROM s.t WHERE c = 'select'"#;
+        assert_eq!(write(sql, &SqlWriterOptions::default()), sql);
+    }
+
+    #[test]
+    fn quote_identifiers() {
+        let options = SqlWriterOptions {
+            identifier_quote: Some(IdentifierQuote::Double),
+            ..Default::default()
+        };
+        assert_eq!(
+            write(
+                r#"SELECT a, "b""c", lower(t.name) AS n FROM s.t JOIN u USING(id)"#,
+                &options
+            ),
+            r#"SELECT "a", "b""c", lower("t"."name") AS "n" FROM "s"."t" JOIN "u" USING("id")"#
+        );
+        assert_eq!(
+            write("UPDATE t SET a = 1 WHERE b IS NULL", &options),
+            r#"UPDATE "t" SET "a" = 1 WHERE "b" IS NULL"#
+        );
+    }
+
+    #[test]
+    fn lowercase_keywords() {
+        let options = SqlWriterOptions {
+            keyword_case: KeywordCase::Lower,
+            ..Default::default()
+        };
+        assert_eq!(
+            write(
+                r#"SELECT "SELECT", CAST(a AS INT) FROM t WHERE b = 'FROM' AND c IS NOT NULL"#,
+                &options
+            ),
+            r#"select "SELECT", cast(a as int) from t where b = 'FROM' and c is not null"#
+        );
+
+        // identifiers which are also keywords are kept as written
+        let dialect = MySqlDialect {};
+        let statement = Parser::parse_sql(&dialect, "SELECT COUNT(*), Status.name FROM Status")
+            .unwrap()
+            .remove(0);
+        assert_eq!(
+            statement.to_sql(&dialect, &options).unwrap(),
+            "select COUNT(*), Status.name from Status"
+        );
+    }
+
+    #[test]
+    fn null_treatment_position() {
+        let options = SqlWriterOptions::default();
+        let to_sql = |sql: &str, dialect: &dyn Dialect| {
+            let statement = Parser::parse_sql(&GenericDialect {}, sql)
+                .unwrap()
+                .remove(0);
+            statement.to_sql(dialect, &options).unwrap()
+        };
+        let after = "SELECT FIRST_VALUE(a) IGNORE NULLS OVER (ORDER BY b) FROM t";
+        let within = "SELECT FIRST_VALUE(a IGNORE NULLS) OVER (ORDER BY b) FROM t";
+
+        assert_eq!(to_sql(after, &BigQueryDialect {}), within);
+        assert_eq!(to_sql(within, &BigQueryDialect {}), within);
+        assert_eq!(to_sql(within, &PostgreSqlDialect {}), after);
+        assert_eq!(to_sql(after, &PostgreSqlDialect {}), after);
+        // both positions are valid for the generic dialect
+        assert_eq!(to_sql(after, &GenericDialect {}), after);
+        assert_eq!(to_sql(within, &GenericDialect {}), within);
+
+        assert_eq!(
+            to_sql(
+                "SELECT ARRAY_AGG(a RESPECT NULLS ORDER BY b LIMIT 1)",
+                &PostgreSqlDialect {}
+            ),
+            "SELECT ARRAY_AGG(a ORDER BY b LIMIT 1) RESPECT NULLS"
+        );
+        assert_eq!(
+            to_sql("SELECT LAG() IGNORE NULLS OVER ()", &BigQueryDialect {}),
+            "SELECT LAG() IGNORE NULLS OVER ()"
+        );
+    }
+
+    #[test]
+    fn bracket_identifier_quote() {
+        let statement = Parser::parse_sql(&MsSqlDialect {}, "SELECT a FROM s.t")
+            .unwrap()
+            .remove(0);
+        let options = SqlWriterOptions {
+            identifier_quote: Some(IdentifierQuote::Bracket),
+            ..Default::default()
+        };
+        assert_eq!(
+            statement.to_sql(&MsSqlDialect {}, &options).unwrap(),
+            "SELECT [a] FROM [s].[t]"
+        );
+    }
+}
@@ -16,6 +16,34 @@
 // under the License.
 
 use crate::dialect::Dialect;
+use crate::keywords::Keyword;
+use core::iter::Peekable;
+use core::str::Chars;
+
+/// Keywords that can't be used as a table alias in BigQuery, see
+/// [`RESERVED_FOR_TABLE_ALIAS`](crate::keywords::RESERVED_FOR_TABLE_ALIAS).
+/// The clauses of other dialects, which aren't parsed for BigQuery, are left
+/// out so that their keywords can be aliases, e.g. `FROM user_settings
+/// settings`, and `TABLESAMPLE` is reserved.
+///
+/// See <https://cloud.google.com/bigquery/docs/reference/standard-sql/lexical#reserved_keywords>
+const RESERVED_FOR_TABLE_ALIAS_BIGQUERY: &[Keyword] = reserved_keywords!(
+    RESERVED_FOR_TABLE_ALIAS,
+    add: [TABLESAMPLE],
+    remove: [PREWHERE, SETTINGS, FORMAT, START, CONNECT, MATCH_RECOGNIZE],
+);
+
+/// Keywords that can't be used as a column alias in BigQuery, see
+/// [`RESERVED_FOR_COLUMN_ALIAS`](crate::keywords::RESERVED_FOR_COLUMN_ALIAS).
+/// `TOP` and `VIEW` aren't reserved keywords in BigQuery, e.g.
+/// `SELECT MAX(score) top`.
+///
+/// See <https://cloud.google.com/bigquery/docs/reference/standard-sql/lexical#reserved_keywords>
+const RESERVED_FOR_COLUMN_ALIAS_BIGQUERY: &[Keyword] = reserved_keywords!(
+    RESERVED_FOR_COLUMN_ALIAS,
+    add: [],
+    remove: [TOP, VIEW],
+);
 
 /// A [`Dialect`] for [Google Bigquery](https://cloud.google.com/bigquery/)
 #[derive(Debug, Default)]
@@ -49,11 +77,39 @@ impl Dialect for BigQueryDialect {
         true
     }
 
+    /// See [doc](https://cloud.google.com/bigquery/docs/reference/standard-sql/navigation_functions#first_value)
+    fn supports_window_function_null_treatment_after_args(&self) -> bool {
+        false
+    }
+
     // See https://cloud.google.com/bigquery/docs/reference/standard-sql/lexical#escape_sequences
     fn supports_string_literal_backslash_escape(&self) -> bool {
         true
     }
 
+    // See https://cloud.google.com/bigquery/docs/reference/standard-sql/lexical#escape_sequences
+    fn unescape_backslash_sequence(&self, mut chars: Peekable<Chars<'_>>) -> Option<(char, usize)> {
+        let unescaped = match chars.next()? {
+            'a' => '\u{7}',
+            'b' => '\u{8}',
+            'f' => '\u{c}',
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            'v' => '\u{b}',
+            ch @ ('\\' | '?' | '"' | '\'' | '`') => ch,
+            ch @ '0'..='7' => {
+                let digits = core::iter::once(ch).chain(chars.take(2));
+                return unescape_code_point(digits, 8, 3).map(|ch| (ch, 3));
+            }
+            'x' | 'X' => return unescape_code_point(chars.take(2), 16, 2).map(|ch| (ch, 3)),
+            'u' => return unescape_code_point(chars.take(4), 16, 4).map(|ch| (ch, 5)),
+            'U' => return unescape_code_point(chars.take(8), 16, 8).map(|ch| (ch, 9)),
+            _ => return None,
+        };
+        Some((unescaped, 1))
+    }
+
     /// See [doc](https://cloud.google.com/bigquery/docs/reference/standard-sql/window-function-calls#ref_named_window)
     fn supports_window_clause_named_window_reference(&self) -> bool {
         true
@@ -72,4 +128,27 @@ impl Dialect for BigQueryDialect {
     fn require_interval_qualifier(&self) -> bool {
         true
     }
+
+    fn reserved_keywords_for_table_alias(&self) -> &'static [Keyword] {
+        RESERVED_FOR_TABLE_ALIAS_BIGQUERY
+    }
+
+    fn reserved_keywords_for_column_alias(&self) -> &'static [Keyword] {
+        RESERVED_FOR_COLUMN_ALIAS_BIGQUERY
+    }
+}
+
+/// Returns the character with the code point written with exactly `len`
+/// `digits` in `radix`, if it is one.
+fn unescape_code_point(digits: impl Iterator<Item = char>, radix: u32, len: usize) -> Option<char> {
+    let mut code_point = 0;
+    let mut count = 0;
+    for digit in digits {
+        code_point = code_point * radix + digit.to_digit(radix)?;
+        count += 1;
+    }
+    if count != len {
+        return None;
+    }
+    char::from_u32(code_point)
 }
@@ -16,6 +16,16 @@
 // under the License.
 
 use crate::dialect::Dialect;
+use crate::keywords::Keyword;
+
+/// Keywords that can't be used as a table alias in ClickHouse, see
+/// [`RESERVED_FOR_TABLE_ALIAS`](crate::keywords::RESERVED_FOR_TABLE_ALIAS).
+/// `ARRAY` starts a join, e.g. `FROM t ARRAY JOIN arr`.
+const RESERVED_FOR_TABLE_ALIAS_CLICKHOUSE: &[Keyword] = reserved_keywords!(
+    RESERVED_FOR_TABLE_ALIAS,
+    add: [ARRAY],
+    remove: [],
+);
 
 // A [`Dialect`] for [ClickHouse](https://clickhouse.com/).
 #[derive(Debug)]
@@ -50,4 +60,8 @@ impl Dialect for ClickHouseDialect {
     fn supports_limit_comma(&self) -> bool {
         true
     }
+
+    fn reserved_keywords_for_table_alias(&self) -> &'static [Keyword] {
+        RESERVED_FOR_TABLE_ALIAS_CLICKHOUSE
+    }
 }
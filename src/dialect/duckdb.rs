@@ -16,6 +16,18 @@
 // under the License.
 
 use crate::dialect::Dialect;
+use crate::keywords::Keyword;
+
+/// Keywords that can't be used as a table alias in DuckDB, see
+/// [`RESERVED_FOR_TABLE_ALIAS`](crate::keywords::RESERVED_FOR_TABLE_ALIAS).
+/// `SEMI`, `ANTI` and `ASOF` start joins, e.g. `FROM t1 SEMI JOIN t2`.
+///
+/// See <https://duckdb.org/docs/sql/query_syntax/from#semi-and-anti-joins>
+const RESERVED_FOR_TABLE_ALIAS_DUCKDB: &[Keyword] = reserved_keywords!(
+    RESERVED_FOR_TABLE_ALIAS,
+    add: [SEMI, ANTI, ASOF],
+    remove: [],
+);
 
 /// A [`Dialect`] for [DuckDB](https://duckdb.org/)
 #[derive(Debug, Default)]
@@ -66,4 +78,8 @@ impl Dialect for DuckDbDialect {
     fn supports_explain_with_utility_options(&self) -> bool {
         true
     }
+
+    fn reserved_keywords_for_table_alias(&self) -> &'static [Keyword] {
+        RESERVED_FOR_TABLE_ALIAS_DUCKDB
+    }
 }
@@ -16,6 +16,18 @@
 // under the License.
 
 use crate::dialect::Dialect;
+use crate::keywords::Keyword;
+
+/// Keywords that can't be used as a table alias in the generic dialect, see
+/// [`RESERVED_FOR_TABLE_ALIAS`](crate::keywords::RESERVED_FOR_TABLE_ALIAS).
+/// The keywords of clauses only some dialects support are reserved too, e.g.
+/// DuckDB's `SEMI JOIN`, Snowflake's `ASOF JOIN ... MATCH_CONDITION`,
+/// ClickHouse's `ARRAY JOIN` and PostgreSQL's `DELETE FROM t RETURNING ...`.
+const RESERVED_FOR_TABLE_ALIAS_GENERIC: &[Keyword] = reserved_keywords!(
+    RESERVED_FOR_TABLE_ALIAS,
+    add: [SEMI, ANTI, ASOF, MATCH_CONDITION, ARRAY, RETURNING],
+    remove: [],
+);
 
 /// A permissive, general purpose [`Dialect`], which parses a wide variety of SQL
 /// statements, from many different dialects.
@@ -107,4 +119,12 @@ impl Dialect for GenericDialect {
     fn supports_asc_desc_in_column_definition(&self) -> bool {
         true
     }
+
+    fn supports_trigger_row_references(&self) -> bool {
+        true
+    }
+
+    fn reserved_keywords_for_table_alias(&self) -> &'static [Keyword] {
+        RESERVED_FOR_TABLE_ALIAS_GENERIC
+    }
 }
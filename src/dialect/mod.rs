@@ -15,6 +15,29 @@
 // specific language governing permissions and limitations
 // under the License.
 
+/// Defines the keywords a dialect reserves as aliases in terms of one of the
+/// default lists in [`keywords`], e.g.
+/// `reserved_keywords!(RESERVED_FOR_TABLE_ALIAS, add: [USE], remove: [TOP])`
+/// evaluates to a `&'static [Keyword]` with `USE` and without `TOP`.
+///
+/// Adding a keyword the default list already has, or removing one it
+/// doesn't have, fails to compile.
+macro_rules! reserved_keywords {
+    (
+        $base:ident,
+        add: [$($add:ident),* $(,)?],
+        remove: [$($remove:ident),* $(,)?] $(,)?
+    ) => {{
+        use $crate::keywords::Keyword;
+        const BASE: &[Keyword] = $crate::keywords::$base;
+        const ADD: &[Keyword] = &[$(Keyword::$add),*];
+        const REMOVE: &[Keyword] = &[$(Keyword::$remove),*];
+        const LEN: usize = $crate::dialect::reserved_keywords_len(BASE, ADD, REMOVE);
+        const KEYWORDS: [Keyword; LEN] = $crate::dialect::reserved_keywords(BASE, ADD, REMOVE);
+        &KEYWORDS
+    }};
+}
+
 mod ansi;
 mod bigquery;
 mod clickhouse;
@@ -24,6 +47,7 @@ mod generic;
 mod hive;
 mod mssql;
 mod mysql;
+mod oracle;
 mod postgresql;
 mod redshift;
 mod snowflake;
@@ -45,6 +69,7 @@ pub use self::generic::GenericDialect;
 pub use self::hive::HiveDialect;
 pub use self::mssql::MsSqlDialect;
 pub use self::mysql::MySqlDialect;
+pub use self::oracle::OracleDialect;
 pub use self::postgresql::PostgreSqlDialect;
 pub use self::redshift::RedshiftSqlDialect;
 pub use self::snowflake::SnowflakeDialect;
@@ -108,8 +133,12 @@ macro_rules! dialect_of {
 /// assert!(dialect.is::<AnsiDialect>());
 /// ```
 ///
+/// Dialects are required to be [`Send`] and [`Sync`] so that a single
+/// instance can be shared between threads, e.g. by
+/// [`Parser::parse_statements_parallel`](crate::parser::Parser::parse_statements_parallel).
+///
 /// [module level documentation]: crate
-pub trait Dialect: Debug + Any {
+pub trait Dialect: Debug + Any + Send + Sync {
     /// Determine the [`TypeId`] of this dialect.
     ///
     /// By default, return the same [`TypeId`] as [`Any::type_id`]. Can be overridden
@@ -167,6 +196,33 @@ pub trait Dialect: Debug + Any {
         false
     }
 
+    /// Returns the character that the escape sequence at the start of
+    /// `chars`, which follow a backslash in a string literal, stands for,
+    /// along with the number of characters in the sequence. Only used if
+    /// [`Self::supports_string_literal_backslash_escape`].
+    ///
+    /// Returns `None` if the sequence is not a valid escape sequence, which
+    /// makes the tokenizer report an error.
+    ///
+    /// The default follows [MySQL], any other escaped character stands for
+    /// itself, e.g. `\'` for `'`.
+    ///
+    /// [MySQL]: https://dev.mysql.com/doc/refman/8.0/en/string-literals.html#character-escape-sequences
+    fn unescape_backslash_sequence(&self, mut chars: Peekable<Chars<'_>>) -> Option<(char, usize)> {
+        let unescaped = match chars.next()? {
+            '0' => '\0',
+            'a' => '\u{7}',
+            'b' => '\u{8}',
+            'f' => '\u{c}',
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            'Z' => '\u{1a}',
+            ch => ch,
+        };
+        Some((unescaped, 1))
+    }
+
     /// Determine if the dialect supports string literals with `U&` prefix.
     /// This is used to specify Unicode code points in string literals.
     /// For example, in PostgreSQL, the following is a valid string literal:
@@ -216,6 +272,26 @@ pub trait Dialect: Debug + Any {
         false
     }
 
+    /// Returns true if the dialect supports the `(+)` outer join operator,
+    /// e.g. `SELECT * FROM t1, t2 WHERE t1.id = t2.id(+)`.
+    fn supports_outer_join_operator(&self) -> bool {
+        false
+    }
+
+    /// Returns true if the dialect supports `MINUS` as a synonym of the
+    /// `EXCEPT` set operator, e.g. `SELECT a FROM t MINUS SELECT a FROM u`.
+    ///
+    /// `MINUS` can't be used as an unquoted alias in such dialects.
+    fn supports_minus_set_operator(&self) -> bool {
+        false
+    }
+
+    /// Returns true if the dialect supports quote-delimited string literals,
+    /// e.g. `Q'[it's]'` or `NQ'{it's}'`.
+    fn supports_quote_delimited_string(&self) -> bool {
+        false
+    }
+
     /// Returns true if the dialect supports the MATCH_RECOGNIZE operation.
     fn supports_match_recognize(&self) -> bool {
         false
@@ -258,6 +334,18 @@ pub trait Dialect: Debug + Any {
         false
     }
 
+    /// Returns true if the dialect supports specifying null treatment
+    /// after a window function's parameter list, e.g.
+    /// ```sql
+    /// FIRST_VALUE(a) IGNORE NULLS OVER ()
+    /// ```
+    ///
+    /// `ast::writer::to_sql` moves the null treatment into the parameter
+    /// list for dialects that return false.
+    fn supports_window_function_null_treatment_after_args(&self) -> bool {
+        true
+    }
+
     /// Returns true if the dialect supports defining structs or objects using a
     /// syntax like `{'x': 1, 'y': 2, 'z': 3}`.
     fn supports_dictionary_syntax(&self) -> bool {
@@ -270,6 +358,13 @@ pub trait Dialect: Debug + Any {
         false
     }
 
+    /// Returns true if the dialect supports `NEW.column` and `OLD.column`
+    /// references to the row that fired a trigger, which are parsed as
+    /// [`Expr::TriggerRowColumn`] in the condition or body of a trigger.
+    fn supports_trigger_row_references(&self) -> bool {
+        false
+    }
+
     /// Returns true if the dialect supports lambda functions, for example:
     ///
     /// ```sql
@@ -353,6 +448,73 @@ pub trait Dialect: Debug + Any {
     /// This method is called to get the precedence of the next token.
     ///
     /// If `None` is returned, falls back to the default behavior.
+    ///
+    /// Together with [`Self::parse_infix`] this adds infix operators: return
+    /// a precedence relative to [`Self::prec_value`] here, and parse the
+    /// operator and its right operand with [`Parser::parse_infix_operand`]
+    /// in [`Self::parse_infix`].
+    ///
+    /// # Example
+    /// ```
+    /// # use sqlparser::ast::{BinaryOperator, Expr};
+    /// # use sqlparser::dialect::{Associativity, Dialect, Precedence};
+    /// # use sqlparser::parser::{Parser, ParserError};
+    /// # use sqlparser::tokenizer::Token;
+    /// /// Parses `^` as a right associative power operator, which binds
+    /// /// tighter than `*`.
+    /// #[derive(Debug)]
+    /// struct PowerDialect;
+    ///
+    /// impl Dialect for PowerDialect {
+    ///     fn is_identifier_start(&self, ch: char) -> bool {
+    ///         ch.is_ascii_alphabetic()
+    ///     }
+    ///
+    ///     fn is_identifier_part(&self, ch: char) -> bool {
+    ///         ch.is_ascii_alphanumeric()
+    ///     }
+    ///
+    ///     fn get_next_precedence(&self, parser: &Parser) -> Option<Result<u8, ParserError>> {
+    ///         match parser.peek_token().token {
+    ///             Token::Caret => Some(Ok(self.prec_value(Precedence::MulDivModOp) + 1)),
+    ///             _ => None,
+    ///         }
+    ///     }
+    ///
+    ///     fn parse_infix(
+    ///         &self,
+    ///         parser: &mut Parser,
+    ///         expr: &Expr,
+    ///         precedence: u8,
+    ///     ) -> Option<Result<Expr, ParserError>> {
+    ///         if !parser.consume_token(&Token::Caret) {
+    ///             return None;
+    ///         }
+    ///         Some(
+    ///             parser
+    ///                 .parse_infix_operand(precedence, Associativity::Right)
+    ///                 .map(|right| Expr::BinaryOp {
+    ///                     left: Box::new(expr.clone()),
+    ///                     op: BinaryOperator::PGExp,
+    ///                     right: Box::new(right),
+    ///                 }),
+    ///         )
+    ///     }
+    /// }
+    ///
+    /// let expr = Parser::new(&PowerDialect)
+    ///     .try_with_sql("2 * 3 ^ 2 ^ 2")
+    ///     .unwrap()
+    ///     .parse_expr()
+    ///     .unwrap();
+    /// let Expr::BinaryOp { op: BinaryOperator::Multiply, right, .. } = expr else {
+    ///     panic!("`*` binds weaker than `^`");
+    /// };
+    /// let Expr::BinaryOp { op: BinaryOperator::PGExp, right, .. } = *right else {
+    ///     panic!();
+    /// };
+    /// assert!(matches!(*right, Expr::BinaryOp { op: BinaryOperator::PGExp, .. }));
+    /// ```
     fn get_next_precedence(&self, _parser: &Parser) -> Option<Result<u8, ParserError>> {
         // return None to fall back to the default behavior
         None
@@ -377,9 +539,9 @@ pub trait Dialect: Debug + Any {
             };
         }
 
-        let token = parser.peek_token();
+        let token = parser.peek_token_ref();
         debug!("get_next_precedence_full() {:?}", token);
-        match token.token {
+        match &token.token {
             Token::Word(w) if w.keyword == Keyword::OR => Ok(p!(Or)),
             Token::Word(w) if w.keyword == Keyword::AND => Ok(p!(And)),
             Token::Word(w) if w.keyword == Keyword::XOR => Ok(p!(Xor)),
@@ -398,21 +560,23 @@ pub trait Dialect: Debug + Any {
                 }
             }
 
-            Token::Word(w) if w.keyword == Keyword::NOT => match parser.peek_nth_token(1).token {
-                // The precedence of NOT varies depending on keyword that
-                // follows it. If it is followed by IN, BETWEEN, or LIKE,
-                // it takes on the precedence of those tokens. Otherwise, it
-                // is not an infix operator, and therefore has zero
-                // precedence.
-                Token::Word(w) if w.keyword == Keyword::IN => Ok(p!(Between)),
-                Token::Word(w) if w.keyword == Keyword::BETWEEN => Ok(p!(Between)),
-                Token::Word(w) if w.keyword == Keyword::LIKE => Ok(p!(Like)),
-                Token::Word(w) if w.keyword == Keyword::ILIKE => Ok(p!(Like)),
-                Token::Word(w) if w.keyword == Keyword::RLIKE => Ok(p!(Like)),
-                Token::Word(w) if w.keyword == Keyword::REGEXP => Ok(p!(Like)),
-                Token::Word(w) if w.keyword == Keyword::SIMILAR => Ok(p!(Like)),
-                _ => Ok(self.prec_unknown()),
-            },
+            Token::Word(w) if w.keyword == Keyword::NOT => {
+                match &parser.peek_nth_token_ref(1).token {
+                    // The precedence of NOT varies depending on keyword that
+                    // follows it. If it is followed by IN, BETWEEN, or LIKE,
+                    // it takes on the precedence of those tokens. Otherwise, it
+                    // is not an infix operator, and therefore has zero
+                    // precedence.
+                    Token::Word(w) if w.keyword == Keyword::IN => Ok(p!(Between)),
+                    Token::Word(w) if w.keyword == Keyword::BETWEEN => Ok(p!(Between)),
+                    Token::Word(w) if w.keyword == Keyword::LIKE => Ok(p!(Like)),
+                    Token::Word(w) if w.keyword == Keyword::ILIKE => Ok(p!(Like)),
+                    Token::Word(w) if w.keyword == Keyword::RLIKE => Ok(p!(Like)),
+                    Token::Word(w) if w.keyword == Keyword::REGEXP => Ok(p!(Like)),
+                    Token::Word(w) if w.keyword == Keyword::SIMILAR => Ok(p!(Like)),
+                    _ => Ok(self.prec_unknown()),
+                }
+            }
             Token::Word(w) if w.keyword == Keyword::IS => Ok(p!(Is)),
             Token::Word(w) if w.keyword == Keyword::IN => Ok(p!(Between)),
             Token::Word(w) if w.keyword == Keyword::BETWEEN => Ok(p!(Between)),
@@ -480,26 +644,10 @@ pub trait Dialect: Debug + Any {
 
     /// Decide the lexical Precedence of operators.
     ///
-    /// Uses (APPROXIMATELY) <https://www.postgresql.org/docs/7.0/operators.htm#AEN2026> as a reference
+    /// Defaults to [`Precedence::default_value`]. Override this to move
+    /// whole groups of built in operators relative to each other.
     fn prec_value(&self, prec: Precedence) -> u8 {
-        match prec {
-            Precedence::DoubleColon => 50,
-            Precedence::AtTz => 41,
-            Precedence::MulDivModOp => 40,
-            Precedence::PlusMinus => 30,
-            Precedence::Xor => 24,
-            Precedence::Ampersand => 23,
-            Precedence::Caret => 22,
-            Precedence::Pipe => 21,
-            Precedence::Between => 20,
-            Precedence::Eq => 20,
-            Precedence::Like => 19,
-            Precedence::Is => 17,
-            Precedence::PgOther => 16,
-            Precedence::UnaryNot => 15,
-            Precedence::And => 10,
-            Precedence::Or => 5,
-        }
+        prec.default_value()
     }
 
     /// Returns the precedence when the precedence is otherwise unknown
@@ -561,31 +709,156 @@ pub trait Dialect: Debug + Any {
     fn supports_asc_desc_in_column_definition(&self) -> bool {
         false
     }
+
+    /// Returns the keywords that can't be used as a table alias without `AS`,
+    /// e.g. `FROM t WHERE` must not parse `WHERE` as an alias of `t`.
+    fn reserved_keywords_for_table_alias(&self) -> &'static [Keyword] {
+        keywords::RESERVED_FOR_TABLE_ALIAS
+    }
+
+    /// Returns the keywords that can't be used as a column alias without `AS`,
+    /// e.g. `SELECT a FROM` must not parse `FROM` as an alias of `a`.
+    fn reserved_keywords_for_column_alias(&self) -> &'static [Keyword] {
+        keywords::RESERVED_FOR_COLUMN_ALIAS
+    }
+}
+
+/// `keywords.contains(&keyword)`, which isn't usable in a `const fn`.
+const fn contains_keyword(keywords: &[Keyword], keyword: Keyword) -> bool {
+    let mut i = 0;
+    while i < keywords.len() {
+        if keywords[i] as usize == keyword as usize {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// The length of the list built by [`reserved_keywords`].
+const fn reserved_keywords_len(base: &[Keyword], add: &[Keyword], remove: &[Keyword]) -> usize {
+    base.len() + add.len() - remove.len()
+}
+
+/// The keywords of `base` that aren't in `remove`, followed by `add`, see
+/// the `reserved_keywords!` macro.
+const fn reserved_keywords<const N: usize>(
+    base: &[Keyword],
+    add: &[Keyword],
+    remove: &[Keyword],
+) -> [Keyword; N] {
+    let mut keywords = [Keyword::NoKeyword; N];
+    let mut len = 0;
+    let mut i = 0;
+    while i < remove.len() {
+        assert!(
+            contains_keyword(base, remove[i]),
+            "removed keyword is not reserved"
+        );
+        i += 1;
+    }
+    i = 0;
+    while i < base.len() {
+        if !contains_keyword(remove, base[i]) {
+            keywords[len] = base[i];
+            len += 1;
+        }
+        i += 1;
+    }
+    i = 0;
+    while i < add.len() {
+        assert!(
+            !contains_keyword(base, add[i]),
+            "added keyword is already reserved"
+        );
+        keywords[len] = add[i];
+        len += 1;
+        i += 1;
+    }
+    keywords
 }
 
 /// This represents the operators for which precedence must be defined
 ///
 /// higher number -> higher precedence
-#[derive(Debug, Clone, Copy)]
+///
+/// [`Dialect::prec_value`] maps each group to its numeric precedence, the
+/// default values are available as [`Precedence::default_value`]. Custom
+/// operators can be slotted in between two groups by returning a value in
+/// between from [`Dialect::get_next_precedence`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Precedence {
+    /// `::` casts, `[]` subscripts and other postfix operators
     DoubleColon,
+    /// `AT TIME ZONE`
     AtTz,
+    /// `*`, `/`, `%`, `DIV` and `||`
     MulDivModOp,
+    /// `+` and `-`
     PlusMinus,
+    /// `XOR`
     Xor,
+    /// `&`
     Ampersand,
+    /// `^`, `#`, `<<` and `>>`
     Caret,
+    /// `|`
     Pipe,
+    /// `[NOT] BETWEEN` and `[NOT] IN`
     Between,
+    /// Comparisons, e.g. `=`, `<>` or `<=>`
     Eq,
+    /// `[NOT] LIKE` and similar pattern matching operators
     Like,
+    /// `IS`
     Is,
+    /// Other PostgreSQL operators, e.g. `->` or `@>`
     PgOther,
+    /// Unary `NOT`
     UnaryNot,
+    /// `AND`
     And,
+    /// `OR`
     Or,
 }
 
+impl Precedence {
+    /// The default numeric precedence of this group, as returned by the
+    /// default implementation of [`Dialect::prec_value`].
+    ///
+    /// Uses (APPROXIMATELY) <https://www.postgresql.org/docs/7.0/operators.htm#AEN2026> as a reference
+    pub const fn default_value(self) -> u8 {
+        match self {
+            Precedence::DoubleColon => 50,
+            Precedence::AtTz => 41,
+            Precedence::MulDivModOp => 40,
+            Precedence::PlusMinus => 30,
+            Precedence::Xor => 24,
+            Precedence::Ampersand => 23,
+            Precedence::Caret => 22,
+            Precedence::Pipe => 21,
+            Precedence::Between => 20,
+            Precedence::Eq => 20,
+            Precedence::Like => 19,
+            Precedence::Is => 17,
+            Precedence::PgOther => 16,
+            Precedence::UnaryNot => 15,
+            Precedence::And => 10,
+            Precedence::Or => 5,
+        }
+    }
+}
+
+/// How a sequence of infix operators with the same precedence is grouped,
+/// see [`Parser::parse_infix_operand`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Associativity {
+    /// `a op b op c` is `(a op b) op c`, as for all built in operators
+    Left,
+    /// `a op b op c` is `a op (b op c)`
+    Right,
+}
+
 impl dyn Dialect {
     #[inline]
     pub fn is<T: Dialect>(&self) -> bool {
@@ -613,6 +886,7 @@ pub fn dialect_from_str(dialect_name: impl AsRef<str>) -> Option<Box<dyn Dialect
         "ansi" => Some(Box::new(AnsiDialect {})),
         "duckdb" => Some(Box::new(DuckDbDialect {})),
         "databricks" => Some(Box::new(DatabricksDialect {})),
+        "oracle" => Some(Box::new(OracleDialect {})),
         _ => None,
     }
 }
@@ -666,6 +940,8 @@ mod tests {
         assert!(parse_dialect("DuckDb").is::<DuckDbDialect>());
         assert!(parse_dialect("DataBricks").is::<DatabricksDialect>());
         assert!(parse_dialect("databricks").is::<DatabricksDialect>());
+        assert!(parse_dialect("Oracle").is::<OracleDialect>());
+        assert!(parse_dialect("oracle").is::<OracleDialect>());
 
         // error cases
         assert!(dialect_from_str("Unknown").is_none());
@@ -720,6 +996,13 @@ mod tests {
                 self.0.supports_string_literal_backslash_escape()
             }
 
+            fn unescape_backslash_sequence(
+                &self,
+                chars: std::iter::Peekable<std::str::Chars<'_>>,
+            ) -> Option<(char, usize)> {
+                self.0.unescape_backslash_sequence(chars)
+            }
+
             fn is_proper_identifier_inside_quotes(
                 &self,
                 chars: std::iter::Peekable<std::str::Chars<'_>>,
@@ -16,6 +16,28 @@
 // under the License.
 
 use crate::dialect::Dialect;
+use crate::keywords::Keyword;
+
+/// Keywords that can't be used as a table alias in MSSQL, see
+/// [`RESERVED_FOR_TABLE_ALIAS`](crate::keywords::RESERVED_FOR_TABLE_ALIAS).
+/// `OPTION` starts the query hints, e.g. `SELECT * FROM t OPTION (RECOMPILE)`,
+/// and the control flow keywords can follow a statement, e.g.
+/// `IF 1 = 1 SELECT * FROM t ELSE SELECT 2`.
+const RESERVED_FOR_TABLE_ALIAS_MSSQL: &[Keyword] = reserved_keywords!(
+    RESERVED_FOR_TABLE_ALIAS,
+    add: [OPTION, ELSE, IF, WHILE, RETURN],
+    remove: [],
+);
+
+/// Keywords that can't be used as a column alias in MSSQL, see
+/// [`RESERVED_FOR_COLUMN_ALIAS`](crate::keywords::RESERVED_FOR_COLUMN_ALIAS).
+/// The control flow keywords can follow a statement, e.g.
+/// `IF 1 = 1 SELECT 1 ELSE SELECT 2`.
+const RESERVED_FOR_COLUMN_ALIAS_MSSQL: &[Keyword] = reserved_keywords!(
+    RESERVED_FOR_COLUMN_ALIAS,
+    add: [ELSE, IF, WHILE, RETURN],
+    remove: [],
+);
 
 /// A [`Dialect`] for [Microsoft SQL Server](https://www.microsoft.com/en-us/sql-server/)
 #[derive(Debug)]
@@ -49,4 +71,16 @@ impl Dialect for MsSqlDialect {
     fn supports_connect_by(&self) -> bool {
         true
     }
+
+    fn supports_outer_join_operator(&self) -> bool {
+        true
+    }
+
+    fn reserved_keywords_for_table_alias(&self) -> &'static [Keyword] {
+        RESERVED_FOR_TABLE_ALIAS_MSSQL
+    }
+
+    fn reserved_keywords_for_column_alias(&self) -> &'static [Keyword] {
+        RESERVED_FOR_COLUMN_ALIAS_MSSQL
+    }
 }
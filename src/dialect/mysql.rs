@@ -20,11 +20,37 @@ use alloc::boxed::Box;
 
 use crate::{
     ast::{BinaryOperator, Expr, LockTable, LockTableType, Statement},
-    dialect::Dialect,
+    dialect::{Dialect, Precedence},
     keywords::Keyword,
     parser::{Parser, ParserError},
+    tokenizer::Token,
 };
 
+/// Keywords that can't be used as a table alias in MySQL, see
+/// [`RESERVED_FOR_TABLE_ALIAS`](crate::keywords::RESERVED_FOR_TABLE_ALIAS).
+/// The clauses of other dialects, which aren't parsed for MySQL, are left out
+/// so that their keywords can be aliases, e.g. `FROM user_settings settings`,
+/// and `STRAIGHT_JOIN` and index hints are reserved.
+///
+/// See <https://dev.mysql.com/doc/refman/8.0/en/keywords.html>
+const RESERVED_FOR_TABLE_ALIAS_MYSQL: &[Keyword] = reserved_keywords!(
+    RESERVED_FOR_TABLE_ALIAS,
+    add: [STRAIGHT_JOIN, USE, IGNORE, FORCE],
+    remove: [PREWHERE, SETTINGS, FORMAT, START, CONNECT, MATCH_RECOGNIZE],
+);
+
+/// Keywords that can't be used as a column alias in MySQL, see
+/// [`RESERVED_FOR_COLUMN_ALIAS`](crate::keywords::RESERVED_FOR_COLUMN_ALIAS).
+/// `TOP` and `VIEW` aren't reserved words in MySQL, e.g. `SELECT MAX(score)
+/// top`.
+///
+/// See <https://dev.mysql.com/doc/refman/8.0/en/keywords.html>
+const RESERVED_FOR_COLUMN_ALIAS_MYSQL: &[Keyword] = reserved_keywords!(
+    RESERVED_FOR_COLUMN_ALIAS,
+    add: [],
+    remove: [TOP, VIEW],
+);
+
 /// A [`Dialect`] for [MySQL](https://www.mysql.com/)
 #[derive(Debug)]
 pub struct MySqlDialect {}
@@ -66,7 +92,7 @@ impl Dialect for MySqlDialect {
         &self,
         parser: &mut crate::parser::Parser,
         expr: &crate::ast::Expr,
-        _precedence: u8,
+        precedence: u8,
     ) -> Option<Result<crate::ast::Expr, ParserError>> {
         // Parse DIV as an operator
         if parser.parse_keyword(Keyword::DIV) {
@@ -75,11 +101,44 @@ impl Dialect for MySqlDialect {
                 op: BinaryOperator::MyIntegerDivide,
                 right: Box::new(parser.parse_expr().unwrap()),
             }))
+        } else if parser.consume_token(&Token::Arrow) {
+            Some(parse_json_extract(
+                parser,
+                expr,
+                BinaryOperator::MyJsonExtract,
+                precedence,
+            ))
+        } else if parser.consume_token(&Token::LongArrow) {
+            Some(parse_json_extract(
+                parser,
+                expr,
+                BinaryOperator::MyJsonUnquoteExtract,
+                precedence,
+            ))
         } else {
             None
         }
     }
 
+    fn get_next_precedence(&self, parser: &Parser) -> Option<Result<u8, ParserError>> {
+        match parser.peek_token().token {
+            // `doc -> '$.path'` binds like a function call
+            Token::Arrow | Token::LongArrow => Some(Ok(self.prec_value(Precedence::DoubleColon))),
+            // PostgreSQL's JSON operators aren't operators in MySQL
+            Token::HashArrow
+            | Token::HashLongArrow
+            | Token::AtArrow
+            | Token::ArrowAt
+            | Token::HashMinus
+            | Token::AtQuestion
+            | Token::AtAt
+            | Token::Question
+            | Token::QuestionAnd
+            | Token::QuestionPipe => Some(Ok(self.prec_unknown())),
+            _ => None,
+        }
+    }
+
     fn parse_statement(&self, parser: &mut Parser) -> Option<Result<Statement, ParserError>> {
         if parser.parse_keywords(&[Keyword::LOCK, Keyword::TABLES]) {
             Some(parse_lock_tables(parser))
@@ -97,6 +156,33 @@ impl Dialect for MySqlDialect {
     fn supports_limit_comma(&self) -> bool {
         true
     }
+
+    fn supports_trigger_row_references(&self) -> bool {
+        true
+    }
+
+    fn reserved_keywords_for_table_alias(&self) -> &'static [Keyword] {
+        RESERVED_FOR_TABLE_ALIAS_MYSQL
+    }
+
+    fn reserved_keywords_for_column_alias(&self) -> &'static [Keyword] {
+        RESERVED_FOR_COLUMN_ALIAS_MYSQL
+    }
+}
+
+/// Parses the JSON path following a `->` or `->>` operator
+fn parse_json_extract(
+    parser: &mut Parser,
+    expr: &Expr,
+    op: BinaryOperator,
+    precedence: u8,
+) -> Result<Expr, ParserError> {
+    let path = parser.parse_subexpr(precedence)?;
+    Ok(Expr::BinaryOp {
+        left: Box::new(expr.clone()),
+        op,
+        right: Box::new(path),
+    })
 }
 
 /// `LOCK TABLES`
@@ -0,0 +1,60 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::dialect::Dialect;
+
+/// A [`Dialect`] for [Oracle Database](https://www.oracle.com/database/)
+///
+/// See <https://docs.oracle.com/en/database/oracle/oracle-database/23/sqlrf/index.html>.
+#[derive(Debug, Default)]
+pub struct OracleDialect;
+
+impl Dialect for OracleDialect {
+    // see https://docs.oracle.com/en/database/oracle/oracle-database/23/sqlrf/Database-Object-Names-and-Qualifiers.html
+
+    fn is_delimited_identifier_start(&self, ch: char) -> bool {
+        ch == '"'
+    }
+
+    fn is_identifier_start(&self, ch: char) -> bool {
+        ch.is_alphabetic()
+    }
+
+    fn is_identifier_part(&self, ch: char) -> bool {
+        ch.is_alphanumeric() || ch == '_' || ch == '$' || ch == '#'
+    }
+
+    // https://docs.oracle.com/en/database/oracle/oracle-database/23/sqlrf/Hierarchical-Queries.html
+    fn supports_connect_by(&self) -> bool {
+        true
+    }
+
+    // https://docs.oracle.com/en/database/oracle/oracle-database/23/sqlrf/Joins.html#GUID-29A4584C-0741-4E6A-A89B-DCFAA222994A
+    fn supports_outer_join_operator(&self) -> bool {
+        true
+    }
+
+    // https://docs.oracle.com/en/database/oracle/oracle-database/23/sqlrf/The-UNION-ALL-INTERSECT-MINUS-Operators.html
+    fn supports_minus_set_operator(&self) -> bool {
+        true
+    }
+
+    // https://docs.oracle.com/en/database/oracle/oracle-database/23/sqlrf/Literals.html#GUID-1824CBAA-6E16-4921-B2A6-112FB02248DA
+    fn supports_quote_delimited_string(&self) -> bool {
+        true
+    }
+}
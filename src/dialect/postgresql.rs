@@ -34,6 +34,18 @@ use crate::keywords::Keyword;
 use crate::parser::{Parser, ParserError};
 use crate::tokenizer::Token;
 
+/// Keywords that can't be used as a table alias in PostgreSQL, see
+/// [`RESERVED_FOR_TABLE_ALIAS`](crate::keywords::RESERVED_FOR_TABLE_ALIAS).
+/// `RETURNING` can follow the table of a `DELETE`, e.g. in the data-modifying
+/// CTE `WITH d AS (DELETE FROM t RETURNING *) ...`.
+///
+/// See <https://www.postgresql.org/docs/current/sql-keywords-appendix.html>
+const RESERVED_FOR_TABLE_ALIAS_POSTGRESQL: &[Keyword] = reserved_keywords!(
+    RESERVED_FOR_TABLE_ALIAS,
+    add: [RETURNING],
+    remove: [],
+);
+
 /// A [`Dialect`] for [PostgreSQL](https://www.postgresql.org/)
 #[derive(Debug)]
 pub struct PostgreSqlDialect {}
@@ -188,6 +200,14 @@ impl Dialect for PostgreSqlDialect {
     fn supports_explain_with_utility_options(&self) -> bool {
         true
     }
+
+    fn supports_trigger_row_references(&self) -> bool {
+        true
+    }
+
+    fn reserved_keywords_for_table_alias(&self) -> &'static [Keyword] {
+        RESERVED_FOR_TABLE_ALIAS_POSTGRESQL
+    }
 }
 
 pub fn parse_comment(parser: &mut Parser) -> Result<Statement, ParserError> {
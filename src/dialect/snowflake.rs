@@ -22,7 +22,10 @@ use crate::ast::helpers::stmt_data_loading::{
     DataLoadingOption, DataLoadingOptionType, DataLoadingOptions, StageLoadSelectItem,
     StageParamsObject,
 };
-use crate::ast::{Ident, ObjectName, RowAccessPolicy, Statement, Tag, WrappedCollection};
+use crate::ast::{
+    AlterTaskOperation, Ident, ObjectName, RowAccessPolicy, ShowObjectType, ShowObjectsScope,
+    Statement, Tag, WrappedCollection,
+};
 use crate::dialect::{Dialect, Precedence};
 use crate::keywords::Keyword;
 use crate::parser::{Parser, ParserError};
@@ -32,7 +35,21 @@ use alloc::string::String;
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
 #[cfg(not(feature = "std"))]
-use alloc::{format, vec};
+use alloc::{boxed::Box, format, vec};
+
+/// Keywords that can't be used as a table alias in Snowflake, see
+/// [`RESERVED_FOR_TABLE_ALIAS`](crate::keywords::RESERVED_FOR_TABLE_ALIAS).
+/// The clauses of other dialects, which aren't parsed for Snowflake, are left
+/// out so that their keywords can be aliases, e.g. `FROM user_settings
+/// settings`. `TABLESAMPLE` is reserved, and so are `ASOF` and
+/// `MATCH_CONDITION` for `ASOF JOIN`.
+///
+/// See <https://docs.snowflake.com/en/sql-reference/reserved-keywords>
+const RESERVED_FOR_TABLE_ALIAS_SNOWFLAKE: &[Keyword] = reserved_keywords!(
+    RESERVED_FOR_TABLE_ALIAS,
+    add: [TABLESAMPLE, ASOF, MATCH_CONDITION],
+    remove: [PARTITION, PREWHERE, SETTINGS, FORMAT],
+);
 
 /// A [`Dialect`] for [Snowflake](https://www.snowflake.com/)
 #[derive(Debug, Default)]
@@ -69,6 +86,14 @@ impl Dialect for SnowflakeDialect {
         true
     }
 
+    fn supports_outer_join_operator(&self) -> bool {
+        true
+    }
+
+    fn supports_minus_set_operator(&self) -> bool {
+        true
+    }
+
     fn supports_match_recognize(&self) -> bool {
         true
     }
@@ -93,6 +118,9 @@ impl Dialect for SnowflakeDialect {
     }
 
     fn parse_statement(&self, parser: &mut Parser) -> Option<Result<Statement, ParserError>> {
+        if parser.parse_keywords(&[Keyword::ALTER, Keyword::TASK]) {
+            return Some(parse_alter_task(parser));
+        }
         if parser.parse_keyword(Keyword::CREATE) {
             // possibly CREATE STAGE
             //[ OR  REPLACE ]
@@ -123,6 +151,8 @@ impl Dialect for SnowflakeDialect {
             if parser.parse_keyword(Keyword::STAGE) {
                 // OK - this is CREATE STAGE statement
                 return Some(parse_create_stage(or_replace, temporary, parser));
+            } else if parser.parse_keyword(Keyword::TASK) {
+                return Some(parse_create_task(or_replace, parser));
             } else if parser.parse_keyword(Keyword::TABLE) {
                 return Some(parse_create_table(
                     or_replace, global, temporary, volatile, transient, parser,
@@ -145,6 +175,33 @@ impl Dialect for SnowflakeDialect {
             // COPY INTO
             return Some(parse_copy_into(parser));
         }
+        if parser.parse_keyword(Keyword::SHOW) {
+            let terse = parser.parse_keyword(Keyword::TERSE);
+            match parse_show_object_type(parser) {
+                Some(object_type @ (ShowObjectType::Tables | ShowObjectType::Columns))
+                    if !terse =>
+                {
+                    // `SHOW TABLES` and `SHOW COLUMNS` are handled by the generic
+                    // parser, unless they use Snowflake specific clauses
+                    if let Some(stmt) = parser
+                        .maybe_parse(|parser| parse_snowflake_show_objects(object_type, parser))
+                    {
+                        return Some(Ok(stmt));
+                    }
+                    parser.prev_token();
+                }
+                Some(object_type) => {
+                    return Some(parse_show_objects(terse, object_type, parser));
+                }
+                None if terse => {
+                    return Some(
+                        parser.expected("an object type after SHOW TERSE", parser.peek_token()),
+                    );
+                }
+                None => {}
+            }
+            parser.prev_token();
+        }
 
         None
     }
@@ -169,6 +226,10 @@ impl Dialect for SnowflakeDialect {
     fn allow_extract_single_quotes(&self) -> bool {
         true
     }
+
+    fn reserved_keywords_for_table_alias(&self) -> &'static [Keyword] {
+        RESERVED_FOR_TABLE_ALIAS_SNOWFLAKE
+    }
 }
 
 /// Parse snowflake create table statement.
@@ -321,7 +382,13 @@ pub fn parse_create_table(
                     builder = builder.with_tags(Some(tags));
                 }
                 _ => {
-                    return parser.expected("end of statement", next_token);
+                    parser.prev_token();
+                    let unknown_options = parser.parse_unknown_options()?;
+                    if unknown_options.is_empty() {
+                        return parser.expected("end of statement", next_token);
+                    }
+                    builder = builder.unknown_options(unknown_options);
+                    break;
                 }
             },
             Token::LParen => {
@@ -357,6 +424,234 @@ pub fn parse_create_table(
     Ok(builder.build())
 }
 
+/// Parse snowflake create task statement.
+/// <https://docs.snowflake.com/en/sql-reference/sql/create-task>
+pub fn parse_create_task(or_replace: bool, parser: &mut Parser) -> Result<Statement, ParserError> {
+    let if_not_exists = parser.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+    let name = parser.parse_object_name(false)?;
+
+    let mut warehouse = None;
+    let mut schedule = None;
+    let mut options = vec![];
+    loop {
+        if parser.parse_keyword(Keyword::WAREHOUSE) {
+            parser.expect_token(&Token::Eq)?;
+            warehouse = Some(parser.parse_identifier(false)?);
+        } else if parser.parse_keyword(Keyword::SCHEDULE) {
+            parser.expect_token(&Token::Eq)?;
+            schedule = Some(parser.parse_literal_string()?);
+        } else {
+            match parser.peek_token().token {
+                Token::Word(w)
+                    if !matches!(w.keyword, Keyword::AFTER | Keyword::WHEN | Keyword::AS) =>
+                {
+                    options.push(parser.parse_sql_option()?)
+                }
+                _ => break,
+            }
+        }
+    }
+
+    let after = if parser.parse_keyword(Keyword::AFTER) {
+        parser.parse_comma_separated(|p| p.parse_object_name(false))?
+    } else {
+        vec![]
+    };
+    let condition = if parser.parse_keyword(Keyword::WHEN) {
+        Some(parser.parse_expr()?)
+    } else {
+        None
+    };
+    parser.expect_keyword(Keyword::AS)?;
+    let statement = Box::new(parser.parse_statement()?);
+
+    Ok(Statement::CreateTask {
+        or_replace,
+        if_not_exists,
+        name,
+        warehouse,
+        schedule,
+        options,
+        after,
+        condition,
+        statement,
+    })
+}
+
+/// Parse snowflake alter task statement.
+/// <https://docs.snowflake.com/en/sql-reference/sql/alter-task>
+pub fn parse_alter_task(parser: &mut Parser) -> Result<Statement, ParserError> {
+    let if_exists = parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+    let name = parser.parse_object_name(false)?;
+
+    let operation = if parser.parse_keyword(Keyword::RESUME) {
+        AlterTaskOperation::Resume
+    } else if parser.parse_keyword(Keyword::SUSPEND) {
+        AlterTaskOperation::Suspend
+    } else if parser.parse_keywords(&[Keyword::ADD, Keyword::AFTER]) {
+        AlterTaskOperation::AddAfter(parser.parse_comma_separated(|p| p.parse_object_name(false))?)
+    } else if parser.parse_keywords(&[Keyword::REMOVE, Keyword::AFTER]) {
+        AlterTaskOperation::RemoveAfter(
+            parser.parse_comma_separated(|p| p.parse_object_name(false))?,
+        )
+    } else if parser.parse_keyword(Keyword::SET) {
+        let mut options = vec![parser.parse_sql_option()?];
+        while parser.consume_token(&Token::Comma)
+            || matches!(parser.peek_token().token, Token::Word(_))
+        {
+            options.push(parser.parse_sql_option()?);
+        }
+        AlterTaskOperation::Set(options)
+    } else if parser.parse_keyword(Keyword::UNSET) {
+        AlterTaskOperation::Unset(parser.parse_comma_separated(|p| p.parse_identifier(false))?)
+    } else if parser.parse_keywords(&[Keyword::MODIFY, Keyword::AS]) {
+        AlterTaskOperation::ModifyAs(Box::new(parser.parse_statement()?))
+    } else if parser.parse_keywords(&[Keyword::MODIFY, Keyword::WHEN]) {
+        AlterTaskOperation::ModifyWhen(parser.parse_expr()?)
+    } else {
+        return parser.expected(
+            "RESUME, SUSPEND, ADD AFTER, REMOVE AFTER, SET, UNSET or MODIFY after ALTER TASK",
+            parser.peek_token(),
+        );
+    };
+
+    Ok(Statement::AlterTask {
+        if_exists,
+        name,
+        operation,
+    })
+}
+
+fn parse_show_object_type(parser: &mut Parser) -> Option<ShowObjectType> {
+    let object_type = if parser.parse_keywords(&[Keyword::EXTERNAL, Keyword::TABLES]) {
+        ShowObjectType::ExternalTables
+    } else if parser.parse_keywords(&[Keyword::MATERIALIZED, Keyword::VIEWS]) {
+        ShowObjectType::MaterializedViews
+    } else if parser.parse_keywords(&[Keyword::USER, Keyword::FUNCTIONS]) {
+        ShowObjectType::UserFunctions
+    } else {
+        match parser.parse_one_of_keywords(&[
+            Keyword::DATABASES,
+            Keyword::SCHEMAS,
+            Keyword::OBJECTS,
+            Keyword::TABLES,
+            Keyword::VIEWS,
+            Keyword::COLUMNS,
+            Keyword::PROCEDURES,
+            Keyword::SEQUENCES,
+            Keyword::STAGES,
+            Keyword::TASKS,
+            Keyword::WAREHOUSES,
+            Keyword::USERS,
+            Keyword::ROLES,
+        ])? {
+            Keyword::DATABASES => ShowObjectType::Databases,
+            Keyword::SCHEMAS => ShowObjectType::Schemas,
+            Keyword::OBJECTS => ShowObjectType::Objects,
+            Keyword::TABLES => ShowObjectType::Tables,
+            Keyword::VIEWS => ShowObjectType::Views,
+            Keyword::COLUMNS => ShowObjectType::Columns,
+            Keyword::PROCEDURES => ShowObjectType::Procedures,
+            Keyword::SEQUENCES => ShowObjectType::Sequences,
+            Keyword::STAGES => ShowObjectType::Stages,
+            Keyword::TASKS => ShowObjectType::Tasks,
+            Keyword::WAREHOUSES => ShowObjectType::Warehouses,
+            Keyword::USERS => ShowObjectType::Users,
+            _ => ShowObjectType::Roles,
+        }
+    };
+    Some(object_type)
+}
+
+/// Parse a snowflake show objects statement, failing if it doesn't use any
+/// of the clauses unknown to [`Parser::parse_show`], i.e. `IN <scope>`,
+/// `STARTS WITH` or `LIMIT`.
+fn parse_snowflake_show_objects(
+    object_type: ShowObjectType,
+    parser: &mut Parser,
+) -> Result<Statement, ParserError> {
+    let stmt = parse_show_objects(false, object_type, parser)?;
+    match &stmt {
+        Statement::ShowObjects {
+            scope: Some(ShowObjectsScope::Name(_)) | None,
+            starts_with: None,
+            limit: None,
+            ..
+        } => parser.expected("IN <scope>, STARTS WITH or LIMIT", parser.peek_token()),
+        _ => Ok(stmt),
+    }
+}
+
+/// Parse snowflake show objects statement, e.g. `SHOW TABLES IN SCHEMA s`.
+/// <https://docs.snowflake.com/en/sql-reference/sql/show-objects>
+pub fn parse_show_objects(
+    terse: bool,
+    object_type: ShowObjectType,
+    parser: &mut Parser,
+) -> Result<Statement, ParserError> {
+    let like = if parser.parse_keyword(Keyword::LIKE) {
+        Some(parser.parse_literal_string()?)
+    } else {
+        None
+    };
+
+    let scope = if parser.parse_keyword(Keyword::IN) {
+        // the name following the scope keyword is optional
+        let optional_name = |parser: &mut Parser| match parser.peek_token().token {
+            Token::Word(w) if !matches!(w.keyword, Keyword::STARTS | Keyword::LIMIT) => {
+                parser.parse_object_name(false).map(Some)
+            }
+            _ => Ok(None),
+        };
+        Some(
+            match parser.parse_one_of_keywords(&[
+                Keyword::ACCOUNT,
+                Keyword::DATABASE,
+                Keyword::SCHEMA,
+                Keyword::TABLE,
+                Keyword::VIEW,
+            ]) {
+                Some(Keyword::ACCOUNT) => ShowObjectsScope::Account,
+                Some(Keyword::DATABASE) => ShowObjectsScope::Database(optional_name(parser)?),
+                Some(Keyword::SCHEMA) => ShowObjectsScope::Schema(optional_name(parser)?),
+                Some(Keyword::TABLE) => ShowObjectsScope::Table(optional_name(parser)?),
+                Some(Keyword::VIEW) => ShowObjectsScope::View(optional_name(parser)?),
+                _ => ShowObjectsScope::Name(parser.parse_object_name(false)?),
+            },
+        )
+    } else {
+        None
+    };
+
+    let starts_with = if parser.parse_keywords(&[Keyword::STARTS, Keyword::WITH]) {
+        Some(parser.parse_literal_string()?)
+    } else {
+        None
+    };
+
+    let (limit, limit_from) = if parser.parse_keyword(Keyword::LIMIT) {
+        let limit = parser.parse_expr()?;
+        let limit_from = if parser.parse_keyword(Keyword::FROM) {
+            Some(parser.parse_literal_string()?)
+        } else {
+            None
+        };
+        (Some(limit), limit_from)
+    } else {
+        (None, None)
+    };
+
+    Ok(Statement::ShowObjects {
+        terse,
+        object_type,
+        like,
+        scope,
+        starts_with,
+        limit,
+        limit_from,
+    })
+}
+
 pub fn parse_create_stage(
     or_replace: bool,
     temporary: bool,
@@ -18,15 +18,26 @@
 //! This module defines
 //! 1) a list of constants for every keyword
 //! 2) an `ALL_KEYWORDS` array with every keyword in it
-//!     This is not a list of *reserved* keywords: some of these can be
-//!     parsed as identifiers if the parser decides so. This means that
-//!     new keywords can be added here without affecting the parse result.
+//!    This is not a list of *reserved* keywords: some of these can be
+//!    parsed as identifiers if the parser decides so. This means that
+//!    new keywords can be added here without affecting the parse result.
 //!
-//!     As a matter of fact, most of these keywords are not used at all
-//!     and could be removed.
+//!    As a matter of fact, most of these keywords are not used at all
+//!    and could be removed.
 //! 3) a `RESERVED_FOR_TABLE_ALIAS` array with keywords reserved in a
-//!     "table alias" context.
+//!    "table alias" context.
+//! 4) a `RESERVED_FOR_COLUMN_ALIAS` array with keywords reserved in a
+//!    "column alias" context.
+//!
+//! Dialects may reserve a different set of keywords, see
+//! [`Dialect::reserved_keywords_for_table_alias`] and
+//! [`Dialect::reserved_keywords_for_column_alias`].
+//!
+//! [`Dialect::reserved_keywords_for_table_alias`]: crate::dialect::Dialect::reserved_keywords_for_table_alias
+//! [`Dialect::reserved_keywords_for_column_alias`]: crate::dialect::Dialect::reserved_keywords_for_column_alias
 
+#[cfg(feature = "json_schema")]
+use schemars::JsonSchema;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -52,6 +63,7 @@ macro_rules! define_keywords {
     ),*) => {
         #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
         #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        #[cfg_attr(feature = "json_schema", derive(JsonSchema))]
         #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
         #[allow(non_camel_case_types)]
         pub enum Keyword {
@@ -74,17 +86,22 @@ macro_rules! define_keywords {
 define_keywords!(
     ABORT,
     ABS,
+    ABSENT,
     ABSOLUTE,
     ACCESS,
+    ACCOUNT,
     ACTION,
     ADD,
     ADMIN,
     AFTER,
     AGAINST,
+    AGGREGATE,
     AGGREGATION,
+    ALGORITHM,
     ALIAS,
     ALL,
     ALLOCATE,
+    ALSO,
     ALTER,
     ALWAYS,
     ANALYZE,
@@ -133,6 +150,7 @@ define_keywords!(
     BUCKETS,
     BY,
     BYPASSRLS,
+    BYTE,
     BYTEA,
     BYTES,
     CACHE,
@@ -158,6 +176,7 @@ define_keywords!(
     CHARSET,
     CHAR_LENGTH,
     CHECK,
+    CLASS,
     CLEAR,
     CLOB,
     CLONE,
@@ -172,24 +191,30 @@ define_keywords!(
     COLUMN,
     COLUMNS,
     COLUMNSTORE,
+    COLUMN_FORMAT,
     COMMENT,
     COMMIT,
     COMMITTED,
+    COMPLETION,
     COMPRESSION,
     COMPUTE,
     CONCURRENTLY,
     CONDITION,
+    CONFIGURATION,
     CONFLICT,
     CONNECT,
     CONNECTION,
+    CONSTANT,
     CONSTRAINT,
     CONTAINS,
     CONTINUE,
+    CONVERSION,
     CONVERT,
     COPY,
     COPY_OPTIONS,
     CORR,
     CORRESPONDING,
+    COST,
     COUNT,
     COVAR_POP,
     COVAR_SAMP,
@@ -217,6 +242,7 @@ define_keywords!(
     CYCLE,
     DATA,
     DATABASE,
+    DATABASES,
     DATA_RETENTION_TIME_IN_DAYS,
     DATE,
     DATE32,
@@ -226,6 +252,7 @@ define_keywords!(
     DAYOFWEEK,
     DAYOFYEAR,
     DEALLOCATE,
+    DEBUG,
     DEC,
     DECADE,
     DECIMAL,
@@ -237,6 +264,7 @@ define_keywords!(
     DEFERRED,
     DEFINE,
     DEFINED,
+    DEFINER,
     DELAYED,
     DELETE,
     DELIMITED,
@@ -249,10 +277,12 @@ define_keywords!(
     DETACH,
     DETAIL,
     DETERMINISTIC,
+    DICTIONARY,
     DIRECTORY,
     DISABLE,
     DISCARD,
     DISCONNECT,
+    DISK,
     DISTINCT,
     DISTRIBUTE,
     DIV,
@@ -268,6 +298,8 @@ define_keywords!(
     ELEMENT,
     ELEMENTS,
     ELSE,
+    ELSEIF,
+    ELSIF,
     EMPTY,
     ENABLE,
     ENABLE_SCHEMA_EVOLUTION,
@@ -276,6 +308,7 @@ define_keywords!(
     END,
     END_EXEC = "END-EXEC",
     ENDPOINT,
+    ENDS,
     END_FRAME,
     END_PARTITION,
     ENFORCED,
@@ -296,6 +329,7 @@ define_keywords!(
     EXEC,
     EXECUTE,
     EXISTS,
+    EXIT,
     EXP,
     EXPANSION,
     EXPLAIN,
@@ -307,6 +341,7 @@ define_keywords!(
     EXTRACT,
     FAIL,
     FALSE,
+    FAMILY,
     FETCH,
     FIELDS,
     FILE,
@@ -317,6 +352,7 @@ define_keywords!(
     FINAL,
     FIRST,
     FIRST_VALUE,
+    FIXED,
     FIXEDSTRING,
     FLOAT,
     FLOAT32,
@@ -328,6 +364,7 @@ define_keywords!(
     FOLLOWING,
     FOR,
     FORCE,
+    FORCESEEK,
     FORCE_NOT_NULL,
     FORCE_NULL,
     FORCE_QUOTE,
@@ -335,6 +372,7 @@ define_keywords!(
     FORMAT,
     FORMATTED,
     FORWARD,
+    FOUND,
     FRAME_ROW,
     FREE,
     FREEZE,
@@ -358,11 +396,13 @@ define_keywords!(
     GROUP,
     GROUPING,
     GROUPS,
+    HANDLER,
     HASH,
     HAVING,
     HEADER,
     HEAP,
     HIGH_PRIORITY,
+    HINT,
     HISTORY,
     HIVEVAR,
     HOLD,
@@ -376,21 +416,26 @@ define_keywords!(
     ILIKE,
     IMMEDIATE,
     IMMUTABLE,
+    IMPORTS,
     IN,
     INCLUDE,
     INCLUDE_NULL_VALUES,
     INCREMENT,
     INDEX,
     INDICATOR,
+    INFO,
     INHERIT,
     INITIALLY,
+    INLINE,
     INNER,
     INOUT,
+    INPLACE,
     INPUT,
     INPUTFORMAT,
     INSENSITIVE,
     INSERT,
     INSTALL,
+    INSTANT,
     INSTEAD,
     INT,
     INT128,
@@ -407,12 +452,15 @@ define_keywords!(
     INTERSECTION,
     INTERVAL,
     INTO,
+    INVISIBLE,
+    INVOKER,
     IS,
     ISODOW,
     ISOLATION,
     ISOWEEK,
     ISOYEAR,
     ITEMS,
+    ITERATE,
     JAR,
     JOIN,
     JSON,
@@ -422,6 +470,7 @@ define_keywords!(
     JULIAN,
     KEY,
     KEYS,
+    KEY_BLOCK_SIZE,
     KILL,
     LAG,
     LANGUAGE,
@@ -431,12 +480,17 @@ define_keywords!(
     LATERAL,
     LEAD,
     LEADING,
+    LEAKPROOF,
+    LEAVE,
     LEFT,
+    LESS,
+    LET,
     LEVEL,
     LIKE,
     LIKE_REGEX,
     LIMIT,
     LINES,
+    LIST,
     LN,
     LOAD,
     LOCAL,
@@ -445,14 +499,18 @@ define_keywords!(
     LOCATION,
     LOCK,
     LOCKED,
+    LOG,
     LOGIN,
     LOGS,
+    LOG_VERBOSITY,
+    LOOP,
     LOWCARDINALITY,
     LOWER,
     LOW_PRIORITY,
     MACRO,
     MANAGEDLOCATION,
     MAP,
+    MAPPING,
     MATCH,
     MATCHED,
     MATCHES,
@@ -466,6 +524,7 @@ define_keywords!(
     MEASURES,
     MEDIUMINT,
     MEMBER,
+    MEMORY,
     MERGE,
     METADATA,
     METHOD,
@@ -476,13 +535,16 @@ define_keywords!(
     MILLISECOND,
     MILLISECONDS,
     MIN,
+    MINUS,
     MINUTE,
     MINVALUE,
     MOD,
     MODE,
+    MODEL,
     MODIFIES,
     MODIFY,
     MODULE,
+    MODULUS,
     MONTH,
     MSCK,
     MULTISET,
@@ -510,6 +572,8 @@ define_keywords!(
     NOSUPERUSER,
     NOT,
     NOTHING,
+    NOTICE,
+    NOTIFY,
     NOWAIT,
     NO_WRITE_TO_BINLOG,
     NTH_VALUE,
@@ -518,19 +582,23 @@ define_keywords!(
     NULLABLE,
     NULLIF,
     NULLS,
+    NUMBER,
     NUMERIC,
     NVARCHAR,
     OBJECT,
+    OBJECTS,
     OCCURRENCES_REGEX,
     OCTETS,
     OCTET_LENGTH,
     OF,
+    OFF,
     OFFSET,
     OLD,
     OMIT,
     ON,
     ONE,
     ONLY,
+    ON_ERROR,
     OPEN,
     OPERATOR,
     OPTIMIZE,
@@ -541,6 +609,7 @@ define_keywords!(
     ORC,
     ORDER,
     ORDINALITY,
+    OTHERS,
     OUT,
     OUTER,
     OUTPUTFORMAT,
@@ -551,9 +620,12 @@ define_keywords!(
     OVERWRITE,
     OWNED,
     OWNER,
+    PACKAGES,
     PARALLEL,
     PARAMETER,
     PARQUET,
+    PARSE,
+    PARSER,
     PART,
     PARTITION,
     PARTITIONED,
@@ -575,6 +647,7 @@ define_keywords!(
     PLAN,
     PLANS,
     POLICY,
+    POPULATE,
     PORTION,
     POSITION,
     POSITION_REGEX,
@@ -585,18 +658,25 @@ define_keywords!(
     PRECISION,
     PREPARE,
     PRESERVE,
+    PREV,
     PREWHERE,
     PRIMARY,
     PRIOR,
     PRIVILEGES,
+    PROCEDURAL,
     PROCEDURE,
+    PROCEDURES,
     PROGRAM,
     PROJECTION,
+    PROXY,
+    PUBLICATION,
     PURGE,
     QUALIFY,
     QUARTER,
     QUERY,
     QUOTE,
+    RAISE,
+    RANDOM,
     RANGE,
     RANK,
     RAW,
@@ -609,6 +689,7 @@ define_keywords!(
     REF,
     REFERENCES,
     REFERENCING,
+    REFRESH,
     REGCLASS,
     REGEXP,
     REGR_AVGX,
@@ -623,10 +704,14 @@ define_keywords!(
     RELATIVE,
     RELAY,
     RELEASE,
+    REMAINDER,
     REMOTE,
+    REMOVE,
     RENAME,
     REORG,
+    REORGANIZE,
     REPAIR,
+    REPEAT,
     REPEATABLE,
     REPLACE,
     REPLICA,
@@ -639,6 +724,7 @@ define_keywords!(
     RESTRICTIVE,
     RESULT,
     RESULTSET,
+    RESUME,
     RETAIN,
     RETURN,
     RETURNING,
@@ -647,6 +733,7 @@ define_keywords!(
     RIGHT,
     RLIKE,
     ROLE,
+    ROLES,
     ROLLBACK,
     ROLLUP,
     ROOT,
@@ -656,10 +743,13 @@ define_keywords!(
     ROW_NUMBER,
     RULE,
     RUN,
+    RUNTIME_VERSION,
     SAFE,
     SAFE_CAST,
     SAVEPOINT,
+    SCHEDULE,
     SCHEMA,
+    SCHEMAS,
     SCOPE,
     SCROLL,
     SEARCH,
@@ -676,15 +766,18 @@ define_keywords!(
     SERDE,
     SERDEPROPERTIES,
     SERIALIZABLE,
+    SERVER,
     SESSION,
     SESSION_USER,
     SET,
     SETS,
     SETTINGS,
     SHARE,
+    SHARED,
     SHOW,
     SIMILAR,
     SKIP,
+    SLAVE,
     SLOW,
     SMALLINT,
     SNAPSHOT,
@@ -700,9 +793,12 @@ define_keywords!(
     SQLSTATE,
     SQLWARNING,
     SQRT,
+    SRID,
     STABLE,
     STAGE,
+    STAGES,
     START,
+    STARTS,
     STATEMENT,
     STATIC,
     STATISTICS,
@@ -712,18 +808,24 @@ define_keywords!(
     STDIN,
     STDOUT,
     STEP,
+    STORAGE,
     STORAGE_INTEGRATION,
     STORED,
+    STRAIGHT_JOIN,
     STRICT,
     STRING,
     STRUCT,
     SUBMULTISET,
+    SUBSCRIPTION,
+    SUBSET,
     SUBSTRING,
     SUBSTRING_REGEX,
     SUCCEEDS,
     SUM,
     SUPER,
     SUPERUSER,
+    SUPPORT,
+    SUSPEND,
     SWAP,
     SYMMETRIC,
     SYNC,
@@ -733,14 +835,20 @@ define_keywords!(
     TABLE,
     TABLES,
     TABLESAMPLE,
+    TABLESPACE,
     TAG,
     TARGET,
+    TASK,
+    TASKS,
     TBLPROPERTIES,
     TEMP,
+    TEMPLATE,
     TEMPORARY,
     TERMINATED,
+    TERSE,
     TEXT,
     TEXTFILE,
+    THAN,
     THEN,
     TIES,
     TIME,
@@ -758,6 +866,7 @@ define_keywords!(
     TOTALS,
     TRAILING,
     TRANSACTION,
+    TRANSFORM,
     TRANSIENT,
     TRANSLATE,
     TRANSLATE_REGEX,
@@ -768,7 +877,10 @@ define_keywords!(
     TRIM_ARRAY,
     TRUE,
     TRUNCATE,
+    TRUSTED,
     TRY_CAST,
+    TRY_CONVERT,
+    TRY_PARSE,
     TUPLE,
     TYPE,
     UESCAPE,
@@ -781,6 +893,7 @@ define_keywords!(
     UNBOUNDED,
     UNCACHE,
     UNCOMMITTED,
+    UNDO,
     UNFREEZE,
     UNION,
     UNIQUE,
@@ -792,6 +905,7 @@ define_keywords!(
     UNNEST,
     UNPIVOT,
     UNSAFE,
+    UNSET,
     UNSIGNED,
     UNTIL,
     UPDATE,
@@ -800,17 +914,21 @@ define_keywords!(
     USAGE,
     USE,
     USER,
+    USERS,
     USER_RESOURCES,
     USING,
     UUID,
     VACUUM,
     VALID,
+    VALIDATE,
     VALIDATION_MODE,
+    VALIDATOR,
     VALUE,
     VALUES,
     VALUE_OF,
     VARBINARY,
     VARCHAR,
+    VARCHAR2,
     VARIABLES,
     VARYING,
     VAR_POP,
@@ -819,13 +937,18 @@ define_keywords!(
     VERSION,
     VERSIONING,
     VIEW,
+    VIEWS,
     VIRTUAL,
+    VISIBLE,
     VOLATILE,
     WAREHOUSE,
+    WAREHOUSES,
+    WARNING,
     WEEK,
     WHEN,
     WHENEVER,
     WHERE,
+    WHILE,
     WIDTH_BUCKET,
     WINDOW,
     WITH,
@@ -833,14 +956,47 @@ define_keywords!(
     WITHOUT,
     WITHOUT_ARRAY_WRAPPER,
     WORK,
+    WRAPPER,
     WRITE,
     XML,
+    XMLDATA,
+    XMLSCHEMA,
     XOR,
+    XSINIL,
     YEAR,
     ZONE,
     ZORDER
 );
 
+impl Keyword {
+    /// Returns the SQL text of this keyword, e.g. `"END-EXEC"` for
+    /// [`Keyword::END_EXEC`], or `None` for [`Keyword::NoKeyword`].
+    pub fn as_str(self) -> Option<&'static str> {
+        match self {
+            Keyword::NoKeyword => None,
+            // `ALL_KEYWORDS` follows the declaration order, minus `NoKeyword`
+            keyword => Some(ALL_KEYWORDS[keyword as usize - 1]),
+        }
+    }
+
+    /// Looks up the keyword matching `word`, ignoring case.
+    pub fn lookup(word: &str) -> Option<Keyword> {
+        // The tokenizer looks up every word, so short ASCII words are
+        // upper-cased on the stack instead of allocating a `String`
+        let mut buffer = [0u8; 32];
+        let index = match buffer.get_mut(..word.len()) {
+            Some(upper) if word.is_ascii() => {
+                upper.copy_from_slice(word.as_bytes());
+                upper.make_ascii_uppercase();
+                let upper = core::str::from_utf8(upper).ok()?;
+                ALL_KEYWORDS.binary_search(&upper)
+            }
+            _ => ALL_KEYWORDS.binary_search(&word.to_uppercase().as_str()),
+        };
+        index.ok().map(|index| ALL_KEYWORDS_INDEX[index])
+    }
+}
+
 /// These keywords can't be used as a table alias, so that `FROM table_name alias`
 /// can be parsed unambiguously without looking ahead.
 pub const RESERVED_FOR_TABLE_ALIAS: &[Keyword] = &[
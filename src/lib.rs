@@ -71,6 +71,12 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::upper_case_acronyms)]
+// Newer clippy releases flag existing code with these lints; addressing
+// them (e.g. boxing the large variants of the AST enums) changes the public
+// API and is left to a dedicated change.
+#![allow(clippy::large_enum_variant)]
+#![allow(clippy::obfuscated_if_else)]
+#![allow(clippy::unneeded_struct_pattern)]
 
 // Allow proc-macros to find this crate
 extern crate self as sqlparser;
@@ -22,6 +22,7 @@ use alloc::{
 };
 use core::{
     fmt::{self, Display},
+    ops::Range,
     str::FromStr,
 };
 
@@ -31,7 +32,13 @@ use recursion::RecursionCounter;
 use IsLateral::*;
 use IsOptional::*;
 
+#[cfg(feature = "arena")]
+use crate::ast::arena::{ArenaExpr, ExprId, ParseArena};
 use crate::ast::helpers::stmt_create_table::{CreateTableBuilder, CreateTableConfiguration};
+use crate::ast::script::{
+    ScriptBlock, ScriptConditionalBlock, ScriptDeclaration, ScriptExceptionHandler,
+    ScriptHandlerAction, ScriptHandlerCondition, ScriptStatement,
+};
 use crate::ast::Statement::CreatePolicy;
 use crate::ast::*;
 use crate::dialect::*;
@@ -39,6 +46,11 @@ use crate::keywords::{Keyword, ALL_KEYWORDS};
 use crate::tokenizer::*;
 
 mod alter;
+#[cfg(feature = "std")]
+mod stream;
+
+#[cfg(feature = "std")]
+pub use stream::StatementIter;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ParserError {
@@ -121,6 +133,39 @@ mod recursion {
     }
 }
 
+/// Converts the [`Location`]s produced by the [`Tokenizer`] back into
+/// byte offsets in the tokenized SQL.
+pub(crate) struct SourceOffsets<'a> {
+    sql: &'a str,
+    /// The byte offset at which each line starts
+    line_starts: Vec<usize>,
+}
+
+impl<'a> SourceOffsets<'a> {
+    pub(crate) fn new(sql: &'a str) -> Self {
+        let line_starts = core::iter::once(0)
+            .chain(sql.match_indices('\n').map(|(i, _)| i + 1))
+            .collect();
+        Self { sql, line_starts }
+    }
+
+    /// Returns the byte offset of `location`, counting columns in
+    /// characters like the tokenizer does
+    pub(crate) fn offset(&self, location: Location) -> usize {
+        let Some(&line_start) = self
+            .line_starts
+            .get((location.line as usize).saturating_sub(1))
+        else {
+            return self.sql.len();
+        };
+        let column = (location.column as usize).saturating_sub(1);
+        self.sql[line_start..]
+            .char_indices()
+            .nth(column)
+            .map_or(self.sql.len(), |(i, _)| line_start + i)
+    }
+}
+
 #[cfg(not(feature = "std"))]
 mod recursion {
     /// Implementation [`RecursionCounter`] if std is NOT available (and does not
@@ -185,6 +230,41 @@ impl std::error::Error for ParserError {}
 // By default, allow expressions up to this deep before erroring
 const DEFAULT_REMAINING_DEPTH: usize = 50;
 
+/// The token peeked at the end of the input
+static EOF_TOKEN: TokenWithLocation = TokenWithLocation {
+    token: Token::EOF,
+    location: Location { line: 0, column: 0 },
+};
+
+/// The object types of the rare PostgreSQL DDL statements that are parsed into
+/// a [`Statement::GenericDdl`], and whether their name follows the object type.
+/// Longer keyword sequences come first, as the first match is used.
+const GENERIC_DDL_OBJECTS: &[(&[Keyword], bool)] = &[
+    (&[Keyword::ACCESS, Keyword::METHOD], true),
+    (&[Keyword::AGGREGATE], true),
+    (&[Keyword::CAST], false),
+    (&[Keyword::DEFAULT, Keyword::CONVERSION], true),
+    (&[Keyword::CONVERSION], true),
+    (&[Keyword::EVENT, Keyword::TRIGGER], true),
+    (&[Keyword::FOREIGN, Keyword::DATA, Keyword::WRAPPER], true),
+    (&[Keyword::OPERATOR, Keyword::CLASS], true),
+    (&[Keyword::OPERATOR, Keyword::FAMILY], true),
+    (&[Keyword::OPERATOR], false),
+    (&[Keyword::PUBLICATION], true),
+    (&[Keyword::SERVER], true),
+    (&[Keyword::SUBSCRIPTION], true),
+    (&[Keyword::TABLESPACE], true),
+    (
+        &[Keyword::TEXT, Keyword::SEARCH, Keyword::CONFIGURATION],
+        true,
+    ),
+    (&[Keyword::TEXT, Keyword::SEARCH, Keyword::DICTIONARY], true),
+    (&[Keyword::TEXT, Keyword::SEARCH, Keyword::PARSER], true),
+    (&[Keyword::TEXT, Keyword::SEARCH, Keyword::TEMPLATE], true),
+    (&[Keyword::TRANSFORM], false),
+    (&[Keyword::USER, Keyword::MAPPING], false),
+];
+
 /// Composite types declarations using angle brackets syntax can be arbitrary
 /// nested such that the following declaration is possible:
 ///      `ARRAY<ARRAY<INT>>`
@@ -205,6 +285,16 @@ impl From<bool> for MatchedTrailingBracket {
     }
 }
 
+/// Properties of a function or procedure whose handler is written in a
+/// language other than SQL, see [Parser::parse_handler_property].
+#[derive(Default)]
+struct HandlerProperties {
+    runtime_version: Option<String>,
+    packages: Option<Vec<String>>,
+    imports: Option<Vec<String>>,
+    handler: Option<String>,
+}
+
 /// Options that control how the [`Parser`] parses SQL text
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ParserOptions {
@@ -212,6 +302,21 @@ pub struct ParserOptions {
     /// Controls how literal values are unescaped. See
     /// [`Tokenizer::with_unescape`] for more details.
     pub unescape: bool,
+    /// Controls whether procedural function bodies are parsed into a
+    /// [`ScriptBlock`]. See [`ParserOptions::with_parse_script_bodies`]
+    /// for more details.
+    pub parse_script_bodies: bool,
+    /// Controls whether `${...}` variable references are accepted. See
+    /// [`Tokenizer::with_variable_substitution`] for more details.
+    pub variable_substitution: bool,
+    /// Controls whether unknown trailing options of DDL statements are kept
+    /// as [`DialectSpecificOption`]s. See
+    /// [`ParserOptions::with_permissive_ddl`] for more details.
+    pub permissive_ddl: bool,
+    /// Controls whether statements that fail to parse are kept as
+    /// [`Statement::Unparsed`]. See
+    /// [`ParserOptions::with_unparsed_statements`] for more details.
+    pub unparsed_statements: bool,
 }
 
 impl Default for ParserOptions {
@@ -219,6 +324,10 @@ impl Default for ParserOptions {
         Self {
             trailing_commas: false,
             unescape: true,
+            parse_script_bodies: false,
+            variable_substitution: false,
+            permissive_ddl: false,
+            unparsed_statements: false,
         }
     }
 }
@@ -251,6 +360,110 @@ impl ParserOptions {
         self.unescape = unescape;
         self
     }
+
+    /// Set if procedural function bodies are parsed. Defaults to false.
+    ///
+    /// If this option is `true`, the bodies of PL/pgSQL functions (Postgres)
+    /// and `LANGUAGE SQL` procedures (Snowflake) are parsed into a
+    /// [`ScriptBlock`] instead of being kept as a string literal:
+    ///
+    /// ```
+    /// # use sqlparser::{ast::*, dialect::PostgreSqlDialect, parser::{Parser, ParserError, ParserOptions}};
+    /// # fn main() -> Result<(), ParserError> {
+    /// let sql = "CREATE FUNCTION f(a INTEGER) RETURNS INTEGER LANGUAGE plpgsql \
+    ///     AS $$ BEGIN RETURN a + 1; END $$";
+    /// let statement = Parser::new(&PostgreSqlDialect {})
+    ///     .with_options(ParserOptions::new().with_parse_script_bodies(true))
+    ///     .try_with_sql(sql)?
+    ///     .parse_statement()?;
+    /// let Statement::CreateFunction { function_body, .. } = statement else { unreachable!() };
+    /// let Some(CreateFunctionBody::AsScript(script)) = function_body else { unreachable!() };
+    /// assert_eq!(script.to_string(), "BEGIN RETURN a + 1; END");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_parse_script_bodies(mut self, parse_script_bodies: bool) -> Self {
+        self.parse_script_bodies = parse_script_bodies;
+        self
+    }
+
+    /// Set if `${...}` variable references, as used by Hive and Spark, are
+    /// accepted. Defaults to false.
+    ///
+    /// If this option is `true`, a variable reference is parsed as a
+    /// [`Value::Placeholder`] in expressions and as an [`Ident`] where an
+    /// identifier is expected:
+    ///
+    /// ```
+    /// # use sqlparser::{ast::*, dialect::HiveDialect, parser::{Parser, ParserError, ParserOptions}};
+    /// # fn main() -> Result<(), ParserError> {
+    /// let sql = "SELECT * FROM ${hiveconf:db}.t WHERE dt = ${hiveconf:date}";
+    /// let statements = Parser::new(&HiveDialect {})
+    ///     .with_options(ParserOptions::new().with_variable_substitution(true))
+    ///     .try_with_sql(sql)?
+    ///     .parse_statements()?;
+    /// assert_eq!(statements[0].to_string(), sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_variable_substitution(mut self, variable_substitution: bool) -> Self {
+        self.variable_substitution = variable_substitution;
+        self
+    }
+
+    /// Set if unknown trailing options of DDL statements are accepted.
+    /// Defaults to false.
+    ///
+    /// If this option is `true`, the options at the end of `CREATE TABLE`,
+    /// `CREATE VIEW` and `ALTER TABLE` statements that the parser does not
+    /// support are kept as raw tokens instead of failing the statement:
+    ///
+    /// ```
+    /// # use sqlparser::{ast::*, dialect::GenericDialect, parser::{Parser, ParserError, ParserOptions}};
+    /// # fn main() -> Result<(), ParserError> {
+    /// let sql = "CREATE TABLE t (a INT) DISTSTYLE = even SORTKEY (a)";
+    /// let statement = Parser::new(&GenericDialect {})
+    ///     .with_options(ParserOptions::new().with_permissive_ddl(true))
+    ///     .try_with_sql(sql)?
+    ///     .parse_statement()?;
+    /// let Statement::CreateTable(create_table) = &statement else { unreachable!() };
+    /// let unknown_options = &create_table.dialect_options.unknown_options;
+    /// assert_eq!(unknown_options[0].to_string(), "DISTSTYLE = even");
+    /// assert_eq!(unknown_options[1].to_string(), "SORTKEY (a)");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_permissive_ddl(mut self, permissive_ddl: bool) -> Self {
+        self.permissive_ddl = permissive_ddl;
+        self
+    }
+
+    /// Set if statements that fail to parse are kept instead of failing
+    /// the whole batch. Defaults to false.
+    ///
+    /// If this option is `true`, [`Parser::parse_statements`] returns a
+    /// [`Statement::Unparsed`] holding the tokens up to the next statement
+    /// delimiter for every statement it can't parse, and carries on with
+    /// the following statements:
+    ///
+    /// ```
+    /// # use sqlparser::{ast::*, dialect::GenericDialect, parser::{Parser, ParserError, ParserOptions}};
+    /// # fn main() -> Result<(), ParserError> {
+    /// let sql = "SELECT 1; FROBNICATE the widgets; SELECT 2";
+    /// let statements = Parser::new(&GenericDialect {})
+    ///     .with_options(ParserOptions::new().with_unparsed_statements(true))
+    ///     .try_with_sql(sql)?
+    ///     .parse_statements()?;
+    /// assert_eq!(statements.len(), 3);
+    /// let Statement::Unparsed { sql_fragment, .. } = &statements[1] else { unreachable!() };
+    /// assert_eq!(sql_fragment, "FROBNICATE the widgets");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_unparsed_statements(mut self, unparsed_statements: bool) -> Self {
+        self.unparsed_statements = unparsed_statements;
+        self
+    }
 }
 
 #[derive(Copy, Clone)]
@@ -261,6 +474,10 @@ enum ParserState {
     /// PRIOR expressions while still allowing prior as an identifier name
     /// in other contexts.
     ConnectBy,
+    /// The state when parsing the condition or body of a trigger. This allows
+    /// parsing `NEW.column` and `OLD.column` as references to the row that
+    /// fired the trigger.
+    Trigger,
 }
 
 pub struct Parser<'a> {
@@ -312,6 +529,10 @@ impl<'a> Parser<'a> {
     /// [`ParserError::RecursionLimitExceeded`] if the parser exceeds
     /// this depth while processing the query.
     ///
+    /// Directly nested parentheses, e.g. `((((a))))`, and chains of binary
+    /// operators are parsed without using more stack per level, so the limit
+    /// can be raised well above the default to accept such inputs.
+    ///
     /// Example:
     /// ```
     /// # use sqlparser::{parser::{Parser, ParserError}, dialect::GenericDialect};
@@ -387,6 +608,7 @@ impl<'a> Parser<'a> {
         debug!("Parsing sql '{}'...", sql);
         let tokens = Tokenizer::new(self.dialect, sql)
             .with_unescape(self.options.unescape)
+            .with_variable_substitution(self.options.variable_substitution)
             .tokenize_with_location()?;
         Ok(self.with_tokens_with_locations(tokens))
     }
@@ -409,6 +631,7 @@ impl<'a> Parser<'a> {
     pub fn parse_statements(&mut self) -> Result<Vec<Statement>, ParserError> {
         let mut stmts = Vec::new();
         let mut expecting_statement_delimiter = false;
+        let mut statement_start = self.index;
         loop {
             // ignore empty statements (between successive statement delimiters)
             while self.consume_token(&Token::SemiColon) {
@@ -418,26 +641,395 @@ impl<'a> Parser<'a> {
             match self.peek_token().token {
                 Token::EOF => break,
 
-                // end of statement
-                Token::Word(word) => {
-                    if expecting_statement_delimiter && word.keyword == Keyword::END {
-                        break;
-                    }
+                // end of statement, unless the rest of the input is to be
+                // kept as unparsed statements
+                Token::Word(word)
+                    if expecting_statement_delimiter
+                        && word.keyword == Keyword::END
+                        && !self.options.unparsed_statements =>
+                {
+                    break;
                 }
                 _ => {}
             }
 
             if expecting_statement_delimiter {
+                if !self.options.unparsed_statements {
+                    return self.expected("end of statement", self.peek_token());
+                }
+                // only a prefix of the previous statement was understood,
+                // keep all of it unparsed instead
+                stmts.pop();
+                self.index = statement_start;
+                stmts.push(self.parse_unparsed_statement());
+                continue;
+            }
+
+            statement_start = self.index;
+            let statement = match self.parse_statement() {
+                Ok(statement) => statement,
+                Err(_) if self.options.unparsed_statements => {
+                    self.index = statement_start;
+                    self.state = ParserState::Normal;
+                    self.parse_unparsed_statement()
+                }
+                Err(err) => return Err(err),
+            };
+            expecting_statement_delimiter = !is_followed_by_copy_data(&statement);
+            stmts.push(statement);
+        }
+        Ok(stmts)
+    }
+
+    /// Consumes the tokens up to the next statement delimiter as a
+    /// [`Statement::Unparsed`].
+    fn parse_unparsed_statement(&mut self) -> Statement {
+        let start = self.index;
+        self.skip_to_statement_delimiter();
+        let tokens = &self.tokens[start..self.index.min(self.tokens.len())];
+        let sql_fragment: String = tokens.iter().map(|t| t.token.to_string()).collect();
+        Statement::Unparsed {
+            tokens: tokens
+                .iter()
+                .filter(|t| !matches!(t.token, Token::Whitespace(_)))
+                .map(|t| t.token.clone())
+                .collect(),
+            sql_fragment: sql_fragment.trim().to_string(),
+        }
+    }
+
+    /// Parse potentially multiple statements, recovering from syntax errors.
+    ///
+    /// Unlike [`Parser::parse_statements`], this does not stop at the first
+    /// error: when a statement fails to parse, the error is recorded and
+    /// the parser skips ahead to the next statement delimiter (`;`) before
+    /// continuing. Returns the statements that parsed successfully along
+    /// with the errors encountered, both in source order.
+    ///
+    /// Example
+    /// ```
+    /// # use sqlparser::{parser::{Parser, ParserError}, dialect::GenericDialect};
+    /// # fn main() -> Result<(), ParserError> {
+    /// let dialect = GenericDialect{};
+    /// let (statements, errors) = Parser::new(&dialect)
+    ///   .try_with_sql("SELECT * FROM foo; SELECT * FROM; SELECT * FROM bar")?
+    ///   .parse_statements_with_recovery();
+    /// assert_eq!(statements.len(), 2);
+    /// assert_eq!(errors.len(), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_statements_with_recovery(&mut self) -> (Vec<Statement>, Vec<ParserError>) {
+        let mut stmts = Vec::new();
+        let mut errors = Vec::new();
+        let mut expecting_statement_delimiter = false;
+        loop {
+            // ignore empty statements (between successive statement delimiters)
+            while self.consume_token(&Token::SemiColon) {
+                expecting_statement_delimiter = false;
+            }
+
+            // unlike `parse_statements`, don't stop at a stray `END`: it is
+            // reported as an error below like any other unexpected token
+            if self.peek_token().token == Token::EOF {
+                break;
+            }
+
+            let start_index = self.index;
+            let result = if expecting_statement_delimiter {
+                self.expected("end of statement", self.peek_token())
+            } else {
+                self.parse_statement()
+            };
+
+            match result {
+                Ok(statement) => {
+                    expecting_statement_delimiter = !is_followed_by_copy_data(&statement);
+                    stmts.push(statement);
+                }
+                Err(err) => {
+                    errors.push(err);
+                    // the failed statement may have consumed the delimiter
+                    // that ends it, so search from where it started
+                    self.index = start_index;
+                    self.skip_to_statement_delimiter();
+                    self.state = ParserState::Normal;
+                    expecting_statement_delimiter = false;
+                }
+            }
+        }
+        (stmts, errors)
+    }
+
+    /// Parse potentially multiple statements, keeping the comments that
+    /// surround each of them and the comments inside them.
+    ///
+    /// Comments between two statements are attached to the second one as
+    /// leading comments, unless they start on the line where the first one
+    /// ends, in which case they are attached to the first one as trailing
+    /// comments. Comments inside a statement are attached the same way to
+    /// the tokens surrounding them, see [`CommentedStatement::inner_comments`].
+    ///
+    /// Example
+    /// ```
+    /// # use sqlparser::{parser::{Parser, ParserError}, dialect::GenericDialect};
+    /// # fn main() -> Result<(), ParserError> {
+    /// let dialect = GenericDialect{};
+    /// let statements = Parser::new(&dialect)
+    ///   .try_with_sql("-- first\nSELECT 1; /* one */\nSELECT 2")?
+    ///   .parse_commented_statements()?;
+    /// assert_eq!(statements[0].to_string(), "-- first\nSELECT 1 /* one */");
+    /// assert_eq!(statements[1].to_string(), "SELECT 2");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_commented_statements(&mut self) -> Result<Vec<CommentedStatement>, ParserError> {
+        let mut stmts = Vec::new();
+        let mut leading_comments = Vec::new();
+        loop {
+            self.consume_comments(&mut leading_comments, false);
+            if self.consume_token(&Token::SemiColon) {
+                continue;
+            }
+            if self.peek_token().token == Token::EOF {
+                break;
+            }
+
+            let start = self.index;
+            let statement = self.parse_statement()?;
+            // looking ahead may have left the index past trailing whitespace,
+            // e.g. after reaching EOF
+            self.index = self.index.min(self.tokens.len());
+            while self.index > 0
+                && matches!(self.tokens[self.index - 1].token, Token::Whitespace(_))
+            {
+                self.index -= 1;
+            }
+            let inner_comments = self.inner_comments(start..self.index);
+
+            let mut trailing_comments = Vec::new();
+            self.consume_comments(&mut trailing_comments, true);
+            if !self.consume_token(&Token::SemiColon)
+                && self.peek_token().token != Token::EOF
+                && !is_followed_by_copy_data(&statement)
+            {
                 return self.expected("end of statement", self.peek_token());
             }
+            if !matches!(
+                trailing_comments.last(),
+                Some(Whitespace::SingleLineComment { .. })
+            ) {
+                self.consume_comments(&mut trailing_comments, true);
+            }
+
+            stmts.push(CommentedStatement {
+                leading_comments: core::mem::take(&mut leading_comments),
+                statement,
+                trailing_comments,
+                inner_comments,
+            });
+        }
+        Ok(stmts)
+    }
+
+    /// Parse a string with one or more SQL statements, recording where the
+    /// text of each of them is located in `sql`.
+    ///
+    /// This allows re-emitting the statements exactly as they were written,
+    /// e.g. `&sql[statement.source_range]`.
+    ///
+    /// Example
+    /// ```
+    /// # use sqlparser::{parser::{Parser, ParserError}, dialect::GenericDialect};
+    /// # fn main() -> Result<(), ParserError> {
+    /// let dialect = GenericDialect{};
+    /// let sql = "select  1;\n-- two\nSELECT\t2";
+    /// let statements = Parser::parse_sql_with_ranges(&dialect, sql)?;
+    /// assert_eq!(&sql[statements[0].source_range.clone()], "select  1");
+    /// assert!(statements[0].ends_with_semicolon);
+    /// assert_eq!(&sql[statements[1].source_range.clone()], "SELECT\t2");
+    /// assert!(!statements[1].ends_with_semicolon);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_sql_with_ranges(
+        dialect: &dyn Dialect,
+        sql: &str,
+    ) -> Result<Vec<ParsedStatement>, ParserError> {
+        Parser::new(dialect)
+            .try_with_sql(sql)?
+            .parse_statements_with_ranges(sql)
+    }
+
+    /// Parse the statements of [`Self::parse_sql_with_ranges`], where `sql`
+    /// is the SQL the tokens of this parser were produced from
+    fn parse_statements_with_ranges(
+        &mut self,
+        sql: &str,
+    ) -> Result<Vec<ParsedStatement>, ParserError> {
+        let offsets = SourceOffsets::new(sql);
+        let mut stmts = Vec::new();
+        loop {
+            while self.consume_token(&Token::SemiColon) {}
+            let start = self.peek_token();
+            if start.token == Token::EOF {
+                break;
+            }
 
             let statement = self.parse_statement()?;
-            stmts.push(statement);
-            expecting_statement_delimiter = true;
+            // looking ahead may have left the index past trailing whitespace,
+            // e.g. after reaching EOF
+            self.index = self.index.min(self.tokens.len());
+            while self.index > 0
+                && matches!(self.tokens[self.index - 1].token, Token::Whitespace(_))
+            {
+                self.index -= 1;
+            }
+            // the statement ends where the token following it starts
+            let end = match self.tokens.get(self.index) {
+                Some(token) => offsets.offset(token.location),
+                None => sql.len(),
+            };
+
+            // the `;` of a `COPY ... FROM STDIN` precedes its data, and was
+            // consumed along with it
+            let ends_with_semicolon =
+                is_followed_by_copy_data(&statement) || self.consume_token(&Token::SemiColon);
+            if !ends_with_semicolon && self.peek_token().token != Token::EOF {
+                return self.expected("end of statement", self.peek_token());
+            }
+
+            stmts.push(ParsedStatement {
+                statement,
+                source_range: offsets.offset(start.location)..end,
+                ends_with_semicolon,
+            });
         }
         Ok(stmts)
     }
 
+    /// Collects the comments between the tokens in `range`. Comments that
+    /// follow a token on its line are attached to it as trailing comments,
+    /// unless another token follows them on that line, all others are
+    /// attached to the token following them as leading comments. Commas are
+    /// skipped, so that e.g. in `a, -- first` the comment is attached to `a`.
+    fn inner_comments(&self, range: Range<usize>) -> Vec<TokenComments> {
+        fn attach(attached: &mut Vec<TokenComments>, span: Span) -> &mut TokenComments {
+            if attached.last().map(|a| a.span) != Some(span) {
+                attached.push(TokenComments {
+                    span,
+                    leading: vec![],
+                    trailing: vec![],
+                });
+            }
+            attached.last_mut().unwrap()
+        }
+
+        let mut attached = Vec::new();
+
+        let mut previous = None;
+        let mut trailing = Vec::new();
+        let mut leading = Vec::new();
+        // whether the previous token ends on the current line
+        let mut same_line = false;
+        for index in range {
+            match &self.tokens[index].token {
+                Token::Whitespace(Whitespace::Newline) => same_line = false,
+                Token::Whitespace(
+                    comment @ (Whitespace::SingleLineComment { .. }
+                    | Whitespace::MultiLineComment(_)),
+                ) => {
+                    if same_line {
+                        trailing.push(comment.clone());
+                        // the comment includes the newline ending it
+                        same_line = matches!(comment, Whitespace::MultiLineComment(_));
+                    } else {
+                        leading.push(comment.clone());
+                    }
+                }
+                Token::Whitespace(_) => {}
+                // comments around a separator belong to the items it separates
+                Token::Comma => {}
+                _ => {
+                    if same_line {
+                        trailing.append(&mut leading);
+                        leading = trailing;
+                        trailing = Vec::new();
+                    }
+                    if let Some(previous) = previous.filter(|_| !trailing.is_empty()) {
+                        attach(&mut attached, self.token_span(previous)).trailing = trailing;
+                        trailing = Vec::new();
+                    }
+                    if !leading.is_empty() {
+                        attach(&mut attached, self.token_span(index)).leading = leading;
+                        leading = Vec::new();
+                    }
+                    previous = Some(index);
+                    same_line = true;
+                }
+            }
+        }
+        attached
+    }
+
+    /// Returns the location of the token at `index`, which ends where the
+    /// token following it starts.
+    fn token_span(&self, index: usize) -> Span {
+        let start = self.tokens[index].location;
+        let end = match self.tokens.get(index + 1) {
+            Some(next) => next.location,
+            None => self.tokens[index]
+                .to_string()
+                .chars()
+                .fold(start, |location, ch| match ch {
+                    '\n' => Location {
+                        line: location.line + 1,
+                        column: 1,
+                    },
+                    _ => Location {
+                        line: location.line,
+                        column: location.column + 1,
+                    },
+                }),
+        };
+        Span { start, end }
+    }
+
+    /// Consumes whitespace, collecting any comments into `comments`. If
+    /// `same_line` is true, stops at the first newline, including the one
+    /// that ends a single line comment.
+    fn consume_comments(&mut self, comments: &mut Vec<Whitespace>, same_line: bool) {
+        while let Token::Whitespace(whitespace) = self.peek_token_no_skip().token {
+            if same_line && whitespace == Whitespace::Newline {
+                return;
+            }
+            self.index += 1;
+            match whitespace {
+                Whitespace::SingleLineComment { .. } => {
+                    comments.push(whitespace);
+                    if same_line {
+                        return;
+                    }
+                }
+                Whitespace::MultiLineComment(_) => comments.push(whitespace),
+                _ => {}
+            }
+        }
+    }
+
+    /// Advances past all tokens up to the next statement delimiter (`;`)
+    /// or EOF, leaving the delimiter itself unconsumed.
+    fn skip_to_statement_delimiter(&mut self) {
+        loop {
+            match self.peek_token().token {
+                Token::SemiColon | Token::EOF => break,
+                _ => {
+                    self.next_token();
+                }
+            }
+        }
+    }
+
     /// Convenience method to parse a string with one or more SQL
     /// statements into produce an Abstract Syntax Tree (AST).
     ///
@@ -457,6 +1049,116 @@ impl<'a> Parser<'a> {
         Parser::new(dialect).try_with_sql(sql)?.parse_statements()
     }
 
+    /// Convenience method to parse a string that contains a single SQL
+    /// expression, such as the condition of a `CHECK` constraint.
+    ///
+    /// Returns an error if anything but whitespace follows the expression.
+    ///
+    /// Example
+    /// ```
+    /// # use sqlparser::{parser::{Parser, ParserError}, dialect::GenericDialect};
+    /// # fn main() -> Result<(), ParserError> {
+    /// let dialect = GenericDialect{};
+    /// let expr = Parser::parse_sql_expr(&dialect, "price > 0 AND price < 100")?;
+    /// assert_eq!(expr.to_string(), "price > 0 AND price < 100");
+    /// assert!(Parser::parse_sql_expr(&dialect, "price > 0 price").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_sql_expr(dialect: &dyn Dialect, sql: &str) -> Result<Expr, ParserError> {
+        let mut parser = Parser::new(dialect).try_with_sql(sql)?;
+        let expr = parser.parse_expr()?;
+        parser.expect_end_of_input("end of expression")?;
+        Ok(expr)
+    }
+
+    /// Convenience method to parse a string that contains a single SQL
+    /// data type, such as the type of a column.
+    ///
+    /// Returns an error if anything but whitespace follows the data type.
+    ///
+    /// Example
+    /// ```
+    /// # use sqlparser::{ast::DataType, parser::{Parser, ParserError}, dialect::GenericDialect};
+    /// # fn main() -> Result<(), ParserError> {
+    /// let dialect = GenericDialect{};
+    /// let data_type = Parser::parse_sql_data_type(&dialect, "VARCHAR(20)")?;
+    /// assert_eq!(data_type.to_string(), "VARCHAR(20)");
+    /// assert!(Parser::parse_sql_data_type(&dialect, "INT NOT NULL").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_sql_data_type(dialect: &dyn Dialect, sql: &str) -> Result<DataType, ParserError> {
+        let mut parser = Parser::new(dialect).try_with_sql(sql)?;
+        let data_type = parser.parse_data_type()?;
+        parser.expect_end_of_input("end of data type")?;
+        Ok(data_type)
+    }
+
+    /// Report an error unless all tokens have been consumed
+    fn expect_end_of_input(&mut self, expected: &str) -> Result<(), ParserError> {
+        match self.peek_token_ref().token {
+            Token::EOF => Ok(()),
+            _ => self.expected(expected, self.peek_token()),
+        }
+    }
+
+    /// Parse each of the given SQL strings with [`Parser::parse_sql`], spreading
+    /// the work over the available CPU cores.
+    ///
+    /// The results are returned in the same order as the inputs, and an error
+    /// in one input does not affect the others.
+    ///
+    /// Example
+    /// ```
+    /// # use sqlparser::{parser::Parser, dialect::GenericDialect};
+    /// let dialect = GenericDialect{};
+    /// let results = Parser::parse_statements_parallel(
+    ///   &dialect, &["SELECT 1", "SELEC 2", "SELECT 3; SELECT 4"]
+    /// );
+    /// assert_eq!(results.len(), 3);
+    /// assert!(results[1].is_err());
+    /// assert_eq!(results[2].as_ref().unwrap().len(), 2);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn parse_statements_parallel(
+        dialect: &dyn Dialect,
+        sqls: &[&str],
+    ) -> Vec<Result<Vec<Statement>, ParserError>> {
+        let threads = std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1)
+            .min(sqls.len());
+        if threads <= 1 {
+            return sqls
+                .iter()
+                .map(|sql| Parser::parse_sql(dialect, sql))
+                .collect();
+        }
+
+        let chunk_size = sqls.len().div_ceil(threads);
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = sqls
+                .chunks(chunk_size)
+                .map(|chunk| {
+                    scope.spawn(move || {
+                        chunk
+                            .iter()
+                            .map(|sql| Parser::parse_sql(dialect, sql))
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .flat_map(|handle| match handle.join() {
+                    Ok(results) => results,
+                    Err(panic) => std::panic::resume_unwind(panic),
+                })
+                .collect()
+        })
+    }
+
     /// Parse a single top-level statement (such as SELECT, INSERT, CREATE, etc.),
     /// stopping before the statement separator, if any.
     pub fn parse_statement(&mut self) -> Result<Statement, ParserError> {
@@ -476,9 +1178,9 @@ impl<'a> Parser<'a> {
                 Keyword::DESCRIBE => self.parse_explain(DescribeAlias::Describe),
                 Keyword::EXPLAIN => self.parse_explain(DescribeAlias::Explain),
                 Keyword::ANALYZE => self.parse_analyze(),
-                Keyword::SELECT | Keyword::WITH | Keyword::VALUES => {
+                Keyword::SELECT | Keyword::WITH | Keyword::VALUES | Keyword::TABLE => {
                     self.prev_token();
-                    self.parse_boxed_query().map(Statement::Query)
+                    self.parse_query().map(QueryRef::new).map(Statement::Query)
                 }
                 Keyword::TRUNCATE => self.parse_truncate(),
                 Keyword::ATTACH => {
@@ -496,6 +1198,35 @@ impl<'a> Parser<'a> {
                 Keyword::CACHE => self.parse_cache_table(),
                 Keyword::DROP => self.parse_drop(),
                 Keyword::DISCARD => self.parse_discard(),
+                Keyword::DO if dialect_of!(self is MySqlDialect) => self.parse_do_expressions(),
+                Keyword::DO
+                    if dialect_of!(self is GenericDialect)
+                        && !matches!(
+                            self.peek_token().token,
+                            Token::Word(Word {
+                                keyword: Keyword::LANGUAGE,
+                                ..
+                            }) | Token::SingleQuotedString(_)
+                                | Token::DollarQuotedString(_)
+                        ) =>
+                {
+                    self.parse_do_expressions()
+                }
+                Keyword::DO if dialect_of!(self is PostgreSqlDialect | GenericDialect) => {
+                    self.parse_do()
+                }
+                Keyword::HANDLER if dialect_of!(self is MySqlDialect | GenericDialect) => {
+                    self.parse_handler()
+                }
+                Keyword::REFRESH if dialect_of!(self is PostgreSqlDialect | GenericDialect) => {
+                    self.parse_refresh_materialized_view()
+                }
+                Keyword::RENAME if dialect_of!(self is MySqlDialect | GenericDialect) => {
+                    self.parse_rename_table()
+                }
+                Keyword::NOTIFY if dialect_of!(self is PostgreSqlDialect | GenericDialect) => {
+                    self.parse_notify()
+                }
                 Keyword::DECLARE => self.parse_declare(),
                 Keyword::FETCH => self.parse_fetch_statement(),
                 Keyword::DELETE => self.parse_delete(),
@@ -516,6 +1247,9 @@ impl<'a> Parser<'a> {
                 // `BEGIN` is a nonstandard but common alias for the
                 // standard `START TRANSACTION` statement. It is supported
                 // by at least PostgreSQL and MySQL.
+                Keyword::BEGIN if dialect_of!(self is MsSqlDialect) && self.peek_block_start() => {
+                    self.parse_block()
+                }
                 Keyword::BEGIN => self.parse_begin(),
                 // `END` is a nonstandard but common alias for the
                 // standard `COMMIT TRANSACTION` statement. It is supported
@@ -526,6 +1260,10 @@ impl<'a> Parser<'a> {
                 Keyword::COMMIT => self.parse_commit(),
                 Keyword::ROLLBACK => self.parse_rollback(),
                 Keyword::ASSERT => self.parse_assert(),
+                // `IF`, `WHILE` and `RETURN` are T-SQL control flow statements
+                Keyword::IF if dialect_of!(self is MsSqlDialect) => self.parse_if_statement(),
+                Keyword::WHILE if dialect_of!(self is MsSqlDialect) => self.parse_while(),
+                Keyword::RETURN if dialect_of!(self is MsSqlDialect) => self.parse_return(),
                 // `PREPARE`, `EXECUTE` and `DEALLOCATE` are Postgres-specific
                 // syntaxes. They are used for Postgres prepared statement.
                 Keyword::DEALLOCATE => self.parse_deallocate(),
@@ -551,7 +1289,7 @@ impl<'a> Parser<'a> {
             },
             Token::LParen => {
                 self.prev_token();
-                self.parse_boxed_query().map(Statement::Query)
+                self.parse_query().map(QueryRef::new).map(Statement::Query)
             }
             _ => self.expected("an SQL statement", next_token),
         }
@@ -862,29 +1600,28 @@ impl<'a> Parser<'a> {
 
         let next_token = self.next_token();
         match next_token.token {
-            t @ (Token::Word(_) | Token::SingleQuotedString(_)) => {
-                if self.peek_token().token == Token::Period {
-                    let mut id_parts: Vec<Ident> = vec![match t {
-                        Token::Word(w) => w.to_ident(),
-                        Token::SingleQuotedString(s) => Ident::with_quote('\'', s),
-                        _ => unreachable!(), // We matched above
-                    }];
-
-                    while self.consume_token(&Token::Period) {
-                        let next_token = self.next_token();
-                        match next_token.token {
-                            Token::Word(w) => id_parts.push(w.to_ident()),
-                            Token::SingleQuotedString(s) => {
-                                // SQLite has single-quoted identifiers
-                                id_parts.push(Ident::with_quote('\'', s))
-                            }
-                            Token::Mul => {
-                                return Ok(Expr::QualifiedWildcard(ObjectName(id_parts)));
-                            }
-                            _ => {
-                                return self
-                                    .expected("an identifier or a '*' after '.'", next_token);
-                            }
+            t @ (Token::Word(_) | Token::SingleQuotedString(_))
+                if self.peek_token().token == Token::Period =>
+            {
+                let mut id_parts: Vec<Ident> = vec![match t {
+                    Token::Word(w) => w.to_ident(),
+                    Token::SingleQuotedString(s) => Ident::with_quote('\'', s),
+                    _ => unreachable!(), // We matched above
+                }];
+
+                while self.consume_token(&Token::Period) {
+                    let next_token = self.next_token();
+                    match next_token.token {
+                        Token::Word(w) => id_parts.push(w.to_ident()),
+                        Token::SingleQuotedString(s) => {
+                            // SQLite has single-quoted identifiers
+                            id_parts.push(Ident::with_quote('\'', s))
+                        }
+                        Token::Mul => {
+                            return Ok(Expr::QualifiedWildcard(ObjectName(id_parts)));
+                        }
+                        _ => {
+                            return self.expected("an identifier or a '*' after '.'", next_token);
                         }
                     }
                 }
@@ -908,8 +1645,18 @@ impl<'a> Parser<'a> {
     pub fn parse_subexpr(&mut self, precedence: u8) -> Result<Expr, ParserError> {
         let _guard = self.recursion_counter.try_decrease()?;
         debug!("parsing expr");
-        let mut expr = self.parse_prefix()?;
+        let expr = self.parse_prefix()?;
         debug!("prefix: {:?}", expr);
+        self.parse_subexpr_with_prefix(expr, precedence)
+    }
+
+    /// Parse the infix operators following an already parsed `expr`, until
+    /// the precedence changes.
+    fn parse_subexpr_with_prefix(
+        &mut self,
+        mut expr: Expr,
+        precedence: u8,
+    ) -> Result<Expr, ParserError> {
         loop {
             let next_precedence = self.get_next_precedence()?;
             debug!("next precedence: {:?}", next_precedence);
@@ -923,6 +1670,134 @@ impl<'a> Parser<'a> {
         Ok(expr)
     }
 
+    /// Parse the right operand of an infix operator with the given
+    /// `precedence`, e.g. in [`Dialect::parse_infix`].
+    ///
+    /// The operand of a right associative operator is parsed with
+    /// `precedence - 1`, which also accepts operators of exactly that
+    /// precedence, so leave a gap below such operators.
+    pub fn parse_infix_operand(
+        &mut self,
+        precedence: u8,
+        associativity: Associativity,
+    ) -> Result<Expr, ParserError> {
+        match associativity {
+            Associativity::Left => self.parse_subexpr(precedence),
+            Associativity::Right => self.parse_subexpr(precedence.saturating_sub(1)),
+        }
+    }
+
+    /// Parse a new expression into `arena`, returning the id of its root.
+    ///
+    /// Chains of binary operators are built directly in the arena, other
+    /// expressions are parsed as usual and then moved into it, see
+    /// [`ParseArena`].
+    ///
+    /// [`Dialect::parse_infix`] is consulted before every infix operator as
+    /// in [`Parser::parse_expr`]. Unless the left operand is a leaf, such as
+    /// an identifier or a value, the dialect is first asked with a `NULL`
+    /// placeholder for it, and the left operand is only moved out of the
+    /// arena if that returns `Some`. So whether a dialect parses an operator
+    /// should only depend on the tokens, not on the left operand.
+    #[cfg(feature = "arena")]
+    pub fn parse_expr_in(&mut self, arena: &mut ParseArena) -> Result<ExprId, ParserError> {
+        self.parse_subexpr_in(arena, self.dialect.prec_unknown())
+    }
+
+    #[cfg(feature = "arena")]
+    fn parse_subexpr_in(
+        &mut self,
+        arena: &mut ParseArena,
+        precedence: u8,
+    ) -> Result<ExprId, ParserError> {
+        let _guard = self.recursion_counter.try_decrease()?;
+        // `expr` and its children always occupy the end of the arena,
+        // starting at `start`
+        let start = arena.len();
+        let prefix = self.parse_prefix()?;
+        let mut expr = arena.alloc(prefix);
+        loop {
+            let next_precedence = self.get_next_precedence()?;
+            if precedence >= next_precedence {
+                break;
+            }
+            expr = self.parse_infix_in(arena, start, expr, next_precedence)?;
+        }
+        Ok(expr)
+    }
+
+    #[cfg(feature = "arena")]
+    fn parse_infix_in(
+        &mut self,
+        arena: &mut ParseArena,
+        start: usize,
+        mut left: ExprId,
+        precedence: u8,
+    ) -> Result<ExprId, ParserError> {
+        let index = self.index;
+        // allow the dialect to override infix parsing, see `parse_infix`,
+        // without moving the left operand out of the arena unless the
+        // dialect parses the operator, see `parse_expr_in`
+        let infix = if let Some(left_expr) = arena.leaf_expr(left) {
+            self.dialect.parse_infix(self, &left_expr, precedence)
+        } else if self
+            .dialect
+            .parse_infix(self, &Expr::Value(Value::Null), precedence)
+            .is_some()
+        {
+            self.index = index;
+            let left_expr = arena.take(left);
+            arena.truncate(start);
+            let infix = self.dialect.parse_infix(self, &left_expr, precedence);
+            if infix.is_none() {
+                left = arena.alloc(left_expr);
+            }
+            infix
+        } else {
+            None
+        };
+        if let Some(infix) = infix {
+            let infix = infix?;
+            arena.truncate(start);
+            return Ok(arena.push(ArenaExpr::Other(Box::new(infix))));
+        }
+        self.index = index;
+
+        let mut tok = self.next_token();
+        if let Some(op) = self.parse_binary_operator(&mut tok.token)? {
+            if self
+                .parse_one_of_keywords(&[Keyword::ANY, Keyword::ALL, Keyword::SOME])
+                .is_none()
+            {
+                let right = self.parse_subexpr_in(arena, precedence)?;
+                return Ok(arena.push(ArenaExpr::BinaryOp { left, op, right }));
+            }
+        }
+        self.index = index;
+        if self.parse_keywords(&[Keyword::IS, Keyword::NULL]) {
+            return Ok(arena.push(ArenaExpr::IsNull(left)));
+        }
+        if self.parse_keywords(&[Keyword::IS, Keyword::NOT, Keyword::NULL]) {
+            return Ok(arena.push(ArenaExpr::IsNotNull(left)));
+        }
+
+        // Everything else only exists for the boxed AST. Unless the left
+        // operand is small, the result is kept boxed so that the left
+        // operand isn't moved back into the arena node by node.
+        let small = matches!(
+            arena[left],
+            ArenaExpr::Identifier(_) | ArenaExpr::CompoundIdentifier(_) | ArenaExpr::Value(_)
+        );
+        let left_expr = arena.take(left);
+        arena.truncate(start);
+        let expr = self.parse_builtin_infix(left_expr, precedence)?;
+        if small {
+            Ok(arena.alloc(expr))
+        } else {
+            Ok(arena.push(ArenaExpr::Other(Box::new(expr))))
+        }
+    }
+
     pub fn parse_assert(&mut self) -> Result<Statement, ParserError> {
         let condition = self.parse_expr()?;
         let message = if self.parse_keyword(Keyword::AS) {
@@ -934,6 +1809,106 @@ impl<'a> Parser<'a> {
         Ok(Statement::Assert { condition, message })
     }
 
+    /// Returns true if the `BEGIN` just consumed starts a `BEGIN ... END`
+    /// block rather than a transaction
+    fn peek_block_start(&self) -> bool {
+        !matches!(
+            self.peek_token().token,
+            Token::EOF
+                | Token::SemiColon
+                | Token::Word(Word {
+                    keyword: Keyword::TRANSACTION | Keyword::WORK,
+                    ..
+                })
+        )
+    }
+
+    /// Parse the statements of a `BEGIN ... END` block, after the `BEGIN`
+    pub fn parse_block(&mut self) -> Result<Statement, ParserError> {
+        let statements = self.parse_statements_until_end()?;
+        Ok(Statement::Block(statements))
+    }
+
+    /// Parse statements up to and including the `END` that terminates them.
+    /// Unlike [`Parser::parse_statements`] the statements don't have to be
+    /// separated by semicolons, as in T-SQL.
+    fn parse_statements_until_end(&mut self) -> Result<Vec<Statement>, ParserError> {
+        let mut statements = vec![];
+        loop {
+            while self.consume_token(&Token::SemiColon) {}
+            if self.parse_keyword(Keyword::END) {
+                break;
+            }
+            if self.peek_token() == Token::EOF {
+                return self.expected("END", self.peek_token());
+            }
+            statements.push(self.parse_statement()?);
+        }
+        Ok(statements)
+    }
+
+    /// Parse an `IF <condition> <statement> [ELSE <statement>]` statement,
+    /// after the `IF`
+    pub fn parse_if_statement(&mut self) -> Result<Statement, ParserError> {
+        let condition = self.parse_expr()?;
+        let then_statement = Box::new(self.parse_statement()?);
+        // The statement before `ELSE` may be terminated with a semicolon
+        if let [Token::SemiColon, Token::Word(w)] = self.peek_tokens() {
+            if w.keyword == Keyword::ELSE {
+                self.next_token();
+            }
+        }
+        let else_statement = if self.parse_keyword(Keyword::ELSE) {
+            Some(Box::new(self.parse_statement()?))
+        } else {
+            None
+        };
+        Ok(Statement::If {
+            condition,
+            then_statement,
+            else_statement,
+        })
+    }
+
+    /// Parse a `WHILE <condition> <statement>` statement, after the `WHILE`
+    pub fn parse_while(&mut self) -> Result<Statement, ParserError> {
+        let condition = self.parse_expr()?;
+        let body = Box::new(self.parse_statement()?);
+        Ok(Statement::While { condition, body })
+    }
+
+    /// Parse a `RETURN [<expr>]` statement, after the `RETURN`
+    pub fn parse_return(&mut self) -> Result<Statement, ParserError> {
+        let expr = match self.peek_token().token {
+            Token::EOF | Token::SemiColon => None,
+            Token::Word(w)
+                if matches!(
+                    w.keyword,
+                    Keyword::END
+                        | Keyword::ELSE
+                        | Keyword::BEGIN
+                        | Keyword::IF
+                        | Keyword::WHILE
+                        | Keyword::RETURN
+                        | Keyword::SELECT
+                        | Keyword::INSERT
+                        | Keyword::UPDATE
+                        | Keyword::DELETE
+                        | Keyword::MERGE
+                        | Keyword::WITH
+                        | Keyword::SET
+                        | Keyword::DECLARE
+                        | Keyword::EXEC
+                        | Keyword::EXECUTE
+                ) =>
+            {
+                None
+            }
+            _ => Some(self.parse_expr()?),
+        };
+        Ok(Statement::Return(expr))
+    }
+
     pub fn parse_savepoint(&mut self) -> Result<Statement, ParserError> {
         let name = self.parse_identifier(false)?;
         Ok(Statement::Savepoint { name })
@@ -1012,6 +1987,7 @@ impl<'a> Parser<'a> {
                         null_treatment: None,
                         filter: None,
                         over: None,
+                        safe: false,
                         within_group: vec![],
                     }))
                 }
@@ -1023,7 +1999,16 @@ impl<'a> Parser<'a> {
                     self.parse_time_functions(ObjectName(vec![w.to_ident()]))
                 }
                 Keyword::CASE => self.parse_case_expr(),
-                Keyword::CONVERT => self.parse_convert_expr(),
+                Keyword::CONVERT => self.parse_convert_expr(false),
+                Keyword::TRY_CONVERT if self.dialect.convert_type_before_value() => {
+                    self.parse_convert_expr(true)
+                }
+                Keyword::PARSE | Keyword::TRY_PARSE
+                    if dialect_of!(self is MsSqlDialect)
+                        && self.peek_token().token == Token::LParen =>
+                {
+                    self.parse_parse_expr(w.keyword == Keyword::TRY_PARSE)
+                }
                 Keyword::CAST => self.parse_cast_expr(CastKind::Cast),
                 Keyword::TRY_CAST => self.parse_cast_expr(CastKind::TryCast),
                 Keyword::SAFE_CAST => self.parse_cast_expr(CastKind::SafeCast),
@@ -1033,7 +2018,7 @@ impl<'a> Parser<'a> {
                         || matches!(
                             self.peek_nth_token(1).token,
                             Token::Word(Word {
-                                keyword: Keyword::SELECT | Keyword::WITH,
+                                keyword: Keyword::SELECT | Keyword::WITH | Keyword::TABLE,
                                 ..
                             })
                         ) =>
@@ -1060,19 +2045,23 @@ impl<'a> Parser<'a> {
                         && !dialect_of!(self is ClickHouseDialect | DatabricksDialect) =>
                 {
                     self.expect_token(&Token::LParen)?;
-                    let query = self.parse_boxed_query()?;
+                    let query = QueryRef::new(self.parse_query()?);
                     self.expect_token(&Token::RParen)?;
-                    Ok(Expr::Function(Function {
-                        name: ObjectName(vec![w.to_ident()]),
-                        parameters: FunctionArguments::None,
-                        args: FunctionArguments::Subquery(query),
-                        filter: None,
-                        null_treatment: None,
-                        over: None,
-                        within_group: vec![],
-                    }))
+                    Ok(Expr::ArraySubquery(query))
+                }
+                Keyword::ROW if self.peek_token() == Token::LParen => {
+                    self.expect_token(&Token::LParen)?;
+                    let exprs = self.parse_comma_separated0(Parser::parse_expr, Token::RParen)?;
+                    self.expect_token(&Token::RParen)?;
+                    Ok(Expr::Row(exprs))
                 }
                 Keyword::NOT => self.parse_not(),
+                // `DEFAULT(col)` is a function in MySQL
+                Keyword::DEFAULT
+                    if !matches!(self.peek_token().token, Token::LParen | Token::Period) =>
+                {
+                    Ok(Expr::Default)
+                }
                 Keyword::MATCH if dialect_of!(self is MySqlDialect | GenericDialect) => {
                     self.parse_match_against()
                 }
@@ -1080,6 +2069,21 @@ impl<'a> Parser<'a> {
                     self.prev_token();
                     self.parse_bigquery_struct_literal()
                 }
+                Keyword::NEW | Keyword::OLD
+                    if matches!(self.state, ParserState::Trigger)
+                        && self.dialect.supports_trigger_row_references()
+                        && self.peek_token() == Token::Period =>
+                {
+                    self.next_token();
+                    let row = match w.keyword {
+                        Keyword::NEW => TriggerRow::New,
+                        _ => TriggerRow::Old,
+                    };
+                    Ok(Expr::TriggerRowColumn {
+                        row,
+                        column: self.parse_identifier(false)?,
+                    })
+                }
                 Keyword::PRIOR if matches!(self.state, ParserState::ConnectBy) => {
                     let expr = self.parse_subexpr(self.dialect.prec_value(Precedence::PlusMinus))?;
                     Ok(Expr::Prior(Box::new(expr)))
@@ -1121,7 +2125,7 @@ impl<'a> Parser<'a> {
                         if ends_with_wildcard {
                             Ok(Expr::QualifiedWildcard(ObjectName(id_parts)))
                         } else if self.consume_token(&Token::LParen) {
-                            if dialect_of!(self is SnowflakeDialect | MsSqlDialect)
+                            if self.dialect.supports_outer_join_operator()
                                 && self.consume_tokens(&[Token::Plus, Token::RParen])
                             {
                                 Ok(Expr::OuterJoin(Box::new(
@@ -1219,44 +2223,18 @@ impl<'a> Parser<'a> {
             | Token::DoubleQuotedRawStringLiteral(_)
             | Token::TripleSingleQuotedRawStringLiteral(_)
             | Token::TripleDoubleQuotedRawStringLiteral(_)
+            | Token::SingleQuotedRawByteStringLiteral(_)
+            | Token::DoubleQuotedRawByteStringLiteral(_)
+            | Token::TripleSingleQuotedRawByteStringLiteral(_)
+            | Token::TripleDoubleQuotedRawByteStringLiteral(_)
             | Token::NationalStringLiteral(_)
+            | Token::QuoteDelimitedStringLiteral(_)
+            | Token::NationalQuoteDelimitedStringLiteral(_)
             | Token::HexStringLiteral(_) => {
                 self.prev_token();
                 Ok(Expr::Value(self.parse_value()?))
             }
-            Token::LParen => {
-                let expr = if let Some(expr) = self.try_parse_expr_sub_query()? {
-                    expr
-                } else if let Some(lambda) = self.try_parse_lambda() {
-                    return Ok(lambda);
-                } else {
-                    let exprs = self.parse_comma_separated(Parser::parse_expr)?;
-                    match exprs.len() {
-                        0 => unreachable!(), // parse_comma_separated ensures 1 or more
-                        1 => Expr::Nested(Box::new(exprs.into_iter().next().unwrap())),
-                        _ => Expr::Tuple(exprs),
-                    }
-                };
-                self.expect_token(&Token::RParen)?;
-                if !self.consume_token(&Token::Period) {
-                    Ok(expr)
-                } else {
-                    let tok = self.next_token();
-                    let key = match tok.token {
-                        Token::Word(word) => word.to_ident(),
-                        _ => {
-                            return parser_err!(
-                                format!("Expected identifier, found: {tok}"),
-                                tok.location
-                            )
-                        }
-                    };
-                    Ok(Expr::CompositeAccess {
-                        expr: Box::new(expr),
-                        key,
-                    })
-                }
-            }
+            Token::LParen => self.parse_parenthesized_expr(),
             Token::Placeholder(_) | Token::Colon | Token::AtSign => {
                 self.prev_token();
                 Ok(Expr::Value(self.parse_value()?))
@@ -1268,6 +2246,11 @@ impl<'a> Parser<'a> {
             _ => self.expected("an expression", next_token),
         }?;
 
+        self.parse_optional_collate(expr)
+    }
+
+    /// Parse an optional `COLLATE` clause following `expr`
+    fn parse_optional_collate(&mut self, expr: Expr) -> Result<Expr, ParserError> {
         if self.parse_keyword(Keyword::COLLATE) {
             Ok(Expr::Collate {
                 expr: Box::new(expr),
@@ -1278,6 +2261,85 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parse a parenthesized expression, tuple, subquery or lambda, after
+    /// its opening `(`.
+    ///
+    /// Directly nested parentheses such as `((((a))))` are parsed in a loop
+    /// rather than recursively, so that deeply nested expressions don't
+    /// overflow the stack. Each level still counts towards the recursion
+    /// limit.
+    fn parse_parenthesized_expr(&mut self) -> Result<Expr, ParserError> {
+        let mut guards = vec![];
+        let mut expr = loop {
+            if self.peek_token().token != Token::LParen {
+                break self.parse_parenthesized_contents(None)?;
+            }
+            guards.push(self.recursion_counter.try_decrease()?);
+            // allow the dialect to override prefix parsing, as in `parse_prefix`
+            if let Some(prefix) = self.dialect.parse_prefix(self) {
+                let expr = self.parse_parenthesized_contents(Some(prefix?))?;
+                guards.pop();
+                break expr;
+            }
+            self.next_token();
+        };
+        // each remaining guard stands for an enclosing `(`, in which `expr`
+        // is the prefix of the first expression
+        while guards.pop().is_some() {
+            let prefix = self.parse_optional_collate(expr)?;
+            expr = self.parse_parenthesized_contents(Some(prefix))?;
+        }
+        Ok(expr)
+    }
+
+    /// Parse the contents of parentheses up to and including the closing
+    /// `)`, where `prefix` is the already parsed prefix of the first
+    /// expression, if any.
+    fn parse_parenthesized_contents(&mut self, prefix: Option<Expr>) -> Result<Expr, ParserError> {
+        let expr = if prefix.is_some() {
+            None
+        } else if let Some(expr) = self.try_parse_expr_sub_query()? {
+            Some(expr)
+        } else if let Some(lambda) = self.try_parse_lambda() {
+            return Ok(lambda);
+        } else {
+            None
+        };
+        let expr = match expr {
+            Some(expr) => expr,
+            None => {
+                let mut prefix = prefix;
+                let exprs = self.parse_comma_separated(|parser| match prefix.take() {
+                    Some(prefix) => {
+                        parser.parse_subexpr_with_prefix(prefix, parser.dialect.prec_unknown())
+                    }
+                    None => parser.parse_expr(),
+                })?;
+                match exprs.len() {
+                    0 => unreachable!(), // parse_comma_separated ensures 1 or more
+                    1 => Expr::Nested(Box::new(exprs.into_iter().next().unwrap())),
+                    _ => Expr::Tuple(exprs),
+                }
+            }
+        };
+        self.expect_token(&Token::RParen)?;
+        if !self.consume_token(&Token::Period) {
+            Ok(expr)
+        } else {
+            let tok = self.next_token();
+            let key = match tok.token {
+                Token::Word(word) => word.to_ident(),
+                _ => {
+                    return parser_err!(format!("Expected identifier, found: {tok}"), tok.location)
+                }
+            };
+            Ok(Expr::CompositeAccess {
+                expr: Box::new(expr),
+                key,
+            })
+        }
+    }
+
     pub fn parse_utility_options(&mut self) -> Result<Vec<UtilityOption>, ParserError> {
         self.expect_token(&Token::LParen)?;
         let options = self.parse_comma_separated(Self::parse_utility_option)?;
@@ -1306,7 +2368,7 @@ impl<'a> Parser<'a> {
             return Ok(None);
         }
 
-        Ok(Some(Expr::Subquery(self.parse_boxed_query()?)))
+        Ok(Some(Expr::Subquery(QueryRef::new(self.parse_query()?))))
     }
 
     fn try_parse_lambda(&mut self) -> Option<Expr> {
@@ -1325,9 +2387,18 @@ impl<'a> Parser<'a> {
         })
     }
 
-    pub fn parse_function(&mut self, name: ObjectName) -> Result<Expr, ParserError> {
+    pub fn parse_function(&mut self, mut name: ObjectName) -> Result<Expr, ParserError> {
         self.expect_token(&Token::LParen)?;
 
+        // BigQuery's `SAFE.` prefix returns NULL instead of raising an error
+        let safe = dialect_of!(self is BigQueryDialect)
+            && name.0.len() > 1
+            && name.0[0].quote_style.is_none()
+            && name.0[0].value.eq_ignore_ascii_case("SAFE");
+        if safe {
+            name.0.remove(0);
+        }
+
         // Snowflake permits a subquery to be passed as an argument without
         // an enclosing set of parens if it's the only argument.
         if dialect_of!(self is SnowflakeDialect) && self.peek_sub_query() {
@@ -1340,6 +2411,7 @@ impl<'a> Parser<'a> {
                 filter: None,
                 null_treatment: None,
                 over: None,
+                safe,
                 within_group: vec![],
             }));
         }
@@ -1407,6 +2479,7 @@ impl<'a> Parser<'a> {
             null_treatment,
             filter,
             over,
+            safe,
             within_group,
         }))
     }
@@ -1440,6 +2513,7 @@ impl<'a> Parser<'a> {
             filter: None,
             over: None,
             null_treatment: None,
+            safe: false,
             within_group: vec![],
         }))
     }
@@ -1467,13 +2541,34 @@ impl<'a> Parser<'a> {
         } else {
             (self.parse_window_frame_bound()?, None)
         };
+        let exclude = if self.parse_keyword(Keyword::EXCLUDE) {
+            Some(self.parse_window_frame_exclusion()?)
+        } else {
+            None
+        };
         Ok(WindowFrame {
             units,
             start_bound,
             end_bound,
+            exclude,
         })
     }
 
+    /// Parse `CURRENT ROW`, `GROUP`, `TIES` or `NO OTHERS` after `EXCLUDE`
+    pub fn parse_window_frame_exclusion(&mut self) -> Result<WindowFrameExclusion, ParserError> {
+        if self.parse_keywords(&[Keyword::CURRENT, Keyword::ROW]) {
+            Ok(WindowFrameExclusion::CurrentRow)
+        } else if self.parse_keyword(Keyword::GROUP) {
+            Ok(WindowFrameExclusion::Group)
+        } else if self.parse_keyword(Keyword::TIES) {
+            Ok(WindowFrameExclusion::Ties)
+        } else if self.parse_keywords(&[Keyword::NO, Keyword::OTHERS]) {
+            Ok(WindowFrameExclusion::NoOthers)
+        } else {
+            self.expected("CURRENT ROW, GROUP, TIES or NO OTHERS", self.peek_token())
+        }
+    }
+
     /// Parse `CURRENT ROW` or `{ <positive number> | UNBOUNDED } { PRECEDING | FOLLOWING }`
     pub fn parse_window_frame_bound(&mut self) -> Result<WindowFrameBound, ParserError> {
         if self.parse_keywords(&[Keyword::CURRENT, Keyword::ROW]) {
@@ -1614,7 +2709,7 @@ impl<'a> Parser<'a> {
     }
 
     /// mssql-like convert function
-    fn parse_mssql_convert(&mut self) -> Result<Expr, ParserError> {
+    fn parse_mssql_convert(&mut self, is_try: bool) -> Result<Expr, ParserError> {
         self.expect_token(&Token::LParen)?;
         let data_type = self.parse_data_type()?;
         self.expect_token(&Token::Comma)?;
@@ -1626,6 +2721,7 @@ impl<'a> Parser<'a> {
         };
         self.expect_token(&Token::RParen)?;
         Ok(Expr::Convert {
+            is_try,
             expr: Box::new(expr),
             data_type: Some(data_type),
             charset: None,
@@ -1638,9 +2734,10 @@ impl<'a> Parser<'a> {
     ///  - `CONVERT('héhé' USING utf8mb4)` (MySQL)
     ///  - `CONVERT('héhé', CHAR CHARACTER SET utf8mb4)` (MySQL)
     ///  - `CONVERT(DECIMAL(10, 5), 42)` (MSSQL) - the type comes first
-    pub fn parse_convert_expr(&mut self) -> Result<Expr, ParserError> {
+    ///  - `TRY_CONVERT(INT, '42', 0)` (MSSQL)
+    pub fn parse_convert_expr(&mut self, is_try: bool) -> Result<Expr, ParserError> {
         if self.dialect.convert_type_before_value() {
-            return self.parse_mssql_convert();
+            return self.parse_mssql_convert(is_try);
         }
         self.expect_token(&Token::LParen)?;
         let expr = self.parse_expr()?;
@@ -1648,6 +2745,7 @@ impl<'a> Parser<'a> {
             let charset = self.parse_object_name(false)?;
             self.expect_token(&Token::RParen)?;
             return Ok(Expr::Convert {
+                is_try,
                 expr: Box::new(expr),
                 data_type: None,
                 charset: Some(charset),
@@ -1664,6 +2762,7 @@ impl<'a> Parser<'a> {
         };
         self.expect_token(&Token::RParen)?;
         Ok(Expr::Convert {
+            is_try,
             expr: Box::new(expr),
             data_type: Some(data_type),
             charset,
@@ -1672,6 +2771,27 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Parse a MSSQL `PARSE` or `TRY_PARSE` function, e.g.
+    /// `PARSE('2024-01-02' AS DATE USING 'en-US')`
+    pub fn parse_parse_expr(&mut self, is_try: bool) -> Result<Expr, ParserError> {
+        self.expect_token(&Token::LParen)?;
+        let expr = self.parse_expr()?;
+        self.expect_keyword(Keyword::AS)?;
+        let data_type = self.parse_data_type()?;
+        let culture = if self.parse_keyword(Keyword::USING) {
+            Some(Box::new(self.parse_expr()?))
+        } else {
+            None
+        };
+        self.expect_token(&Token::RParen)?;
+        Ok(Expr::Parse {
+            is_try,
+            expr: Box::new(expr),
+            data_type,
+            culture,
+        })
+    }
+
     /// Parse a SQL CAST function e.g. `CAST(expr AS FLOAT)`
     pub fn parse_cast_expr(&mut self, kind: CastKind) -> Result<Expr, ParserError> {
         self.expect_token(&Token::LParen)?;
@@ -1693,7 +2813,7 @@ impl<'a> Parser<'a> {
         self.expect_token(&Token::LParen)?;
         let exists_node = Expr::Exists {
             negated,
-            subquery: self.parse_boxed_query()?,
+            subquery: QueryRef::new(self.parse_query()?),
         };
         self.expect_token(&Token::RParen)?;
         Ok(exists_node)
@@ -1837,10 +2957,7 @@ impl<'a> Parser<'a> {
         self.expect_token(&Token::LParen)?;
         let mut trim_where = None;
         if let Token::Word(word) = self.peek_token().token {
-            if [Keyword::BOTH, Keyword::LEADING, Keyword::TRAILING]
-                .iter()
-                .any(|d| word.keyword == *d)
-            {
+            if [Keyword::BOTH, Keyword::LEADING, Keyword::TRAILING].contains(&word.keyword) {
                 trim_where = Some(self.parse_trim_where()?);
             }
         }
@@ -2530,15 +3647,14 @@ impl<'a> Parser<'a> {
         Ok(trailing_bracket)
     }
 
-    /// Parse an operator following an expression
-    pub fn parse_infix(&mut self, expr: Expr, precedence: u8) -> Result<Expr, ParserError> {
-        // allow the dialect to override infix parsing
-        if let Some(infix) = self.dialect.parse_infix(self, &expr, precedence) {
-            return infix;
-        }
-
-        let mut tok = self.next_token();
-        let regular_binary_operator = match &mut tok.token {
+    /// Returns the binary operator that `token`, the token just consumed,
+    /// starts, if any. Consumes the rest of multi-token operators such as
+    /// PostgreSQL's `OPERATOR(schema.op)`.
+    fn parse_binary_operator(
+        &mut self,
+        token: &mut Token,
+    ) -> Result<Option<BinaryOperator>, ParserError> {
+        Ok(match token {
             Token::Spaceship => Some(BinaryOperator::Spaceship),
             Token::DoubleEq => Some(BinaryOperator::Eq),
             Token::Eq => Some(BinaryOperator::Eq),
@@ -2627,7 +3743,23 @@ impl<'a> Parser<'a> {
                 _ => None,
             },
             _ => None,
-        };
+        })
+    }
+
+    /// Parse an operator following an expression
+    pub fn parse_infix(&mut self, expr: Expr, precedence: u8) -> Result<Expr, ParserError> {
+        // allow the dialect to override infix parsing
+        if let Some(infix) = self.dialect.parse_infix(self, &expr, precedence) {
+            return infix;
+        }
+        self.parse_builtin_infix(expr, precedence)
+    }
+
+    /// Parse an operator following an expression, without giving the
+    /// dialect a chance to override it
+    fn parse_builtin_infix(&mut self, expr: Expr, precedence: u8) -> Result<Expr, ParserError> {
+        let mut tok = self.next_token();
+        let regular_binary_operator = self.parse_binary_operator(&mut tok.token)?;
 
         if let Some(op) = regular_binary_operator {
             if let Some(keyword) =
@@ -3024,11 +4156,10 @@ impl<'a> Parser<'a> {
             });
         }
         self.expect_token(&Token::LParen)?;
-        let in_op = if self.parse_keyword(Keyword::SELECT) || self.parse_keyword(Keyword::WITH) {
-            self.prev_token();
+        let in_op = if self.peek_sub_query() {
             Expr::InSubquery {
                 expr: Box::new(expr),
-                subquery: self.parse_boxed_query()?,
+                subquery: QueryRef::new(self.parse_query()?),
                 negated,
             }
         } else {
@@ -3133,7 +4264,19 @@ impl<'a> Parser<'a> {
     }
 
     /// Return nth non-whitespace token that has not yet been processed
-    pub fn peek_nth_token(&self, mut n: usize) -> TokenWithLocation {
+    pub fn peek_nth_token(&self, n: usize) -> TokenWithLocation {
+        self.peek_nth_token_ref(n).clone()
+    }
+
+    /// Like [`Self::peek_token`], but returns a reference instead of a copy
+    /// of the token, which avoids copying the value of words.
+    pub fn peek_token_ref(&self) -> &TokenWithLocation {
+        self.peek_nth_token_ref(0)
+    }
+
+    /// Like [`Self::peek_nth_token`], but returns a reference instead of a
+    /// copy of the token.
+    pub fn peek_nth_token_ref(&self, mut n: usize) -> &TokenWithLocation {
         let mut index = self.index;
         loop {
             index += 1;
@@ -3144,10 +4287,7 @@ impl<'a> Parser<'a> {
                 }) => continue,
                 non_whitespace => {
                     if n == 0 {
-                        return non_whitespace.cloned().unwrap_or(TokenWithLocation {
-                            token: Token::EOF,
-                            location: Location { line: 0, column: 0 },
-                        });
+                        return non_whitespace.unwrap_or(&EOF_TOKEN);
                     }
                     n -= 1;
                 }
@@ -3176,6 +4316,16 @@ impl<'a> Parser<'a> {
     /// (or None if reached end-of-file) and mark it as processed. OK to call
     /// repeatedly after reaching EOF.
     pub fn next_token(&mut self) -> TokenWithLocation {
+        self.advance_token();
+        self.tokens
+            .get(self.index - 1)
+            .cloned()
+            .unwrap_or_else(|| TokenWithLocation::wrap(Token::EOF))
+    }
+
+    /// Marks the first non-whitespace token that has not yet been processed
+    /// as processed, like [`Self::next_token`] without returning it.
+    pub fn advance_token(&mut self) {
         loop {
             self.index += 1;
             match self.tokens.get(self.index - 1) {
@@ -3183,11 +4333,7 @@ impl<'a> Parser<'a> {
                     token: Token::Whitespace(_),
                     location: _,
                 }) => continue,
-                token => {
-                    return token
-                        .cloned()
-                        .unwrap_or_else(|| TokenWithLocation::wrap(Token::EOF))
-                }
+                _ => break,
             }
         }
     }
@@ -3228,9 +4374,9 @@ impl<'a> Parser<'a> {
     /// true. Otherwise, no tokens are consumed and returns false.
     #[must_use]
     pub fn parse_keyword(&mut self, expected: Keyword) -> bool {
-        match self.peek_token().token {
+        match &self.peek_token_ref().token {
             Token::Word(w) if expected == w.keyword => {
-                self.next_token();
+                self.advance_token();
                 true
             }
             _ => false,
@@ -3284,15 +4430,11 @@ impl<'a> Parser<'a> {
     /// and returns [`None`].
     #[must_use]
     pub fn parse_one_of_keywords(&mut self, keywords: &[Keyword]) -> Option<Keyword> {
-        match self.peek_token().token {
+        match &self.peek_token_ref().token {
             Token::Word(w) => {
-                keywords
-                    .iter()
-                    .find(|keyword| **keyword == w.keyword)
-                    .map(|keyword| {
-                        self.next_token();
-                        *keyword
-                    })
+                let keyword = keywords.iter().find(|keyword| **keyword == w.keyword)?;
+                self.advance_token();
+                Some(*keyword)
             }
             _ => None,
         }
@@ -3334,8 +4476,8 @@ impl<'a> Parser<'a> {
     /// Consume the next token if it matches the expected token, otherwise return false
     #[must_use]
     pub fn consume_token(&mut self, expected: &Token) -> bool {
-        if self.peek_token() == *expected {
-            self.next_token();
+        if self.peek_token_ref() == expected {
+            self.advance_token();
             true
         } else {
             false
@@ -3428,7 +4570,10 @@ impl<'a> Parser<'a> {
             let token = self.peek_token().token;
             match token {
                 Token::Word(ref kw)
-                    if keywords::RESERVED_FOR_COLUMN_ALIAS.contains(&kw.keyword) =>
+                    if self
+                        .dialect
+                        .reserved_keywords_for_column_alias()
+                        .contains(&kw.keyword) =>
                 {
                     true
                 }
@@ -3551,23 +4696,94 @@ impl<'a> Parser<'a> {
         Ok(Some(Distinct::On(col_names)))
     }
 
-    /// Parse a SQL CREATE statement
-    pub fn parse_create(&mut self) -> Result<Statement, ParserError> {
-        let or_replace = self.parse_keywords(&[Keyword::OR, Keyword::REPLACE]);
-        let or_alter = self.parse_keywords(&[Keyword::OR, Keyword::ALTER]);
-        let local = self.parse_one_of_keywords(&[Keyword::LOCAL]).is_some();
-        let global = self.parse_one_of_keywords(&[Keyword::GLOBAL]).is_some();
-        let transient = self.parse_one_of_keywords(&[Keyword::TRANSIENT]).is_some();
-        let global: Option<bool> = if global {
-            Some(true)
-        } else if local {
-            Some(false)
+    /// Parses one of the rare PostgreSQL DDL statements in
+    /// [`GENERIC_DDL_OBJECTS`] into a [`Statement::GenericDdl`], after its
+    /// `verb`, e.g. `CREATE`. Returns `None` without consuming any tokens
+    /// if the statement is not one of them.
+    fn parse_generic_ddl(
+        &mut self,
+        mut verb: Vec<Ident>,
+    ) -> Result<Option<Statement>, ParserError> {
+        if !dialect_of!(self is PostgreSqlDialect | GenericDialect) {
+            return Ok(None);
+        }
+        let Some(&(object_type, named)) = GENERIC_DDL_OBJECTS.iter().find(|(object_type, _)| {
+            object_type.iter().enumerate().all(|(i, keyword)| {
+                matches!(&self.peek_nth_token_ref(i).token, Token::Word(w) if w.keyword == *keyword)
+            })
+        }) else {
+            return Ok(None);
+        };
+        for _ in object_type {
+            let word = self.next_token();
+            verb.push(Ident::new(word.to_string().to_uppercase()));
+        }
+        let name = if named {
+            if self.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]) {
+                verb.extend(["IF", "NOT", "EXISTS"].map(Ident::new));
+            } else if self.parse_keywords(&[Keyword::IF, Keyword::EXISTS]) {
+                verb.extend(["IF", "EXISTS"].map(Ident::new));
+            }
+            Some(self.parse_object_name(false)?)
         } else {
             None
         };
-        let temporary = self
-            .parse_one_of_keywords(&[Keyword::TEMP, Keyword::TEMPORARY])
-            .is_some();
+        // Keep the whitespace between the tokens, so that they are displayed
+        // as written, e.g. the operator `===` that is tokenized as `==` and `=`
+        let mut options = vec![];
+        let mut depth = 0;
+        loop {
+            let token = self.peek_token_no_skip().token;
+            match token {
+                Token::EOF | Token::SemiColon if depth == 0 => break,
+                Token::EOF | Token::SemiColon => return self.expected(")", self.peek_token()),
+                Token::LParen => depth += 1,
+                Token::RParen if depth == 0 => return self.expected("(", self.peek_token()),
+                Token::RParen => depth -= 1,
+                _ => {}
+            }
+            self.next_token_no_skip();
+            options.push(token);
+        }
+        while matches!(options.last(), Some(Token::Whitespace(_))) {
+            options.pop();
+        }
+        Ok(Some(Statement::GenericDdl {
+            verb,
+            name,
+            options,
+        }))
+    }
+
+    /// Parse a SQL CREATE statement
+    pub fn parse_create(&mut self) -> Result<Statement, ParserError> {
+        let or_replace = self.parse_keywords(&[Keyword::OR, Keyword::REPLACE]);
+        let verb = if or_replace {
+            vec![
+                Ident::new("CREATE"),
+                Ident::new("OR"),
+                Ident::new("REPLACE"),
+            ]
+        } else {
+            vec![Ident::new("CREATE")]
+        };
+        if let Some(statement) = self.parse_generic_ddl(verb)? {
+            return Ok(statement);
+        }
+        let or_alter = self.parse_keywords(&[Keyword::OR, Keyword::ALTER]);
+        let local = self.parse_one_of_keywords(&[Keyword::LOCAL]).is_some();
+        let global = self.parse_one_of_keywords(&[Keyword::GLOBAL]).is_some();
+        let transient = self.parse_one_of_keywords(&[Keyword::TRANSIENT]).is_some();
+        let global: Option<bool> = if global {
+            Some(true)
+        } else if local {
+            Some(false)
+        } else {
+            None
+        };
+        let temporary = self
+            .parse_one_of_keywords(&[Keyword::TEMP, Keyword::TEMPORARY])
+            .is_some();
         let persistent = dialect_of!(self is DuckDbDialect)
             && self.parse_one_of_keywords(&[Keyword::PERSISTENT]).is_some();
         if self.parse_keyword(Keyword::TABLE) {
@@ -3589,6 +4805,30 @@ impl<'a> Parser<'a> {
             self.parse_create_macro(or_replace, temporary)
         } else if self.parse_keyword(Keyword::SECRET) {
             self.parse_create_secret(or_replace, temporary, persistent)
+        } else if self.parse_keyword(Keyword::EVENT) {
+            self.parse_create_event(None)
+        } else if self.parse_keyword(Keyword::PROCEDURE) {
+            self.parse_create_procedure(or_alter, or_replace)
+        } else if dialect_of!(self is BigQueryDialect | GenericDialect)
+            && self.parse_keyword(Keyword::MODEL)
+        {
+            self.parse_create_model(or_replace)
+        } else if dialect_of!(self is PostgreSqlDialect | GenericDialect)
+            && self.parse_keyword(Keyword::RULE)
+        {
+            self.parse_create_rule(or_replace)
+        } else if self
+            .parse_one_of_keywords(&[Keyword::TRUSTED, Keyword::PROCEDURAL, Keyword::LANGUAGE])
+            .is_some()
+        {
+            self.prev_token();
+            self.parse_create_language(or_replace)
+        } else if dialect_of!(self is MySqlDialect | GenericDialect)
+            && self.parse_keyword(Keyword::DEFINER)
+        {
+            let definer = self.parse_definer()?;
+            self.expect_keyword(Keyword::EVENT)?;
+            self.parse_create_event(Some(definer))
         } else if or_replace {
             self.expected(
                 "[EXTERNAL] TABLE or [MATERIALIZED] VIEW or FUNCTION after CREATE OR REPLACE",
@@ -3596,6 +4836,14 @@ impl<'a> Parser<'a> {
             )
         } else if self.parse_keyword(Keyword::EXTENSION) {
             self.parse_create_extension()
+        } else if dialect_of!(self is PostgreSqlDialect | GenericDialect)
+            && self.parse_keyword(Keyword::COLLATION)
+        {
+            self.parse_create_collation()
+        } else if dialect_of!(self is PostgreSqlDialect | GenericDialect)
+            && self.parse_keyword(Keyword::STATISTICS)
+        {
+            self.parse_create_statistics()
         } else if self.parse_keyword(Keyword::INDEX) {
             self.parse_create_index(false)
         } else if self.parse_keywords(&[Keyword::UNIQUE, Keyword::INDEX]) {
@@ -3612,13 +4860,187 @@ impl<'a> Parser<'a> {
             self.parse_create_sequence(temporary)
         } else if self.parse_keyword(Keyword::TYPE) {
             self.parse_create_type()
-        } else if self.parse_keyword(Keyword::PROCEDURE) {
-            self.parse_create_procedure(or_alter)
         } else {
             self.expected("an object type after CREATE", self.peek_token())
         }
     }
 
+    /// Parse the remainder of a `DEFINER = user` clause
+    fn parse_definer(&mut self) -> Result<AccountName, ParserError> {
+        self.expect_token(&Token::Eq)?;
+        self.parse_account_name()
+    }
+
+    /// Parse a MySQL account name: `user_name[@host_name]`
+    ///
+    /// See <https://dev.mysql.com/doc/refman/8.0/en/account-names.html>
+    pub fn parse_account_name(&mut self) -> Result<AccountName, ParserError> {
+        let user = self.parse_identifier(false)?;
+        // an unquoted `user@host` is tokenized as a single word
+        if user.quote_style.is_none() {
+            if let Some((user, host)) = user.value.split_once('@') {
+                return Ok(AccountName {
+                    user: Ident::new(user),
+                    host: Some(Ident::new(host)),
+                });
+            }
+        }
+        let host = if self.consume_token(&Token::AtSign) {
+            Some(self.parse_identifier(false)?)
+        } else {
+            match self.peek_token().token {
+                // an unquoted host is tokenized as a word starting with `@`
+                Token::Word(w) if w.quote_style.is_none() && w.value.starts_with('@') => {
+                    self.next_token();
+                    Some(Ident::new(&w.value[1..]))
+                }
+                _ => None,
+            }
+        };
+        Ok(AccountName { user, host })
+    }
+
+    /// ```sql
+    /// CREATE [DEFINER = user] EVENT [IF NOT EXISTS] event_name
+    ///     ON SCHEDULE schedule
+    ///     [ON COMPLETION [NOT] PRESERVE]
+    ///     [ENABLE | DISABLE | DISABLE ON SLAVE]
+    ///     [COMMENT 'string']
+    ///     DO event_body
+    /// ```
+    ///
+    /// See [MySQL](https://dev.mysql.com/doc/refman/8.0/en/create-event.html)
+    pub fn parse_create_event(
+        &mut self,
+        definer: Option<AccountName>,
+    ) -> Result<Statement, ParserError> {
+        let if_not_exists = self.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        let name = self.parse_object_name(false)?;
+        self.expect_keywords(&[Keyword::ON, Keyword::SCHEDULE])?;
+        let schedule = self.parse_event_schedule()?;
+        let on_completion_preserve = self.parse_event_completion()?;
+        let status = self.parse_event_status();
+        let comment = if self.parse_keyword(Keyword::COMMENT) {
+            Some(self.parse_literal_string()?)
+        } else {
+            None
+        };
+        self.expect_keyword(Keyword::DO)?;
+        let body = Box::new(self.parse_script_statement()?);
+
+        Ok(Statement::CreateEvent(CreateEvent {
+            definer,
+            if_not_exists,
+            name,
+            schedule,
+            on_completion_preserve,
+            status,
+            comment,
+            body,
+        }))
+    }
+
+    /// ```sql
+    /// ALTER [DEFINER = user] EVENT event_name
+    ///     [ON SCHEDULE schedule]
+    ///     [ON COMPLETION [NOT] PRESERVE]
+    ///     [RENAME TO new_event_name]
+    ///     [ENABLE | DISABLE | DISABLE ON SLAVE]
+    ///     [COMMENT 'string']
+    ///     [DO event_body]
+    /// ```
+    ///
+    /// See [MySQL](https://dev.mysql.com/doc/refman/8.0/en/alter-event.html)
+    pub fn parse_alter_event(
+        &mut self,
+        definer: Option<AccountName>,
+    ) -> Result<Statement, ParserError> {
+        let name = self.parse_object_name(false)?;
+        let schedule = if self.parse_keywords(&[Keyword::ON, Keyword::SCHEDULE]) {
+            Some(self.parse_event_schedule()?)
+        } else {
+            None
+        };
+        let on_completion_preserve = self.parse_event_completion()?;
+        let rename_to = if self.parse_keywords(&[Keyword::RENAME, Keyword::TO]) {
+            Some(self.parse_object_name(false)?)
+        } else {
+            None
+        };
+        let status = self.parse_event_status();
+        let comment = if self.parse_keyword(Keyword::COMMENT) {
+            Some(self.parse_literal_string()?)
+        } else {
+            None
+        };
+        let body = if self.parse_keyword(Keyword::DO) {
+            Some(Box::new(self.parse_script_statement()?))
+        } else {
+            None
+        };
+
+        Ok(Statement::AlterEvent(AlterEvent {
+            definer,
+            name,
+            schedule,
+            on_completion_preserve,
+            rename_to,
+            status,
+            comment,
+            body,
+        }))
+    }
+
+    fn parse_event_schedule(&mut self) -> Result<EventSchedule, ParserError> {
+        if self.parse_keyword(Keyword::AT) {
+            return Ok(EventSchedule::At(self.parse_expr()?));
+        }
+        self.expect_keyword(Keyword::EVERY)?;
+        let quantity = self.parse_expr()?;
+        // MySQL also accepts compound units such as `HOUR_MINUTE`
+        let unit = match self.maybe_parse(|parser| parser.parse_date_time_field()) {
+            Some(unit) => unit,
+            None => DateTimeField::Custom(self.parse_identifier(false)?),
+        };
+        let starts = if self.parse_keyword(Keyword::STARTS) {
+            Some(self.parse_expr()?)
+        } else {
+            None
+        };
+        let ends = if self.parse_keyword(Keyword::ENDS) {
+            Some(self.parse_expr()?)
+        } else {
+            None
+        };
+        Ok(EventSchedule::Every {
+            quantity,
+            unit,
+            starts,
+            ends,
+        })
+    }
+
+    fn parse_event_completion(&mut self) -> Result<Option<bool>, ParserError> {
+        if !self.parse_keywords(&[Keyword::ON, Keyword::COMPLETION]) {
+            return Ok(None);
+        }
+        let not = self.parse_keyword(Keyword::NOT);
+        self.expect_keyword(Keyword::PRESERVE)?;
+        Ok(Some(!not))
+    }
+
+    fn parse_event_status(&mut self) -> Option<EventStatus> {
+        if self.parse_keyword(Keyword::ENABLE) {
+            Some(EventStatus::Enable)
+        } else if self.parse_keywords(&[Keyword::DISABLE, Keyword::ON, Keyword::SLAVE]) {
+            Some(EventStatus::DisableOnSlave)
+        } else if self.parse_keyword(Keyword::DISABLE) {
+            Some(EventStatus::Disable)
+        } else {
+            None
+        }
+    }
+
     /// See [DuckDB Docs](https://duckdb.org/docs/sql/statements/create_secret.html) for more details.
     pub fn parse_create_secret(
         &mut self,
@@ -3804,9 +5226,18 @@ impl<'a> Parser<'a> {
 
         let schema_name = self.parse_schema_name()?;
 
+        let mut elements = vec![];
+        while matches!(
+            self.peek_token().token,
+            Token::Word(w) if matches!(w.keyword, Keyword::CREATE | Keyword::GRANT)
+        ) {
+            elements.push(self.parse_statement()?);
+        }
+
         Ok(Statement::CreateSchema {
             schema_name,
             if_not_exists,
+            elements,
         })
     }
 
@@ -3880,7 +5311,7 @@ impl<'a> Parser<'a> {
     ) -> Result<Statement, ParserError> {
         if dialect_of!(self is HiveDialect) {
             self.parse_hive_create_function(or_replace, temporary)
-        } else if dialect_of!(self is PostgreSqlDialect | GenericDialect) {
+        } else if dialect_of!(self is PostgreSqlDialect | SnowflakeDialect | GenericDialect) {
             self.parse_postgres_create_function(or_replace, temporary)
         } else if dialect_of!(self is DuckDbDialect) {
             self.parse_create_macro(or_replace, temporary)
@@ -3892,9 +5323,10 @@ impl<'a> Parser<'a> {
         }
     }
 
-    /// Parse `CREATE FUNCTION` for [Postgres]
+    /// Parse `CREATE FUNCTION` for [Postgres] and [Snowflake]
     ///
     /// [Postgres]: https://www.postgresql.org/docs/15/sql-createfunction.html
+    /// [Snowflake]: https://docs.snowflake.com/en/sql-reference/sql/create-function
     fn parse_postgres_create_function(
         &mut self,
         or_replace: bool,
@@ -3924,6 +5356,14 @@ impl<'a> Parser<'a> {
             function_body: Option<CreateFunctionBody>,
             called_on_null: Option<FunctionCalledOnNull>,
             parallel: Option<FunctionParallel>,
+            window: bool,
+            security: Option<FunctionSecurity>,
+            leakproof: Option<bool>,
+            cost: Option<Expr>,
+            rows: Option<Expr>,
+            support: Option<ObjectName>,
+            set_params: Vec<FunctionDefinitionSetParam>,
+            properties: HandlerProperties,
         }
         let mut body = Body::default();
         loop {
@@ -3995,11 +5435,64 @@ impl<'a> Parser<'a> {
             } else if self.parse_keyword(Keyword::RETURN) {
                 ensure_not_set(&body.function_body, "RETURN")?;
                 body.function_body = Some(CreateFunctionBody::Return(self.parse_expr()?));
-            } else {
+            } else if self.parse_keyword(Keyword::WINDOW) {
+                if body.window {
+                    return Err(ParserError::ParserError(
+                        "WINDOW specified more than once".to_string(),
+                    ));
+                }
+                body.window = true;
+            } else if self.parse_keyword(Keyword::SECURITY)
+                || self.parse_keywords(&[Keyword::EXTERNAL, Keyword::SECURITY])
+            {
+                ensure_not_set(&body.security, "SECURITY { INVOKER | DEFINER }")?;
+                body.security =
+                    match self.expect_one_of_keywords(&[Keyword::DEFINER, Keyword::INVOKER])? {
+                        Keyword::DEFINER => Some(FunctionSecurity::Definer),
+                        _ => Some(FunctionSecurity::Invoker),
+                    };
+            } else if self.parse_keyword(Keyword::LEAKPROOF) {
+                ensure_not_set(&body.leakproof, "[NOT] LEAKPROOF")?;
+                body.leakproof = Some(true);
+            } else if self.parse_keywords(&[Keyword::NOT, Keyword::LEAKPROOF]) {
+                ensure_not_set(&body.leakproof, "[NOT] LEAKPROOF")?;
+                body.leakproof = Some(false);
+            } else if self.parse_keyword(Keyword::COST) {
+                ensure_not_set(&body.cost, "COST")?;
+                body.cost = Some(self.parse_number()?);
+            } else if self.parse_keyword(Keyword::ROWS) {
+                ensure_not_set(&body.rows, "ROWS")?;
+                body.rows = Some(self.parse_number()?);
+            } else if self.parse_keyword(Keyword::SUPPORT) {
+                ensure_not_set(&body.support, "SUPPORT")?;
+                body.support = Some(self.parse_object_name(false)?);
+            } else if self.parse_keyword(Keyword::SET) {
+                let name = self.parse_object_name(false)?;
+                let value = if self.parse_keywords(&[Keyword::FROM, Keyword::CURRENT]) {
+                    FunctionSetValue::FromCurrent
+                } else {
+                    if !self.consume_token(&Token::Eq) {
+                        self.expect_keyword(Keyword::TO)?;
+                    }
+                    FunctionSetValue::Values(self.parse_comma_separated(Parser::parse_expr)?)
+                };
+                body.set_params
+                    .push(FunctionDefinitionSetParam { name, value });
+            } else if !self.parse_handler_property(&mut body.properties)? {
                 break;
             }
         }
 
+        if dialect_of!(self is PostgreSqlDialect | GenericDialect)
+            && Self::is_script_language(&body.language, "plpgsql")
+        {
+            if let Some(CreateFunctionBody::AsBeforeOptions(definition)) = &body.function_body {
+                if let Some(script) = self.parse_script_body(definition)? {
+                    body.function_body = Some(CreateFunctionBody::AsScript(script));
+                }
+            }
+        }
+
         Ok(Statement::CreateFunction {
             or_replace,
             temporary,
@@ -4009,6 +5502,13 @@ impl<'a> Parser<'a> {
             behavior: body.behavior,
             called_on_null: body.called_on_null,
             parallel: body.parallel,
+            window: body.window,
+            security: body.security,
+            leakproof: body.leakproof,
+            cost: body.cost,
+            rows: body.rows,
+            support: body.support,
+            set_params: body.set_params,
             language: body.language,
             function_body: body.function_body,
             if_not_exists: false,
@@ -4016,9 +5516,486 @@ impl<'a> Parser<'a> {
             determinism_specifier: None,
             options: None,
             remote_connection: None,
+            runtime_version: body.properties.runtime_version,
+            packages: body.properties.packages,
+            imports: body.properties.imports,
+            handler: body.properties.handler,
+        })
+    }
+
+    /// Parse one of the `RUNTIME_VERSION`, `PACKAGES`, `IMPORTS` or `HANDLER`
+    /// properties of a function or procedure, returning `false` if none is
+    /// present.
+    ///
+    /// [Snowflake]: https://docs.snowflake.com/en/sql-reference/sql/create-function
+    fn parse_handler_property(
+        &mut self,
+        properties: &mut HandlerProperties,
+    ) -> Result<bool, ParserError> {
+        if !dialect_of!(self is SnowflakeDialect | GenericDialect) {
+            return Ok(false);
+        }
+        let Some(keyword) = self.parse_one_of_keywords(&[
+            Keyword::RUNTIME_VERSION,
+            Keyword::PACKAGES,
+            Keyword::IMPORTS,
+            Keyword::HANDLER,
+        ]) else {
+            return Ok(false);
+        };
+        self.expect_token(&Token::Eq)?;
+        fn parse_string_list(parser: &mut Parser) -> Result<Vec<String>, ParserError> {
+            parser.expect_token(&Token::LParen)?;
+            let list = if parser.peek_token().token == Token::RParen {
+                vec![]
+            } else {
+                parser.parse_comma_separated(Parser::parse_literal_string)?
+            };
+            parser.expect_token(&Token::RParen)?;
+            Ok(list)
+        }
+        let (name, is_set) = match keyword {
+            Keyword::RUNTIME_VERSION => (
+                "RUNTIME_VERSION",
+                properties
+                    .runtime_version
+                    .replace(self.parse_literal_string()?)
+                    .is_some(),
+            ),
+            Keyword::PACKAGES => (
+                "PACKAGES",
+                properties
+                    .packages
+                    .replace(parse_string_list(self)?)
+                    .is_some(),
+            ),
+            Keyword::IMPORTS => (
+                "IMPORTS",
+                properties
+                    .imports
+                    .replace(parse_string_list(self)?)
+                    .is_some(),
+            ),
+            _ => (
+                "HANDLER",
+                properties
+                    .handler
+                    .replace(self.parse_literal_string()?)
+                    .is_some(),
+            ),
+        };
+        if is_set {
+            return Err(ParserError::ParserError(format!(
+                "{name} specified more than once"
+            )));
+        }
+        Ok(true)
+    }
+
+    /// Returns true if `language` is the given scripting language
+    fn is_script_language(language: &Option<Ident>, script_language: &str) -> bool {
+        language
+            .as_ref()
+            .is_some_and(|language| language.value.eq_ignore_ascii_case(script_language))
+    }
+
+    /// Parse the string literal `definition` of a function or procedure into
+    /// a [`ScriptBlock`], if [`ParserOptions::parse_script_bodies`] is enabled.
+    /// Locations in errors are relative to the start of the definition.
+    fn parse_script_body(&self, definition: &Expr) -> Result<Option<ScriptBlock>, ParserError> {
+        if !self.options.parse_script_bodies {
+            return Ok(None);
+        }
+        let source = match definition {
+            Expr::Value(Value::DollarQuotedString(s)) => &s.value,
+            Expr::Value(Value::SingleQuotedString(s)) => s,
+            _ => return Ok(None),
+        };
+        let mut parser = Parser::new(self.dialect)
+            .with_options(self.options.clone())
+            .try_with_sql(source)?;
+        let script = parser.parse_script_block()?;
+        let _ = parser.consume_token(&Token::SemiColon);
+        parser.expect_token(&Token::EOF)?;
+        Ok(Some(script))
+    }
+
+    /// Parse a block of a procedural script:
+    ///
+    /// ```sql
+    /// [ DECLARE declarations ] BEGIN statements [ EXCEPTION handlers ] END
+    /// ```
+    pub fn parse_script_block(&mut self) -> Result<ScriptBlock, ParserError> {
+        let mut declarations = vec![];
+        if self.parse_keyword(Keyword::DECLARE) {
+            while !matches!(
+                self.peek_token().token,
+                Token::EOF
+                    | Token::Word(Word {
+                        keyword: Keyword::BEGIN,
+                        ..
+                    })
+            ) {
+                declarations.push(self.parse_script_declaration()?);
+                self.expect_token(&Token::SemiColon)?;
+            }
+        }
+        self.expect_keyword(Keyword::BEGIN)?;
+        let statements = self.parse_script_statements()?;
+        let mut exception_handlers = vec![];
+        if self.parse_keyword(Keyword::EXCEPTION) {
+            while self.parse_keyword(Keyword::WHEN) {
+                let mut conditions = vec![self.parse_identifier(false)?];
+                while self.parse_keyword(Keyword::OR) {
+                    conditions.push(self.parse_identifier(false)?);
+                }
+                self.expect_keyword(Keyword::THEN)?;
+                exception_handlers.push(ScriptExceptionHandler {
+                    conditions,
+                    statements: self.parse_script_statements()?,
+                });
+            }
+        }
+        self.expect_keyword(Keyword::END)?;
+        Ok(ScriptBlock {
+            declarations,
+            statements,
+            exception_handlers,
+        })
+    }
+
+    fn parse_script_declaration(&mut self) -> Result<ScriptDeclaration, ParserError> {
+        let name = self.parse_identifier(false)?;
+        let constant = self.parse_keyword(Keyword::CONSTANT);
+        let data_type = match self.peek_token().token {
+            Token::SemiColon | Token::Assignment => None,
+            Token::Word(w) if w.keyword == Keyword::DEFAULT => None,
+            _ => Some(self.parse_data_type()?),
+        };
+        let not_null = self.parse_keywords(&[Keyword::NOT, Keyword::NULL]);
+        let default =
+            if self.parse_keyword(Keyword::DEFAULT) || self.consume_token(&Token::Assignment) {
+                Some(self.parse_expr()?)
+            } else {
+                None
+            };
+        Ok(ScriptDeclaration {
+            name,
+            constant,
+            data_type,
+            not_null,
+            default,
+        })
+    }
+
+    /// Parse `;` terminated script statements up to the keyword that ends
+    /// the enclosing block or branch
+    fn parse_script_statements(&mut self) -> Result<Vec<ScriptStatement>, ParserError> {
+        let mut statements = vec![];
+        loop {
+            match self.peek_token().token {
+                Token::EOF => break,
+                Token::Word(w)
+                    if matches!(
+                        w.keyword,
+                        Keyword::END
+                            | Keyword::EXCEPTION
+                            | Keyword::ELSE
+                            | Keyword::ELSIF
+                            | Keyword::ELSEIF
+                            | Keyword::WHEN
+                            | Keyword::UNTIL
+                    ) =>
+                {
+                    break
+                }
+                _ => {}
+            }
+            statements.push(self.parse_script_statement()?);
+            self.expect_token(&Token::SemiColon)?;
+        }
+        Ok(statements)
+    }
+
+    /// Parse a single statement of a procedural script
+    pub fn parse_script_statement(&mut self) -> Result<ScriptStatement, ParserError> {
+        if let Some(label) = self.parse_script_label() {
+            let statement = Box::new(self.parse_script_statement()?);
+            // The label may be repeated after the end of the block or loop
+            let end_label = match self.peek_token().token {
+                Token::Word(w) if w.value.eq_ignore_ascii_case(&label.value) => {
+                    self.next_token();
+                    Some(w.to_ident())
+                }
+                _ => None,
+            };
+            return Ok(ScriptStatement::Labeled {
+                label,
+                statement,
+                end_label,
+            });
+        }
+        let keyword = match self.peek_token().token {
+            Token::Word(w) => w.keyword,
+            _ => Keyword::NoKeyword,
+        };
+        match keyword {
+            Keyword::DECLARE if dialect_of!(self is MySqlDialect) => {
+                self.next_token();
+                self.parse_mysql_script_declare()
+            }
+            Keyword::DECLARE => Ok(ScriptStatement::Block(self.parse_script_block()?)),
+            Keyword::BEGIN
+                if !matches!(
+                    self.peek_nth_token(1).token,
+                    Token::SemiColon
+                        | Token::Word(Word {
+                            keyword: Keyword::TRANSACTION | Keyword::WORK,
+                            ..
+                        })
+                ) =>
+            {
+                Ok(ScriptStatement::Block(self.parse_script_block()?))
+            }
+            Keyword::IF => {
+                self.next_token();
+                let mut branches = vec![];
+                loop {
+                    let condition = self.parse_expr()?;
+                    self.expect_keyword(Keyword::THEN)?;
+                    branches.push(ScriptConditionalBlock {
+                        condition,
+                        statements: self.parse_script_statements()?,
+                    });
+                    if self
+                        .parse_one_of_keywords(&[Keyword::ELSIF, Keyword::ELSEIF])
+                        .is_none()
+                    {
+                        break;
+                    }
+                }
+                let else_statements = if self.parse_keyword(Keyword::ELSE) {
+                    Some(self.parse_script_statements()?)
+                } else {
+                    None
+                };
+                self.expect_keywords(&[Keyword::END, Keyword::IF])?;
+                Ok(ScriptStatement::If {
+                    branches,
+                    else_statements,
+                })
+            }
+            Keyword::LOOP => {
+                self.next_token();
+                let statements = self.parse_script_statements()?;
+                self.expect_keywords(&[Keyword::END, Keyword::LOOP])?;
+                Ok(ScriptStatement::Loop(statements))
+            }
+            Keyword::WHILE => {
+                self.next_token();
+                let condition = self.parse_expr()?;
+                self.expect_keyword(Keyword::DO)?;
+                let statements = self.parse_script_statements()?;
+                self.expect_keywords(&[Keyword::END, Keyword::WHILE])?;
+                Ok(ScriptStatement::While {
+                    condition,
+                    statements,
+                })
+            }
+            Keyword::REPEAT => {
+                self.next_token();
+                let statements = self.parse_script_statements()?;
+                self.expect_keyword(Keyword::UNTIL)?;
+                let until = self.parse_expr()?;
+                self.expect_keywords(&[Keyword::END, Keyword::REPEAT])?;
+                Ok(ScriptStatement::Repeat { statements, until })
+            }
+            Keyword::LEAVE => {
+                self.next_token();
+                Ok(ScriptStatement::Leave(self.parse_identifier(false)?))
+            }
+            Keyword::ITERATE => {
+                self.next_token();
+                Ok(ScriptStatement::Iterate(self.parse_identifier(false)?))
+            }
+            Keyword::OPEN if dialect_of!(self is MySqlDialect) => {
+                self.next_token();
+                Ok(ScriptStatement::Open(self.parse_identifier(false)?))
+            }
+            Keyword::FETCH if dialect_of!(self is MySqlDialect) => {
+                self.next_token();
+                // FETCH [[NEXT] FROM] cursor INTO variable [, ...]
+                if self.parse_keyword(Keyword::NEXT) {
+                    self.expect_keyword(Keyword::FROM)?;
+                } else {
+                    let _ = self.parse_keyword(Keyword::FROM);
+                }
+                let cursor = self.parse_identifier(false)?;
+                self.expect_keyword(Keyword::INTO)?;
+                let into = self.parse_comma_separated(|parser| parser.parse_identifier(false))?;
+                Ok(ScriptStatement::Fetch { cursor, into })
+            }
+            Keyword::EXIT => {
+                self.next_token();
+                let condition = if self.parse_keyword(Keyword::WHEN) {
+                    Some(self.parse_expr()?)
+                } else {
+                    None
+                };
+                Ok(ScriptStatement::Exit(condition))
+            }
+            Keyword::RETURN => {
+                self.next_token();
+                if self.parse_keyword(Keyword::QUERY) {
+                    Ok(ScriptStatement::ReturnQuery(self.parse_boxed_query()?))
+                } else if self.peek_token() == Token::SemiColon {
+                    Ok(ScriptStatement::Return(None))
+                } else {
+                    Ok(ScriptStatement::Return(Some(self.parse_expr()?)))
+                }
+            }
+            Keyword::RAISE => {
+                self.next_token();
+                let level = match self.peek_token().token {
+                    Token::Word(w)
+                        if matches!(
+                            w.keyword,
+                            Keyword::DEBUG
+                                | Keyword::LOG
+                                | Keyword::INFO
+                                | Keyword::NOTICE
+                                | Keyword::WARNING
+                                | Keyword::EXCEPTION
+                        ) =>
+                    {
+                        Some(self.parse_identifier(false)?)
+                    }
+                    _ => None,
+                };
+                let arguments = if self.peek_token() == Token::SemiColon {
+                    vec![]
+                } else {
+                    self.parse_comma_separated(Parser::parse_expr)?
+                };
+                Ok(ScriptStatement::Raise { level, arguments })
+            }
+            Keyword::LET => {
+                self.next_token();
+                let name = self.parse_identifier(false)?;
+                if !self.consume_token(&Token::Assignment) {
+                    self.expect_keyword(Keyword::DEFAULT)?;
+                }
+                Ok(ScriptStatement::Assignment {
+                    is_let: true,
+                    name: ObjectName(vec![name]),
+                    value: self.parse_expr()?,
+                })
+            }
+            _ => {
+                // PL/pgSQL also accepts `=` for assignments
+                let name = self.maybe_parse(|parser| {
+                    let name = parser.parse_object_name(false)?;
+                    if !parser.consume_token(&Token::Eq) {
+                        parser.expect_token(&Token::Assignment)?;
+                    }
+                    Ok(name)
+                });
+                match name {
+                    Some(name) => Ok(ScriptStatement::Assignment {
+                        is_let: false,
+                        name,
+                        value: self.parse_expr()?,
+                    }),
+                    None => Ok(ScriptStatement::Sql(self.parse_statement()?)),
+                }
+            }
+        }
+    }
+
+    /// Parse the `label:` in front of a block or loop, if any
+    fn parse_script_label(&mut self) -> Option<Ident> {
+        let Token::Word(label) = self.peek_token().token else {
+            return None;
+        };
+        let is_labeled = self.peek_nth_token(1) == Token::Colon
+            && matches!(
+                self.peek_nth_token(2).token,
+                Token::Word(Word {
+                    keyword: Keyword::BEGIN | Keyword::LOOP | Keyword::REPEAT | Keyword::WHILE,
+                    ..
+                })
+            );
+        if !is_labeled {
+            return None;
+        }
+        self.next_token();
+        self.next_token();
+        Some(label.to_ident())
+    }
+
+    /// Parse the declaration of a local variable, cursor or handler of a
+    /// [MySQL] stored program, after the `DECLARE` keyword
+    ///
+    /// [MySQL]: https://dev.mysql.com/doc/refman/8.0/en/declare.html
+    fn parse_mysql_script_declare(&mut self) -> Result<ScriptStatement, ParserError> {
+        if let Some(keyword) =
+            self.parse_one_of_keywords(&[Keyword::CONTINUE, Keyword::EXIT, Keyword::UNDO])
+        {
+            let action = match keyword {
+                Keyword::CONTINUE => ScriptHandlerAction::Continue,
+                Keyword::EXIT => ScriptHandlerAction::Exit,
+                _ => ScriptHandlerAction::Undo,
+            };
+            self.expect_keywords(&[Keyword::HANDLER, Keyword::FOR])?;
+            let conditions = self.parse_comma_separated(Parser::parse_script_handler_condition)?;
+            let statement = Box::new(self.parse_script_statement()?);
+            return Ok(ScriptStatement::DeclareHandler {
+                action,
+                conditions,
+                statement,
+            });
+        }
+        let mut names = self.parse_comma_separated(|parser| parser.parse_identifier(false))?;
+        if names.len() == 1 && self.parse_keywords(&[Keyword::CURSOR, Keyword::FOR]) {
+            return Ok(ScriptStatement::DeclareCursor {
+                name: names.remove(0),
+                query: self.parse_boxed_query()?,
+            });
+        }
+        let data_type = self.parse_data_type()?;
+        let default = if self.parse_keyword(Keyword::DEFAULT) {
+            Some(self.parse_expr()?)
+        } else {
+            None
+        };
+        Ok(ScriptStatement::DeclareVariables {
+            names,
+            data_type,
+            default,
         })
     }
 
+    fn parse_script_handler_condition(&mut self) -> Result<ScriptHandlerCondition, ParserError> {
+        if self.parse_keyword(Keyword::SQLSTATE) {
+            let _ = self.parse_keyword(Keyword::VALUE);
+            Ok(ScriptHandlerCondition::SqlState(
+                self.parse_literal_string()?,
+            ))
+        } else if self.parse_keyword(Keyword::SQLWARNING) {
+            Ok(ScriptHandlerCondition::SqlWarning)
+        } else if self.parse_keywords(&[Keyword::NOT, Keyword::FOUND]) {
+            Ok(ScriptHandlerCondition::NotFound)
+        } else if self.parse_keyword(Keyword::SQLEXCEPTION) {
+            Ok(ScriptHandlerCondition::SqlException)
+        } else if let Token::Number(..) = self.peek_token().token {
+            Ok(ScriptHandlerCondition::ErrorCode(
+                self.parse_literal_uint()?,
+            ))
+        } else {
+            Ok(ScriptHandlerCondition::Name(self.parse_identifier(false)?))
+        }
+    }
+
     /// Parse `CREATE FUNCTION` for [Hive]
     ///
     /// [Hive]: https://cwiki.apache.org/confluence/display/hive/languagemanual+ddl#LanguageManualDDL-Create/Drop/ReloadFunction
@@ -4045,10 +6022,21 @@ impl<'a> Parser<'a> {
             behavior: None,
             called_on_null: None,
             parallel: None,
+            window: false,
+            security: None,
+            leakproof: None,
+            cost: None,
+            rows: None,
+            support: None,
+            set_params: vec![],
             language: None,
             determinism_specifier: None,
             options: None,
             remote_connection: None,
+            runtime_version: None,
+            packages: None,
+            imports: None,
+            handler: None,
         })
     }
 
@@ -4138,6 +6126,17 @@ impl<'a> Parser<'a> {
             behavior: None,
             called_on_null: None,
             parallel: None,
+            window: false,
+            security: None,
+            leakproof: None,
+            cost: None,
+            rows: None,
+            support: None,
+            set_params: vec![],
+            runtime_version: None,
+            packages: None,
+            imports: None,
+            handler: None,
         })
     }
 
@@ -4247,7 +6246,7 @@ impl<'a> Parser<'a> {
 
         let condition = self
             .parse_keyword(Keyword::WHEN)
-            .then(|| self.parse_expr())
+            .then(|| self.with_state(ParserState::Trigger, |parser| parser.parse_expr()))
             .transpose()?;
 
         self.expect_keyword(Keyword::EXECUTE)?;
@@ -4349,6 +6348,34 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Parse a BigQuery `CREATE MODEL` statement, after `CREATE [OR REPLACE] MODEL`
+    pub fn parse_create_model(&mut self, or_replace: bool) -> Result<Statement, ParserError> {
+        let if_not_exists = self.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        let name = self.parse_object_name(false)?;
+        let transform = if self.parse_keyword(Keyword::TRANSFORM) {
+            self.expect_token(&Token::LParen)?;
+            let transform = self.parse_projection()?;
+            self.expect_token(&Token::RParen)?;
+            Some(transform)
+        } else {
+            None
+        };
+        let options = self.parse_options(Keyword::OPTIONS)?;
+        let query = if self.parse_keyword(Keyword::AS) {
+            Some(self.parse_boxed_query()?)
+        } else {
+            None
+        };
+        Ok(Statement::CreateModel {
+            or_replace,
+            if_not_exists,
+            name,
+            transform,
+            options,
+            query,
+        })
+    }
+
     pub fn parse_create_macro(
         &mut self,
         or_replace: bool,
@@ -4469,13 +6496,22 @@ impl<'a> Parser<'a> {
     ) -> Result<Statement, ParserError> {
         let materialized = self.parse_keyword(Keyword::MATERIALIZED);
         self.expect_keyword(Keyword::VIEW)?;
-        let if_not_exists = dialect_of!(self is BigQueryDialect|SQLiteDialect|GenericDialect)
+        let if_not_exists = (dialect_of!(self is BigQueryDialect|SQLiteDialect|ClickHouseDialect|GenericDialect)
+            || (materialized && dialect_of!(self is PostgreSqlDialect)))
             && self.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
         // Many dialects support `OR ALTER` right after `CREATE`, but we don't (yet).
         // ANSI SQL and Postgres support RECURSIVE here, but we don't support it either.
         let allow_unquoted_hyphen = dialect_of!(self is BigQueryDialect);
         let name = self.parse_object_name(allow_unquoted_hyphen)?;
         let columns = self.parse_view_columns()?;
+        let using = if materialized
+            && dialect_of!(self is PostgreSqlDialect | GenericDialect)
+            && self.parse_keyword(Keyword::USING)
+        {
+            Some(self.parse_identifier(false)?)
+        } else {
+            None
+        };
         let mut options = CreateTableOptions::None;
         let with_options = self.parse_options(Keyword::WITH)?;
         if !with_options.is_empty() {
@@ -4505,6 +6541,16 @@ impl<'a> Parser<'a> {
             None
         };
 
+        let engine = if dialect_of!(self is ClickHouseDialect | GenericDialect)
+            && self.parse_keyword(Keyword::ENGINE)
+        {
+            Some(self.parse_view_engine()?)
+        } else {
+            None
+        };
+        let populate = dialect_of!(self is ClickHouseDialect | GenericDialect)
+            && self.parse_keyword(Keyword::POPULATE);
+
         let comment = if dialect_of!(self is SnowflakeDialect | GenericDialect)
             && self.parse_keyword(Keyword::COMMENT)
         {
@@ -4520,6 +6566,11 @@ impl<'a> Parser<'a> {
 
         self.expect_keyword(Keyword::AS)?;
         let query = self.parse_boxed_query()?;
+        let with_data = if materialized && dialect_of!(self is PostgreSqlDialect | GenericDialect) {
+            self.parse_with_data()
+        } else {
+            None
+        };
         // Optional `WITH [ CASCADED | LOCAL ] CHECK OPTION` is widely supported here.
 
         let with_no_schema_binding = dialect_of!(self is RedshiftSqlDialect | GenericDialect)
@@ -4529,6 +6580,7 @@ impl<'a> Parser<'a> {
                 Keyword::SCHEMA,
                 Keyword::BINDING,
             ]);
+        let unknown_options = self.parse_unknown_options()?;
 
         Ok(Statement::CreateView {
             name,
@@ -4543,6 +6595,76 @@ impl<'a> Parser<'a> {
             if_not_exists,
             temporary,
             to,
+            engine,
+            populate,
+            using,
+            with_data,
+            unknown_options,
+        })
+    }
+
+    /// Parse the `= engine_name[(parameters)]` that follows `ENGINE`
+    fn parse_table_engine(&mut self) -> Result<TableEngine, ParserError> {
+        self.expect_token(&Token::Eq)?;
+        let next_token = self.next_token();
+        match next_token.token {
+            Token::Word(w) => {
+                let name = w.value;
+                let parameters = if self.peek_token() == Token::LParen {
+                    Some(self.parse_parenthesized_identifiers()?)
+                } else {
+                    None
+                };
+                Ok(TableEngine { name, parameters })
+            }
+            _ => self.expected("identifier", next_token),
+        }
+    }
+
+    /// Parse the expression, or parenthesized list of expressions, that
+    /// follows the `ORDER BY` of a ClickHouse table engine
+    fn parse_table_order_by(&mut self) -> Result<OneOrManyWithParens<Expr>, ParserError> {
+        if self.consume_token(&Token::LParen) {
+            let columns = if self.peek_token() != Token::RParen {
+                self.parse_comma_separated(|p| p.parse_expr())?
+            } else {
+                vec![]
+            };
+            self.expect_token(&Token::RParen)?;
+            Ok(OneOrManyWithParens::Many(columns))
+        } else {
+            Ok(OneOrManyWithParens::One(self.parse_expr()?))
+        }
+    }
+
+    /// Parse the ClickHouse `ENGINE = ...` clause of a materialized view,
+    /// after the `ENGINE` keyword. The `PRIMARY KEY`, `ORDER BY` and
+    /// `PARTITION BY` clauses may appear in any order.
+    fn parse_view_engine(&mut self) -> Result<ViewEngine, ParserError> {
+        let engine = self.parse_table_engine()?;
+        let mut primary_key = None;
+        let mut order_by = None;
+        let mut partition_by = None;
+        loop {
+            if primary_key.is_none() && self.parse_keywords(&[Keyword::PRIMARY, Keyword::KEY]) {
+                primary_key = Some(Box::new(self.parse_expr()?));
+            } else if order_by.is_none() && self.parse_keywords(&[Keyword::ORDER, Keyword::BY]) {
+                order_by = Some(self.parse_table_order_by()?);
+            } else if partition_by.is_none()
+                && self.parse_keywords(&[Keyword::PARTITION, Keyword::BY])
+            {
+                partition_by = Some(Box::new(self.parse_expr()?));
+            } else {
+                break;
+            }
+        }
+        let settings = self.parse_settings()?;
+        Ok(ViewEngine {
+            engine,
+            primary_key,
+            order_by,
+            partition_by,
+            settings,
         })
     }
 
@@ -4870,6 +6992,9 @@ impl<'a> Parser<'a> {
     }
 
     pub fn parse_drop(&mut self) -> Result<Statement, ParserError> {
+        if let Some(statement) = self.parse_generic_ddl(vec![Ident::new("DROP")])? {
+            return Ok(statement);
+        }
         // MySQL dialect supports `TEMPORARY`
         let temporary = dialect_of!(self is MySqlDialect | GenericDialect | DuckDbDialect)
             && self.parse_keyword(Keyword::TEMPORARY);
@@ -4894,6 +7019,14 @@ impl<'a> Parser<'a> {
             ObjectType::Stage
         } else if self.parse_keyword(Keyword::TYPE) {
             ObjectType::Type
+        } else if self.parse_keyword(Keyword::EVENT) {
+            ObjectType::Event
+        } else if self.parse_keyword(Keyword::COLLATION) {
+            ObjectType::Collation
+        } else if self.parse_keyword(Keyword::TASK) {
+            ObjectType::Task
+        } else if self.parse_keyword(Keyword::STATISTICS) {
+            ObjectType::Statistics
         } else if self.parse_keyword(Keyword::FUNCTION) {
             return self.parse_drop_function();
         } else if self.parse_keyword(Keyword::POLICY) {
@@ -4906,7 +7039,7 @@ impl<'a> Parser<'a> {
             return self.parse_drop_trigger();
         } else {
             return self.expected(
-                "TABLE, VIEW, INDEX, ROLE, SCHEMA, DATABASE, FUNCTION, PROCEDURE, STAGE, TRIGGER, SECRET, SEQUENCE, or TYPE after DROP",
+                "TABLE, VIEW, INDEX, ROLE, SCHEMA, DATABASE, FUNCTION, PROCEDURE, STAGE, TRIGGER, SECRET, SEQUENCE, TYPE, EVENT, COLLATION, TASK, or STATISTICS after DROP",
                 self.peek_token(),
             );
         };
@@ -5461,6 +7594,143 @@ impl<'a> Parser<'a> {
         Ok(Statement::Discard { object_type })
     }
 
+    /// Parse a `DO` statement, after the `DO` keyword.
+    /// The `LANGUAGE` clause may appear before or after the code.
+    pub fn parse_do(&mut self) -> Result<Statement, ParserError> {
+        let mut language = if self.parse_keyword(Keyword::LANGUAGE) {
+            Some(self.parse_identifier(false)?)
+        } else {
+            None
+        };
+        let definition = self.parse_create_function_body_string()?;
+        if language.is_none() && self.parse_keyword(Keyword::LANGUAGE) {
+            language = Some(self.parse_identifier(false)?);
+        }
+        // The language defaults to PL/pgSQL
+        let script = if language.is_none() || Self::is_script_language(&language, "plpgsql") {
+            self.parse_script_body(&definition)?
+        } else {
+            None
+        };
+        let body = match script {
+            Some(script) => DoBody::Script(script),
+            None => DoBody::Definition(definition),
+        };
+        Ok(Statement::Do { language, body })
+    }
+
+    /// Parses a MySQL `DO expr [, expr] ...` statement, after the `DO`
+    pub fn parse_do_expressions(&mut self) -> Result<Statement, ParserError> {
+        let exprs = self.parse_comma_separated(Parser::parse_expr)?;
+        Ok(Statement::Do {
+            language: None,
+            body: DoBody::Expressions(exprs),
+        })
+    }
+
+    /// Parses a MySQL `HANDLER` statement, after the `HANDLER`
+    pub fn parse_handler(&mut self) -> Result<Statement, ParserError> {
+        let table_name = self.parse_object_name(false)?;
+        let operation =
+            match self.expect_one_of_keywords(&[Keyword::OPEN, Keyword::READ, Keyword::CLOSE])? {
+                Keyword::OPEN => {
+                    let alias = if self.parse_keyword(Keyword::AS) {
+                        Some(self.parse_identifier(false)?)
+                    } else {
+                        self.maybe_parse(|parser| parser.parse_identifier(false))
+                    };
+                    HandlerOperation::Open { alias }
+                }
+                Keyword::READ => self.parse_handler_read()?,
+                _ => HandlerOperation::Close,
+            };
+        Ok(Statement::Handler {
+            table_name,
+            operation,
+        })
+    }
+
+    fn parse_handler_read(&mut self) -> Result<HandlerOperation, ParserError> {
+        let positions = [Keyword::FIRST, Keyword::NEXT, Keyword::PREV, Keyword::LAST];
+        // `READ FIRST` and `READ NEXT` read in the natural order of the table
+        let index_name = match self.peek_token().token {
+            Token::Word(w) if positions.contains(&w.keyword) => None,
+            _ => Some(self.parse_identifier(false)?),
+        };
+        let position = match self.parse_one_of_keywords(&positions) {
+            Some(Keyword::FIRST) => HandlerReadPosition::First,
+            Some(Keyword::NEXT) => HandlerReadPosition::Next,
+            Some(Keyword::PREV) => HandlerReadPosition::Prev,
+            Some(Keyword::LAST) => HandlerReadPosition::Last,
+            _ if index_name.is_some() => {
+                let op = match self.next_token().token {
+                    Token::Eq => BinaryOperator::Eq,
+                    Token::LtEq => BinaryOperator::LtEq,
+                    Token::GtEq => BinaryOperator::GtEq,
+                    Token::Lt => BinaryOperator::Lt,
+                    Token::Gt => BinaryOperator::Gt,
+                    _ => {
+                        self.prev_token();
+                        return self.expected(
+                            "FIRST, NEXT, PREV, LAST or a comparison operator",
+                            self.peek_token(),
+                        );
+                    }
+                };
+                self.expect_token(&Token::LParen)?;
+                let values = self.parse_comma_separated(Parser::parse_expr)?;
+                self.expect_token(&Token::RParen)?;
+                HandlerReadPosition::Compare { op, values }
+            }
+            _ => return self.expected("FIRST, NEXT, PREV or LAST", self.peek_token()),
+        };
+        let selection = if self.parse_keyword(Keyword::WHERE) {
+            Some(self.parse_expr()?)
+        } else {
+            None
+        };
+        let (offset, limit) = if self.parse_keyword(Keyword::LIMIT) {
+            let first = self.parse_expr()?;
+            if self.consume_token(&Token::Comma) {
+                (Some(first), Some(self.parse_expr()?))
+            } else {
+                (None, Some(first))
+            }
+        } else {
+            (None, None)
+        };
+        Ok(HandlerOperation::Read {
+            index_name,
+            position,
+            selection,
+            offset,
+            limit,
+        })
+    }
+
+    /// Parses the `WITH [ NO ] DATA` clause of Postgres materialized views
+    fn parse_with_data(&mut self) -> Option<bool> {
+        if self.parse_keywords(&[Keyword::WITH, Keyword::DATA]) {
+            Some(true)
+        } else if self.parse_keywords(&[Keyword::WITH, Keyword::NO, Keyword::DATA]) {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    pub fn parse_refresh_materialized_view(&mut self) -> Result<Statement, ParserError> {
+        self.expect_keywords(&[Keyword::MATERIALIZED, Keyword::VIEW])?;
+        let concurrently = self.parse_keyword(Keyword::CONCURRENTLY);
+        let name = self.parse_object_name(false)?;
+        let with_data = self.parse_with_data();
+        Ok(Statement::RefreshMaterializedView {
+            concurrently,
+            name,
+            with_data,
+        })
+    }
+
     pub fn parse_create_index(&mut self, unique: bool) -> Result<Statement, ParserError> {
         let concurrently = self.parse_keyword(Keyword::CONCURRENTLY);
         let if_not_exists = self.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
@@ -5530,39 +7800,284 @@ impl<'a> Parser<'a> {
         }))
     }
 
-    pub fn parse_create_extension(&mut self) -> Result<Statement, ParserError> {
-        let if_not_exists = self.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+    pub fn parse_create_extension(&mut self) -> Result<Statement, ParserError> {
+        let if_not_exists = self.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        let name = self.parse_identifier(false)?;
+
+        let (schema, version, cascade) = if self.parse_keyword(Keyword::WITH) {
+            let schema = if self.parse_keyword(Keyword::SCHEMA) {
+                Some(self.parse_identifier(false)?)
+            } else {
+                None
+            };
+
+            let version = if self.parse_keyword(Keyword::VERSION) {
+                Some(self.parse_identifier(false)?)
+            } else {
+                None
+            };
+
+            let cascade = self.parse_keyword(Keyword::CASCADE);
+
+            (schema, version, cascade)
+        } else {
+            (None, None, false)
+        };
+
+        Ok(Statement::CreateExtension {
+            name,
+            if_not_exists,
+            schema,
+            version,
+            cascade,
+        })
+    }
+
+    /// Parse a PostgreSQL `CREATE LANGUAGE` statement, after `CREATE [OR REPLACE]`
+    pub fn parse_create_language(&mut self, or_replace: bool) -> Result<Statement, ParserError> {
+        let trusted = self.parse_keyword(Keyword::TRUSTED);
+        let procedural = self.parse_keyword(Keyword::PROCEDURAL);
+        self.expect_keyword(Keyword::LANGUAGE)?;
+        let name = self.parse_identifier(false)?;
+        let (handler, inline_handler, validator) = if self.parse_keyword(Keyword::HANDLER) {
+            let handler = self.parse_object_name(false)?;
+            let inline_handler = if self.parse_keyword(Keyword::INLINE) {
+                Some(self.parse_object_name(false)?)
+            } else {
+                None
+            };
+            let validator = if self.parse_keyword(Keyword::VALIDATOR) {
+                Some(self.parse_object_name(false)?)
+            } else {
+                None
+            };
+            (Some(handler), inline_handler, validator)
+        } else {
+            (None, None, None)
+        };
+
+        Ok(Statement::CreateLanguage {
+            or_replace,
+            trusted,
+            procedural,
+            name,
+            handler,
+            inline_handler,
+            validator,
+        })
+    }
+
+    /// Parse a PostgreSQL `CREATE RULE` statement, after `CREATE [OR REPLACE] RULE`
+    pub fn parse_create_rule(&mut self, or_replace: bool) -> Result<Statement, ParserError> {
+        let name = self.parse_identifier(false)?;
+        self.expect_keywords(&[Keyword::AS, Keyword::ON])?;
+        let event = match self.expect_one_of_keywords(&[
+            Keyword::SELECT,
+            Keyword::INSERT,
+            Keyword::UPDATE,
+            Keyword::DELETE,
+        ])? {
+            Keyword::SELECT => RuleEvent::Select,
+            Keyword::INSERT => RuleEvent::Insert,
+            Keyword::UPDATE => RuleEvent::Update,
+            Keyword::DELETE => RuleEvent::Delete,
+            _ => unreachable!(),
+        };
+        self.expect_keyword(Keyword::TO)?;
+        let table_name = self.parse_object_name(false)?;
+        let condition = if self.parse_keyword(Keyword::WHERE) {
+            Some(self.parse_expr()?)
+        } else {
+            None
+        };
+        self.expect_keyword(Keyword::DO)?;
+        let kind = match self.parse_one_of_keywords(&[Keyword::ALSO, Keyword::INSTEAD]) {
+            Some(Keyword::ALSO) => Some(RuleKind::Also),
+            Some(Keyword::INSTEAD) => Some(RuleKind::Instead),
+            _ => None,
+        };
+        let commands = if self.parse_keyword(Keyword::NOTHING) {
+            vec![]
+        } else if self.consume_token(&Token::LParen) {
+            // the commands are separated by semicolons, which may also be
+            // repeated or trail the last command
+            let mut commands = vec![];
+            loop {
+                while self.consume_token(&Token::SemiColon) {}
+                if self.consume_token(&Token::RParen) {
+                    break;
+                }
+                commands.push(self.parse_statement()?);
+                if !self.consume_token(&Token::SemiColon) {
+                    self.expect_token(&Token::RParen)?;
+                    break;
+                }
+            }
+            commands
+        } else {
+            vec![self.parse_statement()?]
+        };
+
+        Ok(Statement::CreateRule {
+            or_replace,
+            name,
+            event,
+            table_name,
+            condition,
+            kind,
+            commands,
+        })
+    }
+
+    /// Parse a MySQL `RENAME TABLE` statement, after `RENAME`
+    pub fn parse_rename_table(&mut self) -> Result<Statement, ParserError> {
+        self.expect_keyword(Keyword::TABLE)?;
+        let renames = self.parse_comma_separated(|parser| {
+            let old_name = parser.parse_object_name(false)?;
+            parser.expect_keyword(Keyword::TO)?;
+            let new_name = parser.parse_object_name(false)?;
+            Ok(RenameTable { old_name, new_name })
+        })?;
+        Ok(Statement::RenameTable(renames))
+    }
+
+    /// Parse a PostgreSQL `NOTIFY` statement, after `NOTIFY`
+    pub fn parse_notify(&mut self) -> Result<Statement, ParserError> {
+        let channel = self.parse_identifier(false)?;
+        let payload = if self.consume_token(&Token::Comma) {
+            Some(self.parse_literal_string()?)
+        } else {
+            None
+        };
+        Ok(Statement::Notify { channel, payload })
+    }
+
+    /// Parse a PostgreSQL `ALTER EXTENSION` statement, after `ALTER EXTENSION`
+    pub fn parse_alter_extension(&mut self) -> Result<Statement, ParserError> {
         let name = self.parse_identifier(false)?;
-
-        let (schema, version, cascade) = if self.parse_keyword(Keyword::WITH) {
-            let schema = if self.parse_keyword(Keyword::SCHEMA) {
-                Some(self.parse_identifier(false)?)
-            } else {
-                None
-            };
-
-            let version = if self.parse_keyword(Keyword::VERSION) {
+        let operation = if self.parse_keyword(Keyword::UPDATE) {
+            let version = if self.parse_keyword(Keyword::TO) {
                 Some(self.parse_identifier(false)?)
             } else {
                 None
             };
+            AlterExtensionOperation::Update { version }
+        } else if self.parse_keywords(&[Keyword::SET, Keyword::SCHEMA]) {
+            AlterExtensionOperation::SetSchema {
+                schema: self.parse_identifier(false)?,
+            }
+        } else {
+            return self.expected(
+                "UPDATE or SET SCHEMA after ALTER EXTENSION",
+                self.peek_token(),
+            );
+        };
+        Ok(Statement::AlterExtension { name, operation })
+    }
 
-            let cascade = self.parse_keyword(Keyword::CASCADE);
+    /// Parse a PostgreSQL `CREATE COLLATION` statement, after `CREATE COLLATION`
+    pub fn parse_create_collation(&mut self) -> Result<Statement, ParserError> {
+        let if_not_exists = self.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        let name = self.parse_object_name(false)?;
+        let definition = if self.parse_keyword(Keyword::FROM) {
+            CollationDefinition::From(self.parse_object_name(false)?)
+        } else {
+            self.expect_token(&Token::LParen)?;
+            let options = self.parse_comma_separated(Parser::parse_sql_option)?;
+            self.expect_token(&Token::RParen)?;
+            CollationDefinition::Options(options)
+        };
+        Ok(Statement::CreateCollation {
+            if_not_exists,
+            name,
+            definition,
+        })
+    }
 
-            (schema, version, cascade)
+    /// Parse a PostgreSQL `ALTER COLLATION` statement, after `ALTER COLLATION`
+    pub fn parse_alter_collation(&mut self) -> Result<Statement, ParserError> {
+        let name = self.parse_object_name(false)?;
+        let operation = if self.parse_keywords(&[Keyword::REFRESH, Keyword::VERSION]) {
+            AlterCollationOperation::RefreshVersion
+        } else if self.parse_keywords(&[Keyword::RENAME, Keyword::TO]) {
+            AlterCollationOperation::RenameTo {
+                new_name: self.parse_identifier(false)?,
+            }
+        } else if self.parse_keywords(&[Keyword::OWNER, Keyword::TO]) {
+            AlterCollationOperation::OwnerTo(self.parse_owner()?)
+        } else if self.parse_keywords(&[Keyword::SET, Keyword::SCHEMA]) {
+            AlterCollationOperation::SetSchema {
+                schema: self.parse_identifier(false)?,
+            }
         } else {
-            (None, None, false)
+            return self.expected(
+                "REFRESH VERSION, RENAME TO, OWNER TO or SET SCHEMA after ALTER COLLATION",
+                self.peek_token(),
+            );
         };
+        Ok(Statement::AlterCollation { name, operation })
+    }
 
-        Ok(Statement::CreateExtension {
-            name,
+    /// Parse a PostgreSQL `CREATE STATISTICS` statement, after `CREATE STATISTICS`
+    pub fn parse_create_statistics(&mut self) -> Result<Statement, ParserError> {
+        let if_not_exists = self.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        let name = match self.peek_token().token {
+            Token::LParen => None,
+            Token::Word(w) if w.keyword == Keyword::ON && w.quote_style.is_none() => None,
+            _ => Some(self.parse_object_name(false)?),
+        };
+        if if_not_exists && name.is_none() {
+            return self.expected("statistics name after IF NOT EXISTS", self.peek_token());
+        }
+        let kinds = if self.consume_token(&Token::LParen) {
+            let kinds = self.parse_comma_separated(|p| p.parse_identifier(false))?;
+            self.expect_token(&Token::RParen)?;
+            kinds
+        } else {
+            vec![]
+        };
+        self.expect_keyword(Keyword::ON)?;
+        let on = self.parse_comma_separated(Parser::parse_expr)?;
+        self.expect_keyword(Keyword::FROM)?;
+        let table_name = self.parse_object_name(false)?;
+        Ok(Statement::CreateStatistics {
             if_not_exists,
-            schema,
-            version,
-            cascade,
+            name,
+            kinds,
+            on,
+            table_name,
         })
     }
 
+    /// Parse a PostgreSQL `ALTER STATISTICS` statement, after `ALTER STATISTICS`
+    pub fn parse_alter_statistics(&mut self) -> Result<Statement, ParserError> {
+        let name = self.parse_object_name(false)?;
+        let operation = if self.parse_keywords(&[Keyword::OWNER, Keyword::TO]) {
+            AlterStatisticsOperation::OwnerTo(self.parse_owner()?)
+        } else if self.parse_keywords(&[Keyword::RENAME, Keyword::TO]) {
+            AlterStatisticsOperation::RenameTo {
+                new_name: self.parse_identifier(false)?,
+            }
+        } else if self.parse_keywords(&[Keyword::SET, Keyword::SCHEMA]) {
+            AlterStatisticsOperation::SetSchema {
+                schema: self.parse_identifier(false)?,
+            }
+        } else if self.parse_keywords(&[Keyword::SET, Keyword::STATISTICS]) {
+            let target = if self.parse_keyword(Keyword::DEFAULT) {
+                None
+            } else {
+                Some(self.parse_expr()?)
+            };
+            AlterStatisticsOperation::SetStatistics { target }
+        } else {
+            return self.expected(
+                "OWNER TO, RENAME TO, SET SCHEMA or SET STATISTICS after ALTER STATISTICS",
+                self.peek_token(),
+            );
+        };
+        Ok(Statement::AlterStatistics { name, operation })
+    }
+
     //TODO: Implement parsing for Skewed
     pub fn parse_hive_distribution(&mut self) -> Result<HiveDistributionStyle, ParserError> {
         if self.parse_keywords(&[Keyword::PARTITIONED, Keyword::BY]) {
@@ -5641,70 +8156,60 @@ impl<'a> Parser<'a> {
                         Keyword::LINES,
                         Keyword::NULL,
                     ]) {
-                        Some(Keyword::FIELDS) => {
-                            if self.parse_keywords(&[Keyword::TERMINATED, Keyword::BY]) {
+                        Some(Keyword::FIELDS)
+                            if self.parse_keywords(&[Keyword::TERMINATED, Keyword::BY]) =>
+                        {
+                            row_delimiters.push(HiveRowDelimiter {
+                                delimiter: HiveDelimiter::FieldsTerminatedBy,
+                                char: self.parse_identifier(false)?,
+                            });
+
+                            if self.parse_keywords(&[Keyword::ESCAPED, Keyword::BY]) {
                                 row_delimiters.push(HiveRowDelimiter {
-                                    delimiter: HiveDelimiter::FieldsTerminatedBy,
+                                    delimiter: HiveDelimiter::FieldsEscapedBy,
                                     char: self.parse_identifier(false)?,
                                 });
-
-                                if self.parse_keywords(&[Keyword::ESCAPED, Keyword::BY]) {
-                                    row_delimiters.push(HiveRowDelimiter {
-                                        delimiter: HiveDelimiter::FieldsEscapedBy,
-                                        char: self.parse_identifier(false)?,
-                                    });
-                                }
-                            } else {
-                                break;
                             }
                         }
-                        Some(Keyword::COLLECTION) => {
+                        Some(Keyword::COLLECTION)
                             if self.parse_keywords(&[
                                 Keyword::ITEMS,
                                 Keyword::TERMINATED,
                                 Keyword::BY,
-                            ]) {
-                                row_delimiters.push(HiveRowDelimiter {
-                                    delimiter: HiveDelimiter::CollectionItemsTerminatedBy,
-                                    char: self.parse_identifier(false)?,
-                                });
-                            } else {
-                                break;
-                            }
+                            ]) =>
+                        {
+                            row_delimiters.push(HiveRowDelimiter {
+                                delimiter: HiveDelimiter::CollectionItemsTerminatedBy,
+                                char: self.parse_identifier(false)?,
+                            });
                         }
-                        Some(Keyword::MAP) => {
+                        Some(Keyword::MAP)
                             if self.parse_keywords(&[
                                 Keyword::KEYS,
                                 Keyword::TERMINATED,
                                 Keyword::BY,
-                            ]) {
-                                row_delimiters.push(HiveRowDelimiter {
-                                    delimiter: HiveDelimiter::MapKeysTerminatedBy,
-                                    char: self.parse_identifier(false)?,
-                                });
-                            } else {
-                                break;
-                            }
+                            ]) =>
+                        {
+                            row_delimiters.push(HiveRowDelimiter {
+                                delimiter: HiveDelimiter::MapKeysTerminatedBy,
+                                char: self.parse_identifier(false)?,
+                            });
                         }
-                        Some(Keyword::LINES) => {
-                            if self.parse_keywords(&[Keyword::TERMINATED, Keyword::BY]) {
-                                row_delimiters.push(HiveRowDelimiter {
-                                    delimiter: HiveDelimiter::LinesTerminatedBy,
-                                    char: self.parse_identifier(false)?,
-                                });
-                            } else {
-                                break;
-                            }
+                        Some(Keyword::LINES)
+                            if self.parse_keywords(&[Keyword::TERMINATED, Keyword::BY]) =>
+                        {
+                            row_delimiters.push(HiveRowDelimiter {
+                                delimiter: HiveDelimiter::LinesTerminatedBy,
+                                char: self.parse_identifier(false)?,
+                            });
                         }
-                        Some(Keyword::NULL) => {
-                            if self.parse_keywords(&[Keyword::DEFINED, Keyword::AS]) {
-                                row_delimiters.push(HiveRowDelimiter {
-                                    delimiter: HiveDelimiter::NullDefinedAs,
-                                    char: self.parse_identifier(false)?,
-                                });
-                            } else {
-                                break;
-                            }
+                        Some(Keyword::NULL)
+                            if self.parse_keywords(&[Keyword::DEFINED, Keyword::AS]) =>
+                        {
+                            row_delimiters.push(HiveRowDelimiter {
+                                delimiter: HiveDelimiter::NullDefinedAs,
+                                char: self.parse_identifier(false)?,
+                            });
                         }
                         _ => {
                             break;
@@ -5741,6 +8246,14 @@ impl<'a> Parser<'a> {
         // Clickhouse has `ON CLUSTER 'cluster'` syntax for DDLs
         let on_cluster = self.parse_optional_on_cluster()?;
 
+        let partition_of = if dialect_of!(self is PostgreSqlDialect | GenericDialect)
+            && self.parse_keywords(&[Keyword::PARTITION, Keyword::OF])
+        {
+            Some(self.parse_object_name(false)?)
+        } else {
+            None
+        };
+
         let like = if self.parse_keyword(Keyword::LIKE) || self.parse_keyword(Keyword::ILIKE) {
             self.parse_object_name(allow_unquoted_hyphen).ok()
         } else {
@@ -5755,6 +8268,12 @@ impl<'a> Parser<'a> {
 
         // parse optional column list (schema)
         let (columns, constraints) = self.parse_columns()?;
+        let for_values = if partition_of.is_some() {
+            Some(self.parse_partition_for_values()?)
+        } else {
+            None
+        };
+        let partition_spec = self.parse_optional_partition_spec()?;
         let mut comment = if dialect_of!(self is HiveDialect)
             && self.parse_keyword(Keyword::COMMENT)
         {
@@ -5778,20 +8297,7 @@ impl<'a> Parser<'a> {
         let table_properties = self.parse_options(Keyword::TBLPROPERTIES)?;
 
         let engine = if self.parse_keyword(Keyword::ENGINE) {
-            self.expect_token(&Token::Eq)?;
-            let next_token = self.next_token();
-            match next_token.token {
-                Token::Word(w) => {
-                    let name = w.value;
-                    let parameters = if self.peek_token() == Token::LParen {
-                        Some(self.parse_parenthesized_identifiers()?)
-                    } else {
-                        None
-                    };
-                    Some(TableEngine { name, parameters })
-                }
-                _ => self.expected("identifier", next_token)?,
-            }
+            Some(self.parse_table_engine()?)
         } else {
             None
         };
@@ -5818,17 +8324,7 @@ impl<'a> Parser<'a> {
         };
 
         let order_by = if self.parse_keywords(&[Keyword::ORDER, Keyword::BY]) {
-            if self.consume_token(&Token::LParen) {
-                let columns = if self.peek_token() != Token::RParen {
-                    self.parse_comma_separated(|p| p.parse_expr())?
-                } else {
-                    vec![]
-                };
-                self.expect_token(&Token::RParen)?;
-                Some(OneOrManyWithParens::Many(columns))
-            } else {
-                Some(OneOrManyWithParens::One(self.parse_expr()?))
-            }
+            Some(self.parse_table_order_by()?)
         } else {
             None
         };
@@ -5914,19 +8410,145 @@ impl<'a> Parser<'a> {
             .collation(collation)
             .on_commit(on_commit)
             .on_cluster(on_cluster)
+            .partition_of(partition_of)
+            .for_values(for_values)
+            .partition_spec(partition_spec)
             .clustered_by(clustered_by)
             .partition_by(create_table_config.partition_by)
             .cluster_by(create_table_config.cluster_by)
             .options(create_table_config.options)
             .primary_key(primary_key)
             .strict(strict)
+            .unknown_options(self.parse_unknown_options()?)
             .build())
     }
 
+    /// Parses the trailing options of a DDL statement that are not supported
+    /// by the parser, if [`ParserOptions::permissive_ddl`] is set.
+    ///
+    /// Every option starts with a word. Its value are the tokens up to the
+    /// next word that follows a complete value, e.g. `A = 1 B (x, y) C`
+    /// are the three options `A`, `B` and `C`.
+    pub fn parse_unknown_options(&mut self) -> Result<Vec<DialectSpecificOption>, ParserError> {
+        let mut options = vec![];
+        if !self.options.permissive_ddl {
+            return Ok(options);
+        }
+        loop {
+            let next_token = self.peek_token();
+            match next_token.token {
+                Token::EOF | Token::SemiColon => return Ok(options),
+                Token::Word(_) => {}
+                _ => return self.expected("an option", next_token),
+            }
+            let keyword = self.parse_identifier(false)?;
+            let mut value = vec![];
+            let mut depth = 0;
+            let mut complete = false;
+            loop {
+                let next_token = self.peek_token();
+                match next_token.token {
+                    Token::EOF | Token::SemiColon if depth == 0 => break,
+                    Token::EOF | Token::SemiColon => return self.expected(")", next_token),
+                    Token::Word(_) if depth == 0 && complete => break,
+                    Token::LParen => depth += 1,
+                    Token::RParen if depth == 0 => return self.expected("an option", next_token),
+                    Token::RParen => depth -= 1,
+                    _ => {}
+                }
+                complete = depth == 0 && !matches!(next_token.token, Token::Eq | Token::Period);
+                value.push(self.next_token().token);
+            }
+            options.push(DialectSpecificOption { keyword, value });
+        }
+    }
+
     /// Parse configuration like partitioning, clustering information during the table creation.
     ///
     /// [BigQuery](https://cloud.google.com/bigquery/docs/reference/standard-sql/data-definition-language#syntax_2)
     /// [PostgreSQL](https://www.postgresql.org/docs/current/ddl-partitioning.html)
+    /// Parses the bound of a PostgreSQL partition, after `CREATE TABLE ... PARTITION OF parent`
+    fn parse_partition_for_values(&mut self) -> Result<ForValues, ParserError> {
+        if self.parse_keyword(Keyword::DEFAULT) {
+            return Ok(ForValues::Default);
+        }
+        if !self.parse_keywords(&[Keyword::FOR, Keyword::VALUES]) {
+            return self.expected(
+                "FOR VALUES or DEFAULT after PARTITION OF",
+                self.peek_token(),
+            );
+        }
+        if self.parse_keyword(Keyword::IN) {
+            self.expect_token(&Token::LParen)?;
+            let values = self.parse_comma_separated(Parser::parse_expr)?;
+            self.expect_token(&Token::RParen)?;
+            Ok(ForValues::In(values))
+        } else if self.parse_keyword(Keyword::FROM) {
+            let from = self.parse_partition_bound_values()?;
+            self.expect_keyword(Keyword::TO)?;
+            let to = self.parse_partition_bound_values()?;
+            Ok(ForValues::From { from, to })
+        } else if self.parse_keyword(Keyword::WITH) {
+            self.expect_token(&Token::LParen)?;
+            self.expect_keyword(Keyword::MODULUS)?;
+            let modulus = self.parse_literal_uint()?;
+            self.expect_token(&Token::Comma)?;
+            self.expect_keyword(Keyword::REMAINDER)?;
+            let remainder = self.parse_literal_uint()?;
+            self.expect_token(&Token::RParen)?;
+            Ok(ForValues::With { modulus, remainder })
+        } else {
+            self.expected("IN, FROM or WITH after FOR VALUES", self.peek_token())
+        }
+    }
+
+    fn parse_partition_bound_values(&mut self) -> Result<Vec<PartitionBoundValue>, ParserError> {
+        self.expect_token(&Token::LParen)?;
+        let values = self.parse_comma_separated(|p| {
+            if p.parse_keyword(Keyword::MINVALUE) {
+                Ok(PartitionBoundValue::MinValue)
+            } else if p.parse_keyword(Keyword::MAXVALUE) {
+                Ok(PartitionBoundValue::MaxValue)
+            } else {
+                Ok(PartitionBoundValue::Expr(p.parse_expr()?))
+            }
+        })?;
+        self.expect_token(&Token::RParen)?;
+        Ok(values)
+    }
+
+    /// Parses PostgreSQL's `PARTITION BY { RANGE | LIST | HASH } ( ... )` clause
+    /// of `CREATE TABLE`, if present
+    fn parse_optional_partition_spec(&mut self) -> Result<Option<PartitionSpec>, ParserError> {
+        if !dialect_of!(self is PostgreSqlDialect | GenericDialect) {
+            return Ok(None);
+        }
+        let strategy = match (
+            &self.peek_nth_token_ref(0).token,
+            &self.peek_nth_token_ref(1).token,
+            &self.peek_nth_token_ref(2).token,
+            &self.peek_nth_token_ref(3).token,
+        ) {
+            (Token::Word(partition), Token::Word(by), Token::Word(strategy), Token::LParen)
+                if partition.keyword == Keyword::PARTITION && by.keyword == Keyword::BY =>
+            {
+                match strategy.keyword {
+                    Keyword::RANGE => PartitionStrategy::Range,
+                    Keyword::LIST => PartitionStrategy::List,
+                    Keyword::HASH => PartitionStrategy::Hash,
+                    _ => return Ok(None),
+                }
+            }
+            _ => return Ok(None),
+        };
+        self.expect_keywords(&[Keyword::PARTITION, Keyword::BY])?;
+        self.next_token();
+        self.expect_token(&Token::LParen)?;
+        let columns = self.parse_comma_separated(Parser::parse_expr)?;
+        self.expect_token(&Token::RParen)?;
+        Ok(Some(PartitionSpec { strategy, columns }))
+    }
+
     fn parse_optional_create_table_config(
         &mut self,
     ) -> Result<CreateTableConfiguration, ParserError> {
@@ -6034,9 +8656,22 @@ impl<'a> Parser<'a> {
     }
 
     pub fn parse_procedure_param(&mut self) -> Result<ProcedureParam, ParserError> {
+        let mode = if self.parse_keyword(Keyword::IN) {
+            Some(ArgMode::In)
+        } else if self.parse_keyword(Keyword::OUT) {
+            Some(ArgMode::Out)
+        } else if self.parse_keyword(Keyword::INOUT) {
+            Some(ArgMode::InOut)
+        } else {
+            None
+        };
         let name = self.parse_identifier(false)?;
         let data_type = self.parse_data_type()?;
-        Ok(ProcedureParam { name, data_type })
+        Ok(ProcedureParam {
+            mode,
+            name,
+            data_type,
+        })
     }
 
     pub fn parse_column_def(&mut self) -> Result<ColumnDef, ParserError> {
@@ -6104,6 +8739,32 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parses the `[ COLLATE <collation> ] [ <options> ]` following the data
+    /// type of a `CHANGE` or `MODIFY` column, where MySQL also allows the
+    /// `COLLATE` between the options
+    fn parse_column_collation_and_options(
+        &mut self,
+    ) -> Result<(Option<ObjectName>, Vec<ColumnOption>), ParserError> {
+        let mut collation = if self.parse_keyword(Keyword::COLLATE) {
+            Some(self.parse_object_name(false)?)
+        } else {
+            None
+        };
+        let mut options = vec![];
+        loop {
+            if let Some(option) = self.parse_optional_column_option()? {
+                options.push(option);
+            } else if dialect_of!(self is MySqlDialect | GenericDialect)
+                && self.parse_keyword(Keyword::COLLATE)
+            {
+                collation = Some(self.parse_object_name(false)?);
+            } else {
+                break;
+            }
+        }
+        Ok((collation, options))
+    }
+
     pub fn parse_optional_column_option(&mut self) -> Result<Option<ColumnOption>, ParserError> {
         if self.parse_keywords(&[Keyword::CHARACTER, Keyword::SET]) {
             Ok(Some(ColumnOption::CharacterSet(
@@ -6146,6 +8807,10 @@ impl<'a> Parser<'a> {
                 characteristics,
             }))
         } else if self.parse_keyword(Keyword::UNIQUE) {
+            if dialect_of!(self is MySqlDialect | GenericDialect) {
+                // MySQL allows `UNIQUE KEY` as a synonym for `UNIQUE`
+                let _ = self.parse_keyword(Keyword::KEY);
+            }
             let characteristics = self.parse_constraint_characteristics()?;
             Ok(Some(ColumnOption::Unique {
                 is_primary: false,
@@ -6243,6 +8908,43 @@ impl<'a> Parser<'a> {
                 None
             };
             Ok(Some(ColumnOption::Identity(property)))
+        } else if dialect_of!(self is MySqlDialect | GenericDialect)
+            && self.parse_keyword(Keyword::VISIBLE)
+        {
+            Ok(Some(ColumnOption::Visible))
+        } else if dialect_of!(self is MySqlDialect | GenericDialect)
+            && self.parse_keyword(Keyword::INVISIBLE)
+        {
+            Ok(Some(ColumnOption::Invisible))
+        } else if dialect_of!(self is MySqlDialect | GenericDialect)
+            && self.parse_keyword(Keyword::COLUMN_FORMAT)
+        {
+            let format = match self.expect_one_of_keywords(&[
+                Keyword::FIXED,
+                Keyword::DYNAMIC,
+                Keyword::DEFAULT,
+            ])? {
+                Keyword::FIXED => ColumnFormat::Fixed,
+                Keyword::DYNAMIC => ColumnFormat::Dynamic,
+                Keyword::DEFAULT => ColumnFormat::Default,
+                _ => unreachable!(),
+            };
+            Ok(Some(ColumnOption::ColumnFormat(format)))
+        } else if dialect_of!(self is MySqlDialect | GenericDialect)
+            && self.parse_keyword(Keyword::STORAGE)
+        {
+            let storage = match self.expect_one_of_keywords(&[Keyword::DISK, Keyword::MEMORY])? {
+                Keyword::DISK => ColumnStorage::Disk,
+                Keyword::MEMORY => ColumnStorage::Memory,
+                _ => unreachable!(),
+            };
+            Ok(Some(ColumnOption::Storage(storage)))
+        } else if dialect_of!(self is MySqlDialect | GenericDialect)
+            && self.parse_keyword(Keyword::SRID)
+        {
+            Ok(Some(ColumnOption::Srid(Expr::Value(
+                self.parse_number_value()?,
+            ))))
         } else if dialect_of!(self is SQLiteDialect | GenericDialect)
             && self.parse_keywords(&[Keyword::ON, Keyword::CONFLICT])
         {
@@ -6547,12 +9249,14 @@ impl<'a> Parser<'a> {
 
                 let index_type = self.parse_optional_using_then_index_type()?;
                 let columns = self.parse_parenthesized_column_list(Mandatory, false)?;
+                let index_options = self.parse_index_options()?;
 
                 Ok(Some(TableConstraint::Index {
                     display_as_key,
                     name,
                     index_type,
                     columns,
+                    index_options,
                 }))
             }
             Token::Word(w)
@@ -6576,12 +9280,14 @@ impl<'a> Parser<'a> {
                 let opt_index_name = self.parse_optional_indent();
 
                 let columns = self.parse_parenthesized_column_list(Mandatory, false)?;
+                let index_options = self.parse_index_options()?;
 
                 Ok(Some(TableConstraint::FulltextOrSpatial {
                     fulltext,
                     index_type_display,
                     opt_index_name,
                     columns,
+                    index_options,
                 }))
             }
             _ => {
@@ -6676,6 +9382,15 @@ impl<'a> Parser<'a> {
         } else if self.parse_keyword(Keyword::COMMENT) {
             let s = self.parse_literal_string()?;
             Ok(Some(IndexOption::Comment(s)))
+        } else if self.parse_keyword(Keyword::KEY_BLOCK_SIZE) {
+            let _ = self.consume_token(&Token::Eq);
+            Ok(Some(IndexOption::KeyBlockSize(self.parse_number_value()?)))
+        } else if self.parse_keywords(&[Keyword::WITH, Keyword::PARSER]) {
+            Ok(Some(IndexOption::WithParser(self.parse_identifier(false)?)))
+        } else if self.parse_keyword(Keyword::VISIBLE) {
+            Ok(Some(IndexOption::Visible))
+        } else if self.parse_keyword(Keyword::INVISIBLE) {
+            Ok(Some(IndexOption::Invisible))
         } else {
             Ok(None)
         }
@@ -6787,6 +9502,33 @@ impl<'a> Parser<'a> {
         Ok(Partition::Partitions(partitions))
     }
 
+    /// Parse a MySQL partition definition, e.g. `PARTITION p1 VALUES LESS THAN (100)`
+    pub fn parse_partition_definition(&mut self) -> Result<PartitionDefinition, ParserError> {
+        self.expect_keyword(Keyword::PARTITION)?;
+        let name = self.parse_identifier(false)?;
+        let values = if self.parse_keyword(Keyword::VALUES) {
+            if self.parse_keywords(&[Keyword::LESS, Keyword::THAN]) {
+                if self.parse_keyword(Keyword::MAXVALUE) {
+                    Some(PartitionValues::LessThanMaxValue)
+                } else {
+                    self.expect_token(&Token::LParen)?;
+                    let exprs = self.parse_comma_separated(Parser::parse_expr)?;
+                    self.expect_token(&Token::RParen)?;
+                    Some(PartitionValues::LessThan(exprs))
+                }
+            } else {
+                self.expect_keyword(Keyword::IN)?;
+                self.expect_token(&Token::LParen)?;
+                let exprs = self.parse_comma_separated(Parser::parse_expr)?;
+                self.expect_token(&Token::RParen)?;
+                Some(PartitionValues::In(exprs))
+            }
+        } else {
+            None
+        };
+        Ok(PartitionDefinition { name, values })
+    }
+
     pub fn parse_projection_select(&mut self) -> Result<ProjectionSelect, ParserError> {
         self.expect_token(&Token::LParen)?;
         self.expect_keyword(Keyword::SELECT)?;
@@ -6814,7 +9556,11 @@ impl<'a> Parser<'a> {
     pub fn parse_alter_table_operation(&mut self) -> Result<AlterTableOperation, ParserError> {
         let operation = if self.parse_keyword(Keyword::ADD) {
             if let Some(constraint) = self.parse_optional_table_constraint()? {
-                AlterTableOperation::AddConstraint(constraint)
+                let not_valid = self.parse_keywords(&[Keyword::NOT, Keyword::VALID]);
+                AlterTableOperation::AddConstraint {
+                    constraint,
+                    not_valid,
+                }
             } else if dialect_of!(self is ClickHouseDialect|GenericDialect)
                 && self.parse_keyword(Keyword::PROJECTION)
             {
@@ -6822,6 +9568,20 @@ impl<'a> Parser<'a> {
             } else {
                 let if_not_exists =
                     self.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+                if dialect_of!(self is MySqlDialect | GenericDialect)
+                    && matches!(
+                        self.peek_tokens(),
+                        [Token::Word(w1), Token::LParen, Token::Word(w2)]
+                            if w1.keyword == Keyword::PARTITION && w2.keyword == Keyword::PARTITION
+                    )
+                {
+                    self.expect_keyword(Keyword::PARTITION)?;
+                    self.expect_token(&Token::LParen)?;
+                    let partitions =
+                        self.parse_comma_separated(Parser::parse_partition_definition)?;
+                    self.expect_token(&Token::RParen)?;
+                    return Ok(AlterTableOperation::AddPartitionDefinitions { partitions });
+                }
                 let mut new_partitions = vec![];
                 loop {
                     if self.parse_keyword(Keyword::PARTITION) {
@@ -6858,13 +9618,49 @@ impl<'a> Parser<'a> {
                     }
                 }
             }
+        } else if self.parse_keywords(&[Keyword::VALIDATE, Keyword::CONSTRAINT]) {
+            let name = self.parse_identifier(false)?;
+            AlterTableOperation::ValidateConstraint { name }
         } else if self.parse_keyword(Keyword::RENAME) {
-            if dialect_of!(self is PostgreSqlDialect) && self.parse_keyword(Keyword::CONSTRAINT) {
+            // `RENAME KEY TO ...` renames a column called `key`
+            let index_keyword = if dialect_of!(self is MySqlDialect | GenericDialect)
+                && !matches!(
+                    self.peek_nth_token(1).token,
+                    Token::Word(Word {
+                        keyword: Keyword::TO,
+                        ..
+                    })
+                ) {
+                self.parse_one_of_keywords(&[Keyword::INDEX, Keyword::KEY])
+            } else {
+                None
+            };
+            if let Some(keyword) = index_keyword {
+                let index_type_display = match keyword {
+                    Keyword::INDEX => KeyOrIndexDisplay::Index,
+                    Keyword::KEY => KeyOrIndexDisplay::Key,
+                    _ => unreachable!(),
+                };
+                let old_name = self.parse_identifier(false)?;
+                self.expect_keyword(Keyword::TO)?;
+                let new_name = self.parse_identifier(false)?;
+                AlterTableOperation::RenameIndex {
+                    index_type_display,
+                    old_name,
+                    new_name,
+                }
+            } else if dialect_of!(self is PostgreSqlDialect)
+                && self.parse_keyword(Keyword::CONSTRAINT)
+            {
                 let old_name = self.parse_identifier(false)?;
                 self.expect_keyword(Keyword::TO)?;
                 let new_name = self.parse_identifier(false)?;
                 AlterTableOperation::RenameConstraint { old_name, new_name }
-            } else if self.parse_keyword(Keyword::TO) {
+            } else if self.parse_keyword(Keyword::TO)
+                || (dialect_of!(self is MySqlDialect | GenericDialect)
+                    && self.parse_keyword(Keyword::AS))
+            {
+                // MySQL also allows `RENAME AS <table_name>`
                 let table_name = self.parse_object_name(false)?;
                 AlterTableOperation::RenameTable { table_name }
             } else {
@@ -6958,6 +9754,15 @@ impl<'a> Parser<'a> {
                     partitions,
                     if_exists: true,
                 }
+            } else if dialect_of!(self is MySqlDialect | GenericDialect)
+                && matches!(
+                    self.peek_tokens(),
+                    [Token::Word(w), next] if w.keyword == Keyword::PARTITION && next != Token::LParen
+                )
+            {
+                self.expect_keyword(Keyword::PARTITION)?;
+                let partitions = self.parse_comma_separated(|p| p.parse_identifier(false))?;
+                AlterTableOperation::DropPartitionNames { partitions }
             } else if self.parse_keyword(Keyword::PARTITION) {
                 self.expect_token(&Token::LParen)?;
                 let partitions = self.parse_comma_separated(Parser::parse_expr)?;
@@ -7010,38 +9815,36 @@ impl<'a> Parser<'a> {
                 new_partitions: renames,
             }
         } else if self.parse_keyword(Keyword::CHANGE) {
-            let _ = self.parse_keyword(Keyword::COLUMN); // [ COLUMN ]
+            let column_keyword = self.parse_keyword(Keyword::COLUMN);
             let old_name = self.parse_identifier(false)?;
             let new_name = self.parse_identifier(false)?;
             let data_type = self.parse_data_type()?;
-            let mut options = vec![];
-            while let Some(option) = self.parse_optional_column_option()? {
-                options.push(option);
-            }
+            let (collation, options) = self.parse_column_collation_and_options()?;
 
             let column_position = self.parse_column_position()?;
 
             AlterTableOperation::ChangeColumn {
+                column_keyword,
                 old_name,
                 new_name,
                 data_type,
+                collation,
                 options,
                 column_position,
             }
         } else if self.parse_keyword(Keyword::MODIFY) {
-            let _ = self.parse_keyword(Keyword::COLUMN); // [ COLUMN ]
+            let column_keyword = self.parse_keyword(Keyword::COLUMN);
             let col_name = self.parse_identifier(false)?;
             let data_type = self.parse_data_type()?;
-            let mut options = vec![];
-            while let Some(option) = self.parse_optional_column_option()? {
-                options.push(option);
-            }
+            let (collation, options) = self.parse_column_collation_and_options()?;
 
             let column_position = self.parse_column_position()?;
 
             AlterTableOperation::ModifyColumn {
+                column_keyword,
                 col_name,
                 data_type,
+                collation,
                 options,
                 column_position,
             }
@@ -7156,6 +9959,59 @@ impl<'a> Parser<'a> {
                 partition,
                 with_name,
             }
+        } else if dialect_of!(self is MySqlDialect | GenericDialect)
+            && self.parse_keywords(&[Keyword::REORGANIZE, Keyword::PARTITION])
+        {
+            let partitions = self.parse_comma_separated(|p| p.parse_identifier(false))?;
+            self.expect_keyword(Keyword::INTO)?;
+            self.expect_token(&Token::LParen)?;
+            let into = self.parse_comma_separated(Parser::parse_partition_definition)?;
+            self.expect_token(&Token::RParen)?;
+            AlterTableOperation::ReorganizePartitions { partitions, into }
+        } else if dialect_of!(self is MySqlDialect | GenericDialect)
+            && self.parse_keywords(&[Keyword::COALESCE, Keyword::PARTITION])
+        {
+            let number = self.parse_number_value()?;
+            AlterTableOperation::CoalescePartition { number }
+        } else if dialect_of!(self is MySqlDialect | GenericDialect)
+            && self.parse_keyword(Keyword::ALGORITHM)
+        {
+            let equals = self.consume_token(&Token::Eq);
+            let algorithm = match self.expect_one_of_keywords(&[
+                Keyword::DEFAULT,
+                Keyword::INSTANT,
+                Keyword::INPLACE,
+                Keyword::COPY,
+            ])? {
+                Keyword::DEFAULT => AlterTableAlgorithm::Default,
+                Keyword::INSTANT => AlterTableAlgorithm::Instant,
+                Keyword::INPLACE => AlterTableAlgorithm::Inplace,
+                Keyword::COPY => AlterTableAlgorithm::Copy,
+                _ => unreachable!(),
+            };
+            AlterTableOperation::Algorithm { equals, algorithm }
+        } else if dialect_of!(self is MySqlDialect | GenericDialect)
+            && self.parse_keyword(Keyword::LOCK)
+        {
+            let equals = self.consume_token(&Token::Eq);
+            let lock = match self.expect_one_of_keywords(&[
+                Keyword::DEFAULT,
+                Keyword::NONE,
+                Keyword::SHARED,
+                Keyword::EXCLUSIVE,
+            ])? {
+                Keyword::DEFAULT => AlterTableLock::Default,
+                Keyword::NONE => AlterTableLock::None,
+                Keyword::SHARED => AlterTableLock::Shared,
+                Keyword::EXCLUSIVE => AlterTableLock::Exclusive,
+                _ => unreachable!(),
+            };
+            AlterTableOperation::Lock { equals, lock }
+        } else if dialect_of!(self is MySqlDialect | GenericDialect)
+            && self.parse_keywords(&[Keyword::ORDER, Keyword::BY])
+        {
+            let exprs = self.parse_comma_separated(Parser::parse_order_by_expr)?;
+            AlterTableOperation::OrderBy { exprs }
         } else {
             let options: Vec<SqlOption> =
                 self.parse_options_with_keywords(&[Keyword::SET, Keyword::TBLPROPERTIES])?;
@@ -7184,12 +10040,26 @@ impl<'a> Parser<'a> {
     }
 
     pub fn parse_alter(&mut self) -> Result<Statement, ParserError> {
+        if let Some(statement) = self.parse_generic_ddl(vec![Ident::new("ALTER")])? {
+            return Ok(statement);
+        }
+        if dialect_of!(self is MySqlDialect | GenericDialect)
+            && self.parse_keyword(Keyword::DEFINER)
+        {
+            let definer = self.parse_definer()?;
+            self.expect_keyword(Keyword::EVENT)?;
+            return self.parse_alter_event(Some(definer));
+        }
         let object_type = self.expect_one_of_keywords(&[
             Keyword::VIEW,
             Keyword::TABLE,
             Keyword::INDEX,
             Keyword::ROLE,
             Keyword::POLICY,
+            Keyword::EVENT,
+            Keyword::EXTENSION,
+            Keyword::COLLATION,
+            Keyword::STATISTICS,
         ])?;
         match object_type {
             Keyword::VIEW => self.parse_alter_view(),
@@ -7213,6 +10083,8 @@ impl<'a> Parser<'a> {
                     });
                 }
 
+                let unknown_options = self.parse_unknown_options()?;
+
                 Ok(Statement::AlterTable {
                     name: table_name,
                     if_exists,
@@ -7220,6 +10092,7 @@ impl<'a> Parser<'a> {
                     operations,
                     location,
                     on_cluster,
+                    unknown_options,
                 })
             }
             Keyword::INDEX => {
@@ -7242,6 +10115,10 @@ impl<'a> Parser<'a> {
             }
             Keyword::ROLE => self.parse_alter_role(),
             Keyword::POLICY => self.parse_alter_policy(),
+            Keyword::EVENT => self.parse_alter_event(None),
+            Keyword::EXTENSION => self.parse_alter_extension(),
+            Keyword::COLLATION => self.parse_alter_collation(),
+            Keyword::STATISTICS => self.parse_alter_statistics(),
             // unreachable because expect_one_of_keywords used above
             _ => unreachable!(),
         }
@@ -7284,6 +10161,7 @@ impl<'a> Parser<'a> {
                 over: None,
                 filter: None,
                 null_treatment: None,
+                safe: false,
                 within_group: vec![],
             }))
         }
@@ -7342,7 +10220,8 @@ impl<'a> Parser<'a> {
         }
         let values = if let CopyTarget::Stdin = target {
             self.expect_token(&Token::SemiColon)?;
-            self.parse_tsv()
+            let format = CopyDataFormat::new(&options, &legacy_options);
+            self.parse_copy_data(&format)?
         } else {
             vec![]
         };
@@ -7374,42 +10253,86 @@ impl<'a> Parser<'a> {
             Keyword::FREEZE,
             Keyword::DELIMITER,
             Keyword::NULL,
+            Keyword::DEFAULT,
             Keyword::HEADER,
             Keyword::QUOTE,
             Keyword::ESCAPE,
             Keyword::FORCE_QUOTE,
             Keyword::FORCE_NOT_NULL,
             Keyword::FORCE_NULL,
+            Keyword::ON_ERROR,
             Keyword::ENCODING,
+            Keyword::LOG_VERBOSITY,
         ]) {
             Some(Keyword::FORMAT) => CopyOption::Format(self.parse_identifier(false)?),
-            Some(Keyword::FREEZE) => CopyOption::Freeze(!matches!(
-                self.parse_one_of_keywords(&[Keyword::TRUE, Keyword::FALSE]),
-                Some(Keyword::FALSE)
-            )),
+            Some(Keyword::FREEZE) => CopyOption::Freeze(self.parse_copy_option_boolean()?),
             Some(Keyword::DELIMITER) => CopyOption::Delimiter(self.parse_literal_char()?),
             Some(Keyword::NULL) => CopyOption::Null(self.parse_literal_string()?),
-            Some(Keyword::HEADER) => CopyOption::Header(!matches!(
-                self.parse_one_of_keywords(&[Keyword::TRUE, Keyword::FALSE]),
-                Some(Keyword::FALSE)
-            )),
+            Some(Keyword::DEFAULT) => CopyOption::Default(self.parse_literal_string()?),
+            Some(Keyword::HEADER) => {
+                if self.parse_keyword(Keyword::MATCH) {
+                    CopyOption::HeaderMatch
+                } else {
+                    CopyOption::Header(self.parse_copy_option_boolean()?)
+                }
+            }
             Some(Keyword::QUOTE) => CopyOption::Quote(self.parse_literal_char()?),
             Some(Keyword::ESCAPE) => CopyOption::Escape(self.parse_literal_char()?),
             Some(Keyword::FORCE_QUOTE) => {
-                CopyOption::ForceQuote(self.parse_parenthesized_column_list(Mandatory, false)?)
+                if self.consume_token(&Token::Mul) {
+                    CopyOption::ForceQuoteAll
+                } else {
+                    CopyOption::ForceQuote(self.parse_parenthesized_column_list(Mandatory, false)?)
+                }
             }
             Some(Keyword::FORCE_NOT_NULL) => {
-                CopyOption::ForceNotNull(self.parse_parenthesized_column_list(Mandatory, false)?)
+                if self.consume_token(&Token::Mul) {
+                    CopyOption::ForceNotNullAll
+                } else {
+                    CopyOption::ForceNotNull(
+                        self.parse_parenthesized_column_list(Mandatory, false)?,
+                    )
+                }
             }
             Some(Keyword::FORCE_NULL) => {
-                CopyOption::ForceNull(self.parse_parenthesized_column_list(Mandatory, false)?)
+                if self.consume_token(&Token::Mul) {
+                    CopyOption::ForceNullAll
+                } else {
+                    CopyOption::ForceNull(self.parse_parenthesized_column_list(Mandatory, false)?)
+                }
             }
+            Some(Keyword::ON_ERROR) => CopyOption::OnError(self.parse_identifier(false)?),
             Some(Keyword::ENCODING) => CopyOption::Encoding(self.parse_literal_string()?),
+            Some(Keyword::LOG_VERBOSITY) => CopyOption::LogVerbosity(self.parse_identifier(false)?),
             _ => self.expected("option", self.peek_token())?,
         };
         Ok(ret)
     }
 
+    /// Parses the optional boolean value of a `COPY` option such as `HEADER`,
+    /// which may be spelled `TRUE`, `FALSE`, `ON`, `OFF`, `1` or `0`
+    fn parse_copy_option_boolean(&mut self) -> Result<bool, ParserError> {
+        match &self.peek_token_ref().token {
+            Token::Word(w) => match w.keyword {
+                Keyword::TRUE | Keyword::ON => {
+                    self.advance_token();
+                    Ok(true)
+                }
+                Keyword::FALSE | Keyword::OFF => {
+                    self.advance_token();
+                    Ok(false)
+                }
+                _ => Ok(true),
+            },
+            Token::Number(n, _) if n == "1" || n == "0" => {
+                let value = n == "1";
+                self.advance_token();
+                Ok(value)
+            }
+            _ => Ok(true),
+        }
+    }
+
     fn parse_copy_legacy_option(&mut self) -> Result<CopyLegacyOption, ParserError> {
         let ret = match self.parse_one_of_keywords(&[
             Keyword::BINARY,
@@ -7490,34 +10413,55 @@ impl<'a> Parser<'a> {
     }
 
     pub fn parse_tab_value(&mut self) -> Vec<Option<String>> {
-        let mut values = vec![];
-        let mut content = String::from("");
-        while let Some(t) = self.next_token_no_skip().map(|t| &t.token) {
-            match t {
-                Token::Whitespace(Whitespace::Tab) => {
-                    values.push(Some(content.to_string()));
-                    content.clear();
-                }
-                Token::Whitespace(Whitespace::Newline) => {
-                    values.push(Some(content.to_string()));
-                    content.clear();
-                }
-                Token::Backslash => {
-                    if self.consume_token(&Token::Period) {
-                        return values;
-                    }
-                    if let Token::Word(w) = self.next_token().token {
-                        if w.value == "N" {
-                            values.push(None);
-                        }
-                    }
-                }
-                _ => {
-                    content.push_str(&t.to_string());
-                }
-            }
+        let format = CopyDataFormat::new(&[], &[]);
+        self.parse_copy_data(&format).unwrap_or_default()
+    }
+
+    /// Parse the inline data of a `COPY ... FROM STDIN` statement, up to and
+    /// including the `\.` line that ends it, into the values of its rows.
+    ///
+    /// The values are split according to `format`, but are otherwise kept as
+    /// written, i.e. with any `TEXT` escapes or `CSV` quotes.
+    pub fn parse_copy_data(
+        &mut self,
+        format: &CopyDataFormat,
+    ) -> Result<Vec<Option<String>>, ParserError> {
+        let next_token = self.peek_token_no_skip();
+        let data = match next_token.token {
+            Token::CopyData(data) => {
+                self.next_token_no_skip();
+                data
+            }
+            _ => String::new(),
+        };
+        if self.peek_token_no_skip().token == Token::Backslash
+            && self.peek_nth_token_no_skip(1).token == Token::Period
+        {
+            self.next_token_no_skip();
+            self.next_token_no_skip();
+        }
+
+        // the data starts on the line following the statement
+        let data = match data.split_once('\n') {
+            Some((first_line, rest)) if first_line.trim().is_empty() => rest,
+            _ => data.as_str(),
+        };
+        if data.is_empty() {
+            return Ok(vec![]);
+        }
+        if format.binary || data.starts_with("PGCOPY\n") {
+            return parser_err!(
+                "COPY data in binary format is not supported",
+                next_token.location
+            );
+        }
+
+        let data = data.strip_suffix('\n').unwrap_or(data);
+        if format.csv {
+            Ok(split_csv_copy_data(data, format))
+        } else {
+            Ok(split_text_copy_data(data, format))
         }
-        values
     }
 
     /// Parse a literal value (numbers, strings, date/time, booleans)
@@ -7585,7 +10529,25 @@ impl<'a> Parser<'a> {
             Token::TripleDoubleQuotedRawStringLiteral(ref s) => {
                 Ok(Value::TripleDoubleQuotedRawStringLiteral(s.clone()))
             }
+            Token::SingleQuotedRawByteStringLiteral(ref s) => {
+                Ok(Value::SingleQuotedRawByteStringLiteral(s.clone()))
+            }
+            Token::DoubleQuotedRawByteStringLiteral(ref s) => {
+                Ok(Value::DoubleQuotedRawByteStringLiteral(s.clone()))
+            }
+            Token::TripleSingleQuotedRawByteStringLiteral(ref s) => {
+                Ok(Value::TripleSingleQuotedRawByteStringLiteral(s.clone()))
+            }
+            Token::TripleDoubleQuotedRawByteStringLiteral(ref s) => {
+                Ok(Value::TripleDoubleQuotedRawByteStringLiteral(s.clone()))
+            }
             Token::NationalStringLiteral(ref s) => Ok(Value::NationalStringLiteral(s.to_string())),
+            Token::QuoteDelimitedStringLiteral(ref s) => {
+                Ok(Value::QuoteDelimitedStringLiteral(s.clone()))
+            }
+            Token::NationalQuoteDelimitedStringLiteral(ref s) => {
+                Ok(Value::NationalQuoteDelimitedStringLiteral(s.clone()))
+            }
             Token::EscapedStringLiteral(ref s) => Ok(Value::EscapedStringLiteral(s.to_string())),
             Token::UnicodeStringLiteral(ref s) => Ok(Value::UnicodeStringLiteral(s.to_string())),
             Token::HexStringLiteral(ref s) => Ok(Value::HexStringLiteral(s.to_string())),
@@ -7599,7 +10561,8 @@ impl<'a> Parser<'a> {
                     Token::Number(w, false) => Ok(Ident::new(w)),
                     _ => self.expected("placeholder", next_token),
                 }?;
-                let placeholder = tok.to_string() + &ident.value;
+                // keep the quotes of e.g. the MySQL user variable @`my var`
+                let placeholder = tok.to_string() + &ident.to_string();
                 Ok(Value::Placeholder(placeholder))
             }
             unexpected => self.expected(
@@ -7675,7 +10638,7 @@ impl<'a> Parser<'a> {
     fn parse_create_function_body_string(&mut self) -> Result<Expr, ParserError> {
         let peek_token = self.peek_token();
         match peek_token.token {
-            Token::DollarQuotedString(s) if dialect_of!(self is PostgreSqlDialect | GenericDialect) =>
+            Token::DollarQuotedString(s) if dialect_of!(self is PostgreSqlDialect | SnowflakeDialect | GenericDialect) =>
             {
                 self.next_token();
                 Ok(Expr::Value(Value::DollarQuotedString(s)))
@@ -7827,6 +10790,9 @@ impl<'a> Parser<'a> {
                 Keyword::NVARCHAR => {
                     Ok(DataType::Nvarchar(self.parse_optional_character_length()?))
                 }
+                Keyword::VARCHAR2 => {
+                    Ok(DataType::Varchar2(self.parse_optional_character_length()?))
+                }
                 Keyword::CHARACTER => {
                     if self.parse_keyword(Keyword::VARYING) {
                         Ok(DataType::CharacterVarying(
@@ -7918,6 +10884,9 @@ impl<'a> Parser<'a> {
                 Keyword::NUMERIC => Ok(DataType::Numeric(
                     self.parse_exact_number_optional_precision_scale()?,
                 )),
+                Keyword::NUMBER if dialect_of!(self is OracleDialect) => Ok(DataType::Number(
+                    self.parse_exact_number_optional_precision_scale()?,
+                )),
                 Keyword::DECIMAL => Ok(DataType::Decimal(
                     self.parse_exact_number_optional_precision_scale()?,
                 )),
@@ -8063,7 +11032,12 @@ impl<'a> Parser<'a> {
             // which may start a construct allowed in this position, to be parsed as aliases.
             // (For example, in `FROM t1 JOIN` the `JOIN` will always be parsed as a keyword,
             // not an alias.)
-            Token::Word(w) if after_as || !reserved_kwds.contains(&w.keyword) => {
+            Token::Word(w)
+                if after_as
+                    || !(reserved_kwds.contains(&w.keyword)
+                        || w.keyword == Keyword::MINUS
+                            && self.dialect.supports_minus_set_operator()) =>
+            {
                 Ok(Some(w.to_ident()))
             }
             // MSSQL supports single-quoted strings as aliases for columns
@@ -8101,13 +11075,30 @@ impl<'a> Parser<'a> {
     ) -> Result<Option<TableAlias>, ParserError> {
         match self.parse_optional_alias(reserved_kwds)? {
             Some(name) => {
-                let columns = self.parse_parenthesized_column_list(Optional, false)?;
+                let columns = if self.consume_token(&Token::LParen) {
+                    let columns =
+                        self.parse_comma_separated(Parser::parse_table_alias_column_def)?;
+                    self.expect_token(&Token::RParen)?;
+                    columns
+                } else {
+                    vec![]
+                };
                 Ok(Some(TableAlias { name, columns }))
             }
             None => Ok(None),
         }
     }
 
+    /// Parse a column of a table alias, optionally followed by its data type
+    fn parse_table_alias_column_def(&mut self) -> Result<TableAliasColumnDef, ParserError> {
+        let name = self.parse_identifier(false)?;
+        let data_type = match self.peek_token().token {
+            Token::Comma | Token::RParen => None,
+            _ => Some(self.parse_data_type()?),
+        };
+        Ok(TableAliasColumnDef { name, data_type })
+    }
+
     pub fn parse_optional_group_by(&mut self) -> Result<Option<GroupByExpr>, ParserError> {
         if self.parse_keywords(&[Keyword::GROUP, Keyword::BY]) {
             let expressions = if self.parse_keyword(Keyword::ALL) {
@@ -8152,17 +11143,18 @@ impl<'a> Parser<'a> {
 
     pub fn parse_optional_order_by(&mut self) -> Result<Option<OrderBy>, ParserError> {
         if self.parse_keywords(&[Keyword::ORDER, Keyword::BY]) {
-            let order_by_exprs = self.parse_comma_separated(Parser::parse_order_by_expr)?;
+            let kind = if self.parse_keyword(Keyword::ALL) {
+                OrderByKind::All(self.parse_order_by_options())
+            } else {
+                OrderByKind::Expressions(self.parse_comma_separated(Parser::parse_order_by_expr)?)
+            };
             let interpolate = if dialect_of!(self is ClickHouseDialect | GenericDialect) {
                 self.parse_interpolations()?
             } else {
                 None
             };
 
-            Ok(Some(OrderBy {
-                exprs: order_by_exprs,
-                interpolate,
-            }))
+            Ok(Some(OrderBy { kind, interpolate }))
         } else {
             Ok(None)
         }
@@ -8370,6 +11362,8 @@ impl<'a> Parser<'a> {
             }
             Token::SingleQuotedString(s) => Ok(Ident::with_quote('\'', s)),
             Token::DoubleQuotedString(s) => Ok(Ident::with_quote('\"', s)),
+            // a variable reference, see `ParserOptions::variable_substitution`
+            Token::Placeholder(s) if s.starts_with("${") => Ok(Ident::new(s)),
             _ => self.expected("identifier", next_token),
         }
     }
@@ -8496,6 +11490,10 @@ impl<'a> Parser<'a> {
             Some(CharLengthUnits::Characters)
         } else if self.parse_keyword(Keyword::OCTETS) {
             Some(CharLengthUnits::Octets)
+        } else if self.parse_keyword(Keyword::CHAR) {
+            Some(CharLengthUnits::Char)
+        } else if self.parse_keyword(Keyword::BYTE) {
+            Some(CharLengthUnits::Byte)
         } else {
             None
         };
@@ -8578,6 +11576,13 @@ impl<'a> Parser<'a> {
         Ok(parent_type(inside_type.into()))
     }
 
+    /// Parse a DELETE statement, returning a `Box`ed SetExpr
+    ///
+    /// This is used to reduce the size of the stack frames in debug builds
+    fn parse_delete_setexpr_boxed(&mut self) -> Result<Box<SetExpr>, ParserError> {
+        Ok(Box::new(SetExpr::Delete(self.parse_delete()?)))
+    }
+
     pub fn parse_delete(&mut self) -> Result<Statement, ParserError> {
         let (tables, with_from_keyword) = if !self.parse_keyword(Keyword::FROM) {
             // `FROM` keyword is optional in BigQuery SQL.
@@ -8688,7 +11693,16 @@ impl<'a> Parser<'a> {
             }
         }
 
-        match self.maybe_parse(|parser| parser.parse_statement()) {
+        // `TABLE t` is a query, unless the dialect describes tables with
+        // `EXPLAIN TABLE t`
+        let statement = if self.dialect.describe_requires_table_keyword()
+            && matches!(&self.peek_token().token, Token::Word(w) if w.keyword == Keyword::TABLE)
+        {
+            None
+        } else {
+            self.maybe_parse(|parser| parser.parse_statement())
+        };
+        match statement {
             Some(Statement::Explain { .. }) | Some(Statement::ExplainTable { .. }) => Err(
                 ParserError::ParserError("Explain must be root of the plan".to_string()),
             ),
@@ -8763,6 +11777,7 @@ impl<'a> Parser<'a> {
                 for_clause: None,
                 settings: None,
                 format_clause: None,
+                option_clause: None,
             })
         } else if self.parse_keyword(Keyword::UPDATE) {
             Ok(Query {
@@ -8777,6 +11792,22 @@ impl<'a> Parser<'a> {
                 for_clause: None,
                 settings: None,
                 format_clause: None,
+                option_clause: None,
+            })
+        } else if self.parse_keyword(Keyword::DELETE) {
+            Ok(Query {
+                with,
+                body: self.parse_delete_setexpr_boxed()?,
+                limit: None,
+                limit_by: vec![],
+                order_by: None,
+                offset: None,
+                fetch: None,
+                locks: vec![],
+                for_clause: None,
+                settings: None,
+                format_clause: None,
+                option_clause: None,
             })
         } else {
             let body = self.parse_boxed_query_body(self.dialect.prec_unknown())?;
@@ -8836,6 +11867,15 @@ impl<'a> Parser<'a> {
                     locks.push(self.parse_lock()?);
                 }
             }
+            let option_clause =
+                if dialect_of!(self is MsSqlDialect) && self.parse_keyword(Keyword::OPTION) {
+                    self.expect_token(&Token::LParen)?;
+                    let hints = self.parse_comma_separated(Parser::parse_query_hint)?;
+                    self.expect_token(&Token::RParen)?;
+                    Some(hints)
+                } else {
+                    None
+                };
             let format_clause = if dialect_of!(self is ClickHouseDialect | GenericDialect)
                 && self.parse_keyword(Keyword::FORMAT)
             {
@@ -8861,10 +11901,67 @@ impl<'a> Parser<'a> {
                 for_clause,
                 settings,
                 format_clause,
+                option_clause,
             })
         }
     }
 
+    /// Parse a MSSQL query hint of an `OPTION (...)` clause
+    pub fn parse_query_hint(&mut self) -> Result<QueryHint, ParserError> {
+        if self.parse_keywords(&[Keyword::OPTIMIZE, Keyword::FOR]) {
+            if self.parse_keyword(Keyword::UNKNOWN) {
+                return Ok(QueryHint::OptimizeFor(vec![]));
+            }
+            self.expect_token(&Token::LParen)?;
+            let variables = self.parse_comma_separated(|parser| {
+                let name = parser.parse_identifier(false)?;
+                let value = if parser.parse_keyword(Keyword::UNKNOWN) {
+                    None
+                } else {
+                    parser.expect_token(&Token::Eq)?;
+                    Some(parser.parse_expr()?)
+                };
+                Ok(OptimizeForVariable { name, value })
+            })?;
+            self.expect_token(&Token::RParen)?;
+            return Ok(QueryHint::OptimizeFor(variables));
+        }
+        if self.parse_keywords(&[Keyword::USE, Keyword::HINT]) {
+            self.expect_token(&Token::LParen)?;
+            let hints = self.parse_comma_separated(Parser::parse_value)?;
+            self.expect_token(&Token::RParen)?;
+            return Ok(QueryHint::UseHint(hints));
+        }
+        if self.parse_keywords(&[Keyword::TABLE, Keyword::HINT]) {
+            self.expect_token(&Token::LParen)?;
+            let table = self.parse_object_name(false)?;
+            let hints = if self.consume_token(&Token::Comma) {
+                self.parse_comma_separated(Parser::parse_table_hint)?
+            } else {
+                vec![]
+            };
+            self.expect_token(&Token::RParen)?;
+            return Ok(QueryHint::TableHint { table, hints });
+        }
+
+        let name = self.parse_identifier(false)?;
+        if self.consume_token(&Token::Eq) {
+            return Ok(QueryHint::Assignment {
+                name,
+                value: self.parse_expr()?,
+            });
+        }
+        let mut keywords = vec![name];
+        while let Token::Word(_) = self.peek_token().token {
+            keywords.push(self.parse_identifier(false)?);
+        }
+        let value = match self.peek_token().token {
+            Token::Comma | Token::RParen => None,
+            _ => Some(self.parse_expr()?),
+        };
+        Ok(QueryHint::Keywords { keywords, value })
+    }
+
     fn parse_settings(&mut self) -> Result<Option<Vec<Setting>>, ParserError> {
         let settings = if dialect_of!(self is ClickHouseDialect|GenericDialect)
             && self.parse_keyword(Keyword::SETTINGS)
@@ -8898,51 +11995,54 @@ impl<'a> Parser<'a> {
     /// Parse a mssql `FOR XML` clause
     pub fn parse_for_xml(&mut self) -> Result<ForClause, ParserError> {
         let for_xml = if self.parse_keyword(Keyword::RAW) {
-            let mut element_name = None;
-            if self.peek_token().token == Token::LParen {
-                self.expect_token(&Token::LParen)?;
-                element_name = Some(self.parse_literal_string()?);
-                self.expect_token(&Token::RParen)?;
-            }
-            ForXml::Raw(element_name)
+            ForXml::Raw(self.parse_for_clause_name()?)
         } else if self.parse_keyword(Keyword::AUTO) {
             ForXml::Auto
         } else if self.parse_keyword(Keyword::EXPLICIT) {
             ForXml::Explicit
         } else if self.parse_keyword(Keyword::PATH) {
-            let mut element_name = None;
-            if self.peek_token().token == Token::LParen {
-                self.expect_token(&Token::LParen)?;
-                element_name = Some(self.parse_literal_string()?);
-                self.expect_token(&Token::RParen)?;
-            }
-            ForXml::Path(element_name)
+            ForXml::Path(self.parse_for_clause_name()?)
         } else {
             return Err(ParserError::ParserError(
                 "Expected FOR XML [RAW | AUTO | EXPLICIT | PATH ]".to_string(),
             ));
         };
-        let mut elements = false;
+        let mut schema = None;
+        let mut elements = None;
         let mut binary_base64 = false;
         let mut root = None;
         let mut r#type = false;
         while self.peek_token().token == Token::Comma {
             self.next_token();
             if self.parse_keyword(Keyword::ELEMENTS) {
-                elements = true;
+                elements = Some(if self.parse_keyword(Keyword::XSINIL) {
+                    ForXmlElements::XsiNil
+                } else if self.parse_keyword(Keyword::ABSENT) {
+                    ForXmlElements::Absent
+                } else {
+                    ForXmlElements::Default
+                });
             } else if self.parse_keyword(Keyword::BINARY) {
                 self.expect_keyword(Keyword::BASE64)?;
                 binary_base64 = true;
             } else if self.parse_keyword(Keyword::ROOT) {
-                self.expect_token(&Token::LParen)?;
-                root = Some(self.parse_literal_string()?);
-                self.expect_token(&Token::RParen)?;
+                root = Some(self.parse_for_clause_name()?);
             } else if self.parse_keyword(Keyword::TYPE) {
                 r#type = true;
+            } else if self.parse_keyword(Keyword::XMLDATA) {
+                schema = Some(ForXmlSchema::XmlData);
+            } else if self.parse_keyword(Keyword::XMLSCHEMA) {
+                schema = Some(ForXmlSchema::XmlSchema(self.parse_for_clause_name()?));
+            } else {
+                return self.expected(
+                    "ELEMENTS, BINARY BASE64, ROOT, TYPE, XMLDATA or XMLSCHEMA",
+                    self.peek_token(),
+                );
             }
         }
         Ok(ForClause::Xml {
             for_xml,
+            schema,
             elements,
             binary_base64,
             root,
@@ -8967,13 +12067,16 @@ impl<'a> Parser<'a> {
         while self.peek_token().token == Token::Comma {
             self.next_token();
             if self.parse_keyword(Keyword::ROOT) {
-                self.expect_token(&Token::LParen)?;
-                root = Some(self.parse_literal_string()?);
-                self.expect_token(&Token::RParen)?;
+                root = Some(self.parse_for_clause_name()?);
             } else if self.parse_keyword(Keyword::INCLUDE_NULL_VALUES) {
                 include_null_values = true;
             } else if self.parse_keyword(Keyword::WITHOUT_ARRAY_WRAPPER) {
                 without_array_wrapper = true;
+            } else {
+                return self.expected(
+                    "ROOT, INCLUDE_NULL_VALUES or WITHOUT_ARRAY_WRAPPER",
+                    self.peek_token(),
+                );
             }
         }
         Ok(ForClause::Json {
@@ -8984,6 +12087,18 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Parse the optional `('name')` following some options of a mssql
+    /// `FOR XML` or `FOR JSON` clause
+    fn parse_for_clause_name(&mut self) -> Result<Option<String>, ParserError> {
+        if self.consume_token(&Token::LParen) {
+            let name = self.parse_literal_string()?;
+            self.expect_token(&Token::RParen)?;
+            Ok(Some(name))
+        } else {
+            Ok(None)
+        }
+    }
+
     /// Parse a CTE (`alias [( col1, col2, ... )] AS (subquery)`)
     pub fn parse_cte(&mut self) -> Result<Cte, ParserError> {
         let name = self.parse_identifier(false)?;
@@ -9024,7 +12139,10 @@ impl<'a> Parser<'a> {
             self.expect_token(&Token::LParen)?;
             let query = self.parse_boxed_query()?;
             self.expect_token(&Token::RParen)?;
-            let alias = TableAlias { name, columns };
+            let alias = TableAlias {
+                name,
+                columns: columns.into_iter().map(Into::into).collect(),
+            };
             Cte {
                 alias,
                 query,
@@ -9095,8 +12213,10 @@ impl<'a> Parser<'a> {
             // The query can be optionally followed by a set operator:
             let op = self.parse_set_operator(&self.peek_token().token);
             let next_precedence = match op {
-                // UNION and EXCEPT have the same binding power and evaluate left-to-right
-                Some(SetOperator::Union) | Some(SetOperator::Except) => 10,
+                // UNION, EXCEPT and MINUS have the same binding power and evaluate left-to-right
+                Some(SetOperator::Union) | Some(SetOperator::Except) | Some(SetOperator::Minus) => {
+                    10
+                }
                 // INTERSECT has higher precedence than UNION/EXCEPT
                 Some(SetOperator::Intersect) => 20,
                 // Unexpected token or EOF => stop parsing the query body
@@ -9123,13 +12243,23 @@ impl<'a> Parser<'a> {
             Token::Word(w) if w.keyword == Keyword::UNION => Some(SetOperator::Union),
             Token::Word(w) if w.keyword == Keyword::EXCEPT => Some(SetOperator::Except),
             Token::Word(w) if w.keyword == Keyword::INTERSECT => Some(SetOperator::Intersect),
+            Token::Word(w)
+                if w.keyword == Keyword::MINUS && self.dialect.supports_minus_set_operator() =>
+            {
+                Some(SetOperator::Minus)
+            }
             _ => None,
         }
     }
 
     pub fn parse_set_quantifier(&mut self, op: &Option<SetOperator>) -> SetQuantifier {
         match op {
-            Some(SetOperator::Except | SetOperator::Intersect | SetOperator::Union) => {
+            Some(
+                SetOperator::Except
+                | SetOperator::Intersect
+                | SetOperator::Minus
+                | SetOperator::Union,
+            ) => {
                 if self.parse_keywords(&[Keyword::DISTINCT, Keyword::BY, Keyword::NAME]) {
                     SetQuantifier::DistinctByName
                 } else if self.parse_keywords(&[Keyword::BY, Keyword::NAME]) {
@@ -9209,16 +12339,20 @@ impl<'a> Parser<'a> {
             if self.parse_keywords(&[Keyword::LATERAL, Keyword::VIEW]) {
                 let outer = self.parse_keyword(Keyword::OUTER);
                 let lateral_view = self.parse_expr()?;
-                let lateral_view_name = self.parse_object_name(false)?;
+                // Spark SQL allows `LATERAL VIEW explode(a) AS c` without a table name
+                let lateral_view_name = if matches!(
+                    &self.peek_token().token,
+                    Token::Word(w) if w.keyword == Keyword::AS
+                ) {
+                    None
+                } else {
+                    Some(self.parse_object_name(false)?)
+                };
                 let lateral_col_alias = self
                     .parse_comma_separated(|parser| {
-                        parser.parse_optional_alias(&[
-                            Keyword::WHERE,
-                            Keyword::GROUP,
-                            Keyword::CLUSTER,
-                            Keyword::HAVING,
-                            Keyword::LATERAL,
-                        ]) // This couldn't possibly be a bad idea
+                        parser.parse_optional_alias(
+                            parser.dialect.reserved_keywords_for_column_alias(),
+                        )
                     })?
                     .into_iter()
                     .flatten()
@@ -9371,49 +12505,28 @@ impl<'a> Parser<'a> {
         })
     }
 
-    /// Parse `CREATE TABLE x AS TABLE y`
+    /// Parse the table name of a `TABLE t` query, as in
+    /// `CREATE TABLE x AS TABLE y`
     pub fn parse_as_table(&mut self) -> Result<Table, ParserError> {
         let token1 = self.next_token();
-        let token2 = self.next_token();
-        let token3 = self.next_token();
-
-        let table_name;
-        let schema_name;
-        if token2 == Token::Period {
-            match token1.token {
-                Token::Word(w) => {
-                    schema_name = w.value;
-                }
-                _ => {
-                    return self.expected("Schema name", token1);
-                }
-            }
-            match token3.token {
-                Token::Word(w) => {
-                    table_name = w.value;
-                }
-                _ => {
-                    return self.expected("Table name", token3);
-                }
-            }
-            Ok(Table {
-                table_name: Some(table_name),
-                schema_name: Some(schema_name),
-            })
-        } else {
-            match token1.token {
-                Token::Word(w) => {
-                    table_name = w.value;
-                }
-                _ => {
-                    return self.expected("Table name", token1);
-                }
-            }
-            Ok(Table {
-                table_name: Some(table_name),
+        let Token::Word(w) = token1.token else {
+            return self.expected("Table name", token1);
+        };
+        if !self.consume_token(&Token::Period) {
+            return Ok(Table {
+                table_name: Some(w.value),
                 schema_name: None,
-            })
+            });
         }
+        let schema_name = w.value;
+        let token3 = self.next_token();
+        let Token::Word(w) = token3.token else {
+            return self.expected("Table name", token3);
+        };
+        Ok(Table {
+            table_name: Some(w.value),
+            schema_name: Some(schema_name),
+        })
     }
 
     pub fn parse_set(&mut self) -> Result<Statement, ParserError> {
@@ -9437,6 +12550,11 @@ impl<'a> Parser<'a> {
                 context_modifier,
                 role_name,
             });
+        } else if modifier.is_none()
+            && dialect_of!(self is MySqlDialect | GenericDialect)
+            && self.parse_keyword(Keyword::PASSWORD)
+        {
+            return self.parse_set_password();
         }
 
         let variables = if self.parse_keywords(&[Keyword::TIME, Keyword::ZONE]) {
@@ -9597,7 +12715,13 @@ impl<'a> Parser<'a> {
     }
 
     pub fn parse_show_create(&mut self) -> Result<Statement, ParserError> {
+        if self.parse_keyword(Keyword::USER) {
+            let user = self.parse_account_name()?;
+            return Ok(Statement::ShowCreateUser { user });
+        }
         let obj_type = match self.expect_one_of_keywords(&[
+            Keyword::DATABASE,
+            Keyword::SCHEMA,
             Keyword::TABLE,
             Keyword::TRIGGER,
             Keyword::FUNCTION,
@@ -9605,6 +12729,8 @@ impl<'a> Parser<'a> {
             Keyword::EVENT,
             Keyword::VIEW,
         ])? {
+            Keyword::DATABASE => Ok(ShowCreateObject::Database),
+            Keyword::SCHEMA => Ok(ShowCreateObject::Schema),
             Keyword::TABLE => Ok(ShowCreateObject::Table),
             Keyword::TRIGGER => Ok(ShowCreateObject::Trigger),
             Keyword::FUNCTION => Ok(ShowCreateObject::Function),
@@ -9616,9 +12742,17 @@ impl<'a> Parser<'a> {
             ))),
         }?;
 
+        let if_not_exists = matches!(
+            obj_type,
+            ShowCreateObject::Database | ShowCreateObject::Schema
+        ) && self.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
         let obj_name = self.parse_object_name(false)?;
 
-        Ok(Statement::ShowCreate { obj_type, obj_name })
+        Ok(Statement::ShowCreate {
+            obj_type,
+            if_not_exists,
+            obj_name,
+        })
     }
 
     pub fn parse_show_columns(
@@ -9750,11 +12884,43 @@ impl<'a> Parser<'a> {
                     global,
                     join_operator: JoinOperator::OuterApply,
                 }
+            } else if dialect_of!(self is ClickHouseDialect | GenericDialect)
+                && matches!(
+                    self.peek_tokens(),
+                    [Token::Word(w1), Token::Word(w2)]
+                        if w1.keyword == Keyword::ARRAY && w2.keyword == Keyword::JOIN
+                        || w1.keyword == Keyword::LEFT && w2.keyword == Keyword::ARRAY
+                )
+            {
+                let join_operator = if self.parse_keyword(Keyword::LEFT) {
+                    JoinOperator::LeftArrayJoin
+                } else {
+                    JoinOperator::ArrayJoin
+                };
+                self.expect_keywords(&[Keyword::ARRAY, Keyword::JOIN])?;
+                let items = self.parse_comma_separated(Parser::parse_expr_with_alias)?;
+                Join {
+                    relation: TableFactor::ArrayJoinItems(items),
+                    global,
+                    join_operator,
+                }
+            } else if dialect_of!(self is MySqlDialect)
+                && self.parse_keyword(Keyword::STRAIGHT_JOIN)
+            {
+                let relation = self.parse_table_factor()?;
+                Join {
+                    relation,
+                    global,
+                    join_operator: JoinOperator::StraightJoin(self.parse_join_constraint(false)?),
+                }
             } else if self.parse_keyword(Keyword::ASOF) {
                 self.expect_keyword(Keyword::JOIN)?;
                 let relation = self.parse_table_factor()?;
-                self.expect_keyword(Keyword::MATCH_CONDITION)?;
-                let match_condition = self.parse_parenthesized(Self::parse_expr)?;
+                let match_condition = if self.parse_keyword(Keyword::MATCH_CONDITION) {
+                    Some(self.parse_parenthesized(Self::parse_expr)?)
+                } else {
+                    None
+                };
                 Join {
                     relation,
                     global,
@@ -9825,6 +12991,15 @@ impl<'a> Parser<'a> {
                             }
                         }
                     }
+                    kw @ Keyword::SEMI | kw @ Keyword::ANTI => {
+                        let _ = self.next_token(); // consume SEMI/ANTI
+                        self.expect_keyword(Keyword::JOIN)?;
+                        if kw == Keyword::SEMI {
+                            JoinOperator::Semi
+                        } else {
+                            JoinOperator::Anti
+                        }
+                    }
                     Keyword::FULL => {
                         let _ = self.next_token(); // consume FULL
                         let _ = self.parse_keyword(Keyword::OUTER); // [ OUTER ]
@@ -9858,15 +13033,22 @@ impl<'a> Parser<'a> {
             // LATERAL must always be followed by a subquery or table function.
             if self.consume_token(&Token::LParen) {
                 self.parse_derived_table_factor(Lateral)
+            } else if dialect_of!(self is PostgreSqlDialect | GenericDialect)
+                && self.parse_keywords(&[Keyword::ROWS, Keyword::FROM])
+            {
+                self.parse_rows_from_table_factor(Lateral)
             } else {
                 let name = self.parse_object_name(false)?;
                 self.expect_token(&Token::LParen)?;
                 let args = self.parse_optional_args()?;
-                let alias = self.parse_optional_table_alias(keywords::RESERVED_FOR_TABLE_ALIAS)?;
+                let with_ordinality = self.parse_keywords(&[Keyword::WITH, Keyword::ORDINALITY]);
+                let alias = self
+                    .parse_optional_table_alias(self.dialect.reserved_keywords_for_table_alias())?;
                 Ok(TableFactor::Function {
                     lateral: true,
                     name,
                     args,
+                    with_ordinality,
                     alias,
                 })
             }
@@ -9875,8 +13057,13 @@ impl<'a> Parser<'a> {
             self.expect_token(&Token::LParen)?;
             let expr = self.parse_expr()?;
             self.expect_token(&Token::RParen)?;
-            let alias = self.parse_optional_table_alias(keywords::RESERVED_FOR_TABLE_ALIAS)?;
+            let alias =
+                self.parse_optional_table_alias(self.dialect.reserved_keywords_for_table_alias())?;
             Ok(TableFactor::TableFunction { expr, alias })
+        } else if dialect_of!(self is PostgreSqlDialect | GenericDialect)
+            && self.parse_keywords(&[Keyword::ROWS, Keyword::FROM])
+        {
+            self.parse_rows_from_table_factor(NotLateral)
         } else if self.consume_token(&Token::LParen) {
             // A left paren introduces either a derived table (i.e., a subquery)
             // or a nested join. It's nearly impossible to determine ahead of
@@ -9924,7 +13111,8 @@ impl<'a> Parser<'a> {
             #[allow(clippy::if_same_then_else)]
             if !table_and_joins.joins.is_empty() {
                 self.expect_token(&Token::RParen)?;
-                let alias = self.parse_optional_table_alias(keywords::RESERVED_FOR_TABLE_ALIAS)?;
+                let alias = self
+                    .parse_optional_table_alias(self.dialect.reserved_keywords_for_table_alias())?;
                 Ok(TableFactor::NestedJoin {
                     table_with_joins: Box::new(table_and_joins),
                     alias,
@@ -9937,7 +13125,8 @@ impl<'a> Parser<'a> {
                 // (B): `table_and_joins` (what we found inside the parentheses)
                 // is a nested join `(foo JOIN bar)`, not followed by other joins.
                 self.expect_token(&Token::RParen)?;
-                let alias = self.parse_optional_table_alias(keywords::RESERVED_FOR_TABLE_ALIAS)?;
+                let alias = self
+                    .parse_optional_table_alias(self.dialect.reserved_keywords_for_table_alias())?;
                 Ok(TableFactor::NestedJoin {
                     table_with_joins: Box::new(table_and_joins),
                     alias,
@@ -9951,8 +13140,8 @@ impl<'a> Parser<'a> {
                 // [AS alias])`) as well.
                 self.expect_token(&Token::RParen)?;
 
-                if let Some(outer_alias) =
-                    self.parse_optional_table_alias(keywords::RESERVED_FOR_TABLE_ALIAS)?
+                if let Some(outer_alias) = self
+                    .parse_optional_table_alias(self.dialect.reserved_keywords_for_table_alias())?
                 {
                     // Snowflake also allows specifying an alias *after* parens
                     // e.g. `FROM (mytable) AS alias`
@@ -9960,6 +13149,7 @@ impl<'a> Parser<'a> {
                         TableFactor::Derived { alias, .. }
                         | TableFactor::Table { alias, .. }
                         | TableFactor::Function { alias, .. }
+                        | TableFactor::RowsFrom { alias, .. }
                         | TableFactor::UNNEST { alias, .. }
                         | TableFactor::JsonTable { alias, .. }
                         | TableFactor::TableFunction { alias, .. }
@@ -9978,6 +13168,8 @@ impl<'a> Parser<'a> {
                             // `(mytable AS alias)`
                             alias.replace(outer_alias);
                         }
+                        // only the relation of an ARRAY JOIN, never parsed here
+                        TableFactor::ArrayJoinItems(_) => {}
                     };
                 }
                 // Do not store the extra set of parens in the AST
@@ -10005,10 +13197,11 @@ impl<'a> Parser<'a> {
             // SELECT * FROM VALUES (1, 'a'), (2, 'b') AS t (col1, col2)
             // where there are no parentheses around the VALUES clause.
             let values = SetExpr::Values(self.parse_values(false)?);
-            let alias = self.parse_optional_table_alias(keywords::RESERVED_FOR_TABLE_ALIAS)?;
+            let alias =
+                self.parse_optional_table_alias(self.dialect.reserved_keywords_for_table_alias())?;
             Ok(TableFactor::Derived {
                 lateral: false,
-                subquery: Box::new(Query {
+                subquery: QueryRef::new(Query {
                     with: None,
                     body: Box::new(values),
                     order_by: None,
@@ -10020,6 +13213,7 @@ impl<'a> Parser<'a> {
                     for_clause: None,
                     settings: None,
                     format_clause: None,
+                    option_clause: None,
                 }),
                 alias,
             })
@@ -10031,7 +13225,9 @@ impl<'a> Parser<'a> {
             self.expect_token(&Token::RParen)?;
 
             let with_ordinality = self.parse_keywords(&[Keyword::WITH, Keyword::ORDINALITY]);
-            let alias = match self.parse_optional_table_alias(keywords::RESERVED_FOR_TABLE_ALIAS) {
+            let alias = match self
+                .parse_optional_table_alias(self.dialect.reserved_keywords_for_table_alias())
+            {
                 Ok(Some(alias)) => Some(alias),
                 Ok(None) => None,
                 Err(e) => return Err(e),
@@ -10043,7 +13239,7 @@ impl<'a> Parser<'a> {
             };
 
             let with_offset_alias = if with_offset {
-                match self.parse_optional_alias(keywords::RESERVED_FOR_COLUMN_ALIAS) {
+                match self.parse_optional_alias(self.dialect.reserved_keywords_for_column_alias()) {
                     Ok(Some(alias)) => Some(alias),
                     Ok(None) => None,
                     Err(e) => return Err(e),
@@ -10068,7 +13264,8 @@ impl<'a> Parser<'a> {
             let columns = self.parse_comma_separated(Parser::parse_json_table_column_def)?;
             self.expect_token(&Token::RParen)?;
             self.expect_token(&Token::RParen)?;
-            let alias = self.parse_optional_table_alias(keywords::RESERVED_FOR_TABLE_ALIAS)?;
+            let alias =
+                self.parse_optional_table_alias(self.dialect.reserved_keywords_for_table_alias())?;
             Ok(TableFactor::JsonTable {
                 json_expr,
                 json_path,
@@ -10098,13 +13295,14 @@ impl<'a> Parser<'a> {
 
             let with_ordinality = self.parse_keywords(&[Keyword::WITH, Keyword::ORDINALITY]);
 
-            let alias = self.parse_optional_table_alias(keywords::RESERVED_FOR_TABLE_ALIAS)?;
+            let alias =
+                self.parse_optional_table_alias(self.dialect.reserved_keywords_for_table_alias())?;
 
             // MSSQL-specific table hints:
             let mut with_hints = vec![];
             if self.parse_keyword(Keyword::WITH) {
                 if self.consume_token(&Token::LParen) {
-                    with_hints = self.parse_comma_separated(Parser::parse_expr)?;
+                    with_hints = self.parse_comma_separated(Parser::parse_table_hint)?;
                     self.expect_token(&Token::RParen)?;
                 } else {
                     // rewind, as WITH may belong to the next statement's CTE
@@ -10219,18 +13417,32 @@ impl<'a> Parser<'a> {
         self.expect_keyword(Keyword::PATTERN)?;
         let pattern = self.parse_parenthesized(Self::parse_pattern)?;
 
-        self.expect_keyword(Keyword::DEFINE)?;
+        let subsets = if self.parse_keyword(Keyword::SUBSET) {
+            self.parse_comma_separated(|p| {
+                let name = p.parse_identifier(false)?;
+                p.expect_token(&Token::Eq)?;
+                let symbols = p.parse_parenthesized_column_list(Mandatory, false)?;
+                Ok(SubsetDefinition { name, symbols })
+            })?
+        } else {
+            vec![]
+        };
 
-        let symbols = self.parse_comma_separated(|p| {
-            let symbol = p.parse_identifier(false)?;
-            p.expect_keyword(Keyword::AS)?;
-            let definition = p.parse_expr()?;
-            Ok(SymbolDefinition { symbol, definition })
-        })?;
+        let symbols = if self.parse_keyword(Keyword::DEFINE) {
+            self.parse_comma_separated(|p| {
+                let symbol = p.parse_identifier(false)?;
+                p.expect_keyword(Keyword::AS)?;
+                let definition = p.parse_expr()?;
+                Ok(SymbolDefinition { symbol, definition })
+            })?
+        } else {
+            vec![]
+        };
 
         self.expect_token(&Token::RParen)?;
 
-        let alias = self.parse_optional_table_alias(keywords::RESERVED_FOR_TABLE_ALIAS)?;
+        let alias =
+            self.parse_optional_table_alias(self.dialect.reserved_keywords_for_table_alias())?;
 
         Ok(TableFactor::MatchRecognize {
             table: Box::new(table),
@@ -10240,6 +13452,7 @@ impl<'a> Parser<'a> {
             rows_per_match,
             after_match_skip,
             pattern,
+            subsets,
             symbols,
             alias,
         })
@@ -10253,12 +13466,10 @@ impl<'a> Parser<'a> {
             }
             Token::LBrace => {
                 self.expect_token(&Token::Minus)?;
-                let symbol = self
-                    .parse_identifier(false)
-                    .map(MatchRecognizeSymbol::Named)?;
+                let pattern = self.parse_pattern()?;
                 self.expect_token(&Token::Minus)?;
                 self.expect_token(&Token::RBrace)?;
-                Ok(MatchRecognizePattern::Exclude(symbol))
+                Ok(MatchRecognizePattern::Exclude(Box::new(pattern)))
             }
             Token::Word(Word {
                 value,
@@ -10294,7 +13505,8 @@ impl<'a> Parser<'a> {
                 Token::Mul => RepetitionQuantifier::ZeroOrMore,
                 Token::Plus => RepetitionQuantifier::OneOrMore,
                 Token::Placeholder(s) if s == "?" => RepetitionQuantifier::AtMostOne,
-                Token::LBrace => {
+                // `{-` starts an exclusion rather than a quantifier
+                Token::LBrace if self.peek_token().token != Token::Minus => {
                     // quantifier is a range like {n} or {n,} or {,m} or {n,m}
                     let token = self.next_token();
                     match token.token {
@@ -10343,7 +13555,11 @@ impl<'a> Parser<'a> {
 
     fn parse_concat_pattern(&mut self) -> Result<MatchRecognizePattern, ParserError> {
         let mut patterns = vec![self.parse_repetition_pattern()?];
-        while !matches!(self.peek_token().token, Token::RParen | Token::Pipe) {
+        // `-` can only appear as the end of an exclusion, i.e. `-}`
+        while !matches!(
+            self.peek_token().token,
+            Token::RParen | Token::Pipe | Token::Minus
+        ) {
             patterns.push(self.parse_repetition_pattern()?);
         }
         match <[MatchRecognizePattern; 1]>::try_from(patterns) {
@@ -10426,13 +13642,53 @@ impl<'a> Parser<'a> {
         Ok(Some(res))
     }
 
+    /// Parse a MSSQL table hint of a `WITH (...)` clause
+    pub fn parse_table_hint(&mut self) -> Result<TableHint, ParserError> {
+        match &self.peek_tokens() {
+            [Token::Word(_), Token::Comma | Token::RParen] => {
+                return Ok(TableHint::Keyword(self.parse_identifier(false)?));
+            }
+            [Token::Word(w), Token::Eq | Token::LParen] if w.keyword == Keyword::INDEX => {
+                self.next_token();
+                if self.consume_token(&Token::Eq) {
+                    return Ok(TableHint::Index {
+                        indexes: vec![self.parse_expr()?],
+                        equals: true,
+                    });
+                }
+                self.expect_token(&Token::LParen)?;
+                let indexes = self.parse_comma_separated(Parser::parse_expr)?;
+                self.expect_token(&Token::RParen)?;
+                return Ok(TableHint::Index {
+                    indexes,
+                    equals: false,
+                });
+            }
+            [Token::Word(w), Token::LParen] if w.keyword == Keyword::FORCESEEK => {
+                self.next_token();
+                self.expect_token(&Token::LParen)?;
+                // the index is a name or an id
+                let index = match self.peek_token().token {
+                    Token::Number(..) => Expr::Value(self.parse_number_value()?),
+                    _ => Expr::Identifier(self.parse_identifier(false)?),
+                };
+                let columns = self.parse_parenthesized_column_list(IsOptional::Mandatory, false)?;
+                self.expect_token(&Token::RParen)?;
+                return Ok(TableHint::ForceSeek { index, columns });
+            }
+            _ => {}
+        }
+        Ok(TableHint::Expr(self.parse_expr()?))
+    }
+
     pub fn parse_derived_table_factor(
         &mut self,
         lateral: IsLateral,
     ) -> Result<TableFactor, ParserError> {
-        let subquery = self.parse_boxed_query()?;
+        let subquery = QueryRef::new(self.parse_query()?);
         self.expect_token(&Token::RParen)?;
-        let alias = self.parse_optional_table_alias(keywords::RESERVED_FOR_TABLE_ALIAS)?;
+        let alias =
+            self.parse_optional_table_alias(self.dialect.reserved_keywords_for_table_alias())?;
         Ok(TableFactor::Derived {
             lateral: match lateral {
                 Lateral => true,
@@ -10443,6 +13699,40 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Parses a PostgreSQL `ROWS FROM (...)` table factor, after the
+    /// `ROWS FROM` keywords
+    pub fn parse_rows_from_table_factor(
+        &mut self,
+        lateral: IsLateral,
+    ) -> Result<TableFactor, ParserError> {
+        self.expect_token(&Token::LParen)?;
+        let functions = self.parse_comma_separated(|parser| {
+            let expr = parser.parse_expr()?;
+            let columns = if parser.parse_keyword(Keyword::AS) {
+                parser.expect_token(&Token::LParen)?;
+                let columns = parser.parse_comma_separated(Parser::parse_table_alias_column_def)?;
+                parser.expect_token(&Token::RParen)?;
+                columns
+            } else {
+                vec![]
+            };
+            Ok(RowsFromFunction { expr, columns })
+        })?;
+        self.expect_token(&Token::RParen)?;
+        let with_ordinality = self.parse_keywords(&[Keyword::WITH, Keyword::ORDINALITY]);
+        let alias =
+            self.parse_optional_table_alias(self.dialect.reserved_keywords_for_table_alias())?;
+        Ok(TableFactor::RowsFrom {
+            lateral: match lateral {
+                Lateral => true,
+                NotLateral => false,
+            },
+            functions,
+            with_ordinality,
+            alias,
+        })
+    }
+
     fn parse_aliased_function_call(&mut self) -> Result<ExprWithAlias, ParserError> {
         let function_name = match self.next_token().token {
             Token::Word(w) => Ok(w.value),
@@ -10458,13 +13748,13 @@ impl<'a> Parser<'a> {
         Ok(ExprWithAlias { expr, alias })
     }
     /// Parses an expression with an optional alias
-
+    ///
     /// Examples:
-
+    ///
     /// ```sql
     /// SUM(price) AS total_price
     /// ```
-
+    ///
     /// ```sql
     /// SUM(price)
     /// ```
@@ -10477,10 +13767,10 @@ impl<'a> Parser<'a> {
     /// let sql = r#"SUM("a") as "b""#;
     /// let mut parser = Parser::new(&GenericDialect).try_with_sql(sql)?;
     /// let expr_with_alias = parser.parse_expr_with_alias()?;
-    /// assert_eq!(Some("b".to_string()), expr_with_alias.alias.map(|x|x.value));
+    /// assert_eq!(Some("b".to_string()), expr_with_alias.alias.map(|x|x.value.to_string()));
     /// # Ok(())
     /// # }
-
+    /// ```
     pub fn parse_expr_with_alias(&mut self) -> Result<ExprWithAlias, ParserError> {
         let expr = self.parse_expr()?;
         let alias = if self.parse_keyword(Keyword::AS) {
@@ -10528,7 +13818,8 @@ impl<'a> Parser<'a> {
             };
 
         self.expect_token(&Token::RParen)?;
-        let alias = self.parse_optional_table_alias(keywords::RESERVED_FOR_TABLE_ALIAS)?;
+        let alias =
+            self.parse_optional_table_alias(self.dialect.reserved_keywords_for_table_alias())?;
         Ok(TableFactor::Pivot {
             table: Box::new(table),
             aggregate_functions,
@@ -10550,7 +13841,8 @@ impl<'a> Parser<'a> {
         self.expect_keyword(Keyword::IN)?;
         let columns = self.parse_parenthesized_column_list(Mandatory, false)?;
         self.expect_token(&Token::RParen)?;
-        let alias = self.parse_optional_table_alias(keywords::RESERVED_FOR_TABLE_ALIAS)?;
+        let alias =
+            self.parse_optional_table_alias(self.dialect.reserved_keywords_for_table_alias())?;
         Ok(TableFactor::Unpivot {
             table: Box::new(table),
             value,
@@ -10568,19 +13860,80 @@ impl<'a> Parser<'a> {
             Ok(JoinConstraint::On(constraint))
         } else if self.parse_keyword(Keyword::USING) {
             let columns = self.parse_parenthesized_column_list(Mandatory, false)?;
-            Ok(JoinConstraint::Using(columns))
+            let alias = if self.parse_keyword(Keyword::AS) {
+                Some(self.parse_identifier(false)?)
+            } else {
+                None
+            };
+            Ok(JoinConstraint::Using { columns, alias })
         } else {
             Ok(JoinConstraint::None)
             //self.expected("ON, or USING after JOIN", self.peek_token())
         }
     }
 
+    /// Parse a MySQL `SET PASSWORD` statement, after the `SET PASSWORD`
+    fn parse_set_password(&mut self) -> Result<Statement, ParserError> {
+        let user = if self.parse_keyword(Keyword::FOR) {
+            Some(self.parse_account_name()?)
+        } else {
+            None
+        };
+        let password = if self.parse_keywords(&[Keyword::TO, Keyword::RANDOM]) {
+            SetPasswordValue::Random
+        } else {
+            self.expect_token(&Token::Eq)?;
+            SetPasswordValue::Expr(self.parse_expr()?)
+        };
+        let replace = if self.parse_keyword(Keyword::REPLACE) {
+            Some(self.parse_value()?)
+        } else {
+            None
+        };
+        let retain_current_password =
+            self.parse_keywords(&[Keyword::RETAIN, Keyword::CURRENT, Keyword::PASSWORD]);
+        Ok(Statement::SetPassword {
+            user,
+            password,
+            replace,
+            retain_current_password,
+        })
+    }
+
     /// Parse a GRANT statement.
     pub fn parse_grant(&mut self) -> Result<Statement, ParserError> {
+        if dialect_of!(self is MySqlDialect | GenericDialect)
+            && self.parse_keywords(&[Keyword::PROXY, Keyword::ON])
+        {
+            let proxied_user = self.parse_account_name()?;
+            self.expect_keyword(Keyword::TO)?;
+            let grantees = self.parse_comma_separated(Parser::parse_account_name)?;
+            let with_grant_option =
+                self.parse_keywords(&[Keyword::WITH, Keyword::GRANT, Keyword::OPTION]);
+            return Ok(Statement::GrantProxy {
+                proxied_user,
+                grantees,
+                with_grant_option,
+            });
+        }
+
+        if dialect_of!(self is SnowflakeDialect | GenericDialect)
+            && self.parse_keyword(Keyword::ROLE)
+        {
+            let roles = self.parse_comma_separated(|p| p.parse_identifier(false))?;
+            self.expect_keyword(Keyword::TO)?;
+            let (grantees_type, grantees) = self.parse_grantees()?;
+            return Ok(Statement::GrantRole {
+                roles,
+                grantees_type,
+                grantees,
+            });
+        }
+
         let (privileges, objects) = self.parse_grant_revoke_privileges_objects()?;
 
         self.expect_keyword(Keyword::TO)?;
-        let grantees = self.parse_comma_separated(|p| p.parse_identifier(false))?;
+        let (grantees_type, grantees) = self.parse_grantees()?;
 
         let with_grant_option =
             self.parse_keywords(&[Keyword::WITH, Keyword::GRANT, Keyword::OPTION]);
@@ -10592,12 +13945,30 @@ impl<'a> Parser<'a> {
         Ok(Statement::Grant {
             privileges,
             objects,
+            grantees_type,
             grantees,
             with_grant_option,
             granted_by,
         })
     }
 
+    /// Parse the grantees of a GRANT or REVOKE, optionally preceded by their
+    /// kind as in Snowflake's `TO ROLE r`
+    fn parse_grantees(&mut self) -> Result<(Option<GranteesType>, Vec<Ident>), ParserError> {
+        let grantees_type = if dialect_of!(self is SnowflakeDialect | GenericDialect) {
+            match self.parse_one_of_keywords(&[Keyword::ROLE, Keyword::USER, Keyword::SHARE]) {
+                Some(Keyword::ROLE) => Some(GranteesType::Role),
+                Some(Keyword::USER) => Some(GranteesType::User),
+                Some(Keyword::SHARE) => Some(GranteesType::Share),
+                _ => None,
+            }
+        } else {
+            None
+        };
+        let grantees = self.parse_comma_separated(|p| p.parse_identifier(false))?;
+        Ok((grantees_type, grantees))
+    }
+
     pub fn parse_grant_revoke_privileges_objects(
         &mut self,
     ) -> Result<(Privileges, GrantObjects), ParserError> {
@@ -10659,13 +14030,28 @@ impl<'a> Parser<'a> {
             GrantObjects::AllSequencesInSchema {
                 schemas: self.parse_comma_separated(|p| p.parse_object_name(false))?,
             }
+        } else if self.parse_keyword(Keyword::FUNCTION) {
+            GrantObjects::Functions(self.parse_comma_separated(Parser::parse_function_desc)?)
+        } else if self.parse_keyword(Keyword::PROCEDURE) {
+            GrantObjects::Procedures(self.parse_comma_separated(Parser::parse_function_desc)?)
         } else {
-            let object_type =
-                self.parse_one_of_keywords(&[Keyword::SEQUENCE, Keyword::SCHEMA, Keyword::TABLE]);
+            let mut object_types = vec![
+                Keyword::SEQUENCE,
+                Keyword::SCHEMA,
+                Keyword::TABLE,
+                Keyword::DATABASE,
+            ];
+            if dialect_of!(self is SnowflakeDialect | GenericDialect) {
+                object_types.extend([Keyword::STAGE, Keyword::WAREHOUSE]);
+            }
+            let object_type = self.parse_one_of_keywords(&object_types);
             let objects = self.parse_comma_separated(|p| p.parse_object_name(false));
             match object_type {
                 Some(Keyword::SCHEMA) => GrantObjects::Schemas(objects?),
                 Some(Keyword::SEQUENCE) => GrantObjects::Sequences(objects?),
+                Some(Keyword::DATABASE) => GrantObjects::Databases(objects?),
+                Some(Keyword::STAGE) => GrantObjects::Stages(objects?),
+                Some(Keyword::WAREHOUSE) => GrantObjects::Warehouses(objects?),
                 Some(Keyword::TABLE) | None => GrantObjects::Tables(objects?),
                 _ => unreachable!(),
             }
@@ -10708,10 +14094,35 @@ impl<'a> Parser<'a> {
 
     /// Parse a REVOKE statement
     pub fn parse_revoke(&mut self) -> Result<Statement, ParserError> {
+        if dialect_of!(self is MySqlDialect | GenericDialect)
+            && self.parse_keywords(&[Keyword::PROXY, Keyword::ON])
+        {
+            let proxied_user = self.parse_account_name()?;
+            self.expect_keyword(Keyword::FROM)?;
+            let grantees = self.parse_comma_separated(Parser::parse_account_name)?;
+            return Ok(Statement::RevokeProxy {
+                proxied_user,
+                grantees,
+            });
+        }
+
+        if dialect_of!(self is SnowflakeDialect | GenericDialect)
+            && self.parse_keyword(Keyword::ROLE)
+        {
+            let roles = self.parse_comma_separated(|p| p.parse_identifier(false))?;
+            self.expect_keyword(Keyword::FROM)?;
+            let (grantees_type, grantees) = self.parse_grantees()?;
+            return Ok(Statement::RevokeRole {
+                roles,
+                grantees_type,
+                grantees,
+            });
+        }
+
         let (privileges, objects) = self.parse_grant_revoke_privileges_objects()?;
 
         self.expect_keyword(Keyword::FROM)?;
-        let grantees = self.parse_comma_separated(|p| p.parse_identifier(false))?;
+        let (grantees_type, grantees) = self.parse_grantees()?;
 
         let granted_by = self
             .parse_keywords(&[Keyword::GRANTED, Keyword::BY])
@@ -10727,6 +14138,7 @@ impl<'a> Parser<'a> {
         Ok(Statement::Revoke {
             privileges,
             objects,
+            grantees_type,
             grantees,
             granted_by,
             cascade,
@@ -10756,6 +14168,12 @@ impl<'a> Parser<'a> {
 
     /// Parse an INSERT statement
     pub fn parse_insert(&mut self) -> Result<Statement, ParserError> {
+        if dialect_of!(self is SnowflakeDialect | OracleDialect | GenericDialect) {
+            if let Some(insert) = self.maybe_parse_multi_table_insert()? {
+                return Ok(insert);
+            }
+        }
+
         let or = if !dialect_of!(self is SQLiteDialect) {
             None
         } else if self.parse_keywords(&[Keyword::OR, Keyword::REPLACE]) {
@@ -10826,23 +14244,35 @@ impl<'a> Parser<'a> {
 
             let is_mysql = dialect_of!(self is MySqlDialect);
 
-            let (columns, partitioned, after_columns, source) =
+            let (columns, partitioned, after_columns, source, assignments) =
                 if self.parse_keywords(&[Keyword::DEFAULT, Keyword::VALUES]) {
-                    (vec![], None, vec![], None)
+                    (vec![], None, vec![], None, vec![])
+                } else if dialect_of!(self is MySqlDialect | GenericDialect)
+                    && self.parse_keyword(Keyword::SET)
+                {
+                    let assignments = self.parse_comma_separated(Parser::parse_assignment)?;
+                    (vec![], None, vec![], None, assignments)
                 } else {
-                    let columns = self.parse_parenthesized_column_list(Optional, is_mysql)?;
+                    // `INSERT INTO t (SELECT ...)` has a parenthesized query
+                    // rather than a column list
+                    let columns = if self.peek_parenthesized_query() {
+                        vec![]
+                    } else {
+                        self.parse_parenthesized_column_list(Optional, is_mysql)?
+                    };
 
                     let partitioned = self.parse_insert_partition()?;
                     // Hive allows you to specify columns after partitions as well if you want.
-                    let after_columns = if dialect_of!(self is HiveDialect) {
-                        self.parse_parenthesized_column_list(Optional, false)?
-                    } else {
-                        vec![]
-                    };
+                    let after_columns =
+                        if dialect_of!(self is HiveDialect) && !self.peek_parenthesized_query() {
+                            self.parse_parenthesized_column_list(Optional, false)?
+                        } else {
+                            vec![]
+                        };
 
                     let source = Some(self.parse_boxed_query()?);
 
-                    (columns, partitioned, after_columns, source)
+                    (columns, partitioned, after_columns, source, vec![])
                 };
 
             let insert_alias = if dialect_of!(self is MySqlDialect | GenericDialect)
@@ -10858,15 +14288,14 @@ impl<'a> Parser<'a> {
                 None
             };
 
-            let on = if self.parse_keyword(Keyword::ON) {
+            let mut on = vec![];
+            while self.parse_keyword(Keyword::ON) {
                 if self.parse_keyword(Keyword::CONFLICT) {
                     let conflict_target =
                         if self.parse_keywords(&[Keyword::ON, Keyword::CONSTRAINT]) {
                             Some(ConflictTarget::OnConstraint(self.parse_object_name(false)?))
                         } else if self.peek_token() == Token::LParen {
-                            Some(ConflictTarget::Columns(
-                                self.parse_parenthesized_column_list(IsOptional::Mandatory, false)?,
-                            ))
+                            Some(self.parse_conflict_index_target()?)
                         } else {
                             None
                         };
@@ -10889,21 +14318,24 @@ impl<'a> Parser<'a> {
                         })
                     };
 
-                    Some(OnInsert::OnConflict(OnConflict {
+                    on.push(OnInsert::OnConflict(OnConflict {
                         conflict_target,
                         action,
-                    }))
+                    }));
                 } else {
                     self.expect_keyword(Keyword::DUPLICATE)?;
                     self.expect_keyword(Keyword::KEY)?;
                     self.expect_keyword(Keyword::UPDATE)?;
                     let l = self.parse_comma_separated(Parser::parse_assignment)?;
 
-                    Some(OnInsert::DuplicateKeyUpdate(l))
+                    on.push(OnInsert::DuplicateKeyUpdate(l));
                 }
-            } else {
-                None
-            };
+                // SQLite allows an `ON CONFLICT` clause per uniqueness constraint
+                // https://www.sqlite.org/lang_upsert.html
+                if !dialect_of!(self is SQLiteDialect | GenericDialect) {
+                    break;
+                }
+            }
 
             let returning = if self.parse_keyword(Keyword::RETURNING) {
                 Some(self.parse_comma_separated(Parser::parse_select_item)?)
@@ -10922,6 +14354,7 @@ impl<'a> Parser<'a> {
                 columns,
                 after_columns,
                 source,
+                assignments,
                 table,
                 on,
                 returning,
@@ -10932,6 +14365,112 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parse a parenthesized `ON CONFLICT` target with an optional partial
+    /// index predicate: `( { column | expression } [, ...] ) [ WHERE predicate ]`
+    fn parse_conflict_index_target(&mut self) -> Result<ConflictTarget, ParserError> {
+        self.expect_token(&Token::LParen)?;
+        let exprs = self.parse_comma_separated(Parser::parse_expr)?;
+        self.expect_token(&Token::RParen)?;
+        let predicate = if self.parse_keyword(Keyword::WHERE) {
+            Some(self.parse_expr()?)
+        } else {
+            None
+        };
+        if predicate.is_none() && exprs.iter().all(|e| matches!(e, Expr::Identifier(_))) {
+            let columns = exprs
+                .into_iter()
+                .map(|e| match e {
+                    Expr::Identifier(ident) => ident,
+                    _ => unreachable!(),
+                })
+                .collect();
+            return Ok(ConflictTarget::Columns(columns));
+        }
+        Ok(ConflictTarget::IndexInference { exprs, predicate })
+    }
+
+    /// Parse the remainder of a multi-table `INSERT { ALL | FIRST } ...`
+    /// statement, if the next tokens start one.
+    fn maybe_parse_multi_table_insert(&mut self) -> Result<Option<Statement>, ParserError> {
+        let index = self.index;
+        let overwrite = self.parse_keyword(Keyword::OVERWRITE);
+        let kind = match self.parse_one_of_keywords(&[Keyword::ALL, Keyword::FIRST]) {
+            Some(Keyword::ALL) => MultiTableInsertKind::All,
+            Some(Keyword::FIRST) => MultiTableInsertKind::First,
+            _ => {
+                self.index = index;
+                return Ok(None);
+            }
+        };
+
+        let into_clauses = if kind == MultiTableInsertKind::All {
+            self.parse_multi_table_insert_into_clauses()?
+        } else {
+            vec![]
+        };
+        let mut when_clauses = vec![];
+        if into_clauses.is_empty() {
+            while self.parse_keyword(Keyword::WHEN) {
+                let condition = self.parse_expr()?;
+                self.expect_keyword(Keyword::THEN)?;
+                let into_clauses = self.parse_multi_table_insert_into_clauses()?;
+                if into_clauses.is_empty() {
+                    return self.expected("INTO", self.peek_token());
+                }
+                when_clauses.push(MultiTableInsertWhenClause {
+                    condition,
+                    into_clauses,
+                });
+            }
+            if when_clauses.is_empty() {
+                return self.expected("INTO or WHEN", self.peek_token());
+            }
+        }
+        let else_clause = if !when_clauses.is_empty() && self.parse_keyword(Keyword::ELSE) {
+            let into_clauses = self.parse_multi_table_insert_into_clauses()?;
+            if into_clauses.is_empty() {
+                return self.expected("INTO", self.peek_token());
+            }
+            Some(into_clauses)
+        } else {
+            None
+        };
+        let source = self.parse_boxed_query()?;
+
+        Ok(Some(Statement::MultiTableInsert(MultiTableInsert {
+            overwrite,
+            kind,
+            into_clauses,
+            when_clauses,
+            else_clause,
+            source,
+        })))
+    }
+
+    fn parse_multi_table_insert_into_clauses(
+        &mut self,
+    ) -> Result<Vec<MultiTableInsertIntoClause>, ParserError> {
+        let mut clauses = vec![];
+        while self.parse_keyword(Keyword::INTO) {
+            let table_name = self.parse_object_name(false)?;
+            let columns = self.parse_parenthesized_column_list(Optional, false)?;
+            let values = if self.parse_keyword(Keyword::VALUES) {
+                self.expect_token(&Token::LParen)?;
+                let values = self.parse_comma_separated(Parser::parse_expr)?;
+                self.expect_token(&Token::RParen)?;
+                Some(values)
+            } else {
+                None
+            };
+            clauses.push(MultiTableInsertIntoClause {
+                table_name,
+                columns,
+                values,
+            });
+        }
+        Ok(clauses)
+    }
+
     pub fn parse_insert_partition(&mut self) -> Result<Option<Vec<Expr>>, ParserError> {
         if self.parse_keyword(Keyword::PARTITION) {
             self.expect_token(&Token::LParen)?;
@@ -10957,9 +14496,9 @@ impl<'a> Parser<'a> {
         let from = if self.parse_keyword(Keyword::FROM)
             && dialect_of!(self is GenericDialect | PostgreSqlDialect | DuckDbDialect | BigQueryDialect | SnowflakeDialect | RedshiftSqlDialect | MsSqlDialect | SQLiteDialect )
         {
-            Some(self.parse_table_and_joins()?)
+            self.parse_comma_separated(Parser::parse_table_and_joins)?
         } else {
-            None
+            vec![]
         };
         let selection = if self.parse_keyword(Keyword::WHERE) {
             Some(self.parse_expr()?)
@@ -10996,7 +14535,28 @@ impl<'a> Parser<'a> {
             Ok(AssignmentTarget::Tuple(columns))
         } else {
             let column = self.parse_object_name(false)?;
-            Ok(AssignmentTarget::ColumnName(column))
+            if !dialect_of!(self is PostgreSqlDialect | DuckDbDialect | GenericDialect)
+                || self.peek_token() != Token::LBracket
+            {
+                return Ok(AssignmentTarget::ColumnName(column));
+            }
+            let mut element = match column.0.len() {
+                1 => Expr::Identifier(column.0.into_iter().next().unwrap()),
+                _ => Expr::CompoundIdentifier(column.0),
+            };
+            loop {
+                if self.consume_token(&Token::LBracket) {
+                    element = self.parse_subscript(element)?;
+                } else if self.consume_token(&Token::Period) {
+                    element = Expr::CompositeAccess {
+                        expr: Box::new(element),
+                        key: self.parse_identifier(false)?,
+                    };
+                } else {
+                    break;
+                }
+            }
+            Ok(AssignmentTarget::Element(element))
         }
     }
 
@@ -11174,7 +14734,7 @@ impl<'a> Parser<'a> {
                 )
             }
             expr => self
-                .parse_optional_alias(keywords::RESERVED_FOR_COLUMN_ALIAS)
+                .parse_optional_alias(self.dialect.reserved_keywords_for_column_alias())
                 .map(|alias| match alias {
                     Some(alias) => SelectItem::ExprWithAlias { expr, alias },
                     None => SelectItem::UnnamedExpr(expr),
@@ -11373,15 +14933,7 @@ impl<'a> Parser<'a> {
     pub fn parse_order_by_expr(&mut self) -> Result<OrderByExpr, ParserError> {
         let expr = self.parse_expr()?;
 
-        let asc = self.parse_asc_desc();
-
-        let nulls_first = if self.parse_keywords(&[Keyword::NULLS, Keyword::FIRST]) {
-            Some(true)
-        } else if self.parse_keywords(&[Keyword::NULLS, Keyword::LAST]) {
-            Some(false)
-        } else {
-            None
-        };
+        let OrderByOptions { asc, nulls_first } = self.parse_order_by_options();
 
         let with_fill = if dialect_of!(self is ClickHouseDialect | GenericDialect)
             && self.parse_keywords(&[Keyword::WITH, Keyword::FILL])
@@ -11399,6 +14951,21 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Parse the optional `ASC`/`DESC` and `NULLS FIRST`/`NULLS LAST` of an `ORDER BY` item
+    fn parse_order_by_options(&mut self) -> OrderByOptions {
+        let asc = self.parse_asc_desc();
+
+        let nulls_first = if self.parse_keywords(&[Keyword::NULLS, Keyword::FIRST]) {
+            Some(true)
+        } else if self.parse_keywords(&[Keyword::NULLS, Keyword::LAST]) {
+            Some(false)
+        } else {
+            None
+        };
+
+        OrderByOptions { asc, nulls_first }
+    }
+
     // Parse a WITH FILL clause (ClickHouse dialect)
     // that follow the WITH FILL keywords in a ORDER BY clause
     pub fn parse_with_fill(&mut self) -> Result<WithFill, ParserError> {
@@ -11540,9 +15107,9 @@ impl<'a> Parser<'a> {
             _ => unreachable!(),
         };
         let of = if self.parse_keyword(Keyword::OF) {
-            Some(self.parse_object_name(false)?)
+            self.parse_comma_separated(|p| p.parse_object_name(false))?
         } else {
-            None
+            vec![]
         };
         let nonblock = if self.parse_keyword(Keyword::NOWAIT) {
             Some(NonBlock::Nowait)
@@ -11798,8 +15365,17 @@ impl<'a> Parser<'a> {
                         )));
                     }
                     self.expect_keyword(Keyword::SET)?;
+                    let assignments = self.parse_comma_separated(Parser::parse_assignment)?;
+                    let delete_where = if dialect_of!(self is GenericDialect)
+                        && self.parse_keywords(&[Keyword::DELETE, Keyword::WHERE])
+                    {
+                        Some(self.parse_expr()?)
+                    } else {
+                        None
+                    };
                     MergeAction::Update {
-                        assignments: self.parse_comma_separated(Parser::parse_assignment)?,
+                        assignments,
+                        delete_where,
                     }
                 }
                 Some(Keyword::DELETE) => {
@@ -12063,18 +15639,64 @@ impl<'a> Parser<'a> {
         Ok(NamedWindowDefinition(ident, window_expr))
     }
 
-    pub fn parse_create_procedure(&mut self, or_alter: bool) -> Result<Statement, ParserError> {
+    pub fn parse_create_procedure(
+        &mut self,
+        or_alter: bool,
+        or_replace: bool,
+    ) -> Result<Statement, ParserError> {
         let name = self.parse_object_name(false)?;
         let params = self.parse_optional_procedure_parameters()?;
-        self.expect_keyword(Keyword::AS)?;
-        self.expect_keyword(Keyword::BEGIN)?;
-        let statements = self.parse_statements()?;
-        self.expect_keyword(Keyword::END)?;
+        let return_type = if self.parse_keyword(Keyword::RETURNS) {
+            Some(self.parse_data_type()?)
+        } else {
+            None
+        };
+        let mut language = None;
+        let mut properties = HandlerProperties::default();
+        loop {
+            if language.is_none() && self.parse_keyword(Keyword::LANGUAGE) {
+                language = Some(self.parse_identifier(false)?);
+            } else if !self.parse_handler_property(&mut properties)? {
+                break;
+            }
+        }
+        let has_as = self.parse_keyword(Keyword::AS);
+        let body = if !has_as && dialect_of!(self is MySqlDialect) {
+            // MySQL routine bodies are not preceded by `AS`
+            CreateProcedureBody::Routine(Box::new(self.parse_script_statement()?))
+        } else {
+            if !has_as {
+                return self.expected("AS", self.peek_token());
+            }
+            if self.parse_keyword(Keyword::BEGIN) {
+                CreateProcedureBody::Statements(self.parse_statements_until_end()?)
+            } else {
+                let definition = self.parse_create_function_body_string()?;
+                let script = if dialect_of!(self is SnowflakeDialect | GenericDialect)
+                    && Self::is_script_language(&language, "SQL")
+                {
+                    self.parse_script_body(&definition)?
+                } else {
+                    None
+                };
+                match script {
+                    Some(script) => CreateProcedureBody::Script(script),
+                    None => CreateProcedureBody::Definition(definition),
+                }
+            }
+        };
         Ok(Statement::CreateProcedure {
             name,
             or_alter,
+            or_replace,
             params,
-            body: statements,
+            return_type,
+            language,
+            runtime_version: properties.runtime_version,
+            packages: properties.packages,
+            imports: properties.imports,
+            handler: properties.handler,
+            body,
         })
     }
 
@@ -12177,7 +15799,21 @@ impl<'a> Parser<'a> {
         self.tokens
     }
 
-    /// Returns true if the next keyword indicates a sub query, i.e. SELECT or WITH
+    /// Returns true if the next tokens start a parenthesized query, e.g.
+    /// `(SELECT ...` or `((SELECT ...) UNION ...`, without consuming them.
+    fn peek_parenthesized_query(&self) -> bool {
+        if self.peek_token().token != Token::LParen {
+            return false;
+        }
+        match self.peek_nth_token(1).token {
+            Token::Word(w) => matches!(w.keyword, Keyword::SELECT | Keyword::WITH),
+            Token::LParen => true,
+            _ => false,
+        }
+    }
+
+    /// Returns true if the next keyword indicates a sub query, i.e. SELECT or
+    /// WITH, or the `TABLE t` and `VALUES (...)` shorthands
     fn peek_sub_query(&mut self) -> bool {
         if self
             .parse_one_of_keywords(&[Keyword::SELECT, Keyword::WITH])
@@ -12186,14 +15822,97 @@ impl<'a> Parser<'a> {
             self.prev_token();
             return true;
         }
-        false
+        match self.peek_tokens() {
+            [Token::Word(w), Token::Word(_)] => w.keyword == Keyword::TABLE,
+            // `VALUES(col)` is a function in MySQL
+            [Token::Word(w), Token::LParen] => {
+                w.keyword == Keyword::VALUES && !dialect_of!(self is MySqlDialect)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Returns true if `statement` is a `COPY ... FROM STDIN`, which is ended by
+/// its inline data rather than by a statement delimiter
+fn is_followed_by_copy_data(statement: &Statement) -> bool {
+    matches!(
+        statement,
+        Statement::Copy {
+            to: false,
+            target: CopyTarget::Stdin,
+            ..
+        }
+    )
+}
+
+/// Splits the lines of `TEXT` format `COPY` data into values, honoring
+/// backslash escaped delimiters
+fn split_text_copy_data(data: &str, format: &CopyDataFormat) -> Vec<Option<String>> {
+    let mut values = vec![];
+    for line in data.split('\n') {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        let mut value = String::new();
+        let mut chars = line.chars();
+        while let Some(ch) = chars.next() {
+            if ch == format.delimiter {
+                values.push(copy_value(value, false, format));
+                value = String::new();
+                continue;
+            }
+            value.push(ch);
+            if ch == '\\' {
+                value.extend(chars.next());
+            }
+        }
+        values.push(copy_value(value, false, format));
     }
+    values
+}
+
+/// Splits `CSV` format `COPY` data into values, honoring quoted delimiters
+/// and newlines
+fn split_csv_copy_data(data: &str, format: &CopyDataFormat) -> Vec<Option<String>> {
+    let mut values = vec![];
+    let mut value = String::new();
+    let mut quoted = false;
+    let mut in_quotes = false;
+    let mut chars = data.chars().peekable();
+    while let Some(ch) = chars.next() {
+        if in_quotes {
+            value.push(ch);
+            if ch == format.escape
+                && chars
+                    .peek()
+                    .is_some_and(|&next| next == format.quote || next == format.escape)
+            {
+                value.extend(chars.next());
+            } else if ch == format.quote {
+                in_quotes = false;
+            }
+        } else if ch == format.quote {
+            value.push(ch);
+            in_quotes = true;
+            quoted = true;
+        } else if ch == format.delimiter || ch == '\n' {
+            values.push(copy_value(core::mem::take(&mut value), quoted, format));
+            quoted = false;
+        } else if !(ch == '\r' && chars.peek() == Some(&'\n')) {
+            value.push(ch);
+        }
+    }
+    values.push(copy_value(value, quoted, format));
+    values
+}
+
+fn copy_value(value: String, quoted: bool, format: &CopyDataFormat) -> Option<String> {
+    (quoted || value != format.null).then_some(value)
 }
 
 impl Word {
     pub fn to_ident(&self) -> Ident {
         Ident {
-            value: self.value.clone(),
+            value: self.value.as_str().into(),
             quote_style: self.quote_style,
         }
     }
@@ -12663,6 +16382,7 @@ mod tests {
                 name: None,
                 index_type: None,
                 columns: vec![Ident::new("c1")],
+                index_options: vec![],
             }
         );
 
@@ -12674,6 +16394,7 @@ mod tests {
                 name: None,
                 index_type: None,
                 columns: vec![Ident::new("c1")],
+                index_options: vec![],
             }
         );
 
@@ -12685,6 +16406,7 @@ mod tests {
                 name: Some(Ident::with_quote('\'', "index")),
                 index_type: None,
                 columns: vec![Ident::new("c1"), Ident::new("c2")],
+                index_options: vec![],
             }
         );
 
@@ -12696,6 +16418,7 @@ mod tests {
                 name: None,
                 index_type: Some(IndexType::BTree),
                 columns: vec![Ident::new("c1")],
+                index_options: vec![],
             }
         );
 
@@ -12707,6 +16430,7 @@ mod tests {
                 name: None,
                 index_type: Some(IndexType::Hash),
                 columns: vec![Ident::new("c1")],
+                index_options: vec![],
             }
         );
 
@@ -12718,6 +16442,7 @@ mod tests {
                 name: Some(Ident::new("idx_name")),
                 index_type: Some(IndexType::BTree),
                 columns: vec![Ident::new("c1")],
+                index_options: vec![],
             }
         );
 
@@ -12729,6 +16454,7 @@ mod tests {
                 name: Some(Ident::new("idx_name")),
                 index_type: Some(IndexType::Hash),
                 columns: vec![Ident::new("c1")],
+                index_options: vec![],
             }
         );
     }
@@ -12780,15 +16506,15 @@ mod tests {
         // parse multipart with quotes
         let expected = vec![
             Ident {
-                value: "CATALOG".to_string(),
+                value: "CATALOG".into(),
                 quote_style: None,
             },
             Ident {
-                value: "F(o)o. \"bar".to_string(),
+                value: "F(o)o. \"bar".into(),
                 quote_style: Some('"'),
             },
             Ident {
-                value: "table".to_string(),
+                value: "table".into(),
                 quote_style: None,
             },
         ];
@@ -12800,11 +16526,11 @@ mod tests {
         // allow whitespace between ident parts
         let expected = vec![
             Ident {
-                value: "CATALOG".to_string(),
+                value: "CATALOG".into(),
                 quote_style: None,
             },
             Ident {
-                value: "table".to_string(),
+                value: "table".into(),
                 quote_style: None,
             },
         ];
@@ -12883,16 +16609,6 @@ mod tests {
         assert!(Parser::parse_sql(&GenericDialect {}, sql).is_err());
     }
 
-    #[test]
-    fn test_replace_into_set() {
-        // NOTE: This is actually valid MySQL syntax, REPLACE and INSERT,
-        // but the parser does not yet support it.
-        // https://dev.mysql.com/doc/refman/8.3/en/insert.html
-        let sql = "REPLACE INTO t SET a='1'";
-
-        assert!(Parser::parse_sql(&MySqlDialect {}, sql).is_err());
-    }
-
     #[test]
     fn test_replace_into_set_placeholder() {
         let sql = "REPLACE INTO t SET ?";
@@ -0,0 +1,245 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Lazily parsing the statements of a large input, see [`Parser::iter_statements`]
+
+use std::io::BufRead;
+
+use super::{is_followed_by_copy_data, Parser, ParserError, ParserOptions, ParserState};
+use crate::ast::Statement;
+use crate::dialect::Dialect;
+use crate::tokenizer::{Location, Token, TokenWithLocation, Tokenizer, TokenizerError};
+
+/// The minimum number of bytes read from the input at a time
+const MIN_READ_SIZE: usize = 8 * 1024;
+
+impl<'a> Parser<'a> {
+    /// Returns an iterator that reads SQL from `reader` and parses it one
+    /// statement at a time.
+    ///
+    /// Unlike [`Parser::parse_sql`], the input does not have to be loaded
+    /// into memory at once: only the text of the statement being parsed is
+    /// kept, so memory use is bounded by the size of the largest statement.
+    ///
+    /// The iterator stops after the first error.
+    ///
+    /// ```
+    /// # use sqlparser::{parser::{Parser, ParserError}, dialect::GenericDialect};
+    /// # fn main() -> Result<(), ParserError> {
+    /// let dialect = GenericDialect {};
+    /// let reader = "SELECT 1;\nSELECT 2;\n".as_bytes();
+    /// let mut statements = Parser::iter_statements(&dialect, reader);
+    /// assert_eq!(statements.next().unwrap()?.to_string(), "SELECT 1");
+    /// assert_eq!(statements.next().unwrap()?.to_string(), "SELECT 2");
+    /// assert!(statements.next().is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter_statements<R: BufRead>(
+        dialect: &'a dyn Dialect,
+        reader: R,
+    ) -> StatementIter<'a, R> {
+        StatementIter {
+            dialect,
+            options: ParserOptions::default(),
+            reader,
+            buffer: String::new(),
+            start: Location { line: 1, column: 1 },
+            delimiter: None,
+            parser: None,
+            eof: false,
+            done: false,
+        }
+    }
+}
+
+/// An iterator over the statements read from a [`BufRead`], see
+/// [`Parser::iter_statements`]
+pub struct StatementIter<'a, R> {
+    dialect: &'a dyn Dialect,
+    options: ParserOptions,
+    reader: R,
+    /// The input that was read but not yet dropped
+    buffer: String,
+    /// The location of the start of `buffer` in the input
+    start: Location,
+    /// The statement delimiter in effect at the start of `buffer`
+    delimiter: Option<String>,
+    /// A parser over the tokens of `buffer`, positioned after the last
+    /// statement that was returned
+    parser: Option<Parser<'a>>,
+    /// Whether all of the input was read
+    eof: bool,
+    /// Whether the iterator returned its last item
+    done: bool,
+}
+
+impl<'a, R: BufRead> StatementIter<'a, R> {
+    /// Specify additional parser options, see [`Parser::with_options`]
+    pub fn with_options(mut self, options: ParserOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    fn next_statement(&mut self) -> Result<Option<Statement>, ParserError> {
+        loop {
+            if self.parser.is_none() {
+                let mut tokens = vec![];
+                match self.tokenize(&self.buffer, &mut tokens) {
+                    Ok(_) => {}
+                    // the buffer may end in the middle of a token
+                    Err(_) if !self.eof => {
+                        self.read_more()?;
+                        continue;
+                    }
+                    Err(err) => return Err(err.into()),
+                }
+                self.parser = Some(
+                    Parser::new(self.dialect)
+                        .with_options(self.options.clone())
+                        .with_tokens_with_locations(tokens),
+                );
+            }
+
+            let parser = self.parser.as_mut().expect("parser was created above");
+            while parser.consume_token(&Token::SemiColon) {}
+            let statement_start = parser.index;
+
+            if parser.peek_token().token == Token::EOF {
+                if self.eof {
+                    return Ok(None);
+                }
+            } else {
+                let result = parser.parse_statement().and_then(|statement| {
+                    match parser.peek_token().token {
+                        Token::SemiColon | Token::EOF => Ok(statement),
+                        _ if is_followed_by_copy_data(&statement) => Ok(statement),
+                        _ => parser.expected("end of statement", parser.peek_token()),
+                    }
+                });
+                let statement = match result {
+                    Ok(statement) => Some(statement),
+                    // the statement may continue in the input that was not read yet
+                    Err(_) if !self.eof && parser.peek_token().token == Token::EOF => None,
+                    Err(_) if self.options.unparsed_statements => {
+                        parser.index = statement_start;
+                        parser.state = ParserState::Normal;
+                        Some(parser.parse_unparsed_statement())
+                    }
+                    Err(err) => return Err(err),
+                };
+                if let Some(statement) = statement {
+                    // the inline data of `COPY ... FROM STDIN` may continue
+                    // in the input that was not read yet, unless it was ended
+                    let ended_by_copy_data = is_followed_by_copy_data(&statement)
+                        && parser.peek_token().token != Token::EOF;
+                    if parser.consume_token(&Token::SemiColon) || self.eof || ended_by_copy_data {
+                        return Ok(Some(statement));
+                    }
+                }
+            }
+
+            self.drop_before(statement_start)?;
+            self.read_more()?;
+        }
+    }
+
+    fn tokenize(
+        &self,
+        text: &str,
+        tokens: &mut Vec<TokenWithLocation>,
+    ) -> Result<Option<String>, TokenizerError> {
+        Tokenizer::new(self.dialect, text)
+            .with_unescape(self.options.unescape)
+            .with_variable_substitution(self.options.variable_substitution)
+            .tokenize_from(self.start, self.delimiter.clone(), tokens)
+    }
+
+    /// Drops the text of the tokens before `index` from the buffer
+    fn drop_before(&mut self, index: usize) -> Result<(), ParserError> {
+        let Some(parser) = self.parser.take() else {
+            return Ok(());
+        };
+        let (offset, location) = match parser.tokens.get(index) {
+            Some(token) => (
+                offset_of(&self.buffer, self.start, token.location),
+                token.location,
+            ),
+            None => (self.buffer.len(), end_location(&self.buffer, self.start)),
+        };
+        // the dropped text may contain `DELIMITER` commands
+        self.delimiter = self.tokenize(&self.buffer[..offset], &mut vec![])?;
+        self.buffer.drain(..offset);
+        self.start = location;
+        Ok(())
+    }
+
+    /// Appends at least as much input as is already buffered
+    fn read_more(&mut self) -> Result<(), ParserError> {
+        let target = self.buffer.len().max(MIN_READ_SIZE);
+        let mut read = 0;
+        while read < target {
+            let n = self
+                .reader
+                .read_line(&mut self.buffer)
+                .map_err(|e| ParserError::ParserError(format!("Error reading input: {e}")))?;
+            if n == 0 {
+                self.eof = true;
+                break;
+            }
+            read += n;
+        }
+        self.parser = None;
+        Ok(())
+    }
+}
+
+impl<'a, R: BufRead> Iterator for StatementIter<'a, R> {
+    type Item = Result<Statement, ParserError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let result = self.next_statement().transpose();
+        self.done = !matches!(result, Some(Ok(_)));
+        result
+    }
+}
+
+fn advance(location: &mut Location, ch: char) {
+    if ch == '\n' {
+        location.line += 1;
+        location.column = 1;
+    } else {
+        location.column += 1;
+    }
+}
+
+/// Returns the byte offset of `location` in `text`, which starts at `start`
+fn offset_of(text: &str, start: Location, location: Location) -> usize {
+    let mut current = start;
+    for (offset, ch) in text.char_indices() {
+        if current == location {
+            return offset;
+        }
+        advance(&mut current, ch);
+    }
+    text.len()
+}
+
+/// Returns the location following `text`, which starts at `start`
+fn end_location(text: &str, start: Location) -> Location {
+    let mut current = start;
+    text.chars().for_each(|ch| advance(&mut current, ch));
+    current
+}
@@ -23,12 +23,7 @@
 // via `tests/test_utils/helpers`.
 
 #[cfg(not(feature = "std"))]
-use alloc::{
-    boxed::Box,
-    string::{String, ToString},
-    vec,
-    vec::Vec,
-};
+use alloc::{boxed::Box, string::ToString, vec, vec::Vec};
 use core::fmt::Debug;
 
 use crate::dialect::*;
@@ -101,7 +96,9 @@ impl TestedDialects {
         self.one_of_identical_results(|dialect| {
             let mut tokenizer = Tokenizer::new(dialect, sql);
             if let Some(options) = &self.options {
-                tokenizer = tokenizer.with_unescape(options.unescape);
+                tokenizer = tokenizer
+                    .with_unescape(options.unescape)
+                    .with_variable_substitution(options.variable_substitution);
             }
             let tokens = tokenizer.tokenize()?;
             self.new_parser(dialect)
@@ -166,7 +163,7 @@ impl TestedDialects {
     /// string (is not modified after a serialization round-trip).
     pub fn verified_query(&self, sql: &str) -> Query {
         match self.verified_stmt(sql) {
-            Statement::Query(query) => *query,
+            Statement::Query(query) => Query::clone(&query),
             _ => panic!("Expected Query"),
         }
     }
@@ -176,7 +173,7 @@ impl TestedDialects {
     /// sql string.
     pub fn verified_query_with_canonical(&self, query: &str, canonical: &str) -> Query {
         match self.one_statement_parses_to(query, canonical) {
-            Statement::Query(query) => *query,
+            Statement::Query(query) => Query::clone(&query),
             _ => panic!("Expected Query"),
         }
     }
@@ -200,7 +197,7 @@ impl TestedDialects {
     ///    `canonical` sql string
     pub fn verified_only_select_with_canonical(&self, query: &str, canonical: &str) -> Select {
         let q = match self.one_statement_parses_to(query, canonical) {
-            Statement::Query(query) => *query,
+            Statement::Query(query) => Query::clone(&query),
             _ => panic!("Expected Query"),
         };
         match *q.body {
@@ -233,6 +230,7 @@ pub fn all_dialects() -> TestedDialects {
         Box::new(DuckDbDialect {}),
         Box::new(DatabricksDialect {}),
         Box::new(ClickHouseDialect {}),
+        Box::new(OracleDialect {}),
     ])
 }
 
@@ -287,6 +285,7 @@ pub fn alter_table_op_with_name(stmt: Statement, expected_name: &str) -> AlterTa
             operations,
             on_cluster: _,
             location: _,
+            unknown_options: _,
         } => {
             assert_eq!(name.to_string(), expected_name);
             assert!(!if_exists);
@@ -306,16 +305,16 @@ pub fn number(n: &str) -> Value {
     Value::Number(n.parse().unwrap(), false)
 }
 
-pub fn table_alias(name: impl Into<String>) -> Option<TableAlias> {
+pub fn table_alias(name: impl Into<IdentValue>) -> Option<TableAlias> {
     Some(TableAlias {
         name: Ident::new(name),
         columns: vec![],
     })
 }
 
-pub fn table(name: impl Into<String>) -> TableFactor {
+pub fn table(name: impl Into<IdentValue>) -> TableFactor {
     TableFactor::Table {
-        name: ObjectName(vec![Ident::new(name.into())]),
+        name: ObjectName(vec![Ident::new(name)]),
         alias: None,
         args: None,
         with_hints: vec![],
@@ -325,7 +324,7 @@ pub fn table(name: impl Into<String>) -> TableFactor {
     }
 }
 
-pub fn table_with_alias(name: impl Into<String>, alias: impl Into<String>) -> TableFactor {
+pub fn table_with_alias(name: impl Into<IdentValue>, alias: impl Into<IdentValue>) -> TableFactor {
     TableFactor::Table {
         name: ObjectName(vec![Ident::new(name)]),
         alias: Some(TableAlias {
@@ -363,6 +362,7 @@ pub fn call(function: &str, args: impl IntoIterator<Item = Expr>) -> Expr {
         filter: None,
         null_treatment: None,
         over: None,
+        safe: false,
         within_group: vec![],
     })
 }
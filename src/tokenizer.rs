@@ -34,22 +34,26 @@ use core::iter::Peekable;
 use core::num::NonZeroU8;
 use core::str::Chars;
 
+#[cfg(feature = "json_schema")]
+use schemars::JsonSchema;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "visitor")]
 use sqlparser_derive::{Visit, VisitMut};
 
-use crate::ast::DollarQuotedString;
+use crate::ast::{DollarQuotedString, QuoteDelimitedString};
 use crate::dialect::Dialect;
 use crate::dialect::{
-    BigQueryDialect, DuckDbDialect, GenericDialect, PostgreSqlDialect, SnowflakeDialect,
+    BigQueryDialect, DuckDbDialect, GenericDialect, MySqlDialect, PostgreSqlDialect,
+    SnowflakeDialect,
 };
-use crate::keywords::{Keyword, ALL_KEYWORDS, ALL_KEYWORDS_INDEX};
+use crate::keywords::Keyword;
 
 /// SQL Token enumeration
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum Token {
     /// An end-of-file marker, not a real token
@@ -95,8 +99,24 @@ pub enum Token {
     /// Triple double quoted literal with raw string prefix. Example `R"""abc"""`
     /// [BigQuery](https://cloud.google.com/bigquery/docs/reference/standard-sql/lexical#quoted_literals)
     TripleDoubleQuotedRawStringLiteral(String),
+    /// Single quoted literal with raw byte string prefix. Example `RB'abc'`
+    /// [BigQuery](https://cloud.google.com/bigquery/docs/reference/standard-sql/lexical#quoted_literals)
+    SingleQuotedRawByteStringLiteral(String),
+    /// Double quoted literal with raw byte string prefix. Example `RB"abc"`
+    /// [BigQuery](https://cloud.google.com/bigquery/docs/reference/standard-sql/lexical#quoted_literals)
+    DoubleQuotedRawByteStringLiteral(String),
+    /// Triple single quoted literal with raw byte string prefix. Example `RB'''abc'''`
+    /// [BigQuery](https://cloud.google.com/bigquery/docs/reference/standard-sql/lexical#quoted_literals)
+    TripleSingleQuotedRawByteStringLiteral(String),
+    /// Triple double quoted literal with raw byte string prefix. Example `RB"""abc"""`
+    /// [BigQuery](https://cloud.google.com/bigquery/docs/reference/standard-sql/lexical#quoted_literals)
+    TripleDoubleQuotedRawByteStringLiteral(String),
     /// "National" string literal: i.e: N'string'
     NationalStringLiteral(String),
+    /// Quote-delimited string literal: i.e: Q'[it's]'
+    QuoteDelimitedStringLiteral(QuoteDelimitedString),
+    /// "National" quote-delimited string literal: i.e: NQ'[it's]'
+    NationalQuoteDelimitedStringLiteral(QuoteDelimitedString),
     /// "escaped" string literal, which are an extension to the SQL standard: i.e: e'first \n second' or E 'first \n second'
     EscapedStringLiteral(String),
     /// Unicode string literal: i.e: U&'first \000A second'
@@ -242,6 +262,9 @@ pub enum Token {
     /// This is used to represent any custom binary operator that is not part of the SQL standard.
     /// PostgreSQL allows defining custom binary operators using CREATE OPERATOR.
     CustomBinaryOperator(String),
+    /// The inline data following a `COPY ... FROM STDIN` statement, up to
+    /// the `\.` line that ends it, as written
+    CopyData(String),
 }
 
 impl fmt::Display for Token {
@@ -257,6 +280,8 @@ impl fmt::Display for Token {
             Token::TripleDoubleQuotedString(ref s) => write!(f, "\"\"\"{s}\"\"\""),
             Token::DollarQuotedString(ref s) => write!(f, "{s}"),
             Token::NationalStringLiteral(ref s) => write!(f, "N'{s}'"),
+            Token::QuoteDelimitedStringLiteral(ref s) => write!(f, "Q'{s}'"),
+            Token::NationalQuoteDelimitedStringLiteral(ref s) => write!(f, "NQ'{s}'"),
             Token::EscapedStringLiteral(ref s) => write!(f, "E'{s}'"),
             Token::UnicodeStringLiteral(ref s) => write!(f, "U&'{s}'"),
             Token::HexStringLiteral(ref s) => write!(f, "X'{s}'"),
@@ -268,6 +293,12 @@ impl fmt::Display for Token {
             Token::DoubleQuotedRawStringLiteral(ref s) => write!(f, "R\"{s}\""),
             Token::TripleSingleQuotedRawStringLiteral(ref s) => write!(f, "R'''{s}'''"),
             Token::TripleDoubleQuotedRawStringLiteral(ref s) => write!(f, "R\"\"\"{s}\"\"\""),
+            Token::SingleQuotedRawByteStringLiteral(ref s) => write!(f, "RB'{s}'"),
+            Token::DoubleQuotedRawByteStringLiteral(ref s) => write!(f, "RB\"{s}\""),
+            Token::TripleSingleQuotedRawByteStringLiteral(ref s) => write!(f, "RB'''{s}'''"),
+            Token::TripleDoubleQuotedRawByteStringLiteral(ref s) => {
+                write!(f, "RB\"\"\"{s}\"\"\"")
+            }
             Token::Comma => f.write_str(","),
             Token::Whitespace(ws) => write!(f, "{ws}"),
             Token::DoubleEq => f.write_str("=="),
@@ -333,33 +364,134 @@ impl fmt::Display for Token {
             Token::QuestionAnd => write!(f, "?&"),
             Token::QuestionPipe => write!(f, "?|"),
             Token::CustomBinaryOperator(s) => f.write_str(s),
+            Token::CopyData(s) => f.write_str(s),
         }
     }
 }
 
 impl Token {
+    /// Returns the [`TokenKind`] of this token.
+    pub fn kind(&self) -> TokenKind {
+        match self {
+            Token::EOF => TokenKind::EOF,
+            Token::Word(w) if w.quote_style.is_none() && w.keyword != Keyword::NoKeyword => {
+                TokenKind::Keyword
+            }
+            Token::Word(_) => TokenKind::Identifier,
+            Token::Number(_, _) => TokenKind::Number,
+            Token::Char(_) => TokenKind::Char,
+            Token::SingleQuotedString(_)
+            | Token::DoubleQuotedString(_)
+            | Token::TripleSingleQuotedString(_)
+            | Token::TripleDoubleQuotedString(_)
+            | Token::DollarQuotedString(_)
+            | Token::SingleQuotedByteStringLiteral(_)
+            | Token::DoubleQuotedByteStringLiteral(_)
+            | Token::TripleSingleQuotedByteStringLiteral(_)
+            | Token::TripleDoubleQuotedByteStringLiteral(_)
+            | Token::SingleQuotedRawStringLiteral(_)
+            | Token::DoubleQuotedRawStringLiteral(_)
+            | Token::TripleSingleQuotedRawStringLiteral(_)
+            | Token::TripleDoubleQuotedRawStringLiteral(_)
+            | Token::SingleQuotedRawByteStringLiteral(_)
+            | Token::DoubleQuotedRawByteStringLiteral(_)
+            | Token::TripleSingleQuotedRawByteStringLiteral(_)
+            | Token::TripleDoubleQuotedRawByteStringLiteral(_)
+            | Token::NationalStringLiteral(_)
+            | Token::QuoteDelimitedStringLiteral(_)
+            | Token::NationalQuoteDelimitedStringLiteral(_)
+            | Token::EscapedStringLiteral(_)
+            | Token::UnicodeStringLiteral(_)
+            | Token::HexStringLiteral(_)
+            | Token::CopyData(_) => TokenKind::StringLiteral,
+            Token::Whitespace(
+                Whitespace::SingleLineComment { .. } | Whitespace::MultiLineComment(_),
+            ) => TokenKind::Comment,
+            Token::Whitespace(_) => TokenKind::Whitespace,
+            Token::Placeholder(_) => TokenKind::Placeholder,
+            Token::Comma
+            | Token::LParen
+            | Token::RParen
+            | Token::Period
+            | Token::SemiColon
+            | Token::LBracket
+            | Token::RBracket
+            | Token::LBrace
+            | Token::RBrace => TokenKind::Punctuation,
+            _ => TokenKind::Operator,
+        }
+    }
+
     pub fn make_keyword(keyword: &str) -> Self {
         Token::make_word(keyword, None)
     }
 
-    pub fn make_word(word: &str, quote_style: Option<char>) -> Self {
-        let word_uppercase = word.to_uppercase();
+    pub fn make_word(word: impl Into<String>, quote_style: Option<char>) -> Self {
+        let value = word.into();
+        let keyword = match quote_style {
+            None => Keyword::lookup(&value).unwrap_or(Keyword::NoKeyword),
+            Some(_) => Keyword::NoKeyword,
+        };
         Token::Word(Word {
-            value: word.to_string(),
+            value,
             quote_style,
-            keyword: if quote_style.is_none() {
-                let keyword = ALL_KEYWORDS.binary_search(&word_uppercase.as_str());
-                keyword.map_or(Keyword::NoKeyword, |x| ALL_KEYWORDS_INDEX[x])
-            } else {
-                Keyword::NoKeyword
-            },
+            keyword,
         })
     }
 }
 
+/// A coarse classification of [`Token`]s, e.g. for syntax highlighting.
+///
+/// See [`Token::kind`].
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+pub enum TokenKind {
+    /// An unquoted word that matches a [`Keyword`], e.g. `SELECT`
+    Keyword,
+    /// A quoted identifier, or an unquoted word that is not a keyword
+    Identifier,
+    /// A numeric literal
+    Number,
+    /// A string literal of any kind, e.g. `'abc'`, `N'abc'` or `$$abc$$`
+    StringLiteral,
+    /// An operator, e.g. `+`, `<=` or `->>`
+    Operator,
+    /// Punctuation, e.g. `,`, `(` or `;`
+    Punctuation,
+    /// A placeholder, e.g. `?` or `$1`
+    Placeholder,
+    /// Spaces, tabs and newlines
+    Whitespace,
+    /// A single or multi line comment
+    Comment,
+    /// A character that doesn't fit any other kind
+    Char,
+    /// End of input
+    EOF,
+}
+
+impl TokenKind {
+    /// All token kinds.
+    pub const ALL: &'static [TokenKind] = &[
+        TokenKind::Keyword,
+        TokenKind::Identifier,
+        TokenKind::Number,
+        TokenKind::StringLiteral,
+        TokenKind::Operator,
+        TokenKind::Punctuation,
+        TokenKind::Placeholder,
+        TokenKind::Whitespace,
+        TokenKind::Comment,
+        TokenKind::Char,
+        TokenKind::EOF,
+    ];
+}
+
 /// A keyword (like SELECT) or an optionally quoted SQL identifier
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct Word {
     /// The value of the token, without the enclosing quotes, and with the
@@ -399,6 +531,7 @@ impl Word {
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum Whitespace {
     Space,
@@ -421,7 +554,10 @@ impl fmt::Display for Whitespace {
 }
 
 /// Location in input string
-#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+#[derive(Debug, Eq, PartialEq, Clone, Copy, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct Location {
     /// Line number, starting from 1
     pub line: u64,
@@ -443,6 +579,17 @@ impl fmt::Display for Location {
     }
 }
 
+/// The locations in the input string where a token starts and where the
+/// text following it starts
+#[derive(Debug, Eq, PartialEq, Clone, Copy, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct Span {
+    pub start: Location,
+    pub end: Location,
+}
+
 /// A [Token] with [Location] attached to it
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub struct TokenWithLocation {
@@ -501,6 +648,9 @@ struct State<'a> {
     peekable: Peekable<Chars<'a>>,
     pub line: u64,
     pub col: u64,
+    /// The statement delimiter set by a MySQL `DELIMITER` command, if it
+    /// isn't `;`
+    delimiter: Option<String>,
 }
 
 impl<'a> State<'a> {
@@ -531,6 +681,30 @@ impl<'a> State<'a> {
             column: self.col,
         }
     }
+
+    /// return true if the remaining input starts with `prefix`, ignoring
+    /// ASCII case
+    fn starts_with(&self, prefix: &str) -> bool {
+        let mut chars = self.peekable.clone();
+        prefix
+            .chars()
+            .all(|p| chars.next().is_some_and(|c| c.eq_ignore_ascii_case(&p)))
+    }
+
+    /// advance the stream past `prefix`, see [`State::starts_with`]
+    fn skip(&mut self, prefix: &str) {
+        for _ in prefix.chars() {
+            self.next();
+        }
+    }
+
+    /// return true if the remaining input starts with the statement delimiter
+    /// set by a MySQL `DELIMITER` command
+    fn at_delimiter(&self) -> bool {
+        self.delimiter
+            .as_deref()
+            .is_some_and(|delimiter| self.starts_with(delimiter))
+    }
 }
 
 /// Represents how many quote characters enclose a string literal.
@@ -566,6 +740,9 @@ pub struct Tokenizer<'a> {
     /// If true (the default), the tokenizer will un-escape literal
     /// SQL strings See [`Tokenizer::with_unescape`] for more details.
     unescape: bool,
+    /// If true, `${...}` is tokenized as a [`Token::Placeholder`]. See
+    /// [`Tokenizer::with_variable_substitution`] for more details.
+    variable_substitution: bool,
 }
 
 impl<'a> Tokenizer<'a> {
@@ -590,6 +767,7 @@ impl<'a> Tokenizer<'a> {
             dialect,
             query,
             unescape: true,
+            variable_substitution: false,
         }
     }
 
@@ -628,6 +806,29 @@ impl<'a> Tokenizer<'a> {
         self
     }
 
+    /// Set variable substitution mode
+    ///
+    /// When true, variable references such as Hive's `${hiveconf:date}` or
+    /// Spark's `${var}`, which are substituted before the query is run, are
+    /// tokenized as a single [`Token::Placeholder`] so that the query can
+    /// be parsed. Defaults to false.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use sqlparser::tokenizer::{Token, Tokenizer};
+    /// # use sqlparser::dialect::HiveDialect;
+    /// # let dialect = HiveDialect {};
+    /// let tokens = Tokenizer::new(&dialect, "${hiveconf:date}")
+    ///    .with_variable_substitution(true)
+    ///    .tokenize().unwrap();
+    /// assert_eq!(tokens, vec![Token::Placeholder("${hiveconf:date}".to_string())]);
+    /// ```
+    pub fn with_variable_substitution(mut self, variable_substitution: bool) -> Self {
+        self.variable_substitution = variable_substitution;
+        self
+    }
+
     /// Tokenize the statement and produce a vector of tokens
     pub fn tokenize(&mut self) -> Result<Vec<Token>, TokenizerError> {
         let twl = self.tokenize_with_location()?;
@@ -635,6 +836,10 @@ impl<'a> Tokenizer<'a> {
     }
 
     /// Tokenize the statement and produce a vector of tokens with location information
+    ///
+    /// Like [`Tokenizer::tokenize`], the result includes the whitespace and
+    /// comments between tokens as [`Token::Whitespace`], so the original text
+    /// can be reproduced from it.
     pub fn tokenize_with_location(&mut self) -> Result<Vec<TokenWithLocation>, TokenizerError> {
         let mut tokens: Vec<TokenWithLocation> = vec![];
         self.tokenize_with_location_into_buf(&mut tokens)
@@ -647,19 +852,122 @@ impl<'a> Tokenizer<'a> {
         &mut self,
         buf: &mut Vec<TokenWithLocation>,
     ) -> Result<(), TokenizerError> {
+        self.tokenize_from(Location { line: 1, column: 1 }, None, buf)?;
+        Ok(())
+    }
+
+    /// Tokenize a query that is a fragment of a larger input, starting at
+    /// `start` with the statement `delimiter` set by a preceding MySQL
+    /// `DELIMITER` command, if any. Returns the delimiter in effect at the
+    /// end of the query.
+    pub(crate) fn tokenize_from(
+        &mut self,
+        start: Location,
+        delimiter: Option<String>,
+        buf: &mut Vec<TokenWithLocation>,
+    ) -> Result<Option<String>, TokenizerError> {
         let mut state = State {
             peekable: self.query.chars().peekable(),
-            line: 1,
-            col: 1,
+            line: start.line,
+            col: start.column,
+            delimiter,
         };
 
         let mut location = state.location();
-        while let Some(token) = self.next_token(&mut state)? {
-            buf.push(TokenWithLocation { token, location });
+        loop {
+            if let Some(d) = state.delimiter.clone().filter(|d| state.starts_with(d)) {
+                state.skip(&d);
+                buf.push(TokenWithLocation {
+                    token: Token::SemiColon,
+                    location,
+                });
+            } else if let Some(d) = self.tokenize_delimiter_command(&mut state, buf)? {
+                state.delimiter = Some(d).filter(|d| d != ";");
+            } else if let Some(token) = self.next_token(&mut state)? {
+                let copy_data = token == Token::SemiColon && is_copy_from_stdin(buf);
+                buf.push(TokenWithLocation { token, location });
+                if copy_data {
+                    let location = state.location();
+                    if let Some(token) = self.tokenize_copy_data(&mut state) {
+                        buf.push(TokenWithLocation { token, location });
+                    }
+                }
+            } else {
+                break;
+            }
 
             location = state.location();
         }
-        Ok(())
+        Ok(state.delimiter)
+    }
+
+    /// Consume a MySQL client `DELIMITER` command at the start of a statement
+    /// and return the new delimiter. The command is not part of the
+    /// tokenized output, and the delimiter is tokenized as [`Token::SemiColon`]
+    /// until it is changed again, so that scripts containing stored program
+    /// definitions can be parsed as is:
+    ///
+    /// ```sql
+    /// DELIMITER //
+    /// CREATE PROCEDURE p() BEGIN SELECT 1; END //
+    /// DELIMITER ;
+    /// ```
+    fn tokenize_delimiter_command(
+        &self,
+        chars: &mut State,
+        buf: &[TokenWithLocation],
+    ) -> Result<Option<String>, TokenizerError> {
+        if !dialect_of!(self is MySqlDialect)
+            || !(chars.starts_with("DELIMITER ") || chars.starts_with("DELIMITER\t"))
+        {
+            return Ok(None);
+        }
+        let at_statement_start = matches!(
+            buf.iter()
+                .rev()
+                .find(|t| !matches!(t.token, Token::Whitespace(_))),
+            None | Some(TokenWithLocation {
+                token: Token::SemiColon,
+                ..
+            })
+        );
+        if !at_statement_start {
+            return Ok(None);
+        }
+        chars.skip("DELIMITER");
+        peeking_take_while(chars, |ch| matches!(ch, ' ' | '\t'));
+        let delimiter = peeking_take_while(chars, |ch| !ch.is_whitespace());
+        if delimiter.is_empty() {
+            return self.tokenizer_error(chars.location(), "Expected a delimiter after DELIMITER");
+        }
+        Ok(Some(delimiter))
+    }
+
+    /// Consume the inline data following a `COPY ... FROM STDIN` statement,
+    /// up to the `\.` line that ends it or the end of the input. The data is
+    /// not SQL, e.g. CSV values may contain unbalanced quotes or quoted
+    /// newlines, so it is kept as a single [`Token::CopyData`] that the
+    /// parser splits according to the format of the statement.
+    fn tokenize_copy_data(&self, chars: &mut State) -> Option<Token> {
+        let mut data = String::new();
+        // the data starts on the line following the statement
+        let mut line_start = false;
+        loop {
+            if line_start && chars.starts_with("\\.") {
+                let mut rest = chars.peekable.clone().skip(2);
+                if matches!(rest.next(), None | Some('\n' | '\r')) {
+                    break;
+                }
+            }
+            match chars.next() {
+                Some(ch) => {
+                    data.push(ch);
+                    line_start = ch == '\n';
+                }
+                None => break,
+            }
+        }
+        (!data.is_empty()).then_some(Token::CopyData(data))
     }
 
     // Tokenize the identifier or keywords in `ch`
@@ -678,6 +986,7 @@ impl<'a> Tokenizer<'a> {
                 peekable: word.chars().peekable(),
                 line: 0,
                 col: 0,
+                delimiter: None,
             };
             let mut s = peeking_take_while(&mut inner_state, |ch| matches!(ch, '0'..='9' | '.'));
             let s2 = peeking_take_while(chars, |ch| matches!(ch, '0'..='9' | '.'));
@@ -685,7 +994,7 @@ impl<'a> Tokenizer<'a> {
             return Ok(Some(Token::Number(s, false)));
         }
 
-        Ok(Some(Token::make_word(&word, None)))
+        Ok(Some(Token::make_word(word, None)))
     }
 
     /// Get the next token or return None
@@ -706,7 +1015,7 @@ impl<'a> Tokenizer<'a> {
                 // BigQuery uses b or B for byte string literal
                 b @ 'B' | b @ 'b' if dialect_of!(self is BigQueryDialect | GenericDialect) => {
                     chars.next(); // consume
-                    match chars.peek() {
+                    match chars.peek().copied() {
                         Some('\'') => {
                             if self.dialect.supports_triple_quoted_string() {
                                 return self
@@ -735,17 +1044,21 @@ impl<'a> Tokenizer<'a> {
                             let s = self.tokenize_single_quoted_string(chars, '\"', false)?;
                             Ok(Some(Token::DoubleQuotedByteStringLiteral(s)))
                         }
+                        Some('r' | 'R') if is_quote_after_prefix(chars) => {
+                            chars.next(); // consume the 'r'
+                            self.tokenize_raw_byte_string_literal(chars)
+                        }
                         _ => {
                             // regular identifier starting with an "b" or "B"
                             let s = self.tokenize_word(b, chars);
-                            Ok(Some(Token::make_word(&s, None)))
+                            Ok(Some(Token::make_word(s, None)))
                         }
                     }
                 }
                 // BigQuery uses r or R for raw string literal
                 b @ 'R' | b @ 'r' if dialect_of!(self is BigQueryDialect | GenericDialect) => {
                     chars.next(); // consume
-                    match chars.peek() {
+                    match chars.peek().copied() {
                         Some('\'') => self
                             .tokenize_single_or_triple_quoted_string::<fn(String) -> Token>(
                                 chars,
@@ -762,17 +1075,47 @@ impl<'a> Tokenizer<'a> {
                                 Token::DoubleQuotedRawStringLiteral,
                                 Token::TripleDoubleQuotedRawStringLiteral,
                             ),
+                        Some('b' | 'B') if is_quote_after_prefix(chars) => {
+                            chars.next(); // consume the 'b'
+                            self.tokenize_raw_byte_string_literal(chars)
+                        }
                         _ => {
                             // regular identifier starting with an "r" or "R"
                             let s = self.tokenize_word(b, chars);
-                            Ok(Some(Token::make_word(&s, None)))
+                            Ok(Some(Token::make_word(s, None)))
+                        }
+                    }
+                }
+                // Oracle uses q or Q for alternative quoting, e.g. q'[it's]'
+                q @ 'Q' | q @ 'q' if self.dialect.supports_quote_delimited_string() => {
+                    let starting_loc = chars.location();
+                    chars.next(); // consume, to check the next char
+                    match chars.peek() {
+                        Some('\'') => {
+                            let s = self.tokenize_quote_delimited_string(starting_loc, chars)?;
+                            Ok(Some(Token::QuoteDelimitedStringLiteral(s)))
+                        }
+                        _ => {
+                            // regular identifier starting with an "q" or "Q"
+                            let s = self.tokenize_word(q, chars);
+                            Ok(Some(Token::make_word(s, None)))
                         }
                     }
                 }
                 // Redshift uses lower case n for national string literal
                 n @ 'N' | n @ 'n' => {
+                    let starting_loc = chars.location();
                     chars.next(); // consume, to check the next char
-                    match chars.peek() {
+                    match chars.peek().copied() {
+                        // Oracle national quote-delimited string, e.g. nq'[it's]'
+                        Some('q' | 'Q')
+                            if self.dialect.supports_quote_delimited_string()
+                                && chars.peekable.clone().nth(1) == Some('\'') =>
+                        {
+                            chars.next(); // consume the 'q'
+                            let s = self.tokenize_quote_delimited_string(starting_loc, chars)?;
+                            Ok(Some(Token::NationalQuoteDelimitedStringLiteral(s)))
+                        }
                         Some('\'') => {
                             // N'...' - a <national character string literal>
                             let s = self.tokenize_single_quoted_string(chars, '\'', true)?;
@@ -781,7 +1124,7 @@ impl<'a> Tokenizer<'a> {
                         _ => {
                             // regular identifier starting with an "N"
                             let s = self.tokenize_word(n, chars);
-                            Ok(Some(Token::make_word(&s, None)))
+                            Ok(Some(Token::make_word(s, None)))
                         }
                     }
                 }
@@ -798,7 +1141,7 @@ impl<'a> Tokenizer<'a> {
                         _ => {
                             // regular identifier starting with an "E" or "e"
                             let s = self.tokenize_word(x, chars);
-                            Ok(Some(Token::make_word(&s, None)))
+                            Ok(Some(Token::make_word(s, None)))
                         }
                     }
                 }
@@ -817,7 +1160,7 @@ impl<'a> Tokenizer<'a> {
                     }
                     // regular identifier starting with an "U" or "u"
                     let s = self.tokenize_word(x, chars);
-                    Ok(Some(Token::make_word(&s, None)))
+                    Ok(Some(Token::make_word(s, None)))
                 }
                 // The spec only allows an uppercase 'X' to introduce a hex
                 // string, but PostgreSQL, at least, allows a lowercase 'x' too.
@@ -832,7 +1175,7 @@ impl<'a> Tokenizer<'a> {
                         _ => {
                             // regular identifier starting with an "X"
                             let s = self.tokenize_word(x, chars);
-                            Ok(Some(Token::make_word(&s, None)))
+                            Ok(Some(Token::make_word(s, None)))
                         }
                     }
                 }
@@ -891,7 +1234,7 @@ impl<'a> Tokenizer<'a> {
                     let (s, last_char) = self.parse_quoted_ident(chars, quote_end);
 
                     if last_char == Some(quote_end) {
-                        Ok(Some(Token::make_word(&s, Some(quote_start))))
+                        Ok(Some(Token::make_word(s, Some(quote_start))))
                     } else {
                         self.tokenizer_error(
                             error_loc,
@@ -955,12 +1298,11 @@ impl<'a> Tokenizer<'a> {
                     // mysql dialect supports identifiers that start with a numeric prefix,
                     // as long as they aren't an exponent number.
                     if self.dialect.supports_numeric_prefix() && exponent_part.is_empty() {
-                        let word =
-                            peeking_take_while(chars, |ch| self.dialect.is_identifier_part(ch));
+                        let word = self.tokenize_word("", chars);
 
                         if !word.is_empty() {
                             s += word.as_str();
-                            return Ok(Some(Token::make_word(s.as_str(), None)));
+                            return Ok(Some(Token::make_word(s, None)));
                         }
                     }
 
@@ -1200,6 +1542,9 @@ impl<'a> Tokenizer<'a> {
                             }
                         }
                         Some(' ') => Ok(Some(Token::AtSign)),
+                        // a quoted string or identifier follows, e.g. in the
+                        // MySQL account name `'user'@'host'`
+                        Some('\'') | Some('"') | Some('`') => Ok(Some(Token::AtSign)),
                         Some(sch) if self.dialect.is_identifier_start('@') => {
                             self.tokenize_identifier_or_keyword([ch, *sch], chars)
                         }
@@ -1221,6 +1566,20 @@ impl<'a> Tokenizer<'a> {
                     Ok(Some(Token::Placeholder(String::from("?") + &s)))
                 }
 
+                // variable reference such as `${hiveconf:date}`, checked
+                // before identifiers as some dialects allow `$` in those
+                '$' if self.variable_substitution && chars.starts_with("${") => {
+                    chars.skip("${");
+                    let name = peeking_take_while(chars, |ch| ch != '}');
+                    if chars.next() == Some('}') {
+                        Ok(Some(Token::Placeholder(format!("${{{name}}}"))))
+                    } else {
+                        self.tokenizer_error(
+                            chars.location(),
+                            "Unterminated variable substitution, expected }",
+                        )
+                    }
+                }
                 // identifier or keyword
                 ch if self.dialect.is_identifier_start(ch) => {
                     self.tokenize_identifier_or_keyword([ch], chars)
@@ -1395,9 +1754,15 @@ impl<'a> Tokenizer<'a> {
     /// Tokenize an identifier or keyword, after the first char is already consumed.
     fn tokenize_word(&self, first_chars: impl Into<String>, chars: &mut State) -> String {
         let mut s = first_chars.into();
-        s.push_str(&peeking_take_while(chars, |ch| {
-            self.dialect.is_identifier_part(ch)
-        }));
+        // MySQL scripts often end a statement with e.g. `END$$`, where `$`
+        // would otherwise be part of the word
+        while let Some(&ch) = chars.peek() {
+            if !self.dialect.is_identifier_part(ch) || chars.at_delimiter() {
+                break;
+            }
+            s.push(ch);
+            chars.next();
+        }
         s
     }
 
@@ -1414,6 +1779,71 @@ impl<'a> Tokenizer<'a> {
         self.tokenizer_error(starting_loc, "Unterminated encoded string literal")
     }
 
+    /// Reads an Oracle quote-delimited string literal such as `q'[it's]'`,
+    /// starting at the opening single quote. The character following the
+    /// quote is the delimiter; `[`, `{`, `(` and `<` are closed by their
+    /// counterpart, any other character is closed by itself.
+    fn tokenize_quote_delimited_string(
+        &self,
+        starting_loc: Location,
+        chars: &mut State,
+    ) -> Result<QuoteDelimitedString, TokenizerError> {
+        chars.next(); // consume the opening quote
+        let start_quote = chars.next();
+        let end_quote = match start_quote {
+            Some('[') => ']',
+            Some('{') => '}',
+            Some('(') => ')',
+            Some('<') => '>',
+            Some(ch) if !ch.is_whitespace() && ch != '\'' => ch,
+            _ => {
+                return self.tokenizer_error(
+                    starting_loc,
+                    "Expected a delimiter after q' in quote-delimited string literal",
+                )
+            }
+        };
+        let mut value = String::new();
+        while let Some(ch) = chars.next() {
+            if ch == end_quote && chars.peek() == Some(&'\'') {
+                chars.next(); // consume the closing quote
+                return Ok(QuoteDelimitedString {
+                    // checked above
+                    start_quote: start_quote.unwrap(),
+                    value,
+                    end_quote,
+                });
+            }
+            value.push(ch);
+        }
+        self.tokenizer_error(starting_loc, "Unterminated quote-delimited string literal")
+    }
+
+    /// Tokenize a raw byte string literal such as `RB'abc'` or `BR"""abc"""`,
+    /// whose prefix has already been consumed.
+    fn tokenize_raw_byte_string_literal(
+        &self,
+        chars: &mut State,
+    ) -> Result<Option<Token>, TokenizerError> {
+        if chars.peek() == Some(&'\'') {
+            self.tokenize_single_or_triple_quoted_string::<fn(String) -> Token>(
+                chars,
+                '\'',
+                false,
+                Token::SingleQuotedRawByteStringLiteral,
+                Token::TripleSingleQuotedRawByteStringLiteral,
+            )
+        } else {
+            self.tokenize_single_or_triple_quoted_string::<fn(String) -> Token>(
+                chars,
+                '"',
+                false,
+                Token::DoubleQuotedRawByteStringLiteral,
+                Token::TripleDoubleQuotedRawByteStringLiteral,
+            )
+        }
+    }
+
     /// Reads a string literal quoted by a single or triple quote characters.
     /// Examples: `'abc'`, `'''abc'''`, `"""abc"""`.
     fn tokenize_single_or_triple_quoted_string<F>(
@@ -1547,31 +1977,30 @@ impl<'a> Tokenizer<'a> {
                     }
                 }
                 '\\' if settings.backslash_escape => {
+                    let escape_loc = chars.location();
                     // consume backslash
                     chars.next();
 
                     num_consecutive_quotes = 0;
 
-                    if let Some(next) = chars.peek() {
+                    if chars.peek().is_some() {
+                        let Some((unescaped, len)) = self
+                            .dialect
+                            .unescape_backslash_sequence(chars.peekable.clone())
+                        else {
+                            return self.tokenizer_error(escape_loc, "Invalid escape sequence");
+                        };
                         if !self.unescape {
                             // In no-escape mode, the given query has to be saved completely including backslashes.
                             s.push(ch);
-                            s.push(*next);
-                            chars.next(); // consume next
+                            for _ in 0..len {
+                                s.extend(chars.next());
+                            }
                         } else {
-                            let n = match next {
-                                '0' => '\0',
-                                'a' => '\u{7}',
-                                'b' => '\u{8}',
-                                'f' => '\u{c}',
-                                'n' => '\n',
-                                'r' => '\r',
-                                't' => '\t',
-                                'Z' => '\u{1a}',
-                                _ => *next,
-                            };
-                            s.push(n);
-                            chars.next(); // consume next
+                            s.push(unescaped);
+                            for _ in 0..len {
+                                chars.next();
+                            }
                         }
                     }
                 }
@@ -1661,6 +2090,33 @@ impl<'a> Tokenizer<'a> {
 /// Read from `chars` until `predicate` returns `false` or EOF is hit.
 /// Return the characters read as String, and keep the first non-matching
 /// char available as `chars.next()`.
+/// Returns true if the `;` just tokenized ends a `COPY ... FROM STDIN`
+/// statement, i.e. it is followed by inline data
+fn is_copy_from_stdin(buf: &[TokenWithLocation]) -> bool {
+    let mut statement: Vec<&Token> = buf
+        .iter()
+        .rev()
+        .map(|t| &t.token)
+        .take_while(|t| **t != Token::SemiColon)
+        .filter(|t| !matches!(t, Token::Whitespace(_)))
+        .collect();
+    statement.reverse();
+    let is_keyword =
+        |token: &Token, keyword| matches!(token, Token::Word(w) if w.keyword == keyword);
+    statement
+        .first()
+        .is_some_and(|t| is_keyword(t, Keyword::COPY))
+        && statement
+            .windows(2)
+            .any(|w| is_keyword(w[0], Keyword::FROM) && is_keyword(w[1], Keyword::STDIN))
+}
+
+/// Returns true if the second next character is a quote, i.e. the next
+/// character completes a two letter string literal prefix such as `RB'`.
+fn is_quote_after_prefix(chars: &State) -> bool {
+    matches!(chars.peekable.clone().nth(1), Some('\'' | '"'))
+}
+
 fn peeking_take_while(chars: &mut State, mut predicate: impl FnMut(char) -> bool) -> String {
     let mut s = String::new();
     while let Some(&ch) = chars.peek() {
@@ -1886,6 +2342,7 @@ mod tests {
     use crate::dialect::{
         BigQueryDialect, ClickHouseDialect, HiveDialect, MsSqlDialect, MySqlDialect,
     };
+    use crate::keywords::{ALL_KEYWORDS, ALL_KEYWORDS_INDEX};
     use core::fmt::Debug;
 
     #[test]
@@ -2286,6 +2743,27 @@ mod tests {
         compare(expected, tokens);
     }
 
+    #[test]
+    fn tokenize_raw_byte_string_literal() {
+        let sql = String::from(r#"rb'\d' Br"x" rB'''a'b''' bR""" " """ rbx br"#);
+        let dialect = BigQueryDialect {};
+        let tokens = Tokenizer::new(&dialect, &sql).tokenize().unwrap();
+        let expected = vec![
+            Token::SingleQuotedRawByteStringLiteral(r"\d".to_string()),
+            Token::Whitespace(Whitespace::Space),
+            Token::DoubleQuotedRawByteStringLiteral("x".to_string()),
+            Token::Whitespace(Whitespace::Space),
+            Token::TripleSingleQuotedRawByteStringLiteral("a'b".to_string()),
+            Token::Whitespace(Whitespace::Space),
+            Token::TripleDoubleQuotedRawByteStringLiteral(" \" ".to_string()),
+            Token::Whitespace(Whitespace::Space),
+            Token::make_word("rbx", None),
+            Token::Whitespace(Whitespace::Space),
+            Token::make_word("br", None),
+        ];
+        compare(expected, tokens);
+    }
+
     #[test]
     fn tokenize_dollar_quoted_string_tagged() {
         let sql = String::from(
@@ -2355,6 +2833,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn tokenize_variable_substitution() {
+        let sql = "SELECT ${env:X}, $1 FROM t";
+        let dialect = GenericDialect {};
+        let tokens = Tokenizer::new(&dialect, sql)
+            .with_variable_substitution(true)
+            .tokenize()
+            .unwrap();
+        let expected = vec![
+            Token::make_keyword("SELECT"),
+            Token::Whitespace(Whitespace::Space),
+            Token::Placeholder("${env:X}".into()),
+            Token::Comma,
+            Token::Whitespace(Whitespace::Space),
+            Token::Placeholder("$1".into()),
+            Token::Whitespace(Whitespace::Space),
+            Token::make_keyword("FROM"),
+            Token::Whitespace(Whitespace::Space),
+            Token::make_word("t", None),
+        ];
+        compare(expected, tokens);
+
+        assert_eq!(
+            Tokenizer::new(&dialect, "SELECT ${hiveconf:date")
+                .with_variable_substitution(true)
+                .tokenize(),
+            Err(TokenizerError {
+                message: "Unterminated variable substitution, expected }".into(),
+                location: Location {
+                    line: 1,
+                    column: 23
+                }
+            })
+        );
+    }
+
     #[test]
     fn tokenize_right_arrow() {
         let sql = String::from("FUNCTION(key=>value)");
@@ -2678,6 +3192,56 @@ mod tests {
         compare(expected, tokens);
     }
 
+    #[test]
+    fn tokenize_kinds() {
+        let sql = "SELECT \"a\", b + 1.5 FROM t WHERE c = 'x' AND d = ? -- done\n;";
+        let dialect = GenericDialect {};
+        let tokens = Tokenizer::new(&dialect, sql).tokenize().unwrap();
+        let kinds: Vec<_> = tokens
+            .iter()
+            .map(Token::kind)
+            .filter(|kind| *kind != TokenKind::Whitespace)
+            .collect();
+        compare(
+            vec![
+                TokenKind::Keyword,
+                TokenKind::Identifier,
+                TokenKind::Punctuation,
+                TokenKind::Identifier,
+                TokenKind::Operator,
+                TokenKind::Number,
+                TokenKind::Keyword,
+                TokenKind::Identifier,
+                TokenKind::Keyword,
+                TokenKind::Identifier,
+                TokenKind::Operator,
+                TokenKind::StringLiteral,
+                TokenKind::Keyword,
+                TokenKind::Identifier,
+                TokenKind::Operator,
+                TokenKind::Placeholder,
+                TokenKind::Comment,
+                TokenKind::Punctuation,
+            ],
+            kinds,
+        );
+    }
+
+    #[test]
+    fn keyword_text_round_trip() {
+        assert_eq!(Keyword::NoKeyword.as_str(), None);
+        for (keyword, text) in ALL_KEYWORDS_INDEX.iter().zip(ALL_KEYWORDS) {
+            assert_eq!(keyword.as_str(), Some(*text));
+            assert_eq!(Keyword::lookup(text), Some(*keyword));
+        }
+        assert_eq!(Keyword::lookup("end-exec"), Some(Keyword::END_EXEC));
+        assert_eq!(Keyword::lookup("not_a_keyword"), None);
+        assert_eq!(Keyword::lookup("SeLeCt"), Some(Keyword::SELECT));
+        // non-ASCII words are upper-cased with the Unicode rules
+        assert_eq!(Keyword::lookup("ſelect"), Some(Keyword::SELECT));
+        assert_eq!(Keyword::lookup(&"a".repeat(100)), None);
+    }
+
     fn compare<T: PartialEq + std::fmt::Debug>(expected: Vec<T>, actual: Vec<T>) {
         //println!("------------------------------");
         //println!("tokens   = {:?}", actual);
@@ -2692,6 +3256,7 @@ mod tests {
             peekable: s.chars().peekable(),
             line: 0,
             col: 0,
+            delimiter: None,
         };
 
         assert_eq!(
@@ -2901,9 +3466,9 @@ mod tests {
                 ),
                 // backslash-escaped characters
                 (
-                    format!(r#"{q}{q}{q}abc\0\n\rdef{q}{q}{q}"#),
-                    r#"abc\0\n\rdef"#.into(),
-                    "abc\0\n\rdef".into(),
+                    format!(r#"{q}{q}{q}abc\t\n\rdef{q}{q}{q}"#),
+                    r#"abc\t\n\rdef"#.into(),
+                    "abc\t\n\rdef".into(),
                 ),
             ] {
                 let tokens = Tokenizer::new(&dialect, sql.as_str())
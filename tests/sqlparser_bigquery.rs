@@ -21,7 +21,7 @@ mod test_utils;
 use std::ops::Deref;
 
 use sqlparser::ast::*;
-use sqlparser::dialect::{BigQueryDialect, GenericDialect};
+use sqlparser::dialect::{BigQueryDialect, ClickHouseDialect, GenericDialect};
 use sqlparser::parser::{ParserError, ParserOptions};
 use test_utils::*;
 
@@ -96,6 +96,45 @@ fn parse_literal_string() {
     );
 }
 
+#[test]
+fn parse_backslash_escape_sequences() {
+    assert_eq!(
+        bigquery().expr_parses_to(r#"'a\vb\n\?\"\`\\'"#, "'a\u{b}b\n?\"`\\'"),
+        Expr::Value(Value::SingleQuotedString("a\u{b}b\n?\"`\\".to_string()))
+    );
+    assert_eq!(
+        bigquery().expr_parses_to(r"'\101\x41\X41\u00e9\U0001F600'", "'AAAé😀'"),
+        Expr::Value(Value::SingleQuotedString("AAAé😀".to_string()))
+    );
+
+    // the raw text is kept as long as the escape sequences are valid
+    let unescaped = TestedDialects {
+        dialects: vec![Box::new(BigQueryDialect {})],
+        options: Some(ParserOptions::new().with_unescape(false)),
+    };
+    unescaped.verified_only_select(r"SELECT 'a\vb\101\x41\u00e9\''");
+
+    for sql in [
+        r"SELECT '\0'",
+        r"SELECT '\Z'",
+        r"SELECT '\q'",
+        r"SELECT '\18'",
+        r"SELECT '\x4'",
+        r"SELECT '\u00e'",
+        r"SELECT '\UD800'",
+        r"SELECT '\U0000D800'",
+    ] {
+        assert_eq!(
+            bigquery().parse_sql_statements(sql).unwrap_err(),
+            ParserError::TokenizerError(
+                "Invalid escape sequence at Line: 1, Column: 9".to_string()
+            ),
+            "{sql}"
+        );
+        assert!(unescaped.parse_sql_statements(sql).is_err(), "{sql}");
+    }
+}
+
 #[test]
 fn parse_byte_literal() {
     let sql = concat!(
@@ -109,7 +148,7 @@ fn parse_byte_literal() {
     );
     let stmt = bigquery().verified_stmt(sql);
     if let Statement::Query(query) = stmt {
-        if let SetExpr::Select(select) = *query.body {
+        if let SetExpr::Select(select) = &*query.body {
             assert_eq!(6, select.projection.len());
             assert_eq!(
                 &Expr::Value(Value::SingleQuotedByteStringLiteral("abc".to_string())),
@@ -167,7 +206,7 @@ fn parse_raw_literal() {
     );
     let stmt = bigquery().verified_stmt(sql);
     if let Statement::Query(query) = stmt {
-        if let SetExpr::Select(select) = *query.body {
+        if let SetExpr::Select(select) = &*query.body {
             assert_eq!(6, select.projection.len());
             assert_eq!(
                 &Expr::Value(Value::SingleQuotedRawStringLiteral("abc".to_string())),
@@ -212,6 +251,41 @@ fn parse_raw_literal() {
     );
 }
 
+#[test]
+fn parse_raw_byte_literal() {
+    let sql = concat!(
+        "SELECT ",
+        r"RB'a\d+', ",
+        r#"RB"abc", "#,
+        "RB'''multi\nline''', ",
+        r#"RB"""abc""""#,
+    );
+    let select = bigquery().verified_only_select(sql);
+    assert_eq!(
+        select
+            .projection
+            .iter()
+            .map(expr_from_projection)
+            .cloned()
+            .collect::<Vec<_>>(),
+        vec![
+            Expr::Value(Value::SingleQuotedRawByteStringLiteral(r"a\d+".to_string())),
+            Expr::Value(Value::DoubleQuotedRawByteStringLiteral("abc".to_string())),
+            Expr::Value(Value::TripleSingleQuotedRawByteStringLiteral(
+                "multi\nline".to_string()
+            )),
+            Expr::Value(Value::TripleDoubleQuotedRawByteStringLiteral(
+                "abc".to_string()
+            )),
+        ]
+    );
+
+    bigquery().one_statement_parses_to(
+        r#"SELECT rb'123', br"123", Rb'''123''', bR"""123""""#,
+        r#"SELECT RB'123', RB"123", RB'''123''', RB"""123""""#,
+    );
+}
+
 #[test]
 fn parse_delete_statement() {
     let sql = "DELETE \"table\" WHERE 1";
@@ -237,6 +311,48 @@ fn parse_delete_statement() {
     }
 }
 
+#[test]
+fn parse_create_model() {
+    let sql = concat!(
+        "CREATE OR REPLACE MODEL dataset.model ",
+        "TRANSFORM(ML.STANDARD_SCALER(f1) OVER () AS f1, label) ",
+        "OPTIONS(model_type = 'linear_reg', input_label_cols = ['label']) ",
+        "AS SELECT f1, label FROM dataset.training"
+    );
+    match bigquery_and_generic().verified_stmt(sql) {
+        Statement::CreateModel {
+            or_replace,
+            if_not_exists,
+            name,
+            transform,
+            options,
+            query,
+        } => {
+            assert!(or_replace);
+            assert!(!if_not_exists);
+            assert_eq!(name.to_string(), "dataset.model");
+            assert_eq_vec(
+                &["ML.STANDARD_SCALER(f1) OVER () AS f1", "label"],
+                &transform.unwrap(),
+            );
+            assert_eq_vec(
+                &["model_type = 'linear_reg'", "input_label_cols = ['label']"],
+                &options,
+            );
+            assert_eq!(
+                query.unwrap().to_string(),
+                "SELECT f1, label FROM dataset.training"
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    bigquery_and_generic().verified_stmt(
+        "CREATE MODEL IF NOT EXISTS m OPTIONS(model_type = 'kmeans') AS (SELECT * FROM t)",
+    );
+    bigquery_and_generic().verified_stmt("CREATE MODEL m");
+}
+
 #[test]
 fn parse_create_view_with_options() {
     let sql = concat!(
@@ -397,9 +513,13 @@ fn parse_create_table_with_options() {
         Statement::CreateTable(CreateTable {
             name,
             columns,
-            partition_by,
-            cluster_by,
-            options,
+            partitioning:
+                CreateTablePartitioning {
+                    partition_by,
+                    cluster_by,
+                    ..
+                },
+            dialect_options: CreateTableDialectOptions { options, .. },
             ..
         }) => {
             assert_eq!(
@@ -804,7 +924,7 @@ fn parse_typed_struct_syntax_bigquery() {
         &Expr::Struct {
             values: vec![Expr::TypedString {
                 data_type: DataType::Datetime(None),
-                value: "1999-01-01 01:23:34.45".to_string()
+                value: "1999-01-01 01:23:34.45".into()
             },],
             fields: vec![StructField {
                 field_name: None,
@@ -884,7 +1004,7 @@ fn parse_typed_struct_syntax_bigquery() {
         &Expr::Struct {
             values: vec![Expr::TypedString {
                 data_type: DataType::Timestamp(None, TimezoneInfo::None),
-                value: "2008-12-25 15:30:00 America/Los_Angeles".to_string()
+                value: "2008-12-25 15:30:00 America/Los_Angeles".into()
             },],
             fields: vec![StructField {
                 field_name: None,
@@ -898,7 +1018,7 @@ fn parse_typed_struct_syntax_bigquery() {
         &Expr::Struct {
             values: vec![Expr::TypedString {
                 data_type: DataType::Time(None, TimezoneInfo::None),
-                value: "15:30:00".to_string()
+                value: "15:30:00".into()
             },],
             fields: vec![StructField {
                 field_name: None,
@@ -915,7 +1035,7 @@ fn parse_typed_struct_syntax_bigquery() {
         &Expr::Struct {
             values: vec![Expr::TypedString {
                 data_type: DataType::Numeric(ExactNumberInfo::None),
-                value: "1".to_string()
+                value: "1".into()
             },],
             fields: vec![StructField {
                 field_name: None,
@@ -928,7 +1048,7 @@ fn parse_typed_struct_syntax_bigquery() {
         &Expr::Struct {
             values: vec![Expr::TypedString {
                 data_type: DataType::BigNumeric(ExactNumberInfo::None),
-                value: "1".to_string()
+                value: "1".into()
             },],
             fields: vec![StructField {
                 field_name: None,
@@ -1113,7 +1233,7 @@ fn parse_typed_struct_syntax_bigquery_and_generic() {
         &Expr::Struct {
             values: vec![Expr::TypedString {
                 data_type: DataType::Datetime(None),
-                value: "1999-01-01 01:23:34.45".to_string()
+                value: "1999-01-01 01:23:34.45".into()
             },],
             fields: vec![StructField {
                 field_name: None,
@@ -1193,7 +1313,7 @@ fn parse_typed_struct_syntax_bigquery_and_generic() {
         &Expr::Struct {
             values: vec![Expr::TypedString {
                 data_type: DataType::Timestamp(None, TimezoneInfo::None),
-                value: "2008-12-25 15:30:00 America/Los_Angeles".to_string()
+                value: "2008-12-25 15:30:00 America/Los_Angeles".into()
             },],
             fields: vec![StructField {
                 field_name: None,
@@ -1207,7 +1327,7 @@ fn parse_typed_struct_syntax_bigquery_and_generic() {
         &Expr::Struct {
             values: vec![Expr::TypedString {
                 data_type: DataType::Time(None, TimezoneInfo::None),
-                value: "15:30:00".to_string()
+                value: "15:30:00".into()
             },],
             fields: vec![StructField {
                 field_name: None,
@@ -1224,7 +1344,7 @@ fn parse_typed_struct_syntax_bigquery_and_generic() {
         &Expr::Struct {
             values: vec![Expr::TypedString {
                 data_type: DataType::Numeric(ExactNumberInfo::None),
-                value: "1".to_string()
+                value: "1".into()
             },],
             fields: vec![StructField {
                 field_name: None,
@@ -1237,7 +1357,7 @@ fn parse_typed_struct_syntax_bigquery_and_generic() {
         &Expr::Struct {
             values: vec![Expr::TypedString {
                 data_type: DataType::BigNumeric(ExactNumberInfo::None),
-                value: "1".to_string()
+                value: "1".into()
             },],
             fields: vec![StructField {
                 field_name: None,
@@ -1622,6 +1742,7 @@ fn parse_merge() {
                 value: Expr::Value(number("2")),
             },
         ],
+        delete_where: None,
     };
     match bigquery_and_generic().verified_stmt(sql) {
         Statement::Merge {
@@ -1744,10 +1865,7 @@ fn parse_merge() {
                             columns: vec![Ident::new("a"), Ident::new("b"),],
                             kind: MergeInsertKind::Values(Values {
                                 explicit_row: false,
-                                rows: vec![vec![
-                                    Expr::Value(number("1")),
-                                    Expr::Identifier(Ident::new("DEFAULT")),
-                                ]]
+                                rows: vec![vec![Expr::Value(number("1")), Expr::Default,]]
                             })
                         })
                     },
@@ -1758,10 +1876,7 @@ fn parse_merge() {
                             columns: vec![],
                             kind: MergeInsertKind::Values(Values {
                                 explicit_row: false,
-                                rows: vec![vec![
-                                    Expr::Value(number("1")),
-                                    Expr::Identifier(Ident::new("DEFAULT")),
-                                ]]
+                                rows: vec![vec![Expr::Value(number("1")), Expr::Default,]]
                             })
                         })
                     },
@@ -1829,6 +1944,50 @@ fn parse_cast_type() {
     bigquery_and_generic().verified_only_select(sql);
 }
 
+#[test]
+fn parse_safe_function_prefix() {
+    let select = bigquery().verified_only_select("SELECT SAFE.SUBSTR('foo', 0, -2)");
+    match expr_from_projection(only(&select.projection)) {
+        Expr::Function(Function { name, safe, .. }) => {
+            assert_eq!(&ObjectName(vec![Ident::new("SUBSTR")]), name);
+            assert!(safe);
+        }
+        _ => unreachable!(),
+    }
+
+    let select = bigquery().verified_only_select("SELECT SAFE.`my-project`.dataset.routine(1)");
+    match expr_from_projection(only(&select.projection)) {
+        Expr::Function(Function { name, safe, .. }) => {
+            assert_eq!(
+                &ObjectName(vec![
+                    Ident::with_quote('`', "my-project"),
+                    Ident::new("dataset"),
+                    Ident::new("routine"),
+                ]),
+                name
+            );
+            assert!(safe);
+        }
+        _ => unreachable!(),
+    }
+    bigquery().one_statement_parses_to(
+        "SELECT safe.`my-project`.dataset.routine(1)",
+        "SELECT SAFE.`my-project`.dataset.routine(1)",
+    );
+
+    // a fully-qualified routine call, or a dataset that happens to be named `safe`
+    for sql in [
+        "SELECT project.dataset.routine(1)",
+        "SELECT `safe`.routine(1)",
+    ] {
+        let select = bigquery().verified_only_select(sql);
+        match expr_from_projection(only(&select.projection)) {
+            Expr::Function(Function { safe, .. }) => assert!(!safe),
+            _ => unreachable!(),
+        }
+    }
+}
+
 #[test]
 fn parse_cast_date_format() {
     let sql =
@@ -2019,8 +2178,19 @@ fn test_bigquery_create_function() {
             language: None,
             determinism_specifier: None,
             remote_connection: None,
+            runtime_version: None,
+            packages: None,
+            imports: None,
+            handler: None,
             called_on_null: None,
             parallel: None,
+            window: false,
+            security: None,
+            leakproof: None,
+            cost: None,
+            rows: None,
+            support: None,
+            set_params: vec![],
         }
     );
 
@@ -2197,3 +2367,29 @@ fn test_any_value() {
     bigquery_and_generic().verified_expr("ANY_VALUE(fruit HAVING MAX sold)");
     bigquery_and_generic().verified_expr("ANY_VALUE(fruit HAVING MIN sold)");
 }
+
+#[test]
+fn parse_reserved_keywords_for_aliases() {
+    // ClickHouse's SETTINGS clause isn't parsed for BigQuery
+    let sql = "SELECT settings.a FROM user_settings settings";
+    bigquery().one_statement_parses_to(sql, "SELECT settings.a FROM user_settings AS settings");
+    assert!(TestedDialects {
+        dialects: vec![Box::new(ClickHouseDialect {})],
+        options: None,
+    }
+    .parse_sql_statements(sql)
+    .is_err());
+
+    // TOP isn't a reserved keyword in BigQuery
+    let sql = "SELECT MAX(score) top FROM t";
+    bigquery().one_statement_parses_to(sql, "SELECT MAX(score) AS top FROM t");
+    assert!(TestedDialects {
+        dialects: vec![Box::new(GenericDialect {})],
+        options: None,
+    }
+    .parse_sql_statements(sql)
+    .is_err());
+
+    let sql = "SELECT * FROM t tablesample";
+    assert!(bigquery().parse_sql_statements(sql).is_err());
+}
@@ -42,7 +42,7 @@ fn parse_map_access_expr() {
             top: None,
             projection: vec![UnnamedExpr(MapAccess {
                 column: Box::new(Identifier(Ident {
-                    value: "string_values".to_string(),
+                    value: "string_values".into(),
                     quote_style: None,
                 })),
                 keys: vec![MapAccessKey {
@@ -201,6 +201,7 @@ fn parse_delimited_identifiers() {
             null_treatment: None,
             filter: None,
             over: None,
+            safe: false,
             within_group: vec![],
         }),
         expr_from_projection(&select.projection[1]),
@@ -318,12 +319,12 @@ fn parse_alter_table_add_projection() {
                             vec![]
                         )),
                         order_by: Some(OrderBy {
-                            exprs: vec![OrderByExpr {
+                            kind: OrderByKind::Expressions(vec![OrderByExpr {
                                 expr: Identifier(Ident::new("b")),
                                 asc: None,
                                 nulls_first: None,
                                 with_fill: None,
-                            }],
+                            }]),
                             interpolate: None,
                         }),
                     }
@@ -720,9 +721,13 @@ fn parse_create_table_with_primary_key() {
         Statement::CreateTable(CreateTable {
             name,
             columns,
-            engine,
-            primary_key,
-            order_by,
+            storage: CreateTableStorage { engine, .. },
+            partitioning:
+                CreateTablePartitioning {
+                    primary_key,
+                    order_by,
+                    ..
+                },
             ..
         }) => {
             assert_eq!(name.to_string(), "db.table");
@@ -823,6 +828,7 @@ fn parse_create_table_with_variant_default_expressions() {
                                 null_treatment: None,
                                 filter: None,
                                 over: None,
+                                safe: false,
                                 within_group: vec![],
                             }))
                         }],
@@ -844,6 +850,7 @@ fn parse_create_table_with_variant_default_expressions() {
                                 null_treatment: None,
                                 filter: None,
                                 over: None,
+                                safe: false,
                                 within_group: vec![],
                             })))
                         }],
@@ -876,6 +883,7 @@ fn parse_create_table_with_variant_default_expressions() {
                                 null_treatment: None,
                                 filter: None,
                                 over: None,
+                                safe: false,
                                 within_group: vec![],
                             }))
                         }],
@@ -1059,6 +1067,78 @@ fn parse_create_materialized_view() {
     clickhouse_and_generic().verified_stmt(sql);
 }
 
+#[test]
+fn parse_create_materialized_view_with_engine() {
+    let sql = concat!(
+        "CREATE MATERIALIZED VIEW IF NOT EXISTS db.mv ",
+        "ENGINE=SummingMergeTree PRIMARY KEY id ORDER BY (id, day) PARTITION BY toYYYYMM(day) ",
+        "SETTINGS index_granularity = 8192 ",
+        "POPULATE AS SELECT id, day, count() AS c FROM db.t GROUP BY id, day"
+    );
+    match clickhouse_and_generic().verified_stmt(sql) {
+        Statement::CreateView {
+            name,
+            materialized,
+            if_not_exists,
+            to,
+            engine: Some(engine),
+            populate,
+            ..
+        } => {
+            assert_eq!("db.mv", name.to_string());
+            assert!(materialized);
+            assert!(if_not_exists);
+            assert!(to.is_none());
+            assert!(populate);
+            assert_eq!(
+                engine.engine,
+                TableEngine {
+                    name: "SummingMergeTree".to_string(),
+                    parameters: None,
+                }
+            );
+            assert_eq!(
+                Some(Box::new(Identifier(Ident::new("id")))),
+                engine.primary_key
+            );
+            assert_eq!(
+                Some(OneOrManyWithParens::Many(vec![
+                    Identifier(Ident::new("id")),
+                    Identifier(Ident::new("day")),
+                ])),
+                engine.order_by
+            );
+            assert_eq!("toYYYYMM(day)", engine.partition_by.unwrap().to_string());
+            assert_eq!(
+                "index_granularity = 8192",
+                engine.settings.unwrap()[0].to_string()
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    // `PARTITION BY`, `PRIMARY KEY` and `ORDER BY` may appear in any order
+    clickhouse_and_generic().one_statement_parses_to(
+        "CREATE MATERIALIZED VIEW mv ENGINE=MergeTree PARTITION BY day ORDER BY id AS SELECT * FROM t",
+        "CREATE MATERIALIZED VIEW mv ENGINE=MergeTree ORDER BY id PARTITION BY day AS SELECT * FROM t",
+    );
+    clickhouse_and_generic().verified_stmt(
+        "CREATE MATERIALIZED VIEW mv ENGINE=ReplacingMergeTree(ver) ORDER BY id POPULATE AS SELECT * FROM t",
+    );
+    clickhouse_and_generic()
+        .verified_stmt("CREATE MATERIALIZED VIEW mv TO db.t POPULATE AS SELECT * FROM t");
+
+    assert_eq!(
+        clickhouse_and_generic()
+            .parse_sql_statements(
+                "CREATE MATERIALIZED VIEW mv ENGINE=MergeTree ORDER BY a ORDER BY b AS SELECT 1"
+            )
+            .unwrap_err()
+            .to_string(),
+        "sql parser error: Expected: AS, found: ORDER"
+    );
+}
+
 #[test]
 fn parse_group_by_with_modifier() {
     let clauses = ["x", "a, b", "ALL"];
@@ -1125,7 +1205,7 @@ fn parse_select_order_by_with_fill_interpolate() {
     let select = clickhouse().verified_query(sql);
     assert_eq!(
         OrderBy {
-            exprs: vec![
+            kind: OrderByKind::Expressions(vec![
                 OrderByExpr {
                     expr: Expr::Identifier(Ident::new("fname")),
                     asc: Some(true),
@@ -1146,7 +1226,7 @@ fn parse_select_order_by_with_fill_interpolate() {
                         step: Some(Expr::Value(number("3"))),
                     }),
                 },
-            ],
+            ]),
             interpolate: Some(Interpolate {
                 exprs: Some(vec![InterpolateExpr {
                     column: Ident::new("col1"),
@@ -1205,7 +1285,10 @@ fn parse_with_fill() {
             to: Some(Expr::Value(number("20"))),
             step: Some(Expr::Value(number("2"))),
         }),
-        select.order_by.expect("ORDER BY expected").exprs[0].with_fill
+        match select.order_by.expect("ORDER BY expected").kind {
+            OrderByKind::Expressions(exprs) => exprs[0].with_fill.clone(),
+            OrderByKind::All(_) => unreachable!(),
+        }
     );
 }
 
@@ -1612,6 +1695,51 @@ fn parse_explain_table() {
     }
 }
 
+#[test]
+fn parse_array_join() {
+    let select = clickhouse_and_generic()
+        .verified_only_select("SELECT s, a FROM t LEFT ARRAY JOIN arr AS a, [1, 2] AS b");
+    assert_eq!(
+        only(&select.from).joins,
+        vec![Join {
+            relation: TableFactor::ArrayJoinItems(vec![
+                ExprWithAlias {
+                    expr: Expr::Identifier(Ident::new("arr")),
+                    alias: Some(Ident::new("a")),
+                },
+                ExprWithAlias {
+                    expr: Expr::Array(Array {
+                        elem: vec![Expr::Value(number("1")), Expr::Value(number("2"))],
+                        named: false,
+                    }),
+                    alias: Some(Ident::new("b")),
+                },
+            ]),
+            global: false,
+            join_operator: JoinOperator::LeftArrayJoin,
+        }]
+    );
+
+    clickhouse_and_generic().verified_stmt("SELECT s, arr FROM t ARRAY JOIN arr");
+    clickhouse_and_generic()
+        .verified_stmt("SELECT * FROM t ARRAY JOIN arr AS a JOIN u ON a = u.x WHERE a > 1");
+
+    // ARRAY is only reserved as a table alias where ARRAY JOIN is parsed
+    all_dialects_except(|d| d.is::<ClickHouseDialect>() || d.is::<GenericDialect>())
+        .one_statement_parses_to("SELECT * FROM t1 array", "SELECT * FROM t1 AS array");
+}
+
+#[test]
+fn parse_global_join() {
+    let select = clickhouse().verified_only_select("SELECT * FROM t GLOBAL LEFT JOIN u USING(x)");
+    let join = &only(&select.from).joins[0];
+    assert!(join.global);
+    assert!(matches!(join.join_operator, JoinOperator::LeftOuter(_)));
+
+    clickhouse().verified_stmt("SELECT * FROM t GLOBAL JOIN u ON t.a = u.a");
+    clickhouse().verified_stmt("SELECT * FROM t GLOBAL ARRAY JOIN arr");
+}
+
 fn clickhouse() -> TestedDialects {
     TestedDialects {
         dialects: vec![Box::new(ClickHouseDialect {})],
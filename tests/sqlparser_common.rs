@@ -36,7 +36,8 @@ use sqlparser::dialect::{
 };
 use sqlparser::keywords::ALL_KEYWORDS;
 use sqlparser::parser::{Parser, ParserError, ParserOptions};
-use sqlparser::tokenizer::Tokenizer;
+use sqlparser::tokenizer::{Location, Span, Token, Tokenizer, Whitespace};
+use std::sync::Arc;
 use test_utils::{
     all_dialects, all_dialects_where, alter_table_op, assert_eq_vec, call, expr_from_projection,
     join, number, only, table, table_alias, TestedDialects,
@@ -116,6 +117,34 @@ fn parse_insert_values() {
     verified_stmt("INSERT INTO customer WITH foo AS (SELECT 1) SELECT * FROM foo UNION VALUES (1)");
 }
 
+#[test]
+fn parse_insert_values_with_default() {
+    let Statement::Insert(Insert {
+        source: Some(source),
+        ..
+    }) = verified_stmt("INSERT INTO t (a, b) VALUES (DEFAULT, 1), (2, DEFAULT)")
+    else {
+        unreachable!()
+    };
+    let SetExpr::Values(Values { rows, .. }) = source.body.as_ref() else {
+        unreachable!()
+    };
+    assert_eq!(
+        rows,
+        &vec![
+            vec![Expr::Default, Expr::Value(number("1"))],
+            vec![Expr::Value(number("2")), Expr::Default],
+        ]
+    );
+
+    match verified_stmt("UPDATE t SET a = DEFAULT") {
+        Statement::Update { assignments, .. } => {
+            assert_eq!(assignments[0].value, Expr::Default)
+        }
+        _ => unreachable!(),
+    }
+}
+
 #[test]
 fn parse_replace_into() {
     let dialect = PostgreSqlDialect {};
@@ -144,7 +173,7 @@ fn parse_insert_default_values() {
         }) => {
             assert_eq!(columns, vec![]);
             assert_eq!(after_columns, vec![]);
-            assert_eq!(on, None);
+            assert!(on.is_empty());
             assert_eq!(partitioned, None);
             assert_eq!(returning, None);
             assert_eq!(source, None);
@@ -169,7 +198,7 @@ fn parse_insert_default_values() {
         }) => {
             assert_eq!(after_columns, vec![]);
             assert_eq!(columns, vec![]);
-            assert_eq!(on, None);
+            assert!(on.is_empty());
             assert_eq!(partitioned, None);
             assert!(returning.is_some());
             assert_eq!(source, None);
@@ -194,7 +223,7 @@ fn parse_insert_default_values() {
         }) => {
             assert_eq!(after_columns, vec![]);
             assert_eq!(columns, vec![]);
-            assert!(on.is_some());
+            assert!(!on.is_empty());
             assert_eq!(partitioned, None);
             assert_eq!(returning, None);
             assert_eq!(source, None);
@@ -246,6 +275,37 @@ fn parse_insert_select_returning() {
     }
 }
 
+#[test]
+fn parse_insert_set_operation_source() {
+    for query in [
+        "SELECT a FROM x EXCEPT SELECT a FROM y",
+        "(SELECT a FROM x EXCEPT SELECT a FROM y)",
+        "(SELECT a FROM x) INTERSECT (SELECT a FROM y)",
+        "((SELECT a FROM x) EXCEPT SELECT a FROM y)",
+        "(SELECT 1) UNION (SELECT 2) ORDER BY 1",
+        "(WITH c AS (SELECT 1) SELECT * FROM c INTERSECT SELECT 2)",
+    ] {
+        // the source is parsed exactly like the standalone query
+        for prefix in ["INSERT INTO t", "INSERT INTO t (a)"] {
+            match verified_stmt(&format!("{prefix} {query}")) {
+                Statement::Insert(Insert {
+                    columns, source, ..
+                }) => {
+                    assert_eq!(columns.len(), usize::from(prefix.ends_with("(a)")));
+                    assert_eq!(source, Some(Box::new(verified_query(query))));
+                }
+                _ => unreachable!(),
+            }
+        }
+        match verified_stmt(&format!("CREATE TABLE t AS {query}")) {
+            Statement::CreateTable(CreateTable { query: ctas, .. }) => {
+                assert_eq!(ctas, Some(Box::new(verified_query(query))));
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+
 #[test]
 fn parse_returning_as_column_alias() {
     verified_stmt("SELECT 1 AS RETURNING");
@@ -374,10 +434,10 @@ fn parse_update_set_from() {
                 target: AssignmentTarget::ColumnName(ObjectName(vec![Ident::new("name")])),
                 value: Expr::CompoundIdentifier(vec![Ident::new("t2"), Ident::new("name")])
             }],
-            from: Some(TableWithJoins {
+            from: vec![TableWithJoins {
                 relation: TableFactor::Derived {
                     lateral: false,
-                    subquery: Box::new(Query {
+                    subquery: Arc::new(Query {
                         with: None,
                         body: Box::new(SetExpr::Select(Box::new(Select {
                             distinct: None,
@@ -425,6 +485,7 @@ fn parse_update_set_from() {
                         for_clause: None,
                         settings: None,
                         format_clause: None,
+                        option_clause: None,
                     }),
                     alias: Some(TableAlias {
                         name: Ident::new("t2"),
@@ -432,7 +493,7 @@ fn parse_update_set_from() {
                     })
                 },
                 joins: vec![],
-            }),
+            }],
             selection: Some(Expr::BinaryOp {
                 left: Box::new(Expr::CompoundIdentifier(vec![
                     Ident::new("t1"),
@@ -505,6 +566,62 @@ fn parse_update_with_table_alias() {
         }
         _ => unreachable!(),
     }
+
+    // AS is optional
+    one_statement_parses_to(
+        "UPDATE users u SET u.username = 'new_user'",
+        "UPDATE users AS u SET u.username = 'new_user'",
+    );
+}
+
+#[test]
+fn parse_update_tuple_assignment() {
+    let target = AssignmentTarget::Tuple(vec![
+        ObjectName(vec![Ident::new("a")]),
+        ObjectName(vec![Ident::new("b")]),
+    ]);
+
+    let sql = "UPDATE t SET (a, b) = (SELECT x, y FROM u WHERE u.id = t.id), c = 1";
+    let Statement::Update { assignments, .. } = verified_stmt(sql) else {
+        unreachable!()
+    };
+    assert_eq!(assignments[0].target, target);
+    assert!(matches!(assignments[0].value, Expr::Subquery(_)));
+    assert_eq!(
+        assignments[1].target,
+        AssignmentTarget::ColumnName(ObjectName(vec![Ident::new("c")]))
+    );
+
+    let Statement::Update { assignments, .. } = verified_stmt("UPDATE t SET (a, b) = ROW(1, 2)")
+    else {
+        unreachable!()
+    };
+    assert_eq!(assignments[0].target, target);
+    assert_eq!(assignments[0].value.to_string(), "ROW(1, 2)",);
+
+    verified_stmt("UPDATE t SET (a, b) = (1, DEFAULT)");
+}
+
+#[test]
+fn parse_update_set_from_multiple_tables() {
+    let sql = "UPDATE t1 AS x SET c = s1.c FROM s1 JOIN s2 ON s1.id = s2.id, s3 WHERE x.id = s1.id AND s3.id = s2.id";
+    let dialects = all_dialects_where(|d| {
+        d.is::<GenericDialect>()
+            || d.is::<PostgreSqlDialect>()
+            || d.is::<DuckDbDialect>()
+            || d.is::<SnowflakeDialect>()
+            || d.is::<RedshiftSqlDialect>()
+    });
+    match dialects.verified_stmt(sql) {
+        Statement::Update { table, from, .. } => {
+            assert_eq!(table.to_string(), "t1 AS x");
+            assert_eq!(
+                from.iter().map(ToString::to_string).collect::<Vec<_>>(),
+                vec!["s1 JOIN s2 ON s1.id = s2.id", "s3"]
+            );
+        }
+        _ => unreachable!(),
+    }
 }
 
 #[test]
@@ -534,7 +651,11 @@ fn parse_select_with_table_alias() {
                 name: ObjectName(vec![Ident::new("lineitem")]),
                 alias: Some(TableAlias {
                     name: Ident::new("l"),
-                    columns: vec![Ident::new("A"), Ident::new("B"), Ident::new("C"),],
+                    columns: vec![
+                        TableAliasColumnDef::from_name("A"),
+                        TableAliasColumnDef::from_name("B"),
+                        TableAliasColumnDef::from_name("C"),
+                    ],
                 }),
                 args: None,
                 with_hints: vec![],
@@ -1079,6 +1200,7 @@ fn parse_select_count_wildcard() {
             null_treatment: None,
             filter: None,
             over: None,
+            safe: false,
             within_group: vec![]
         }),
         expr_from_projection(only(&select.projection))
@@ -1102,6 +1224,7 @@ fn parse_select_count_distinct() {
                 clauses: vec![],
             }),
             null_treatment: None,
+            safe: false,
             within_group: vec![],
             filter: None,
             over: None
@@ -1203,7 +1326,7 @@ fn parse_exponent_in_select() -> Result<(), ParserError> {
     let mut select = dialects.parse_sql_statements(sql)?;
 
     let select = match select.pop().unwrap() {
-        Statement::Query(inner) => *inner,
+        Statement::Query(inner) => Arc::unwrap_or_clone(inner),
         _ => panic!("Expected: Query"),
     };
     let select = match *select.body {
@@ -1409,20 +1532,22 @@ fn pg_and_generic() -> TestedDialects {
 #[test]
 fn parse_json_ops_without_colon() {
     use self::BinaryOperator::*;
+    // MySQL has its own `->` and `->>` operators, and none of the others
+    let all_but_mysql = || all_dialects_except(|d| d.is::<MySqlDialect>());
     let binary_ops = [
         (
             "->",
             Arrow,
-            all_dialects_except(|d| d.supports_lambda_functions()),
+            all_dialects_except(|d| d.supports_lambda_functions() || d.is::<MySqlDialect>()),
         ),
-        ("->>", LongArrow, all_dialects()),
+        ("->>", LongArrow, all_but_mysql()),
         ("#>", HashArrow, pg_and_generic()),
         ("#>>", HashLongArrow, pg_and_generic()),
-        ("@>", AtArrow, all_dialects()),
-        ("<@", ArrowAt, all_dialects()),
+        ("@>", AtArrow, all_but_mysql()),
+        ("<@", ArrowAt, all_but_mysql()),
         ("#-", HashMinus, pg_and_generic()),
-        ("@?", AtQuestion, all_dialects()),
-        ("@@", AtAt, all_dialects()),
+        ("@?", AtQuestion, all_but_mysql()),
+        ("@@", AtAt, all_but_mysql()),
     ];
 
     for (str_op, op, dialects) in binary_ops {
@@ -1587,7 +1712,7 @@ fn parse_null_like() {
                 escape_char: None,
             },
             alias: Ident {
-                value: "col_null".to_owned(),
+                value: "col_null".into(),
                 quote_style: None,
             },
         },
@@ -1603,7 +1728,7 @@ fn parse_null_like() {
                 escape_char: None,
             },
             alias: Ident {
-                value: "null_col".to_owned(),
+                value: "null_col".into(),
                 quote_style: None,
             },
         },
@@ -1812,7 +1937,7 @@ fn parse_in_subquery() {
     assert_eq!(
         Expr::InSubquery {
             expr: Box::new(Expr::Identifier(Ident::new("segment"))),
-            subquery: Box::new(verified_query("SELECT segm FROM bar")),
+            subquery: Arc::new(verified_query("SELECT segm FROM bar")),
             negated: false,
         },
         select.selection.unwrap()
@@ -2077,7 +2202,7 @@ fn parse_select_order_by() {
     fn chk(sql: &str) {
         let select = verified_query(sql);
         assert_eq!(
-            vec![
+            OrderByKind::Expressions(vec![
                 OrderByExpr {
                     expr: Expr::Identifier(Ident::new("lname")),
                     asc: Some(true),
@@ -2096,8 +2221,8 @@ fn parse_select_order_by() {
                     nulls_first: None,
                     with_fill: None,
                 },
-            ],
-            select.order_by.expect("ORDER BY expected").exprs
+            ]),
+            select.order_by.expect("ORDER BY expected").kind
         );
     }
     chk("SELECT id, fname, lname FROM customer WHERE id < 5 ORDER BY lname ASC, fname DESC, id");
@@ -2112,7 +2237,7 @@ fn parse_select_order_by_limit() {
                ORDER BY lname ASC, fname DESC LIMIT 2";
     let select = verified_query(sql);
     assert_eq!(
-        vec![
+        OrderByKind::Expressions(vec![
             OrderByExpr {
                 expr: Expr::Identifier(Ident::new("lname")),
                 asc: Some(true),
@@ -2125,8 +2250,8 @@ fn parse_select_order_by_limit() {
                 nulls_first: None,
                 with_fill: None,
             },
-        ],
-        select.order_by.expect("ORDER BY expected").exprs
+        ]),
+        select.order_by.expect("ORDER BY expected").kind
     );
     assert_eq!(Some(Expr::Value(number("2"))), select.limit);
 }
@@ -2137,7 +2262,7 @@ fn parse_select_order_by_nulls_order() {
                ORDER BY lname ASC NULLS FIRST, fname DESC NULLS LAST LIMIT 2";
     let select = verified_query(sql);
     assert_eq!(
-        vec![
+        OrderByKind::Expressions(vec![
             OrderByExpr {
                 expr: Expr::Identifier(Ident::new("lname")),
                 asc: Some(true),
@@ -2150,12 +2275,37 @@ fn parse_select_order_by_nulls_order() {
                 nulls_first: Some(false),
                 with_fill: None,
             },
-        ],
-        select.order_by.expect("ORDER BY expeccted").exprs
+        ]),
+        select.order_by.expect("ORDER BY expeccted").kind
     );
     assert_eq!(Some(Expr::Value(number("2"))), select.limit);
 }
 
+#[test]
+fn parse_select_order_by_all() {
+    let select = verified_query("SELECT id, fname FROM customer ORDER BY ALL");
+    assert_eq!(
+        OrderByKind::All(OrderByOptions::default()),
+        select.order_by.expect("ORDER BY expected").kind
+    );
+    let select = verified_query("SELECT id, fname FROM customer ORDER BY ALL DESC NULLS LAST");
+    assert_eq!(
+        OrderByKind::All(OrderByOptions {
+            asc: Some(false),
+            nulls_first: Some(false),
+        }),
+        select.order_by.expect("ORDER BY expected").kind
+    );
+
+    // ALL still combines with GROUP BY ALL and QUALIFY
+    let select = verified_only_select(
+        "SELECT a, count(*) AS c FROM t GROUP BY ALL QUALIFY ROW_NUMBER() OVER (ORDER BY c DESC) = 1",
+    );
+    assert_eq!(GroupByExpr::All(vec![]), select.group_by);
+    assert!(select.qualify.is_some());
+    verified_stmt("SELECT a, count(*) AS c FROM t GROUP BY ALL QUALIFY c > 1 ORDER BY ALL");
+}
+
 #[test]
 fn parse_select_group_by() {
     let sql = "SELECT id, fname, lname FROM customer GROUP BY lname, fname";
@@ -2207,6 +2357,7 @@ fn parse_select_having() {
                 null_treatment: None,
                 filter: None,
                 over: None,
+                safe: false,
                 within_group: vec![]
             })),
             op: BinaryOperator::Gt,
@@ -2247,6 +2398,7 @@ fn parse_select_qualify() {
                     }],
                     window_frame: None,
                 })),
+                safe: false,
                 within_group: vec![]
             })),
             op: BinaryOperator::Eq,
@@ -2657,10 +2809,11 @@ fn parse_listagg() {
             filter: None,
             null_treatment: None,
             over: None,
+            safe: false,
             within_group: vec![
                 OrderByExpr {
                     expr: Expr::Identifier(Ident {
-                        value: "id".to_string(),
+                        value: "id".into(),
                         quote_style: None,
                     }),
                     asc: None,
@@ -2669,7 +2822,7 @@ fn parse_listagg() {
                 },
                 OrderByExpr {
                     expr: Expr::Identifier(Ident {
-                        value: "username".to_string(),
+                        value: "username".into(),
                         quote_style: None,
                     }),
                     asc: None,
@@ -2880,11 +3033,15 @@ fn parse_create_table() {
             name,
             columns,
             constraints,
-            with_options,
             if_not_exists: false,
             external: false,
-            file_format: None,
-            location: None,
+            storage:
+                CreateTableStorage {
+                    with_options,
+                    file_format: None,
+                    location: None,
+                    ..
+                },
             ..
         }) => {
             assert_eq!("uk_cities", name.to_string());
@@ -3069,11 +3226,15 @@ fn parse_create_table_with_constraint_characteristics() {
             name,
             columns,
             constraints,
-            with_options,
             if_not_exists: false,
             external: false,
-            file_format: None,
-            location: None,
+            storage:
+                CreateTableStorage {
+                    with_options,
+                    file_format: None,
+                    location: None,
+                    ..
+                },
             ..
         }) => {
             assert_eq!("uk_cities", name.to_string());
@@ -3463,6 +3624,38 @@ fn parse_create_schema() {
     }
 }
 
+#[test]
+fn parse_create_schema_with_elements() {
+    let sql = "CREATE SCHEMA s \
+        CREATE TABLE t (a INT, b TEXT) \
+        CREATE VIEW v AS SELECT a FROM t WHERE b = 'x' \
+        GRANT SELECT ON v TO PUBLIC";
+    // Snowflake's `CREATE TABLE` doesn't end at the next `CREATE`
+    let dialects = all_dialects_except(|d| d.is::<SnowflakeDialect>());
+    match dialects.verified_stmt(sql) {
+        Statement::CreateSchema {
+            schema_name,
+            elements,
+            ..
+        } => {
+            assert_eq!(schema_name.to_string(), "s");
+            assert_eq!(elements.len(), 3);
+            assert!(matches!(elements[0], Statement::CreateTable(_)));
+            assert!(matches!(elements[1], Statement::CreateView { .. }));
+            assert!(matches!(elements[2], Statement::Grant { .. }));
+        }
+        _ => unreachable!(),
+    }
+
+    // statements other than `CREATE` and `GRANT` are not schema elements
+    assert_eq!(
+        dialects
+            .parse_sql_statements("CREATE SCHEMA s CREATE TABLE t (a INT) SELECT 1")
+            .unwrap_err(),
+        ParserError::ParserError("Expected: end of statement, found: SELECT".to_string())
+    );
+}
+
 #[test]
 fn parse_create_schema_with_authorization() {
     let sql = "CREATE SCHEMA AUTHORIZATION Y";
@@ -3546,6 +3739,7 @@ fn parse_create_table_as_table() {
         for_clause: None,
         settings: None,
         format_clause: None,
+        option_clause: None,
     });
 
     match verified_stmt(sql1) {
@@ -3573,6 +3767,7 @@ fn parse_create_table_as_table() {
         for_clause: None,
         settings: None,
         format_clause: None,
+        option_clause: None,
     });
 
     match verified_stmt(sql2) {
@@ -3584,6 +3779,35 @@ fn parse_create_table_as_table() {
     }
 }
 
+#[test]
+fn parse_table_and_values_queries() {
+    let query = all_dialects().verified_query("TABLE s.t");
+    assert_eq!(
+        *query.body,
+        SetExpr::Table(Box::new(Table {
+            table_name: Some("t".to_string()),
+            schema_name: Some("s".to_string()),
+        }))
+    );
+    all_dialects().verified_stmt("TABLE t ORDER BY a");
+    all_dialects().verified_stmt("TABLE t UNION TABLE u");
+    all_dialects().verified_stmt("WITH c AS (TABLE t) TABLE c");
+    all_dialects().verified_stmt("INSERT INTO t TABLE u");
+    all_dialects().verified_stmt("SELECT * FROM t WHERE a IN (TABLE u)");
+    all_dialects().verified_stmt("SELECT * FROM t WHERE EXISTS (TABLE u)");
+    all_dialects().verified_stmt("VALUES (1), (2) UNION TABLE t");
+
+    // `VALUES(col)` is a function in MySQL
+    let sql = "SELECT * FROM t WHERE a IN (VALUES (1), (2))";
+    match all_dialects_except(|d| d.is::<MySqlDialect>()).verified_expr(&sql[22..]) {
+        Expr::InSubquery { subquery, .. } => {
+            assert!(matches!(*subquery.body, SetExpr::Values(_)))
+        }
+        _ => unreachable!(),
+    }
+    all_dialects_except(|d| d.is::<MySqlDialect>()).verified_stmt("SELECT (VALUES (1))");
+}
+
 #[test]
 fn parse_create_table_on_cluster() {
     let generic = TestedDialects {
@@ -3594,7 +3818,10 @@ fn parse_create_table_on_cluster() {
     // Using single-quote literal to define current cluster
     let sql = "CREATE TABLE t ON CLUSTER '{cluster}' (a INT, b INT)";
     match generic.verified_stmt(sql) {
-        Statement::CreateTable(CreateTable { on_cluster, .. }) => {
+        Statement::CreateTable(CreateTable {
+            dialect_options: CreateTableDialectOptions { on_cluster, .. },
+            ..
+        }) => {
             assert_eq!(on_cluster.unwrap().to_string(), "'{cluster}'".to_string());
         }
         _ => unreachable!(),
@@ -3603,7 +3830,10 @@ fn parse_create_table_on_cluster() {
     // Using explicitly declared cluster name
     let sql = "CREATE TABLE t ON CLUSTER my_cluster (a INT, b INT)";
     match generic.verified_stmt(sql) {
-        Statement::CreateTable(CreateTable { on_cluster, .. }) => {
+        Statement::CreateTable(CreateTable {
+            dialect_options: CreateTableDialectOptions { on_cluster, .. },
+            ..
+        }) => {
             assert_eq!(on_cluster.unwrap().to_string(), "my_cluster".to_string());
         }
         _ => unreachable!(),
@@ -3662,7 +3892,10 @@ fn parse_create_table_with_options() {
 
     let sql = "CREATE TABLE t (c INT) WITH (foo = 'bar', a = 123)";
     match generic.verified_stmt(sql) {
-        Statement::CreateTable(CreateTable { with_options, .. }) => {
+        Statement::CreateTable(CreateTable {
+            storage: CreateTableStorage { with_options, .. },
+            ..
+        }) => {
             assert_eq!(
                 vec![
                     SqlOption::KeyValue {
@@ -3724,11 +3957,15 @@ fn parse_create_external_table() {
             name,
             columns,
             constraints,
-            with_options,
             if_not_exists,
             external,
-            file_format,
-            location,
+            storage:
+                CreateTableStorage {
+                    with_options,
+                    file_format,
+                    location,
+                    ..
+                },
             ..
         }) => {
             assert_eq!("uk_cities", name.to_string());
@@ -3795,12 +4032,16 @@ fn parse_create_or_replace_external_table() {
             name,
             columns,
             constraints,
-            with_options,
             if_not_exists,
             external,
-            file_format,
-            location,
             or_replace,
+            storage:
+                CreateTableStorage {
+                    with_options,
+                    file_format,
+                    location,
+                    ..
+                },
             ..
         }) => {
             assert_eq!("uk_cities", name.to_string());
@@ -3900,7 +4141,7 @@ fn parse_alter_table() {
                 table_properties,
                 [SqlOption::KeyValue {
                     key: Ident {
-                        value: "classification".to_string(),
+                        value: "classification".into(),
                         quote_style: Some('\'')
                     },
                     value: Expr::Value(Value::SingleQuotedString("parquet".to_string())),
@@ -4091,8 +4332,12 @@ fn parse_alter_table_constraints() {
         match alter_table_op(verified_stmt(&format!(
             "ALTER TABLE tab ADD {constraint_text}"
         ))) {
-            AlterTableOperation::AddConstraint(constraint) => {
+            AlterTableOperation::AddConstraint {
+                constraint,
+                not_valid,
+            } => {
                 assert_eq!(constraint_text, constraint.to_string());
+                assert!(!not_valid);
             }
             _ => unreachable!(),
         }
@@ -4482,6 +4727,7 @@ fn parse_named_argument_function() {
             null_treatment: None,
             filter: None,
             over: None,
+            safe: false,
             within_group: vec![]
         }),
         expr_from_projection(only(&select.projection))
@@ -4521,6 +4767,7 @@ fn parse_named_argument_function_with_eq_operator() {
             null_treatment: None,
             filter: None,
             over: None,
+            safe: false,
             within_group: vec![],
         }),
         expr_from_projection(only(&select.projection))
@@ -4590,6 +4837,7 @@ fn parse_window_functions() {
                 }],
                 window_frame: None,
             })),
+            safe: false,
             within_group: vec![],
         }),
         expr_from_projection(&select.projection[0])
@@ -4656,6 +4904,60 @@ fn parse_named_window_functions() {
     supported_dialects.verified_stmt(sql);
 }
 
+#[test]
+fn parse_window_frame_exclude() {
+    let sql = "SELECT \
+        SUM(x) OVER (ORDER BY y ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING EXCLUDE CURRENT ROW), \
+        SUM(x) OVER (ORDER BY y RANGE UNBOUNDED PRECEDING EXCLUDE GROUP), \
+        SUM(x) OVER (w GROUPS BETWEEN 1 PRECEDING AND CURRENT ROW EXCLUDE TIES), \
+        SUM(x) OVER (w2 ROWS UNBOUNDED PRECEDING EXCLUDE NO OTHERS) \
+        FROM t \
+        WINDOW w AS (ORDER BY y), \
+        w2 AS (w ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW EXCLUDE CURRENT ROW)";
+    let select = verified_only_select(sql);
+
+    let excludes: Vec<_> = select
+        .projection
+        .iter()
+        .map(|item| match expr_from_projection(item) {
+            Expr::Function(Function {
+                over: Some(WindowType::WindowSpec(spec)),
+                ..
+            }) => spec.window_frame.as_ref().unwrap().exclude,
+            expr => unreachable!("{expr:?}"),
+        })
+        .collect();
+    assert_eq!(
+        excludes,
+        vec![
+            Some(WindowFrameExclusion::CurrentRow),
+            Some(WindowFrameExclusion::Group),
+            Some(WindowFrameExclusion::Ties),
+            Some(WindowFrameExclusion::NoOthers),
+        ]
+    );
+
+    let NamedWindowDefinition(name, NamedWindowExpr::WindowSpec(spec)) = &select.named_window[1]
+    else {
+        unreachable!()
+    };
+    assert_eq!(name, &Ident::new("w2"));
+    assert_eq!(spec.window_name, Some(Ident::new("w")));
+    assert_eq!(
+        spec.window_frame.as_ref().unwrap().exclude,
+        Some(WindowFrameExclusion::CurrentRow)
+    );
+
+    let res =
+        parse_sql_statements("SELECT SUM(x) OVER (ROWS UNBOUNDED PRECEDING EXCLUDE ALL) FROM t");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected: CURRENT ROW, GROUP, TIES or NO OTHERS, found: ALL".to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
 #[test]
 fn parse_window_clause() {
     let sql = "SELECT * \
@@ -4698,7 +5000,7 @@ fn test_parse_named_window() {
             SelectItem::ExprWithAlias {
                 expr: Expr::Function(Function {
                     name: ObjectName(vec![Ident {
-                        value: "MIN".to_string(),
+                        value: "MIN".into(),
                         quote_style: None,
                     }]),
                     parameters: FunctionArguments::None,
@@ -4706,7 +5008,7 @@ fn test_parse_named_window() {
                         duplicate_treatment: None,
                         args: vec![FunctionArg::Unnamed(FunctionArgExpr::Expr(
                             Expr::Identifier(Ident {
-                                value: "c12".to_string(),
+                                value: "c12".into(),
                                 quote_style: None,
                             }),
                         ))],
@@ -4715,20 +5017,21 @@ fn test_parse_named_window() {
                     null_treatment: None,
                     filter: None,
                     over: Some(WindowType::NamedWindow(Ident {
-                        value: "window1".to_string(),
+                        value: "window1".into(),
                         quote_style: None,
                     })),
+                    safe: false,
                     within_group: vec![],
                 }),
                 alias: Ident {
-                    value: "min1".to_string(),
+                    value: "min1".into(),
                     quote_style: None,
                 },
             },
             SelectItem::ExprWithAlias {
                 expr: Expr::Function(Function {
                     name: ObjectName(vec![Ident {
-                        value: "MAX".to_string(),
+                        value: "MAX".into(),
                         quote_style: None,
                     }]),
                     parameters: FunctionArguments::None,
@@ -4736,7 +5039,7 @@ fn test_parse_named_window() {
                         duplicate_treatment: None,
                         args: vec![FunctionArg::Unnamed(FunctionArgExpr::Expr(
                             Expr::Identifier(Ident {
-                                value: "c12".to_string(),
+                                value: "c12".into(),
                                 quote_style: None,
                             }),
                         ))],
@@ -4745,13 +5048,14 @@ fn test_parse_named_window() {
                     null_treatment: None,
                     filter: None,
                     over: Some(WindowType::NamedWindow(Ident {
-                        value: "window2".to_string(),
+                        value: "window2".into(),
                         quote_style: None,
                     })),
+                    safe: false,
                     within_group: vec![],
                 }),
                 alias: Ident {
-                    value: "max1".to_string(),
+                    value: "max1".into(),
                     quote_style: None,
                 },
             },
@@ -4760,7 +5064,7 @@ fn test_parse_named_window() {
         from: vec![TableWithJoins {
             relation: TableFactor::Table {
                 name: ObjectName(vec![Ident {
-                    value: "aggregate_test_100".to_string(),
+                    value: "aggregate_test_100".into(),
                     quote_style: None,
                 }]),
                 alias: None,
@@ -4783,7 +5087,7 @@ fn test_parse_named_window() {
         named_window: vec![
             NamedWindowDefinition(
                 Ident {
-                    value: "window1".to_string(),
+                    value: "window1".into(),
                     quote_style: None,
                 },
                 NamedWindowExpr::WindowSpec(WindowSpec {
@@ -4791,7 +5095,7 @@ fn test_parse_named_window() {
                     partition_by: vec![],
                     order_by: vec![OrderByExpr {
                         expr: Expr::Identifier(Ident {
-                            value: "C12".to_string(),
+                            value: "C12".into(),
                             quote_style: None,
                         }),
                         asc: None,
@@ -4803,13 +5107,13 @@ fn test_parse_named_window() {
             ),
             NamedWindowDefinition(
                 Ident {
-                    value: "window2".to_string(),
+                    value: "window2".into(),
                     quote_style: None,
                 },
                 NamedWindowExpr::WindowSpec(WindowSpec {
                     window_name: None,
                     partition_by: vec![Expr::Identifier(Ident {
-                        value: "C11".to_string(),
+                        value: "C11".into(),
                         quote_style: None,
                     })],
                     order_by: vec![],
@@ -5290,7 +5594,7 @@ fn interval_disallow_interval_expr_gt() {
             },)),
             op: BinaryOperator::Gt,
             right: Box::new(Expr::Identifier(Ident {
-                value: "x".to_string(),
+                value: "x".into(),
                 quote_style: None,
             })),
         }
@@ -5329,20 +5633,20 @@ fn parse_interval_and_or_xor() {
     let dialects = all_dialects_except(|d| d.require_interval_qualifier());
     let actual_ast = dialects.parse_sql_statements(sql).unwrap();
 
-    let expected_ast = vec![Statement::Query(Box::new(Query {
+    let expected_ast = vec![Statement::Query(Arc::new(Query {
         with: None,
         body: Box::new(SetExpr::Select(Box::new(Select {
             distinct: None,
             top: None,
             projection: vec![UnnamedExpr(Expr::Identifier(Ident {
-                value: "col".to_string(),
+                value: "col".into(),
                 quote_style: None,
             }))],
             into: None,
             from: vec![TableWithJoins {
                 relation: TableFactor::Table {
                     name: ObjectName(vec![Ident {
-                        value: "test".to_string(),
+                        value: "test".into(),
                         quote_style: None,
                     }]),
                     alias: None,
@@ -5359,13 +5663,13 @@ fn parse_interval_and_or_xor() {
             selection: Some(Expr::BinaryOp {
                 left: Box::new(Expr::BinaryOp {
                     left: Box::new(Expr::Identifier(Ident {
-                        value: "d3_date".to_string(),
+                        value: "d3_date".into(),
                         quote_style: None,
                     })),
                     op: BinaryOperator::Gt,
                     right: Box::new(Expr::BinaryOp {
                         left: Box::new(Expr::Identifier(Ident {
-                            value: "d1_date".to_string(),
+                            value: "d1_date".into(),
                             quote_style: None,
                         })),
                         op: BinaryOperator::Plus,
@@ -5383,13 +5687,13 @@ fn parse_interval_and_or_xor() {
                 op: BinaryOperator::And,
                 right: Box::new(Expr::BinaryOp {
                     left: Box::new(Expr::Identifier(Ident {
-                        value: "d2_date".to_string(),
+                        value: "d2_date".into(),
                         quote_style: None,
                     })),
                     op: BinaryOperator::Gt,
                     right: Box::new(Expr::BinaryOp {
                         left: Box::new(Expr::Identifier(Ident {
-                            value: "d1_date".to_string(),
+                            value: "d1_date".into(),
                             quote_style: None,
                         })),
                         op: BinaryOperator::Plus,
@@ -5425,6 +5729,7 @@ fn parse_interval_and_or_xor() {
         for_clause: None,
         settings: None,
         format_clause: None,
+        option_clause: None,
     }))];
 
     assert_eq!(actual_ast, expected_ast);
@@ -5480,7 +5785,7 @@ fn parse_at_timezone() {
                 ]
             ),
             alias: Ident {
-                value: "hour".to_string(),
+                value: "hour".into(),
                 quote_style: Some('"'),
             },
         },
@@ -6164,7 +6469,10 @@ fn parse_joins_using() {
                 with_ordinality: false,
             },
             global: false,
-            join_operator: f(JoinConstraint::Using(vec!["c1".into()])),
+            join_operator: f(JoinConstraint::Using {
+                columns: vec!["c1".into()],
+                alias: None,
+            }),
         }
     }
     // Test parsing of aliases
@@ -6213,6 +6521,16 @@ fn parse_joins_using() {
         only(&verified_only_select("SELECT * FROM t1 FULL JOIN t2 USING(c1)").from).joins,
         vec![join_with_constraint("t2", None, JoinOperator::FullOuter)]
     );
+    // Test parsing of an alias for the join columns
+    assert_eq!(
+        only(&verified_only_select("SELECT * FROM t1 JOIN t2 USING(a, b) AS j").from).joins[0]
+            .join_operator,
+        JoinOperator::Inner(JoinConstraint::Using {
+            columns: vec!["a".into(), "b".into()],
+            alias: Some("j".into()),
+        })
+    );
+    verified_stmt("SELECT j.a FROM t1 LEFT JOIN t2 USING(a) AS j JOIN t3 USING(a)");
 }
 
 #[test]
@@ -6262,6 +6580,22 @@ fn parse_natural_join() {
         vec![natural_join(JoinOperator::Inner, table_alias("t3"))]
     );
 
+    // all natural join kinds round trip
+    verified_stmt("SELECT * FROM t1 NATURAL LEFT SEMI JOIN t2");
+    verified_stmt("SELECT * FROM t1 NATURAL RIGHT ANTI JOIN t2");
+    one_statement_parses_to(
+        "SELECT * FROM t1 NATURAL INNER JOIN t2",
+        "SELECT * FROM t1 NATURAL JOIN t2",
+    );
+    one_statement_parses_to(
+        "SELECT * FROM t1 NATURAL LEFT OUTER JOIN t2",
+        "SELECT * FROM t1 NATURAL LEFT JOIN t2",
+    );
+    one_statement_parses_to(
+        "SELECT * FROM t1 NATURAL FULL OUTER JOIN t2",
+        "SELECT * FROM t1 NATURAL FULL JOIN t2",
+    );
+
     let sql = "SELECT * FROM t1 natural";
     assert_eq!(
         ParserError::ParserError("Expected: a join type after NATURAL, found: EOF".to_string()),
@@ -6324,6 +6658,62 @@ fn parse_join_nesting() {
     assert_eq!(from.joins, vec![]);
 }
 
+#[test]
+fn parse_nested_join_groups() {
+    fn join_on(relation: TableFactor, on: &str) -> Join {
+        Join {
+            relation,
+            global: false,
+            join_operator: JoinOperator::Inner(JoinConstraint::On(verified_expr(on))),
+        }
+    }
+
+    let sql = "SELECT * FROM ((a JOIN b ON a.x = b.x) JOIN c ON b.y = c.y)";
+    let from = only(verified_only_select(sql).from);
+    assert_eq!(
+        from.relation,
+        TableFactor::NestedJoin {
+            table_with_joins: Box::new(TableWithJoins {
+                relation: TableFactor::NestedJoin {
+                    table_with_joins: Box::new(TableWithJoins {
+                        relation: table("a"),
+                        joins: vec![join_on(table("b"), "a.x = b.x")],
+                    }),
+                    alias: None,
+                },
+                joins: vec![join_on(table("c"), "b.y = c.y")],
+            }),
+            alias: None,
+        }
+    );
+    assert_eq!(from.joins, vec![]);
+
+    // aliases on the inner and the outer group
+    let sql = "SELECT * FROM ((a JOIN b ON a.x = b.x) AS j JOIN c ON j.y = c.y) AS k (x, y)";
+    let from = only(verified_only_select(sql).from);
+    let TableFactor::NestedJoin {
+        table_with_joins,
+        alias: Some(outer_alias),
+    } = from.relation
+    else {
+        panic!("Expected a nested join, got {:?}", from.relation);
+    };
+    assert_eq!(outer_alias.to_string(), "k (x, y)");
+    assert_eq!(
+        table_with_joins.relation,
+        TableFactor::NestedJoin {
+            table_with_joins: Box::new(TableWithJoins {
+                relation: table("a"),
+                joins: vec![join_on(table("b"), "a.x = b.x")],
+            }),
+            alias: table_alias("j"),
+        }
+    );
+
+    // a group on the right side of a join keeps its own constraint
+    verified_stmt("SELECT * FROM t LEFT JOIN (a JOIN b ON a.x = b.x) AS j ON t.id = j.id");
+}
+
 #[test]
 fn parse_join_syntax_variants() {
     one_statement_parses_to(
@@ -6411,7 +6801,10 @@ fn parse_cte_renamed_columns() {
     let sql = "WITH cte (col1, col2) AS (SELECT foo, bar FROM baz) SELECT * FROM cte";
     let query = all_dialects().verified_query(sql);
     assert_eq!(
-        vec![Ident::new("col1"), Ident::new("col2")],
+        vec![
+            TableAliasColumnDef::from_name("col1"),
+            TableAliasColumnDef::from_name("col2")
+        ],
         query
             .with
             .unwrap()
@@ -6437,13 +6830,10 @@ fn parse_recursive_cte() {
     let expected = Cte {
         alias: TableAlias {
             name: Ident {
-                value: "nums".to_string(),
+                value: "nums".into(),
                 quote_style: None,
             },
-            columns: vec![Ident {
-                value: "val".to_string(),
-                quote_style: None,
-            }],
+            columns: vec![TableAliasColumnDef::from_name("val")],
         },
         query: Box::new(cte_query),
         from: None,
@@ -6481,7 +6871,7 @@ fn parse_derived_tables() {
             table_with_joins: Box::new(TableWithJoins {
                 relation: TableFactor::Derived {
                     lateral: false,
-                    subquery: Box::new(verified_query("(SELECT 1) UNION (SELECT 2)")),
+                    subquery: Arc::new(verified_query("(SELECT 1) UNION (SELECT 2)")),
                     alias: Some(TableAlias {
                         name: "t1".into(),
                         columns: vec![],
@@ -6584,64 +6974,611 @@ fn parse_multiple_statements() {
 }
 
 #[test]
-fn parse_scalar_subqueries() {
-    let sql = "(SELECT 1) + (SELECT 2)";
-    assert_matches!(
-        verified_expr(sql),
-        Expr::BinaryOp {
-            op: BinaryOperator::Plus,
-            ..
-        }
-    );
+#[cfg(feature = "std")]
+fn parse_statements_parallel() {
+    let sqls: Vec<String> = (0..100)
+        .map(|i| match i % 3 {
+            0 => format!("SELECT a{i} FROM t{i}"),
+            1 => format!("INSERT INTO t VALUES (x{i}); DELETE FROM t{i}"),
+            _ => format!("SELECT FROM WHERE {i}"),
+        })
+        .collect();
+    let sqls: Vec<&str> = sqls.iter().map(String::as_str).collect();
+
+    let dialect = GenericDialect {};
+    let results = Parser::parse_statements_parallel(&dialect, &sqls);
+    assert_eq!(results.len(), sqls.len());
+    for (sql, result) in sqls.iter().zip(results) {
+        assert_eq!(result, Parser::parse_sql(&dialect, sql));
+    }
+
+    assert!(Parser::parse_statements_parallel(&dialect, &[]).is_empty());
 }
 
 #[test]
-fn parse_substring() {
-    verified_stmt("SELECT SUBSTRING('1')");
-    verified_stmt("SELECT SUBSTRING('1' FROM 1)");
-    verified_stmt("SELECT SUBSTRING('1' FROM 1 FOR 3)");
-    verified_stmt("SELECT SUBSTRING('1', 1, 3)");
-    verified_stmt("SELECT SUBSTRING('1', 1)");
-    verified_stmt("SELECT SUBSTRING('1' FOR 3)");
+fn dialects_and_ast_are_send_and_sync() {
+    fn assert_send_sync<T: Send + Sync + ?Sized>() {}
+    assert_send_sync::<dyn Dialect>();
+    assert_send_sync::<Statement>();
+    assert_send_sync::<ParserError>();
 }
 
 #[test]
-fn parse_overlay() {
-    one_statement_parses_to(
-        "SELECT OVERLAY('abccccde' PLACING 'abc' FROM 3)",
-        "SELECT OVERLAY('abccccde' PLACING 'abc' FROM 3)",
+fn ast_builders() {
+    let t = TableWithJoins {
+        relation: table("t"),
+        joins: vec![],
+    };
+    let a_eq_1 = Expr::BinaryOp {
+        left: Box::new(Expr::Identifier(Ident::new("a"))),
+        op: BinaryOperator::Eq,
+        right: Box::new(Expr::Value(number("1"))),
+    };
+
+    let select = Select::builder()
+        .distinct(Some(Distinct::Distinct))
+        .projection(vec![UnnamedExpr(Expr::Identifier(Ident::new("a")))])
+        .from(vec![t.clone()])
+        .selection(Some(a_eq_1.clone()))
+        .build();
+    assert_eq!(
+        select,
+        verified_only_select("SELECT DISTINCT a FROM t WHERE a = 1")
     );
-    one_statement_parses_to(
-        "SELECT OVERLAY('abccccde' PLACING 'abc' FROM 3 FOR 12)",
-        "SELECT OVERLAY('abccccde' PLACING 'abc' FROM 3 FOR 12)",
+
+    let query = Query::select(select)
+        .limit(Some(Expr::Value(number("5"))))
+        .build();
+    assert_eq!(
+        query,
+        verified_query("SELECT DISTINCT a FROM t WHERE a = 1 LIMIT 5")
     );
+
+    let insert = Insert::builder(ObjectName(vec![Ident::new("t")]))
+        .columns(vec![Ident::new("a")])
+        .source(Some(Box::new(query)))
+        .build();
     assert_eq!(
-        ParserError::ParserError("Expected: PLACING, found: FROM".to_owned()),
-        parse_sql_statements("SELECT OVERLAY('abccccde' FROM 3)").unwrap_err(),
+        insert,
+        verified_stmt("INSERT INTO t (a) SELECT DISTINCT a FROM t WHERE a = 1 LIMIT 5")
     );
 
-    let sql = "SELECT OVERLAY('abcdef' PLACING name FROM 3 FOR id + 1) FROM CUSTOMERS";
-    let select = verified_only_select(sql);
+    let update = builder::UpdateBuilder::new(
+        t.clone(),
+        vec![Assignment {
+            target: AssignmentTarget::ColumnName(ObjectName(vec![Ident::new("b")])),
+            value: Expr::Value(number("2")),
+        }],
+    )
+    .selection(Some(a_eq_1.clone()))
+    .build();
+    assert_eq!(update, verified_stmt("UPDATE t SET b = 2 WHERE a = 1"));
+
+    let delete = Delete::builder(vec![t])
+        .selection(Some(a_eq_1.clone()))
+        .build();
+    assert_eq!(delete, verified_stmt("DELETE FROM t WHERE a = 1"));
+
+    let t_name = ObjectName(vec![Ident::new("t")]);
+    let create_index = CreateIndex::builder(
+        t_name.clone(),
+        vec![OrderByExpr {
+            expr: Expr::Identifier(Ident::new("a")),
+            asc: None,
+            nulls_first: None,
+            with_fill: None,
+        }],
+    )
+    .name(Some(ObjectName(vec![Ident::new("i")])))
+    .unique(true)
+    .predicate(Some(a_eq_1))
+    .build();
     assert_eq!(
-        &Expr::Overlay {
-            expr: Box::new(Expr::Value(Value::SingleQuotedString("abcdef".to_string()))),
-            overlay_what: Box::new(Expr::Identifier(Ident::new("name"))),
-            overlay_from: Box::new(Expr::Value(number("3"))),
-            overlay_for: Some(Box::new(Expr::BinaryOp {
-                left: Box::new(Expr::Identifier(Ident::new("id"))),
-                op: BinaryOperator::Plus,
-                right: Box::new(Expr::Value(number("1"))),
-            })),
-        },
-        expr_from_projection(only(&select.projection))
+        create_index,
+        verified_stmt("CREATE UNIQUE INDEX i ON t(a) WHERE a = 1")
     );
-}
 
-#[test]
-fn parse_trim() {
-    one_statement_parses_to(
-        "SELECT TRIM(BOTH 'xyz' FROM 'xyzfooxyz')",
-        "SELECT TRIM(BOTH 'xyz' FROM 'xyzfooxyz')",
+    let view_query = verified_query("SELECT a FROM t");
+    let create_view =
+        builder::CreateViewBuilder::new(ObjectName(vec![Ident::new("v")]), view_query)
+            .or_replace(true)
+            .build();
+    assert_eq!(
+        create_view,
+        verified_stmt("CREATE OR REPLACE VIEW v AS SELECT a FROM t")
+    );
+
+    let alter_table = builder::AlterTableBuilder::new(t_name)
+        .if_exists(true)
+        .operation(AlterTableOperation::RenameColumn {
+            old_column_name: Ident::new("a"),
+            new_column_name: Ident::new("b"),
+        })
+        .operation(AlterTableOperation::DropColumn {
+            column_name: Ident::new("c"),
+            if_exists: false,
+            cascade: false,
+        })
+        .build();
+    assert_eq!(
+        alter_table,
+        verified_stmt("ALTER TABLE IF EXISTS t RENAME COLUMN a TO b, DROP COLUMN c")
+    );
+}
+
+#[test]
+fn parse_commented_statements() {
+    let sql = concat!(
+        "-- header\n",
+        "/* about t */\n",
+        "SELECT a FROM t; -- trailing\n",
+        "\n",
+        "-- second\n",
+        "INSERT INTO t VALUES (1) /* inline */;\n",
+        ";\n",
+        "DELETE FROM t -- no delimiter\n",
+    );
+    let statements = Parser::new(&GenericDialect {})
+        .try_with_sql(sql)
+        .unwrap()
+        .parse_commented_statements()
+        .unwrap();
+    assert_eq!(statements.len(), 3);
+
+    assert_eq!(
+        statements[0].leading_comments,
+        vec![
+            Whitespace::SingleLineComment {
+                comment: " header\n".into(),
+                prefix: "--".into(),
+            },
+            Whitespace::MultiLineComment(" about t ".into()),
+        ]
+    );
+    assert_eq!(statements[0].statement.to_string(), "SELECT a FROM t");
+    assert_eq!(
+        statements[0].trailing_comments,
+        vec![Whitespace::SingleLineComment {
+            comment: " trailing\n".into(),
+            prefix: "--".into(),
+        }]
+    );
+
+    assert_eq!(
+        statements
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>(),
+        vec![
+            "-- header\n/* about t */\nSELECT a FROM t -- trailing\n",
+            "-- second\nINSERT INTO t VALUES (1) /* inline */",
+            "DELETE FROM t -- no delimiter\n",
+        ]
+    );
+
+    // round trip
+    let rendered = statements
+        .iter()
+        .map(|s| format!("{s}\n;"))
+        .collect::<String>();
+    let reparsed = Parser::new(&GenericDialect {})
+        .try_with_sql(&rendered)
+        .unwrap()
+        .parse_commented_statements()
+        .unwrap();
+    assert_eq!(reparsed, statements);
+
+    assert_eq!(
+        Parser::new(&GenericDialect {})
+            .try_with_sql("SELECT 1 SELECT 2")
+            .unwrap()
+            .parse_commented_statements()
+            .unwrap_err()
+            .to_string(),
+        "sql parser error: Expected: end of statement, found: SELECT at Line: 1, Column: 10"
+    );
+}
+
+#[test]
+fn parse_commented_statements_inner_comments() {
+    let sql = concat!(
+        "-- header\n",
+        "SELECT a, -- first\n",
+        "  /* second */ b\n",
+        "FROM t /* table */ WHERE x = 1 -- done\n",
+    );
+    let statements = Parser::new(&GenericDialect {})
+        .try_with_sql(sql)
+        .unwrap()
+        .parse_commented_statements()
+        .unwrap();
+    assert_eq!(statements.len(), 1);
+    let span = |line, column, end_column| Span {
+        start: Location { line, column },
+        end: Location {
+            line,
+            column: end_column,
+        },
+    };
+    assert_eq!(
+        statements[0].inner_comments,
+        vec![
+            TokenComments {
+                span: span(2, 8, 9),
+                leading: vec![],
+                trailing: vec![Whitespace::SingleLineComment {
+                    comment: " first\n".into(),
+                    prefix: "--".into(),
+                }],
+            },
+            TokenComments {
+                span: span(3, 16, 17),
+                leading: vec![Whitespace::MultiLineComment(" second ".into())],
+                trailing: vec![],
+            },
+            TokenComments {
+                span: span(4, 20, 25),
+                leading: vec![Whitespace::MultiLineComment(" table ".into())],
+                trailing: vec![],
+            },
+        ]
+    );
+    assert_eq!(
+        statements[0].to_string(),
+        "-- header\nSELECT a, b FROM t WHERE x = 1 -- done\n"
+    );
+
+    // the spans locate the nodes the comments belong to
+    let lines: Vec<&str> = sql.lines().collect();
+    let text = |span: Span| {
+        &lines[span.start.line as usize - 1]
+            [span.start.column as usize - 1..span.end.column as usize - 1]
+    };
+    assert_eq!(
+        statements[0]
+            .inner_comments
+            .iter()
+            .map(|c| text(c.span))
+            .collect::<Vec<_>>(),
+        vec!["a", "b", "WHERE"]
+    );
+
+    let statements = Parser::new(&GenericDialect {})
+        .try_with_sql("SELECT /* all */\n-- columns\nxyz")
+        .unwrap()
+        .parse_commented_statements()
+        .unwrap();
+    assert_eq!(
+        statements[0].inner_comments,
+        vec![
+            TokenComments {
+                span: span(1, 1, 7),
+                leading: vec![],
+                trailing: vec![Whitespace::MultiLineComment(" all ".into())],
+            },
+            TokenComments {
+                span: span(3, 1, 4),
+                leading: vec![Whitespace::SingleLineComment {
+                    comment: " columns\n".into(),
+                    prefix: "--".into(),
+                }],
+                trailing: vec![],
+            },
+        ]
+    );
+}
+
+#[test]
+fn parse_sql_with_ranges() {
+    let sql = concat!(
+        "-- header\n",
+        "select  a\n  FROM \"tëst\"; -- trailing\n",
+        ";\n",
+        "INSERT INTO t VALUES ('ü', 1) /* inline */ ;\n",
+        "\tDELETE FROM t\n",
+    );
+    let statements = Parser::parse_sql_with_ranges(&GenericDialect {}, sql).unwrap();
+    assert_eq!(
+        statements
+            .iter()
+            .map(|s| (&sql[s.source_range.clone()], s.ends_with_semicolon))
+            .collect::<Vec<_>>(),
+        vec![
+            ("select  a\n  FROM \"tëst\"", true),
+            ("INSERT INTO t VALUES ('ü', 1)", true),
+            ("DELETE FROM t", false),
+        ]
+    );
+    assert_eq!(
+        statements[1].statement.to_string(),
+        "INSERT INTO t VALUES ('ü', 1)"
+    );
+
+    // re-emitting the statements gives back the same statements
+    let rendered = statements
+        .iter()
+        .map(|s| {
+            let semicolon = if s.ends_with_semicolon { ";" } else { "" };
+            format!("{}{semicolon}\n", &sql[s.source_range.clone()])
+        })
+        .collect::<String>();
+    assert_eq!(
+        Parser::parse_sql(&GenericDialect {}, &rendered).unwrap(),
+        statements
+            .into_iter()
+            .map(|s| s.statement)
+            .collect::<Vec<_>>()
+    );
+
+    assert_eq!(
+        Parser::parse_sql_with_ranges(&GenericDialect {}, "SELECT 1 SELECT 2")
+            .unwrap_err()
+            .to_string(),
+        "sql parser error: Expected: end of statement, found: SELECT at Line: 1, Column: 10"
+    );
+}
+
+#[test]
+fn parse_sql_with_ranges_copy_from_stdin() {
+    let sql = "COPY t (a) FROM STDIN;\n1\n2\n\\.\nSELECT 1;";
+    let statements = Parser::parse_sql_with_ranges(&PostgreSqlDialect {}, sql).unwrap();
+    assert_eq!(
+        statements
+            .iter()
+            .map(|s| (&sql[s.source_range.clone()], s.ends_with_semicolon))
+            .collect::<Vec<_>>(),
+        vec![
+            ("COPY t (a) FROM STDIN;\n1\n2\n\\.", true),
+            ("SELECT 1", true),
+        ]
+    );
+}
+
+#[test]
+fn parse_statements_with_recovery() {
+    let sql = "SELECT 1; SELECT * FROM; INSERT INTO t VALUES (1); SELECT a b c; SELECT 2";
+    let (statements, errors) = Parser::new(&GenericDialect {})
+        .try_with_sql(sql)
+        .unwrap()
+        .parse_statements_with_recovery();
+    assert_eq!(
+        statements
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>(),
+        vec![
+            "SELECT 1",
+            "INSERT INTO t VALUES (1)",
+            "SELECT a AS b",
+            "SELECT 2"
+        ]
+    );
+    assert_eq!(
+        errors,
+        vec![
+            ParserError::ParserError(
+                "Expected: identifier, found: ; at Line: 1, Column: 24".to_string()
+            ),
+            ParserError::ParserError(
+                "Expected: end of statement, found: c at Line: 1, Column: 63".to_string()
+            ),
+        ]
+    );
+
+    // an error in the last statement consumes the rest of the input
+    let (statements, errors) = Parser::new(&GenericDialect {})
+        .try_with_sql("SELECT 1; SELECT (")
+        .unwrap()
+        .parse_statements_with_recovery();
+    assert_eq!(statements.len(), 1);
+    assert_eq!(errors.len(), 1);
+
+    // a stray END is an error too, the statements after it are still parsed
+    let (statements, errors) = Parser::new(&GenericDialect {})
+        .try_with_sql("SELECT 1 END; SELECT 2")
+        .unwrap()
+        .parse_statements_with_recovery();
+    assert_eq!(
+        statements
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>(),
+        vec!["SELECT 1", "SELECT 2"]
+    );
+    assert_eq!(
+        errors,
+        vec![ParserError::ParserError(
+            "Expected: end of statement, found: END at Line: 1, Column: 10".to_string()
+        )]
+    );
+}
+
+#[test]
+fn parse_unparsed_statements() {
+    let sql = "SELECT 1; FROBNICATE 'x' /* why */ (a, b); SELECT a b c;\nSELECT (; SELECT 2";
+    let options = ParserOptions::new().with_unparsed_statements(true);
+    let statements = Parser::new(&GenericDialect {})
+        .with_options(options.clone())
+        .try_with_sql(sql)
+        .unwrap()
+        .parse_statements()
+        .unwrap();
+    assert_eq!(
+        statements
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>(),
+        vec![
+            "SELECT 1",
+            "FROBNICATE 'x' /* why */ (a, b)",
+            "SELECT a b c",
+            "SELECT (",
+            "SELECT 2"
+        ]
+    );
+    assert_eq!(
+        statements[1],
+        Statement::Unparsed {
+            tokens: vec![
+                Token::make_word("FROBNICATE", None),
+                Token::SingleQuotedString("x".to_string()),
+                Token::LParen,
+                Token::make_word("a", None),
+                Token::Comma,
+                Token::make_word("b", None),
+                Token::RParen,
+            ],
+            sql_fragment: "FROBNICATE 'x' /* why */ (a, b)".to_string(),
+        }
+    );
+    assert!(matches!(statements[2], Statement::Unparsed { .. }));
+
+    // a stray END doesn't drop the statements after it
+    let statements = Parser::new(&GenericDialect {})
+        .with_options(options.clone())
+        .try_with_sql("SELECT 1 END; SELECT 2")
+        .unwrap()
+        .parse_statements()
+        .unwrap();
+    assert_eq!(
+        statements
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>(),
+        vec!["SELECT 1 END", "SELECT 2"]
+    );
+    assert!(matches!(statements[0], Statement::Unparsed { .. }));
+
+    // without the option, the first error fails the whole batch
+    assert!(Parser::parse_sql(&GenericDialect {}, sql).is_err());
+    // tokenizer errors can't be recovered from
+    assert!(Parser::new(&GenericDialect {})
+        .with_options(options)
+        .try_with_sql("SELECT 'unterminated")
+        .is_err());
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn parse_unparsed_statements_serde_round_trip() {
+    let statements = Parser::new(&GenericDialect {})
+        .with_options(ParserOptions::new().with_unparsed_statements(true))
+        .try_with_sql("FROBNICATE the widgets")
+        .unwrap()
+        .parse_statements()
+        .unwrap();
+    assert_matches!(statements[0], Statement::Unparsed { .. });
+    assert_serde_round_trip!(statements[0]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn parse_statements_lazily() {
+    // large enough for statements to span the chunks read from the input
+    let mut sql = String::new();
+    for i in 0..2000 {
+        match i % 4 {
+            0 => sql.push_str(&format!("SELECT {i};\n")),
+            1 => sql.push_str(&format!("SELECT\n  a\nFROM\n  t{i}\n;")),
+            2 => sql.push_str(&format!("INSERT INTO t VALUES ('a;\nb', {i}); ")),
+            _ => sql.push_str(&format!("/* ; */ UPDATE t SET a = {i} -- ;\n;\n")),
+        }
+    }
+    sql.push_str(&format!("SELECT '{}'", "x".repeat(20_000)));
+
+    let dialect = GenericDialect {};
+    let expected = Parser::parse_sql(&dialect, &sql).unwrap();
+    let statements = Parser::iter_statements(&dialect, sql.as_bytes())
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(statements.len(), 2001);
+    assert_eq!(statements, expected);
+
+    // errors are located in the whole input, and end the iteration
+    sql.push_str(";\nSELECT 1;\nSELECT (;\nSELECT 2");
+    let mut statements = Parser::iter_statements(&dialect, sql.as_bytes()).skip(2002);
+    assert_eq!(
+        statements.next().unwrap().unwrap_err(),
+        Parser::parse_sql(&dialect, &sql).unwrap_err()
+    );
+    assert!(statements.next().is_none());
+
+    let statements = Parser::iter_statements(&dialect, "SELECT 1; FROBNICATE; SELECT 2".as_bytes())
+        .with_options(ParserOptions::new().with_unparsed_statements(true))
+        .map(|statement| statement.unwrap().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(statements, ["SELECT 1", "FROBNICATE", "SELECT 2"]);
+
+    assert!(Parser::iter_statements(&dialect, "".as_bytes())
+        .next()
+        .is_none());
+    assert!(
+        Parser::iter_statements(&dialect, "SELECT 'unterminated".as_bytes())
+            .next()
+            .unwrap()
+            .is_err()
+    );
+}
+
+#[test]
+fn parse_scalar_subqueries() {
+    let sql = "(SELECT 1) + (SELECT 2)";
+    assert_matches!(
+        verified_expr(sql),
+        Expr::BinaryOp {
+            op: BinaryOperator::Plus,
+            ..
+        }
+    );
+}
+
+#[test]
+fn parse_substring() {
+    verified_stmt("SELECT SUBSTRING('1')");
+    verified_stmt("SELECT SUBSTRING('1' FROM 1)");
+    verified_stmt("SELECT SUBSTRING('1' FROM 1 FOR 3)");
+    verified_stmt("SELECT SUBSTRING('1', 1, 3)");
+    verified_stmt("SELECT SUBSTRING('1', 1)");
+    verified_stmt("SELECT SUBSTRING('1' FOR 3)");
+}
+
+#[test]
+fn parse_overlay() {
+    one_statement_parses_to(
+        "SELECT OVERLAY('abccccde' PLACING 'abc' FROM 3)",
+        "SELECT OVERLAY('abccccde' PLACING 'abc' FROM 3)",
+    );
+    one_statement_parses_to(
+        "SELECT OVERLAY('abccccde' PLACING 'abc' FROM 3 FOR 12)",
+        "SELECT OVERLAY('abccccde' PLACING 'abc' FROM 3 FOR 12)",
+    );
+    assert_eq!(
+        ParserError::ParserError("Expected: PLACING, found: FROM".to_owned()),
+        parse_sql_statements("SELECT OVERLAY('abccccde' FROM 3)").unwrap_err(),
+    );
+
+    let sql = "SELECT OVERLAY('abcdef' PLACING name FROM 3 FOR id + 1) FROM CUSTOMERS";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        &Expr::Overlay {
+            expr: Box::new(Expr::Value(Value::SingleQuotedString("abcdef".to_string()))),
+            overlay_what: Box::new(Expr::Identifier(Ident::new("name"))),
+            overlay_from: Box::new(Expr::Value(number("3"))),
+            overlay_for: Some(Box::new(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("id"))),
+                op: BinaryOperator::Plus,
+                right: Box::new(Expr::Value(number("1"))),
+            })),
+        },
+        expr_from_projection(only(&select.projection))
+    );
+}
+
+#[test]
+fn parse_trim() {
+    one_statement_parses_to(
+        "SELECT TRIM(BOTH 'xyz' FROM 'xyzfooxyz')",
+        "SELECT TRIM(BOTH 'xyz' FROM 'xyzfooxyz')",
     );
 
     one_statement_parses_to(
@@ -6702,7 +7639,7 @@ fn parse_exists_subquery() {
     assert_eq!(
         Expr::Exists {
             negated: false,
-            subquery: Box::new(expected_inner.clone()),
+            subquery: Arc::new(expected_inner.clone()),
         },
         select.selection.unwrap(),
     );
@@ -6712,7 +7649,7 @@ fn parse_exists_subquery() {
     assert_eq!(
         Expr::Exists {
             negated: true,
-            subquery: Box::new(expected_inner),
+            subquery: Arc::new(expected_inner),
         },
         select.selection.unwrap(),
     );
@@ -6831,6 +7768,11 @@ fn parse_create_view() {
             if_not_exists,
             temporary,
             to,
+            engine: _,
+            populate: _,
+            using: _,
+            with_data: _,
+            unknown_options: _,
         } => {
             assert_eq!("myschema.myview", name.to_string());
             assert_eq!(Vec::<ViewColumnDef>::new(), columns);
@@ -6891,6 +7833,11 @@ fn parse_create_view_with_columns() {
             if_not_exists,
             temporary,
             to,
+            engine: _,
+            populate: _,
+            using: _,
+            with_data: _,
+            unknown_options: _,
         } => {
             assert_eq!("v", name.to_string());
             assert_eq!(
@@ -6936,6 +7883,11 @@ fn parse_create_view_temporary() {
             if_not_exists,
             temporary,
             to,
+            engine: _,
+            populate: _,
+            using: _,
+            with_data: _,
+            unknown_options: _,
         } => {
             assert_eq!("myschema.myview", name.to_string());
             assert_eq!(Vec::<ViewColumnDef>::new(), columns);
@@ -6971,6 +7923,11 @@ fn parse_create_or_replace_view() {
             if_not_exists,
             temporary,
             to,
+            engine: _,
+            populate: _,
+            using: _,
+            with_data: _,
+            unknown_options: _,
         } => {
             assert_eq!("v", name.to_string());
             assert_eq!(columns, vec![]);
@@ -7010,6 +7967,11 @@ fn parse_create_or_replace_materialized_view() {
             if_not_exists,
             temporary,
             to,
+            engine: _,
+            populate: _,
+            using: _,
+            with_data: _,
+            unknown_options: _,
         } => {
             assert_eq!("v", name.to_string());
             assert_eq!(columns, vec![]);
@@ -7045,6 +8007,11 @@ fn parse_create_materialized_view() {
             if_not_exists,
             temporary,
             to,
+            engine: _,
+            populate: _,
+            using: _,
+            with_data: _,
+            unknown_options: _,
         } => {
             assert_eq!("myschema.myview", name.to_string());
             assert_eq!(Vec::<ViewColumnDef>::new(), columns);
@@ -7080,6 +8047,11 @@ fn parse_create_materialized_view_with_cluster_by() {
             if_not_exists,
             temporary,
             to,
+            engine: _,
+            populate: _,
+            using: _,
+            with_data: _,
+            unknown_options: _,
         } => {
             assert_eq!("myschema.myview", name.to_string());
             assert_eq!(Vec::<ViewColumnDef>::new(), columns);
@@ -7425,7 +8397,7 @@ fn lateral_function() {
         from: vec![TableWithJoins {
             relation: TableFactor::Table {
                 name: ObjectName(vec![Ident {
-                    value: "customer".to_string(),
+                    value: "customer".into(),
                     quote_style: None,
                 }]),
                 alias: None,
@@ -7445,6 +8417,7 @@ fn lateral_function() {
                             vec![Ident::new("customer"), Ident::new("id")],
                         ))),
                     ],
+                    with_ordinality: false,
                     alias: None,
                 },
                 global: false,
@@ -7466,6 +8439,22 @@ fn lateral_function() {
         connect_by: None,
     };
     assert_eq!(actual_select_only, expected);
+
+    let select = verified_only_select(
+        "SELECT * FROM t CROSS JOIN LATERAL unnest(t.arr) WITH ORDINALITY AS x (i, v)",
+    );
+    match &only(&select.from).joins[0].relation {
+        TableFactor::Function {
+            lateral: true,
+            with_ordinality: true,
+            alias: Some(alias),
+            ..
+        } => assert_eq!(alias.to_string(), "x (i, v)"),
+        relation => panic!("Expected a lateral function, got {relation:?}"),
+    }
+    verified_stmt(
+        "SELECT * FROM t LEFT JOIN LATERAL generate_series(1, t.n) WITH ORDINALITY AS g (n, i) ON true",
+    );
 }
 
 #[test]
@@ -8162,6 +9151,49 @@ fn parse_grant() {
     }
 }
 
+#[test]
+fn parse_grant_on_routines_and_databases() {
+    match verified_stmt("GRANT EXECUTE ON FUNCTION f(INT, TEXT), g TO u") {
+        Statement::Grant {
+            objects: GrantObjects::Functions(functions),
+            ..
+        } => {
+            assert_eq!(
+                functions,
+                vec![
+                    FunctionDesc {
+                        name: ObjectName(vec![Ident::new("f")]),
+                        args: Some(vec![
+                            OperateFunctionArg::unnamed(DataType::Int(None)),
+                            OperateFunctionArg::unnamed(DataType::Text),
+                        ]),
+                    },
+                    FunctionDesc {
+                        name: ObjectName(vec![Ident::new("g")]),
+                        args: None,
+                    },
+                ]
+            );
+        }
+        _ => unreachable!(),
+    }
+    match verified_stmt("GRANT EXECUTE ON PROCEDURE db.p TO u") {
+        Statement::Grant {
+            objects: GrantObjects::Procedures(procedures),
+            ..
+        } => assert_eq!(procedures[0].name.to_string(), "db.p"),
+        _ => unreachable!(),
+    }
+    match verified_stmt("GRANT CONNECT, TEMPORARY ON DATABASE d1, d2 TO u") {
+        Statement::Grant {
+            objects: GrantObjects::Databases(databases),
+            ..
+        } => assert_eq_vec(&["d1", "d2"], &databases),
+        _ => unreachable!(),
+    }
+    verified_stmt("REVOKE EXECUTE ON FUNCTION f(INT) FROM u CASCADE");
+}
+
 #[test]
 fn test_revoke() {
     let sql = "REVOKE ALL PRIVILEGES ON users, auth FROM analyst CASCADE";
@@ -8169,6 +9201,7 @@ fn test_revoke() {
         Statement::Revoke {
             privileges,
             objects: GrantObjects::Tables(tables),
+            grantees_type: None,
             grantees,
             cascade,
             granted_by,
@@ -8233,7 +9266,7 @@ fn parse_merge() {
                 source,
                 TableFactor::Derived {
                     lateral: false,
-                    subquery: Box::new(Query {
+                    subquery: Arc::new(Query {
                         with: None,
                         body: Box::new(SetExpr::Select(Box::new(Select {
                             distinct: None,
@@ -8277,10 +9310,11 @@ fn parse_merge() {
                         for_clause: None,
                         settings: None,
                         format_clause: None,
+                        option_clause: None,
                     }),
                     alias: Some(TableAlias {
                         name: Ident {
-                            value: "stg".to_string(),
+                            value: "stg".into(),
                             quote_style: None,
                         },
                         columns: vec![],
@@ -8381,6 +9415,7 @@ fn parse_merge() {
                                     ]),
                                 },
                             ],
+                            delete_where: None,
                         },
                     },
                     MergeClause {
@@ -8411,6 +9446,41 @@ fn test_merge_into_using_table() {
     verified_stmt(sql);
 }
 
+#[test]
+fn test_merge_update_delete_where() {
+    let sql = "MERGE INTO t USING s ON t.id = s.id \
+        WHEN MATCHED AND s.kind = 'a' THEN UPDATE SET t.a = s.a DELETE WHERE t.a IS NULL \
+        WHEN MATCHED AND s.kind = 'b' THEN DELETE \
+        WHEN MATCHED THEN UPDATE SET t.b = s.b";
+    let dialects = TestedDialects::new(vec![Box::new(GenericDialect {})]);
+    let Statement::Merge { clauses, .. } = dialects.verified_stmt(sql) else {
+        unreachable!()
+    };
+    // The clauses are kept in the order they are evaluated in
+    assert_eq!(
+        vec![
+            "WHEN MATCHED AND s.kind = 'a' THEN UPDATE SET t.a = s.a DELETE WHERE t.a IS NULL",
+            "WHEN MATCHED AND s.kind = 'b' THEN DELETE",
+            "WHEN MATCHED THEN UPDATE SET t.b = s.b",
+        ],
+        clauses.iter().map(ToString::to_string).collect::<Vec<_>>()
+    );
+    match &clauses[0].action {
+        MergeAction::Update { delete_where, .. } => assert_eq!(
+            Some(Expr::IsNull(Box::new(Expr::CompoundIdentifier(vec![
+                Ident::new("t"),
+                Ident::new("a"),
+            ])))),
+            *delete_where
+        ),
+        _ => unreachable!(),
+    }
+
+    assert!(all_dialects_except(|d| d.is::<GenericDialect>())
+        .parse_sql_statements(sql)
+        .is_err());
+}
+
 #[test]
 fn test_merge_with_delimiter() {
     let sql = "MERGE INTO target_table USING source_table \
@@ -8458,7 +9528,7 @@ fn test_lock() {
     assert_eq!(ast.locks.len(), 1);
     let lock = ast.locks.pop().unwrap();
     assert_eq!(lock.lock_type, LockType::Update);
-    assert!(lock.of.is_none());
+    assert!(lock.of.is_empty());
     assert!(lock.nonblock.is_none());
 
     let sql = "SELECT * FROM student WHERE id = '1' FOR SHARE";
@@ -8466,7 +9536,7 @@ fn test_lock() {
     assert_eq!(ast.locks.len(), 1);
     let lock = ast.locks.pop().unwrap();
     assert_eq!(lock.lock_type, LockType::Share);
-    assert!(lock.of.is_none());
+    assert!(lock.of.is_empty());
     assert!(lock.nonblock.is_none());
 }
 
@@ -8478,9 +9548,9 @@ fn test_lock_table() {
     let lock = ast.locks.pop().unwrap();
     assert_eq!(lock.lock_type, LockType::Update);
     assert_eq!(
-        lock.of.unwrap().0,
+        only(lock.of).0,
         vec![Ident {
-            value: "school".to_string(),
+            value: "school".into(),
             quote_style: None
         }]
     );
@@ -8492,9 +9562,9 @@ fn test_lock_table() {
     let lock = ast.locks.pop().unwrap();
     assert_eq!(lock.lock_type, LockType::Share);
     assert_eq!(
-        lock.of.unwrap().0,
+        only(lock.of).0,
         vec![Ident {
-            value: "school".to_string(),
+            value: "school".into(),
             quote_style: None
         }]
     );
@@ -8506,9 +9576,9 @@ fn test_lock_table() {
     let lock = ast.locks.remove(0);
     assert_eq!(lock.lock_type, LockType::Share);
     assert_eq!(
-        lock.of.unwrap().0,
+        only(lock.of).0,
         vec![Ident {
-            value: "school".to_string(),
+            value: "school".into(),
             quote_style: None
         }]
     );
@@ -8516,9 +9586,9 @@ fn test_lock_table() {
     let lock = ast.locks.remove(0);
     assert_eq!(lock.lock_type, LockType::Update);
     assert_eq!(
-        lock.of.unwrap().0,
+        only(lock.of).0,
         vec![Ident {
-            value: "student".to_string(),
+            value: "student".into(),
             quote_style: None
         }]
     );
@@ -8533,9 +9603,9 @@ fn test_lock_nonblock() {
     let lock = ast.locks.pop().unwrap();
     assert_eq!(lock.lock_type, LockType::Update);
     assert_eq!(
-        lock.of.unwrap().0,
+        only(lock.of).0,
         vec![Ident {
-            value: "school".to_string(),
+            value: "school".into(),
             quote_style: None
         }]
     );
@@ -8547,15 +9617,31 @@ fn test_lock_nonblock() {
     let lock = ast.locks.pop().unwrap();
     assert_eq!(lock.lock_type, LockType::Share);
     assert_eq!(
-        lock.of.unwrap().0,
+        only(lock.of).0,
         vec![Ident {
-            value: "school".to_string(),
+            value: "school".into(),
             quote_style: None
         }]
     );
     assert_eq!(lock.nonblock.unwrap(), NonBlock::Nowait);
 }
 
+#[test]
+fn test_lock_multiple_tables() {
+    let sql = "SELECT * FROM a JOIN b ON a.id = b.id JOIN c ON c.id = a.id \
+        FOR UPDATE OF a, s.b SKIP LOCKED FOR SHARE OF c NOWAIT";
+    let ast = verified_query(sql);
+    assert_eq!(ast.locks.len(), 2);
+    assert_eq!(ast.locks[0].lock_type, LockType::Update);
+    assert_eq_vec(&["a", "s.b"], &ast.locks[0].of);
+    assert_eq!(ast.locks[0].nonblock, Some(NonBlock::SkipLocked));
+    assert_eq!(ast.locks[1].lock_type, LockType::Share);
+    assert_eq_vec(&["c"], &ast.locks[1].of);
+    assert_eq!(ast.locks[1].nonblock, Some(NonBlock::Nowait));
+
+    verified_query("SELECT * FROM a, b FOR UPDATE OF a, b NOWAIT");
+}
+
 #[test]
 fn test_placeholder() {
     let dialects = TestedDialects {
@@ -8749,6 +9835,7 @@ fn parse_time_functions() {
             null_treatment: None,
             filter: None,
             over: None,
+            safe: false,
             within_group: vec![],
         };
         assert_eq!(
@@ -9216,21 +10303,60 @@ fn parse_with_recursion_limit() {
     assert!(res.is_ok(), "{res:?}");
 }
 
+#[test]
+fn parse_deeply_nested_parens_with_raised_recursion_limit() {
+    // nested parentheses are parsed without recursing, so they don't
+    // overflow the stack once the recursion limit allows them
+    let dialect = GenericDialect {};
+    let depth = 5000;
+    let sql = format!("SELECT {}1{} + 2", "(".repeat(depth), ")".repeat(depth));
+    let mut parser = Parser::new(&dialect)
+        .with_recursion_limit(depth + 10)
+        .try_with_sql(&sql)
+        .unwrap();
+    let Statement::Query(query) = parser.parse_statement().unwrap() else {
+        unreachable!()
+    };
+    let SetExpr::Select(select) = query.body.as_ref() else {
+        unreachable!()
+    };
+    let SelectItem::UnnamedExpr(Expr::BinaryOp { left, op, right }) = &select.projection[0] else {
+        unreachable!()
+    };
+    assert_eq!(*op, BinaryOperator::Plus);
+    assert_eq!(**right, Expr::Value(number("2")));
+    let mut expr = &**left;
+    let mut nesting = 0;
+    while let Expr::Nested(inner) = expr {
+        expr = inner;
+        nesting += 1;
+    }
+    assert_eq!(nesting, depth);
+    assert_eq!(*expr, Expr::Value(number("1")));
+
+    verified_stmt("SELECT (((a) + (b, c)) * ((a) + (b, c)))");
+    verified_stmt("SELECT ((((a) + 1) * 2), (b).c) FROM t");
+
+    // chains of binary operators don't count towards the limit
+    let sql = format!("SELECT 1{}", " + 1".repeat(depth));
+    assert!(Parser::new(&dialect)
+        .try_with_sql(&sql)
+        .unwrap()
+        .parse_statements()
+        .is_ok());
+}
+
 #[test]
 fn parse_escaped_string_with_unescape() {
     fn assert_mysql_query_value(sql: &str, quoted: &str) {
         let stmt = TestedDialects {
-            dialects: vec![
-                Box::new(MySqlDialect {}),
-                Box::new(BigQueryDialect {}),
-                Box::new(SnowflakeDialect {}),
-            ],
+            dialects: vec![Box::new(MySqlDialect {}), Box::new(SnowflakeDialect {})],
             options: None,
         }
         .one_statement_parses_to(sql, "");
 
         match stmt {
-            Statement::Query(query) => match *query.body {
+            Statement::Query(query) => match *Arc::unwrap_or_clone(query).body {
                 SetExpr::Select(value) => {
                     let expr = expr_from_projection(only(&value.projection));
                     assert_eq!(
@@ -9260,17 +10386,13 @@ fn parse_escaped_string_with_unescape() {
 fn parse_escaped_string_without_unescape() {
     fn assert_mysql_query_value(sql: &str, quoted: &str) {
         let stmt = TestedDialects {
-            dialects: vec![
-                Box::new(MySqlDialect {}),
-                Box::new(BigQueryDialect {}),
-                Box::new(SnowflakeDialect {}),
-            ],
+            dialects: vec![Box::new(MySqlDialect {}), Box::new(SnowflakeDialect {})],
             options: Some(ParserOptions::new().with_unescape(false)),
         }
         .one_statement_parses_to(sql, "");
 
         match stmt {
-            Statement::Query(query) => match *query.body {
+            Statement::Query(query) => match *Arc::unwrap_or_clone(query).body {
                 SetExpr::Select(value) => {
                     let expr = expr_from_projection(only(&value.projection));
                     assert_eq!(
@@ -9296,6 +10418,30 @@ fn parse_escaped_string_without_unescape() {
     assert_mysql_query_value(sql, r"Testing: \0 \\ \% \_ \b \n \r \t \Z \a \ ");
 }
 
+#[test]
+fn parse_backslash_in_string_without_backslash_escape() {
+    // Only doubled quotes are escapes, a backslash is an ordinary character
+    let dialects = all_dialects_where(|d| !d.supports_string_literal_backslash_escape());
+    for (sql, value) in [
+        (r"SELECT 'a\nb'", r"a\nb"),
+        (r"SELECT 'C:\'", r"C:\"),
+        (r"SELECT 'I''m \\ fine'", r"I'm \\ fine"),
+    ] {
+        assert_eq!(
+            dialects.verified_expr(&sql["SELECT ".len()..]),
+            Expr::Value(Value::SingleQuotedString(value.to_string())),
+            "{sql}"
+        );
+    }
+
+    // whereas dialects with backslash escapes unescape them
+    assert_eq!(
+        all_dialects_where(|d| d.supports_string_literal_backslash_escape())
+            .expr_parses_to(r"'a\nb'", "'a\nb'"),
+        Expr::Value(Value::SingleQuotedString("a\nb".to_string()))
+    );
+}
+
 #[test]
 fn parse_pivot_table() {
     let sql = concat!(
@@ -9358,10 +10504,13 @@ fn parse_pivot_table() {
             default_on_null: None,
             alias: Some(TableAlias {
                 name: Ident {
-                    value: "p".to_string(),
+                    value: "p".into(),
                     quote_style: None
                 },
-                columns: vec![Ident::new("c"), Ident::new("d")],
+                columns: vec![
+                    TableAliasColumnDef::from_name("c"),
+                    TableAliasColumnDef::from_name("d"),
+                ],
             }),
         }
     );
@@ -9406,12 +10555,12 @@ fn parse_unpivot_table() {
                 with_ordinality: false,
             }),
             value: Ident {
-                value: "quantity".to_string(),
+                value: "quantity".into(),
                 quote_style: None
             },
 
             name: Ident {
-                value: "quarter".to_string(),
+                value: "quarter".into(),
                 quote_style: None
             },
             columns: ["Q1", "Q2", "Q3", "Q4"]
@@ -9422,7 +10571,7 @@ fn parse_unpivot_table() {
                 name: Ident::new("u"),
                 columns: ["product", "quarter", "quantity"]
                     .into_iter()
-                    .map(Ident::new)
+                    .map(TableAliasColumnDef::from_name)
                     .collect()
             }),
         }
@@ -9473,12 +10622,12 @@ fn parse_pivot_unpivot_table() {
                     with_ordinality: false,
                 }),
                 value: Ident {
-                    value: "population".to_string(),
+                    value: "population".into(),
                     quote_style: None
                 },
 
                 name: Ident {
-                    value: "year".to_string(),
+                    value: "year".into(),
                     quote_style: None
                 },
                 columns: ["population_2000", "population_2010"]
@@ -9766,6 +10915,7 @@ fn parse_call() {
             filter: None,
             null_treatment: None,
             over: None,
+            safe: false,
             within_group: vec![],
         })
     );
@@ -9882,14 +11032,15 @@ fn parse_unload() {
                 order_by: None,
                 settings: None,
                 format_clause: None,
+                option_clause: None,
             }),
             to: Ident {
-                value: "s3://...".to_string(),
+                value: "s3://...".into(),
                 quote_style: Some('\'')
             },
             with: vec![SqlOption::KeyValue {
                 key: Ident {
-                    value: "format".to_string(),
+                    value: "format".into(),
                     quote_style: None
                 },
                 value: Expr::Value(Value::SingleQuotedString("AVRO".to_string()))
@@ -10207,6 +11358,7 @@ fn test_selective_aggregation() {
                     Ident::new("name")
                 ))))),
                 over: None,
+                safe: false,
                 within_group: vec![],
                 null_treatment: None
             })),
@@ -10230,6 +11382,7 @@ fn test_selective_aggregation() {
                     })),
                     null_treatment: None,
                     over: None,
+                    safe: false,
                     within_group: vec![]
                 }),
                 alias: Ident::new("agg2")
@@ -10341,6 +11494,7 @@ fn test_match_recognize() {
                     OneOrMore,
                 ),
             ]),
+            subsets: vec![],
             symbols: vec![
                 SymbolDefinition {
                     symbol: Ident::new("row_with_price_decrease"),
@@ -10415,6 +11569,70 @@ fn test_match_recognize() {
     }
 }
 
+#[test]
+fn test_match_recognize_clauses() {
+    let dialects = all_dialects_where(|d| d.supports_match_recognize());
+
+    for (skip, expected) in [
+        ("PAST LAST ROW", AfterMatchSkip::PastLastRow),
+        ("TO NEXT ROW", AfterMatchSkip::ToNextRow),
+        ("TO FIRST A", AfterMatchSkip::ToFirst(Ident::new("A"))),
+        ("TO LAST A", AfterMatchSkip::ToLast(Ident::new("A"))),
+    ] {
+        let select = dialects.verified_only_select(&format!(
+            "SELECT * FROM t MATCH_RECOGNIZE(AFTER MATCH SKIP {skip} PATTERN (A B*) DEFINE B AS x > 0)"
+        ));
+        match &select.from[0].relation {
+            TableFactor::MatchRecognize {
+                after_match_skip, ..
+            } => assert_eq!(after_match_skip, &Some(expected)),
+            _ => unreachable!(),
+        }
+    }
+
+    // SUBSET, and DEFINE omitted
+    let select = dialects.verified_only_select(
+        "SELECT * FROM t MATCH_RECOGNIZE(ORDER BY ts PATTERN (A B C) SUBSET U = (A, B), V = (C))",
+    );
+    match &select.from[0].relation {
+        TableFactor::MatchRecognize {
+            subsets, symbols, ..
+        } => {
+            assert_eq!(
+                subsets,
+                &vec![
+                    SubsetDefinition {
+                        name: Ident::new("U"),
+                        symbols: vec![Ident::new("A"), Ident::new("B")],
+                    },
+                    SubsetDefinition {
+                        name: Ident::new("V"),
+                        symbols: vec![Ident::new("C")],
+                    },
+                ]
+            );
+            assert!(symbols.is_empty());
+        }
+        _ => unreachable!(),
+    }
+
+    // inside a CTE
+    dialects.verified_query(concat!(
+        "WITH matches AS (",
+        "SELECT * FROM t MATCH_RECOGNIZE(",
+        "PARTITION BY a ORDER BY b ",
+        "MEASURES CLASSIFIER() AS cls ",
+        "ALL ROWS PER MATCH WITH UNMATCHED ROWS ",
+        "AFTER MATCH SKIP TO NEXT ROW ",
+        "PATTERN (A {- B+ -} ( C | ( D | E ) )) ",
+        "SUBSET U = (A, B) ",
+        "DEFINE A AS x > 0",
+        ") AS mr",
+        ") ",
+        "SELECT * FROM matches"
+    ));
+}
+
 #[test]
 fn test_match_recognize_patterns() {
     use MatchRecognizePattern::*;
@@ -10449,7 +11667,43 @@ fn test_match_recognize_patterns() {
     );
 
     // exclusion
-    check("{- FOO -}", Exclude(Named(Ident::new("FOO"))));
+    check(
+        "{- FOO -}",
+        Exclude(Box::new(Symbol(Named(Ident::new("FOO"))))),
+    );
+
+    // exclusion of a nested alternation
+    check(
+        "A {- B+ | ( C D )* -}",
+        Concat(vec![
+            Symbol(Named(Ident::new("A"))),
+            Exclude(Box::new(Alternation(vec![
+                Repetition(Box::new(Symbol(Named(Ident::new("B")))), OneOrMore),
+                Repetition(
+                    Box::new(Group(Box::new(Concat(vec![
+                        Symbol(Named(Ident::new("C"))),
+                        Symbol(Named(Ident::new("D"))),
+                    ])))),
+                    ZeroOrMore,
+                ),
+            ]))),
+        ]),
+    );
+
+    // nested alternation
+    check(
+        "A ( B | ( C | D ) )",
+        Concat(vec![
+            Symbol(Named(Ident::new("A"))),
+            Group(Box::new(Alternation(vec![
+                Symbol(Named(Ident::new("B"))),
+                Group(Box::new(Alternation(vec![
+                    Symbol(Named(Ident::new("C"))),
+                    Symbol(Named(Ident::new("D"))),
+                ]))),
+            ]))),
+        ]),
+    );
 
     check(
         "PERMUTE(A, B, C)",
@@ -10517,7 +11771,7 @@ fn test_match_recognize_patterns() {
         "( {- S3 -} S4 )+",
         Repetition(
             Box::new(Group(Box::new(Concat(vec![
-                Exclude(Named(Ident::new("S3"))),
+                Exclude(Box::new(Symbol(Named(Ident::new("S3"))))),
                 Symbol(Named(Ident::new("S4"))),
             ])))),
             OneOrMore,
@@ -10540,7 +11794,7 @@ fn test_match_recognize_patterns() {
                 ),
                 Repetition(
                     Box::new(Group(Box::new(Concat(vec![
-                        Exclude(Named(Ident::new("S3"))),
+                        Exclude(Box::new(Symbol(Named(Ident::new("S3"))))),
                         Symbol(Named(Ident::new("S4"))),
                     ])))),
                     OneOrMore,
@@ -10954,7 +12208,7 @@ fn test_extract_seconds_ok() {
         stmt,
         Expr::Extract {
             field: DateTimeField::Custom(Ident {
-                value: "seconds".to_string(),
+                value: "seconds".into(),
                 quote_style: None,
             }),
             syntax: ExtractSyntax::From,
@@ -10979,7 +12233,7 @@ fn test_extract_seconds_single_quote_ok() {
         stmt,
         Expr::Extract {
             field: DateTimeField::Custom(Ident {
-                value: "seconds".to_string(),
+                value: "seconds".into(),
                 quote_style: Some('\''),
             }),
             syntax: ExtractSyntax::From,
@@ -11217,6 +12471,39 @@ fn test_create_policy() {
     // omit AS / FOR / TO / USING / WITH CHECK clauses is allowed
     all_dialects().verified_stmt("CREATE POLICY my_policy ON my_table");
 
+    for (policy_type_sql, expected_type) in [
+        ("PERMISSIVE", CreatePolicyType::Permissive),
+        ("RESTRICTIVE", CreatePolicyType::Restrictive),
+    ] {
+        for (command_sql, expected_command) in [
+            ("ALL", CreatePolicyCommand::All),
+            ("SELECT", CreatePolicyCommand::Select),
+            ("INSERT", CreatePolicyCommand::Insert),
+            ("UPDATE", CreatePolicyCommand::Update),
+            ("DELETE", CreatePolicyCommand::Delete),
+        ] {
+            let sql = format!(
+                "CREATE POLICY p ON s.t AS {policy_type_sql} FOR {command_sql} TO PUBLIC, SESSION_USER"
+            );
+            match all_dialects().verified_stmt(&sql) {
+                Statement::CreatePolicy {
+                    policy_type,
+                    command,
+                    to,
+                    ..
+                } => {
+                    assert_eq!(policy_type, Some(expected_type.clone()));
+                    assert_eq!(command, Some(expected_command));
+                    assert_eq!(
+                        to,
+                        Some(vec![Owner::Ident(Ident::new("PUBLIC")), Owner::SessionUser])
+                    );
+                }
+                _ => unreachable!(),
+            }
+        }
+    }
+
     // missing table name
     assert_eq!(
         all_dialects()
@@ -11404,3 +12691,100 @@ fn test_any_some_all_comparison() {
     verified_stmt("SELECT c1 FROM tbl WHERE c1 <> SOME(SELECT c2 FROM tbl)");
     verified_stmt("SELECT 1 = ANY(WITH x AS (SELECT 1) SELECT * FROM x)");
 }
+
+#[test]
+fn parse_permissive_ddl_options() {
+    let permissive = TestedDialects {
+        options: Some(ParserOptions::new().with_permissive_ddl(true)),
+        ..all_dialects()
+    };
+
+    let sql = "CREATE TABLE t (a INT) DISTSTYLE = even SORTKEY (a, b) BACKUP NO";
+    match permissive.verified_stmt(sql) {
+        Statement::CreateTable(CreateTable {
+            dialect_options:
+                CreateTableDialectOptions {
+                    unknown_options, ..
+                },
+            ..
+        }) => {
+            assert_eq!(
+                vec![
+                    DialectSpecificOption {
+                        keyword: Ident::new("DISTSTYLE"),
+                        value: vec![Token::Eq, Token::make_word("even", None)],
+                    },
+                    DialectSpecificOption {
+                        keyword: Ident::new("SORTKEY"),
+                        value: vec![
+                            Token::LParen,
+                            Token::make_word("a", None),
+                            Token::Comma,
+                            Token::make_word("b", None),
+                            Token::RParen,
+                        ],
+                    },
+                    DialectSpecificOption {
+                        keyword: Ident::new("BACKUP"),
+                        value: vec![Token::make_keyword("NO")],
+                    },
+                ],
+                unknown_options
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    permissive.verified_stmt("CREATE VIEW v AS SELECT 1 WITH CASCADED CHECK OPTION");
+    permissive.verified_stmt("ALTER TABLE t ADD COLUMN c INT WITH VALUES");
+
+    // Unknown options are still an error by default
+    assert!(all_dialects()
+        .parse_sql_statements("CREATE TABLE t (a INT) DISTSTYLE = even")
+        .is_err());
+    // ... as are unbalanced parentheses
+    assert!(permissive
+        .parse_sql_statements("CREATE TABLE t (a INT) SORTKEY (a")
+        .is_err());
+}
+
+#[test]
+fn parse_standalone_expr_and_data_type() {
+    for dialect in all_dialects().dialects {
+        assert_eq!(
+            Parser::parse_sql_expr(&*dialect, "a + 1 > 0").unwrap(),
+            Expr::BinaryOp {
+                left: Box::new(Expr::BinaryOp {
+                    left: Box::new(Expr::Identifier(Ident::new("a"))),
+                    op: BinaryOperator::Plus,
+                    right: Box::new(Expr::Value(number("1"))),
+                }),
+                op: BinaryOperator::Gt,
+                right: Box::new(Expr::Value(number("0"))),
+            }
+        );
+        assert_eq!(
+            Parser::parse_sql_data_type(&*dialect, " VARCHAR(20) ").unwrap(),
+            DataType::Varchar(Some(CharacterLength::IntegerLength {
+                length: 20,
+                unit: None
+            }))
+        );
+    }
+
+    let dialect = GenericDialect {};
+    assert_eq!(
+        Parser::parse_sql_expr(&dialect, "a > 0 b").unwrap_err(),
+        ParserError::ParserError(
+            "Expected: end of expression, found: b at Line: 1, Column: 7".to_string()
+        )
+    );
+    assert_eq!(
+        Parser::parse_sql_data_type(&dialect, "INT NOT NULL").unwrap_err(),
+        ParserError::ParserError(
+            "Expected: end of data type, found: NOT at Line: 1, Column: 5".to_string()
+        )
+    );
+    assert!(Parser::parse_sql_expr(&dialect, "a > 0;").is_err());
+    assert!(Parser::parse_sql_expr(&dialect, "").is_err());
+}
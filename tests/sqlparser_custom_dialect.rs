@@ -19,7 +19,7 @@
 
 use sqlparser::{
     ast::{BinaryOperator, Expr, Statement, Value},
-    dialect::Dialect,
+    dialect::{Associativity, Dialect, Precedence},
     keywords::Keyword,
     parser::{Parser, ParserError},
     tokenizer::Token,
@@ -152,6 +152,90 @@ fn test_map_syntax_not_support_default() -> Result<(), ParserError> {
     Ok(())
 }
 
+#[test]
+fn custom_precedence() -> Result<(), ParserError> {
+    #[derive(Debug)]
+    struct MyDialect {}
+
+    impl Dialect for MyDialect {
+        fn is_identifier_start(&self, ch: char) -> bool {
+            is_identifier_start(ch)
+        }
+
+        fn is_identifier_part(&self, ch: char) -> bool {
+            is_identifier_part(ch)
+        }
+
+        // `+` binds tighter than `*`
+        fn prec_value(&self, prec: Precedence) -> u8 {
+            match prec {
+                Precedence::PlusMinus => Precedence::MulDivModOp.default_value(),
+                Precedence::MulDivModOp => Precedence::PlusMinus.default_value(),
+                _ => prec.default_value(),
+            }
+        }
+
+        // `<->` is a right associative operator between `*` and `+`
+        fn get_next_precedence(&self, parser: &Parser) -> Option<Result<u8, ParserError>> {
+            match (parser.peek_token().token, parser.peek_nth_token(1).token) {
+                (Token::Lt, Token::Arrow) => Some(Ok(self.prec_value(Precedence::MulDivModOp) + 5)),
+                _ => None,
+            }
+        }
+
+        fn parse_infix(
+            &self,
+            parser: &mut Parser,
+            expr: &Expr,
+            precedence: u8,
+        ) -> Option<Result<Expr, ParserError>> {
+            if !matches!(
+                (parser.peek_token().token, parser.peek_nth_token(1).token),
+                (Token::Lt, Token::Arrow)
+            ) {
+                return None;
+            }
+            parser.next_token();
+            parser.next_token();
+            Some(
+                parser
+                    .parse_infix_operand(precedence, Associativity::Right)
+                    .map(|right| Expr::BinaryOp {
+                        left: Box::new(expr.clone()),
+                        op: BinaryOperator::Custom("<->".to_string()),
+                        right: Box::new(right),
+                    }),
+            )
+        }
+    }
+
+    let ident = |name: &str| Box::new(Expr::Identifier(name.into()));
+    let binary_op = |left, op, right| Box::new(Expr::BinaryOp { left, op, right });
+    let distance = || BinaryOperator::Custom("<->".to_string());
+
+    let dialect = MyDialect {};
+    let expr = Parser::new(&dialect)
+        .try_with_sql("a * b <-> c <-> d + e")?
+        .parse_expr()?;
+    assert_eq!(
+        expr,
+        *binary_op(
+            ident("a"),
+            BinaryOperator::Multiply,
+            binary_op(
+                ident("b"),
+                distance(),
+                binary_op(
+                    ident("c"),
+                    distance(),
+                    binary_op(ident("d"), BinaryOperator::Plus, ident("e"))
+                )
+            )
+        )
+    );
+    Ok(())
+}
+
 fn is_identifier_start(ch: char) -> bool {
     ch.is_ascii_lowercase() || ch.is_ascii_uppercase() || ch == '_'
 }
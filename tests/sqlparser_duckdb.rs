@@ -21,7 +21,7 @@ mod test_utils;
 use test_utils::*;
 
 use sqlparser::ast::*;
-use sqlparser::dialect::{DuckDbDialect, GenericDialect};
+use sqlparser::dialect::{DuckDbDialect, GenericDialect, PostgreSqlDialect, SnowflakeDialect};
 
 fn duckdb() -> TestedDialects {
     TestedDialects {
@@ -275,7 +275,7 @@ fn test_select_union_by_name() {
                 from: vec![TableWithJoins {
                     relation: TableFactor::Table {
                         name: ObjectName(vec![Ident {
-                            value: "capitals".to_string(),
+                            value: "capitals".into(),
                             quote_style: None,
                         }]),
                         alias: None,
@@ -315,7 +315,7 @@ fn test_select_union_by_name() {
                 from: vec![TableWithJoins {
                     relation: TableFactor::Table {
                         name: ObjectName(vec![Ident {
-                            value: "weather".to_string(),
+                            value: "weather".into(),
                             quote_style: None,
                         }]),
                         alias: None,
@@ -353,7 +353,7 @@ fn test_duckdb_install() {
         stmt,
         Statement::Install {
             extension_name: Ident {
-                value: "tpch".to_string(),
+                value: "tpch".into(),
                 quote_style: None
             }
         }
@@ -366,7 +366,7 @@ fn test_duckdb_load_extension() {
     assert_eq!(
         Statement::Load {
             extension_name: Ident {
-                value: "my_extension".to_string(),
+                value: "my_extension".into(),
                 quote_style: None
             }
         },
@@ -633,6 +633,7 @@ fn test_duckdb_named_argument_function_with_assignment_operator() {
             null_treatment: None,
             filter: None,
             over: None,
+            safe: false,
             within_group: vec![],
         }),
         expr_from_projection(only(&select.projection))
@@ -720,44 +721,54 @@ fn test_duckdb_union_datatype() {
                 }
             ],
             constraints: Default::default(),
-            hive_distribution: HiveDistributionStyle::NONE,
-            hive_formats: Some(HiveFormat {
-                row_format: Default::default(),
-                serde_properties: Default::default(),
-                storage: Default::default(),
-                location: Default::default()
-            }),
-            table_properties: Default::default(),
-            with_options: Default::default(),
-            file_format: Default::default(),
-            location: Default::default(),
             query: Default::default(),
-            without_rowid: Default::default(),
             like: Default::default(),
             clone: Default::default(),
-            engine: Default::default(),
             comment: Default::default(),
-            auto_increment_offset: Default::default(),
-            default_charset: Default::default(),
-            collation: Default::default(),
             on_commit: Default::default(),
-            on_cluster: Default::default(),
-            primary_key: Default::default(),
-            order_by: Default::default(),
-            partition_by: Default::default(),
-            cluster_by: Default::default(),
-            clustered_by: Default::default(),
-            options: Default::default(),
-            strict: Default::default(),
-            copy_grants: Default::default(),
-            enable_schema_evolution: Default::default(),
-            change_tracking: Default::default(),
-            data_retention_time_in_days: Default::default(),
-            max_data_extension_time_in_days: Default::default(),
-            default_ddl_collation: Default::default(),
-            with_aggregation_policy: Default::default(),
-            with_row_access_policy: Default::default(),
-            with_tags: Default::default()
+            storage: CreateTableStorage {
+                hive_formats: Some(HiveFormat {
+                    row_format: Default::default(),
+                    serde_properties: Default::default(),
+                    storage: Default::default(),
+                    location: Default::default()
+                }),
+                table_properties: Default::default(),
+                with_options: Default::default(),
+                file_format: Default::default(),
+                location: Default::default(),
+                engine: Default::default(),
+                auto_increment_offset: Default::default(),
+                default_charset: Default::default(),
+                collation: Default::default()
+            },
+            partitioning: CreateTablePartitioning {
+                hive_distribution: HiveDistributionStyle::NONE,
+                primary_key: Default::default(),
+                order_by: Default::default(),
+                partition_of: Default::default(),
+                for_values: Default::default(),
+                partition_spec: Default::default(),
+                partition_by: Default::default(),
+                cluster_by: Default::default(),
+                clustered_by: Default::default()
+            },
+            dialect_options: CreateTableDialectOptions {
+                without_rowid: Default::default(),
+                on_cluster: Default::default(),
+                options: Default::default(),
+                strict: Default::default(),
+                copy_grants: Default::default(),
+                enable_schema_evolution: Default::default(),
+                change_tracking: Default::default(),
+                data_retention_time_in_days: Default::default(),
+                max_data_extension_time_in_days: Default::default(),
+                default_ddl_collation: Default::default(),
+                with_aggregation_policy: Default::default(),
+                with_row_access_policy: Default::default(),
+                with_tags: Default::default(),
+                unknown_options: vec![],
+            }
         }),
         stmt
     );
@@ -814,3 +825,104 @@ fn parse_use() {
         ])))
     );
 }
+
+#[test]
+fn test_asof_join() {
+    let select = duckdb_and_generic().verified_only_select(
+        "SELECT * FROM trades AS t ASOF JOIN prices AS p ON t.symbol = p.symbol AND t.when >= p.when",
+    );
+    assert!(matches!(
+        &only(&select.from).joins[0].join_operator,
+        JoinOperator::AsOf {
+            match_condition: None,
+            constraint: JoinConstraint::On(_),
+        }
+    ));
+    duckdb_and_generic()
+        .verified_stmt("SELECT * FROM trades ASOF JOIN prices USING(symbol, \"when\")");
+}
+
+#[test]
+fn test_semi_anti_join() {
+    let select = duckdb_and_generic()
+        .verified_only_select("SELECT * FROM t1 SEMI JOIN t2 ON t1.a = t2.a ANTI JOIN t3 USING(a)");
+    let joins = &only(&select.from).joins;
+    assert!(matches!(
+        joins[0].join_operator,
+        JoinOperator::Semi(JoinConstraint::On(_))
+    ));
+    assert!(matches!(
+        joins[1].join_operator,
+        JoinOperator::Anti(JoinConstraint::Using { .. })
+    ));
+    duckdb_and_generic().verified_stmt("SELECT * FROM t1 NATURAL ANTI JOIN t2");
+    // SEMI and ANTI are not parsed as table aliases
+    duckdb_and_generic().one_statement_parses_to(
+        "SELECT * FROM t1 semi JOIN t2 USING(a)",
+        "SELECT * FROM t1 SEMI JOIN t2 USING(a)",
+    );
+    duckdb_and_generic().verified_stmt("SELECT * FROM t1 AS semi ANTI JOIN t2 USING(a)");
+
+    // Dialects without these joins accept SEMI, ANTI and ASOF as aliases
+    let dialects = TestedDialects {
+        dialects: vec![
+            Box::new(PostgreSqlDialect {}),
+            Box::new(SnowflakeDialect {}),
+        ],
+        options: None,
+    };
+    dialects.one_statement_parses_to("SELECT * FROM t semi", "SELECT * FROM t AS semi");
+    dialects.one_statement_parses_to(
+        "SELECT * FROM t1 NATURAL JOIN t2 anti",
+        "SELECT * FROM t1 NATURAL JOIN t2 AS anti",
+    );
+    let postgres = TestedDialects {
+        dialects: vec![Box::new(PostgreSqlDialect {})],
+        options: None,
+    };
+    postgres.one_statement_parses_to("SELECT a FROM t asof", "SELECT a FROM t AS asof");
+}
+
+#[test]
+fn test_table_function_named_args() {
+    let select = duckdb().verified_only_select(
+        "SELECT * FROM read_csv('file.csv', header => true, columns => {'a': 'INT'})",
+    );
+    let TableFactor::Table {
+        name,
+        args: Some(args),
+        ..
+    } = &only(&select.from).relation
+    else {
+        panic!("Expected a table function, got {:?}", select.from);
+    };
+    assert_eq!(name, &ObjectName(vec![Ident::new("read_csv")]));
+    assert_eq!(
+        args.args,
+        vec![
+            FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Value(
+                Value::SingleQuotedString("file.csv".to_string())
+            ))),
+            FunctionArg::Named {
+                name: Ident::new("header"),
+                arg: FunctionArgExpr::Expr(Expr::Value(Value::Boolean(true))),
+                operator: FunctionArgOperator::RightArrow,
+            },
+            FunctionArg::Named {
+                name: Ident::new("columns"),
+                arg: FunctionArgExpr::Expr(Expr::Dictionary(vec![DictionaryField {
+                    key: Ident::with_quote('\'', "a"),
+                    value: Box::new(Expr::Value(Value::SingleQuotedString("INT".to_string()))),
+                }])),
+                operator: FunctionArgOperator::RightArrow,
+            },
+        ]
+    );
+
+    duckdb().verified_stmt(
+        "SELECT * FROM read_csv('f.csv', header = true, columns = {'a': 'INT', 'b': 'VARCHAR'}) AS t",
+    );
+    duckdb().verified_stmt("SELECT * FROM read_json('f.json', transform := x -> x + 1)");
+    duckdb()
+        .verified_stmt("SELECT * FROM f(list_transform([1, 2], x -> x + 1), m => MAP {'k': 1})");
+}
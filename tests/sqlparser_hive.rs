@@ -21,12 +21,13 @@
 //! is also tested (on the inputs it can handle).
 
 use sqlparser::ast::{
-    ClusteredBy, CommentDef, CreateFunctionBody, CreateFunctionUsing, CreateTable, Expr, Function,
-    FunctionArgumentList, FunctionArguments, Ident, ObjectName, OneOrManyWithParens, OrderByExpr,
-    SelectItem, Statement, TableFactor, UnaryOperator, Use, Value,
+    ClusteredBy, CommentDef, CreateFunctionBody, CreateFunctionUsing, CreateTable,
+    CreateTablePartitioning, Expr, Function, FunctionArgumentList, FunctionArguments, Ident,
+    LateralView, ObjectName, OneOrManyWithParens, OrderByExpr, SelectItem, Statement, TableFactor,
+    UnaryOperator, Use, Value,
 };
 use sqlparser::dialect::{GenericDialect, HiveDialect, MsSqlDialect};
-use sqlparser::parser::ParserError;
+use sqlparser::parser::{ParserError, ParserOptions};
 use sqlparser::test_utils::*;
 
 #[test]
@@ -162,7 +163,10 @@ fn create_table_with_clustered_by() {
         " INTO 4 BUCKETS"
     );
     match hive_and_generic().verified_stmt(sql) {
-        Statement::CreateTable(CreateTable { clustered_by, .. }) => {
+        Statement::CreateTable(CreateTable {
+            partitioning: CreateTablePartitioning { clustered_by, .. },
+            ..
+        }) => {
             assert_eq!(
                 clustered_by.unwrap(),
                 ClusteredBy {
@@ -333,6 +337,42 @@ fn create_local_directory() {
 fn lateral_view() {
     let view = "SELECT a FROM db.table LATERAL VIEW explode(a) t AS j, P LATERAL VIEW OUTER explode(a) t AS a, b WHERE a = 1";
     hive().verified_stmt(view);
+
+    let select = hive().verified_only_select(
+        "SELECT a, b, c FROM t LATERAL VIEW OUTER explode(col) t2 AS a, b LATERAL VIEW explode(col2) t3 AS c ORDER BY c",
+    );
+    assert_eq!(
+        select.lateral_views,
+        vec![
+            LateralView {
+                lateral_view: call("explode", [Expr::Identifier(Ident::new("col"))]),
+                lateral_view_name: Some(ObjectName(vec![Ident::new("t2")])),
+                lateral_col_alias: vec![Ident::new("a"), Ident::new("b")],
+                outer: true,
+            },
+            LateralView {
+                lateral_view: call("explode", [Expr::Identifier(Ident::new("col2"))]),
+                lateral_view_name: Some(ObjectName(vec![Ident::new("t3")])),
+                lateral_col_alias: vec![Ident::new("c")],
+                outer: false,
+            },
+        ]
+    );
+
+    // the table name may be omitted
+    let select = hive().verified_only_select("SELECT c FROM t LATERAL VIEW explode(arr) AS c");
+    assert_eq!(select.lateral_views[0].lateral_view_name, None);
+    assert_eq!(
+        select.lateral_views[0].lateral_col_alias,
+        vec![Ident::new("c")]
+    );
+
+    // keywords ending the FROM clause are not parsed as column aliases
+    hive().verified_stmt("SELECT * FROM t LATERAL VIEW explode(arr) t2 LIMIT 1");
+    hive().one_statement_parses_to(
+        "SELECT * FROM t LATERAL VIEW explode(arr) t2 c UNION SELECT 1",
+        "SELECT * FROM t LATERAL VIEW explode(arr) t2 AS c UNION SELECT 1",
+    );
 }
 
 #[test]
@@ -490,6 +530,7 @@ fn parse_delimited_identifiers() {
             null_treatment: None,
             filter: None,
             over: None,
+            safe: false,
             within_group: vec![],
         }),
         expr_from_projection(&select.projection[1]),
@@ -537,6 +578,38 @@ fn parse_use() {
     );
 }
 
+#[test]
+fn parse_variable_substitution() {
+    let dialects = TestedDialects {
+        dialects: vec![Box::new(HiveDialect {}), Box::new(GenericDialect {})],
+        options: Some(ParserOptions::new().with_variable_substitution(true)),
+    };
+    let select = dialects.verified_only_select(
+        "SELECT a, ${hiveconf:col} FROM ${hiveconf:db}.events WHERE dt = '${hiveconf:date}' AND env = ${env:X}",
+    );
+    assert_eq!(
+        select.projection[1],
+        SelectItem::UnnamedExpr(Expr::Value(Value::Placeholder(
+            "${hiveconf:col}".to_string()
+        )))
+    );
+    match &select.from[0].relation {
+        TableFactor::Table { name, .. } => {
+            assert_eq!(
+                name,
+                &ObjectName(vec![Ident::new("${hiveconf:db}"), Ident::new("events")])
+            );
+        }
+        _ => unreachable!(),
+    }
+    dialects.verified_stmt("INSERT OVERWRITE TABLE ${target} SELECT * FROM ${source}");
+
+    // without the option, `${` is not a valid token sequence
+    assert!(hive()
+        .parse_sql_statements("SELECT * FROM ${hiveconf:db}.events")
+        .is_err());
+}
+
 fn hive() -> TestedDialects {
     TestedDialects {
         dialects: vec![Box::new(HiveDialect {})],
@@ -30,6 +30,7 @@ use sqlparser::ast::Value::SingleQuotedString;
 use sqlparser::ast::*;
 use sqlparser::dialect::{GenericDialect, MsSqlDialect};
 use sqlparser::parser::{Parser, ParserError};
+use std::sync::Arc;
 
 #[test]
 fn parse_mssql_identifiers() {
@@ -100,7 +101,8 @@ fn parse_create_procedure() {
         ms().verified_stmt(sql),
         Statement::CreateProcedure {
             or_alter: true,
-            body: vec![Statement::Query(Box::new(Query {
+            or_replace: false,
+            body: CreateProcedureBody::Statements(vec![Statement::Query(Arc::new(Query {
                 with: None,
                 limit: None,
                 limit_by: vec![],
@@ -111,6 +113,7 @@ fn parse_create_procedure() {
                 order_by: None,
                 settings: None,
                 format_clause: None,
+                option_clause: None,
                 body: Box::new(SetExpr::Select(Box::new(Select {
                     distinct: None,
                     top: None,
@@ -131,9 +134,10 @@ fn parse_create_procedure() {
                     value_table_mode: None,
                     connect_by: None,
                 })))
-            }))],
+            }))]),
             params: Some(vec![
                 ProcedureParam {
+                    mode: None,
                     name: Ident {
                         value: "@foo".into(),
                         quote_style: None
@@ -141,6 +145,7 @@ fn parse_create_procedure() {
                     data_type: DataType::Int(None)
                 },
                 ProcedureParam {
+                    mode: None,
                     name: Ident {
                         value: "@bar".into(),
                         quote_style: None
@@ -154,7 +159,13 @@ fn parse_create_procedure() {
             name: ObjectName(vec![Ident {
                 value: "test".into(),
                 quote_style: None
-            }])
+            }]),
+            return_type: None,
+            language: None,
+            runtime_version: None,
+            packages: None,
+            imports: None,
+            handler: None,
         }
     )
 }
@@ -176,6 +187,98 @@ fn parse_mssql_create_procedure() {
     let _ = ms().verified_stmt("CREATE PROCEDURE [foo] AS BEGIN UPDATE bar SET col = 'test'; SELECT [foo] FROM BAR WHERE [FOO] > 10 END");
 }
 
+#[test]
+fn parse_mssql_quoted_variables() {
+    let select = ms_and_generic().verified_only_select(r#"SELECT @"x y" FROM t"#);
+    assert_eq!(
+        select.projection[0],
+        SelectItem::UnnamedExpr(Expr::Value(Value::Placeholder(r#"@"x y""#.to_string())))
+    );
+    ms_and_generic().verified_stmt(r#"SELECT * FROM t WHERE a = @"x y""#);
+}
+
+#[test]
+fn parse_mssql_control_flow() {
+    let sql = "IF @x > 1 SELECT 1 ELSE SELECT 2";
+    match ms().verified_stmt(sql) {
+        Statement::If {
+            condition,
+            then_statement,
+            else_statement,
+        } => {
+            assert_eq!(condition.to_string(), "@x > 1");
+            assert_eq!(then_statement.to_string(), "SELECT 1");
+            assert_eq!(else_statement.unwrap().to_string(), "SELECT 2");
+        }
+        _ => unreachable!(),
+    }
+
+    let sql = "WHILE @i < 10 BEGIN SET @i = @i + 1; IF @i = 5 RETURN END";
+    match ms().verified_stmt(sql) {
+        Statement::While { condition, body } => {
+            assert_eq!(condition.to_string(), "@i < 10");
+            match *body {
+                Statement::Block(statements) => {
+                    assert_eq!(statements.len(), 2);
+                    assert_eq!(
+                        statements[1],
+                        Statement::If {
+                            condition: Expr::BinaryOp {
+                                left: Box::new(Expr::Identifier(Ident::new("@i"))),
+                                op: BinaryOperator::Eq,
+                                right: Box::new(Expr::Value(number("5"))),
+                            },
+                            then_statement: Box::new(Statement::Return(None)),
+                            else_statement: None,
+                        }
+                    );
+                }
+                _ => unreachable!(),
+            }
+        }
+        _ => unreachable!(),
+    }
+
+    ms().verified_stmt("RETURN @x + 1");
+    ms().verified_stmt("BEGIN TRANSACTION");
+    ms().one_statement_parses_to(
+        "IF EXISTS (SELECT 1 FROM t) BEGIN UPDATE t SET a = 1 DELETE FROM t WHERE b = 2 END; ELSE RETURN -1",
+        "IF EXISTS (SELECT 1 FROM t) BEGIN UPDATE t SET a = 1; DELETE FROM t WHERE b = 2 END ELSE RETURN -1",
+    );
+    assert_eq!(
+        ms().parse_sql_statements("IF 1 = 1 SELECT 1; SELECT 2")
+            .unwrap()
+            .len(),
+        2
+    );
+
+    // Statements of a procedure body don't need to be separated by semicolons
+    ms().one_statement_parses_to(
+        "CREATE PROCEDURE p (@a INT) AS BEGIN \
+            IF @a IS NULL RETURN 0 \
+            WHILE @a > 0 BEGIN DELETE FROM t WHERE a = @a SET @a = @a - 1 END \
+            RETURN 1 \
+        END",
+        "CREATE PROCEDURE p (@a INT) AS BEGIN \
+            IF @a IS NULL RETURN 0; \
+            WHILE @a > 0 BEGIN DELETE FROM t WHERE a = @a; SET @a = @a - 1 END; \
+            RETURN 1 \
+        END",
+    );
+
+    assert_eq!(
+        ms().parse_sql_statements("BEGIN SELECT 1").unwrap_err(),
+        ParserError::ParserError("Expected: END, found: EOF".to_string())
+    );
+
+    // The control flow keywords are only reserved as aliases in MSSQL
+    let dialects = all_dialects_except(|d| d.is::<MsSqlDialect>());
+    dialects.one_statement_parses_to("SELECT 1 if", "SELECT 1 AS if");
+    dialects.one_statement_parses_to("SELECT 1 return", "SELECT 1 AS return");
+    dialects.one_statement_parses_to("SELECT * FROM t else", "SELECT * FROM t AS else");
+    dialects.one_statement_parses_to("SELECT * FROM t while", "SELECT * FROM t AS while");
+}
+
 #[test]
 fn parse_mssql_apply_join() {
     let _ = ms_and_generic().verified_only_select(
@@ -373,6 +476,7 @@ fn parse_delimited_identifiers() {
             null_treatment: None,
             filter: None,
             over: None,
+            safe: false,
             within_group: vec![],
         }),
         expr_from_projection(&select.projection[1]),
@@ -424,6 +528,28 @@ fn parse_for_clause() {
     ms_and_generic().verified_stmt("SELECT z FROM t FOR XML EXPLICIT, BINARY BASE64");
     ms_and_generic().verified_stmt("SELECT * FROM t FOR XML RAW('x')");
     ms_and_generic().verified_stmt("SELECT * FROM t FOR BROWSE");
+    ms_and_generic().verified_stmt(
+        "SELECT a FROM t FOR JSON PATH, ROOT('x'), INCLUDE_NULL_VALUES, WITHOUT_ARRAY_WRAPPER",
+    );
+    ms_and_generic().verified_stmt("SELECT a FROM t FOR JSON AUTO, ROOT");
+    ms_and_generic().verified_stmt("SELECT a FROM t FOR XML PATH, ROOT");
+    ms_and_generic().verified_stmt("SELECT a FROM t FOR XML RAW, ELEMENTS XSINIL");
+    ms_and_generic().verified_stmt("SELECT a FROM t FOR XML PATH('row'), ELEMENTS ABSENT");
+    ms_and_generic().verified_stmt("SELECT a FROM t FOR XML AUTO, XMLDATA");
+    ms_and_generic().verified_stmt(
+        "SELECT a FROM t FOR XML RAW('r'), BINARY BASE64, TYPE, ROOT('it''s'), XMLSCHEMA('urn:x'), ELEMENTS XSINIL",
+    );
+    // options are displayed in the documented order
+    ms_and_generic().one_statement_parses_to(
+        "SELECT a FROM t FOR XML AUTO, ELEMENTS, TYPE",
+        "SELECT a FROM t FOR XML AUTO, TYPE, ELEMENTS",
+    );
+    ms_and_generic().verified_stmt(
+        "SELECT a, (SELECT b FROM u WHERE u.id = t.id FOR JSON AUTO) AS bs FROM t FOR JSON PATH",
+    );
+    assert!(ms()
+        .parse_sql_statements("SELECT a FROM t FOR JSON PATH, ELEMENTS")
+        .is_err());
 }
 
 #[test]
@@ -441,13 +567,133 @@ fn parse_for_json_expect_ast() {
             .unwrap(),
         ForClause::Json {
             for_json: ForJson::Path,
-            root: Some("root".into()),
+            root: Some(Some("root".into())),
             without_array_wrapper: false,
             include_null_values: false,
         }
     );
 }
 
+#[test]
+fn parse_table_hints() {
+    let select = ms().verified_only_select(
+        "SELECT * FROM t WITH (NOLOCK, INDEX(ix_a, 2), FORCESEEK(ix_b(a, b)), SPATIAL_WINDOW_MAX_CELLS = 512)",
+    );
+    let TableFactor::Table { with_hints, .. } = &select.from[0].relation else {
+        unreachable!()
+    };
+    assert_eq!(
+        with_hints,
+        &vec![
+            TableHint::Keyword(Ident::new("NOLOCK")),
+            TableHint::Index {
+                indexes: vec![
+                    Expr::Identifier(Ident::new("ix_a")),
+                    Expr::Value(number("2"))
+                ],
+                equals: false,
+            },
+            TableHint::ForceSeek {
+                index: Expr::Identifier(Ident::new("ix_b")),
+                columns: vec![Ident::new("a"), Ident::new("b")],
+            },
+            TableHint::Expr(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("SPATIAL_WINDOW_MAX_CELLS"))),
+                op: BinaryOperator::Eq,
+                right: Box::new(Expr::Value(number("512"))),
+            }),
+        ]
+    );
+
+    ms().verified_only_select("SELECT * FROM t AS x WITH (INDEX = ix, FORCESEEK, READPAST)");
+    ms().one_statement_parses_to(
+        "SELECT * FROM t WITH (FORCESEEK (ix (a)))",
+        "SELECT * FROM t WITH (FORCESEEK(ix(a)))",
+    );
+}
+
+#[test]
+fn parse_query_hints() {
+    let query = ms().verified_query(
+        "SELECT a FROM t ORDER BY a OPTION (MAXDOP 1, RECOMPILE, HASH JOIN, MAX_GRANT_PERCENT = 5)",
+    );
+    assert_eq!(
+        query.option_clause,
+        Some(vec![
+            QueryHint::Keywords {
+                keywords: vec![Ident::new("MAXDOP")],
+                value: Some(Expr::Value(number("1"))),
+            },
+            QueryHint::Keywords {
+                keywords: vec![Ident::new("RECOMPILE")],
+                value: None,
+            },
+            QueryHint::Keywords {
+                keywords: vec![Ident::new("HASH"), Ident::new("JOIN")],
+                value: None,
+            },
+            QueryHint::Assignment {
+                name: Ident::new("MAX_GRANT_PERCENT"),
+                value: Expr::Value(number("5")),
+            },
+        ])
+    );
+
+    let query = ms()
+        .verified_query("SELECT * FROM t WHERE a = @a OPTION (OPTIMIZE FOR (@a = 1, @b UNKNOWN))");
+    assert_eq!(
+        query.option_clause,
+        Some(vec![QueryHint::OptimizeFor(vec![
+            OptimizeForVariable {
+                name: Ident::new("@a"),
+                value: Some(Expr::Value(number("1"))),
+            },
+            OptimizeForVariable {
+                name: Ident::new("@b"),
+                value: None,
+            },
+        ])])
+    );
+
+    ms().verified_stmt(
+        "SELECT a FROM t OPTION (OPTIMIZE FOR UNKNOWN, USE HINT ('DISABLE_OPTIMIZED_NESTED_LOOP'))",
+    );
+    ms().verified_stmt(
+        "SELECT * FROM t OPTION (TABLE HINT (t, NOLOCK, INDEX(ix)), TABLE HINT (u))",
+    );
+    ms().verified_stmt(
+        "SELECT a FROM t UNION SELECT b FROM u OPTION (MERGE UNION, LABEL = 'q', USE PLAN N'<x/>')",
+    );
+    ms().verified_stmt("SELECT * FROM t FOR JSON AUTO OPTION (KEEPFIXED PLAN)");
+    ms().verified_stmt("WITH c AS (SELECT 1 AS a) SELECT * FROM c OPTION (MAXRECURSION 0)");
+
+    assert_eq!(
+        ms().parse_sql_statements("SELECT * FROM t OPTION (OPTIMIZE FOR (@a))")
+            .unwrap_err(),
+        ParserError::ParserError("Expected: =, found: )".to_string())
+    );
+
+    // OPTION is only reserved as a table alias
+    all_dialects().one_statement_parses_to("SELECT a option FROM t", "SELECT a AS option FROM t");
+}
+
+#[test]
+fn parse_for_xml_expect_ast() {
+    assert_eq!(
+        ms().verified_query("SELECT * FROM t FOR XML RAW, XMLSCHEMA, ELEMENTS XSINIL")
+            .for_clause
+            .unwrap(),
+        ForClause::Xml {
+            for_xml: ForXml::Raw(None),
+            schema: Some(ForXmlSchema::XmlSchema(None)),
+            elements: Some(ForXmlElements::XsiNil),
+            binary_base64: false,
+            root: None,
+            r#type: false,
+        }
+    );
+}
+
 #[test]
 fn parse_ampersand_arobase() {
     // In SQL Server, a&@b means (a) & (@b), in PostgreSQL it means (a) &@ (b)
@@ -464,6 +710,7 @@ fn parse_cast_varchar_max() {
 fn parse_convert() {
     let sql = "CONVERT(INT, 1, 2, 3, NULL)";
     let Expr::Convert {
+        is_try,
         expr,
         data_type,
         charset,
@@ -473,6 +720,7 @@ fn parse_convert() {
     else {
         unreachable!()
     };
+    assert!(!is_try);
     assert_eq!(Expr::Value(number("1")), *expr);
     assert_eq!(Some(DataType::Int(None)), data_type);
     assert!(charset.is_none());
@@ -497,6 +745,63 @@ fn parse_convert() {
     );
 }
 
+#[test]
+fn parse_try_convert() {
+    match ms().verified_expr("TRY_CONVERT(INT, col, 112)") {
+        Expr::Convert {
+            is_try,
+            expr,
+            data_type,
+            styles,
+            ..
+        } => {
+            assert!(is_try);
+            assert_eq!(Expr::Identifier(Ident::new("col")), *expr);
+            assert_eq!(Some(DataType::Int(None)), data_type);
+            assert_eq!(vec![Expr::Value(number("112"))], styles);
+        }
+        _ => unreachable!(),
+    }
+    ms().verified_expr("TRY_CONVERT(VARCHAR(10), 'foo')");
+}
+
+#[test]
+fn parse_parse_function() {
+    match ms().verified_expr("PARSE('2024-01-02' AS DATE USING 'en-US')") {
+        Expr::Parse {
+            is_try,
+            expr,
+            data_type,
+            culture,
+        } => {
+            assert!(!is_try);
+            assert_eq!(
+                Expr::Value(Value::SingleQuotedString("2024-01-02".to_string())),
+                *expr
+            );
+            assert_eq!(DataType::Date, data_type);
+            assert_eq!(
+                Some(Box::new(Expr::Value(Value::SingleQuotedString(
+                    "en-US".to_string()
+                )))),
+                culture
+            );
+        }
+        _ => unreachable!(),
+    }
+    ms().verified_expr("TRY_PARSE(@s AS DECIMAL(10,2))");
+    ms().verified_expr("TRY_PARSE('€345,98' AS MONEY USING @culture)");
+
+    // `parse` is still usable as an identifier
+    ms().verified_only_select("SELECT parse, try_parse FROM t");
+
+    assert_eq!(
+        ms().parse_sql_statements("SELECT PARSE('1', INT)")
+            .unwrap_err(),
+        ParserError::ParserError("Expected: AS, found: ,".to_owned())
+    );
+}
+
 #[test]
 fn parse_substring_in_select() {
     let sql = "SELECT DISTINCT SUBSTRING(description, 0, 1) FROM test";
@@ -506,7 +811,7 @@ fn parse_substring_in_select() {
     ) {
         Statement::Query(query) => {
             assert_eq!(
-                Box::new(Query {
+                Arc::new(Query {
                     with: None,
 
                     body: Box::new(SetExpr::Select(Box::new(Select {
@@ -514,7 +819,7 @@ fn parse_substring_in_select() {
                         top: None,
                         projection: vec![SelectItem::UnnamedExpr(Expr::Substring {
                             expr: Box::new(Expr::Identifier(Ident {
-                                value: "description".to_string(),
+                                value: "description".into(),
                                 quote_style: None
                             })),
                             substring_from: Some(Box::new(Expr::Value(number("0")))),
@@ -525,7 +830,7 @@ fn parse_substring_in_select() {
                         from: vec![TableWithJoins {
                             relation: TableFactor::Table {
                                 name: ObjectName(vec![Ident {
-                                    value: "test".to_string(),
+                                    value: "test".into(),
                                     quote_style: None
                                 }]),
                                 alias: None,
@@ -560,6 +865,7 @@ fn parse_substring_in_select() {
                     for_clause: None,
                     settings: None,
                     format_clause: None,
+                    option_clause: None,
                 }),
                 query
             );
@@ -578,7 +884,7 @@ fn parse_mssql_declare() {
             stmts: vec![
                 Declare {
                     names: vec![Ident {
-                        value: "@foo".to_string(),
+                        value: "@foo".into(),
                         quote_style: None
                     }],
                     data_type: None,
@@ -592,7 +898,7 @@ fn parse_mssql_declare() {
                 },
                 Declare {
                     names: vec![Ident {
-                        value: "@bar".to_string(),
+                        value: "@bar".into(),
                         quote_style: None
                     }],
                     data_type: Some(Int(None)),
@@ -606,7 +912,7 @@ fn parse_mssql_declare() {
                 },
                 Declare {
                     names: vec![Ident {
-                        value: "@baz".to_string(),
+                        value: "@baz".into(),
                         quote_style: None
                     }],
                     data_type: Some(Text),
@@ -666,11 +972,11 @@ fn parse_create_table_with_valid_options() {
             vec![
                 SqlOption::KeyValue {
                     key: Ident {
-                        value: "DISTRIBUTION".to_string(),
+                        value: "DISTRIBUTION".into(),
                         quote_style: None,
                     },
                     value: Expr::Identifier(Ident {
-                        value: "ROUND_ROBIN".to_string(),
+                        value: "ROUND_ROBIN".into(),
                         quote_style: None,
                     })
                 },
@@ -713,21 +1019,21 @@ fn parse_create_table_with_valid_options() {
                 SqlOption::Clustered(TableOptionsClustered::Index(vec![
                         ClusteredIndex {
                             name: Ident {
-                                value: "column_a".to_string(),
+                                value: "column_a".into(),
                                 quote_style: None,
                             },
                             asc: Some(true),
                         },
                         ClusteredIndex {
                             name: Ident {
-                                value: "column_b".to_string(),
+                                value: "column_b".into(),
                                 quote_style: None,
                             },
                             asc: Some(false),
                         },
                         ClusteredIndex {
                             name: Ident {
-                                value: "column_c".to_string(),
+                                value: "column_c".into(),
                                 quote_style: None,
                             },
                             asc: None,
@@ -740,7 +1046,7 @@ fn parse_create_table_with_valid_options() {
             vec![
                 SqlOption::KeyValue {
                     key: Ident {
-                        value: "DISTRIBUTION".to_string(),
+                        value: "DISTRIBUTION".into(),
                         quote_style: None,
                     },
                     value: Expr::Function(
@@ -748,7 +1054,7 @@ fn parse_create_table_with_valid_options() {
                             name: ObjectName(
                                 vec![
                                     Ident {
-                                        value: "HASH".to_string(),
+                                        value: "HASH".into(),
                                         quote_style: None,
                                     },
                                 ],
@@ -762,7 +1068,7 @@ fn parse_create_table_with_valid_options() {
                                             FunctionArgExpr::Expr(
                                                 Expr::Identifier(
                                                     Ident {
-                                                        value: "column_a".to_string(),
+                                                        value: "column_a".into(),
                                                         quote_style: None,
                                                     },
                                                 ),
@@ -772,7 +1078,7 @@ fn parse_create_table_with_valid_options() {
                                             FunctionArgExpr::Expr(
                                                 Expr::Identifier(
                                                     Ident {
-                                                        value: "column_b".to_string(),
+                                                        value: "column_b".into(),
                                                         quote_style: None,
                                                     },
                                                 ),
@@ -785,6 +1091,7 @@ fn parse_create_table_with_valid_options() {
                             filter: None,
                             null_treatment: None,
                             over: None,
+                            safe: false,
                             within_group: vec![],
                         },
                     ),
@@ -806,13 +1113,13 @@ fn parse_create_table_with_valid_options() {
                 transient: false,
                 volatile: false,
                 name: ObjectName(vec![Ident {
-                    value: "mytable".to_string(),
+                    value: "mytable".into(),
                     quote_style: None,
                 },],),
                 columns: vec![
                     ColumnDef {
                         name: Ident {
-                            value: "column_a".to_string(),
+                            value: "column_a".into(),
                             quote_style: None,
                         },
                         data_type: Int(None,),
@@ -821,7 +1128,7 @@ fn parse_create_table_with_valid_options() {
                     },
                     ColumnDef {
                         name: Ident {
-                            value: "column_b".to_string(),
+                            value: "column_b".into(),
                             quote_style: None,
                         },
                         data_type: Int(None,),
@@ -830,7 +1137,7 @@ fn parse_create_table_with_valid_options() {
                     },
                     ColumnDef {
                         name: Ident {
-                            value: "column_c".to_string(),
+                            value: "column_c".into(),
                             quote_style: None,
                         },
                         data_type: Int(None,),
@@ -839,44 +1146,54 @@ fn parse_create_table_with_valid_options() {
                     },
                 ],
                 constraints: vec![],
-                hive_distribution: HiveDistributionStyle::NONE,
-                hive_formats: Some(HiveFormat {
-                    row_format: None,
-                    serde_properties: None,
-                    storage: None,
-                    location: None,
-                },),
-                table_properties: vec![],
-                with_options,
-                file_format: None,
-                location: None,
                 query: None,
-                without_rowid: false,
                 like: None,
                 clone: None,
-                engine: None,
                 comment: None,
-                auto_increment_offset: None,
-                default_charset: None,
-                collation: None,
                 on_commit: None,
-                on_cluster: None,
-                primary_key: None,
-                order_by: None,
-                partition_by: None,
-                cluster_by: None,
-                clustered_by: None,
-                options: None,
-                strict: false,
-                copy_grants: false,
-                enable_schema_evolution: None,
-                change_tracking: None,
-                data_retention_time_in_days: None,
-                max_data_extension_time_in_days: None,
-                default_ddl_collation: None,
-                with_aggregation_policy: None,
-                with_row_access_policy: None,
-                with_tags: None,
+                storage: CreateTableStorage {
+                    hive_formats: Some(HiveFormat {
+                        row_format: None,
+                        serde_properties: None,
+                        storage: None,
+                        location: None,
+                    },),
+                    table_properties: vec![],
+                    with_options,
+                    file_format: None,
+                    location: None,
+                    engine: None,
+                    auto_increment_offset: None,
+                    default_charset: None,
+                    collation: None
+                },
+                partitioning: CreateTablePartitioning {
+                    hive_distribution: HiveDistributionStyle::NONE,
+                    primary_key: None,
+                    order_by: None,
+                    partition_of: None,
+                    for_values: None,
+                    partition_spec: None,
+                    partition_by: None,
+                    cluster_by: None,
+                    clustered_by: None
+                },
+                dialect_options: CreateTableDialectOptions {
+                    without_rowid: false,
+                    on_cluster: None,
+                    options: None,
+                    strict: false,
+                    copy_grants: false,
+                    enable_schema_evolution: None,
+                    change_tracking: None,
+                    data_retention_time_in_days: None,
+                    max_data_extension_time_in_days: None,
+                    default_ddl_collation: None,
+                    with_aggregation_policy: None,
+                    with_row_access_policy: None,
+                    with_tags: None,
+                    unknown_options: vec![],
+                }
             })
         );
     }
@@ -968,12 +1285,12 @@ fn parse_create_table_with_identity_column() {
                 transient: false,
                 volatile: false,
                 name: ObjectName(vec![Ident {
-                    value: "mytable".to_string(),
+                    value: "mytable".into(),
                     quote_style: None,
                 },],),
                 columns: vec![ColumnDef {
                     name: Ident {
-                        value: "columnA".to_string(),
+                        value: "columnA".into(),
                         quote_style: None,
                     },
                     data_type: Int(None,),
@@ -981,49 +1298,95 @@ fn parse_create_table_with_identity_column() {
                     options: column_options,
                 },],
                 constraints: vec![],
-                hive_distribution: HiveDistributionStyle::NONE,
-                hive_formats: Some(HiveFormat {
-                    row_format: None,
-                    serde_properties: None,
-                    storage: None,
-                    location: None,
-                },),
-                table_properties: vec![],
-                with_options: vec![],
-                file_format: None,
-                location: None,
                 query: None,
-                without_rowid: false,
                 like: None,
                 clone: None,
-                engine: None,
                 comment: None,
-                auto_increment_offset: None,
-                default_charset: None,
-                collation: None,
                 on_commit: None,
-                on_cluster: None,
-                primary_key: None,
-                order_by: None,
-                partition_by: None,
-                cluster_by: None,
-                clustered_by: None,
-                options: None,
-                strict: false,
-                copy_grants: false,
-                enable_schema_evolution: None,
-                change_tracking: None,
-                data_retention_time_in_days: None,
-                max_data_extension_time_in_days: None,
-                default_ddl_collation: None,
-                with_aggregation_policy: None,
-                with_row_access_policy: None,
-                with_tags: None,
+                storage: CreateTableStorage {
+                    hive_formats: Some(HiveFormat {
+                        row_format: None,
+                        serde_properties: None,
+                        storage: None,
+                        location: None,
+                    },),
+                    table_properties: vec![],
+                    with_options: vec![],
+                    file_format: None,
+                    location: None,
+                    engine: None,
+                    auto_increment_offset: None,
+                    default_charset: None,
+                    collation: None
+                },
+                partitioning: CreateTablePartitioning {
+                    hive_distribution: HiveDistributionStyle::NONE,
+                    primary_key: None,
+                    order_by: None,
+                    partition_of: None,
+                    for_values: None,
+                    partition_spec: None,
+                    partition_by: None,
+                    cluster_by: None,
+                    clustered_by: None
+                },
+                dialect_options: CreateTableDialectOptions {
+                    without_rowid: false,
+                    on_cluster: None,
+                    options: None,
+                    strict: false,
+                    copy_grants: false,
+                    enable_schema_evolution: None,
+                    change_tracking: None,
+                    data_retention_time_in_days: None,
+                    max_data_extension_time_in_days: None,
+                    default_ddl_collation: None,
+                    with_aggregation_policy: None,
+                    with_row_access_policy: None,
+                    with_tags: None,
+                    unknown_options: vec![],
+                }
             }),
         );
     }
 }
 
+#[test]
+fn parse_pivot() {
+    let sql = concat!(
+        "SELECT * FROM (SELECT [year], [month], amount FROM sales) AS src ",
+        "PIVOT(SUM(amount) AS total, COUNT(amount) AS n FOR [month] IN ([1] AS jan, [2] AS feb)) ",
+        "AS p ([year], jan_total, jan_n, feb_total, feb_n)"
+    );
+    let select = ms().verified_only_select(sql);
+    let TableFactor::Pivot {
+        table,
+        aggregate_functions,
+        value_column,
+        value_source: PivotValueSource::List(values),
+        alias: Some(alias),
+        ..
+    } = &only(&select.from).relation
+    else {
+        panic!("Expected a PIVOT, got {:?}", select.from);
+    };
+    assert!(matches!(**table, TableFactor::Derived { .. }));
+    assert_eq!(aggregate_functions.len(), 2);
+    assert_eq!(value_column, &vec![Ident::with_quote('[', "month")]);
+    assert_eq!(
+        values
+            .iter()
+            .map(|v| v.alias.as_ref().unwrap().value.as_str())
+            .collect::<Vec<_>>(),
+        vec!["jan", "feb"]
+    );
+    assert_eq!(alias.columns.len(), 5);
+
+    ms().verified_stmt(
+        "SELECT * FROM (SELECT * FROM sales) AS src UNPIVOT(amount FOR [month] IN ([1], [2])) AS u",
+    );
+}
+
 fn ms() -> TestedDialects {
     TestedDialects {
         dialects: vec![Box::new(MsSqlDialect {})],
@@ -21,11 +21,13 @@
 
 use matches::assert_matches;
 
+use sqlparser::ast::script::*;
 use sqlparser::ast::MysqlInsertPriority::{Delayed, HighPriority, LowPriority};
 use sqlparser::ast::*;
-use sqlparser::dialect::{GenericDialect, MySqlDialect};
+use sqlparser::dialect::{ClickHouseDialect, GenericDialect, MsSqlDialect, MySqlDialect};
 use sqlparser::parser::{ParserError, ParserOptions};
 use sqlparser::tokenizer::Token;
+use std::sync::Arc;
 use test_utils::*;
 
 #[macro_use]
@@ -141,16 +143,16 @@ fn parse_flush() {
             tables: vec![
                 ObjectName(vec![
                     Ident {
-                        value: "mek".to_string(),
+                        value: "mek".into(),
                         quote_style: Some('`')
                     },
                     Ident {
-                        value: "table1".to_string(),
+                        value: "table1".into(),
                         quote_style: Some('`')
                     }
                 ]),
                 ObjectName(vec![Ident {
-                    value: "table2".to_string(),
+                    value: "table2".into(),
                     quote_style: None
                 }])
             ]
@@ -178,16 +180,16 @@ fn parse_flush() {
             tables: vec![
                 ObjectName(vec![
                     Ident {
-                        value: "mek".to_string(),
+                        value: "mek".into(),
                         quote_style: Some('`')
                     },
                     Ident {
-                        value: "table1".to_string(),
+                        value: "table1".into(),
                         quote_style: Some('`')
                     }
                 ]),
                 ObjectName(vec![Ident {
-                    value: "table2".to_string(),
+                    value: "table2".into(),
                     quote_style: None
                 }])
             ]
@@ -204,16 +206,16 @@ fn parse_flush() {
             tables: vec![
                 ObjectName(vec![
                     Ident {
-                        value: "mek".to_string(),
+                        value: "mek".into(),
                         quote_style: Some('`')
                     },
                     Ident {
-                        value: "table1".to_string(),
+                        value: "table1".into(),
                         quote_style: Some('`')
                     }
                 ]),
                 ObjectName(vec![Ident {
-                    value: "table2".to_string(),
+                    value: "table2".into(),
                     quote_style: None
                 }])
             ]
@@ -408,6 +410,8 @@ fn parse_show_create() {
     let obj_name = ObjectName(vec![Ident::new("myident")]);
 
     for obj_type in &[
+        ShowCreateObject::Database,
+        ShowCreateObject::Schema,
         ShowCreateObject::Table,
         ShowCreateObject::Trigger,
         ShowCreateObject::Event,
@@ -419,10 +423,35 @@ fn parse_show_create() {
             mysql_and_generic().verified_stmt(format!("SHOW CREATE {obj_type} myident").as_str()),
             Statement::ShowCreate {
                 obj_type: *obj_type,
+                if_not_exists: false,
                 obj_name: obj_name.clone(),
             }
         );
     }
+
+    mysql_and_generic().verified_stmt("SHOW CREATE DATABASE IF NOT EXISTS db");
+    mysql_and_generic().verified_stmt("SHOW CREATE SCHEMA IF NOT EXISTS db");
+    mysql_and_generic().verified_stmt("SHOW CREATE VIEW db.v");
+    assert!(mysql_and_generic()
+        .parse_sql_statements("SHOW CREATE TABLE IF NOT EXISTS t")
+        .is_err());
+
+    assert_eq!(
+        mysql_and_generic().verified_stmt("SHOW CREATE USER 'u'@'localhost'"),
+        Statement::ShowCreateUser {
+            user: AccountName {
+                user: Ident::with_quote('\'', "u"),
+                host: Some(Ident::with_quote('\'', "localhost")),
+            }
+        }
+    );
+    mysql_and_generic().verified_stmt("SHOW CREATE USER root");
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn parse_show_create_user_serde_round_trip() {
+    assert_serde_round_trip!(mysql_and_generic().verified_stmt("SHOW CREATE USER 'u'@'localhost'"));
 }
 
 #[test]
@@ -738,7 +767,11 @@ fn parse_create_table_auto_increment_offset() {
         match mysql().one_statement_parses_to(sql, canonical) {
             Statement::CreateTable(CreateTable {
                 name,
-                auto_increment_offset,
+                storage:
+                    CreateTableStorage {
+                        auto_increment_offset,
+                        ..
+                    },
                 ..
             }) => {
                 assert_eq!(name.to_string(), "foo");
@@ -787,8 +820,12 @@ fn parse_create_table_engine_default_charset() {
         Statement::CreateTable(CreateTable {
             name,
             columns,
-            engine,
-            default_charset,
+            storage:
+                CreateTableStorage {
+                    engine,
+                    default_charset,
+                    ..
+                },
             ..
         }) => {
             assert_eq!(name.to_string(), "foo");
@@ -821,7 +858,7 @@ fn parse_create_table_collate() {
         Statement::CreateTable(CreateTable {
             name,
             columns,
-            collation,
+            storage: CreateTableStorage { collation, .. },
             ..
         }) => {
             assert_eq!(name.to_string(), "foo");
@@ -846,8 +883,8 @@ fn parse_create_table_both_options_and_as_query() {
     match mysql_and_generic().verified_stmt(sql) {
         Statement::CreateTable(CreateTable {
             name,
-            collation,
             query,
+            storage: CreateTableStorage { collation, .. },
             ..
         }) => {
             assert_eq!(name.to_string(), "foo");
@@ -949,7 +986,7 @@ fn parse_escaped_quote_identifiers_with_escape() {
             options: None,
         }
         .verified_stmt(sql),
-        Statement::Query(Box::new(Query {
+        Statement::Query(Arc::new(Query {
             with: None,
             body: Box::new(SetExpr::Select(Box::new(Select {
                 distinct: None,
@@ -983,6 +1020,7 @@ fn parse_escaped_quote_identifiers_with_escape() {
             for_clause: None,
             settings: None,
             format_clause: None,
+            option_clause: None,
         }))
     );
 }
@@ -996,10 +1034,14 @@ fn parse_escaped_quote_identifiers_with_no_escape() {
             options: Some(ParserOptions {
                 trailing_commas: false,
                 unescape: false,
+                parse_script_bodies: false,
+                variable_substitution: false,
+                permissive_ddl: false,
+                unparsed_statements: false,
             }),
         }
         .verified_stmt(sql),
-        Statement::Query(Box::new(Query {
+        Statement::Query(Arc::new(Query {
             with: None,
             body: Box::new(SetExpr::Select(Box::new(Select {
                 distinct: None,
@@ -1033,6 +1075,7 @@ fn parse_escaped_quote_identifiers_with_no_escape() {
             for_clause: None,
             settings: None,
             format_clause: None,
+            option_clause: None,
         }))
     );
 }
@@ -1046,7 +1089,7 @@ fn parse_escaped_backticks_with_escape() {
             options: None,
         }
         .verified_stmt(sql),
-        Statement::Query(Box::new(Query {
+        Statement::Query(Arc::new(Query {
             with: None,
             body: Box::new(SetExpr::Select(Box::new(Select {
                 distinct: None,
@@ -1080,6 +1123,7 @@ fn parse_escaped_backticks_with_escape() {
             for_clause: None,
             settings: None,
             format_clause: None,
+            option_clause: None,
         }))
     );
 }
@@ -1093,7 +1137,7 @@ fn parse_escaped_backticks_with_no_escape() {
             options: Some(ParserOptions::new().with_unescape(false)),
         }
         .verified_stmt(sql),
-        Statement::Query(Box::new(Query {
+        Statement::Query(Arc::new(Query {
             with: None,
             body: Box::new(SetExpr::Select(Box::new(Select {
                 distinct: None,
@@ -1127,6 +1171,7 @@ fn parse_escaped_backticks_with_no_escape() {
             for_clause: None,
             settings: None,
             format_clause: None,
+            option_clause: None,
         }))
     );
 }
@@ -1301,7 +1346,7 @@ fn parse_simple_insert() {
         }) => {
             assert_eq!(ObjectName(vec![Ident::new("tasks")]), table_name);
             assert_eq!(vec![Ident::new("title"), Ident::new("priority")], columns);
-            assert!(on.is_none());
+            assert!(on.is_empty());
             assert_eq!(
                 Some(Box::new(Query {
                     with: None,
@@ -1333,6 +1378,7 @@ fn parse_simple_insert() {
                     for_clause: None,
                     settings: None,
                     format_clause: None,
+                    option_clause: None,
                 })),
                 source
             );
@@ -1356,7 +1402,7 @@ fn parse_ignore_insert() {
         }) => {
             assert_eq!(ObjectName(vec![Ident::new("tasks")]), table_name);
             assert_eq!(vec![Ident::new("title"), Ident::new("priority")], columns);
-            assert!(on.is_none());
+            assert!(on.is_empty());
             assert!(ignore);
             assert_eq!(
                 Some(Box::new(Query {
@@ -1377,6 +1423,7 @@ fn parse_ignore_insert() {
                     for_clause: None,
                     settings: None,
                     format_clause: None,
+                    option_clause: None,
                 })),
                 source
             );
@@ -1400,7 +1447,7 @@ fn parse_priority_insert() {
         }) => {
             assert_eq!(ObjectName(vec![Ident::new("tasks")]), table_name);
             assert_eq!(vec![Ident::new("title"), Ident::new("priority")], columns);
-            assert!(on.is_none());
+            assert!(on.is_empty());
             assert_eq!(priority, Some(HighPriority));
             assert_eq!(
                 Some(Box::new(Query {
@@ -1421,6 +1468,7 @@ fn parse_priority_insert() {
                     for_clause: None,
                     settings: None,
                     format_clause: None,
+                    option_clause: None,
                 })),
                 source
             );
@@ -1441,7 +1489,7 @@ fn parse_priority_insert() {
         }) => {
             assert_eq!(ObjectName(vec![Ident::new("tasks")]), table_name);
             assert_eq!(vec![Ident::new("title"), Ident::new("priority")], columns);
-            assert!(on.is_none());
+            assert!(on.is_empty());
             assert_eq!(priority, Some(LowPriority));
             assert_eq!(
                 Some(Box::new(Query {
@@ -1462,6 +1510,7 @@ fn parse_priority_insert() {
                     for_clause: None,
                     settings: None,
                     format_clause: None,
+                    option_clause: None,
                 })),
                 source
             );
@@ -1511,6 +1560,7 @@ fn parse_insert_as() {
                     for_clause: None,
                     settings: None,
                     format_clause: None,
+                    option_clause: None,
                 })),
                 source
             );
@@ -1572,6 +1622,7 @@ fn parse_insert_as() {
                     for_clause: None,
                     settings: None,
                     format_clause: None,
+                    option_clause: None,
                 })),
                 source
             );
@@ -1595,7 +1646,7 @@ fn parse_replace_insert() {
         }) => {
             assert_eq!(ObjectName(vec![Ident::new("tasks")]), table_name);
             assert_eq!(vec![Ident::new("title"), Ident::new("priority")], columns);
-            assert!(on.is_none());
+            assert!(on.is_empty());
             assert!(replace_into);
             assert_eq!(priority, Some(Delayed));
             assert_eq!(
@@ -1617,6 +1668,7 @@ fn parse_replace_insert() {
                     for_clause: None,
                     settings: None,
                     format_clause: None,
+                    option_clause: None,
                 })),
                 source
             );
@@ -1625,6 +1677,48 @@ fn parse_replace_insert() {
     }
 }
 
+#[test]
+fn parse_insert_set() {
+    match mysql_and_generic().verified_stmt("REPLACE INTO t SET a = 1, b = 'x'") {
+        Statement::Insert(Insert {
+            table_name,
+            columns,
+            source,
+            assignments,
+            replace_into,
+            ignore,
+            ..
+        }) => {
+            assert_eq!(ObjectName(vec![Ident::new("t")]), table_name);
+            assert!(columns.is_empty());
+            assert!(source.is_none());
+            assert!(replace_into);
+            assert!(!ignore);
+            assert_eq!(
+                vec![
+                    Assignment {
+                        target: AssignmentTarget::ColumnName(ObjectName(vec![Ident::new("a")])),
+                        value: Expr::Value(number("1")),
+                    },
+                    Assignment {
+                        target: AssignmentTarget::ColumnName(ObjectName(vec![Ident::new("b")])),
+                        value: Expr::Value(Value::SingleQuotedString("x".to_string())),
+                    },
+                ],
+                assignments
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    mysql_and_generic().verified_stmt("INSERT IGNORE INTO t SET a = 1");
+    mysql_and_generic().verified_stmt("REPLACE LOW_PRIORITY t SET a = 1");
+    mysql_and_generic()
+        .verified_stmt("INSERT INTO t SET a = 1 AS new ON DUPLICATE KEY UPDATE a = new.a");
+    mysql_and_generic().verified_stmt("REPLACE INTO t SELECT * FROM s");
+    mysql_and_generic().verified_stmt("REPLACE INTO t TABLE s");
+}
+
 #[test]
 fn parse_empty_row_insert() {
     let sql = "INSERT INTO tb () VALUES (), ()";
@@ -1639,7 +1733,7 @@ fn parse_empty_row_insert() {
         }) => {
             assert_eq!(ObjectName(vec![Ident::new("tb")]), table_name);
             assert!(columns.is_empty());
-            assert!(on.is_none());
+            assert!(on.is_empty());
             assert_eq!(
                 Some(Box::new(Query {
                     with: None,
@@ -1656,6 +1750,7 @@ fn parse_empty_row_insert() {
                     for_clause: None,
                     settings: None,
                     format_clause: None,
+                    option_clause: None,
                 })),
                 source
             );
@@ -1718,11 +1813,12 @@ fn parse_insert_with_on_duplicate_update() {
                     for_clause: None,
                     settings: None,
                     format_clause: None,
+                    option_clause: None,
                 })),
                 source
             );
             assert_eq!(
-                Some(OnInsert::DuplicateKeyUpdate(vec![
+                vec![OnInsert::DuplicateKeyUpdate(vec![
                     Assignment {
                         target: AssignmentTarget::ColumnName(ObjectName(vec![Ident::new(
                             "description".to_string()
@@ -1753,7 +1849,7 @@ fn parse_insert_with_on_duplicate_update() {
                         )])),
                         value: call("VALUES", [Expr::Identifier(Ident::new("perm_delete"))]),
                     },
-                ])),
+                ])],
                 on
             );
         }
@@ -1970,7 +2066,7 @@ fn parse_delete_with_order_by() {
             assert_eq!(
                 vec![OrderByExpr {
                     expr: Expr::Identifier(Ident {
-                        value: "id".to_owned(),
+                        value: "id".into(),
                         quote_style: None
                     }),
                     asc: Some(false),
@@ -2005,6 +2101,7 @@ fn parse_alter_table_add_column() {
             operations,
             location: _,
             on_cluster: _,
+            unknown_options: _,
         } => {
             assert_eq!(name.to_string(), "tab");
             assert!(!if_exists);
@@ -2035,6 +2132,7 @@ fn parse_alter_table_add_column() {
             operations,
             location: _,
             on_cluster: _,
+            unknown_options: _,
         } => {
             assert_eq!(name.to_string(), "tab");
             assert!(!if_exists);
@@ -2051,7 +2149,7 @@ fn parse_alter_table_add_column() {
                         options: vec![],
                     },
                     column_position: Some(MySQLColumnPosition::After(Ident {
-                        value: String::from("foo"),
+                        value: "foo".into(),
                         quote_style: None
                     })),
                 },]
@@ -2073,6 +2171,7 @@ fn parse_alter_table_add_columns() {
             operations,
             location: _,
             on_cluster: _,
+            unknown_options: _,
         } => {
             assert_eq!(name.to_string(), "tab");
             assert!(!if_exists);
@@ -2101,7 +2200,7 @@ fn parse_alter_table_add_columns() {
                             options: vec![],
                         },
                         column_position: Some(MySQLColumnPosition::After(Ident {
-                            value: String::from("foo"),
+                            value: "foo".into(),
                             quote_style: None,
                         })),
                     },
@@ -2120,13 +2219,181 @@ fn parse_alter_table_drop_primary_key() {
     );
 }
 
+#[test]
+fn parse_rename_table() {
+    assert_eq!(
+        mysql_and_generic().verified_stmt("RENAME TABLE a TO b, db.c TO db2.d"),
+        Statement::RenameTable(vec![
+            RenameTable {
+                old_name: ObjectName(vec![Ident::new("a")]),
+                new_name: ObjectName(vec![Ident::new("b")]),
+            },
+            RenameTable {
+                old_name: ObjectName(vec![Ident::new("db"), Ident::new("c")]),
+                new_name: ObjectName(vec![Ident::new("db2"), Ident::new("d")]),
+            },
+        ])
+    );
+
+    assert_eq!(
+        alter_table_op(mysql_and_generic().one_statement_parses_to(
+            "ALTER TABLE tab RENAME AS new_tab",
+            "ALTER TABLE tab RENAME TO new_tab"
+        )),
+        AlterTableOperation::RenameTable {
+            table_name: ObjectName(vec![Ident::new("new_tab")]),
+        }
+    );
+
+    assert_eq!(
+        mysql()
+            .parse_sql_statements("RENAME TABLE a")
+            .unwrap_err()
+            .to_string(),
+        "sql parser error: Expected: TO, found: EOF"
+    );
+}
+
+#[test]
+fn parse_alter_table_online_ddl() {
+    match mysql_and_generic()
+        .verified_stmt("ALTER TABLE tab ADD COLUMN c INT, ALGORITHM=INPLACE, LOCK=NONE")
+    {
+        Statement::AlterTable { operations, .. } => {
+            assert_eq!(
+                operations[1..],
+                [
+                    AlterTableOperation::Algorithm {
+                        equals: true,
+                        algorithm: AlterTableAlgorithm::Inplace,
+                    },
+                    AlterTableOperation::Lock {
+                        equals: true,
+                        lock: AlterTableLock::None,
+                    },
+                ]
+            );
+        }
+        _ => unreachable!(),
+    }
+    mysql_and_generic().verified_stmt("ALTER TABLE tab ALGORITHM INSTANT, LOCK DEFAULT");
+    mysql_and_generic().verified_stmt("ALTER TABLE tab ALGORITHM=COPY, LOCK=SHARED");
+    mysql_and_generic().verified_stmt("ALTER TABLE tab ALGORITHM=DEFAULT, LOCK=EXCLUSIVE");
+}
+
+#[test]
+fn parse_alter_table_rename_index() {
+    assert_eq!(
+        alter_table_op(mysql_and_generic().verified_stmt("ALTER TABLE tab RENAME INDEX i1 TO i2")),
+        AlterTableOperation::RenameIndex {
+            index_type_display: KeyOrIndexDisplay::Index,
+            old_name: Ident::new("i1"),
+            new_name: Ident::new("i2"),
+        }
+    );
+    mysql_and_generic().verified_stmt("ALTER TABLE tab RENAME KEY i1 TO i2");
+    // a column called `key`
+    assert_matches!(
+        alter_table_op(mysql_and_generic().one_statement_parses_to(
+            "ALTER TABLE tab RENAME key TO k",
+            "ALTER TABLE tab RENAME COLUMN key TO k"
+        )),
+        AlterTableOperation::RenameColumn { .. }
+    );
+    mysql_and_generic().verified_stmt("ALTER TABLE tab RENAME COLUMN a TO b");
+}
+
+#[test]
+fn parse_alter_table_order_by() {
+    match alter_table_op(mysql_and_generic().verified_stmt("ALTER TABLE tab ORDER BY a, b DESC")) {
+        AlterTableOperation::OrderBy { exprs } => {
+            assert_eq!(
+                exprs.iter().map(ToString::to_string).collect::<Vec<_>>(),
+                ["a", "b DESC"]
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_alter_table_partition_operations() {
+    assert_eq!(
+        alter_table_op(mysql_and_generic().verified_stmt(
+            "ALTER TABLE tab ADD PARTITION (PARTITION p1 VALUES LESS THAN (100), PARTITION p2 VALUES LESS THAN MAXVALUE)"
+        )),
+        AlterTableOperation::AddPartitionDefinitions {
+            partitions: vec![
+                PartitionDefinition {
+                    name: Ident::new("p1"),
+                    values: Some(PartitionValues::LessThan(vec![Expr::Value(number("100"))])),
+                },
+                PartitionDefinition {
+                    name: Ident::new("p2"),
+                    values: Some(PartitionValues::LessThanMaxValue),
+                },
+            ],
+        }
+    );
+    mysql_and_generic().verified_stmt(
+        "ALTER TABLE tab ADD PARTITION (PARTITION p3 VALUES IN (1, 2), PARTITION p4)",
+    );
+
+    assert_eq!(
+        alter_table_op(mysql_and_generic().verified_stmt("ALTER TABLE tab DROP PARTITION p1, p2")),
+        AlterTableOperation::DropPartitionNames {
+            partitions: vec![Ident::new("p1"), Ident::new("p2")],
+        }
+    );
+
+    match alter_table_op(mysql_and_generic().verified_stmt(
+        "ALTER TABLE tab REORGANIZE PARTITION p1, p2 INTO (PARTITION p1 VALUES LESS THAN (10), PARTITION p2 VALUES LESS THAN (20, 30))",
+    )) {
+        AlterTableOperation::ReorganizePartitions { partitions, into } => {
+            assert_eq!(partitions, vec![Ident::new("p1"), Ident::new("p2")]);
+            assert_eq!(into.len(), 2);
+        }
+        _ => unreachable!(),
+    }
+
+    assert_eq!(
+        alter_table_op(mysql_and_generic().verified_stmt("ALTER TABLE tab COALESCE PARTITION 2")),
+        AlterTableOperation::CoalescePartition {
+            number: number("2"),
+        }
+    );
+
+    mysql_and_generic().verified_stmt(
+        "ALTER TABLE tab ADD PARTITION (PARTITION p5 VALUES LESS THAN (200)), ALGORITHM=INPLACE, LOCK=NONE",
+    );
+}
+
+/// Asserts that `operation` equals `expected` except for omitting the
+/// `COLUMN` keyword
+fn assert_without_column_keyword(
+    expected: AlterTableOperation,
+    mut operation: AlterTableOperation,
+) {
+    match &mut operation {
+        AlterTableOperation::ChangeColumn { column_keyword, .. }
+        | AlterTableOperation::ModifyColumn { column_keyword, .. } => {
+            assert!(!*column_keyword);
+            *column_keyword = true;
+        }
+        _ => unreachable!(),
+    }
+    assert_eq!(expected, operation);
+}
+
 #[test]
 fn parse_alter_table_change_column() {
     let expected_name = ObjectName(vec![Ident::new("orders")]);
     let expected_operation = AlterTableOperation::ChangeColumn {
+        column_keyword: true,
         old_name: Ident::new("description"),
         new_name: Ident::new("desc"),
         data_type: DataType::Text,
+        collation: None,
         options: vec![ColumnOption::NotNull],
         column_position: None,
     };
@@ -2137,16 +2404,16 @@ fn parse_alter_table_change_column() {
     assert_eq!(expected_operation, operation);
 
     let sql2 = "ALTER TABLE orders CHANGE description desc TEXT NOT NULL";
-    let operation = alter_table_op_with_name(
-        mysql().one_statement_parses_to(sql2, sql1),
-        &expected_name.to_string(),
-    );
-    assert_eq!(expected_operation, operation);
+    let operation =
+        alter_table_op_with_name(mysql().verified_stmt(sql2), &expected_name.to_string());
+    assert_without_column_keyword(expected_operation, operation);
 
     let expected_operation = AlterTableOperation::ChangeColumn {
+        column_keyword: true,
         old_name: Ident::new("description"),
         new_name: Ident::new("desc"),
         data_type: DataType::Text,
+        collation: None,
         options: vec![ColumnOption::NotNull],
         column_position: Some(MySQLColumnPosition::First),
     };
@@ -2156,12 +2423,14 @@ fn parse_alter_table_change_column() {
     assert_eq!(expected_operation, operation);
 
     let expected_operation = AlterTableOperation::ChangeColumn {
+        column_keyword: true,
         old_name: Ident::new("description"),
         new_name: Ident::new("desc"),
         data_type: DataType::Text,
+        collation: None,
         options: vec![ColumnOption::NotNull],
         column_position: Some(MySQLColumnPosition::After(Ident {
-            value: String::from("foo"),
+            value: "foo".into(),
             quote_style: None,
         })),
     };
@@ -2175,9 +2444,11 @@ fn parse_alter_table_change_column() {
 fn parse_alter_table_change_column_with_column_position() {
     let expected_name = ObjectName(vec![Ident::new("orders")]);
     let expected_operation_first = AlterTableOperation::ChangeColumn {
+        column_keyword: true,
         old_name: Ident::new("description"),
         new_name: Ident::new("desc"),
         data_type: DataType::Text,
+        collation: None,
         options: vec![ColumnOption::NotNull],
         column_position: Some(MySQLColumnPosition::First),
     };
@@ -2188,19 +2459,19 @@ fn parse_alter_table_change_column_with_column_position() {
     assert_eq!(expected_operation_first, operation);
 
     let sql2 = "ALTER TABLE orders CHANGE description desc TEXT NOT NULL FIRST";
-    let operation = alter_table_op_with_name(
-        mysql().one_statement_parses_to(sql2, sql1),
-        &expected_name.to_string(),
-    );
-    assert_eq!(expected_operation_first, operation);
+    let operation =
+        alter_table_op_with_name(mysql().verified_stmt(sql2), &expected_name.to_string());
+    assert_without_column_keyword(expected_operation_first, operation);
 
     let expected_operation_after = AlterTableOperation::ChangeColumn {
+        column_keyword: true,
         old_name: Ident::new("description"),
         new_name: Ident::new("desc"),
         data_type: DataType::Text,
+        collation: None,
         options: vec![ColumnOption::NotNull],
         column_position: Some(MySQLColumnPosition::After(Ident {
-            value: String::from("total_count"),
+            value: "total_count".into(),
             quote_style: None,
         })),
     };
@@ -2211,19 +2482,19 @@ fn parse_alter_table_change_column_with_column_position() {
     assert_eq!(expected_operation_after, operation);
 
     let sql2 = "ALTER TABLE orders CHANGE description desc TEXT NOT NULL AFTER total_count";
-    let operation = alter_table_op_with_name(
-        mysql().one_statement_parses_to(sql2, sql1),
-        &expected_name.to_string(),
-    );
-    assert_eq!(expected_operation_after, operation);
+    let operation =
+        alter_table_op_with_name(mysql().verified_stmt(sql2), &expected_name.to_string());
+    assert_without_column_keyword(expected_operation_after, operation);
 }
 
 #[test]
 fn parse_alter_table_modify_column() {
     let expected_name = ObjectName(vec![Ident::new("orders")]);
     let expected_operation = AlterTableOperation::ModifyColumn {
+        column_keyword: true,
         col_name: Ident::new("description"),
         data_type: DataType::Text,
+        collation: None,
         options: vec![ColumnOption::NotNull],
         column_position: None,
     };
@@ -2234,15 +2505,15 @@ fn parse_alter_table_modify_column() {
     assert_eq!(expected_operation, operation);
 
     let sql2 = "ALTER TABLE orders MODIFY description TEXT NOT NULL";
-    let operation = alter_table_op_with_name(
-        mysql().one_statement_parses_to(sql2, sql1),
-        &expected_name.to_string(),
-    );
-    assert_eq!(expected_operation, operation);
+    let operation =
+        alter_table_op_with_name(mysql().verified_stmt(sql2), &expected_name.to_string());
+    assert_without_column_keyword(expected_operation, operation);
 
     let expected_operation = AlterTableOperation::ModifyColumn {
+        column_keyword: true,
         col_name: Ident::new("description"),
         data_type: DataType::Text,
+        collation: None,
         options: vec![ColumnOption::NotNull],
         column_position: Some(MySQLColumnPosition::First),
     };
@@ -2252,11 +2523,13 @@ fn parse_alter_table_modify_column() {
     assert_eq!(expected_operation, operation);
 
     let expected_operation = AlterTableOperation::ModifyColumn {
+        column_keyword: true,
         col_name: Ident::new("description"),
         data_type: DataType::Text,
+        collation: None,
         options: vec![ColumnOption::NotNull],
         column_position: Some(MySQLColumnPosition::After(Ident {
-            value: String::from("foo"),
+            value: "foo".into(),
             quote_style: None,
         })),
     };
@@ -2270,8 +2543,10 @@ fn parse_alter_table_modify_column() {
 fn parse_alter_table_modify_column_with_column_position() {
     let expected_name = ObjectName(vec![Ident::new("orders")]);
     let expected_operation_first = AlterTableOperation::ModifyColumn {
+        column_keyword: true,
         col_name: Ident::new("description"),
         data_type: DataType::Text,
+        collation: None,
         options: vec![ColumnOption::NotNull],
         column_position: Some(MySQLColumnPosition::First),
     };
@@ -2282,18 +2557,18 @@ fn parse_alter_table_modify_column_with_column_position() {
     assert_eq!(expected_operation_first, operation);
 
     let sql2 = "ALTER TABLE orders MODIFY description TEXT NOT NULL FIRST";
-    let operation = alter_table_op_with_name(
-        mysql().one_statement_parses_to(sql2, sql1),
-        &expected_name.to_string(),
-    );
-    assert_eq!(expected_operation_first, operation);
+    let operation =
+        alter_table_op_with_name(mysql().verified_stmt(sql2), &expected_name.to_string());
+    assert_without_column_keyword(expected_operation_first, operation);
 
     let expected_operation_after = AlterTableOperation::ModifyColumn {
+        column_keyword: true,
         col_name: Ident::new("description"),
         data_type: DataType::Text,
+        collation: None,
         options: vec![ColumnOption::NotNull],
         column_position: Some(MySQLColumnPosition::After(Ident {
-            value: String::from("total_count"),
+            value: "total_count".into(),
             quote_style: None,
         })),
     };
@@ -2304,11 +2579,48 @@ fn parse_alter_table_modify_column_with_column_position() {
     assert_eq!(expected_operation_after, operation);
 
     let sql2 = "ALTER TABLE orders MODIFY description TEXT NOT NULL AFTER total_count";
-    let operation = alter_table_op_with_name(
-        mysql().one_statement_parses_to(sql2, sql1),
-        &expected_name.to_string(),
+    let operation =
+        alter_table_op_with_name(mysql().verified_stmt(sql2), &expected_name.to_string());
+    assert_without_column_keyword(expected_operation_after, operation);
+}
+
+#[test]
+fn parse_alter_table_change_column_full_definition() {
+    assert_eq!(
+        alter_table_op(mysql_and_generic().verified_stmt(
+            "ALTER TABLE tab CHANGE old new BIGINT UNSIGNED COLLATE utf8mb4_bin NOT NULL \
+            DEFAULT 0 COMMENT 'c' INVISIBLE COLUMN_FORMAT FIXED STORAGE DISK AFTER `x y`"
+        )),
+        AlterTableOperation::ChangeColumn {
+            column_keyword: false,
+            old_name: Ident::new("old"),
+            new_name: Ident::new("new"),
+            data_type: DataType::UnsignedBigInt(None),
+            collation: Some(ObjectName(vec![Ident::new("utf8mb4_bin")])),
+            options: vec![
+                ColumnOption::NotNull,
+                ColumnOption::Default(Expr::Value(number("0"))),
+                ColumnOption::Comment("c".to_string()),
+                ColumnOption::Invisible,
+                ColumnOption::ColumnFormat(ColumnFormat::Fixed),
+                ColumnOption::Storage(ColumnStorage::Disk),
+            ],
+            column_position: Some(MySQLColumnPosition::After(Ident::with_quote('`', "x y"))),
+        }
     );
-    assert_eq!(expected_operation_after, operation);
+    mysql_and_generic().verified_stmt(
+        "ALTER TABLE tab MODIFY COLUMN g POINT NOT NULL SRID 4326 VISIBLE COLUMN_FORMAT DYNAMIC STORAGE MEMORY FIRST",
+    );
+    mysql_and_generic().one_statement_parses_to(
+        "ALTER TABLE tab MODIFY id INT UNIQUE KEY AUTO_INCREMENT",
+        "ALTER TABLE tab MODIFY id INT UNIQUE AUTO_INCREMENT",
+    );
+    mysql_and_generic().one_statement_parses_to(
+        "ALTER TABLE tab MODIFY b VARCHAR(10) CHARACTER SET utf8mb4 COLLATE utf8mb4_bin",
+        "ALTER TABLE tab MODIFY b VARCHAR(10) COLLATE utf8mb4_bin CHARACTER SET utf8mb4",
+    );
+    mysql_and_generic()
+        .verified_stmt("CREATE TABLE t (a INT INVISIBLE, b INT COLUMN_FORMAT DEFAULT)");
 }
 
 #[test]
@@ -2320,14 +2632,14 @@ fn parse_substring_in_select() {
     ) {
         Statement::Query(query) => {
             assert_eq!(
-                Box::new(Query {
+                Arc::new(Query {
                     with: None,
                     body: Box::new(SetExpr::Select(Box::new(Select {
                         distinct: Some(Distinct::Distinct),
                         top: None,
                         projection: vec![SelectItem::UnnamedExpr(Expr::Substring {
                             expr: Box::new(Expr::Identifier(Ident {
-                                value: "description".to_string(),
+                                value: "description".into(),
                                 quote_style: None
                             })),
                             substring_from: Some(Box::new(Expr::Value(number("0")))),
@@ -2338,7 +2650,7 @@ fn parse_substring_in_select() {
                         from: vec![TableWithJoins {
                             relation: TableFactor::Table {
                                 name: ObjectName(vec![Ident {
-                                    value: "test".to_string(),
+                                    value: "test".into(),
                                     quote_style: None
                                 }]),
                                 alias: None,
@@ -2373,6 +2685,7 @@ fn parse_substring_in_select() {
                     for_clause: None,
                     settings: None,
                     format_clause: None,
+                    option_clause: None,
                 }),
                 query
             );
@@ -2591,6 +2904,52 @@ fn parse_create_table_with_spatial_definition() {
         .verified_stmt("CREATE TABLE tb (c1 INT, c2 INT, SPATIAL KEY potato (c1, c2))");
 }
 
+#[test]
+fn parse_create_table_index_definition_with_index_options() {
+    let sql = "CREATE TABLE t (a INT, b TEXT, KEY idx_a (a) USING BTREE COMMENT 'x', FULLTEXT KEY ft (b) WITH PARSER ngram INVISIBLE)";
+    match mysql_and_generic().verified_stmt(sql) {
+        Statement::CreateTable(CreateTable { constraints, .. }) => {
+            assert_eq!(
+                constraints,
+                vec![
+                    TableConstraint::Index {
+                        display_as_key: true,
+                        name: Some(Ident::new("idx_a")),
+                        index_type: None,
+                        columns: vec![Ident::new("a")],
+                        index_options: vec![
+                            IndexOption::Using(IndexType::BTree),
+                            IndexOption::Comment("x".into()),
+                        ],
+                    },
+                    TableConstraint::FulltextOrSpatial {
+                        fulltext: true,
+                        index_type_display: KeyOrIndexDisplay::Key,
+                        opt_index_name: Some(Ident::new("ft")),
+                        columns: vec![Ident::new("b")],
+                        index_options: vec![
+                            IndexOption::WithParser(Ident::new("ngram")),
+                            IndexOption::Invisible,
+                        ],
+                    },
+                ]
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    mysql_and_generic().verified_stmt(
+        "CREATE TABLE t (g GEOMETRY NOT NULL, SPATIAL INDEX s (g) KEY_BLOCK_SIZE = 8 VISIBLE)",
+    );
+    mysql_and_generic().verified_stmt(
+        "CREATE TABLE t (a INT, UNIQUE KEY u (a) KEY_BLOCK_SIZE = 4 COMMENT 'c' INVISIBLE)",
+    );
+    mysql_and_generic().one_statement_parses_to(
+        "CREATE TABLE t (a INT, INDEX (a) KEY_BLOCK_SIZE 8)",
+        "CREATE TABLE t (a INT, INDEX (a) KEY_BLOCK_SIZE = 8)",
+    );
+}
+
 #[test]
 fn parse_fulltext_expression() {
     mysql_and_generic().verified_stmt("SELECT * FROM tb WHERE MATCH (c1) AGAINST ('string')");
@@ -2623,6 +2982,47 @@ fn parse_create_table_with_fulltext_definition_should_not_accept_constraint_name
     mysql_and_generic().verified_stmt("CREATE TABLE tb (c1 INT, CONSTRAINT cons FULLTEXT (c1))");
 }
 
+#[test]
+fn parse_straight_join() {
+    let select = mysql().verified_only_select("SELECT * FROM a STRAIGHT_JOIN b ON a.id = b.id");
+    assert_eq!(
+        only(&select.from).joins,
+        vec![Join {
+            relation: TableFactor::Table {
+                name: ObjectName(vec![Ident::new("b")]),
+                alias: None,
+                args: None,
+                with_hints: vec![],
+                version: None,
+                partitions: vec![],
+                with_ordinality: false,
+            },
+            global: false,
+            join_operator: JoinOperator::StraightJoin(JoinConstraint::On(Expr::BinaryOp {
+                left: Box::new(Expr::CompoundIdentifier(vec![
+                    Ident::new("a"),
+                    Ident::new("id")
+                ])),
+                op: BinaryOperator::Eq,
+                right: Box::new(Expr::CompoundIdentifier(vec![
+                    Ident::new("b"),
+                    Ident::new("id")
+                ])),
+            })),
+        }]
+    );
+
+    mysql().verified_stmt("SELECT * FROM a STRAIGHT_JOIN b");
+    mysql()
+        .verified_stmt("SELECT * FROM a AS x STRAIGHT_JOIN b AS y ON x.id = y.id JOIN c USING(id)");
+
+    // STRAIGHT_JOIN is only reserved as a table alias in MySQL
+    all_dialects_except(|d| d.is::<MySqlDialect>()).one_statement_parses_to(
+        "SELECT * FROM a straight_join",
+        "SELECT * FROM a AS straight_join",
+    );
+}
+
 fn mysql() -> TestedDialects {
     TestedDialects {
         dialects: vec![Box::new(MySqlDialect {})],
@@ -2647,7 +3047,7 @@ fn parse_values() {
 fn parse_hex_string_introducer() {
     assert_eq!(
         mysql().verified_stmt("SELECT _latin1 X'4D7953514C'"),
-        Statement::Query(Box::new(Query {
+        Statement::Query(Arc::new(Query {
             with: None,
             body: Box::new(SetExpr::Select(Box::new(Select {
                 distinct: None,
@@ -2681,6 +3081,7 @@ fn parse_hex_string_introducer() {
             for_clause: None,
             settings: None,
             format_clause: None,
+            option_clause: None,
         }))
     )
 }
@@ -2698,6 +3099,36 @@ fn parse_div_infix() {
     mysql().verified_stmt(r#"SELECT 5 DIV 2"#);
 }
 
+#[test]
+fn parse_json_extract_operators() {
+    let select = mysql().verified_only_select("SELECT doc -> '$.name' = 1 FROM t");
+    assert_eq!(
+        Expr::BinaryOp {
+            left: Box::new(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("doc"))),
+                op: BinaryOperator::MyJsonExtract,
+                right: Box::new(Expr::Value(Value::SingleQuotedString("$.name".into()))),
+            }),
+            op: BinaryOperator::Eq,
+            right: Box::new(Expr::Value(number("1"))),
+        },
+        *expr_from_projection(only(&select.projection))
+    );
+
+    let select = mysql().verified_only_select("SELECT doc ->> '$.tags[0]' FROM t");
+    assert_eq!(
+        Expr::BinaryOp {
+            left: Box::new(Expr::Identifier(Ident::new("doc"))),
+            op: BinaryOperator::MyJsonUnquoteExtract,
+            right: Box::new(Expr::Value(Value::SingleQuotedString("$.tags[0]".into()))),
+        },
+        *expr_from_projection(only(&select.projection))
+    );
+
+    assert!(mysql().parse_sql_statements("SELECT a @> b").is_err());
+    assert!(mysql().parse_sql_statements("SELECT a #> b").is_err());
+}
+
 #[test]
 fn parse_drop_temporary_table() {
     let sql = "DROP TEMPORARY TABLE foo";
@@ -2839,3 +3270,546 @@ fn test_group_concat() {
     mysql_and_generic()
         .verified_expr("GROUP_CONCAT(DISTINCT test_score ORDER BY test_score DESC SEPARATOR ' ')");
 }
+
+#[test]
+fn parse_partition_selection() {
+    let select =
+        mysql_and_generic().verified_only_select("SELECT * FROM t PARTITION (p0, p1) AS x");
+    match &select.from[0].relation {
+        TableFactor::Table { partitions, .. } => {
+            assert_eq!(partitions, &vec![Ident::new("p0"), Ident::new("p1")]);
+        }
+        _ => unreachable!(),
+    }
+
+    match mysql_and_generic().verified_stmt("UPDATE t PARTITION (p0, p1) SET a = 1 WHERE b = 2") {
+        Statement::Update { table, .. } => match table.relation {
+            TableFactor::Table { partitions, .. } => {
+                assert_eq!(partitions, vec![Ident::new("p0"), Ident::new("p1")]);
+            }
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+
+    match mysql_and_generic().verified_stmt("DELETE FROM t PARTITION (p0) WHERE a = 1") {
+        Statement::Delete(Delete {
+            from: FromTable::WithFromKeyword(from),
+            ..
+        }) => match &from[0].relation {
+            TableFactor::Table { partitions, .. } => {
+                assert_eq!(partitions, &vec![Ident::new("p0")]);
+            }
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_quoted_user_variables() {
+    let select = mysql_and_generic().verified_only_select("SELECT @`x y`, @`a``b` FROM t");
+    assert_eq!(
+        select.projection[0],
+        SelectItem::UnnamedExpr(Expr::Value(Value::Placeholder("@`x y`".to_string())))
+    );
+    mysql_and_generic().verified_stmt("SELECT * FROM t WHERE a = @`x y`");
+}
+
+#[test]
+fn parse_create_event() {
+    let sql = "CREATE DEFINER = `root`@`localhost` EVENT IF NOT EXISTS e ON SCHEDULE EVERY 1 DAY STARTS CURRENT_TIMESTAMP + INTERVAL 1 HOUR ON COMPLETION NOT PRESERVE DISABLE ON SLAVE COMMENT 'purge' DO DELETE FROM t WHERE a < NOW()";
+    match mysql_and_generic().verified_stmt(sql) {
+        Statement::CreateEvent(CreateEvent {
+            definer,
+            if_not_exists,
+            name,
+            schedule,
+            on_completion_preserve,
+            status,
+            comment,
+            body,
+        }) => {
+            assert_eq!(
+                definer,
+                Some(AccountName {
+                    user: Ident::with_quote('`', "root"),
+                    host: Some(Ident::with_quote('`', "localhost")),
+                })
+            );
+            assert!(if_not_exists);
+            assert_eq!(name.to_string(), "e");
+            match schedule {
+                EventSchedule::Every {
+                    unit, starts, ends, ..
+                } => {
+                    assert_eq!(unit, DateTimeField::Day);
+                    assert!(starts.is_some());
+                    assert!(ends.is_none());
+                }
+                _ => unreachable!(),
+            }
+            assert_eq!(on_completion_preserve, Some(false));
+            assert_eq!(status, Some(EventStatus::DisableOnSlave));
+            assert_eq!(comment, Some("purge".to_string()));
+            assert!(matches!(*body, ScriptStatement::Sql(Statement::Delete(_))));
+        }
+        _ => unreachable!(),
+    }
+
+    mysql_and_generic()
+        .verified_stmt("CREATE EVENT e ON SCHEDULE AT CURRENT_TIMESTAMP DO SELECT 1");
+    mysql_and_generic().verified_stmt(
+        "CREATE DEFINER = 'u'@'%' EVENT e ON SCHEDULE EVERY 1 DAY ENABLE DO SELECT 1",
+    );
+    mysql_and_generic().verified_stmt(
+        "CREATE DEFINER = root@localhost EVENT e ON SCHEDULE EVERY '1:30' HOUR_MINUTE DO SELECT 1",
+    );
+    mysql_and_generic().one_statement_parses_to(
+        "CREATE DEFINER=root EVENT e ON SCHEDULE AT NOW() DO SELECT 1",
+        "CREATE DEFINER = root EVENT e ON SCHEDULE AT NOW() DO SELECT 1",
+    );
+
+    match mysql_and_generic().verified_stmt(
+        "CREATE EVENT e ON SCHEDULE AT CURRENT_TIMESTAMP DO BEGIN DELETE FROM t; DELETE FROM u; END",
+    ) {
+        Statement::CreateEvent(CreateEvent { body, .. }) => match *body {
+            ScriptStatement::Block(block) => assert_eq!(block.statements.len(), 2),
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_alter_event() {
+    match mysql_and_generic().verified_stmt(
+        "ALTER EVENT e ON SCHEDULE AT '2024-01-01 00:00:00' ON COMPLETION PRESERVE RENAME TO db.e2 ENABLE DO SELECT 1",
+    ) {
+        Statement::AlterEvent(AlterEvent {
+            definer,
+            name,
+            schedule,
+            on_completion_preserve,
+            rename_to,
+            status,
+            comment,
+            body,
+        }) => {
+            assert_eq!(definer, None);
+            assert_eq!(name.to_string(), "e");
+            assert!(matches!(schedule, Some(EventSchedule::At(_))));
+            assert_eq!(on_completion_preserve, Some(true));
+            assert_eq!(rename_to.unwrap().to_string(), "db.e2");
+            assert_eq!(status, Some(EventStatus::Enable));
+            assert_eq!(comment, None);
+            assert!(body.is_some());
+        }
+        _ => unreachable!(),
+    }
+
+    mysql_and_generic().verified_stmt("ALTER DEFINER = 'u'@'h' EVENT e DISABLE");
+}
+
+#[test]
+fn parse_drop_event() {
+    match mysql_and_generic().verified_stmt("DROP EVENT IF EXISTS e") {
+        Statement::Drop {
+            object_type,
+            if_exists,
+            names,
+            ..
+        } => {
+            assert_eq!(object_type, ObjectType::Event);
+            assert!(if_exists);
+            assert_eq!(names[0].to_string(), "e");
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_create_procedure_with_cursor_loop() {
+    let sql = concat!(
+        "CREATE PROCEDURE p (IN a INT, OUT total INT) BEGIN ",
+        "DECLARE done INT DEFAULT false; ",
+        "DECLARE x, y INT; ",
+        "DECLARE cur CURSOR FOR SELECT id FROM t WHERE b = a; ",
+        "DECLARE CONTINUE HANDLER FOR NOT FOUND SET done = true; ",
+        "SET total = 0; ",
+        "OPEN cur; ",
+        "read_loop: LOOP FETCH cur INTO x; ",
+        "IF done THEN LEAVE read_loop; ELSEIF x < 0 THEN ITERATE read_loop; END IF; ",
+        "SET total = total + x; ",
+        "END LOOP read_loop; ",
+        "CLOSE cur; ",
+        "END"
+    );
+    let Statement::CreateProcedure { params, body, .. } = mysql().verified_stmt(sql) else {
+        unreachable!()
+    };
+    let params = params.unwrap();
+    assert_eq!(params[0].mode, Some(ArgMode::In));
+    assert_eq!(params[1].mode, Some(ArgMode::Out));
+
+    let CreateProcedureBody::Routine(routine) = body else {
+        unreachable!()
+    };
+    let ScriptStatement::Block(block) = *routine else {
+        unreachable!()
+    };
+    assert!(block.declarations.is_empty());
+    assert_eq!(block.statements.len(), 8);
+    assert_eq!(
+        block.statements[1],
+        ScriptStatement::DeclareVariables {
+            names: vec![Ident::new("x"), Ident::new("y")],
+            data_type: DataType::Int(None),
+            default: None,
+        }
+    );
+    assert_matches!(
+        &block.statements[2],
+        ScriptStatement::DeclareCursor { name, .. } if name.value == "cur"
+    );
+    assert_eq!(
+        block.statements[5],
+        ScriptStatement::Open(Ident::new("cur"))
+    );
+    match &block.statements[6] {
+        ScriptStatement::Labeled {
+            label,
+            statement,
+            end_label,
+        } => {
+            assert_eq!(label, &Ident::new("read_loop"));
+            assert_eq!(end_label, &Some(Ident::new("read_loop")));
+            let ScriptStatement::Loop(statements) = statement.as_ref() else {
+                unreachable!()
+            };
+            assert_eq!(
+                statements[0],
+                ScriptStatement::Fetch {
+                    cursor: Ident::new("cur"),
+                    into: vec![Ident::new("x")],
+                }
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    // the parameter list and the end label are optional
+    mysql().one_statement_parses_to(
+        "CREATE PROCEDURE p() l: LOOP FETCH NEXT FROM c INTO a, b; LEAVE l; END LOOP",
+        "CREATE PROCEDURE p () l: LOOP FETCH c INTO a, b; LEAVE l; END LOOP",
+    );
+    mysql().verified_stmt(
+        "CREATE PROCEDURE p () BEGIN WHILE i < 10 DO SET i = i + 1; END WHILE; REPEAT SET i = i - 1; UNTIL i = 0 END REPEAT; END",
+    );
+
+    // the `l:` label needn't be repeated, but is kept if it is
+    mysql().verified_stmt("CREATE PROCEDURE p () l: BEGIN LEAVE l; END l");
+
+    // bodies preceded by `AS` are parsed as in other dialects
+    assert!(matches!(
+        mysql().verified_stmt("CREATE PROCEDURE foo AS BEGIN SELECT 1 END"),
+        Statement::CreateProcedure {
+            body: CreateProcedureBody::Statements(_),
+            ..
+        }
+    ));
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn parse_create_procedure_serde_round_trip() {
+    assert_serde_round_trip!(mysql().verified_stmt("CREATE PROCEDURE p (IN a INT) BEGIN DECLARE c CURSOR FOR SELECT 1; DECLARE CONTINUE HANDLER FOR NOT FOUND SET a = 1; OPEN c; l: REPEAT FETCH c INTO a; UNTIL a > 1 END REPEAT l; END"));
+}
+
+#[test]
+fn parse_declare_handler() {
+    let sql = "CREATE PROCEDURE p () BEGIN DECLARE EXIT HANDLER FOR SQLSTATE '23000', 1051, SQLWARNING, SQLEXCEPTION, no_such_table BEGIN ROLLBACK; END; END";
+    let Statement::CreateProcedure {
+        body: CreateProcedureBody::Routine(routine),
+        ..
+    } = mysql().verified_stmt(sql)
+    else {
+        unreachable!()
+    };
+    let ScriptStatement::Block(block) = *routine else {
+        unreachable!()
+    };
+    match &block.statements[0] {
+        ScriptStatement::DeclareHandler {
+            action,
+            conditions,
+            statement,
+        } => {
+            assert_eq!(action, &ScriptHandlerAction::Exit);
+            assert_eq!(
+                conditions,
+                &vec![
+                    ScriptHandlerCondition::SqlState("23000".to_string()),
+                    ScriptHandlerCondition::ErrorCode(1051),
+                    ScriptHandlerCondition::SqlWarning,
+                    ScriptHandlerCondition::SqlException,
+                    ScriptHandlerCondition::Name(Ident::new("no_such_table")),
+                ]
+            );
+            assert_matches!(statement.as_ref(), ScriptStatement::Block(_));
+        }
+        _ => unreachable!(),
+    }
+
+    mysql().one_statement_parses_to(
+        "CREATE PROCEDURE p() BEGIN DECLARE UNDO HANDLER FOR SQLSTATE VALUE '42S02' SET x = 1; END",
+        "CREATE PROCEDURE p () BEGIN DECLARE UNDO HANDLER FOR SQLSTATE '42S02' SET x = 1; END",
+    );
+}
+
+#[test]
+fn parse_delimiter_command() {
+    let sql = concat!(
+        "DELIMITER //\n",
+        "CREATE PROCEDURE p() BEGIN SELECT 1; END//\n",
+        "DELIMITER ;\n",
+        "SELECT 'a//b';\n",
+        "DELIMITER $$\n",
+        "SELECT c$$\n",
+    );
+    let statements = mysql().parse_sql_statements(sql).unwrap();
+    assert_eq!(
+        statements
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>(),
+        vec![
+            "CREATE PROCEDURE p () BEGIN SELECT 1; END",
+            "SELECT 'a//b'",
+            "SELECT c",
+        ]
+    );
+
+    // `DELIMITER` is only a command at the start of a statement
+    mysql().one_statement_parses_to("SELECT a,\ndelimiter FROM t", "SELECT a, delimiter FROM t");
+    assert_eq!(
+        mysql().parse_sql_statements("DELIMITER \n").unwrap_err(),
+        ParserError::TokenizerError(
+            "Expected a delimiter after DELIMITER at Line: 1, Column: 11".to_string()
+        )
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn parse_delimiter_command_lazily() {
+    // the delimiter has to be kept across the chunks read from the input
+    let mut sql = String::from("DELIMITER //\n");
+    for i in 0..1000 {
+        sql.push_str(&format!("CREATE PROCEDURE p{i}() BEGIN SELECT 1; END//\n"));
+    }
+    sql.push_str("DELIMITER ;\nSELECT 'a//b';\n");
+    let dialect = MySqlDialect {};
+    let statements = sqlparser::parser::Parser::iter_statements(&dialect, sql.as_bytes())
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(statements, mysql().parse_sql_statements(&sql).unwrap());
+    assert_eq!(statements.len(), 1001);
+}
+
+#[test]
+fn parse_handler() {
+    assert_eq!(
+        mysql_and_generic().verified_stmt("HANDLER t OPEN AS h"),
+        Statement::Handler {
+            table_name: ObjectName(vec![Ident::new("t")]),
+            operation: HandlerOperation::Open {
+                alias: Some(Ident::new("h")),
+            },
+        }
+    );
+    mysql_and_generic().one_statement_parses_to("HANDLER t OPEN h", "HANDLER t OPEN AS h");
+    mysql_and_generic().verified_stmt("HANDLER db.t OPEN");
+    mysql_and_generic().verified_stmt("HANDLER t READ FIRST");
+    mysql_and_generic().verified_stmt("HANDLER t READ NEXT LIMIT 10");
+    mysql_and_generic().verified_stmt("HANDLER t READ idx PREV");
+    mysql_and_generic().verified_stmt("HANDLER t READ idx LAST WHERE a > 1 LIMIT 2, 5");
+    mysql_and_generic().verified_stmt("HANDLER t CLOSE");
+
+    assert_eq!(
+        mysql_and_generic().verified_stmt("HANDLER t READ idx >= (1, 'a') WHERE b IS NULL"),
+        Statement::Handler {
+            table_name: ObjectName(vec![Ident::new("t")]),
+            operation: HandlerOperation::Read {
+                index_name: Some(Ident::new("idx")),
+                position: HandlerReadPosition::Compare {
+                    op: BinaryOperator::GtEq,
+                    values: vec![
+                        Expr::Value(number("1")),
+                        Expr::Value(Value::SingleQuotedString("a".to_string())),
+                    ],
+                },
+                selection: Some(Expr::IsNull(Box::new(Expr::Identifier(Ident::new("b"))))),
+                offset: None,
+                limit: None,
+            },
+        }
+    );
+
+    assert!(mysql_and_generic()
+        .parse_sql_statements("HANDLER t READ idx")
+        .is_err());
+    assert!(mysql_and_generic()
+        .parse_sql_statements("HANDLER t READ idx != (1)")
+        .is_err());
+    assert!(mysql_and_generic()
+        .parse_sql_statements("HANDLER t DELETE")
+        .is_err());
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn parse_handler_serde_round_trip() {
+    assert_serde_round_trip!(
+        mysql_and_generic().verified_stmt("HANDLER t READ idx >= (1, 'a') WHERE b > 2 LIMIT 10")
+    );
+}
+
+#[test]
+fn parse_do_expressions() {
+    match mysql_and_generic().verified_stmt("DO SLEEP(1), RELEASE_LOCK('lock')") {
+        Statement::Do {
+            language: None,
+            body: DoBody::Expressions(exprs),
+        } => {
+            assert_eq!(exprs.len(), 2);
+            assert_eq!(exprs[0].to_string(), "SLEEP(1)");
+        }
+        _ => unreachable!(),
+    }
+    mysql_and_generic().verified_stmt("DO 1 + 1");
+    // A string literal after DO is a code block in the generic dialect
+    mysql().verified_stmt("DO 'a'");
+}
+
+#[test]
+fn parse_set_password() {
+    assert_eq!(
+        mysql_and_generic().verified_stmt("SET PASSWORD FOR 'u'@'h' = 'hash'"),
+        Statement::SetPassword {
+            user: Some(AccountName {
+                user: Ident::with_quote('\'', "u"),
+                host: Some(Ident::with_quote('\'', "h")),
+            }),
+            password: SetPasswordValue::Expr(Expr::Value(Value::SingleQuotedString(
+                "hash".to_string()
+            ))),
+            replace: None,
+            retain_current_password: false,
+        }
+    );
+    assert_eq!(
+        mysql_and_generic()
+            .verified_stmt("SET PASSWORD TO RANDOM REPLACE 'old' RETAIN CURRENT PASSWORD"),
+        Statement::SetPassword {
+            user: None,
+            password: SetPasswordValue::Random,
+            replace: Some(Value::SingleQuotedString("old".to_string())),
+            retain_current_password: true,
+        }
+    );
+    mysql_and_generic().verified_stmt("SET PASSWORD = PASSWORD('secret')");
+    mysql_and_generic().verified_stmt("SET PASSWORD FOR u = 'secret' RETAIN CURRENT PASSWORD");
+    assert!(mysql_and_generic()
+        .parse_sql_statements("SET PASSWORD FOR 'u'@'h'")
+        .is_err());
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn parse_set_password_serde_round_trip() {
+    assert_serde_round_trip!(mysql_and_generic().verified_stmt(
+        "SET PASSWORD FOR 'u'@'h' = 'secret' REPLACE 'old' RETAIN CURRENT PASSWORD"
+    ));
+}
+
+#[test]
+fn parse_grant_revoke_proxy() {
+    let proxied_user = AccountName {
+        user: Ident::with_quote('\'', "admin"),
+        host: Some(Ident::with_quote('\'', "%")),
+    };
+    assert_eq!(
+        mysql_and_generic()
+            .verified_stmt("GRANT PROXY ON 'admin'@'%' TO 'u'@'localhost', v WITH GRANT OPTION"),
+        Statement::GrantProxy {
+            proxied_user: proxied_user.clone(),
+            grantees: vec![
+                AccountName {
+                    user: Ident::with_quote('\'', "u"),
+                    host: Some(Ident::with_quote('\'', "localhost")),
+                },
+                AccountName {
+                    user: Ident::new("v"),
+                    host: None,
+                },
+            ],
+            with_grant_option: true,
+        }
+    );
+    assert_eq!(
+        mysql_and_generic().verified_stmt("REVOKE PROXY ON 'admin'@'%' FROM v"),
+        Statement::RevokeProxy {
+            proxied_user,
+            grantees: vec![AccountName {
+                user: Ident::new("v"),
+                host: None,
+            }],
+        }
+    );
+    assert!(mysql_and_generic()
+        .parse_sql_statements("GRANT PROXY ON 'admin'@'%'")
+        .is_err());
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn parse_grant_revoke_proxy_serde_round_trip() {
+    for sql in [
+        "GRANT PROXY ON 'a'@'%' TO 'b'@'localhost' WITH GRANT OPTION",
+        "REVOKE PROXY ON 'a'@'%' FROM 'b'@'localhost'",
+    ] {
+        assert_serde_round_trip!(mysql_and_generic().verified_stmt(sql));
+    }
+}
+
+#[test]
+fn parse_reserved_keywords_for_aliases() {
+    let clickhouse = TestedDialects {
+        dialects: vec![Box::new(ClickHouseDialect {})],
+        options: None,
+    };
+    let mssql = TestedDialects {
+        dialects: vec![Box::new(MsSqlDialect {})],
+        options: None,
+    };
+
+    // ClickHouse's SETTINGS clause isn't parsed for MySQL
+    let sql = "SELECT settings.a FROM user_settings settings";
+    mysql().one_statement_parses_to(sql, "SELECT settings.a FROM user_settings AS settings");
+    assert!(clickhouse.parse_sql_statements(sql).is_err());
+
+    // TOP isn't a reserved word in MySQL
+    let sql = "SELECT MAX(score) top FROM t";
+    mysql().one_statement_parses_to(sql, "SELECT MAX(score) AS top FROM t");
+    assert!(mssql.parse_sql_statements(sql).is_err());
+
+    // index hints
+    let sql = "SELECT * FROM t use";
+    assert!(mysql().parse_sql_statements(sql).is_err());
+    TestedDialects {
+        dialects: vec![Box::new(GenericDialect {})],
+        options: None,
+    }
+    .one_statement_parses_to(sql, "SELECT * FROM t AS use");
+}
@@ -0,0 +1,210 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+#![warn(clippy::all)]
+//! Test SQL syntax specific to Oracle. The parser based on the generic dialect
+//! is also tested (on the inputs it can handle).
+
+use sqlparser::ast::*;
+use sqlparser::dialect::{GenericDialect, OracleDialect, SnowflakeDialect};
+use sqlparser::parser::ParserError;
+use test_utils::*;
+
+#[macro_use]
+mod test_utils;
+
+fn oracle() -> TestedDialects {
+    TestedDialects::new(vec![Box::new(OracleDialect {})])
+}
+
+#[test]
+fn parse_quote_delimited_string() {
+    let select = oracle().verified_only_select_with_canonical(
+        "SELECT q'[it's]', Q'{a}b}', q'!x!', q'<(')>', nq'[y]'",
+        "SELECT Q'[it's]', Q'{a}b}', Q'!x!', Q'<(')>', NQ'[y]'",
+    );
+    assert_eq!(
+        select.projection[0],
+        SelectItem::UnnamedExpr(Expr::Value(Value::QuoteDelimitedStringLiteral(
+            QuoteDelimitedString {
+                start_quote: '[',
+                value: "it's".into(),
+                end_quote: ']',
+            }
+        )))
+    );
+    assert_eq!(
+        select.projection[4],
+        SelectItem::UnnamedExpr(Expr::Value(Value::NationalQuoteDelimitedStringLiteral(
+            QuoteDelimitedString {
+                start_quote: '[',
+                value: "y".into(),
+                end_quote: ']',
+            }
+        )))
+    );
+
+    // a plain identifier starting with `q` or `nq` is unaffected
+    oracle().verified_only_select("SELECT qty, q, nq, nqty FROM t");
+
+    assert_eq!(
+        oracle().parse_sql_statements("SELECT q'[abc'").unwrap_err(),
+        ParserError::TokenizerError(
+            "Unterminated quote-delimited string literal at Line: 1, Column: 8".to_string()
+        )
+    );
+
+    // other dialects parse `q` as an alias of the following string
+    let generic = TestedDialects::new(vec![Box::new(GenericDialect {})]);
+    generic.one_statement_parses_to("SELECT q'abc' FROM t", "SELECT q AS 'abc' FROM t");
+}
+
+#[test]
+fn parse_minus() {
+    let dialects = TestedDialects::new(vec![
+        Box::new(OracleDialect {}),
+        Box::new(SnowflakeDialect {}),
+    ]);
+    let query = dialects.verified_query("SELECT a FROM t MINUS SELECT a FROM u");
+    match *query.body {
+        SetExpr::SetOperation {
+            op, set_quantifier, ..
+        } => {
+            assert_eq!(op, SetOperator::Minus);
+            assert_eq!(set_quantifier, SetQuantifier::None);
+        }
+        _ => unreachable!(),
+    }
+
+    oracle().verified_stmt("SELECT 1 MINUS ALL SELECT 2 UNION SELECT 3");
+
+    // `MINUS` is an ordinary identifier in other dialects
+    let dialects = all_dialects_except(|d| d.supports_minus_set_operator());
+    dialects.one_statement_parses_to("SELECT a minus FROM t", "SELECT a AS minus FROM t");
+    dialects.one_statement_parses_to(
+        "SELECT * FROM t minus WHERE minus.x = 1",
+        "SELECT * FROM t AS minus WHERE minus.x = 1",
+    );
+}
+
+#[test]
+fn parse_connect_by_with_rownum() {
+    let select = oracle().verified_only_select(
+        "SELECT employee_id, ROWNUM FROM employees \
+         START WITH manager_id IS NULL \
+         CONNECT BY PRIOR employee_id = manager_id",
+    );
+    assert_eq!(
+        select.projection[1],
+        SelectItem::UnnamedExpr(Expr::Identifier(Ident::new("ROWNUM")))
+    );
+    assert!(select.connect_by.is_some());
+
+    oracle().verified_only_select("SELECT * FROM t WHERE ROWNUM <= 10");
+}
+
+#[test]
+fn parse_outer_join_operator() {
+    let select =
+        oracle().verified_only_select("SELECT t1.c1, t2.c2 FROM t1, t2 WHERE t1.c1 = t2.c2 (+)");
+    assert_eq!(
+        select.selection,
+        Some(Expr::BinaryOp {
+            left: Box::new(Expr::CompoundIdentifier(vec![
+                Ident::new("t1"),
+                Ident::new("c1")
+            ])),
+            op: BinaryOperator::Eq,
+            right: Box::new(Expr::OuterJoin(Box::new(Expr::CompoundIdentifier(vec![
+                Ident::new("t2"),
+                Ident::new("c2")
+            ])))),
+        })
+    );
+
+    // the operator is tied to the dialect, `c2(+)` is a function call elsewhere
+    assert!(TestedDialects::new(vec![Box::new(GenericDialect {})])
+        .parse_sql_statements("SELECT * FROM t1, t2 WHERE c1 = c2(+)")
+        .is_err());
+}
+
+#[test]
+fn parse_oracle_data_types() {
+    let sql = "CREATE TABLE t (a VARCHAR2(20), b NUMBER(10,2), c NUMBER, d VARCHAR2)";
+    match oracle().verified_stmt(sql) {
+        Statement::CreateTable(CreateTable { columns, .. }) => {
+            let data_types: Vec<_> = columns.into_iter().map(|c| c.data_type).collect();
+            assert_eq!(
+                data_types,
+                vec![
+                    DataType::Varchar2(Some(CharacterLength::IntegerLength {
+                        length: 20,
+                        unit: None
+                    })),
+                    DataType::Number(ExactNumberInfo::PrecisionAndScale(10, 2)),
+                    DataType::Number(ExactNumberInfo::None),
+                    DataType::Varchar2(None),
+                ]
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_oracle_character_length_semantics() {
+    let sql = "CREATE TABLE t (a VARCHAR2(10 CHAR), b VARCHAR2(10 BYTE))";
+    match oracle().verified_stmt(sql) {
+        Statement::CreateTable(CreateTable { columns, .. }) => {
+            let data_types: Vec<_> = columns.into_iter().map(|c| c.data_type).collect();
+            assert_eq!(
+                data_types,
+                vec![
+                    DataType::Varchar2(Some(CharacterLength::IntegerLength {
+                        length: 10,
+                        unit: Some(CharLengthUnits::Char)
+                    })),
+                    DataType::Varchar2(Some(CharacterLength::IntegerLength {
+                        length: 10,
+                        unit: Some(CharLengthUnits::Byte)
+                    })),
+                ]
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_oracle_identifiers() {
+    oracle().verified_only_select("SELECT emp#, sys$id FROM hr.employees");
+}
+
+#[test]
+fn parse_multi_table_insert() {
+    match oracle().verified_stmt("INSERT ALL INTO t1 INTO t2 (c1) VALUES (n1) SELECT n1 FROM src") {
+        Statement::MultiTableInsert(MultiTableInsert {
+            kind, into_clauses, ..
+        }) => {
+            assert_eq!(kind, MultiTableInsertKind::All);
+            assert_eq!(into_clauses.len(), 2);
+        }
+        _ => unreachable!(),
+    }
+
+    oracle()
+        .verified_stmt("INSERT FIRST WHEN n1 > 10 THEN INTO t1 ELSE INTO t2 SELECT n1 FROM src");
+}
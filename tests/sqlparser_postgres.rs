@@ -25,7 +25,9 @@ use test_utils::*;
 
 use sqlparser::ast::*;
 use sqlparser::dialect::{GenericDialect, PostgreSqlDialect};
-use sqlparser::parser::ParserError;
+use sqlparser::parser::{ParserError, ParserOptions};
+use sqlparser::tokenizer::{Token, Whitespace};
+use std::sync::Arc;
 
 #[test]
 fn parse_create_table_generated_always_as_identity() {
@@ -346,11 +348,15 @@ fn parse_create_table_with_defaults() {
             name,
             columns,
             constraints,
-            with_options,
             if_not_exists: false,
             external: false,
-            file_format: None,
-            location: None,
+            storage:
+                CreateTableStorage {
+                    with_options,
+                    file_format: None,
+                    location: None,
+                    ..
+                },
             ..
         }) => {
             use pretty_assertions::assert_eq;
@@ -592,6 +598,32 @@ fn parse_alter_table_constraints_rename() {
     }
 }
 
+#[test]
+fn parse_alter_table_constraints_not_valid() {
+    match alter_table_op(pg_and_generic().verified_stmt(
+        "ALTER TABLE tab ADD CONSTRAINT fk FOREIGN KEY (a) REFERENCES other(b) NOT VALID",
+    )) {
+        AlterTableOperation::AddConstraint {
+            constraint,
+            not_valid,
+        } => {
+            assert_eq!(
+                constraint.to_string(),
+                "CONSTRAINT fk FOREIGN KEY (a) REFERENCES other(b)"
+            );
+            assert!(not_valid);
+        }
+        _ => unreachable!(),
+    }
+
+    match alter_table_op(pg_and_generic().verified_stmt("ALTER TABLE tab VALIDATE CONSTRAINT fk")) {
+        AlterTableOperation::ValidateConstraint { name } => {
+            assert_eq!(name.to_string(), "fk");
+        }
+        _ => unreachable!(),
+    }
+}
+
 #[test]
 fn parse_alter_table_disable() {
     pg_and_generic().verified_stmt("ALTER TABLE tab DISABLE ROW LEVEL SECURITY");
@@ -641,6 +673,307 @@ fn parse_create_extension() {
         .verified_stmt("CREATE EXTENSION extension_name WITH SCHEMA schema_name VERSION version");
 }
 
+#[test]
+fn parse_alter_extension() {
+    assert_eq!(
+        pg_and_generic().verified_stmt("ALTER EXTENSION postgis UPDATE TO '3.4'"),
+        Statement::AlterExtension {
+            name: Ident::new("postgis"),
+            operation: AlterExtensionOperation::Update {
+                version: Some(Ident::with_quote('\'', "3.4")),
+            },
+        }
+    );
+    pg_and_generic().verified_stmt("ALTER EXTENSION hstore UPDATE");
+    pg_and_generic().verified_stmt("ALTER EXTENSION hstore SET SCHEMA utils");
+
+    assert_eq!(
+        pg().parse_sql_statements("ALTER EXTENSION hstore OWNER TO bob")
+            .unwrap_err(),
+        ParserError::ParserError(
+            "Expected: UPDATE or SET SCHEMA after ALTER EXTENSION, found: OWNER".to_string()
+        )
+    );
+}
+
+#[test]
+fn parse_create_collation() {
+    assert_eq!(
+        pg_and_generic().verified_stmt(
+            "CREATE COLLATION IF NOT EXISTS german_ci (provider = icu, locale = 'de-u-ks-level2', deterministic = false)"
+        ),
+        Statement::CreateCollation {
+            if_not_exists: true,
+            name: ObjectName(vec![Ident::new("german_ci")]),
+            definition: CollationDefinition::Options(vec![
+                SqlOption::KeyValue {
+                    key: Ident::new("provider"),
+                    value: Expr::Identifier(Ident::new("icu")),
+                },
+                SqlOption::KeyValue {
+                    key: Ident::new("locale"),
+                    value: Expr::Value(Value::SingleQuotedString("de-u-ks-level2".to_string())),
+                },
+                SqlOption::KeyValue {
+                    key: Ident::new("deterministic"),
+                    value: Expr::Value(Value::Boolean(false)),
+                },
+            ]),
+        }
+    );
+    assert_eq!(
+        pg_and_generic().verified_stmt(r#"CREATE COLLATION s.c FROM "C""#),
+        Statement::CreateCollation {
+            if_not_exists: false,
+            name: ObjectName(vec![Ident::new("s"), Ident::new("c")]),
+            definition: CollationDefinition::From(ObjectName(vec![Ident::with_quote('"', "C")])),
+        }
+    );
+    pg_and_generic().verified_stmt("DROP COLLATION IF EXISTS german_ci CASCADE");
+    pg_and_generic().verified_query(r#"SELECT a FROM t ORDER BY a COLLATE "de-x-icu""#);
+}
+
+#[test]
+fn parse_alter_collation() {
+    assert_eq!(
+        pg_and_generic().verified_stmt("ALTER COLLATION german_ci RENAME TO german"),
+        Statement::AlterCollation {
+            name: ObjectName(vec![Ident::new("german_ci")]),
+            operation: AlterCollationOperation::RenameTo {
+                new_name: Ident::new("german"),
+            },
+        }
+    );
+    pg_and_generic().verified_stmt("ALTER COLLATION s.german_ci REFRESH VERSION");
+    pg_and_generic().verified_stmt("ALTER COLLATION german_ci OWNER TO CURRENT_ROLE");
+    pg_and_generic().verified_stmt("ALTER COLLATION german_ci SET SCHEMA utils");
+
+    assert_eq!(
+        pg().parse_sql_statements("ALTER COLLATION c UPDATE")
+            .unwrap_err(),
+        ParserError::ParserError(
+            "Expected: REFRESH VERSION, RENAME TO, OWNER TO or SET SCHEMA after ALTER COLLATION, found: UPDATE".to_string()
+        )
+    );
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn parse_collation_serde_round_trip() {
+    for sql in [
+        "CREATE COLLATION c (provider = icu, locale = 'und')",
+        "ALTER COLLATION c REFRESH VERSION",
+    ] {
+        assert_serde_round_trip!(pg_and_generic().verified_stmt(sql));
+    }
+}
+
+#[test]
+fn parse_create_statistics() {
+    assert_eq!(
+        pg_and_generic()
+            .verified_stmt("CREATE STATISTICS st (dependencies, ndistinct) ON a, b FROM t"),
+        Statement::CreateStatistics {
+            if_not_exists: false,
+            name: Some(ObjectName(vec![Ident::new("st")])),
+            kinds: vec![Ident::new("dependencies"), Ident::new("ndistinct")],
+            on: vec![
+                Expr::Identifier(Ident::new("a")),
+                Expr::Identifier(Ident::new("b")),
+            ],
+            table_name: ObjectName(vec![Ident::new("t")]),
+        }
+    );
+    pg_and_generic().verified_stmt(
+        "CREATE STATISTICS IF NOT EXISTS s.st (mcv) ON (lower(a)), (b + c) FROM s.t",
+    );
+    pg_and_generic().verified_stmt("CREATE STATISTICS ON a, b FROM t");
+    pg_and_generic().verified_stmt("DROP STATISTICS IF EXISTS st, s.st2 CASCADE");
+
+    assert!(pg()
+        .parse_sql_statements("CREATE STATISTICS IF NOT EXISTS ON a, b FROM t")
+        .is_err());
+    assert!(pg()
+        .parse_sql_statements("CREATE STATISTICS st ON a, b")
+        .is_err());
+}
+
+#[test]
+fn parse_alter_statistics() {
+    assert_eq!(
+        pg_and_generic().verified_stmt("ALTER STATISTICS s.st SET STATISTICS 500"),
+        Statement::AlterStatistics {
+            name: ObjectName(vec![Ident::new("s"), Ident::new("st")]),
+            operation: AlterStatisticsOperation::SetStatistics {
+                target: Some(Expr::Value(number("500"))),
+            },
+        }
+    );
+    pg_and_generic().verified_stmt("ALTER STATISTICS st SET STATISTICS DEFAULT");
+    pg_and_generic().verified_stmt("ALTER STATISTICS st OWNER TO CURRENT_USER");
+    pg_and_generic().verified_stmt("ALTER STATISTICS st RENAME TO st2");
+    pg_and_generic().verified_stmt("ALTER STATISTICS st SET SCHEMA utils");
+
+    assert_eq!(
+        pg().parse_sql_statements("ALTER STATISTICS st REFRESH VERSION")
+            .unwrap_err(),
+        ParserError::ParserError(
+            "Expected: OWNER TO, RENAME TO, SET SCHEMA or SET STATISTICS after ALTER STATISTICS, found: REFRESH".to_string()
+        )
+    );
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn parse_statistics_serde_round_trip() {
+    for sql in [
+        "CREATE STATISTICS s (ndistinct) ON a, (b + 1) FROM t",
+        "ALTER STATISTICS s SET STATISTICS 100",
+    ] {
+        assert_serde_round_trip!(pg_and_generic().verified_stmt(sql));
+    }
+}
+
+#[test]
+fn parse_generic_ddl() {
+    assert_eq!(
+        pg_and_generic()
+            .verified_stmt("CREATE ACCESS METHOD heptree TYPE INDEX HANDLER heptree_handler"),
+        Statement::GenericDdl {
+            verb: vec![
+                Ident::new("CREATE"),
+                Ident::new("ACCESS"),
+                Ident::new("METHOD")
+            ],
+            name: Some(ObjectName(vec![Ident::new("heptree")])),
+            options: vec![
+                Token::Whitespace(Whitespace::Space),
+                Token::make_keyword("TYPE"),
+                Token::Whitespace(Whitespace::Space),
+                Token::make_keyword("INDEX"),
+                Token::Whitespace(Whitespace::Space),
+                Token::make_keyword("HANDLER"),
+                Token::Whitespace(Whitespace::Space),
+                Token::make_word("heptree_handler", None),
+            ],
+        }
+    );
+    match pg_and_generic().verified_stmt("DROP SERVER IF EXISTS s1, s2 CASCADE") {
+        Statement::GenericDdl { verb, name, .. } => {
+            assert_eq!(
+                display_separated(&verb, " ").to_string(),
+                "DROP SERVER IF EXISTS"
+            );
+            assert_eq!(name, Some(ObjectName(vec![Ident::new("s1")])));
+        }
+        _ => unreachable!(),
+    }
+    match pg_and_generic().verified_stmt("CREATE CAST (text AS int) WITH INOUT") {
+        Statement::GenericDdl { name, .. } => assert_eq!(name, None),
+        _ => unreachable!(),
+    }
+
+    for sql in [
+        "CREATE OR REPLACE TRANSFORM FOR hstore LANGUAGE plpython3u (FROM SQL WITH FUNCTION hstore_to_plpython(internal), TO SQL WITH FUNCTION plpython_to_hstore(internal))",
+        "CREATE SERVER IF NOT EXISTS foo FOREIGN DATA WRAPPER postgres_fdw OPTIONS (host 'foo', dbname 'foodb')",
+        "CREATE USER MAPPING FOR bob SERVER foo OPTIONS (user 'bob', password 'secret')",
+        "CREATE FOREIGN DATA WRAPPER dummy",
+        "ALTER TEXT SEARCH CONFIGURATION my_config ALTER MAPPING FOR asciiword WITH english_stem",
+        "CREATE EVENT TRIGGER abort_ddl ON ddl_command_start EXECUTE FUNCTION abort_any_command()",
+        "CREATE OPERATOR === (LEFTARG = box, RIGHTARG = box, FUNCTION = area_equal_function)",
+        "CREATE OPERATOR CLASS gist__int_ops DEFAULT FOR TYPE _int4 USING gist AS OPERATOR 3 &&",
+        "CREATE AGGREGATE sum2 (int) (sfunc = int4pl, stype = int)",
+        "CREATE PUBLICATION alltables FOR ALL TABLES",
+        "ALTER SUBSCRIPTION mysub DISABLE",
+        "DROP TABLESPACE IF EXISTS space1",
+        "CREATE DEFAULT CONVERSION myconv FOR 'UTF8' TO 'LATIN1' FROM myfunc",
+    ] {
+        pg_and_generic().verified_stmt(sql);
+    }
+    let statements = pg()
+        .parse_sql_statements("DROP SUBSCRIPTION mysub ; SELECT 1")
+        .unwrap();
+    assert_eq!(statements.len(), 2);
+    assert_eq!(statements[0].to_string(), "DROP SUBSCRIPTION mysub");
+
+    assert!(pg()
+        .parse_sql_statements("CREATE SERVER s OPTIONS (host 'foo'")
+        .is_err());
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn parse_generic_ddl_serde_round_trip() {
+    assert_serde_round_trip!(pg_and_generic()
+        .verified_stmt("CREATE ACCESS METHOD m TYPE TABLE HANDLER heap_tableam_handler"));
+}
+
+#[test]
+fn parse_create_language() {
+    assert_eq!(
+        pg_and_generic().verified_stmt(
+            "CREATE OR REPLACE TRUSTED PROCEDURAL LANGUAGE plperl HANDLER plperl_call_handler \
+            INLINE plperl_inline_handler VALIDATOR pg_catalog.plperl_validator"
+        ),
+        Statement::CreateLanguage {
+            or_replace: true,
+            trusted: true,
+            procedural: true,
+            name: Ident::new("plperl"),
+            handler: Some(ObjectName(vec![Ident::new("plperl_call_handler")])),
+            inline_handler: Some(ObjectName(vec![Ident::new("plperl_inline_handler")])),
+            validator: Some(ObjectName(vec![
+                Ident::new("pg_catalog"),
+                Ident::new("plperl_validator")
+            ])),
+        }
+    );
+    pg_and_generic().verified_stmt("CREATE TRUSTED LANGUAGE plperl");
+    pg_and_generic().verified_stmt("CREATE PROCEDURAL LANGUAGE plsample");
+    pg_and_generic().verified_stmt("CREATE LANGUAGE plsample HANDLER plsample_call_handler");
+}
+
+#[test]
+fn parse_create_rule() {
+    assert_eq!(
+        pg_and_generic().verified_stmt("CREATE RULE notify_me AS ON UPDATE TO t DO ALSO NOTIFY ch"),
+        Statement::CreateRule {
+            or_replace: false,
+            name: Ident::new("notify_me"),
+            event: RuleEvent::Update,
+            table_name: ObjectName(vec![Ident::new("t")]),
+            condition: None,
+            kind: Some(RuleKind::Also),
+            commands: vec![Statement::Notify {
+                channel: Ident::new("ch"),
+                payload: None,
+            }],
+        }
+    );
+    pg_and_generic().verified_stmt(
+        "CREATE OR REPLACE RULE r AS ON INSERT TO public.t WHERE NEW.a > 1 DO INSTEAD NOTHING",
+    );
+    pg_and_generic()
+        .verified_stmt(r#"CREATE RULE "_RETURN" AS ON SELECT TO v DO INSTEAD SELECT * FROM t"#);
+    pg_and_generic().one_statement_parses_to(
+        "CREATE RULE r AS ON DELETE TO t DO ( INSERT INTO log VALUES (OLD.id); NOTIFY ch, 'gone'; )",
+        "CREATE RULE r AS ON DELETE TO t DO (INSERT INTO log VALUES (OLD.id); NOTIFY ch, 'gone')",
+    );
+
+    assert_eq!(
+        pg().parse_sql_statements("CREATE RULE r AS ON TRUNCATE TO t DO NOTHING")
+            .unwrap_err()
+            .to_string(),
+        "sql parser error: Expected: one of SELECT or INSERT or UPDATE or DELETE, found: TRUNCATE"
+    );
+}
+
+#[test]
+fn parse_notify() {
+    pg_and_generic().verified_stmt("NOTIFY ch");
+    pg_and_generic().verified_stmt("NOTIFY ch, 'payload'");
+}
+
 #[test]
 fn parse_alter_table_alter_column() {
     pg().one_statement_parses_to(
@@ -714,6 +1047,7 @@ fn parse_alter_table_add_columns() {
             operations,
             location: _,
             on_cluster: _,
+            unknown_options: _,
         } => {
             assert_eq!(name.to_string(), "tab");
             assert!(if_exists);
@@ -797,6 +1131,7 @@ fn parse_alter_table_owner_to() {
                 operations,
                 location: _,
                 on_cluster: _,
+                unknown_options: _,
             } => {
                 assert_eq!(name.to_string(), "tab");
                 assert_eq!(
@@ -874,6 +1209,7 @@ fn parse_create_schema_if_not_exists() {
         Statement::CreateSchema {
             if_not_exists: true,
             schema_name,
+            ..
         } => assert_eq!("schema_name", schema_name.to_string()),
         _ => unreachable!(),
     }
@@ -918,6 +1254,80 @@ PHP	₱ USD $
     pg_and_generic().one_statement_parses_to(sql, "");
 }
 
+#[test]
+fn parse_copy_from_stdin_data_formats() {
+    let copy_values = |statement: &Statement| match statement {
+        Statement::Copy { values, .. } => values.clone(),
+        _ => unreachable!(),
+    };
+
+    // quotes and backslash escapes in TEXT data are not SQL syntax
+    let sql = "COPY t (a, b) FROM STDIN;\nO'Brien\ta\\\tb\n\\N\t\n\\.";
+    let statement = pg_and_generic().one_statement_parses_to(sql, "");
+    assert_eq!(
+        copy_values(&statement),
+        vec![
+            Some("O'Brien".to_string()),
+            Some("a\\\tb".to_string()),
+            None,
+            Some("".to_string()),
+        ]
+    );
+
+    // CSV values keep their quotes, which may contain delimiters and newlines
+    let sql = "COPY t (a, b) FROM STDIN (FORMAT csv, NULL 'null');\n\"x, \"\"y\"\"\nz\",null";
+    let statement = pg_and_generic().verified_stmt(&format!("{sql}\n\\."));
+    assert_eq!(
+        copy_values(&statement),
+        vec![Some("\"x, \"\"y\"\"\nz\"".to_string()), None]
+    );
+    let sql = "COPY t FROM STDIN CSV QUOTE '''' ESCAPE '\\';\n'it\\'s',,''\n1;2,\"a\n\\.";
+    let statement = pg_and_generic().one_statement_parses_to(sql, "");
+    assert_eq!(
+        copy_values(&statement),
+        vec![
+            Some("'it\\'s'".to_string()),
+            None,
+            Some("''".to_string()),
+            Some("1;2".to_string()),
+            Some("\"a".to_string()),
+        ]
+    );
+
+    // the data ends the statement without a delimiter
+    let sql = "COPY t FROM STDIN (FORMAT csv);\n\"1;\nSELECT 2;\"\n\\.\nSELECT 3";
+    let statements = pg().parse_sql_statements(sql).unwrap();
+    assert_eq!(statements.len(), 2);
+    assert_eq!(
+        copy_values(&statements[0]),
+        vec![Some("\"1;\nSELECT 2;\"".to_string())]
+    );
+    assert_eq!(statements[1].to_string(), "SELECT 3");
+
+    // binary data can't be split
+    assert_eq!(
+        pg().parse_sql_statements("COPY t FROM STDIN (FORMAT binary);\nPGCOPY\n\\.")
+            .unwrap_err()
+            .to_string(),
+        "sql parser error: COPY data in binary format is not supported"
+    );
+    assert!(pg()
+        .parse_sql_statements("COPY t FROM STDIN BINARY;")
+        .is_ok());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn parse_copy_from_stdin_lazily() {
+    // the data ends the statement without a delimiter
+    let sql = "COPY t FROM STDIN (FORMAT csv);\n\"1;\nSELECT 2;\"\n\\.\nSELECT 3";
+    let statements: Vec<_> =
+        sqlparser::parser::Parser::iter_statements(&PostgreSqlDialect {}, sql.as_bytes())
+            .collect::<Result<_, _>>()
+            .unwrap();
+    assert_eq!(statements, pg().parse_sql_statements(sql).unwrap());
+}
+
 #[test]
 fn test_copy_from() {
     let stmt = pg().verified_stmt("COPY users FROM 'data.csv'");
@@ -1092,6 +1502,34 @@ fn parse_copy_from() {
     );
 }
 
+#[test]
+fn parse_copy_options() {
+    let sql = "COPY t FROM 'file.csv' (FORMAT csv, HEADER MATCH, DEFAULT '\\D', FORCE_NOT_NULL *, FORCE_NULL *, ON_ERROR ignore, LOG_VERBOSITY verbose)";
+    match pg_and_generic().verified_stmt(sql) {
+        Statement::Copy { options, .. } => assert_eq!(
+            options,
+            vec![
+                CopyOption::Format("csv".into()),
+                CopyOption::HeaderMatch,
+                CopyOption::Default("\\D".into()),
+                CopyOption::ForceNotNullAll,
+                CopyOption::ForceNullAll,
+                CopyOption::OnError("ignore".into()),
+                CopyOption::LogVerbosity("verbose".into()),
+            ]
+        ),
+        _ => unreachable!(),
+    }
+
+    pg_and_generic().verified_stmt(
+        "COPY (SELECT * FROM t) TO PROGRAM 'gzip > x.gz' (FORMAT csv, HEADER, FORCE_QUOTE *)",
+    );
+    pg_and_generic().one_statement_parses_to(
+        "COPY t TO 'f' WITH (HEADER ON, FREEZE OFF, HEADER 0, FREEZE 1)",
+        "COPY t TO 'f' (HEADER, FREEZE FALSE, HEADER FALSE, FREEZE)",
+    );
+}
+
 #[test]
 fn parse_copy_from_error() {
     let res = pg().parse_sql_statements("COPY (SELECT 42 AS a, 'hello' AS b) FROM 'query.csv'");
@@ -1206,6 +1644,7 @@ fn parse_copy_to() {
                 for_clause: None,
                 settings: None,
                 format_clause: None,
+                option_clause: None,
             })),
             to: true,
             target: CopyTarget::File {
@@ -1351,10 +1790,7 @@ fn parse_set() {
             local: false,
             hivevar: false,
             variables: OneOrManyWithParens::One(ObjectName(vec![Ident::new("a")])),
-            value: vec![Expr::Identifier(Ident {
-                value: "DEFAULT".into(),
-                quote_style: None
-            })],
+            value: vec![Expr::Default],
         }
     );
 
@@ -1452,7 +1888,7 @@ fn parse_set_role() {
         Statement::SetRole {
             context_modifier: ContextModifier::Local,
             role_name: Some(Ident {
-                value: "rolename".to_string(),
+                value: "rolename".into(),
                 quote_style: Some('\"'),
             }),
         }
@@ -1466,7 +1902,7 @@ fn parse_set_role() {
         Statement::SetRole {
             context_modifier: ContextModifier::None,
             role_name: Some(Ident {
-                value: "rolename".to_string(),
+                value: "rolename".into(),
                 quote_style: Some('\''),
             }),
         }
@@ -1643,12 +2079,20 @@ fn parse_prepare() {
     };
     assert_eq!(
         sub_stmt,
-        Box::new(Statement::Query(Box::new(pg_and_generic().verified_query(
+        Box::new(Statement::Query(Arc::new(pg_and_generic().verified_query(
             "SELECT * FROM customers WHERE customers.id = a1"
         ))))
     );
 }
 
+/// Returns the only `ON` clause of an `INSERT` statement.
+fn single_on_insert(stmt: Statement) -> OnInsert {
+    match stmt {
+        Statement::Insert(Insert { mut on, .. }) if on.len() == 1 => on.remove(0),
+        _ => unreachable!(),
+    }
+}
+
 #[test]
 fn parse_pg_on_conflict() {
     let stmt = pg_and_generic().verified_stmt(
@@ -1657,14 +2101,10 @@ fn parse_pg_on_conflict() {
         ON CONFLICT(did) \
         DO UPDATE SET dname = EXCLUDED.dname",
     );
-    match stmt {
-        Statement::Insert(Insert {
-            on:
-                Some(OnInsert::OnConflict(OnConflict {
-                    conflict_target: Some(ConflictTarget::Columns(cols)),
-                    action,
-                })),
-            ..
+    match single_on_insert(stmt) {
+        OnInsert::OnConflict(OnConflict {
+            conflict_target: Some(ConflictTarget::Columns(cols)),
+            action,
         }) => {
             assert_eq!(vec![Ident::from("did")], cols);
             assert_eq!(
@@ -1687,14 +2127,10 @@ fn parse_pg_on_conflict() {
         ON CONFLICT(did, area) \
         DO UPDATE SET dname = EXCLUDED.dname, area = EXCLUDED.area",
     );
-    match stmt {
-        Statement::Insert(Insert {
-            on:
-                Some(OnInsert::OnConflict(OnConflict {
-                    conflict_target: Some(ConflictTarget::Columns(cols)),
-                    action,
-                })),
-            ..
+    match single_on_insert(stmt) {
+        OnInsert::OnConflict(OnConflict {
+            conflict_target: Some(ConflictTarget::Columns(cols)),
+            action,
         }) => {
             assert_eq!(vec![Ident::from("did"), Ident::from("area"),], cols);
             assert_eq!(
@@ -1725,14 +2161,10 @@ fn parse_pg_on_conflict() {
     VALUES (5, 'Gizmo Transglobal'), (6, 'Associated Computing, Inc') \
     ON CONFLICT DO NOTHING",
     );
-    match stmt {
-        Statement::Insert(Insert {
-            on:
-                Some(OnInsert::OnConflict(OnConflict {
-                    conflict_target: None,
-                    action,
-                })),
-            ..
+    match single_on_insert(stmt) {
+        OnInsert::OnConflict(OnConflict {
+            conflict_target: None,
+            action,
         }) => {
             assert_eq!(OnConflictAction::DoNothing, action);
         }
@@ -1745,14 +2177,10 @@ fn parse_pg_on_conflict() {
         ON CONFLICT(did) \
         DO UPDATE SET dname = $1 WHERE dsize > $2",
     );
-    match stmt {
-        Statement::Insert(Insert {
-            on:
-                Some(OnInsert::OnConflict(OnConflict {
-                    conflict_target: Some(ConflictTarget::Columns(cols)),
-                    action,
-                })),
-            ..
+    match single_on_insert(stmt) {
+        OnInsert::OnConflict(OnConflict {
+            conflict_target: Some(ConflictTarget::Columns(cols)),
+            action,
         }) => {
             assert_eq!(vec![Ident::from("did")], cols);
             assert_eq!(
@@ -1763,7 +2191,7 @@ fn parse_pg_on_conflict() {
                     },],
                     selection: Some(Expr::BinaryOp {
                         left: Box::new(Expr::Identifier(Ident {
-                            value: "dsize".to_string(),
+                            value: "dsize".into(),
                             quote_style: None
                         })),
                         op: BinaryOperator::Gt,
@@ -1782,14 +2210,10 @@ fn parse_pg_on_conflict() {
         ON CONFLICT ON CONSTRAINT distributors_did_pkey \
         DO UPDATE SET dname = $1 WHERE dsize > $2",
     );
-    match stmt {
-        Statement::Insert(Insert {
-            on:
-                Some(OnInsert::OnConflict(OnConflict {
-                    conflict_target: Some(ConflictTarget::OnConstraint(cname)),
-                    action,
-                })),
-            ..
+    match single_on_insert(stmt) {
+        OnInsert::OnConflict(OnConflict {
+            conflict_target: Some(ConflictTarget::OnConstraint(cname)),
+            action,
         }) => {
             assert_eq!(vec![Ident::from("distributors_did_pkey")], cname.0);
             assert_eq!(
@@ -1800,7 +2224,7 @@ fn parse_pg_on_conflict() {
                     },],
                     selection: Some(Expr::BinaryOp {
                         left: Box::new(Expr::Identifier(Ident {
-                            value: "dsize".to_string(),
+                            value: "dsize".into(),
                             quote_style: None
                         })),
                         op: BinaryOperator::Gt,
@@ -1814,6 +2238,54 @@ fn parse_pg_on_conflict() {
     };
 }
 
+#[test]
+fn parse_pg_on_conflict_index_inference() {
+    let stmt = pg_and_generic().verified_stmt(
+        "INSERT INTO users (email, name) VALUES ('a@b.c', 'x') \
+        ON CONFLICT(lower(email)) WHERE active \
+        DO UPDATE SET name = EXCLUDED.name WHERE users.name <> EXCLUDED.name",
+    );
+    match single_on_insert(stmt) {
+        OnInsert::OnConflict(OnConflict {
+            conflict_target: Some(ConflictTarget::IndexInference { exprs, predicate }),
+            action: OnConflictAction::DoUpdate(DoUpdate { selection, .. }),
+        }) => {
+            assert_eq_vec(&["lower(email)"], &exprs);
+            assert_eq!(Some(Expr::Identifier(Ident::new("active"))), predicate);
+            assert_eq!(
+                "users.name <> EXCLUDED.name",
+                selection.unwrap().to_string()
+            );
+        }
+        _ => unreachable!(),
+    };
+
+    // A plain column list with a partial index predicate
+    match single_on_insert(pg_and_generic().verified_stmt(
+        "INSERT INTO t VALUES (1) ON CONFLICT(a, (b + 1)) WHERE b IS NULL DO NOTHING",
+    )) {
+        OnInsert::OnConflict(OnConflict {
+            conflict_target: Some(ConflictTarget::IndexInference { exprs, predicate }),
+            action: OnConflictAction::DoNothing,
+        }) => {
+            assert_eq_vec(&["a", "(b + 1)"], &exprs);
+            assert_eq!("b IS NULL", predicate.unwrap().to_string());
+        }
+        _ => unreachable!(),
+    };
+
+    // Without expressions or predicate the target is still a column list
+    match single_on_insert(
+        pg_and_generic().verified_stmt("INSERT INTO t VALUES (1) ON CONFLICT(a, \"b\") DO NOTHING"),
+    ) {
+        OnInsert::OnConflict(OnConflict {
+            conflict_target: Some(ConflictTarget::Columns(cols)),
+            ..
+        }) => assert_eq!(vec![Ident::new("a"), Ident::with_quote('"', "b")], cols),
+        _ => unreachable!(),
+    };
+}
+
 #[test]
 fn parse_pg_returning() {
     let stmt = pg_and_generic().verified_stmt(
@@ -2103,14 +2575,14 @@ fn parse_array_index_expr() {
                 }),
                 subscript: Box::new(Subscript::Index {
                     index: Expr::Identifier(Ident {
-                        value: "baz".to_string(),
+                        value: "baz".into(),
                         quote_style: Some('"')
                     })
                 })
             }),
             subscript: Box::new(Subscript::Index {
                 index: Expr::Identifier(Ident {
-                    value: "fooz".to_string(),
+                    value: "fooz".into(),
                     quote_style: Some('"')
                 })
             })
@@ -2494,83 +2966,106 @@ fn parse_array_subquery_expr() {
     let sql = "SELECT ARRAY(SELECT 1 UNION SELECT 2)";
     let select = pg().verified_only_select(sql);
     assert_eq!(
-        &Expr::Function(Function {
-            name: ObjectName(vec![Ident::new("ARRAY")]),
-            parameters: FunctionArguments::None,
-            args: FunctionArguments::Subquery(Box::new(Query {
-                with: None,
-                body: Box::new(SetExpr::SetOperation {
-                    op: SetOperator::Union,
-                    set_quantifier: SetQuantifier::None,
-                    left: Box::new(SetExpr::Select(Box::new(Select {
-                        distinct: None,
-                        top: None,
-                        projection: vec![SelectItem::UnnamedExpr(Expr::Value(number("1")))],
-                        into: None,
-                        from: vec![],
-                        lateral_views: vec![],
-                        prewhere: None,
-                        selection: None,
-                        group_by: GroupByExpr::Expressions(vec![], vec![]),
-                        cluster_by: vec![],
-                        distribute_by: vec![],
-                        sort_by: vec![],
-                        having: None,
-                        named_window: vec![],
-                        qualify: None,
-                        window_before_qualify: false,
-                        value_table_mode: None,
-                        connect_by: None,
-                    }))),
-                    right: Box::new(SetExpr::Select(Box::new(Select {
-                        distinct: None,
-                        top: None,
-                        projection: vec![SelectItem::UnnamedExpr(Expr::Value(number("2")))],
-                        into: None,
-                        from: vec![],
-                        lateral_views: vec![],
-                        prewhere: None,
-                        selection: None,
-                        group_by: GroupByExpr::Expressions(vec![], vec![]),
-                        cluster_by: vec![],
-                        distribute_by: vec![],
-                        sort_by: vec![],
-                        having: None,
-                        named_window: vec![],
-                        qualify: None,
-                        window_before_qualify: false,
-                        value_table_mode: None,
-                        connect_by: None,
-                    }))),
-                }),
-                order_by: None,
-                limit: None,
-                limit_by: vec![],
-                offset: None,
-                fetch: None,
-                locks: vec![],
-                for_clause: None,
-                settings: None,
-                format_clause: None,
-            })),
-            filter: None,
-            null_treatment: None,
-            over: None,
-            within_group: vec![]
-        }),
-        expr_from_projection(only(&select.projection)),
-    );
-}
-
-#[test]
-fn test_transaction_statement() {
-    let statement = pg().verified_stmt("SET TRANSACTION SNAPSHOT '000003A1-1'");
-    assert_eq!(
-        statement,
-        Statement::SetTransaction {
-            modes: vec![],
-            snapshot: Some(Value::SingleQuotedString(String::from("000003A1-1"))),
-            session: false
+        &Expr::ArraySubquery(Arc::new(Query {
+            with: None,
+            body: Box::new(SetExpr::SetOperation {
+                op: SetOperator::Union,
+                set_quantifier: SetQuantifier::None,
+                left: Box::new(SetExpr::Select(Box::new(Select {
+                    distinct: None,
+                    top: None,
+                    projection: vec![SelectItem::UnnamedExpr(Expr::Value(number("1")))],
+                    into: None,
+                    from: vec![],
+                    lateral_views: vec![],
+                    prewhere: None,
+                    selection: None,
+                    group_by: GroupByExpr::Expressions(vec![], vec![]),
+                    cluster_by: vec![],
+                    distribute_by: vec![],
+                    sort_by: vec![],
+                    having: None,
+                    named_window: vec![],
+                    qualify: None,
+                    window_before_qualify: false,
+                    value_table_mode: None,
+                    connect_by: None,
+                }))),
+                right: Box::new(SetExpr::Select(Box::new(Select {
+                    distinct: None,
+                    top: None,
+                    projection: vec![SelectItem::UnnamedExpr(Expr::Value(number("2")))],
+                    into: None,
+                    from: vec![],
+                    lateral_views: vec![],
+                    prewhere: None,
+                    selection: None,
+                    group_by: GroupByExpr::Expressions(vec![], vec![]),
+                    cluster_by: vec![],
+                    distribute_by: vec![],
+                    sort_by: vec![],
+                    having: None,
+                    named_window: vec![],
+                    qualify: None,
+                    window_before_qualify: false,
+                    value_table_mode: None,
+                    connect_by: None,
+                }))),
+            }),
+            order_by: None,
+            limit: None,
+            limit_by: vec![],
+            offset: None,
+            fetch: None,
+            locks: vec![],
+            for_clause: None,
+            settings: None,
+            format_clause: None,
+            option_clause: None,
+        })),
+        expr_from_projection(only(&select.projection)),
+    );
+    pg().verified_only_select("SELECT ARRAY(SELECT id FROM t ORDER BY id)[1]");
+}
+
+#[test]
+fn parse_row_constructor() {
+    let select = pg().verified_only_select("SELECT ROW(1, 'a')::mytype, ROW(), (1, 'a')");
+    assert_eq!(
+        expr_from_projection(&select.projection[0]),
+        &Expr::Cast {
+            kind: CastKind::DoubleColon,
+            expr: Box::new(Expr::Row(vec![
+                Expr::Value(number("1")),
+                Expr::Value(Value::SingleQuotedString("a".to_string())),
+            ])),
+            data_type: DataType::Custom(ObjectName(vec![Ident::new("mytype")]), vec![]),
+            format: None,
+        }
+    );
+    assert_eq!(
+        expr_from_projection(&select.projection[1]),
+        &Expr::Row(vec![])
+    );
+    assert_eq!(
+        expr_from_projection(&select.projection[2]),
+        &Expr::Tuple(vec![
+            Expr::Value(number("1")),
+            Expr::Value(Value::SingleQuotedString("a".to_string())),
+        ])
+    );
+    pg().verified_stmt("SELECT * FROM t WHERE ROW(a, b) = ROW(1, 2)");
+}
+
+#[test]
+fn test_transaction_statement() {
+    let statement = pg().verified_stmt("SET TRANSACTION SNAPSHOT '000003A1-1'");
+    assert_eq!(
+        statement,
+        Statement::SetTransaction {
+            modes: vec![],
+            snapshot: Some(Value::SingleQuotedString(String::from("000003A1-1"))),
+            session: false
         }
     );
     let statement = pg().verified_stmt("SET SESSION CHARACTERISTICS AS TRANSACTION READ ONLY, READ WRITE, ISOLATION LEVEL SERIALIZABLE");
@@ -2878,6 +3373,7 @@ fn test_composite_value() {
                 null_treatment: None,
                 filter: None,
                 over: None,
+                safe: false,
                 within_group: vec![],
             }))))
         }),
@@ -3108,6 +3604,7 @@ fn parse_current_functions() {
             null_treatment: None,
             filter: None,
             over: None,
+            safe: false,
             within_group: vec![],
         }),
         expr_from_projection(&select.projection[0])
@@ -3120,6 +3617,7 @@ fn parse_current_functions() {
             null_treatment: None,
             filter: None,
             over: None,
+            safe: false,
             within_group: vec![],
         }),
         expr_from_projection(&select.projection[1])
@@ -3132,6 +3630,7 @@ fn parse_current_functions() {
             null_treatment: None,
             filter: None,
             over: None,
+            safe: false,
             within_group: vec![],
         }),
         expr_from_projection(&select.projection[2])
@@ -3144,6 +3643,7 @@ fn parse_current_functions() {
             null_treatment: None,
             filter: None,
             over: None,
+            safe: false,
             within_group: vec![],
         }),
         expr_from_projection(&select.projection[3])
@@ -3600,6 +4100,7 @@ fn parse_delimited_identifiers() {
             null_treatment: None,
             filter: None,
             over: None,
+            safe: false,
             within_group: vec![],
         }),
         expr_from_projection(&select.projection[1]),
@@ -3632,6 +4133,70 @@ fn parse_update_has_keyword() {
     );
 }
 
+#[test]
+fn parse_update_element_assignment() {
+    let sql = "UPDATE t SET arr[2] = 5, arr[:3] = '{1}', t.arr[2:] = NULL, j['a'] = '1', c[1].f = 2, c.f = 3";
+    let Statement::Update { assignments, .. } = pg_and_generic().verified_stmt(sql) else {
+        unreachable!()
+    };
+    assert_eq!(
+        assignments[0].target,
+        AssignmentTarget::Element(Expr::Subscript {
+            expr: Box::new(Expr::Identifier(Ident::new("arr"))),
+            subscript: Box::new(Subscript::Index {
+                index: Expr::Value(number("2")),
+            }),
+        })
+    );
+    assert_eq!(
+        assignments[1].target,
+        AssignmentTarget::Element(Expr::Subscript {
+            expr: Box::new(Expr::Identifier(Ident::new("arr"))),
+            subscript: Box::new(Subscript::Slice {
+                lower_bound: None,
+                upper_bound: Some(Expr::Value(number("3"))),
+                stride: None,
+            }),
+        })
+    );
+    assert_eq!(
+        assignments[2].target,
+        AssignmentTarget::Element(Expr::Subscript {
+            expr: Box::new(Expr::CompoundIdentifier(vec![
+                Ident::new("t"),
+                Ident::new("arr")
+            ])),
+            subscript: Box::new(Subscript::Slice {
+                lower_bound: Some(Expr::Value(number("2"))),
+                upper_bound: None,
+                stride: None,
+            }),
+        })
+    );
+    assert_eq!(
+        assignments[3].target,
+        AssignmentTarget::Element(Expr::Subscript {
+            expr: Box::new(Expr::Identifier(Ident::new("j"))),
+            subscript: Box::new(Subscript::Index {
+                index: Expr::Value(Value::SingleQuotedString("a".to_string())),
+            }),
+        })
+    );
+    assert!(matches!(
+        &assignments[4].target,
+        AssignmentTarget::Element(Expr::CompositeAccess { key, .. }) if key == &Ident::new("f")
+    ));
+    // field access without a subscript is a qualified column name
+    assert_eq!(
+        assignments[5].target,
+        AssignmentTarget::ColumnName(ObjectName(vec![Ident::new("c"), Ident::new("f")]))
+    );
+
+    pg().verified_stmt(
+        "INSERT INTO t (a) VALUES (1) ON CONFLICT(a) DO UPDATE SET arr[1] = EXCLUDED.a",
+    );
+}
+
 #[test]
 fn parse_update_in_with_subquery() {
     pg_and_generic().verified_stmt(r#"WITH "result" AS (UPDATE "Hero" SET "name" = 'Captain America', "number_of_movies" = "number_of_movies" + 1 WHERE "secret_identity" = 'Sam Wilson' RETURNING "id", "name", "secret_identity", "number_of_movies") SELECT * FROM "result""#);
@@ -3655,6 +4220,13 @@ fn parse_create_function() {
             behavior: Some(FunctionBehavior::Immutable),
             called_on_null: Some(FunctionCalledOnNull::Strict),
             parallel: Some(FunctionParallel::Safe),
+            window: false,
+            security: None,
+            leakproof: None,
+            cost: None,
+            rows: None,
+            support: None,
+            set_params: vec![],
             function_body: Some(CreateFunctionBody::AsBeforeOptions(Expr::Value(
                 Value::SingleQuotedString("select $1 + $2;".into())
             ))),
@@ -3663,6 +4235,10 @@ fn parse_create_function() {
             determinism_specifier: None,
             options: None,
             remote_connection: None,
+            runtime_version: None,
+            packages: None,
+            imports: None,
+            handler: None,
         }
     );
 }
@@ -3675,6 +4251,221 @@ fn parse_create_function_detailed() {
     pg_and_generic().verified_stmt("CREATE OR REPLACE FUNCTION add(a INTEGER, IN b INTEGER = 1) RETURNS INTEGER LANGUAGE SQL STABLE CALLED ON NULL INPUT PARALLEL UNSAFE RETURN a + b");
     pg_and_generic().verified_stmt(r#"CREATE OR REPLACE FUNCTION increment(i INTEGER) RETURNS INTEGER LANGUAGE plpgsql AS $$ BEGIN RETURN i + 1; END; $$"#);
 }
+
+#[test]
+fn parse_create_function_pg_dump_attributes() {
+    let sql = "CREATE FUNCTION public.f(a INTEGER) RETURNS INTEGER LANGUAGE plpgsql WINDOW IMMUTABLE STRICT \
+        SECURITY DEFINER LEAKPROOF PARALLEL SAFE COST 10 ROWS 1000 SUPPORT public.f_support \
+        SET search_path TO 'public', 'pg_temp' SET plpgsql.extra_warnings FROM CURRENT \
+        AS $$ BEGIN RETURN a; END $$";
+    match pg_and_generic().verified_stmt(sql) {
+        Statement::CreateFunction {
+            window,
+            security,
+            leakproof,
+            cost,
+            rows,
+            support,
+            set_params,
+            ..
+        } => {
+            assert!(window);
+            assert_eq!(security, Some(FunctionSecurity::Definer));
+            assert_eq!(leakproof, Some(true));
+            assert_eq!(cost.unwrap().to_string(), "10");
+            assert_eq!(rows.unwrap().to_string(), "1000");
+            assert_eq!(support.unwrap().to_string(), "public.f_support");
+            assert_eq!(
+                set_params,
+                vec![
+                    FunctionDefinitionSetParam {
+                        name: ObjectName(vec![Ident::new("search_path")]),
+                        value: FunctionSetValue::Values(vec![
+                            Expr::Value(Value::SingleQuotedString("public".to_string())),
+                            Expr::Value(Value::SingleQuotedString("pg_temp".to_string())),
+                        ]),
+                    },
+                    FunctionDefinitionSetParam {
+                        name: ObjectName(vec![Ident::new("plpgsql"), Ident::new("extra_warnings")]),
+                        value: FunctionSetValue::FromCurrent,
+                    },
+                ]
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    pg_and_generic().one_statement_parses_to(
+        "CREATE FUNCTION f(a INTEGER) RETURNS INTEGER EXTERNAL SECURITY INVOKER NOT LEAKPROOF SET work_mem = '64MB' AS 'select a'",
+        "CREATE FUNCTION f(a INTEGER) RETURNS INTEGER SECURITY INVOKER NOT LEAKPROOF SET work_mem TO '64MB' AS 'select a'",
+    );
+
+    assert_eq!(
+        pg().parse_sql_statements(
+            "CREATE FUNCTION f() RETURNS INTEGER COST 1 COST 2 AS 'select 1'"
+        )
+        .unwrap_err(),
+        ParserError::ParserError("COST specified more than once".to_string())
+    );
+}
+
+#[test]
+fn parse_create_function_plpgsql_body() {
+    let dialects = TestedDialects {
+        dialects: vec![Box::new(PostgreSqlDialect {}), Box::new(GenericDialect {})],
+        options: Some(ParserOptions::new().with_parse_script_bodies(true)),
+    };
+    let sql = "CREATE FUNCTION safe_div(a INTEGER, b INTEGER) RETURNS INTEGER AS $$ \
+        DECLARE result INTEGER := 0; total CONSTANT INTEGER NOT NULL DEFAULT 10; \
+        BEGIN \
+            IF b = 0 THEN RAISE NOTICE 'division by %', b; RETURN NULL; \
+            ELSIF b < 0 THEN result := -a / b; \
+            ELSE result = a / b; \
+            END IF; \
+            LOOP total := total - 1; EXIT WHEN total = 0; END LOOP; \
+            BEGIN UPDATE t SET x = result; END; \
+            RETURN result; \
+        EXCEPTION WHEN division_by_zero OR numeric_value_out_of_range THEN RETURN -1; \
+        END; $$ LANGUAGE plpgsql";
+    let canonical =
+        "CREATE FUNCTION safe_div(a INTEGER, b INTEGER) RETURNS INTEGER LANGUAGE plpgsql AS $$ \
+        DECLARE result INTEGER DEFAULT 0; total CONSTANT INTEGER NOT NULL DEFAULT 10; \
+        BEGIN \
+        IF b = 0 THEN RAISE NOTICE 'division by %', b; RETURN NULL; \
+        ELSEIF b < 0 THEN result := -a / b; \
+        ELSE result := a / b; \
+        END IF; \
+        LOOP total := total - 1; EXIT WHEN total = 0; END LOOP; \
+        BEGIN UPDATE t SET x = result; END; \
+        RETURN result; \
+        EXCEPTION WHEN division_by_zero OR numeric_value_out_of_range THEN RETURN -1; \
+        END $$";
+    match dialects.one_statement_parses_to(sql, canonical) {
+        Statement::CreateFunction {
+            function_body: Some(CreateFunctionBody::AsScript(script)),
+            ..
+        } => {
+            assert_eq!(script.declarations.len(), 2);
+            assert_eq!(script.declarations[1].name, Ident::new("total"));
+            assert!(script.declarations[1].constant);
+            assert_eq!(script.statements.len(), 4);
+            match &script.statements[0] {
+                script::ScriptStatement::If {
+                    branches,
+                    else_statements,
+                } => {
+                    assert_eq!(branches.len(), 2);
+                    assert_eq!(
+                        branches[0].statements[0],
+                        script::ScriptStatement::Raise {
+                            level: Some(Ident::new("NOTICE")),
+                            arguments: vec![
+                                Expr::Value(Value::SingleQuotedString("division by %".to_string())),
+                                Expr::Identifier(Ident::new("b")),
+                            ],
+                        }
+                    );
+                    assert_eq!(
+                        else_statements.as_ref().unwrap()[0],
+                        script::ScriptStatement::Assignment {
+                            is_let: false,
+                            name: ObjectName(vec![Ident::new("result")]),
+                            value: Expr::BinaryOp {
+                                left: Box::new(Expr::Identifier(Ident::new("a"))),
+                                op: BinaryOperator::Divide,
+                                right: Box::new(Expr::Identifier(Ident::new("b"))),
+                            },
+                        }
+                    );
+                }
+                _ => unreachable!(),
+            }
+            assert_eq!(
+                script.exception_handlers[0].conditions,
+                vec![
+                    Ident::new("division_by_zero"),
+                    Ident::new("numeric_value_out_of_range")
+                ]
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    dialects.verified_stmt(
+        "CREATE FUNCTION f(a INTEGER) RETURNS INTEGER LANGUAGE plpgsql AS $$ BEGIN RETURN QUERY SELECT a FROM t; END $$",
+    );
+
+    // Without the option, or for other languages, the body is kept as a string
+    pg().verified_stmt(
+        "CREATE FUNCTION f(a INTEGER) RETURNS INTEGER LANGUAGE plpgsql AS $$ BEGIN RETURN 1; END $$",
+    );
+    dialects.verified_stmt(
+        "CREATE FUNCTION f(a INTEGER) RETURNS INTEGER LANGUAGE sql AS $$ SELECT 1 $$",
+    );
+
+    assert_eq!(
+        dialects
+            .parse_sql_statements(
+                "CREATE FUNCTION f(a INTEGER) RETURNS INTEGER LANGUAGE plpgsql AS $$ BEGIN RETURN 1 END $$"
+            )
+            .unwrap_err(),
+        // the location is relative to the start of the function body
+        ParserError::ParserError("Expected: ;, found: END at Line: 1, Column: 17".to_string())
+    );
+}
+
+#[test]
+fn parse_do() {
+    let sql = "DO LANGUAGE plpgsql $$ BEGIN RAISE NOTICE 'hi'; END $$";
+    assert_eq!(
+        pg_and_generic().verified_stmt(sql),
+        Statement::Do {
+            language: Some(Ident::new("plpgsql")),
+            body: DoBody::Definition(Expr::Value(Value::DollarQuotedString(DollarQuotedString {
+                value: " BEGIN RAISE NOTICE 'hi'; END ".to_string(),
+                tag: None,
+            }))),
+        }
+    );
+
+    pg_and_generic().verified_stmt("DO $body$ BEGIN NULL; END $body$");
+    pg_and_generic().verified_stmt("DO 'BEGIN NULL; END'");
+    pg_and_generic().one_statement_parses_to(
+        "DO $$ SELECT 1 $$ LANGUAGE sql",
+        "DO LANGUAGE sql $$ SELECT 1 $$",
+    );
+
+    let dialects = TestedDialects {
+        dialects: vec![Box::new(PostgreSqlDialect {}), Box::new(GenericDialect {})],
+        options: Some(ParserOptions::new().with_parse_script_bodies(true)),
+    };
+    match dialects.verified_stmt("DO $$ BEGIN RAISE NOTICE 'hi'; END $$") {
+        Statement::Do {
+            language: None,
+            body: DoBody::Script(script),
+        } => {
+            assert_eq!(script.statements.len(), 1);
+        }
+        _ => unreachable!(),
+    }
+    // bodies in other languages are kept as strings
+    match dialects.verified_stmt("DO LANGUAGE plperl $$ print 1 $$") {
+        Statement::Do {
+            body: DoBody::Definition(_),
+            ..
+        } => {}
+        _ => unreachable!(),
+    }
+
+    assert!(pg().parse_sql_statements("DO").is_err());
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn parse_do_serde_round_trip() {
+    assert_serde_round_trip!(pg_and_generic().verified_stmt("DO $$ BEGIN NULL; END $$"));
+}
+
 #[test]
 fn parse_incorrect_create_function_parallel() {
     let sql = "CREATE FUNCTION add(INTEGER, INTEGER) RETURNS INTEGER LANGUAGE SQL PARALLEL BLAH AS 'select $1 + $2;'";
@@ -3690,7 +4481,7 @@ fn parse_drop_function() {
             if_exists: true,
             func_desc: vec![FunctionDesc {
                 name: ObjectName(vec![Ident {
-                    value: "test_func".to_string(),
+                    value: "test_func".into(),
                     quote_style: None
                 }]),
                 args: None
@@ -3706,7 +4497,7 @@ fn parse_drop_function() {
             if_exists: true,
             func_desc: vec![FunctionDesc {
                 name: ObjectName(vec![Ident {
-                    value: "test_func".to_string(),
+                    value: "test_func".into(),
                     quote_style: None
                 }]),
                 args: Some(vec![
@@ -3731,7 +4522,7 @@ fn parse_drop_function() {
             func_desc: vec![
                 FunctionDesc {
                     name: ObjectName(vec![Ident {
-                        value: "test_func1".to_string(),
+                        value: "test_func1".into(),
                         quote_style: None
                     }]),
                     args: Some(vec![
@@ -3749,7 +4540,7 @@ fn parse_drop_function() {
                 },
                 FunctionDesc {
                     name: ObjectName(vec![Ident {
-                        value: "test_func2".to_string(),
+                        value: "test_func2".into(),
                         quote_style: None
                     }]),
                     args: Some(vec![
@@ -3780,7 +4571,7 @@ fn parse_drop_procedure() {
             if_exists: true,
             proc_desc: vec![FunctionDesc {
                 name: ObjectName(vec![Ident {
-                    value: "test_proc".to_string(),
+                    value: "test_proc".into(),
                     quote_style: None
                 }]),
                 args: None
@@ -3796,7 +4587,7 @@ fn parse_drop_procedure() {
             if_exists: true,
             proc_desc: vec![FunctionDesc {
                 name: ObjectName(vec![Ident {
-                    value: "test_proc".to_string(),
+                    value: "test_proc".into(),
                     quote_style: None
                 }]),
                 args: Some(vec![
@@ -3821,7 +4612,7 @@ fn parse_drop_procedure() {
             proc_desc: vec![
                 FunctionDesc {
                     name: ObjectName(vec![Ident {
-                        value: "test_proc1".to_string(),
+                        value: "test_proc1".into(),
                         quote_style: None
                     }]),
                     args: Some(vec![
@@ -3839,7 +4630,7 @@ fn parse_drop_procedure() {
                 },
                 FunctionDesc {
                     name: ObjectName(vec![Ident {
-                        value: "test_proc2".to_string(),
+                        value: "test_proc2".into(),
                         quote_style: None
                     }]),
                     args: Some(vec![
@@ -4115,11 +4906,15 @@ fn parse_create_table_with_alias() {
             name,
             columns,
             constraints,
-            with_options: _with_options,
             if_not_exists: false,
             external: false,
-            file_format: None,
-            location: None,
+            storage:
+                CreateTableStorage {
+                    with_options: _with_options,
+                    file_format: None,
+                    location: None,
+                    ..
+                },
             ..
         }) => {
             assert_eq!("public.datatype_aliases", name.to_string());
@@ -4172,7 +4967,7 @@ fn parse_create_table_with_alias() {
 
 #[test]
 fn parse_create_table_with_partition_by() {
-    let sql = "CREATE TABLE t1 (a INT, b TEXT) PARTITION BY RANGE(a)";
+    let sql = "CREATE TABLE t1 (a INT, b TEXT) PARTITION BY RANGE (a)";
     match pg_and_generic().verified_stmt(sql) {
         Statement::CreateTable(create_table) => {
             assert_eq!("t1", create_table.name.to_string());
@@ -4193,27 +4988,64 @@ fn parse_create_table_with_partition_by() {
                 ],
                 create_table.columns
             );
-            match *create_table.partition_by.unwrap() {
-                Expr::Function(f) => {
-                    assert_eq!("RANGE", f.name.to_string());
-                    assert_eq!(
-                        FunctionArguments::List(FunctionArgumentList {
-                            duplicate_treatment: None,
-                            clauses: vec![],
-                            args: vec![FunctionArg::Unnamed(FunctionArgExpr::Expr(
-                                Expr::Identifier(Ident::new("a"))
-                            ))],
-                        }),
-                        f.args
-                    );
-                }
-                _ => unreachable!(),
-            }
+            assert_eq!(
+                create_table.partitioning.partition_spec,
+                Some(PartitionSpec {
+                    strategy: PartitionStrategy::Range,
+                    columns: vec![Expr::Identifier(Ident::new("a"))],
+                })
+            );
+            assert_eq!(create_table.partitioning.partition_by, None);
         }
         _ => unreachable!(),
     }
 }
 
+#[test]
+fn parse_create_table_partition_of() {
+    match pg_and_generic()
+        .verified_stmt("CREATE TABLE m PARTITION OF parent FOR VALUES FROM (1) TO (10)")
+    {
+        Statement::CreateTable(create_table) => {
+            assert_eq!(
+                create_table.partitioning.partition_of,
+                Some(ObjectName(vec![Ident::new("parent")]))
+            );
+            assert_eq!(
+                create_table.partitioning.for_values,
+                Some(ForValues::From {
+                    from: vec![PartitionBoundValue::Expr(Expr::Value(number("1")))],
+                    to: vec![PartitionBoundValue::Expr(Expr::Value(number("10")))],
+                })
+            );
+            assert!(create_table.columns.is_empty());
+        }
+        _ => unreachable!(),
+    }
+
+    pg_and_generic().verified_stmt(
+        "CREATE TABLE m PARTITION OF parent FOR VALUES FROM (MINVALUE, 1) TO ('2024-01-01', MAXVALUE)",
+    );
+    pg_and_generic().verified_stmt("CREATE TABLE m PARTITION OF parent FOR VALUES IN ('a', 'b')");
+    pg_and_generic().verified_stmt(
+        "CREATE TABLE m PARTITION OF parent FOR VALUES WITH (MODULUS 4, REMAINDER 3)",
+    );
+    pg_and_generic().verified_stmt("CREATE TABLE m PARTITION OF parent DEFAULT");
+    pg_and_generic().verified_stmt(
+        "CREATE TABLE IF NOT EXISTS m PARTITION OF parent (CONSTRAINT positive CHECK (a > 0)) FOR VALUES IN (1) PARTITION BY LIST ((lower(b)), c)",
+    );
+    pg_and_generic()
+        .verified_stmt("CREATE TABLE t (a INT) PARTITION BY HASH (a) WITH (fillfactor = 70)");
+
+    assert_eq!(
+        pg().parse_sql_statements("CREATE TABLE m PARTITION OF parent")
+            .unwrap_err(),
+        ParserError::ParserError(
+            "Expected: FOR VALUES or DEFAULT after PARTITION OF, found: EOF".to_string()
+        )
+    );
+}
+
 #[test]
 fn parse_join_constraint_unnest_alias() {
     assert_eq!(
@@ -4264,20 +5096,20 @@ fn test_simple_postgres_insert_with_alias() {
             ignore: false,
             into: true,
             table_name: ObjectName(vec![Ident {
-                value: "test_tables".to_string(),
+                value: "test_tables".into(),
                 quote_style: None
             }]),
             table_alias: Some(Ident {
-                value: "test_table".to_string(),
+                value: "test_table".into(),
                 quote_style: None
             }),
             columns: vec![
                 Ident {
-                    value: "id".to_string(),
+                    value: "id".into(),
                     quote_style: None
                 },
                 Ident {
-                    value: "a".to_string(),
+                    value: "a".into(),
                     quote_style: None
                 }
             ],
@@ -4287,10 +5119,7 @@ fn test_simple_postgres_insert_with_alias() {
                 body: Box::new(SetExpr::Values(Values {
                     explicit_row: false,
                     rows: vec![vec![
-                        Expr::Identifier(Ident {
-                            value: "DEFAULT".to_string(),
-                            quote_style: None
-                        }),
+                        Expr::Default,
                         Expr::Value(Value::Number("123".to_string(), false))
                     ]]
                 })),
@@ -4303,11 +5132,13 @@ fn test_simple_postgres_insert_with_alias() {
                 for_clause: None,
                 settings: None,
                 format_clause: None,
+                option_clause: None,
             })),
+            assignments: vec![],
             partitioned: None,
             after_columns: vec![],
             table: false,
-            on: None,
+            on: vec![],
             returning: None,
             replace_into: false,
             priority: None,
@@ -4330,20 +5161,20 @@ fn test_simple_postgres_insert_with_alias() {
             ignore: false,
             into: true,
             table_name: ObjectName(vec![Ident {
-                value: "test_tables".to_string(),
+                value: "test_tables".into(),
                 quote_style: None
             }]),
             table_alias: Some(Ident {
-                value: "test_table".to_string(),
+                value: "test_table".into(),
                 quote_style: None
             }),
             columns: vec![
                 Ident {
-                    value: "id".to_string(),
+                    value: "id".into(),
                     quote_style: None
                 },
                 Ident {
-                    value: "a".to_string(),
+                    value: "a".into(),
                     quote_style: None
                 }
             ],
@@ -4353,10 +5184,7 @@ fn test_simple_postgres_insert_with_alias() {
                 body: Box::new(SetExpr::Values(Values {
                     explicit_row: false,
                     rows: vec![vec![
-                        Expr::Identifier(Ident {
-                            value: "DEFAULT".to_string(),
-                            quote_style: None
-                        }),
+                        Expr::Default,
                         Expr::Value(Value::Number(
                             bigdecimal::BigDecimal::new(123.into(), 0),
                             false
@@ -4372,11 +5200,13 @@ fn test_simple_postgres_insert_with_alias() {
                 for_clause: None,
                 settings: None,
                 format_clause: None,
+                option_clause: None,
             })),
+            assignments: vec![],
             partitioned: None,
             after_columns: vec![],
             table: false,
-            on: None,
+            on: vec![],
             returning: None,
             replace_into: false,
             priority: None,
@@ -4398,20 +5228,20 @@ fn test_simple_insert_with_quoted_alias() {
             ignore: false,
             into: true,
             table_name: ObjectName(vec![Ident {
-                value: "test_tables".to_string(),
+                value: "test_tables".into(),
                 quote_style: None
             }]),
             table_alias: Some(Ident {
-                value: "Test_Table".to_string(),
+                value: "Test_Table".into(),
                 quote_style: Some('"')
             }),
             columns: vec![
                 Ident {
-                    value: "id".to_string(),
+                    value: "id".into(),
                     quote_style: None
                 },
                 Ident {
-                    value: "a".to_string(),
+                    value: "a".into(),
                     quote_style: None
                 }
             ],
@@ -4421,10 +5251,7 @@ fn test_simple_insert_with_quoted_alias() {
                 body: Box::new(SetExpr::Values(Values {
                     explicit_row: false,
                     rows: vec![vec![
-                        Expr::Identifier(Ident {
-                            value: "DEFAULT".to_string(),
-                            quote_style: None
-                        }),
+                        Expr::Default,
                         Expr::Value(Value::SingleQuotedString("0123".to_string()))
                     ]]
                 })),
@@ -4437,11 +5264,13 @@ fn test_simple_insert_with_quoted_alias() {
                 for_clause: None,
                 settings: None,
                 format_clause: None,
+                option_clause: None,
             })),
+            assignments: vec![],
             partitioned: None,
             after_columns: vec![],
             table: false,
-            on: None,
+            on: vec![],
             returning: None,
             replace_into: false,
             priority: None,
@@ -4488,7 +5317,7 @@ fn parse_at_time_zone() {
         left: Box::new(Expr::AtTimeZone {
             timestamp: Box::new(Expr::TypedString {
                 data_type: DataType::Timestamp(None, TimezoneInfo::None),
-                value: "2001-09-28 01:00".to_owned(),
+                value: "2001-09-28 01:00".into(),
             }),
             time_zone: Box::new(Expr::Cast {
                 kind: CastKind::DoubleColon,
@@ -4522,7 +5351,10 @@ fn parse_at_time_zone() {
 fn parse_create_table_with_options() {
     let sql = "CREATE TABLE t (c INT) WITH (foo = 'bar', a = 123)";
     match pg().verified_stmt(sql) {
-        Statement::CreateTable(CreateTable { with_options, .. }) => {
+        Statement::CreateTable(CreateTable {
+            storage: CreateTableStorage { with_options, .. },
+            ..
+        }) => {
             assert_eq!(
                 vec![
                     SqlOption::KeyValue {
@@ -4627,10 +5459,10 @@ fn parse_create_after_update_trigger_with_condition() {
         trigger_object: TriggerObject::Row,
         include_each: true,
         condition: Some(Expr::Nested(Box::new(Expr::BinaryOp {
-            left: Box::new(Expr::CompoundIdentifier(vec![
-                Ident::new("NEW"),
-                Ident::new("balance"),
-            ])),
+            left: Box::new(Expr::TriggerRowColumn {
+                row: TriggerRow::New,
+                column: Ident::new("balance"),
+            }),
             op: BinaryOperator::Gt,
             right: Box::new(Expr::Value(number("10000"))),
         }))),
@@ -4647,6 +5479,41 @@ fn parse_create_after_update_trigger_with_condition() {
     assert_eq!(pg().verified_stmt(sql), expected);
 }
 
+#[test]
+fn parse_trigger_row_references() {
+    let sql = "CREATE TRIGGER tr BEFORE UPDATE ON t FOR EACH ROW WHEN (OLD.a IS DISTINCT FROM NEW.a) EXECUTE FUNCTION f";
+    let Statement::CreateTrigger { condition, .. } = pg().verified_stmt(sql) else {
+        unreachable!()
+    };
+    let Some(Expr::Nested(condition)) = condition else {
+        unreachable!()
+    };
+    assert_eq!(
+        *condition,
+        Expr::IsDistinctFrom(
+            Box::new(Expr::TriggerRowColumn {
+                row: TriggerRow::Old,
+                column: Ident::new("a"),
+            }),
+            Box::new(Expr::TriggerRowColumn {
+                row: TriggerRow::New,
+                column: Ident::new("a"),
+            }),
+        )
+    );
+
+    pg().one_statement_parses_to(
+        "CREATE TRIGGER tr BEFORE INSERT ON t FOR EACH ROW WHEN (new.b = 1) EXECUTE FUNCTION f",
+        "CREATE TRIGGER tr BEFORE INSERT ON t FOR EACH ROW WHEN (NEW.b = 1) EXECUTE FUNCTION f",
+    );
+
+    // outside of a trigger, `new` is an ordinary table name
+    assert_eq!(
+        pg().verified_expr("new.a"),
+        Expr::CompoundIdentifier(vec![Ident::new("new"), Ident::new("a")])
+    );
+}
+
 #[test]
 fn parse_create_instead_of_delete_trigger() {
     let sql = "CREATE TRIGGER check_delete INSTEAD OF DELETE ON accounts FOR EACH ROW EXECUTE FUNCTION check_account_deletes";
@@ -4941,44 +5808,54 @@ fn parse_trigger_related_functions() {
                 },
             ],
             constraints: vec![],
-            hive_distribution: HiveDistributionStyle::NONE,
-            hive_formats: Some(HiveFormat {
-                row_format: None,
-                serde_properties: None,
-                storage: None,
-                location: None
-            }),
-            table_properties: vec![],
-            with_options: vec![],
-            file_format: None,
-            location: None,
             query: None,
-            without_rowid: false,
             like: None,
             clone: None,
-            engine: None,
             comment: None,
-            auto_increment_offset: None,
-            default_charset: None,
-            collation: None,
             on_commit: None,
-            on_cluster: None,
-            primary_key: None,
-            order_by: None,
-            partition_by: None,
-            cluster_by: None,
-            clustered_by: None,
-            options: None,
-            strict: false,
-            copy_grants: false,
-            enable_schema_evolution: None,
-            change_tracking: None,
-            data_retention_time_in_days: None,
-            max_data_extension_time_in_days: None,
-            default_ddl_collation: None,
-            with_aggregation_policy: None,
-            with_row_access_policy: None,
-            with_tags: None,
+            storage: CreateTableStorage {
+                hive_formats: Some(HiveFormat {
+                    row_format: None,
+                    serde_properties: None,
+                    storage: None,
+                    location: None
+                }),
+                table_properties: vec![],
+                with_options: vec![],
+                file_format: None,
+                location: None,
+                engine: None,
+                auto_increment_offset: None,
+                default_charset: None,
+                collation: None
+            },
+            partitioning: CreateTablePartitioning {
+                hive_distribution: HiveDistributionStyle::NONE,
+                primary_key: None,
+                order_by: None,
+                partition_of: None,
+                for_values: None,
+                partition_spec: None,
+                partition_by: None,
+                cluster_by: None,
+                clustered_by: None
+            },
+            dialect_options: CreateTableDialectOptions {
+                without_rowid: false,
+                on_cluster: None,
+                options: None,
+                strict: false,
+                copy_grants: false,
+                enable_schema_evolution: None,
+                change_tracking: None,
+                data_retention_time_in_days: None,
+                max_data_extension_time_in_days: None,
+                default_ddl_collation: None,
+                with_aggregation_policy: None,
+                with_row_access_policy: None,
+                with_tags: None,
+                unknown_options: vec![],
+            }
         }
     );
 
@@ -4998,7 +5875,7 @@ fn parse_trigger_related_functions() {
                     Expr::Value(
                         Value::DollarQuotedString(
                             DollarQuotedString {
-                                value: "\n        BEGIN\n            -- Check that empname and salary are given\n            IF NEW.empname IS NULL THEN\n                RAISE EXCEPTION 'empname cannot be null';\n            END IF;\n            IF NEW.salary IS NULL THEN\n                RAISE EXCEPTION '% cannot have null salary', NEW.empname;\n            END IF;\n\n            -- Who works for us when they must pay for it?\n            IF NEW.salary < 0 THEN\n                RAISE EXCEPTION '% cannot have a negative salary', NEW.empname;\n            END IF;\n\n            -- Remember who changed the payroll when\n            NEW.last_date := current_timestamp;\n            NEW.last_user := current_user;\n            RETURN NEW;\n        END;\n    ".to_owned(),
+                                value: "\n        BEGIN\n            -- Check that empname and salary are given\n            IF NEW.empname IS NULL THEN\n                RAISE EXCEPTION 'empname cannot be null';\n            END IF;\n            IF NEW.salary IS NULL THEN\n                RAISE EXCEPTION '% cannot have null salary', NEW.empname;\n            END IF;\n\n            -- Who works for us when they must pay for it?\n            IF NEW.salary < 0 THEN\n                RAISE EXCEPTION '% cannot have a negative salary', NEW.empname;\n            END IF;\n\n            -- Remember who changed the payroll when\n            NEW.last_date := current_timestamp;\n            NEW.last_user := current_user;\n            RETURN NEW;\n        END;\n    ".into(),
                                 tag: Some(
                                     "emp_stamp".to_owned(),
                                 ),
@@ -5010,11 +5887,22 @@ fn parse_trigger_related_functions() {
             behavior: None,
             called_on_null: None,
             parallel: None,
+            window: false,
+            security: None,
+            leakproof: None,
+            cost: None,
+            rows: None,
+            support: None,
+            set_params: vec![],
             using: None,
             language: Some(Ident::new("plpgsql")),
             determinism_specifier: None,
             options: None,
-            remote_connection: None
+            remote_connection: None,
+            runtime_version: None,
+            packages: None,
+            imports: None,
+            handler: None,
         }
     );
 
@@ -5085,7 +5973,7 @@ fn check_arrow_precedence(sql: &str, arrow_operator: BinaryOperator) {
         Expr::BinaryOp {
             left: Box::new(Expr::BinaryOp {
                 left: Box::new(Expr::Identifier(Ident {
-                    value: "foo".to_string(),
+                    value: "foo".into(),
                     quote_style: None,
                 })),
                 op: arrow_operator,
@@ -5115,7 +6003,7 @@ fn arrow_cast_precedence() {
         stmt,
         Expr::BinaryOp {
             left: Box::new(Expr::Identifier(Ident {
-                value: "foo".to_string(),
+                value: "foo".into(),
                 quote_style: None,
             })),
             op: BinaryOperator::Arrow,
@@ -5128,3 +6016,241 @@ fn arrow_cast_precedence() {
         }
     )
 }
+
+#[test]
+fn parse_create_materialized_view() {
+    let sql = "CREATE MATERIALIZED VIEW IF NOT EXISTS mv (a) USING heap WITH (fillfactor = 70) AS SELECT a FROM t WITH NO DATA";
+    match pg_and_generic().verified_stmt(sql) {
+        Statement::CreateView {
+            materialized,
+            if_not_exists,
+            name,
+            using,
+            with_data,
+            ..
+        } => {
+            assert!(materialized);
+            assert!(if_not_exists);
+            assert_eq!("mv", name.to_string());
+            assert_eq!(Some(Ident::new("heap")), using);
+            assert_eq!(Some(false), with_data);
+        }
+        _ => unreachable!(),
+    }
+
+    pg_and_generic().verified_stmt("CREATE MATERIALIZED VIEW mv AS SELECT 1 WITH DATA");
+    assert!(pg()
+        .parse_sql_statements("CREATE VIEW v AS SELECT 1 WITH DATA")
+        .is_err());
+}
+
+#[test]
+fn parse_refresh_materialized_view() {
+    assert_eq!(
+        pg_and_generic().verified_stmt("REFRESH MATERIALIZED VIEW CONCURRENTLY mv WITH NO DATA"),
+        Statement::RefreshMaterializedView {
+            concurrently: true,
+            name: ObjectName(vec![Ident::new("mv")]),
+            with_data: Some(false),
+        }
+    );
+    pg_and_generic().verified_stmt("REFRESH MATERIALIZED VIEW s.mv");
+    pg_and_generic().verified_stmt("REFRESH MATERIALIZED VIEW mv WITH DATA");
+    assert!(pg()
+        .parse_sql_statements("REFRESH MATERIALIZED VIEW")
+        .is_err());
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn parse_refresh_materialized_view_serde_round_trip() {
+    assert_serde_round_trip!(
+        pg_and_generic().verified_stmt("REFRESH MATERIALIZED VIEW CONCURRENTLY mv WITH NO DATA")
+    );
+}
+
+#[test]
+fn parse_explain_with_options() {
+    let sql = "EXPLAIN (ANALYZE, BUFFERS, FORMAT JSON, SETTINGS) SELECT * FROM t";
+    let Statement::Explain {
+        analyze, options, ..
+    } = pg_and_generic().verified_stmt(sql)
+    else {
+        unreachable!()
+    };
+    assert!(!analyze);
+    assert_eq!(
+        options,
+        Some(vec![
+            UtilityOption {
+                name: Ident::new("ANALYZE"),
+                arg: None,
+            },
+            UtilityOption {
+                name: Ident::new("BUFFERS"),
+                arg: None,
+            },
+            UtilityOption {
+                name: Ident::new("FORMAT"),
+                arg: Some(Expr::Identifier(Ident::new("JSON"))),
+            },
+            UtilityOption {
+                name: Ident::new("SETTINGS"),
+                arg: None,
+            },
+        ])
+    );
+
+    pg().verified_stmt("EXPLAIN (ANALYZE true, BUFFERS off, SERIALIZE TEXT) UPDATE t SET a = 1");
+    pg().verified_stmt("EXPLAIN (COSTS 0) WITH x AS (SELECT 1) SELECT * FROM x");
+    pg().verified_stmt("EXPLAIN ANALYZE VERBOSE SELECT 1");
+}
+
+#[test]
+fn parse_with_insert() {
+    let sql = "WITH cte AS (SELECT a, b FROM s) INSERT INTO t (a, b) SELECT a, b FROM cte";
+    let Statement::Query(query) = pg_and_generic().verified_stmt(sql) else {
+        unreachable!()
+    };
+    assert_eq!(
+        query.with.as_ref().unwrap().cte_tables[0].alias.name.value,
+        "cte"
+    );
+    let SetExpr::Insert(Statement::Insert(insert)) = query.body.as_ref() else {
+        unreachable!()
+    };
+    assert_eq!(insert.table_name.to_string(), "t");
+    assert_eq!(
+        insert.source.as_ref().unwrap().to_string(),
+        "SELECT a, b FROM cte"
+    );
+
+    pg().verified_stmt(
+        "WITH cte AS (SELECT 1) INSERT INTO t (a, b) VALUES (DEFAULT, 1) RETURNING a",
+    );
+}
+
+#[test]
+fn parse_table_alias_with_column_types() {
+    let select = pg_and_generic().verified_only_select(
+        "SELECT * FROM dblink('dbname=mydb', 'SELECT proname, prosrc FROM pg_proc') AS t1 (proname NAME, prosrc TEXT)",
+    );
+    let TableFactor::Table {
+        args: Some(_),
+        alias: Some(alias),
+        ..
+    } = &only(&select.from).relation
+    else {
+        panic!("Expected a table function, got {:?}", select.from);
+    };
+    assert_eq!(
+        alias.columns,
+        vec![
+            TableAliasColumnDef {
+                name: Ident::new("proname"),
+                data_type: Some(DataType::Custom(
+                    ObjectName(vec![Ident::new("NAME")]),
+                    vec![]
+                )),
+            },
+            TableAliasColumnDef {
+                name: Ident::new("prosrc"),
+                data_type: Some(DataType::Text),
+            },
+        ]
+    );
+
+    // types are optional for each column
+    pg_and_generic().verified_stmt("SELECT * FROM f() AS t (a, b VARCHAR(10))");
+    pg_and_generic()
+        .verified_stmt("SELECT x.a FROM json_to_record('{\"a\":1}') AS x (a INT, b TEXT)");
+}
+
+#[test]
+fn parse_rows_from() {
+    let sql = "SELECT * FROM ROWS FROM (f1() AS (a INT), f2() AS (b TEXT)) WITH ORDINALITY AS t (x, y, ord)";
+    let select = pg_and_generic().verified_only_select(sql);
+    assert_eq!(
+        only(&select.from).relation,
+        TableFactor::RowsFrom {
+            lateral: false,
+            functions: vec![
+                RowsFromFunction {
+                    expr: call("f1", []),
+                    columns: vec![TableAliasColumnDef {
+                        name: Ident::new("a"),
+                        data_type: Some(DataType::Int(None)),
+                    }],
+                },
+                RowsFromFunction {
+                    expr: call("f2", []),
+                    columns: vec![TableAliasColumnDef {
+                        name: Ident::new("b"),
+                        data_type: Some(DataType::Text),
+                    }],
+                },
+            ],
+            with_ordinality: true,
+            alias: Some(TableAlias {
+                name: Ident::new("t"),
+                columns: vec![
+                    TableAliasColumnDef::from_name("x"),
+                    TableAliasColumnDef::from_name("y"),
+                    TableAliasColumnDef::from_name("ord"),
+                ],
+            }),
+        }
+    );
+
+    pg_and_generic().verified_stmt(
+        "SELECT * FROM t, LATERAL ROWS FROM (generate_series(1, t.n), unnest(t.arr)) AS r",
+    );
+    pg_and_generic().verified_stmt("SELECT * FROM ROWS FROM (dblink('db', 'SELECT 1') AS (a INT))");
+    pg_and_generic().one_statement_parses_to(
+        "SELECT * FROM ROWS FROM (f()) r (a)",
+        "SELECT * FROM ROWS FROM (f()) AS r (a)",
+    );
+}
+
+#[test]
+fn parse_data_modifying_ctes() {
+    let sql = "WITH moved AS (DELETE FROM t RETURNING *) INSERT INTO archive SELECT * FROM moved";
+    let Statement::Query(query) = pg_and_generic().verified_stmt(sql) else {
+        unreachable!()
+    };
+    let cte = &query.with.as_ref().unwrap().cte_tables[0];
+    assert!(matches!(
+        cte.query.body.as_ref(),
+        SetExpr::Delete(Statement::Delete(Delete {
+            returning: Some(_),
+            ..
+        }))
+    ));
+    assert!(matches!(
+        query.body.as_ref(),
+        SetExpr::Insert(Statement::Insert(_))
+    ));
+
+    // a statement-level WITH on DELETE and UPDATE
+    let sql = "WITH c AS (SELECT 1) DELETE FROM t WHERE a IN (SELECT * FROM c)";
+    let Statement::Query(query) = pg_and_generic().verified_stmt(sql) else {
+        unreachable!()
+    };
+    assert!(query.with.is_some());
+    assert!(matches!(
+        query.body.as_ref(),
+        SetExpr::Delete(Statement::Delete(_))
+    ));
+    pg_and_generic().verified_stmt("WITH c AS (SELECT 1) UPDATE t SET a = 1 FROM c");
+
+    pg().verified_stmt(concat!(
+        "WITH d AS (DELETE FROM t WHERE a < 0 RETURNING a), ",
+        "u AS (UPDATE s SET n = n - 1 FROM d WHERE s.a = d.a RETURNING s.a), ",
+        "i AS (INSERT INTO log (a) SELECT a FROM u RETURNING a) ",
+        "SELECT count(*) FROM i"
+    ));
+
+    // RETURNING is only reserved as a table alias where it can end a DELETE
+    all_dialects_except(|d| d.is::<PostgreSqlDialect>() || d.is::<GenericDialect>())
+        .one_statement_parses_to("SELECT * FROM t returning", "SELECT * FROM t AS returning");
+}
@@ -30,7 +30,7 @@ fn test_square_brackets_over_db_schema_table_name() {
     assert_eq!(
         select.projection[0],
         SelectItem::UnnamedExpr(Expr::Identifier(Ident {
-            value: "col1".to_string(),
+            value: "col1".into(),
             quote_style: Some('[')
         })),
     );
@@ -40,11 +40,11 @@ fn test_square_brackets_over_db_schema_table_name() {
             relation: TableFactor::Table {
                 name: ObjectName(vec![
                     Ident {
-                        value: "test_schema".to_string(),
+                        value: "test_schema".into(),
                         quote_style: Some('[')
                     },
                     Ident {
-                        value: "test_table".to_string(),
+                        value: "test_table".into(),
                         quote_style: Some('[')
                     }
                 ]),
@@ -77,7 +77,7 @@ fn test_double_quotes_over_db_schema_table_name() {
     assert_eq!(
         select.projection[0],
         SelectItem::UnnamedExpr(Expr::Identifier(Ident {
-            value: "col1".to_string(),
+            value: "col1".into(),
             quote_style: Some('"')
         })),
     );
@@ -87,11 +87,11 @@ fn test_double_quotes_over_db_schema_table_name() {
             relation: TableFactor::Table {
                 name: ObjectName(vec![
                     Ident {
-                        value: "test_schema".to_string(),
+                        value: "test_schema".into(),
                         quote_style: Some('"')
                     },
                     Ident {
-                        value: "test_table".to_string(),
+                        value: "test_table".into(),
                         quote_style: Some('"')
                     }
                 ]),
@@ -153,6 +153,7 @@ fn parse_delimited_identifiers() {
             null_treatment: None,
             filter: None,
             over: None,
+            safe: false,
             within_group: vec![],
         }),
         expr_from_projection(&select.projection[1]),
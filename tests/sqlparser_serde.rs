@@ -0,0 +1,302 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+#![cfg(feature = "serde")]
+#![warn(clippy::all)]
+
+//! Test that the AST survives a round-trip through its serde
+//! representation, and that the JSON wire format stays stable.
+
+use std::collections::BTreeSet;
+
+use sqlparser::ast::Statement;
+use sqlparser::dialect::*;
+use sqlparser::parser::{Parser, ParserOptions};
+
+/// Declares [`statement_variant`] and [`STATEMENT_VARIANTS`] from one list
+/// of variant names.
+macro_rules! statement_variants {
+    ($($variant:ident),* $(,)?) => {
+        /// The name of the variant of `statement`.
+        ///
+        /// The match is exhaustive, so a new variant of [`Statement`] doesn't
+        /// compile until it is listed here, and then fails
+        /// `serde_round_trip_covers_all_statements` until [`statements`] has
+        /// a fixture for it.
+        fn statement_variant(statement: &Statement) -> &'static str {
+            match statement {
+                $(Statement::$variant { .. } => stringify!($variant),)*
+            }
+        }
+
+        /// The names of all variants of [`Statement`].
+        const STATEMENT_VARIANTS: &[&str] = &[$(stringify!($variant)),*];
+    };
+}
+
+statement_variants! {
+    Analyze, Truncate, Msck, Query, Insert, MultiTableInsert, CreateEvent, AlterEvent, Install,
+    Load, Directory, Call, Copy, CopyIntoSnowflake, Close, Update, Delete, CreateView, CreateTable,
+    CreateVirtualTable, CreateIndex, CreateRole, CreateSecret, CreatePolicy, AlterTable,
+    RenameTable, AlterIndex, AlterView, AlterRole, AlterPolicy, AttachDatabase,
+    AttachDuckDBDatabase, DetachDuckDBDatabase, Drop, DropFunction, DropProcedure, DropSecret,
+    DropPolicy, Declare, CreateExtension, AlterExtension, CreateCollation, AlterCollation,
+    CreateStatistics, AlterStatistics, GenericDdl, CreateLanguage, CreateRule, Notify, Fetch,
+    Flush, Discard, Do, Handler, RefreshMaterializedView, SetRole, SetVariable, SetTimeZone,
+    SetNames, SetNamesDefault, ShowFunctions, ShowVariable, ShowStatus, ShowVariables, ShowCreate,
+    ShowCreateUser, ShowColumns, ShowTables, ShowCollation, ShowObjects, Use, StartTransaction,
+    SetTransaction, Comment, Commit, Rollback, CreateSchema, CreateDatabase, CreateFunction,
+    CreateTrigger, DropTrigger, CreateProcedure, CreateMacro, CreateModel, CreateStage, CreateTask,
+    AlterTask, Assert, If, While, Block, Return, Grant, Revoke, GrantRole, RevokeRole, GrantProxy,
+    RevokeProxy, SetPassword, Deallocate, Execute, Prepare, Kill, ExplainTable, Explain, Savepoint,
+    ReleaseSavepoint, Merge, Cache, UNCache, CreateSequence, CreateType, Pragma, LockTables,
+    UnlockTables, Unload, OptimizeTable, Unparsed,
+}
+
+/// Statements covering every variant of [`Statement`], along with a dialect
+/// that is able to parse them.
+fn statements() -> Vec<(Box<dyn Dialect>, &'static str)> {
+    let generic = || Box::new(GenericDialect {}) as Box<dyn Dialect>;
+    let pg = || Box::new(PostgreSqlDialect {}) as Box<dyn Dialect>;
+    let mysql = || Box::new(MySqlDialect {}) as Box<dyn Dialect>;
+    let mssql = || Box::new(MsSqlDialect {}) as Box<dyn Dialect>;
+    let hive = || Box::new(HiveDialect {}) as Box<dyn Dialect>;
+    let duckdb = || Box::new(DuckDbDialect {}) as Box<dyn Dialect>;
+    let sqlite = || Box::new(SQLiteDialect {}) as Box<dyn Dialect>;
+    let snowflake = || Box::new(SnowflakeDialect {}) as Box<dyn Dialect>;
+    vec![
+        (hive(), "ANALYZE TABLE t COMPUTE STATISTICS"),
+        (generic(), "TRUNCATE TABLE t"),
+        (hive(), "MSCK REPAIR TABLE t"),
+        (generic(), "WITH c AS (SELECT a FROM t) SELECT DISTINCT a, b AS x FROM c JOIN u ON c.a = u.a WHERE a > 1 GROUP BY a, b HAVING COUNT(*) > 1 ORDER BY a DESC LIMIT 10"),
+        (generic(), "INSERT INTO t (a, b) VALUES (1, 'x') RETURNING a"),
+        (snowflake(), "INSERT ALL INTO t1 INTO t2 SELECT * FROM s"),
+        (mysql(), "CREATE EVENT e ON SCHEDULE EVERY 1 DAY DO DELETE FROM t"),
+        (mysql(), "ALTER EVENT e RENAME TO e2"),
+        (duckdb(), "INSTALL httpfs"),
+        (duckdb(), "LOAD httpfs"),
+        (hive(), "INSERT OVERWRITE LOCAL DIRECTORY '/tmp/out' SELECT * FROM t"),
+        (generic(), "CALL p(1, 'x')"),
+        (pg(), "COPY t (a, b) TO STDOUT WITH (FORMAT csv)"),
+        (snowflake(), "COPY INTO t FROM @stage"),
+        (pg(), "CLOSE c"),
+        (generic(), "UPDATE t SET a = 1 WHERE b = 2"),
+        (generic(), "DELETE FROM t WHERE a = 1"),
+        (generic(), "CREATE VIEW v (a) AS SELECT a FROM t"),
+        (generic(), "CREATE TABLE t (a INT PRIMARY KEY, b VARCHAR(10) NOT NULL DEFAULT 'x', CONSTRAINT fk FOREIGN KEY (b) REFERENCES u(b))"),
+        (sqlite(), "CREATE VIRTUAL TABLE t USING fts5(a, b)"),
+        (generic(), "CREATE UNIQUE INDEX i ON t (a, b)"),
+        (pg(), "CREATE ROLE r LOGIN"),
+        (duckdb(), "CREATE SECRET s (TYPE s3)"),
+        (pg(), "CREATE POLICY p ON t USING (a = 1)"),
+        (generic(), "ALTER TABLE t ADD COLUMN c INT"),
+        (mysql(), "RENAME TABLE a TO b, c TO d"),
+        (generic(), "ALTER INDEX i RENAME TO j"),
+        (generic(), "ALTER VIEW v AS SELECT 1"),
+        (pg(), "ALTER ROLE r RENAME TO s"),
+        (pg(), "ALTER POLICY p ON t RENAME TO q"),
+        (sqlite(), "ATTACH DATABASE 'file.db' AS d"),
+        (duckdb(), "ATTACH 'file.db' AS d"),
+        (duckdb(), "DETACH d"),
+        (generic(), "DROP TABLE IF EXISTS t CASCADE"),
+        (pg(), "DROP FUNCTION f(INT)"),
+        (pg(), "DROP PROCEDURE p"),
+        (duckdb(), "DROP SECRET s"),
+        (pg(), "DROP POLICY p ON t"),
+        (pg(), "DECLARE c CURSOR FOR SELECT 1"),
+        (pg(), "CREATE EXTENSION e"),
+        (pg(), "ALTER EXTENSION postgis UPDATE TO '3.4'"),
+        (pg(), r#"CREATE COLLATION s.c FROM "C""#),
+        (pg(), "ALTER COLLATION german_ci RENAME TO german"),
+        (pg(), "CREATE STATISTICS st (dependencies, ndistinct) ON a, b FROM t"),
+        (pg(), "ALTER STATISTICS s.st SET STATISTICS 500"),
+        (pg(), "CREATE ACCESS METHOD heptree TYPE INDEX HANDLER heptree_handler"),
+        (pg(), "CREATE TRUSTED LANGUAGE plperl"),
+        (pg(), "CREATE RULE r AS ON INSERT TO t DO INSTEAD NOTHING"),
+        (pg(), "NOTIFY c, 'payload'"),
+        (pg(), "FETCH NEXT IN c"),
+        (mysql(), "FLUSH TABLES"),
+        (pg(), "DISCARD ALL"),
+        (pg(), "DO LANGUAGE plpgsql $$ BEGIN RAISE NOTICE 'hi'; END $$"),
+        (mysql(), "HANDLER t READ idx >= (1, 'a') WHERE b IS NULL"),
+        (pg(), "REFRESH MATERIALIZED VIEW CONCURRENTLY mv WITH NO DATA"),
+        (generic(), "SET ROLE r"),
+        (generic(), "SET a = 1"),
+        (generic(), "SET TIME ZONE 'UTC'"),
+        (mysql(), "SET NAMES utf8mb4"),
+        (mysql(), "SET NAMES DEFAULT"),
+        (generic(), "SHOW FUNCTIONS"),
+        (generic(), "SHOW a"),
+        (mysql(), "SHOW STATUS"),
+        (mysql(), "SHOW VARIABLES"),
+        (mysql(), "SHOW CREATE TABLE t"),
+        (mysql(), "SHOW CREATE USER 'u'@'localhost'"),
+        (mysql(), "SHOW COLUMNS FROM t"),
+        (generic(), "SHOW TABLES"),
+        (mysql(), "SHOW COLLATION"),
+        (snowflake(), "SHOW TERSE TABLES IN DATABASE db STARTS WITH 'x' LIMIT 10 FROM 'y'"),
+        (generic(), "USE db"),
+        (generic(), "START TRANSACTION"),
+        (generic(), "SET TRANSACTION READ ONLY"),
+        (pg(), "COMMENT ON TABLE t IS 'x'"),
+        (generic(), "COMMIT"),
+        (generic(), "ROLLBACK"),
+        (generic(), "CREATE SCHEMA s"),
+        (generic(), "CREATE DATABASE d"),
+        (pg(), "CREATE FUNCTION f(a INT) RETURNS INT LANGUAGE SQL AS 'SELECT a'"),
+        (pg(), "CREATE TRIGGER tr BEFORE INSERT ON t FOR EACH ROW EXECUTE FUNCTION f"),
+        (pg(), "DROP TRIGGER tr ON t"),
+        (mssql(), "CREATE PROCEDURE p AS BEGIN SELECT 1 END"),
+        (duckdb(), "CREATE MACRO m(a) AS a + 1"),
+        (generic(), "CREATE MODEL m OPTIONS(model_type = 'linear_reg') AS SELECT 1"),
+        (snowflake(), "CREATE STAGE s"),
+        (snowflake(), "CREATE TASK t WAREHOUSE = wh SCHEDULE = '5 MINUTE' AFTER a WHEN SYSTEM$STREAM_HAS_DATA('s') AS INSERT INTO t SELECT * FROM s"),
+        (snowflake(), "ALTER TASK IF EXISTS t ADD AFTER a, b"),
+        (generic(), "ASSERT 1 = 1"),
+        (mssql(), "IF @x > 1 SELECT 1 ELSE SELECT 2"),
+        (mssql(), "WHILE @i < 10 SET @i = @i + 1"),
+        (mssql(), "BEGIN SELECT 1 END"),
+        (mssql(), "RETURN 1"),
+        (generic(), "GRANT SELECT ON t TO r"),
+        (generic(), "REVOKE SELECT ON t FROM r"),
+        (snowflake(), "GRANT ROLE analyst TO USER alice"),
+        (snowflake(), "REVOKE ROLE analyst FROM USER alice"),
+        (mysql(), "GRANT PROXY ON 'admin'@'%' TO 'u'@'localhost', v WITH GRANT OPTION"),
+        (mysql(), "REVOKE PROXY ON 'admin'@'%' FROM v"),
+        (mysql(), "SET PASSWORD FOR 'u'@'h' = 'hash'"),
+        (generic(), "DEALLOCATE p"),
+        (generic(), "EXECUTE p(1)"),
+        (generic(), "PREPARE p AS SELECT 1"),
+        (mysql(), "KILL 1"),
+        (mysql(), "DESCRIBE t"),
+        (generic(), "EXPLAIN SELECT 1"),
+        (generic(), "SAVEPOINT s"),
+        (generic(), "RELEASE SAVEPOINT s"),
+        (generic(), "MERGE INTO t USING s ON t.a = s.a WHEN MATCHED THEN UPDATE SET b = s.b WHEN NOT MATCHED THEN INSERT (a, b) VALUES (s.a, s.b)"),
+        (generic(), "CACHE TABLE t"),
+        (generic(), "UNCACHE TABLE t"),
+        (generic(), "CREATE SEQUENCE s"),
+        (pg(), "CREATE TYPE t AS (a INT, b TEXT)"),
+        (sqlite(), "PRAGMA cache_size = 10"),
+        (mysql(), "LOCK TABLES t READ"),
+        (mysql(), "UNLOCK TABLES"),
+        (generic(), "UNLOAD(SELECT 1) TO 's3://bucket/prefix'"),
+        (Box::new(ClickHouseDialect {}), "OPTIMIZE TABLE t"),
+    ]
+}
+
+/// The parsed [`statements`], plus statements that are only produced with
+/// non-default parser options.
+fn fixtures() -> Vec<(&'static str, Statement)> {
+    let mut fixtures: Vec<_> = statements()
+        .into_iter()
+        .map(|(dialect, sql)| {
+            let mut statements = Parser::parse_sql(&*dialect, sql).expect(sql);
+            assert_eq!(1, statements.len(), "{sql}");
+            (sql, statements.remove(0))
+        })
+        .collect();
+    let sql = "FROBNICATE the widgets";
+    let unparsed = Parser::new(&GenericDialect {})
+        .with_options(ParserOptions::new().with_unparsed_statements(true))
+        .try_with_sql(sql)
+        .unwrap()
+        .parse_statements()
+        .unwrap()
+        .remove(0);
+    fixtures.push((sql, unparsed));
+    fixtures
+}
+
+#[test]
+fn serde_round_trip_statements() {
+    for (sql, statement) in &fixtures() {
+        let json = serde_json::to_string(statement).unwrap();
+        let deserialized: Statement = serde_json::from_str(&json).expect(sql);
+        assert_eq!(statement, &deserialized, "{sql}");
+        assert_eq!(json, serde_json::to_string(&deserialized).unwrap());
+        assert_eq!(statement.to_string(), deserialized.to_string());
+    }
+}
+
+#[test]
+fn serde_round_trip_covers_all_statements() {
+    let covered: BTreeSet<_> = fixtures()
+        .iter()
+        .map(|(_, statement)| statement_variant(statement))
+        .collect();
+    let missing: Vec<_> = STATEMENT_VARIANTS
+        .iter()
+        .filter(|variant| !covered.contains(*variant))
+        .collect();
+    assert!(missing.is_empty(), "no round-trip fixture for {missing:?}");
+}
+
+#[test]
+fn serde_wire_format() {
+    // Changes to this representation break external consumers of the JSON
+    // output, so they should be deliberate.
+    let statements = Parser::parse_sql(&GenericDialect {}, "DROP TABLE IF EXISTS s.t").unwrap();
+    assert_eq!(
+        drop_wire_format(),
+        serde_json::to_value(&statements).unwrap()
+    );
+}
+
+/// The serialization of `DROP TABLE IF EXISTS s.t`.
+fn drop_wire_format() -> serde_json::Value {
+    serde_json::json!([{
+        "Drop": {
+            "object_type": "Table",
+            "if_exists": true,
+            "names": [[
+                {"value": "s", "quote_style": null},
+                {"value": "t", "quote_style": null}
+            ]],
+            "cascade": false,
+            "restrict": false,
+            "purge": false,
+            "temporary": false
+        }
+    }])
+}
+
+#[cfg(feature = "json_schema")]
+mod json_schema {
+    use std::collections::BTreeSet;
+
+    use sqlparser::ast::Statement;
+
+    fn statement_schema() -> serde_json::Value {
+        serde_json::to_value(schemars::schema_for!(Statement)).unwrap()
+    }
+
+    #[test]
+    fn json_schema_describes_wire_format() {
+        let schema = statement_schema();
+        let drop = schema["oneOf"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find_map(|variant| variant["properties"].get("Drop"))
+            .expect("Drop variant");
+        let properties: BTreeSet<_> = drop["properties"].as_object().unwrap().keys().collect();
+        let wire_format = super::drop_wire_format();
+        let fields: BTreeSet<_> = wire_format[0]["Drop"].as_object().unwrap().keys().collect();
+        assert_eq!(properties, fields);
+    }
+}
@@ -23,7 +23,7 @@ use sqlparser::ast::helpers::stmt_data_loading::{
     DataLoadingOption, DataLoadingOptionType, StageLoadSelectItem,
 };
 use sqlparser::ast::*;
-use sqlparser::dialect::{Dialect, GenericDialect, SnowflakeDialect};
+use sqlparser::dialect::{ClickHouseDialect, Dialect, GenericDialect, SnowflakeDialect};
 use sqlparser::parser::{ParserError, ParserOptions};
 use sqlparser::tokenizer::*;
 use test_utils::*;
@@ -66,7 +66,7 @@ fn test_snowflake_create_or_replace_table_copy_grants() {
         Statement::CreateTable(CreateTable {
             name,
             or_replace,
-            copy_grants,
+            dialect_options: CreateTableDialectOptions { copy_grants, .. },
             ..
         }) => {
             assert_eq!("my_table", name.to_string());
@@ -85,7 +85,7 @@ fn test_snowflake_create_or_replace_table_copy_grants_at_end() {
         Statement::CreateTable(CreateTable {
             name,
             or_replace,
-            copy_grants,
+            dialect_options: CreateTableDialectOptions { copy_grants, .. },
             ..
         }) => {
             assert_eq!("my_table", name.to_string());
@@ -103,7 +103,7 @@ fn test_snowflake_create_or_replace_table_copy_grants_cta() {
         Statement::CreateTable(CreateTable {
             name,
             or_replace,
-            copy_grants,
+            dialect_options: CreateTableDialectOptions { copy_grants, .. },
             ..
         }) => {
             assert_eq!("my_table", name.to_string());
@@ -120,7 +120,11 @@ fn test_snowflake_create_table_enable_schema_evolution() {
     match snowflake().verified_stmt(sql) {
         Statement::CreateTable(CreateTable {
             name,
-            enable_schema_evolution,
+            dialect_options:
+                CreateTableDialectOptions {
+                    enable_schema_evolution,
+                    ..
+                },
             ..
         }) => {
             assert_eq!("my_table", name.to_string());
@@ -136,7 +140,10 @@ fn test_snowflake_create_table_change_tracking() {
     match snowflake().verified_stmt(sql) {
         Statement::CreateTable(CreateTable {
             name,
-            change_tracking,
+            dialect_options:
+                CreateTableDialectOptions {
+                    change_tracking, ..
+                },
             ..
         }) => {
             assert_eq!("my_table", name.to_string());
@@ -152,7 +159,11 @@ fn test_snowflake_create_table_data_retention_time_in_days() {
     match snowflake().verified_stmt(sql) {
         Statement::CreateTable(CreateTable {
             name,
-            data_retention_time_in_days,
+            dialect_options:
+                CreateTableDialectOptions {
+                    data_retention_time_in_days,
+                    ..
+                },
             ..
         }) => {
             assert_eq!("my_table", name.to_string());
@@ -168,7 +179,11 @@ fn test_snowflake_create_table_max_data_extension_time_in_days() {
     match snowflake().verified_stmt(sql) {
         Statement::CreateTable(CreateTable {
             name,
-            max_data_extension_time_in_days,
+            dialect_options:
+                CreateTableDialectOptions {
+                    max_data_extension_time_in_days,
+                    ..
+                },
             ..
         }) => {
             assert_eq!("my_table", name.to_string());
@@ -185,7 +200,11 @@ fn test_snowflake_create_table_with_aggregation_policy() {
     {
         Statement::CreateTable(CreateTable {
             name,
-            with_aggregation_policy,
+            dialect_options:
+                CreateTableDialectOptions {
+                    with_aggregation_policy,
+                    ..
+                },
             ..
         }) => {
             assert_eq!("my_table", name.to_string());
@@ -205,7 +224,11 @@ fn test_snowflake_create_table_with_aggregation_policy() {
     {
         Statement::CreateTable(CreateTable {
             name,
-            with_aggregation_policy,
+            dialect_options:
+                CreateTableDialectOptions {
+                    with_aggregation_policy,
+                    ..
+                },
             ..
         }) => {
             assert_eq!("my_table", name.to_string());
@@ -225,7 +248,11 @@ fn test_snowflake_create_table_with_row_access_policy() {
     ) {
         Statement::CreateTable(CreateTable {
             name,
-            with_row_access_policy,
+            dialect_options:
+                CreateTableDialectOptions {
+                    with_row_access_policy,
+                    ..
+                },
             ..
         }) => {
             assert_eq!("my_table", name.to_string());
@@ -247,7 +274,11 @@ fn test_snowflake_create_table_with_row_access_policy() {
     {
         Statement::CreateTable(CreateTable {
             name,
-            with_row_access_policy,
+            dialect_options:
+                CreateTableDialectOptions {
+                    with_row_access_policy,
+                    ..
+                },
             ..
         }) => {
             assert_eq!("my_table", name.to_string());
@@ -266,7 +297,9 @@ fn test_snowflake_create_table_with_tag() {
         .verified_stmt("CREATE TABLE my_table (a number) WITH TAG (A='TAG A', B='TAG B')")
     {
         Statement::CreateTable(CreateTable {
-            name, with_tags, ..
+            name,
+            dialect_options: CreateTableDialectOptions { with_tags, .. },
+            ..
         }) => {
             assert_eq!("my_table", name.to_string());
             assert_eq!(
@@ -287,7 +320,9 @@ fn test_snowflake_create_table_with_tag() {
         .unwrap()
     {
         Statement::CreateTable(CreateTable {
-            name, with_tags, ..
+            name,
+            dialect_options: CreateTableDialectOptions { with_tags, .. },
+            ..
         }) => {
             assert_eq!("my_table", name.to_string());
             assert_eq!(
@@ -308,7 +343,11 @@ fn test_snowflake_create_table_default_ddl_collation() {
     match snowflake().verified_stmt(sql) {
         Statement::CreateTable(CreateTable {
             name,
-            default_ddl_collation,
+            dialect_options:
+                CreateTableDialectOptions {
+                    default_ddl_collation,
+                    ..
+                },
             ..
         }) => {
             assert_eq!("my_table", name.to_string());
@@ -445,7 +484,9 @@ fn test_snowflake_create_table_if_not_exists() {
 fn test_snowflake_create_table_cluster_by() {
     match snowflake().verified_stmt("CREATE TABLE my_table (a INT) CLUSTER BY (a, b)") {
         Statement::CreateTable(CreateTable {
-            name, cluster_by, ..
+            name,
+            partitioning: CreateTablePartitioning { cluster_by, .. },
+            ..
         }) => {
             assert_eq!("my_table", name.to_string());
             assert_eq!(
@@ -907,6 +948,7 @@ fn parse_delimited_identifiers() {
             filter: None,
             null_treatment: None,
             over: None,
+            safe: false,
             within_group: vec![],
         }),
         expr_from_projection(&select.projection[1]),
@@ -1379,6 +1421,123 @@ fn parse_snowflake_declare_multi_statements() {
     );
 }
 
+#[test]
+fn test_create_task() {
+    let sql = concat!(
+        "CREATE OR REPLACE TASK IF NOT EXISTS db.load WAREHOUSE = wh SCHEDULE = '5 MINUTE' ",
+        "COMMENT = 'load' AFTER db.extract, db.stage ",
+        "WHEN SYSTEM$STREAM_HAS_DATA('s') ",
+        "AS INSERT INTO t SELECT * FROM s"
+    );
+    match snowflake().verified_stmt(sql) {
+        Statement::CreateTask {
+            or_replace,
+            if_not_exists,
+            name,
+            warehouse,
+            schedule,
+            options,
+            after,
+            condition,
+            statement,
+        } => {
+            assert!(or_replace);
+            assert!(if_not_exists);
+            assert_eq!("db.load", name.to_string());
+            assert_eq!(Some(Ident::new("wh")), warehouse);
+            assert_eq!(Some("5 MINUTE".to_string()), schedule);
+            assert_eq!(
+                vec![SqlOption::KeyValue {
+                    key: Ident::new("COMMENT"),
+                    value: Expr::Value(Value::SingleQuotedString("load".to_string())),
+                }],
+                options
+            );
+            assert_eq!(
+                vec!["db.extract", "db.stage"],
+                after.iter().map(ToString::to_string).collect::<Vec<_>>()
+            );
+            assert_eq!(
+                "SYSTEM$STREAM_HAS_DATA('s')",
+                condition.unwrap().to_string()
+            );
+            assert!(matches!(*statement, Statement::Insert(_)));
+        }
+        _ => unreachable!(),
+    }
+
+    snowflake().verified_stmt("CREATE TASK t SCHEDULE = 'USING CRON 0 9 * * * UTC' AS CALL p()");
+    snowflake().verified_stmt("CREATE TASK t AFTER root AS DELETE FROM t WHERE a < 1");
+    assert!(snowflake()
+        .parse_sql_statements("CREATE TASK t WAREHOUSE = wh")
+        .is_err());
+}
+
+#[test]
+fn test_alter_task() {
+    match snowflake().verified_stmt("ALTER TASK IF EXISTS t ADD AFTER a, b") {
+        Statement::AlterTask {
+            if_exists,
+            name,
+            operation,
+        } => {
+            assert!(if_exists);
+            assert_eq!("t", name.to_string());
+            assert_eq!(
+                AlterTaskOperation::AddAfter(vec![
+                    ObjectName(vec![Ident::new("a")]),
+                    ObjectName(vec![Ident::new("b")]),
+                ]),
+                operation
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    snowflake().verified_stmt("ALTER TASK t RESUME");
+    snowflake().verified_stmt("ALTER TASK t SUSPEND");
+    snowflake().verified_stmt("ALTER TASK t REMOVE AFTER a");
+    snowflake().verified_stmt("ALTER TASK t SET WAREHOUSE = wh SCHEDULE = '1 MINUTE'");
+    snowflake().one_statement_parses_to(
+        "ALTER TASK t SET TIMEZONE = 'UTC', COMMENT = 'x'",
+        "ALTER TASK t SET TIMEZONE = 'UTC' COMMENT = 'x'",
+    );
+    snowflake().verified_stmt("ALTER TASK t UNSET COMMENT, TIMEZONE");
+    snowflake().verified_stmt("ALTER TASK t MODIFY AS SELECT 1");
+    snowflake().verified_stmt("ALTER TASK t MODIFY WHEN a > 1");
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn test_task_serde_round_trip() {
+    for sql in [
+        "CREATE TASK t WAREHOUSE = wh SCHEDULE = '5 MINUTE' AFTER a WHEN x > 1 AS CALL p()",
+        "ALTER TASK t RESUME",
+    ] {
+        assert_serde_round_trip!(snowflake().verified_stmt(sql));
+    }
+}
+
+#[test]
+fn test_drop_task() {
+    match snowflake().verified_stmt("DROP TASK IF EXISTS t") {
+        Statement::Drop {
+            object_type,
+            if_exists,
+            names,
+            ..
+        } => {
+            assert_eq!(ObjectType::Task, object_type);
+            assert!(if_exists);
+            assert_eq!(
+                vec!["t"],
+                names.iter().map(ToString::to_string).collect::<Vec<_>>()
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
 #[test]
 fn test_create_stage() {
     let sql = "CREATE STAGE s1.s2";
@@ -1456,7 +1615,7 @@ fn test_create_stage_with_stage_params() {
                 .contains(&DataLoadingOption {
                     option_name: "AWS_KEY_ID".to_string(),
                     option_type: DataLoadingOptionType::STRING,
-                    value: "1a2b3c".to_string()
+                    value: "1a2b3c".into()
                 }));
             assert!(stage_params
                 .credentials
@@ -1464,7 +1623,7 @@ fn test_create_stage_with_stage_params() {
                 .contains(&DataLoadingOption {
                     option_name: "AWS_SECRET_KEY".to_string(),
                     option_type: DataLoadingOptionType::STRING,
-                    value: "4x5y6z".to_string()
+                    value: "4x5y6z".into()
                 }));
             assert!(stage_params
                 .encryption
@@ -1472,7 +1631,7 @@ fn test_create_stage_with_stage_params() {
                 .contains(&DataLoadingOption {
                     option_name: "MASTER_KEY".to_string(),
                     option_type: DataLoadingOptionType::STRING,
-                    value: "key".to_string()
+                    value: "key".into()
                 }));
             assert!(stage_params
                 .encryption
@@ -1480,7 +1639,7 @@ fn test_create_stage_with_stage_params() {
                 .contains(&DataLoadingOption {
                     option_name: "TYPE".to_string(),
                     option_type: DataLoadingOptionType::STRING,
-                    value: "AWS_SSE_KMS".to_string()
+                    value: "AWS_SSE_KMS".into()
                 }));
         }
         _ => unreachable!(),
@@ -1505,17 +1664,17 @@ fn test_create_stage_with_directory_table_params() {
             assert!(directory_table_params.options.contains(&DataLoadingOption {
                 option_name: "ENABLE".to_string(),
                 option_type: DataLoadingOptionType::BOOLEAN,
-                value: "TRUE".to_string()
+                value: "TRUE".into()
             }));
             assert!(directory_table_params.options.contains(&DataLoadingOption {
                 option_name: "REFRESH_ON_CREATE".to_string(),
                 option_type: DataLoadingOptionType::BOOLEAN,
-                value: "FALSE".to_string()
+                value: "FALSE".into()
             }));
             assert!(directory_table_params.options.contains(&DataLoadingOption {
                 option_name: "NOTIFICATION_INTEGRATION".to_string(),
                 option_type: DataLoadingOptionType::STRING,
-                value: "some-string".to_string()
+                value: "some-string".into()
             }));
         }
         _ => unreachable!(),
@@ -1536,12 +1695,12 @@ fn test_create_stage_with_file_format() {
             assert!(file_format.options.contains(&DataLoadingOption {
                 option_name: "COMPRESSION".to_string(),
                 option_type: DataLoadingOptionType::ENUM,
-                value: "AUTO".to_string()
+                value: "AUTO".into()
             }));
             assert!(file_format.options.contains(&DataLoadingOption {
                 option_name: "BINARY_FORMAT".to_string(),
                 option_type: DataLoadingOptionType::ENUM,
-                value: "HEX".to_string()
+                value: "HEX".into()
             }));
             assert!(file_format.options.contains(&DataLoadingOption {
                 option_name: "ESCAPE".to_string(),
@@ -1569,12 +1728,12 @@ fn test_create_stage_with_copy_options() {
             assert!(copy_options.options.contains(&DataLoadingOption {
                 option_name: "ON_ERROR".to_string(),
                 option_type: DataLoadingOptionType::ENUM,
-                value: "CONTINUE".to_string()
+                value: "CONTINUE".into()
             }));
             assert!(copy_options.options.contains(&DataLoadingOption {
                 option_name: "FORCE".to_string(),
                 option_type: DataLoadingOptionType::BOOLEAN,
-                value: "TRUE".to_string()
+                value: "TRUE".into()
             }));
         }
         _ => unreachable!(),
@@ -1647,7 +1806,7 @@ fn test_copy_into_with_stage_params() {
                 .contains(&DataLoadingOption {
                     option_name: "AWS_KEY_ID".to_string(),
                     option_type: DataLoadingOptionType::STRING,
-                    value: "1a2b3c".to_string()
+                    value: "1a2b3c".into()
                 }));
             assert!(stage_params
                 .credentials
@@ -1655,7 +1814,7 @@ fn test_copy_into_with_stage_params() {
                 .contains(&DataLoadingOption {
                     option_name: "AWS_SECRET_KEY".to_string(),
                     option_type: DataLoadingOptionType::STRING,
-                    value: "4x5y6z".to_string()
+                    value: "4x5y6z".into()
                 }));
             assert!(stage_params
                 .encryption
@@ -1663,7 +1822,7 @@ fn test_copy_into_with_stage_params() {
                 .contains(&DataLoadingOption {
                     option_name: "MASTER_KEY".to_string(),
                     option_type: DataLoadingOptionType::STRING,
-                    value: "key".to_string()
+                    value: "key".into()
                 }));
             assert!(stage_params
                 .encryption
@@ -1671,7 +1830,7 @@ fn test_copy_into_with_stage_params() {
                 .contains(&DataLoadingOption {
                     option_name: "TYPE".to_string(),
                     option_type: DataLoadingOptionType::STRING,
-                    value: "AWS_SSE_KMS".to_string()
+                    value: "AWS_SSE_KMS".into()
                 }));
         }
         _ => unreachable!(),
@@ -1797,12 +1956,12 @@ fn test_copy_into_file_format() {
             assert!(file_format.options.contains(&DataLoadingOption {
                 option_name: "COMPRESSION".to_string(),
                 option_type: DataLoadingOptionType::ENUM,
-                value: "AUTO".to_string()
+                value: "AUTO".into()
             }));
             assert!(file_format.options.contains(&DataLoadingOption {
                 option_name: "BINARY_FORMAT".to_string(),
                 option_type: DataLoadingOptionType::ENUM,
-                value: "HEX".to_string()
+                value: "HEX".into()
             }));
             assert!(file_format.options.contains(&DataLoadingOption {
                 option_name: "ESCAPE".to_string(),
@@ -1833,12 +1992,12 @@ fn test_copy_into_copy_options() {
             assert!(copy_options.options.contains(&DataLoadingOption {
                 option_name: "ON_ERROR".to_string(),
                 option_type: DataLoadingOptionType::ENUM,
-                value: "CONTINUE".to_string()
+                value: "CONTINUE".into()
             }));
             assert!(copy_options.options.contains(&DataLoadingOption {
                 option_name: "FORCE".to_string(),
                 option_type: DataLoadingOptionType::BOOLEAN,
-                value: "TRUE".to_string()
+                value: "TRUE".into()
             }));
         }
         _ => unreachable!(),
@@ -2242,7 +2401,7 @@ fn asof_joins() {
                 relation: table_with_alias("quotes_unixtime", "qu"),
                 global: false,
                 join_operator: JoinOperator::AsOf {
-                    match_condition: Expr::BinaryOp {
+                    match_condition: Some(Expr::BinaryOp {
                         left: Box::new(Expr::CompoundIdentifier(vec![
                             Ident::new("tu"),
                             Ident::new("trade_time"),
@@ -2252,7 +2411,7 @@ fn asof_joins() {
                             Ident::new("qu"),
                             Ident::new("quote_time"),
                         ])),
-                    },
+                    }),
                     constraint: JoinConstraint::None,
                 },
             }],
@@ -2290,6 +2449,11 @@ fn asof_joins() {
               "ON s.state = p.state ",
           "ORDER BY s.observed",
     ));
+
+    // ASOF is not parsed as a table alias
+    snowflake_and_generic().verified_stmt(
+        "SELECT * FROM trades ASOF JOIN quotes MATCH_CONDITION (trade_time >= quote_time)",
+    );
 }
 
 #[test]
@@ -2444,3 +2608,374 @@ fn parse_view_column_descriptions() {
         _ => unreachable!(),
     };
 }
+
+#[test]
+fn parse_multi_table_insert() {
+    let sql = "INSERT ALL INTO t1 INTO t2 (c1, c2) VALUES (n1, DEFAULT) SELECT n1, n2 FROM src";
+    match snowflake_and_generic().verified_stmt(sql) {
+        Statement::MultiTableInsert(MultiTableInsert {
+            overwrite,
+            kind,
+            into_clauses,
+            when_clauses,
+            else_clause,
+            source,
+        }) => {
+            assert!(!overwrite);
+            assert_eq!(kind, MultiTableInsertKind::All);
+            assert_eq!(
+                into_clauses,
+                vec![
+                    MultiTableInsertIntoClause {
+                        table_name: ObjectName(vec![Ident::new("t1")]),
+                        columns: vec![],
+                        values: None,
+                    },
+                    MultiTableInsertIntoClause {
+                        table_name: ObjectName(vec![Ident::new("t2")]),
+                        columns: vec![Ident::new("c1"), Ident::new("c2")],
+                        values: Some(vec![Expr::Identifier(Ident::new("n1")), Expr::Default,]),
+                    },
+                ]
+            );
+            assert!(when_clauses.is_empty());
+            assert!(else_clause.is_none());
+            assert_eq!(source.to_string(), "SELECT n1, n2 FROM src");
+        }
+        _ => unreachable!(),
+    }
+
+    let sql = "INSERT FIRST \
+        WHEN n1 > 100 THEN INTO t1 \
+        WHEN n1 > 10 THEN INTO t1 INTO t2 (c1) VALUES (n1) \
+        ELSE INTO t3 \
+        SELECT n1 FROM src";
+    match snowflake_and_generic().verified_stmt(sql) {
+        Statement::MultiTableInsert(MultiTableInsert {
+            kind,
+            into_clauses,
+            when_clauses,
+            else_clause,
+            ..
+        }) => {
+            assert_eq!(kind, MultiTableInsertKind::First);
+            assert!(into_clauses.is_empty());
+            assert_eq!(when_clauses.len(), 2);
+            assert_eq!(when_clauses[0].condition.to_string(), "n1 > 100");
+            assert_eq!(when_clauses[1].into_clauses.len(), 2);
+            assert_eq!(else_clause.unwrap().len(), 1);
+        }
+        _ => unreachable!(),
+    }
+
+    snowflake_and_generic().verified_stmt(
+        "INSERT OVERWRITE ALL WHEN n1 > 100 THEN INTO t1 SELECT n1 FROM (SELECT 1 AS n1)",
+    );
+    snowflake_and_generic()
+        .verified_stmt("INSERT ALL INTO t1 SELECT * FROM src UNION SELECT * FROM src2");
+
+    assert_eq!(
+        snowflake_and_generic()
+            .parse_sql_statements("INSERT FIRST INTO t1 SELECT 1")
+            .unwrap_err(),
+        ParserError::ParserError("Expected: INTO or WHEN, found: INTO".to_string())
+    );
+}
+
+#[test]
+fn parse_create_function_with_handler() {
+    let sql = "CREATE OR REPLACE FUNCTION py_add(a INT, b INT) RETURNS INT LANGUAGE PYTHON \
+        RUNTIME_VERSION = '3.10' PACKAGES = ('numpy', 'pandas==2.1.4') HANDLER = 'add' \
+        AS $$def add(a, b): return a + b$$";
+    match snowflake_and_generic().verified_stmt(sql) {
+        Statement::CreateFunction {
+            or_replace,
+            language,
+            runtime_version,
+            packages,
+            imports,
+            handler,
+            function_body,
+            ..
+        } => {
+            assert!(or_replace);
+            assert_eq!(language, Some(Ident::new("PYTHON")));
+            assert_eq!(runtime_version.as_deref(), Some("3.10"));
+            assert_eq!(
+                packages,
+                Some(vec!["numpy".to_string(), "pandas==2.1.4".to_string()])
+            );
+            assert_eq!(imports, None);
+            assert_eq!(handler.as_deref(), Some("add"));
+            assert_eq!(
+                function_body,
+                Some(CreateFunctionBody::AsBeforeOptions(Expr::Value(
+                    Value::DollarQuotedString(DollarQuotedString {
+                        value: "def add(a, b): return a + b".to_string(),
+                        tag: None,
+                    })
+                )))
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    snowflake_and_generic().verified_stmt(
+        "CREATE FUNCTION echo(x STRING) RETURNS STRING LANGUAGE JAVA CALLED ON NULL INPUT \
+        RUNTIME_VERSION = '11' IMPORTS = ('@stage/echo.jar') HANDLER = 'Echo.run' \
+        AS 'class Echo {}'",
+    );
+    snowflake().one_statement_parses_to(
+        "CREATE FUNCTION f(x INT) RETURNS INT LANGUAGE SCALA HANDLER='F.f' RUNTIME_VERSION='2.12' PACKAGES=() AS ''",
+        "CREATE FUNCTION f(x INT) RETURNS INT LANGUAGE SCALA RUNTIME_VERSION = '2.12' PACKAGES = () HANDLER = 'F.f' AS ''",
+    );
+
+    assert_eq!(
+        snowflake()
+            .parse_sql_statements(
+                "CREATE FUNCTION f() RETURNS INT LANGUAGE PYTHON HANDLER = 'a' HANDLER = 'b' AS ''"
+            )
+            .unwrap_err(),
+        ParserError::ParserError("HANDLER specified more than once".to_string())
+    );
+}
+
+#[test]
+fn parse_create_procedure_with_handler() {
+    let sql = "CREATE OR REPLACE PROCEDURE run_job (name VARCHAR) RETURNS VARCHAR LANGUAGE PYTHON \
+        RUNTIME_VERSION = '3.10' PACKAGES = ('snowflake-snowpark-python') \
+        IMPORTS = ('@stage/job.py') HANDLER = 'job.run' AS 'import job'";
+    match snowflake_and_generic().verified_stmt(sql) {
+        Statement::CreateProcedure {
+            or_replace,
+            return_type,
+            language,
+            runtime_version,
+            packages,
+            imports,
+            handler,
+            body,
+            ..
+        } => {
+            assert!(or_replace);
+            assert_eq!(return_type, Some(DataType::Varchar(None)));
+            assert_eq!(language, Some(Ident::new("PYTHON")));
+            assert_eq!(runtime_version.as_deref(), Some("3.10"));
+            assert_eq!(
+                packages,
+                Some(vec!["snowflake-snowpark-python".to_string()])
+            );
+            assert_eq!(imports, Some(vec!["@stage/job.py".to_string()]));
+            assert_eq!(handler.as_deref(), Some("job.run"));
+            assert_eq!(
+                body,
+                CreateProcedureBody::Definition(Expr::Value(Value::SingleQuotedString(
+                    "import job".to_string()
+                )))
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    snowflake().verified_stmt(
+        "CREATE PROCEDURE p RETURNS INT LANGUAGE JAVA RUNTIME_VERSION = '11' \
+        PACKAGES = ('com.snowflake:snowpark:latest') HANDLER = 'P.run' AS $$class P {}$$",
+    );
+}
+
+#[test]
+fn parse_create_procedure_scripting_body() {
+    let dialects = TestedDialects {
+        dialects: vec![Box::new(SnowflakeDialect {}), Box::new(GenericDialect {})],
+        options: Some(ParserOptions::new().with_parse_script_bodies(true)),
+    };
+    let sql = "CREATE OR REPLACE PROCEDURE count_to (n INT) RETURNS INT LANGUAGE SQL AS $$ \
+        DECLARE counter INT DEFAULT 0; \
+        BEGIN \
+        LOOP counter := counter + 1; IF (counter >= n) THEN EXIT; END IF; END LOOP; \
+        LET total := counter * 2; \
+        INSERT INTO log VALUES (total); \
+        RETURN total; \
+        EXCEPTION WHEN statement_error THEN RAISE; WHEN OTHER THEN RETURN -1; \
+        END $$";
+    match dialects.verified_stmt(sql) {
+        Statement::CreateProcedure {
+            body: CreateProcedureBody::Script(script),
+            ..
+        } => {
+            assert_eq!(script.declarations[0].to_string(), "counter INT DEFAULT 0");
+            assert_eq!(script.statements.len(), 4);
+            assert!(matches!(
+                script.statements[0],
+                script::ScriptStatement::Loop(ref body) if body.len() == 2
+            ));
+            assert_eq!(
+                script.statements[1],
+                script::ScriptStatement::Assignment {
+                    is_let: true,
+                    name: ObjectName(vec![Ident::new("total")]),
+                    value: Expr::BinaryOp {
+                        left: Box::new(Expr::Identifier(Ident::new("counter"))),
+                        op: BinaryOperator::Multiply,
+                        right: Box::new(Expr::Value(number("2"))),
+                    },
+                }
+            );
+            assert!(matches!(
+                script.statements[2],
+                script::ScriptStatement::Sql(Statement::Insert(_))
+            ));
+            assert_eq!(script.exception_handlers.len(), 2);
+        }
+        _ => unreachable!(),
+    }
+
+    // Non-SQL handlers are not parsed as scripts
+    dialects.verified_stmt(
+        "CREATE PROCEDURE p RETURNS INT LANGUAGE PYTHON RUNTIME_VERSION = '3.10' HANDLER = 'run' AS $$def run(s): return 1$$",
+    );
+}
+
+#[test]
+fn parse_grant_snowflake_objects() {
+    match snowflake_and_generic().verified_stmt("GRANT USAGE ON WAREHOUSE w1, w2 TO ROLE analyst") {
+        Statement::Grant {
+            objects: GrantObjects::Warehouses(warehouses),
+            grantees_type,
+            grantees,
+            ..
+        } => {
+            assert_eq_vec(&["w1", "w2"], &warehouses);
+            assert_eq!(grantees_type, Some(GranteesType::Role));
+            assert_eq_vec(&["analyst"], &grantees);
+        }
+        _ => unreachable!(),
+    }
+    snowflake_and_generic().verified_stmt("GRANT USAGE ON STAGE db.sch.s TO ROLE loader");
+    snowflake_and_generic().verified_stmt("GRANT USAGE ON DATABASE d TO SHARE s");
+    snowflake_and_generic()
+        .verified_stmt("GRANT USAGE ON FUNCTION db.sch.f(INT, VARCHAR) TO ROLE analyst");
+    snowflake_and_generic().verified_stmt("REVOKE USAGE ON WAREHOUSE w FROM ROLE analyst RESTRICT");
+
+    assert_eq!(
+        snowflake_and_generic().verified_stmt("GRANT ROLE analyst TO USER alice"),
+        Statement::GrantRole {
+            roles: vec![Ident::new("analyst")],
+            grantees_type: Some(GranteesType::User),
+            grantees: vec![Ident::new("alice")],
+        }
+    );
+    snowflake_and_generic().verified_stmt("GRANT ROLE analyst TO ROLE sysadmin");
+    assert_eq!(
+        snowflake_and_generic().verified_stmt("REVOKE ROLE analyst FROM USER alice"),
+        Statement::RevokeRole {
+            roles: vec![Ident::new("analyst")],
+            grantees_type: Some(GranteesType::User),
+            grantees: vec![Ident::new("alice")],
+        }
+    );
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn parse_grant_role_serde_round_trip() {
+    for sql in ["GRANT ROLE r TO USER u", "REVOKE ROLE r FROM USER u"] {
+        assert_serde_round_trip!(snowflake().verified_stmt(sql));
+    }
+}
+
+#[test]
+fn parse_show_objects() {
+    assert_eq!(
+        snowflake()
+            .verified_stmt("SHOW TERSE TABLES IN DATABASE db STARTS WITH 'x' LIMIT 10 FROM 'y'"),
+        Statement::ShowObjects {
+            terse: true,
+            object_type: ShowObjectType::Tables,
+            like: None,
+            scope: Some(ShowObjectsScope::Database(Some(ObjectName(vec![
+                Ident::new("db")
+            ])))),
+            starts_with: Some("x".to_string()),
+            limit: Some(Expr::Value(number("10"))),
+            limit_from: Some("y".to_string()),
+        }
+    );
+    assert_eq!(
+        snowflake().verified_stmt("SHOW TABLES LIKE 'a%' IN SCHEMA db.s"),
+        Statement::ShowObjects {
+            terse: false,
+            object_type: ShowObjectType::Tables,
+            like: Some("a%".to_string()),
+            scope: Some(ShowObjectsScope::Schema(Some(ObjectName(vec![
+                Ident::new("db"),
+                Ident::new("s")
+            ])))),
+            starts_with: None,
+            limit: None,
+            limit_from: None,
+        }
+    );
+
+    snowflake().verified_stmt("SHOW WAREHOUSES");
+    snowflake().verified_stmt("SHOW WAREHOUSES LIKE 'etl%'");
+    snowflake().verified_stmt("SHOW SCHEMAS IN ACCOUNT");
+    snowflake().verified_stmt("SHOW TERSE OBJECTS IN db.s");
+    snowflake().verified_stmt("SHOW EXTERNAL TABLES IN DATABASE");
+    snowflake().verified_stmt("SHOW MATERIALIZED VIEWS IN SCHEMA LIMIT 5");
+    snowflake().verified_stmt("SHOW USER FUNCTIONS IN SCHEMA s STARTS WITH 'f'");
+    snowflake().verified_stmt("SHOW COLUMNS IN TABLE t");
+    snowflake().verified_stmt("SHOW DATABASES");
+
+    // other SHOW statements are unaffected
+    snowflake().verified_stmt("SHOW search_path");
+
+    // without Snowflake specific clauses, the generic SHOW statements are used
+    assert!(matches!(
+        snowflake().verified_stmt("SHOW TABLES FROM mydb"),
+        Statement::ShowTables { .. }
+    ));
+    assert!(matches!(
+        snowflake().verified_stmt("SHOW COLUMNS FROM mytable"),
+        Statement::ShowColumns { .. }
+    ));
+    snowflake().verified_stmt("SHOW TABLES");
+    snowflake().verified_stmt("SHOW TABLES WHERE 1 = 2");
+    snowflake().verified_stmt("SHOW COLUMNS FROM mydb.mytable");
+    snowflake().verified_stmt("SHOW COLUMNS FROM mytable LIKE 'pattern'");
+    snowflake().one_statement_parses_to("SHOW FIELDS FROM mytable", "SHOW COLUMNS FROM mytable");
+
+    assert_eq!(
+        snowflake()
+            .parse_sql_statements("SHOW TERSE x")
+            .unwrap_err(),
+        ParserError::ParserError("Expected: an object type after SHOW TERSE, found: x".to_string())
+    );
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn parse_show_objects_serde_round_trip() {
+    assert_serde_round_trip!(snowflake().verified_stmt("SHOW TERSE TABLES IN SCHEMA s"));
+}
+
+#[test]
+fn parse_reserved_keywords_for_table_alias() {
+    // ClickHouse's SETTINGS clause isn't parsed for Snowflake
+    let sql = "SELECT settings.a FROM user_settings settings";
+    snowflake().one_statement_parses_to(sql, "SELECT settings.a FROM user_settings AS settings");
+    assert!(TestedDialects {
+        dialects: vec![Box::new(ClickHouseDialect {})],
+        options: None,
+    }
+    .parse_sql_statements(sql)
+    .is_err());
+
+    let sql = "SELECT * FROM t tablesample";
+    assert!(snowflake().parse_sql_statements(sql).is_err());
+    TestedDialects {
+        dialects: vec![Box::new(GenericDialect {})],
+        options: None,
+    }
+    .one_statement_parses_to(sql, "SELECT * FROM t AS tablesample");
+}
@@ -28,7 +28,7 @@ use test_utils::*;
 use sqlparser::ast::SelectItem::UnnamedExpr;
 use sqlparser::ast::Value::Placeholder;
 use sqlparser::ast::*;
-use sqlparser::dialect::{GenericDialect, SQLiteDialect};
+use sqlparser::dialect::{GenericDialect, MsSqlDialect, PostgreSqlDialect, SQLiteDialect};
 use sqlparser::parser::{ParserError, ParserOptions};
 use sqlparser::tokenizer::Token;
 
@@ -131,7 +131,11 @@ fn parse_create_table_without_rowid() {
     match sqlite_and_generic().verified_stmt(sql) {
         Statement::CreateTable(CreateTable {
             name,
-            without_rowid: true,
+            dialect_options:
+                CreateTableDialectOptions {
+                    without_rowid: true,
+                    ..
+                },
             ..
         }) => {
             assert_eq!("t", name.to_string());
@@ -344,6 +348,67 @@ fn parse_create_table_on_conflict_col() {
     }
 }
 
+#[test]
+fn parse_insert_on_conflict_index_inference() {
+    sqlite_and_generic().verified_stmt(
+        "INSERT INTO t (a, b) VALUES (1, 2) ON CONFLICT(lower(a)) WHERE b > 0 DO UPDATE SET b = excluded.b",
+    );
+    sqlite_and_generic().verified_stmt("INSERT INTO t (a) VALUES (1) ON CONFLICT(a) DO NOTHING");
+}
+
+#[test]
+fn parse_insert_multiple_on_conflict() {
+    let sql = "INSERT INTO t (a, b) VALUES (1, 2) \
+        ON CONFLICT(a) DO NOTHING \
+        ON CONFLICT(b) DO UPDATE SET a = excluded.a WHERE t.a < excluded.a \
+        ON CONFLICT DO NOTHING";
+    match sqlite_and_generic().verified_stmt(sql) {
+        Statement::Insert(Insert { on, .. }) => {
+            assert_eq!(on.len(), 3);
+            assert_eq!(
+                on[0],
+                OnInsert::OnConflict(OnConflict {
+                    conflict_target: Some(ConflictTarget::Columns(vec![Ident::new("a")])),
+                    action: OnConflictAction::DoNothing,
+                })
+            );
+            match &on[1] {
+                OnInsert::OnConflict(OnConflict {
+                    conflict_target: Some(ConflictTarget::Columns(cols)),
+                    action:
+                        OnConflictAction::DoUpdate(DoUpdate {
+                            assignments,
+                            selection,
+                        }),
+                }) => {
+                    assert_eq!(cols, &vec![Ident::new("b")]);
+                    assert_eq!(assignments[0].to_string(), "a = excluded.a");
+                    assert_eq!(selection.as_ref().unwrap().to_string(), "t.a < excluded.a");
+                }
+                _ => unreachable!(),
+            }
+            assert_eq!(
+                on[2],
+                OnInsert::OnConflict(OnConflict {
+                    conflict_target: None,
+                    action: OnConflictAction::DoNothing,
+                })
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    // other dialects accept a single `ON CONFLICT` clause
+    assert_eq!(
+        TestedDialects::new(vec![Box::new(PostgreSqlDialect {})])
+            .parse_sql_statements(
+                "INSERT INTO t VALUES (1) ON CONFLICT(a) DO NOTHING ON CONFLICT(b) DO NOTHING"
+            )
+            .unwrap_err(),
+        ParserError::ParserError("Expected: end of statement, found: ON".to_string())
+    );
+}
+
 #[test]
 fn test_parse_create_table_on_conflict_col_err() {
     let sql_err = "CREATE TABLE t1 (a INT, b INT ON CONFLICT BOH)";
@@ -380,7 +445,12 @@ fn test_placeholder() {
 #[test]
 fn parse_create_table_with_strict() {
     let sql = "CREATE TABLE Fruits (id TEXT NOT NULL PRIMARY KEY) STRICT";
-    if let Statement::CreateTable(CreateTable { name, strict, .. }) = sqlite().verified_stmt(sql) {
+    if let Statement::CreateTable(CreateTable {
+        name,
+        dialect_options: CreateTableDialectOptions { strict, .. },
+        ..
+    }) = sqlite().verified_stmt(sql)
+    {
         assert_eq!(name.to_string(), "Fruits");
         assert!(strict);
     }
@@ -435,6 +505,7 @@ fn parse_window_function_with_filter() {
                     window_frame: None,
                 })),
                 filter: Some(Box::new(Expr::Identifier(Ident::new("y")))),
+                safe: false,
                 within_group: vec![],
             }))]
         );
@@ -488,7 +559,7 @@ fn parse_update_tuple_row_values() {
                 },
                 joins: vec![],
             },
-            from: None,
+            from: vec![],
             returning: None
         }
     );
@@ -533,7 +604,10 @@ fn parse_start_transaction_with_modifier() {
         dialects: all_dialects()
             .dialects
             .into_iter()
-            .filter(|x| !(x.is::<SQLiteDialect>() || x.is::<GenericDialect>()))
+            // `BEGIN` starts a `BEGIN ... END` block in MSSQL
+            .filter(|x| {
+                !(x.is::<SQLiteDialect>() || x.is::<GenericDialect>() || x.is::<MsSqlDialect>())
+            })
             .collect(),
         options: None,
     };
@@ -37,3 +37,18 @@ macro_rules! nest {
         }), alias: None}
     };
 }
+
+/// Asserts that a [`Statement`](sqlparser::ast::Statement) survives a
+/// round-trip through its serde JSON representation.
+#[cfg(feature = "serde")]
+#[macro_export]
+macro_rules! assert_serde_round_trip {
+    ($statement:expr) => {{
+        let statement: &sqlparser::ast::Statement = &$statement;
+        let json = serde_json::to_string(statement).unwrap();
+        let deserialized: sqlparser::ast::Statement = serde_json::from_str(&json).expect(&json);
+        assert_eq!(statement, &deserialized);
+        assert_eq!(json, serde_json::to_string(&deserialized).unwrap());
+        assert_eq!(statement.to_string(), deserialized.to_string());
+    }};
+}